@@ -137,6 +137,85 @@ pub const PERSIST_FAST_PATH_ORDER: Config<bool> = Config::new(
     "If set, send queries with a compatible literal constraint or ordering clause down the Persist fast path.",
 );
 
+/// How often the index advisor replays the statement log.
+pub const INDEX_ADVISOR_INTERVAL: Config<Duration> = Config::new(
+    "index_advisor_interval",
+    Duration::from_secs(60 * 60),
+    "How often the index advisor replays the statement log to suggest indexes.",
+);
+
+/// How far back the index advisor looks in the statement log.
+pub const INDEX_ADVISOR_WINDOW: Config<Duration> = Config::new(
+    "index_advisor_window",
+    Duration::from_secs(24 * 60 * 60),
+    "How far back the index advisor reads statement executions from the statement log.",
+);
+
+/// How many statement executions the index advisor reads per run.
+pub const INDEX_ADVISOR_MAX_EXECUTIONS: Config<usize> = Config::new(
+    "index_advisor_max_executions",
+    100_000,
+    "The maximum number of statement executions the index advisor reads from the statement log per run.",
+);
+
+/// How many distinct statements the index advisor replays per run.
+pub const INDEX_ADVISOR_MAX_STATEMENTS: Config<usize> = Config::new(
+    "index_advisor_max_statements",
+    100,
+    "The maximum number of distinct logged statements the index advisor replays per run.",
+);
+
+/// How many indexes the index advisor suggests per run.
+pub const INDEX_ADVISOR_MAX_SUGGESTIONS: Config<usize> = Config::new(
+    "index_advisor_max_suggestions",
+    10,
+    "The maximum number of indexes the index advisor suggests per run.",
+);
+
+/// Whether to keep slow-path peek dataflows around to serve later peeks of the
+/// same plan.
+pub const ENABLE_PEEK_DATAFLOW_CACHE: Config<bool> = Config::new(
+    "enable_peek_dataflow_cache",
+    false,
+    "Whether to keep the dataflows of slow-path peeks warm to serve later peeks of the same plan. Requires `enable_compute_arrangement_size_reporting`.",
+);
+
+/// How much memory kept peek dataflows may use per cluster.
+pub const PEEK_DATAFLOW_CACHE_MEMORY_BUDGET: Config<usize> = Config::new(
+    "peek_dataflow_cache_memory_budget",
+    256 * 1024 * 1024,
+    "The number of bytes that the arrangements of kept peek dataflows, as reported by the replicas, may use per cluster before the least recently used ones are dropped.",
+);
+
+/// How long a kept peek dataflow may go unused before it is dropped.
+pub const PEEK_DATAFLOW_CACHE_IDLE_TIMEOUT: Config<Duration> = Config::new(
+    "peek_dataflow_cache_idle_timeout",
+    Duration::from_secs(60),
+    "How long a kept peek dataflow may go unused before it is dropped.",
+);
+
+/// How often a statement must be executed for its query shapes to be promoted
+/// to indexes.
+pub const AUTO_INDEX_MIN_EXECUTIONS: Config<usize> = Config::new(
+    "auto_index_min_executions",
+    10,
+    "The number of slow-path executions of a statement, identified by its statement log fingerprint, within `auto_index_window` after which its query shapes are promoted to indexes.",
+);
+
+/// The window in which executions of a statement are counted.
+pub const AUTO_INDEX_WINDOW: Config<Duration> = Config::new(
+    "auto_index_window",
+    Duration::from_secs(60),
+    "The window in which slow-path executions of a statement are counted towards promoting its query shapes to indexes.",
+);
+
+/// How long an automatically created index may go unused before it is dropped.
+pub const AUTO_INDEX_IDLE_TIMEOUT: Config<Duration> = Config::new(
+    "auto_index_idle_timeout",
+    Duration::from_secs(10 * 60),
+    "How long an automatically created index may go unused before it is dropped.",
+);
+
 /// Adds the full set of all compute `Config`s.
 pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
     configs
@@ -158,4 +237,15 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&ENABLE_SELF_MANAGED_AUTH)
         .add(&CONSTRAINT_BASED_TIMESTAMP_SELECTION)
         .add(&PERSIST_FAST_PATH_ORDER)
+        .add(&INDEX_ADVISOR_INTERVAL)
+        .add(&INDEX_ADVISOR_WINDOW)
+        .add(&INDEX_ADVISOR_MAX_EXECUTIONS)
+        .add(&INDEX_ADVISOR_MAX_STATEMENTS)
+        .add(&INDEX_ADVISOR_MAX_SUGGESTIONS)
+        .add(&ENABLE_PEEK_DATAFLOW_CACHE)
+        .add(&PEEK_DATAFLOW_CACHE_MEMORY_BUDGET)
+        .add(&PEEK_DATAFLOW_CACHE_IDLE_TIMEOUT)
+        .add(&AUTO_INDEX_MIN_EXECUTIONS)
+        .add(&AUTO_INDEX_WINDOW)
+        .add(&AUTO_INDEX_IDLE_TIMEOUT)
 }
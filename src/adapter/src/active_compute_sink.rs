@@ -437,6 +437,39 @@ impl ActiveCopyTo {
     }
 }
 
+/// A description of an active notification subscribe from the coordinator's
+/// perspective.
+///
+/// Unlike an [`ActiveSubscribe`], a notification subscribe is not owned by any
+/// connection. The coordinator installs one per notification definition and
+/// fans its output out to every session listening on the notification's
+/// channel.
+#[derive(Debug)]
+pub struct ActiveNotification {
+    /// The ID of the notification's catalog item.
+    pub item_id: CatalogItemId,
+    /// The ID of the cluster on which the subscribe is running.
+    pub cluster_id: ClusterId,
+    /// The channel on which notifications are raised.
+    pub channel: String,
+}
+
+impl ActiveNotification {
+    /// Processes a subscribe response from the controller.
+    ///
+    /// Returns one payload per distinct timestamp at which the subscribed
+    /// relation changed, in timestamp order, or the error reported by the
+    /// subscribe.
+    pub fn process_response(&self, batch: SubscribeBatch) -> Result<Vec<String>, String> {
+        let times: BTreeSet<Timestamp> = batch
+            .updates?
+            .into_iter()
+            .map(|(time, _row, _diff)| time)
+            .collect();
+        Ok(times.into_iter().map(|time| time.to_string()).collect())
+    }
+}
+
 /// State we keep in the `Coordinator` to track active `COPY FROM` statements.
 #[derive(Debug)]
 pub(crate) struct ActiveCopyFrom {
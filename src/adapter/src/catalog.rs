@@ -71,7 +71,7 @@ use mz_sql::names::{
     PUBLIC_ROLE_NAME, PartialItemName, QualifiedItemName, QualifiedSchemaName,
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaId, SchemaSpecifier, SystemObjectId,
 };
-use mz_sql::plan::{Plan, PlanNotice, StatementDesc};
+use mz_sql::plan::{IndexKind, Plan, PlanNotice, StatementDesc};
 use mz_sql::rbac;
 use mz_sql::session::metadata::SessionMetadata;
 use mz_sql::session::user::{MZ_SYSTEM_ROLE_ID, SUPPORT_USER, SYSTEM_USER};
@@ -411,6 +411,8 @@ impl Catalog {
                     | CatalogItemType::Type
                     | CatalogItemType::Func
                     | CatalogItemType::Secret
+                    | CatalogItemType::Publication
+                    | CatalogItemType::Notification
                     | CatalogItemType::Connection
                     | CatalogItemType::ContinualTask => {
                         dependencies.extend(global_ids);
@@ -1304,6 +1306,17 @@ impl Catalog {
         self.state.try_get_network_policy_by_name(name)
     }
 
+    pub fn get_replication_slot(
+        &self,
+        name: &str,
+    ) -> Option<&mz_catalog::durable::ReplicationSlot> {
+        self.state.replication_slots.get(name)
+    }
+
+    pub fn replication_slots(&self) -> impl Iterator<Item = &mz_catalog::durable::ReplicationSlot> {
+        self.state.replication_slots.values()
+    }
+
     pub fn clusters(&self) -> impl Iterator<Item = &Cluster> {
         self.state.clusters_by_id.values()
     }
@@ -1515,6 +1528,19 @@ impl Catalog {
         }
     }
 
+    /// Removes the cached expressions of `ids` from the expression cache, e.g.
+    /// because the objects were replanned without being assigned new IDs.
+    pub(crate) fn invalidate_expression_cache<'b>(
+        &self,
+        ids: BTreeSet<GlobalId>,
+    ) -> BoxFuture<'b, ()> {
+        if let Some(expr_cache) = &self.expr_cache_handle {
+            expr_cache.update(Vec::new(), Vec::new(), ids).boxed()
+        } else {
+            async {}.boxed()
+        }
+    }
+
     /// Listen for and apply all unconsumed updates to the durable catalog state.
     // TODO(jkosh44) When this method is actually used outside of a test we can remove the
     // `#[cfg(test)]` annotation.
@@ -1565,6 +1591,8 @@ pub(crate) fn comment_id_to_audit_object_type(id: CommentObjectId) -> ObjectType
         CommentObjectId::ClusterReplica(_) => ObjectType::ClusterReplica,
         CommentObjectId::ContinualTask(_) => ObjectType::ContinualTask,
         CommentObjectId::NetworkPolicy(_) => ObjectType::NetworkPolicy,
+        CommentObjectId::Publication(_) => ObjectType::Publication,
+        CommentObjectId::Notification(_) => ObjectType::Notification,
     }
 }
 
@@ -1596,6 +1624,8 @@ pub(crate) fn system_object_type_to_audit_object_type(
             mz_sql::catalog::ObjectType::Func => ObjectType::Func,
             mz_sql::catalog::ObjectType::ContinualTask => ObjectType::ContinualTask,
             mz_sql::catalog::ObjectType::NetworkPolicy => ObjectType::NetworkPolicy,
+            mz_sql::catalog::ObjectType::Publication => ObjectType::Publication,
+            mz_sql::catalog::ObjectType::Notification => ObjectType::Notification,
         },
         SystemObjectType::System => ObjectType::System,
     }
@@ -1694,6 +1724,9 @@ impl ExprHumanizer for ConnCatalog<'_> {
             Record {
                 custom_id: Some(item_id),
                 ..
+            }
+            | Enum {
+                custom_id: item_id, ..
             } => {
                 let item = self.get_item(item_id);
                 self.minimal_qualification(item.name()).to_string()
@@ -1718,6 +1751,10 @@ impl ExprHumanizer for ConnCatalog<'_> {
                 None => "varchar".into(),
                 Some(length) => format!("varchar({})", length.into_u32()),
             },
+            Vector { dimensions } => match dimensions {
+                None => "vector".into(),
+                Some(dimensions) => format!("vector({})", dimensions.into_u32()),
+            },
             UInt16 => "uint2".into(),
             UInt32 => "uint4".into(),
             UInt64 => "uint8".into(),
@@ -1759,6 +1796,18 @@ impl ExprHumanizer for ConnCatalog<'_> {
                     .map(|col_name| col_name.to_string())
                     .collect::<Vec<_>>();
 
+                // A lexeme index arranges its lexemes, followed by the columns of the rows that
+                // contain them, by the lexeme alone. That permutation is the identity.
+                if index.kind == IndexKind::Lexeme {
+                    on_names.insert(0, "lexeme".to_string());
+                    return Some(on_names);
+                }
+                // The same holds for the buckets of an ANN index.
+                if index.kind == IndexKind::Ann {
+                    on_names.insert(0, "bucket".to_string());
+                    return Some(on_names);
+                }
+
                 let (p, _) = mz_expr::permutation_for_arrangement(&index.keys, on_desc.arity());
 
                 // Init ix_names with unknown column names. Unknown columns are
@@ -3572,7 +3621,10 @@ mod tests {
                         | typ @ ScalarType::MzTimestamp
                         | typ @ ScalarType::List { .. }
                         | typ @ ScalarType::Map { .. }
-                        | typ @ ScalarType::MzAclItem => {
+                        | typ @ ScalarType::MzAclItem
+                        | typ @ ScalarType::Enum { .. }
+                        | typ @ ScalarType::Vector { .. }
+                        | typ @ ScalarType::Geometry => {
                             panic!("{typ:?} type found in {full_name}");
                         }
                         ScalarType::AclItem
@@ -3603,7 +3655,9 @@ mod tests {
                         | ScalarType::RegClass
                         | ScalarType::Int2Vector
                         | ScalarType::Range { .. }
-                        | ScalarType::PgLegacyName => {}
+                        | ScalarType::PgLegacyName
+                        | ScalarType::TsVector
+                        | ScalarType::TsQuery => {}
                     }
                 }
             }
@@ -27,7 +27,7 @@ use mz_catalog::durable::objects::{
     ClusterKey, DatabaseKey, DurableType, ItemKey, NetworkPolicyKey, RoleAuthKey, RoleKey,
     SchemaKey,
 };
-use mz_catalog::durable::{CatalogError, SystemObjectMapping};
+use mz_catalog::durable::{CatalogError, DurableCatalogError, SystemObjectMapping};
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogEntry, CatalogItem, Cluster, ClusterReplica, DataSourceDesc, Database, Func, Index, Log,
@@ -271,6 +271,9 @@ impl CatalogState {
             StateUpdateKind::SourceReferences(source_reference) => {
                 self.apply_source_references_update(source_reference, diff, retractions);
             }
+            StateUpdateKind::ReplicationSlot(slot) => {
+                self.apply_replication_slot_update(slot, diff, retractions);
+            }
             StateUpdateKind::AuditLog(_audit_log) => {
                 // Audit logs are not stored in-memory.
             }
@@ -689,6 +692,7 @@ impl CatalogState {
                         data_source: TableDataSource::TableWrites {
                             defaults: vec![Expr::null(); table.desc.arity()],
                         },
+                        foreign_keys: Vec::new(),
                     }),
                     MZ_SYSTEM_ROLE_ID,
                     PrivilegeMap::from_mz_acl_items(acl_items),
@@ -1013,6 +1017,7 @@ impl CatalogState {
                     },
                     item: name.clone(),
                 };
+                let mut reparse_dependents = false;
                 let entry = match retractions.items.remove(&key) {
                     Some(mut retraction) => {
                         assert_eq!(retraction.id, item.id);
@@ -1021,6 +1026,7 @@ impl CatalogState {
                         // This makes it difficult to use the `UpdateFrom` trait, but the structure
                         // is still the same as the trait.
                         if retraction.create_sql() != create_sql {
+                            reparse_dependents = matches!(retraction.item, CatalogItem::Type(_));
                             let item = self
                                 .deserialize_item(
                                     global_id,
@@ -1068,6 +1074,9 @@ impl CatalogState {
                 };
 
                 self.insert_entry(entry);
+                if reparse_dependents {
+                    self.reparse_type_dependents(id, local_expression_cache)?;
+                }
             }
             StateDiff::Retraction => {
                 let entry = self.drop_item(item.id);
@@ -1078,6 +1087,47 @@ impl CatalogState {
         Ok(())
     }
 
+    /// Reparses the transitive dependents of the type `id`.
+    ///
+    /// The column types of relations that use an enum type carry the type's
+    /// labels, so they must be replanned when labels are added to the type.
+    /// Labels can only be added while no temporary items depend on the type,
+    /// as they cannot be replanned outside of their session.
+    fn reparse_type_dependents(
+        &mut self,
+        id: CatalogItemId,
+        local_expression_cache: &mut LocalExpressionCache,
+    ) -> Result<(), CatalogError> {
+        // Every dependent is reparsed after the dependents it uses, so that it
+        // sees their new column types.
+        for dependent in self.transitive_used_by(id) {
+            let entry = self.get_entry(&dependent);
+            if entry.item().is_temporary() {
+                continue;
+            }
+            let previous_item = entry.item().clone();
+            let (create_sql, global_id, extra_versions) = previous_item.clone().into_serialized();
+            let item = self
+                .deserialize_item(
+                    global_id,
+                    &create_sql,
+                    &extra_versions,
+                    local_expression_cache,
+                    Some(previous_item),
+                )
+                .map_err(|e| {
+                    DurableCatalogError::Internal(format!(
+                        "replanning {dependent} with the new labels of type {id}: {e}"
+                    ))
+                })?;
+            self.entry_by_id
+                .get_mut(&dependent)
+                .expect("catalog out of sync")
+                .item = item;
+        }
+        Ok(())
+    }
+
     #[instrument(level = "debug")]
     fn apply_comment_update(
         &mut self,
@@ -1139,6 +1189,17 @@ impl CatalogState {
         }
     }
 
+    #[instrument(level = "debug")]
+    fn apply_replication_slot_update(
+        &mut self,
+        slot: mz_catalog::durable::ReplicationSlot,
+        diff: StateDiff,
+        _retractions: &mut InProgressRetractions,
+    ) {
+        let name = slot.name.clone();
+        apply_inverted_lookup(&mut self.replication_slots, &name, slot, diff);
+    }
+
     #[instrument(level = "debug")]
     fn apply_storage_collection_metadata_update(
         &mut self,
@@ -1277,6 +1338,9 @@ impl CatalogState {
             StateUpdateKind::NetworkPolicy(policy) => self
                 .pack_network_policy_update(&policy.id, diff)
                 .expect("could not pack audit log update"),
+            StateUpdateKind::ReplicationSlot(slot) => {
+                vec![self.pack_replication_slot_update(&slot, diff)]
+            }
             StateUpdateKind::StorageCollectionMetadata(_)
             | StateUpdateKind::UnfinalizedShard(_)
             | StateUpdateKind::RoleAuth(_) => Vec::new(),
@@ -1610,7 +1674,7 @@ impl CatalogState {
         );
 
         let prev_id = match entry.item() {
-            CatalogItem::Func(_) => schema
+            CatalogItem::Func(_) | CatalogItem::UserFunc(_) => schema
                 .functions
                 .insert(entry.name().item.clone(), entry.id()),
             CatalogItem::Type(_) => schema.types.insert(entry.name().item.clone(), entry.id()),
@@ -1678,11 +1742,12 @@ impl CatalogState {
                 .types
                 .remove(&metadata.name().item)
                 .expect("catalog out of sync");
+        } else if metadata.item_type() == CatalogItemType::Func {
+            schema
+                .functions
+                .remove(&metadata.name().item)
+                .expect("catalog out of sync");
         } else {
-            // Functions would need special handling, but we don't yet support
-            // dropping functions.
-            assert_ne!(metadata.item_type(), CatalogItemType::Func);
-
             schema
                 .items
                 .remove(&metadata.name().item)
@@ -1756,6 +1821,7 @@ impl CatalogState {
                 .into_iter()
                 .map(MirScalarExpr::column)
                 .collect(),
+            kind: plan::IndexKind::Arrangement,
             create_sql: index_sql(
                 index_item_name,
                 cluster_id,
@@ -1874,6 +1940,7 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
             ),
             StateUpdateKind::Comment(_)
             | StateUpdateKind::SourceReferences(_)
+            | StateUpdateKind::ReplicationSlot(_)
             | StateUpdateKind::AuditLog(_)
             | StateUpdateKind::StorageCollectionMetadata(_)
             | StateUpdateKind::UnfinalizedShard(_) => push_update(
@@ -1923,6 +1990,8 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
             | CatalogItemType::Type
             | CatalogItemType::Func
             | CatalogItemType::Secret
+            | CatalogItemType::Publication
+            | CatalogItemType::Notification
             | CatalogItemType::Connection => push_update(
                 StateUpdate {
                     kind: StateUpdateKind::SystemObjectMapping(builtin_item_update),
@@ -2023,9 +2092,6 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         // Partition items into groups s.t. each item in one group has a predefined order with all
         // items in other groups. For example, all sinks are ordered greater than all tables.
         let mut types = Vec::new();
-        // N.B. Functions can depend on system tables, but not user tables.
-        // TODO(udf): This will change when UDFs are supported.
-        let mut funcs = Vec::new();
         let mut secrets = Vec::new();
         let mut connections = Vec::new();
         let mut sources = Vec::new();
@@ -2037,15 +2103,20 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         for update in item_updates {
             match update.0.item_type() {
                 CatalogItemType::Type => types.push(update),
-                CatalogItemType::Func => funcs.push(update),
                 CatalogItemType::Secret => secrets.push(update),
                 CatalogItemType::Connection => connections.push(update),
                 CatalogItemType::Source => sources.push(update),
                 CatalogItemType::Table => tables.push(update),
+                // User-defined functions can depend on user relations and
+                // vice versa, and they cannot be altered, so they are
+                // ordered by ID alongside views.
                 CatalogItemType::View
                 | CatalogItemType::MaterializedView
-                | CatalogItemType::Index => derived_items.push(update),
-                CatalogItemType::Sink => sinks.push(update),
+                | CatalogItemType::Index
+                | CatalogItemType::Func => derived_items.push(update),
+                CatalogItemType::Sink
+                | CatalogItemType::Publication
+                | CatalogItemType::Notification => sinks.push(update),
                 CatalogItemType::ContinualTask => continual_tasks.push(update),
             }
         }
@@ -2053,7 +2124,6 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         // Within each group, sort by ID.
         for group in [
             &mut types,
-            &mut funcs,
             &mut secrets,
             &mut sources,
             &mut tables,
@@ -2071,7 +2141,6 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
 
         iter::empty()
             .chain(types)
-            .chain(funcs)
             .chain(secrets)
             .chain(connections)
             .chain(sources)
@@ -2094,8 +2163,6 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         // Partition items into groups s.t. each item in one group has a predefined order with all
         // items in other groups. For example, all sinks are ordered greater than all tables.
         let mut types = Vec::new();
-        // N.B. Functions can depend on system tables, but not user tables.
-        let mut funcs = Vec::new();
         let mut secrets = Vec::new();
         let mut connections = Vec::new();
         let mut sources = Vec::new();
@@ -2107,15 +2174,17 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         for update in temp_item_updates {
             match update.0.item.typ() {
                 CatalogItemType::Type => types.push(update),
-                CatalogItemType::Func => funcs.push(update),
                 CatalogItemType::Secret => secrets.push(update),
                 CatalogItemType::Connection => connections.push(update),
                 CatalogItemType::Source => sources.push(update),
                 CatalogItemType::Table => tables.push(update),
                 CatalogItemType::View
                 | CatalogItemType::MaterializedView
-                | CatalogItemType::Index => derived_items.push(update),
-                CatalogItemType::Sink => sinks.push(update),
+                | CatalogItemType::Index
+                | CatalogItemType::Func => derived_items.push(update),
+                CatalogItemType::Sink
+                | CatalogItemType::Publication
+                | CatalogItemType::Notification => sinks.push(update),
                 CatalogItemType::ContinualTask => continual_tasks.push(update),
             }
         }
@@ -2123,7 +2192,6 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         // Within each group, sort by ID.
         for group in [
             &mut types,
-            &mut funcs,
             &mut secrets,
             &mut connections,
             &mut sources,
@@ -2137,7 +2205,6 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
 
         iter::empty()
             .chain(types)
-            .chain(funcs)
             .chain(secrets)
             .chain(connections)
             .chain(sources)
@@ -24,19 +24,21 @@ use mz_catalog::builtin::{
     MZ_INTERNAL_CLUSTER_REPLICAS, MZ_KAFKA_CONNECTIONS, MZ_KAFKA_SINKS, MZ_KAFKA_SOURCE_TABLES,
     MZ_KAFKA_SOURCES, MZ_LIST_TYPES, MZ_MAP_TYPES, MZ_MATERIALIZED_VIEW_REFRESH_STRATEGIES,
     MZ_MATERIALIZED_VIEWS, MZ_MYSQL_SOURCE_TABLES, MZ_NETWORK_POLICIES, MZ_NETWORK_POLICY_RULES,
-    MZ_OBJECT_DEPENDENCIES, MZ_OPERATORS, MZ_PENDING_CLUSTER_REPLICAS, MZ_POSTGRES_SOURCE_TABLES,
-    MZ_POSTGRES_SOURCES, MZ_PSEUDO_TYPES, MZ_ROLE_MEMBERS, MZ_ROLE_PARAMETERS, MZ_ROLES,
+    MZ_NOTIFICATIONS, MZ_OBJECT_DEPENDENCIES, MZ_OPERATORS, MZ_PENDING_CLUSTER_REPLICAS,
+    MZ_POSTGRES_SOURCE_TABLES, MZ_POSTGRES_SOURCES, MZ_PSEUDO_TYPES, MZ_PUBLICATION_TABLES,
+    MZ_PUBLICATIONS, MZ_REPLICATION_SLOTS, MZ_ROLE_MEMBERS, MZ_ROLE_PARAMETERS, MZ_ROLES,
     MZ_SCHEMAS, MZ_SECRETS, MZ_SESSIONS, MZ_SINKS, MZ_SOURCE_REFERENCES, MZ_SOURCES,
     MZ_SQL_SERVER_SOURCE_TABLES, MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_USAGE_BY_SHARD,
     MZ_SUBSCRIPTIONS, MZ_SYSTEM_PRIVILEGES, MZ_TABLES, MZ_TYPE_PG_METADATA, MZ_TYPES, MZ_VIEWS,
     MZ_WEBHOOKS_SOURCES,
 };
 use mz_catalog::config::AwsPrincipalContext;
-use mz_catalog::durable::SourceReferences;
+use mz_catalog::durable::{ReplicationSlot, SourceReferences};
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogItem, ClusterReplicaProcessStatus, ClusterVariant, Connection, ContinualTask,
-    DataSourceDesc, Func, Index, MaterializedView, Sink, Table, TableDataSource, Type, View,
+    DataSourceDesc, Func, Index, MaterializedView, Notification, Publication, Sink, Table,
+    TableDataSource, Type, UserFunc, View,
 };
 use mz_controller::clusters::{
     ClusterStatus, ManagedReplicaAvailabilityZones, ManagedReplicaLocation, ProcessId,
@@ -58,8 +60,8 @@ use mz_repr::role_id::RoleId;
 use mz_repr::{CatalogItemId, Datum, Diff, GlobalId, Row, RowPacker, ScalarType, Timestamp};
 use mz_sql::ast::{ContinualTaskStmt, CreateIndexStatement, Statement, UnresolvedItemName};
 use mz_sql::catalog::{
-    CatalogCluster, CatalogDatabase, CatalogSchema, CatalogType, DefaultPrivilegeObject,
-    TypeCategory,
+    CatalogCluster, CatalogDatabase, CatalogFuncReturns, CatalogSchema, CatalogType,
+    DefaultPrivilegeObject, TypeCategory,
 };
 use mz_sql::func::FuncImplCatalogDetails;
 use mz_sql::names::{
@@ -758,6 +760,9 @@ impl CatalogState {
             CatalogItem::Func(func) => {
                 self.pack_func_update(id, schema_id, name, owner_id, func, diff)
             }
+            CatalogItem::UserFunc(func) => {
+                vec![self.pack_user_func_update(id, oid, schema_id, name, owner_id, func, diff)]
+            }
             CatalogItem::Secret(_) => {
                 self.pack_secret_update(id, oid, schema_id, name, owner_id, privileges, diff)
             }
@@ -767,6 +772,18 @@ impl CatalogState {
             CatalogItem::ContinualTask(ct) => self.pack_continual_task_update(
                 id, oid, schema_id, name, owner_id, privileges, ct, diff,
             ),
+            CatalogItem::Publication(publication) => {
+                self.pack_publication_update(id, oid, schema_id, name, owner_id, publication, diff)
+            }
+            CatalogItem::Notification(notification) => self.pack_notification_update(
+                id,
+                oid,
+                schema_id,
+                name,
+                owner_id,
+                notification,
+                diff,
+            ),
         };
 
         if !entry.item().is_temporary() {
@@ -1867,6 +1884,89 @@ impl CatalogState {
         updates
     }
 
+    fn pack_user_func_update(
+        &self,
+        id: CatalogItemId,
+        oid: u32,
+        schema_id: &SchemaSpecifier,
+        name: &str,
+        owner_id: &RoleId,
+        func: &UserFunc,
+        diff: Diff,
+    ) -> BuiltinTableUpdate<&'static BuiltinTable> {
+        // Maps a type to the ID of its entry in `mz_types`. Custom types carry
+        // their ID, while all other types are builtin types identified by OID.
+        let type_id = |typ: &ScalarType| -> String {
+            match typ {
+                ScalarType::List {
+                    custom_id: Some(custom_id),
+                    ..
+                }
+                | ScalarType::Map {
+                    custom_id: Some(custom_id),
+                    ..
+                }
+                | ScalarType::Record {
+                    custom_id: Some(custom_id),
+                    ..
+                }
+                | ScalarType::Enum { custom_id, .. } => custom_id.to_string(),
+                _ => {
+                    let oid = mz_pgrepr::Type::from(typ).oid();
+                    self.system_schema_ids()
+                        .flat_map(|schema_id| self.ambient_schemas_by_id[&schema_id].types.values())
+                        .map(|id| self.get_entry(id))
+                        .find(|entry| entry.oid() == oid)
+                        .unwrap_or_else(|| panic!("cannot find builtin type with OID {oid}"))
+                        .id()
+                        .to_string()
+                }
+            }
+        };
+
+        let arg_type_ids: Vec<_> = func
+            .details
+            .params
+            .iter()
+            .map(|param| type_id(&param.typ))
+            .collect();
+        let mut row = Row::default();
+        row.packer()
+            .try_push_array(
+                &[ArrayDimension {
+                    lower_bound: 1,
+                    length: arg_type_ids.len(),
+                }],
+                arg_type_ids.iter().map(|id| Datum::String(id)),
+            )
+            .expect("arg_type_ids is 1 dimensional, and its length is used for the array length");
+        let arg_type_ids = row.unpack_first();
+
+        let (return_type_id, returns_set) = match &func.details.returns {
+            CatalogFuncReturns::Scalar(typ) => (type_id(typ), false),
+            CatalogFuncReturns::Table(columns) => match &columns[..] {
+                [(_, typ)] => (type_id(typ), true),
+                _ => (self.get_system_type("record").id().to_string(), true),
+            },
+        };
+
+        BuiltinTableUpdate::row(
+            &*MZ_FUNCTIONS,
+            Row::pack_slice(&[
+                Datum::String(&id.to_string()),
+                Datum::UInt32(oid),
+                Datum::String(&schema_id.to_string()),
+                Datum::String(name),
+                arg_type_ids,
+                Datum::Null,
+                Datum::String(&return_type_id),
+                returns_set.into(),
+                Datum::String(&owner_id.to_string()),
+            ]),
+            diff,
+        )
+    }
+
     pub fn pack_op_update(
         &self,
         operator: &str,
@@ -1932,6 +2032,64 @@ impl CatalogState {
         )]
     }
 
+    fn pack_publication_update(
+        &self,
+        id: CatalogItemId,
+        oid: u32,
+        schema_id: &SchemaSpecifier,
+        name: &str,
+        owner_id: &RoleId,
+        publication: &Publication,
+        diff: Diff,
+    ) -> Vec<BuiltinTableUpdate<&'static BuiltinTable>> {
+        let id = id.to_string();
+        let mut updates = vec![BuiltinTableUpdate::row(
+            &*MZ_PUBLICATIONS,
+            Row::pack_slice(&[
+                Datum::String(&id),
+                Datum::UInt32(oid),
+                Datum::String(&schema_id.to_string()),
+                Datum::String(name),
+                Datum::String(&owner_id.to_string()),
+            ]),
+            diff,
+        )];
+        for relation in &publication.relations {
+            updates.push(BuiltinTableUpdate::row(
+                &*MZ_PUBLICATION_TABLES,
+                Row::pack_slice(&[Datum::String(&id), Datum::String(&relation.to_string())]),
+                diff,
+            ));
+        }
+        updates
+    }
+
+    fn pack_notification_update(
+        &self,
+        id: CatalogItemId,
+        oid: u32,
+        schema_id: &SchemaSpecifier,
+        name: &str,
+        owner_id: &RoleId,
+        notification: &Notification,
+        diff: Diff,
+    ) -> Vec<BuiltinTableUpdate<&'static BuiltinTable>> {
+        vec![BuiltinTableUpdate::row(
+            &*MZ_NOTIFICATIONS,
+            Row::pack_slice(&[
+                Datum::String(&id.to_string()),
+                Datum::UInt32(oid),
+                Datum::String(&schema_id.to_string()),
+                Datum::String(name),
+                Datum::String(&notification.on.to_string()),
+                Datum::String(&notification.channel),
+                Datum::from(notification.predicate.as_deref()),
+                Datum::String(&owner_id.to_string()),
+            ]),
+            diff,
+        )]
+    }
+
     pub fn pack_audit_log_update(
         &self,
         event: &VersionedEvent,
@@ -2224,7 +2382,9 @@ impl CatalogState {
             | CommentObjectId::Connection(global_id)
             | CommentObjectId::Secret(global_id)
             | CommentObjectId::Type(global_id)
-            | CommentObjectId::ContinualTask(global_id) => global_id.to_string(),
+            | CommentObjectId::ContinualTask(global_id)
+            | CommentObjectId::Publication(global_id)
+            | CommentObjectId::Notification(global_id) => global_id.to_string(),
             CommentObjectId::Role(role_id) => role_id.to_string(),
             CommentObjectId::Database(database_id) => database_id.to_string(),
             CommentObjectId::Schema((_, schema_id)) => schema_id.to_string(),
@@ -2277,6 +2437,23 @@ impl CatalogState {
         )
     }
 
+    pub fn pack_replication_slot_update(
+        &self,
+        slot: &ReplicationSlot,
+        diff: Diff,
+    ) -> BuiltinTableUpdate<&'static BuiltinTable> {
+        BuiltinTableUpdate::row(
+            &*MZ_REPLICATION_SLOTS,
+            Row::pack_slice(&[
+                Datum::String(&slot.name),
+                Datum::String(&slot.plugin),
+                Datum::String(&slot.owner_id.to_string()),
+                Datum::MzTimestamp(Timestamp::from(slot.confirmed_lsn)),
+            ]),
+            diff,
+        )
+    }
+
     pub fn pack_source_references_update(
         &self,
         source_references: &SourceReferences,
@@ -91,6 +91,7 @@ impl Catalog {
         DataflowMetainfo {
             optimizer_notices,
             index_usage_types: df_meta.index_usage_types,
+            statistics: df_meta.statistics,
         }
     }
 }
@@ -274,7 +274,9 @@ impl CatalogState {
                 | CommentObjectId::Connection(item_id)
                 | CommentObjectId::Type(item_id)
                 | CommentObjectId::Secret(item_id)
-                | CommentObjectId::ContinualTask(item_id) => {
+                | CommentObjectId::ContinualTask(item_id)
+                | CommentObjectId::Publication(item_id)
+                | CommentObjectId::Notification(item_id) => {
                     let entry = self.entry_by_id.get(&item_id);
                     match entry {
                         None => comment_inconsistencies
@@ -552,7 +554,15 @@ impl CatalogState {
                         )
                         | Statement::CreateTable(ast::CreateTableStatement { name, .. })
                         | Statement::CreateType(ast::CreateTypeStatement { name, .. })
-                        | Statement::CreateSecret(ast::CreateSecretStatement { name, .. }) => {
+                        | Statement::CreateSecret(ast::CreateSecretStatement { name, .. })
+                        | Statement::CreatePublication(ast::CreatePublicationStatement {
+                            name,
+                            ..
+                        })
+                        | Statement::CreateNotification(ast::CreateNotificationStatement {
+                            name,
+                            ..
+                        }) => {
                             let [db_component, schema_component, item_component] = &name.0[..]
                             else {
                                 let name =
@@ -149,6 +149,7 @@ impl Catalog {
             system_privileges: PrivilegeMap::default(),
             comments: CommentsMap::default(),
             source_references: BTreeMap::new(),
+            replication_slots: BTreeMap::new(),
             storage_metadata: Default::default(),
             temporary_schemas: BTreeMap::new(),
             config: mz_sql::catalog::CatalogConfig {
@@ -298,6 +299,7 @@ impl Catalog {
                 BootstrapStateUpdateKind::Comment(_)
                 | BootstrapStateUpdateKind::StorageCollectionMetadata(_)
                 | BootstrapStateUpdateKind::SourceReferences(_)
+                | BootstrapStateUpdateKind::ReplicationSlot(_)
                 | BootstrapStateUpdateKind::UnfinalizedShard(_) => {
                     post_item_updates.push((kind, ts, diff));
                 }
@@ -931,6 +933,8 @@ fn add_new_remove_old_builtin_clusters_migration(
                         schedule: Default::default(),
                     }),
                     workload_class: None,
+                    arrangement_spill: false,
+                    auto_index: false,
                 },
                 &HashSet::new(),
             )?;
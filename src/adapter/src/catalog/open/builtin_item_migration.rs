@@ -257,7 +257,10 @@ async fn migrate_builtin_items_0dt(
                 | CatalogItem::Index(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
+                | CatalogItem::UserFunc(_)
                 | CatalogItem::Secret(_)
+                | CatalogItem::Publication(_)
+                | CatalogItem::Notification(_)
                 | CatalogItem::Connection(_) => return None,
             };
             let GlobalId::System(raw_gid) = gid else {
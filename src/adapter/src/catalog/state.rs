@@ -32,8 +32,8 @@ use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogCollectionEntry, CatalogEntry, CatalogItem, Cluster, ClusterReplica, CommentsMap,
     Connection, DataSourceDesc, Database, DefaultPrivileges, Index, MaterializedView,
-    NetworkPolicy, Role, RoleAuth, Schema, Secret, Sink, Source, SourceReferences, Table,
-    TableDataSource, Type, View,
+    NetworkPolicy, Notification, Publication, Role, RoleAuth, Schema, Secret, Sink, Source,
+    SourceReferences, Table, TableDataSource, Type, UserFunc, View,
 };
 use mz_controller::clusters::{
     ManagedReplicaAvailabilityZones, ManagedReplicaLocation, ReplicaAllocation, ReplicaLocation,
@@ -71,9 +71,9 @@ use mz_sql::names::{
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaId, SchemaSpecifier, SystemObjectId,
 };
 use mz_sql::plan::{
-    CreateConnectionPlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateSecretPlan,
-    CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, Params,
-    Plan, PlanContext,
+    CreateConnectionPlan, CreateFunctionPlan, CreateIndexPlan, CreateMaterializedViewPlan,
+    CreateNotificationPlan, CreatePublicationPlan, CreateSecretPlan, CreateSinkPlan,
+    CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, Params, Plan, PlanContext,
 };
 use mz_sql::rbac;
 use mz_sql::session::metadata::SessionMetadata;
@@ -85,6 +85,7 @@ use mz_storage_types::connections::ConnectionContext;
 use mz_storage_types::connections::inline::{
     ConnectionResolver, InlinedConnection, IntoInlineConnection,
 };
+use mz_transform::ForeignKey;
 use serde::Serialize;
 use timely::progress::Antichain;
 use tokio::sync::mpsc;
@@ -139,6 +140,8 @@ pub struct CatalogState {
     pub(super) comments: CommentsMap,
     #[serde(serialize_with = "mz_ore::serde::map_key_to_string")]
     pub(super) source_references: BTreeMap<CatalogItemId, SourceReferences>,
+    #[serde(skip)]
+    pub(super) replication_slots: BTreeMap<String, mz_catalog::durable::ReplicationSlot>,
     pub(super) storage_metadata: StorageMetadata,
 
     // Mutable state not derived from the durable catalog.
@@ -308,6 +311,7 @@ impl CatalogState {
             system_privileges: Default::default(),
             comments: Default::default(),
             source_references: Default::default(),
+            replication_slots: Default::default(),
             storage_metadata: Default::default(),
         }
     }
@@ -395,6 +399,42 @@ impl CatalogState {
         }
     }
 
+    /// Returns the identifiers of all objects that transitively depend on this
+    /// catalog entry, in the sense of [`CatalogEntry::used_by`], excluding the
+    /// entry itself.
+    ///
+    /// The order is guaranteed to be in dependency order, i.e. every object
+    /// appears after all of the returned objects it depends on.
+    pub fn transitive_used_by(&self, id: CatalogItemId) -> Vec<CatalogItemId> {
+        // Count, for every dependent, the edges into it from the entry and
+        // from other dependents.
+        let mut pending: BTreeMap<CatalogItemId, usize> = BTreeMap::new();
+        let mut todo = vec![id];
+        while let Some(id) = todo.pop() {
+            for dependent in self.get_entry(&id).used_by() {
+                let count = pending.entry(*dependent).or_default();
+                if *count == 0 {
+                    todo.push(*dependent);
+                }
+                *count += 1;
+            }
+        }
+        // Emit every dependent once all of the edges into it are visited.
+        let mut used_by = Vec::with_capacity(pending.len());
+        let mut ready = vec![id];
+        while let Some(id) = ready.pop() {
+            for dependent in self.get_entry(&id).used_by() {
+                let count = pending.get_mut(dependent).expect("counted above");
+                *count -= 1;
+                if *count == 0 {
+                    used_by.push(*dependent);
+                    ready.push(*dependent);
+                }
+            }
+        }
+        used_by
+    }
+
     /// Computes the IDs of any log sources this catalog entry transitively
     /// depends on.
     pub fn introspection_dependencies(&self, id: CatalogItemId) -> Vec<CatalogItemId> {
@@ -427,7 +467,10 @@ impl CatalogState {
             | CatalogItem::Source(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
-            | CatalogItem::Secret(_) => (),
+            | CatalogItem::UserFunc(_)
+            | CatalogItem::Secret(_)
+            | CatalogItem::Publication(_)
+            | CatalogItem::Notification(_) => (),
         }
     }
 
@@ -1091,6 +1134,7 @@ impl CatalogState {
                     custom_logical_compaction_window: custom_logical_compaction_window
                         .or(table.compaction_window),
                     is_retained_metrics_object,
+                    foreign_keys: table.foreign_keys,
                     data_source: match table.data_source {
                         mz_sql::plan::TableDataSource::TableWrites { defaults } => {
                             TableDataSource::TableWrites { defaults }
@@ -1348,6 +1392,7 @@ impl CatalogState {
                 global_id,
                 on: index.on,
                 keys: index.keys.into(),
+                kind: index.kind,
                 conn_id: None,
                 resolved_ids,
                 cluster_id: index.cluster_id,
@@ -1405,6 +1450,32 @@ impl CatalogState {
                 details,
                 resolved_ids,
             }),
+            Plan::CreatePublication(CreatePublicationPlan { publication, .. }) => {
+                CatalogItem::Publication(Publication {
+                    create_sql: publication.create_sql,
+                    global_id,
+                    relations: publication.relations,
+                    resolved_ids,
+                })
+            }
+            Plan::CreateFunction(CreateFunctionPlan { function, .. }) => {
+                CatalogItem::UserFunc(UserFunc {
+                    create_sql: function.create_sql,
+                    global_id,
+                    details: function.details,
+                    resolved_ids,
+                })
+            }
+            Plan::CreateNotification(CreateNotificationPlan { notification, .. }) => {
+                CatalogItem::Notification(Notification {
+                    create_sql: notification.create_sql,
+                    global_id,
+                    on: notification.on,
+                    channel: notification.channel,
+                    predicate: notification.predicate,
+                    resolved_ids,
+                })
+            }
             _ => {
                 return Err((
                     Error::new(ErrorKind::Corruption {
@@ -1465,6 +1536,30 @@ impl CatalogState {
             .flatten()
     }
 
+    /// Returns the foreign keys declared on the given object, once for each
+    /// [`GlobalId`] of the referenced object.
+    pub fn get_foreign_keys_on(&self, id: GlobalId) -> Vec<ForeignKey> {
+        let Some(CatalogItem::Table(table)) = self
+            .try_get_entry_by_global_id(&id)
+            .map(|entry| entry.item())
+        else {
+            return Vec::new();
+        };
+        table
+            .foreign_keys
+            .iter()
+            .flat_map(|fk| {
+                self.get_entry(&fk.referenced_id)
+                    .global_ids()
+                    .map(|referenced_id| ForeignKey {
+                        columns: fk.columns.clone(),
+                        referenced_id,
+                        referenced_columns: fk.referenced_columns.clone(),
+                    })
+            })
+            .collect()
+    }
+
     pub(super) fn get_database(&self, database_id: &DatabaseId) -> &Database {
         &self.database_by_id[database_id]
     }
@@ -1738,6 +1833,8 @@ impl CatalogState {
             | CatalogItemType::MaterializedView
             | CatalogItemType::Index
             | CatalogItemType::Secret
+            | CatalogItemType::Publication
+            | CatalogItemType::Notification
             | CatalogItemType::Connection
             | CatalogItemType::ContinualTask => schema.items[builtin.name()],
         }
@@ -1788,8 +1885,23 @@ impl CatalogState {
             CatalogType::Bytes => CatalogType::Bytes,
             CatalogType::Char => CatalogType::Char,
             CatalogType::Date => CatalogType::Date,
+            CatalogType::Domain {
+                base_reference,
+                base_modifiers,
+                not_null,
+                check,
+            } => CatalogType::Domain {
+                base_reference: self.get_system_type(base_reference).id,
+                base_modifiers: base_modifiers.clone(),
+                not_null: *not_null,
+                check: check.clone(),
+            },
+            CatalogType::Enum { labels } => CatalogType::Enum {
+                labels: labels.clone(),
+            },
             CatalogType::Float32 => CatalogType::Float32,
             CatalogType::Float64 => CatalogType::Float64,
+            CatalogType::Geometry => CatalogType::Geometry,
             CatalogType::Int16 => CatalogType::Int16,
             CatalogType::Int32 => CatalogType::Int32,
             CatalogType::Int64 => CatalogType::Int64,
@@ -1811,8 +1923,11 @@ impl CatalogState {
             CatalogType::Time => CatalogType::Time,
             CatalogType::Timestamp => CatalogType::Timestamp,
             CatalogType::TimestampTz => CatalogType::TimestampTz,
+            CatalogType::TsQuery => CatalogType::TsQuery,
+            CatalogType::TsVector => CatalogType::TsVector,
             CatalogType::Uuid => CatalogType::Uuid,
             CatalogType::VarChar => CatalogType::VarChar,
+            CatalogType::Vector => CatalogType::Vector,
             CatalogType::Int2Vector => CatalogType::Int2Vector,
             CatalogType::MzAclItem => CatalogType::MzAclItem,
         };
@@ -2148,6 +2263,8 @@ impl CatalogState {
                     CatalogItemType::Type => CommentObjectId::Type(item_id),
                     CatalogItemType::Secret => CommentObjectId::Secret(item_id),
                     CatalogItemType::ContinualTask => CommentObjectId::ContinualTask(item_id),
+                    CatalogItemType::Publication => CommentObjectId::Publication(item_id),
+                    CatalogItemType::Notification => CommentObjectId::Notification(item_id),
                 }
             }
             ObjectId::Role(role_id) => CommentObjectId::Role(role_id),
@@ -2570,7 +2687,9 @@ impl CatalogState {
             | CommentObjectId::Connection(id)
             | CommentObjectId::Type(id)
             | CommentObjectId::Secret(id)
-            | CommentObjectId::ContinualTask(id) => Some(*id),
+            | CommentObjectId::ContinualTask(id)
+            | CommentObjectId::Publication(id)
+            | CommentObjectId::Notification(id) => Some(*id),
             CommentObjectId::Role(_)
             | CommentObjectId::Database(_)
             | CommentObjectId::Schema(_)
@@ -2600,7 +2719,9 @@ impl CatalogState {
             | CommentObjectId::Connection(id)
             | CommentObjectId::Type(id)
             | CommentObjectId::Secret(id)
-            | CommentObjectId::ContinualTask(id) => {
+            | CommentObjectId::ContinualTask(id)
+            | CommentObjectId::Publication(id)
+            | CommentObjectId::Notification(id) => {
                 let item = self.get_entry(&id);
                 let name = self.resolve_full_name(item.name(), Some(conn_id));
                 name.to_string()
@@ -2672,6 +2793,17 @@ impl OptimizerCatalog for CatalogState {
     ) -> Box<dyn Iterator<Item = (GlobalId, &Index)> + '_> {
         Box::new(CatalogState::get_indexes_on(self, id, cluster))
     }
+
+    fn get_index(&self, id: &GlobalId) -> Option<&Index> {
+        match self.try_get_entry_by_global_id(id)?.item() {
+            CatalogItem::Index(index) => Some(index),
+            _ => None,
+        }
+    }
+
+    fn get_foreign_keys_on(&self, id: GlobalId) -> Vec<ForeignKey> {
+        CatalogState::get_foreign_keys_on(self, id)
+    }
 }
 
 impl OptimizerCatalog for Catalog {
@@ -2698,6 +2830,14 @@ impl OptimizerCatalog for Catalog {
     ) -> Box<dyn Iterator<Item = (GlobalId, &Index)> + '_> {
         Box::new(self.state.get_indexes_on(id, cluster))
     }
+
+    fn get_index(&self, id: &GlobalId) -> Option<&Index> {
+        OptimizerCatalog::get_index(&self.state, id)
+    }
+
+    fn get_foreign_keys_on(&self, id: GlobalId) -> Vec<ForeignKey> {
+        self.state.get_foreign_keys_on(id)
+    }
 }
 
 impl Catalog {
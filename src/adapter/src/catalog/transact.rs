@@ -26,7 +26,7 @@ use mz_audit_log::{
 };
 use mz_catalog::SYSTEM_CONN_ID;
 use mz_catalog::builtin::BuiltinLog;
-use mz_catalog::durable::{NetworkPolicy, Transaction};
+use mz_catalog::durable::{NetworkPolicy, ReplicationSlot, Transaction};
 use mz_catalog::memory::error::{AmbiguousRename, Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogItem, ClusterConfig, DataSourceDesc, SourceReferences, StateDiff, StateUpdate,
@@ -98,6 +98,10 @@ pub enum Op {
         typ: ColumnType,
         sql: RawDataType,
     },
+    AlterTypeAddValue {
+        id: CatalogItemId,
+        labels: Vec<String>,
+    },
     CreateDatabase {
         name: String,
         owner_id: RoleId,
@@ -208,6 +212,15 @@ pub enum Op {
         source_id: CatalogItemId,
         references: SourceReferences,
     },
+    CreateReplicationSlot {
+        slot: ReplicationSlot,
+    },
+    UpdateReplicationSlot {
+        slot: ReplicationSlot,
+    },
+    DropReplicationSlot {
+        name: String,
+    },
     UpdateSystemConfiguration {
         name: String,
         value: OwnedVarInput,
@@ -740,6 +753,13 @@ impl Catalog {
                 tx.update_item(id, new_entry.into())?;
                 storage_collections_to_register.insert(new_global_id, shard_id);
             }
+            Op::AlterTypeAddValue { id, labels } => {
+                let mut new_entry = state.get_entry(&id).clone();
+                new_entry.item.set_enum_labels(labels)?;
+                tx.update_item(id, new_entry.into())?;
+
+                Self::log_update(state, &id);
+            }
             Op::CreateDatabase { name, owner_id } => {
                 let database_owner_privileges = vec![rbac::owner_privilege(
                     mz_sql::catalog::ObjectType::Database,
@@ -1066,7 +1086,10 @@ impl Catalog {
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
+                    | CatalogItem::UserFunc(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Publication(_)
+                    | CatalogItem::Notification(_)
                     | CatalogItem::Connection(_) => (),
                 }
 
@@ -1347,6 +1370,15 @@ impl Catalog {
                     references.updated_at,
                 )?;
             }
+            Op::CreateReplicationSlot { slot } => {
+                tx.insert_replication_slot(slot)?;
+            }
+            Op::UpdateReplicationSlot { slot } => {
+                tx.update_replication_slot(slot)?;
+            }
+            Op::DropReplicationSlot { name } => {
+                tx.remove_replication_slot(&name)?;
+            }
             Op::DropObjects(drop_object_infos) => {
                 // Generate all of the objects that need to get dropped.
                 let delta = ObjectsToDrop::generate(drop_object_infos, state, session)?;
@@ -1367,12 +1399,14 @@ impl Catalog {
                     (entry.clone().into(), StateDiff::Retraction)
                 }));
 
+                let mut dropped_global_ids = BTreeSet::new();
                 for item_id in delta.items {
                     let entry = state.get_entry(&item_id);
 
                     if entry.item().is_storage_collection() {
                         storage_collections_to_drop.extend(entry.global_ids());
                     }
+                    dropped_global_ids.extend(entry.global_ids());
 
                     if state.source_references.contains_key(&item_id) {
                         tx.remove_source_references(item_id)?;
@@ -1404,6 +1438,17 @@ impl Catalog {
                     );
                 }
 
+                // Replication slots stop streaming dropped collections.
+                for slot in state.replication_slots.values() {
+                    if slot.collections.is_disjoint(&dropped_global_ids) {
+                        continue;
+                    }
+                    let mut slot = slot.clone();
+                    slot.collections
+                        .retain(|id| !dropped_global_ids.contains(id));
+                    tx.update_replication_slot(slot)?;
+                }
+
                 // Drop any schemas.
                 let schemas = delta
                     .schemas
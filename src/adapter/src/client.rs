@@ -33,7 +33,7 @@ use mz_ore::result::ResultExt;
 use mz_ore::task::AbortOnDropHandle;
 use mz_ore::thread::JoinOnDropHandle;
 use mz_ore::tracing::OpenTelemetryContext;
-use mz_repr::{CatalogItemId, ColumnIndex, Row, RowIterator, ScalarType};
+use mz_repr::{CatalogItemId, ColumnIndex, Row, RowIterator, ScalarType, Timestamp};
 use mz_sql::ast::{Raw, Statement};
 use mz_sql::catalog::{EnvironmentId, SessionCatalog};
 use mz_sql::session::hint::ApplicationNameHint;
@@ -49,14 +49,16 @@ use uuid::Uuid;
 
 use crate::catalog::Catalog;
 use crate::command::{
-    AuthResponse, CatalogDump, CatalogSnapshot, Command, ExecuteResponse, Response,
+    AuthResponse, CatalogDump, CatalogSnapshot, Command, ExecuteResponse, ReplicationSlotRequest,
+    Response,
 };
 use crate::coord::{Coordinator, ExecuteContextExtra};
 use crate::error::AdapterError;
 use crate::metrics::Metrics;
 use crate::optimize::{self, Optimize};
 use crate::session::{
-    EndTransactionAction, PreparedStatement, Session, SessionConfig, TransactionId,
+    ChannelNotification, EndTransactionAction, PreparedStatement, Session, SessionConfig,
+    TransactionId,
 };
 use crate::statement_logging::{StatementEndedExecutionReason, StatementExecutionStrategy};
 use crate::telemetry::{self, EventDetails, SegmentClientExt, StatementFailureType};
@@ -184,6 +186,7 @@ impl Client {
         let client_ip = session.client_ip();
         let application_name = session.application_name().into();
         let notice_tx = session.retain_notice_transmitter();
+        let notification_tx = session.retain_notification_transmitter();
 
         let (tx, rx) = oneshot::channel();
 
@@ -208,6 +211,7 @@ impl Client {
             client_ip: client_ip.copied(),
             application_name,
             notice_tx,
+            notification_tx,
         });
 
         // When startup fails, no need to call terminate (handle_startup does this). Delay creating
@@ -740,6 +744,21 @@ impl SessionClient {
         self.send_without_session(|tx| Command::Dump { tx }).await
     }
 
+    /// Creates, drops, starts, advances, or releases a logical replication slot,
+    /// returning the position the slot's consumer should resume from.
+    pub async fn replication_slot(
+        &mut self,
+        request: ReplicationSlotRequest,
+    ) -> Result<Timestamp, AdapterError> {
+        let conn_id = self.session().conn_id().clone();
+        self.send_without_session(|tx| Command::ReplicationSlot {
+            conn_id,
+            request,
+            tx,
+        })
+        .await
+    }
+
     /// Tells the coordinator a statement has finished execution, in the cases
     /// where we have no other reason to communicate with the coordinator.
     pub fn retire_execute(&self, data: ExecuteContextExtra, reason: StatementEndedExecutionReason) {
@@ -902,7 +921,8 @@ impl SessionClient {
                 | Command::Terminate { .. }
                 | Command::RetireExecute { .. }
                 | Command::CheckConsistency { .. }
-                | Command::Dump { .. } => {}
+                | Command::Dump { .. }
+                | Command::ReplicationSlot { .. } => {}
             };
             cmd
         });
@@ -974,6 +994,22 @@ impl SessionClient {
     pub async fn recv_timeout(&mut self) -> Option<TimeoutType> {
         self.timeouts.recv().await
     }
+
+    /// Awaits the next event for an idle session: either a timeout or an
+    /// asynchronous notification. Timeouts take priority.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe, as both `recv_timeout` and
+    /// [`Session::recv_notification`] are.
+    pub async fn recv_idle_event(&mut self) -> IdleEvent {
+        let session = self.session.as_mut().expect("session invariant violated");
+        tokio::select! {
+            biased;
+            Some(timeout) = self.timeouts.recv() => IdleEvent::Timeout(timeout),
+            notification = session.recv_notification() => IdleEvent::Notification(notification),
+        }
+    }
 }
 
 impl Drop for SessionClient {
@@ -994,6 +1030,15 @@ impl Drop for SessionClient {
     }
 }
 
+/// An event that can arrive for a session while it is idle.
+#[derive(Debug)]
+pub enum IdleEvent {
+    /// A session timeout fired.
+    Timeout(TimeoutType),
+    /// A notification arrived on a channel the session is listening on.
+    Notification(ChannelNotification),
+}
+
 #[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Debug)]
 pub enum TimeoutType {
     IdleInTransactionSession(TransactionId),
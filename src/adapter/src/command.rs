@@ -23,7 +23,7 @@ use mz_ore::soft_assert_no_log;
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_pgcopy::CopyFormatParams;
 use mz_repr::role_id::RoleId;
-use mz_repr::{CatalogItemId, ColumnIndex, RowIterator};
+use mz_repr::{CatalogItemId, ColumnIndex, GlobalId, RowIterator, Timestamp};
 use mz_sql::ast::{FetchDirection, Raw, Statement};
 use mz_sql::catalog::ObjectType;
 use mz_sql::plan::{ExecuteTimeout, Plan, PlanKind};
@@ -39,7 +39,7 @@ use crate::coord::appends::BuiltinTableAppendNotify;
 use crate::coord::consistency::CoordinatorInconsistencies;
 use crate::coord::peek::PeekResponseUnary;
 use crate::error::AdapterError;
-use crate::session::{EndTransactionAction, RowBatchStream, Session};
+use crate::session::{ChannelNotification, EndTransactionAction, RowBatchStream, Session};
 use crate::statement_logging::{StatementEndedExecutionReason, StatementExecutionStrategy};
 use crate::util::Transmittable;
 use crate::webhook::AppendWebhookResponse;
@@ -65,6 +65,7 @@ pub enum Command {
         uuid: Uuid,
         application_name: String,
         notice_tx: mpsc::UnboundedSender<AdapterNotice>,
+        notification_tx: mpsc::UnboundedSender<ChannelNotification>,
     },
 
     AuthenticatePassword {
@@ -139,6 +140,35 @@ pub enum Command {
     Dump {
         tx: oneshot::Sender<Result<serde_json::Value, anyhow::Error>>,
     },
+
+    /// Creates, drops, starts, or advances a logical replication slot on
+    /// behalf of the connection `conn_id`. Responds with the position the
+    /// slot's consumer should resume from.
+    ReplicationSlot {
+        conn_id: ConnectionId,
+        request: ReplicationSlotRequest,
+        tx: oneshot::Sender<Result<Timestamp, AdapterError>>,
+    },
+}
+
+/// A request concerning a logical replication slot.
+#[derive(Debug)]
+pub enum ReplicationSlotRequest {
+    /// Durably records a new slot.
+    Create { name: String, plugin: String },
+    /// Drops a slot and releases its read holds.
+    Drop { name: String },
+    /// Begins streaming the relations `collections` from the slot, at `lsn`
+    /// or the slot's confirmed position, whichever is later.
+    Start {
+        name: String,
+        lsn: Timestamp,
+        collections: BTreeSet<GlobalId>,
+    },
+    /// Records that the slot's consumer has flushed all changes up to `lsn`.
+    Confirm { name: String, lsn: Timestamp },
+    /// Ends streaming from the slot, so that another connection may start it.
+    Release { name: String },
 }
 
 impl Command {
@@ -156,7 +186,8 @@ impl Command {
             | Command::SetSystemVars { .. }
             | Command::RetireExecute { .. }
             | Command::CheckConsistency { .. }
-            | Command::Dump { .. } => None,
+            | Command::Dump { .. }
+            | Command::ReplicationSlot { .. } => None,
         }
     }
 
@@ -174,7 +205,8 @@ impl Command {
             | Command::SetSystemVars { .. }
             | Command::RetireExecute { .. }
             | Command::CheckConsistency { .. }
-            | Command::Dump { .. } => None,
+            | Command::Dump { .. }
+            | Command::ReplicationSlot { .. } => None,
         }
     }
 }
@@ -291,10 +323,16 @@ pub enum ExecuteResponse {
     CreatedCluster,
     /// The requested cluster replica was created.
     CreatedClusterReplica,
+    /// The requested function was created.
+    CreatedFunction,
     /// The requested index was created.
     CreatedIndex,
     /// The requested introspection subscribe was created.
     CreatedIntrospectionSubscribe,
+    /// The requested notification was created.
+    CreatedNotification,
+    /// The requested publication was created.
+    CreatedPublication,
     /// The requested secret was created.
     CreatedSecret,
     /// The requested sink was created.
@@ -347,6 +385,8 @@ pub enum ExecuteResponse {
     GrantedRole,
     /// The specified number of rows were inserted into the requested table.
     Inserted(usize),
+    /// The session started listening on a notification channel.
+    Listening,
     /// The specified prepared statement was created.
     Prepare,
     /// A user-requested warning was raised.
@@ -395,6 +435,8 @@ pub enum ExecuteResponse {
         /// Session parameters that changed because the transaction ended.
         params: BTreeMap<&'static str, String>,
     },
+    /// The session stopped listening on one or all notification channels.
+    Unlistening,
     /// The specified number of rows were updated in the requested table.
     Updated(usize),
     /// A connection was validated.
@@ -470,7 +512,10 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::CreatedClusterReplica => {
                 Ok(ExecuteResponse::CreatedClusterReplica)
             }
+            ExecuteResponseKind::CreatedFunction => Ok(ExecuteResponse::CreatedFunction),
             ExecuteResponseKind::CreatedIndex => Ok(ExecuteResponse::CreatedIndex),
+            ExecuteResponseKind::CreatedNotification => Ok(ExecuteResponse::CreatedNotification),
+            ExecuteResponseKind::CreatedPublication => Ok(ExecuteResponse::CreatedPublication),
             ExecuteResponseKind::CreatedSecret => Ok(ExecuteResponse::CreatedSecret),
             ExecuteResponseKind::CreatedSink => Ok(ExecuteResponse::CreatedSink),
             ExecuteResponseKind::CreatedSource => Ok(ExecuteResponse::CreatedSource),
@@ -495,6 +540,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::GrantedPrivilege => Ok(ExecuteResponse::GrantedPrivilege),
             ExecuteResponseKind::GrantedRole => Ok(ExecuteResponse::GrantedRole),
             ExecuteResponseKind::Inserted => Err(()),
+            ExecuteResponseKind::Listening => Ok(ExecuteResponse::Listening),
             ExecuteResponseKind::Prepare => Ok(ExecuteResponse::Prepare),
             ExecuteResponseKind::Raised => Ok(ExecuteResponse::Raised),
             ExecuteResponseKind::ReassignOwned => Ok(ExecuteResponse::ReassignOwned),
@@ -506,6 +552,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::Subscribing => Err(()),
             ExecuteResponseKind::TransactionCommitted => Err(()),
             ExecuteResponseKind::TransactionRolledBack => Err(()),
+            ExecuteResponseKind::Unlistening => Ok(ExecuteResponse::Unlistening),
             ExecuteResponseKind::Updated => Err(()),
             ExecuteResponseKind::ValidatedConnection => Ok(ExecuteResponse::ValidatedConnection),
             ExecuteResponseKind::SendingRowsImmediate => Err(()),
@@ -535,7 +582,10 @@ impl ExecuteResponse {
             CreatedRole => Some("CREATE ROLE".into()),
             CreatedCluster { .. } => Some("CREATE CLUSTER".into()),
             CreatedClusterReplica { .. } => Some("CREATE CLUSTER REPLICA".into()),
+            CreatedFunction => Some("CREATE FUNCTION".into()),
             CreatedIndex { .. } => Some("CREATE INDEX".into()),
+            CreatedNotification => Some("CREATE NOTIFICATION".into()),
+            CreatedPublication { .. } => Some("CREATE PUBLICATION".into()),
             CreatedSecret { .. } => Some("CREATE SECRET".into()),
             CreatedSink { .. } => Some("CREATE SINK".into()),
             CreatedSource { .. } => Some("CREATE SOURCE".into()),
@@ -567,6 +617,7 @@ impl ExecuteResponse {
                 // have OIDs.
                 Some(format!("INSERT 0 {}", n))
             }
+            Listening => Some("LISTEN".into()),
             Prepare => Some("PREPARE".into()),
             Raised => Some("RAISE".into()),
             ReassignOwned => Some("REASSIGN OWNED".into()),
@@ -579,6 +630,7 @@ impl ExecuteResponse {
             Subscribing { .. } => None,
             TransactionCommitted { .. } => Some("COMMIT".into()),
             TransactionRolledBack { .. } => Some("ROLLBACK".into()),
+            Unlistening => Some("UNLISTEN".into()),
             Updated(n) => Some(format!("UPDATE {}", n)),
             ValidatedConnection => Some("VALIDATE CONNECTION".into()),
             CreatedIntrospectionSubscribe => Some("CREATE INTROSPECTION SUBSCRIBE".into()),
@@ -609,6 +661,7 @@ impl ExecuteResponse {
             | AlterSource
             | AlterSink
             | AlterTableAddColumn
+            | AlterTypeAddValue
             | AlterNetworkPolicy => &[AlteredObject],
             AlterDefaultPrivileges => &[AlteredDefaultPrivileges],
             AlterSetCluster => &[AlteredObject],
@@ -628,6 +681,9 @@ impl ExecuteResponse {
             CreateCluster => &[CreatedCluster],
             CreateClusterReplica => &[CreatedClusterReplica],
             CreateSource | CreateSources => &[CreatedSource],
+            CreateFunction => &[CreatedFunction],
+            CreateNotification => &[CreatedNotification],
+            CreatePublication => &[CreatedPublication],
             CreateSecret => &[CreatedSecret],
             CreateSink => &[CreatedSink],
             CreateTable => &[CreatedTable],
@@ -661,6 +717,7 @@ impl ExecuteResponse {
             GrantPrivileges => &[GrantedPrivilege],
             GrantRole => &[GrantedRole],
             Insert => &[Inserted, SendingRowsImmediate],
+            Listen => &[Listening],
             PlanKind::Prepare => &[ExecuteResponseKind::Prepare],
             PlanKind::Raise => &[ExecuteResponseKind::Raised],
             PlanKind::ReassignOwned => &[ExecuteResponseKind::ReassignOwned],
@@ -672,6 +729,7 @@ impl ExecuteResponse {
             PlanKind::Subscribe => &[Subscribing, ExecuteResponseKind::CopyTo],
             StartTransaction => &[StartedTransaction],
             SideEffectingFunc => &[SendingRows, SendingRowsImmediate],
+            Unlisten => &[Unlistening],
             ValidateConnection => &[ExecuteResponseKind::ValidatedConnection],
         }
     }
@@ -132,7 +132,7 @@ use mz_repr::explain::{ExplainConfig, ExplainFormat};
 use mz_repr::global_id::TransientIdGen;
 use mz_repr::optimize::OptimizerFeatures;
 use mz_repr::role_id::RoleId;
-use mz_repr::{CatalogItemId, Diff, GlobalId, RelationDesc, Timestamp};
+use mz_repr::{CatalogItemId, Diff, GlobalId, RelationDesc, Row, Timestamp};
 use mz_secrets::cache::CachingSecretsReader;
 use mz_secrets::{SecretsController, SecretsReader};
 use mz_sql::ast::{Raw, Statement};
@@ -156,11 +156,9 @@ use mz_storage_types::read_holds::ReadHold;
 use mz_storage_types::sinks::{S3SinkFormat, StorageSinkDesc};
 use mz_storage_types::sources::Timeline;
 use mz_storage_types::sources::kafka::KAFKA_PROGRESS_DESC;
-use mz_timestamp_oracle::WriteTimestamp;
-use mz_timestamp_oracle::postgres_oracle::{
-    PostgresTimestampOracle, PostgresTimestampOracleConfig,
-};
+use mz_timestamp_oracle::{TimestampOracleConfig, WriteTimestamp};
 use mz_transform::dataflow::DataflowMetainfo;
+use mz_transform::notice::OptimizerNotice;
 use opentelemetry::trace::TraceContextExt;
 use serde::Serialize;
 use thiserror::Error;
@@ -173,7 +171,7 @@ use tracing::{Instrument, Level, Span, debug, info, info_span, span, warn};
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 use uuid::Uuid;
 
-use crate::active_compute_sink::{ActiveComputeSink, ActiveCopyFrom};
+use crate::active_compute_sink::{ActiveComputeSink, ActiveCopyFrom, ActiveNotification};
 use crate::catalog::{BuiltinTableUpdate, Catalog, OpenCatalogResult};
 use crate::client::{Client, Handle};
 use crate::command::{Command, ExecuteResponse};
@@ -181,12 +179,15 @@ use crate::config::{SynchronizedParameters, SystemParameterFrontend, SystemParam
 use crate::coord::appends::{
     BuiltinTableAppendNotify, DeferredOp, GroupCommitPermit, PendingWriteTxn,
 };
+use crate::coord::auto_index::{AutoIndexes, QueryShape};
 use crate::coord::caught_up::CaughtUpCheckContext;
 use crate::coord::cluster_scheduling::SchedulingDecision;
 use crate::coord::id_bundle::CollectionIdBundle;
+use crate::coord::index_advisor::IndexSuggestion;
 use crate::coord::introspection::IntrospectionSubscribe;
 use crate::coord::peek::PendingPeek;
-use crate::coord::statement_logging::{StatementLogging, StatementLoggingId};
+use crate::coord::peek_dataflow_cache::PeekDataflowCache;
+use crate::coord::statement_logging::{StatementFingerprint, StatementLogging, StatementLoggingId};
 use crate::coord::timeline::{TimelineContext, TimelineState};
 use crate::coord::timestamp_selection::{TimestampContext, TimestampDetermination};
 use crate::coord::validity::PlanValidity;
@@ -198,7 +199,7 @@ use crate::optimize::dataflows::{
     ComputeInstanceSnapshot, DataflowBuilder, dataflow_import_id_bundle,
 };
 use crate::optimize::{self, Optimize, OptimizerConfig};
-use crate::session::{EndTransactionAction, Session};
+use crate::session::{ChannelNotification, EndTransactionAction, Session};
 use crate::statement_logging::{StatementEndedExecutionReason, StatementLifecycleEvent};
 use crate::util::{ClientTransmitter, ResultExt};
 use crate::webhook::{WebhookAppenderInvalidator, WebhookConcurrencyLimiter};
@@ -212,17 +213,22 @@ pub(crate) mod timeline;
 pub(crate) mod timestamp_selection;
 
 pub mod appends;
+mod auto_index;
 mod catalog_serving;
 mod caught_up;
 pub mod cluster_scheduling;
 mod command_handler;
 pub mod consistency;
 mod ddl;
+mod index_advisor;
 mod indexes;
 mod introspection;
 mod message_handler;
+mod notification;
+mod peek_dataflow_cache;
 mod privatelink_status;
 pub mod read_policy;
+mod replication_slot;
 mod sequencer;
 mod sql;
 mod validity;
@@ -266,6 +272,10 @@ pub enum Message {
     StorageUsageFetch,
     StorageUsageUpdate(ShardsUsageReferenced),
     StorageUsagePrune(Vec<BuiltinTableUpdate>),
+    IndexAdvisorRun,
+    IndexAdvisorUpdate(Vec<IndexSuggestion>),
+    PeekDataflowCacheExpire,
+    AutoIndexExpire,
     /// Performs any cleanup and logging actions necessary for
     /// finalizing a statement execution.
     RetireExecute {
@@ -352,6 +362,7 @@ impl Message {
                 Command::RetireExecute { .. } => "command-retire_execute",
                 Command::CheckConsistency { .. } => "command-check_consistency",
                 Command::Dump { .. } => "command-dump",
+                Command::ReplicationSlot { .. } => "command-replication_slot",
                 Command::AuthenticatePassword { .. } => "command-auth_check",
             },
             Message::ControllerReady => "controller_ready",
@@ -368,6 +379,10 @@ impl Message {
             Message::StorageUsageFetch => "storage_usage_fetch",
             Message::StorageUsageUpdate(_) => "storage_usage_update",
             Message::StorageUsagePrune(_) => "storage_usage_prune",
+            Message::IndexAdvisorRun => "index_advisor_run",
+            Message::IndexAdvisorUpdate(_) => "index_advisor_update",
+            Message::PeekDataflowCacheExpire => "peek_dataflow_cache_expire",
+            Message::AutoIndexExpire => "auto_index_expire",
             Message::RetireExecute { .. } => "retire_execute",
             Message::ExecuteSingleStatementTransaction { .. } => {
                 "execute_single_statement_transaction"
@@ -522,6 +537,8 @@ pub struct PeekStageOptimize {
     /// An optional context set iff the state machine is initiated from
     /// sequencing an EXPLAIN for this statement.
     explain_ctx: ExplainContext,
+    /// Read holds on the automatic indexes made available to the optimizer.
+    auto_index_holds: read_policy::ReadHolds<Timestamp>,
 }
 
 #[derive(Debug)]
@@ -541,6 +558,11 @@ pub struct PeekStageFinish {
     insights_ctx: Option<Box<PlanInsightsContext>>,
     global_lir_plan: optimize::peek::GlobalLirPlan,
     optimization_finished_at: EpochMillis,
+    /// Read holds on the automatic indexes made available to the optimizer.
+    auto_index_holds: read_policy::ReadHolds<Timestamp>,
+    /// The query shapes to count towards automatic indexes, should the peek
+    /// take the slow path.
+    auto_index_shapes: Vec<QueryShape>,
 }
 
 #[derive(Debug)]
@@ -799,6 +821,8 @@ pub struct CreateMaterializedViewExplain {
     global_id: GlobalId,
     validity: PlanValidity,
     plan: plan::CreateMaterializedViewPlan,
+    /// The freshly optimized physical plan, if optimization succeeded.
+    df_desc: Option<DataflowDescription<Plan>>,
     df_meta: DataflowMetainfo,
     explain_ctx: ExplainPlanContext,
 }
@@ -1076,6 +1100,13 @@ pub struct ConnMeta {
     #[serde(skip)]
     notice_tx: mpsc::UnboundedSender<AdapterNotice>,
 
+    /// Channel on which to send asynchronous notifications to a session.
+    #[serde(skip)]
+    notification_tx: mpsc::UnboundedSender<ChannelNotification>,
+
+    /// The notification channels the session is listening on.
+    listen_channels: BTreeSet<String>,
+
     /// The role that initiated the database context. Fixed for the duration of the connection.
     /// WARNING: This role reference is not updated when the role is dropped.
     /// Consumers should not assume that this role exist.
@@ -1272,33 +1303,57 @@ impl PendingRead {
 #[must_use]
 pub struct ExecuteContextExtra {
     statement_uuid: Option<StatementLoggingId>,
+    /// The fingerprint of the executed statement, if known.
+    fingerprint: Option<StatementFingerprint>,
 }
 
 impl ExecuteContextExtra {
     pub(crate) fn new(statement_uuid: Option<StatementLoggingId>) -> Self {
-        Self { statement_uuid }
+        Self {
+            statement_uuid,
+            fingerprint: None,
+        }
+    }
+    pub(crate) fn with_fingerprint(mut self, fingerprint: StatementFingerprint) -> Self {
+        self.fingerprint = Some(fingerprint);
+        self
     }
     pub fn is_trivial(&self) -> bool {
-        let Self { statement_uuid } = self;
+        let Self {
+            statement_uuid,
+            fingerprint: _,
+        } = self;
         statement_uuid.is_none()
     }
     pub fn contents(&self) -> Option<StatementLoggingId> {
-        let Self { statement_uuid } = self;
+        let Self {
+            statement_uuid,
+            fingerprint: _,
+        } = self;
         *statement_uuid
     }
+    pub(crate) fn fingerprint(&self) -> Option<StatementFingerprint> {
+        self.fingerprint
+    }
     /// Take responsibility for the contents.  This should only be
     /// called from code that knows what to do to finish up logging
     /// based on the inner value.
     #[must_use]
     fn retire(mut self) -> Option<StatementLoggingId> {
-        let Self { statement_uuid } = &mut self;
+        let Self {
+            statement_uuid,
+            fingerprint: _,
+        } = &mut self;
         statement_uuid.take()
     }
 }
 
 impl Drop for ExecuteContextExtra {
     fn drop(&mut self) {
-        let Self { statement_uuid } = &*self;
+        let Self {
+            statement_uuid,
+            fingerprint: _,
+        } = &*self;
         if let Some(statement_uuid) = statement_uuid {
             // Note: the impact when this error hits
             // is that the statement will never be marked
@@ -1658,12 +1713,24 @@ pub struct Coordinator {
     /// Upon completing a transaction, these read holds should be dropped.
     txn_read_holds: BTreeMap<ConnectionId, read_policy::ReadHolds<Timestamp>>,
 
+    /// Read holds that keep the collections a replication slot streams from
+    /// readable at the slot's confirmed position, keyed by slot name.
+    replication_slot_read_holds: BTreeMap<String, read_policy::ReadHolds<Timestamp>>,
+    /// The connection that is streaming from each active replication slot,
+    /// keyed by slot name.
+    active_replication_slots: BTreeMap<String, ConnectionId>,
+
     /// Access to the peek fields should be restricted to methods in the [`peek`] API.
     /// A map from pending peek ids to the queue into which responses are sent, and
     /// the connection id of the client that initiated the peek.
     pending_peeks: BTreeMap<Uuid, PendingPeek>,
     /// A map from client connection ids to a set of all pending peeks for that client.
     client_pending_peeks: BTreeMap<ConnectionId, BTreeMap<Uuid, ClusterId>>,
+    /// The dataflows kept around after slow-path peeks to serve later peeks of
+    /// the same plan.
+    peek_dataflow_cache: PeekDataflowCache,
+    /// The transient indexes installed for hot ad-hoc query shapes.
+    auto_indexes: AutoIndexes,
 
     /// A map from client connection ids to pending linearize read transaction.
     pending_linearize_read_txns: BTreeMap<ConnectionId, PendingReadTxn>,
@@ -1681,6 +1748,8 @@ pub struct Coordinator {
     staged_cancellation: BTreeMap<ConnectionId, (watch::Sender<bool>, watch::Receiver<bool>)>,
     /// Active introspection subscribes.
     introspection_subscribes: BTreeMap<GlobalId, IntrospectionSubscribe>,
+    /// Active notification subscribes, keyed by the ID of their compute sink.
+    active_notifications: BTreeMap<GlobalId, ActiveNotification>,
 
     /// Locks that grant access to a specific object, populated lazily as objects are written to.
     write_locks: BTreeMap<CatalogItemId, Arc<tokio::sync::Mutex<()>>>,
@@ -1733,6 +1802,10 @@ pub struct Coordinator {
     /// The interval at which to collect storage usage information.
     storage_usage_collection_interval: Duration,
 
+    /// The notices and `mz_index_suggestions` rows written by the most recent
+    /// index advisor run, retracted when the next run completes.
+    index_advisor_suggestions: Vec<(Arc<OptimizerNotice>, Row)>,
+
     /// Segment analytics client.
     #[derivative(Debug = "ignore")]
     segment_client: Option<mz_segment::Client>,
@@ -1751,10 +1824,10 @@ pub struct Coordinator {
     /// Limit for how many concurrent webhook requests we allow.
     webhook_concurrency_limit: WebhookConcurrencyLimiter,
 
-    /// Optional config for the Postgres-backed timestamp oracle. This is
-    /// _required_ when `postgres` is configured using the `timestamp_oracle`
-    /// system variable.
-    pg_timestamp_oracle_config: Option<PostgresTimestampOracleConfig>,
+    /// Optional config for the durable timestamp oracle. This is _required_
+    /// when `postgres` is configured using the `timestamp_oracle` system
+    /// variable.
+    timestamp_oracle_config: Option<TimestampOracleConfig>,
 
     /// Periodically asks cluster scheduling policies to make their decisions.
     check_cluster_scheduling_policies_interval: Interval,
@@ -1891,6 +1964,7 @@ impl Coordinator {
                 ClusterConfig {
                     arranged_logs: instance.log_indexes.clone(),
                     workload_class: instance.config.workload_class.clone(),
+                    arrangement_spill: instance.config.arrangement_spill,
                 },
             )?;
             for replica in instance.replicas() {
@@ -1914,6 +1988,7 @@ impl Coordinator {
         info!("startup: coordinator init: bootstrap: storage collections init beginning");
         self.bootstrap_storage_collections(&migrated_storage_collections_0dt)
             .await;
+        self.bootstrap_replication_slots();
         info!(
             "startup: coordinator init: bootstrap: storage collections init complete in {:?}",
             init_storage_collections_start.elapsed()
@@ -2148,7 +2223,10 @@ impl Coordinator {
                 CatalogItem::Log(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
-                | CatalogItem::Secret(_) => {}
+                | CatalogItem::UserFunc(_)
+                | CatalogItem::Secret(_)
+                | CatalogItem::Publication(_)
+                | CatalogItem::Notification(_) => {}
             }
         }
 
@@ -2397,6 +2475,9 @@ impl Coordinator {
         // Initialize unified introspection.
         self.bootstrap_introspection_subscribes().await;
 
+        // Install the subscribes that back notifications.
+        self.bootstrap_notifications().await;
+
         info!(
             "startup: coordinator init: bootstrap: migrate builtin tables in read-only mode complete in {:?}",
             final_steps_start.elapsed()
@@ -2985,6 +3066,7 @@ impl Coordinator {
                                         entry.name().clone(),
                                         idx.on,
                                         idx.keys.to_vec(),
+                                        idx.kind,
                                     );
                                     let global_mir_plan = optimizer.optimize(index_plan)?;
                                     let optimized_plan = global_mir_plan.df_desc().clone();
@@ -3213,7 +3295,10 @@ impl Coordinator {
                 | CatalogItem::Sink(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
+                | CatalogItem::UserFunc(_)
                 | CatalogItem::Secret(_)
+                | CatalogItem::Publication(_)
+                | CatalogItem::Notification(_)
                 | CatalogItem::Connection(_) => continue,
             };
             if let Some(plan) = self.catalog.try_get_physical_plan(&gid) {
@@ -3320,6 +3405,9 @@ impl Coordinator {
             });
 
             self.schedule_storage_usage_collection().await;
+            self.schedule_index_advisor();
+            self.schedule_peek_dataflow_cache_expiration();
+            self.schedule_auto_index_expiration();
             self.spawn_privatelink_vpc_endpoints_watch_task();
             self.spawn_statement_logging_task();
             flags::tracing_config(self.catalog.system_config()).apply(&self.tracing_handle);
@@ -3995,10 +4083,11 @@ pub fn serve(
         let oracle_init_start = Instant::now();
         info!("startup: coordinator init: timestamp oracle init beginning");
 
-        let pg_timestamp_oracle_config = timestamp_oracle_url
-            .map(|pg_url| PostgresTimestampOracleConfig::new(&pg_url, &metrics_registry));
+        let timestamp_oracle_config = timestamp_oracle_url
+            .map(|url| TimestampOracleConfig::from_url(&url, &metrics_registry))
+            .transpose()?;
         let mut initial_timestamps =
-            get_initial_oracle_timestamps(&pg_timestamp_oracle_config).await?;
+            get_initial_oracle_timestamps(&timestamp_oracle_config).await?;
 
         // Insert an entry for the `EpochMilliseconds` timeline if one doesn't exist,
         // which will ensure that the timeline is initialized since it's required
@@ -4012,7 +4101,7 @@ pub fn serve(
                 &timeline,
                 initial_timestamp,
                 now.clone(),
-                pg_timestamp_oracle_config.clone(),
+                timestamp_oracle_config.clone(),
                 &mut timestamp_oracles,
                 read_only_controllers,
             )
@@ -4171,7 +4260,7 @@ pub fn serve(
                 exclude_collections: new_builtin_collections.into_iter().collect(),
             });
 
-        if let Some(config) = pg_timestamp_oracle_config.as_ref() {
+        if let Some(TimestampOracleConfig::Postgres(config)) = timestamp_oracle_config.as_ref() {
             // Apply settings from system vars as early as possible because some
             // of them are locked in right when an oracle is first opened!
             let pg_timestamp_oracle_params =
@@ -4257,8 +4346,12 @@ pub fn serve(
                     transient_id_gen: Arc::new(TransientIdGen::new()),
                     active_conns: BTreeMap::new(),
                     txn_read_holds: Default::default(),
+                    replication_slot_read_holds: Default::default(),
+                    active_replication_slots: Default::default(),
                     pending_peeks: BTreeMap::new(),
                     client_pending_peeks: BTreeMap::new(),
+                    peek_dataflow_cache: PeekDataflowCache::default(),
+                    auto_indexes: AutoIndexes::default(),
                     pending_linearize_read_txns: BTreeMap::new(),
                     serialized_ddl: LockedVecDeque::new(),
                     active_compute_sinks: BTreeMap::new(),
@@ -4266,6 +4359,7 @@ pub fn serve(
                     active_copies: BTreeMap::new(),
                     staged_cancellation: BTreeMap::new(),
                     introspection_subscribes: BTreeMap::new(),
+                    active_notifications: BTreeMap::new(),
                     write_locks: BTreeMap::new(),
                     deferred_write_ops: BTreeMap::new(),
                     pending_writes: Vec::new(),
@@ -4276,13 +4370,14 @@ pub fn serve(
                     transient_replica_metadata: BTreeMap::new(),
                     storage_usage_client,
                     storage_usage_collection_interval,
+                    index_advisor_suggestions: Vec::new(),
                     segment_client,
                     metrics,
                     optimizer_metrics,
                     tracing_handle,
                     statement_logging: StatementLogging::new(coord_now.clone()),
                     webhook_concurrency_limit,
-                    pg_timestamp_oracle_config,
+                    timestamp_oracle_config,
                     check_cluster_scheduling_policies_interval: check_scheduling_policies_interval,
                     cluster_scheduling_decisions: BTreeMap::new(),
                     caught_up_check_interval: clusters_caught_up_check_interval,
@@ -4385,27 +4480,25 @@ pub fn serve(
 // window (which is the only point where we should switch oracle
 // implementations).
 async fn get_initial_oracle_timestamps(
-    pg_timestamp_oracle_config: &Option<PostgresTimestampOracleConfig>,
+    timestamp_oracle_config: &Option<TimestampOracleConfig>,
 ) -> Result<BTreeMap<Timeline, Timestamp>, AdapterError> {
     let mut initial_timestamps = BTreeMap::new();
 
-    if let Some(pg_timestamp_oracle_config) = pg_timestamp_oracle_config {
-        let postgres_oracle_timestamps =
-            PostgresTimestampOracle::<NowFn>::get_all_timelines(pg_timestamp_oracle_config.clone())
-                .await?;
+    if let Some(timestamp_oracle_config) = timestamp_oracle_config {
+        let oracle_timestamps = timestamp_oracle_config.clone().get_all_timelines().await?;
 
         let debug_msg = || {
-            postgres_oracle_timestamps
+            oracle_timestamps
                 .iter()
                 .map(|(timeline, ts)| format!("{:?} -> {}", timeline, ts))
                 .join(", ")
         };
         info!(
-            "current timestamps from the postgres-backed timestamp oracle: {}",
+            "current timestamps from the durable timestamp oracle: {}",
             debug_msg()
         );
 
-        for (timeline, ts) in postgres_oracle_timestamps {
+        for (timeline, ts) in oracle_timestamps {
             let entry = initial_timestamps
                 .entry(Timeline::from_str(&timeline).expect("could not parse timeline"));
 
@@ -4414,7 +4507,7 @@ async fn get_initial_oracle_timestamps(
                 .or_insert(ts);
         }
     } else {
-        info!("no postgres url for postgres-backed timestamp oracle configured!");
+        info!("no url for durable timestamp oracle configured!");
     };
 
     let debug_msg = || {
@@ -958,6 +958,9 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::CreateSource(_)
         | Plan::CreateSources(_)
         | Plan::CreateSecret(_)
+        | Plan::CreateFunction(_)
+        | Plan::CreateNotification(_)
+        | Plan::CreatePublication(_)
         | Plan::CreateSink(_)
         | Plan::CreateTable(_)
         | Plan::CreateView(_)
@@ -1007,9 +1010,12 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::AlterRole(_)
         | Plan::AlterOwner(_)
         | Plan::AlterTableAddColumn(_)
+        | Plan::AlterTypeAddValue(_)
         | Plan::Declare(_)
         | Plan::Fetch(_)
         | Plan::Close(_)
+        | Plan::Listen(_)
+        | Plan::Unlisten(_)
         | Plan::Prepare(_)
         | Plan::Execute(_)
         | Plan::Deallocate(_)
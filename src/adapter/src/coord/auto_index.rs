@@ -0,0 +1,503 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Automatic promotion of hot ad-hoc queries to transient indexes.
+//!
+//! On clusters with the `AUTO INDEX` option, the coordinator counts the
+//! slow-path peeks of each statement by its statement-log fingerprint (see
+//! [`StatementFingerprint`]), so the count is shared by all sessions that
+//! execute the same SQL. Once a statement has been executed
+//! `auto_index_min_executions` times within `auto_index_window`, the
+//! coordinator installs transient indexes for its query shapes. A shape is a
+//! user collection together with the columns that the statement constrains to
+//! literals, which is what an index would need to serve the statement as a
+//! fast-path lookup (see [`literal_constraint_keys`]). The index is not part of
+//! the catalog, but the peek optimizer plans against it as if it were, so later
+//! peeks of the same shape become [`FastPathPlan::PeekExisting`] lookups.
+//! Indexes that have not served a peek for `auto_index_idle_timeout` are
+//! dropped again.
+//!
+//! [`FastPathPlan::PeekExisting`]: crate::coord::peek::FastPathPlan::PeekExisting
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use mz_adapter_types::dyncfgs::{
+    AUTO_INDEX_IDLE_TIMEOUT, AUTO_INDEX_MIN_EXECUTIONS, AUTO_INDEX_WINDOW,
+};
+use mz_catalog::memory::objects::Index;
+use mz_compute_types::ComputeInstanceId;
+use mz_controller_types::ClusterId;
+use mz_expr::{MirRelationExpr, MirScalarExpr};
+use mz_ore::task;
+use mz_repr::optimize::OverrideFrom;
+use mz_repr::{GlobalId, Timestamp};
+use mz_sql::names::ResolvedIds;
+use mz_sql::plan::IndexKind;
+use tracing::{debug, warn};
+
+use crate::coord::index_advisor::literal_constraint_keys;
+use crate::coord::read_policy::ReadHolds;
+use crate::coord::statement_logging::StatementFingerprint;
+use crate::coord::{Coordinator, Message};
+use crate::optimize::dataflows::dataflow_import_id_bundle;
+use crate::optimize::{self, Optimize, OptimizerConfig};
+
+/// The shape of a peek that an index could serve: the indexed collection and
+/// the cluster, together with the key that the peek constrains to literals.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct QueryShape {
+    cluster_id: ClusterId,
+    on_id: GlobalId,
+    key: Vec<MirScalarExpr>,
+}
+
+/// An index installed for a hot query shape.
+#[derive(Debug, Clone)]
+struct AutoIndex {
+    /// The transient id of the index.
+    index_id: GlobalId,
+    /// The last time the index served a peek.
+    last_used: Instant,
+}
+
+/// The recent slow-path executions of a statement.
+#[derive(Debug, Default)]
+struct Executions {
+    /// The collections that the statement's unindexed query shapes are on.
+    on_ids: BTreeSet<GlobalId>,
+    /// The times of the executions.
+    times: VecDeque<Instant>,
+}
+
+/// The state of automatic index promotion.
+#[derive(Debug, Default)]
+pub(crate) struct AutoIndexes {
+    /// The recent slow-path executions of each statement on each cluster that
+    /// has query shapes without an index.
+    executions: BTreeMap<(ClusterId, StatementFingerprint), Executions>,
+    /// The installed indexes.
+    indexes: BTreeMap<QueryShape, AutoIndex>,
+}
+
+impl AutoIndexes {
+    /// Records a slow-path execution at `now` of the statement with
+    /// `fingerprint` on `cluster_id`, whose query shapes are `shapes`.
+    ///
+    /// Returns the shapes without an index if the statement has been executed
+    /// at least `min_executions` times within `window`, and they should now be
+    /// promoted to indexes.
+    fn observe(
+        &mut self,
+        cluster_id: ClusterId,
+        fingerprint: StatementFingerprint,
+        shapes: Vec<QueryShape>,
+        now: Instant,
+        window: Duration,
+        min_executions: usize,
+    ) -> Vec<QueryShape> {
+        let shapes: Vec<_> = shapes
+            .into_iter()
+            .filter(|shape| !self.indexes.contains_key(shape))
+            .collect();
+        let key = (cluster_id, fingerprint);
+        if shapes.is_empty() {
+            self.executions.remove(&key);
+            return shapes;
+        }
+        let executions = self.executions.entry(key).or_default();
+        executions
+            .on_ids
+            .extend(shapes.iter().map(|shape| shape.on_id));
+        executions.times.push_back(now);
+        while executions
+            .times
+            .front()
+            .is_some_and(|t| now.saturating_duration_since(*t) > window)
+        {
+            executions.times.pop_front();
+        }
+        if executions.times.len() >= min_executions {
+            self.executions.remove(&key);
+            shapes
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Records the index installed for `shape`.
+    fn insert(&mut self, shape: QueryShape, index_id: GlobalId, now: Instant) {
+        let index = AutoIndex {
+            index_id,
+            last_used: now,
+        };
+        self.indexes.insert(shape, index);
+    }
+
+    /// Marks the index `index_id` as used at `now`. Returns whether the index
+    /// is an installed automatic index.
+    pub(crate) fn touch(&mut self, index_id: GlobalId, now: Instant) -> bool {
+        match self.indexes.values_mut().find(|i| i.index_id == index_id) {
+            Some(index) => {
+                index.last_used = now;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns whether `index_id` is an installed automatic index.
+    pub(crate) fn contains_index(&self, index_id: GlobalId) -> bool {
+        self.indexes.values().any(|i| i.index_id == index_id)
+    }
+
+    /// Removes the indexes that have not been used since `idle_timeout` before
+    /// `now`, along with the execution counts that have fallen out of
+    /// `window`.
+    ///
+    /// Returns the removed indexes, which the caller must drop.
+    fn expire(
+        &mut self,
+        now: Instant,
+        idle_timeout: Duration,
+        window: Duration,
+    ) -> Vec<(ClusterId, GlobalId)> {
+        self.executions.retain(|_, executions| {
+            executions
+                .times
+                .back()
+                .is_some_and(|t| now.saturating_duration_since(*t) <= window)
+        });
+        self.remove_where(|_, index| now.saturating_duration_since(index.last_used) >= idle_timeout)
+    }
+
+    /// Removes the indexes on the given collections or on the given clusters,
+    /// along with the execution counts of the statements that read them.
+    ///
+    /// Returns the removed indexes, which the caller must drop.
+    pub(crate) fn remove_dependents(
+        &mut self,
+        collections: &BTreeSet<GlobalId>,
+        clusters: &[ClusterId],
+    ) -> Vec<(ClusterId, GlobalId)> {
+        self.executions.retain(|(cluster_id, _), executions| {
+            executions.on_ids.is_disjoint(collections) && !clusters.contains(cluster_id)
+        });
+        self.remove_where(|shape, _| {
+            collections.contains(&shape.on_id) || clusters.contains(&shape.cluster_id)
+        })
+    }
+
+    fn remove_where<F>(&mut self, mut f: F) -> Vec<(ClusterId, GlobalId)>
+    where
+        F: FnMut(&QueryShape, &AutoIndex) -> bool,
+    {
+        let mut removed = Vec::new();
+        self.indexes.retain(|shape, index| {
+            let remove = f(shape, index);
+            if remove {
+                removed.push((shape.cluster_id, index.index_id));
+            }
+            !remove
+        });
+        removed
+    }
+}
+
+/// Returns the query shapes of a slow-path peek of `expr` on `cluster_id`.
+///
+/// For every collection, this is only the shape with the most constrained
+/// columns, which is the one an index would serve best.
+pub(crate) fn query_shapes(cluster_id: ClusterId, expr: &MirRelationExpr) -> Vec<QueryShape> {
+    let mut best: BTreeMap<GlobalId, Vec<MirScalarExpr>> = BTreeMap::new();
+    for (on_id, key) in literal_constraint_keys(expr) {
+        let entry = best.entry(on_id).or_default();
+        if key.len() > entry.len() {
+            *entry = key;
+        }
+    }
+    best.into_iter()
+        .map(|(on_id, key)| QueryShape {
+            cluster_id,
+            on_id,
+            key,
+        })
+        .collect()
+}
+
+impl Coordinator {
+    /// Returns whether `cluster_id` has the `AUTO INDEX` option.
+    pub(crate) fn auto_index_enabled(&self, cluster_id: ClusterId) -> bool {
+        self.catalog()
+            .try_get_cluster(cluster_id)
+            .is_some_and(|cluster| cluster.config.auto_index)
+    }
+
+    /// Records a slow-path execution of the statement with `fingerprint` on
+    /// `cluster_id`, and installs indexes for its `shapes` if the statement
+    /// became hot.
+    pub(crate) async fn auto_index_observe(
+        &mut self,
+        cluster_id: ClusterId,
+        fingerprint: StatementFingerprint,
+        shapes: Vec<QueryShape>,
+    ) {
+        let dyncfgs = self.catalog().system_config().dyncfgs();
+        let window = AUTO_INDEX_WINDOW.get(dyncfgs);
+        let min_executions = AUTO_INDEX_MIN_EXECUTIONS.get(dyncfgs);
+        let hot = self.auto_indexes.observe(
+            cluster_id,
+            fingerprint,
+            shapes,
+            Instant::now(),
+            window,
+            min_executions,
+        );
+        for shape in hot {
+            self.install_auto_index(shape).await;
+        }
+    }
+
+    /// Installs a transient index for `shape`.
+    async fn install_auto_index(&mut self, shape: QueryShape) {
+        let Ok(compute_instance) = self.instance_snapshot(shape.cluster_id) else {
+            return;
+        };
+        let Some(on_entry) = self.catalog().try_get_entry_by_global_id(&shape.on_id) else {
+            return;
+        };
+        let name = on_entry.name().clone();
+        let (_, index_id) = self.allocate_transient_id();
+
+        let optimizer_config = OptimizerConfig::from(self.catalog().system_config())
+            .override_from(&self.catalog.get_cluster(shape.cluster_id).config.features());
+        let mut optimizer = optimize::index::Optimizer::new(
+            self.owned_catalog(),
+            compute_instance,
+            index_id,
+            optimizer_config,
+            self.optimizer_metrics(),
+        );
+        let index = optimize::index::Index::new(
+            name,
+            shape.on_id,
+            shape.key.clone(),
+            IndexKind::Arrangement,
+        );
+        let global_lir_plan = match optimizer
+            .catch_unwind_optimize(index)
+            .and_then(|plan| optimizer.catch_unwind_optimize(plan))
+        {
+            Ok(plan) => plan,
+            Err(err) => {
+                warn!(?shape, "failed to optimize automatic index: {err}");
+                return;
+            }
+        };
+        let (mut df_desc, _df_meta) = global_lir_plan.unapply();
+
+        // Hold back the inputs while we ship the dataflow, as when creating
+        // regular indexes.
+        let id_bundle = dataflow_import_id_bundle(&df_desc, shape.cluster_id);
+        let read_holds = self.acquire_read_holds(&id_bundle);
+        df_desc.set_as_of(read_holds.least_valid_read());
+        self.ship_dataflow(df_desc, shape.cluster_id, None).await;
+        drop(read_holds);
+
+        debug!(?shape, %index_id, "installed automatic index");
+        self.auto_indexes.insert(shape, index_id, Instant::now());
+    }
+
+    /// Returns the automatic indexes on `cluster_id` that can serve a peek of
+    /// `source_ids` at `timestamp` right away, along with read holds that keep
+    /// them readable at `timestamp` until the peek is issued.
+    pub(crate) fn auto_indexes_for_peek(
+        &self,
+        cluster_id: ClusterId,
+        source_ids: &BTreeSet<GlobalId>,
+        timestamp: Timestamp,
+    ) -> (Vec<Index>, ReadHolds<Timestamp>) {
+        let mut indexes = Vec::new();
+        let mut read_holds = ReadHolds::new();
+        for (shape, index) in &self.auto_indexes.indexes {
+            if shape.cluster_id != cluster_id || !source_ids.contains(&shape.on_id) {
+                continue;
+            }
+            // Indexes that are still hydrating would delay the peek.
+            let Ok(frontiers) = self
+                .controller
+                .compute
+                .collection_frontiers(index.index_id, Some(cluster_id))
+            else {
+                continue;
+            };
+            if frontiers.write_frontier.less_equal(&timestamp) {
+                continue;
+            }
+            let Ok(read_hold) = self
+                .controller
+                .compute
+                .acquire_read_hold(cluster_id, index.index_id)
+            else {
+                continue;
+            };
+            if !read_hold.since().less_equal(&timestamp) {
+                continue;
+            }
+            read_holds
+                .compute_holds
+                .insert((cluster_id, index.index_id), read_hold);
+            indexes.push(Index {
+                create_sql: String::new(),
+                global_id: index.index_id,
+                on: shape.on_id,
+                keys: Arc::from(shape.key.clone()),
+                kind: IndexKind::Arrangement,
+                conn_id: None,
+                resolved_ids: ResolvedIds::empty(),
+                cluster_id,
+                custom_logical_compaction_window: None,
+                is_retained_metrics_object: false,
+            });
+        }
+        (indexes, read_holds)
+    }
+
+    /// Schedules the next expiration of idle automatic indexes.
+    pub(crate) fn schedule_auto_index_expiration(&self) {
+        let idle_timeout = AUTO_INDEX_IDLE_TIMEOUT.get(self.catalog().system_config().dyncfgs());
+        let interval = idle_timeout.max(Duration::from_secs(1));
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "auto_index_expiration", async move {
+            tokio::time::sleep(interval).await;
+            if internal_cmd_tx.send(Message::AutoIndexExpire).is_err() {
+                // If sending fails, the main thread has shutdown.
+            }
+        });
+    }
+
+    /// Drops the automatic indexes that have been idle for too long, or that
+    /// are on clusters that no longer have the `AUTO INDEX` option.
+    pub(crate) fn auto_index_expire(&mut self) {
+        let dyncfgs = self.catalog().system_config().dyncfgs();
+        let idle_timeout = AUTO_INDEX_IDLE_TIMEOUT.get(dyncfgs);
+        let window = AUTO_INDEX_WINDOW.get(dyncfgs);
+        let disabled: Vec<_> = self
+            .catalog()
+            .clusters()
+            .filter(|cluster| !cluster.config.auto_index)
+            .map(|cluster| cluster.id)
+            .collect();
+        let mut expired = self
+            .auto_indexes
+            .expire(Instant::now(), idle_timeout, window);
+        expired.extend(
+            self.auto_indexes
+                .remove_dependents(&BTreeSet::new(), &disabled),
+        );
+        self.drop_auto_indexes(expired);
+        self.schedule_auto_index_expiration();
+    }
+
+    /// Drops indexes that were removed from the automatic indexes.
+    pub(crate) fn drop_auto_indexes(&mut self, indexes: Vec<(ComputeInstanceId, GlobalId)>) {
+        if indexes.is_empty() {
+            return;
+        }
+        debug!(?indexes, "dropping automatic indexes");
+        self.drop_indexes(indexes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_expr::BinaryFunc;
+    use mz_repr::{Datum, RelationType, ScalarType};
+
+    use super::*;
+
+    fn shape(on_id: u64) -> QueryShape {
+        QueryShape {
+            cluster_id: ClusterId::User(1),
+            on_id: GlobalId::User(on_id),
+            key: vec![MirScalarExpr::column(0)],
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_promotion() {
+        let start = Instant::now();
+        let later = |secs| start + Duration::from_secs(secs);
+        let window = Duration::from_secs(10);
+        let cluster_id = ClusterId::User(1);
+        let mut auto_indexes = AutoIndexes::default();
+        let observe = |auto_indexes: &mut AutoIndexes, fingerprint: u8, on_ids: &[u64], t| {
+            let shapes = on_ids.iter().map(|id| shape(*id)).collect();
+            auto_indexes.observe(cluster_id, [fingerprint; 32], shapes, later(t), window, 3)
+        };
+
+        // Executions that fall out of the window don't count.
+        assert!(observe(&mut auto_indexes, 1, &[1], 0).is_empty());
+        assert!(observe(&mut auto_indexes, 1, &[1], 5).is_empty());
+        assert!(observe(&mut auto_indexes, 1, &[1], 15).is_empty());
+        // Executions of other statements with the same shape don't count.
+        assert!(observe(&mut auto_indexes, 2, &[1], 15).is_empty());
+        assert_eq!(observe(&mut auto_indexes, 1, &[1], 16), vec![shape(1)]);
+
+        // Shapes with an index are not promoted again, and statements whose
+        // shapes are all indexed are not counted.
+        auto_indexes.insert(shape(1), GlobalId::Transient(1), later(16));
+        for t in 17..20 {
+            assert!(observe(&mut auto_indexes, 2, &[1], t).is_empty());
+        }
+        assert!(!auto_indexes.executions.contains_key(&(cluster_id, [2; 32])));
+        assert!(observe(&mut auto_indexes, 3, &[1, 2], 17).is_empty());
+        assert!(observe(&mut auto_indexes, 3, &[1, 2], 18).is_empty());
+        assert_eq!(observe(&mut auto_indexes, 3, &[1, 2], 19), vec![shape(2)]);
+        assert!(auto_indexes.touch(GlobalId::Transient(1), later(20)));
+        assert!(!auto_indexes.touch(GlobalId::Transient(2), later(20)));
+
+        // Idle indexes expire.
+        auto_indexes.insert(shape(2), GlobalId::Transient(2), later(10));
+        let expired = auto_indexes.expire(later(30), Duration::from_secs(15), window);
+        assert_eq!(expired, vec![(ClusterId::User(1), GlobalId::Transient(2))]);
+        assert!(auto_indexes.executions.is_empty());
+
+        let removed = auto_indexes.remove_dependents(&BTreeSet::from([GlobalId::User(1)]), &[]);
+        assert_eq!(removed, vec![(ClusterId::User(1), GlobalId::Transient(1))]);
+        assert!(!auto_indexes.contains_index(GlobalId::Transient(1)));
+    }
+
+    #[mz_ore::test]
+    fn test_query_shapes() {
+        let eq_literal = |column: usize| {
+            MirScalarExpr::column(column).call_binary(
+                MirScalarExpr::literal_ok(Datum::Int64(5), ScalarType::Int64),
+                BinaryFunc::Eq,
+            )
+        };
+        let typ = RelationType::new(vec![
+            ScalarType::Int64.nullable(false),
+            ScalarType::Int64.nullable(false),
+        ]);
+        let expr = MirRelationExpr::global_get(GlobalId::User(1), typ)
+            .filter([eq_literal(1), eq_literal(0)]);
+
+        let shapes = query_shapes(ClusterId::User(1), &expr);
+        assert_eq!(
+            shapes,
+            vec![QueryShape {
+                cluster_id: ClusterId::User(1),
+                on_id: GlobalId::User(1),
+                key: vec![MirScalarExpr::column(0), MirScalarExpr::column(1)],
+            }]
+        );
+    }
+}
@@ -79,6 +79,9 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::CreateSource(_)
         | Plan::CreateSources(_)
         | Plan::CreateSecret(_)
+        | Plan::CreateFunction(_)
+        | Plan::CreateNotification(_)
+        | Plan::CreatePublication(_)
         | Plan::CreateSink(_)
         | Plan::CreateTable(_)
         | Plan::CreateView(_)
@@ -128,9 +131,12 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::AlterRole(_)
         | Plan::AlterOwner(_)
         | Plan::AlterTableAddColumn(_)
+        | Plan::AlterTypeAddValue(_)
         | Plan::Declare(_)
         | Plan::Fetch(_)
         | Plan::Close(_)
+        | Plan::Listen(_)
+        | Plan::Unlisten(_)
         | Plan::ReadThenWrite(_)
         | Plan::Prepare(_)
         | Plan::Execute(_)
@@ -67,7 +67,7 @@ use crate::coord::{
 };
 use crate::error::AdapterError;
 use crate::notice::AdapterNotice;
-use crate::session::{Session, TransactionOps, TransactionStatus};
+use crate::session::{ChannelNotification, Session, TransactionOps, TransactionStatus};
 use crate::util::{ClientTransmitter, ResultExt};
 use crate::webhook::{
     AppendWebhookResponse, AppendWebhookValidator, WebhookAppender, WebhookAppenderInvalidator,
@@ -95,6 +95,7 @@ impl Coordinator {
                     client_ip,
                     application_name,
                     notice_tx,
+                    notification_tx,
                 } => {
                     // Note: We purposefully do not use a ClientTransmitter here because startup
                     // handles errors and cleanup of sessions itself.
@@ -107,6 +108,7 @@ impl Coordinator {
                         client_ip,
                         application_name,
                         notice_tx,
+                        notification_tx,
                     )
                     .await;
                 }
@@ -240,6 +242,17 @@ impl Coordinator {
                 Command::Dump { tx } => {
                     let _ = tx.send(self.dump().await);
                 }
+
+                Command::ReplicationSlot {
+                    conn_id,
+                    request,
+                    tx,
+                } => {
+                    let _ = tx.send(
+                        self.handle_replication_slot_request(&conn_id, request)
+                            .await,
+                    );
+                }
             }
         }
         .instrument(debug_span!("handle_command"))
@@ -296,6 +309,7 @@ impl Coordinator {
         client_ip: Option<IpAddr>,
         application_name: String,
         notice_tx: mpsc::UnboundedSender<AdapterNotice>,
+        notification_tx: mpsc::UnboundedSender<ChannelNotification>,
     ) {
         // Early return if successful, otherwise cleanup any possible state.
         match self.handle_startup_inner(&user, &conn_id, &client_ip).await {
@@ -308,6 +322,8 @@ impl Coordinator {
                 let conn = ConnMeta {
                     secret_key,
                     notice_tx,
+                    notification_tx,
+                    listen_channels: BTreeSet::new(),
                     drop_sinks: BTreeSet::new(),
                     pending_cluster_alters: BTreeSet::new(),
                     connected_at: self.now(),
@@ -560,8 +576,9 @@ impl Coordinator {
             } else {
                 // This is a new statement, log it and return the context
                 let maybe_uuid = self.begin_statement_execution(&mut session, &params, &logging);
+                let fingerprint = session.qcell_rw(&logging).fingerprint();
 
-                ExecuteContextExtra::new(maybe_uuid)
+                ExecuteContextExtra::new(maybe_uuid).with_fingerprint(fingerprint)
             };
             let ctx = ExecuteContext::from_parts(tx, self.internal_cmd_tx.clone(), session, extra);
             (stmt, ctx, params)
@@ -701,9 +718,11 @@ impl Coordinator {
                     | Statement::Execute(_)
                     | Statement::ExplainPlan(_)
                     | Statement::ExplainPushdown(_)
+                    | Statement::ExplainAnalyze(_)
                     | Statement::ExplainTimestamp(_)
                     | Statement::ExplainSinkSchema(_)
                     | Statement::Fetch(_)
+                    | Statement::Listen(_)
                     | Statement::Prepare(_)
                     | Statement::Rollback(_)
                     | Statement::Select(_)
@@ -713,6 +732,7 @@ impl Coordinator {
                     | Statement::ResetVariable(_)
                     | Statement::StartTransaction(_)
                     | Statement::Subscribe(_)
+                    | Statement::Unlisten(_)
                     | Statement::Raise(_) => {
                         // Always safe.
                     }
@@ -757,6 +777,7 @@ impl Coordinator {
                     | Statement::AlterSystemResetAll(_)
                     | Statement::AlterSystemSet(_)
                     | Statement::AlterTableAddColumn(_)
+                    | Statement::AlterTypeAddValue(_)
                     | Statement::AlterNetworkPolicy(_)
                     | Statement::CreateCluster(_)
                     | Statement::CreateClusterReplica(_)
@@ -765,6 +786,9 @@ impl Coordinator {
                     | Statement::CreateIndex(_)
                     | Statement::CreateMaterializedView(_)
                     | Statement::CreateContinualTask(_)
+                    | Statement::CreateFunction(_)
+                    | Statement::CreateNotification(_)
+                    | Statement::CreatePublication(_)
                     | Statement::CreateRole(_)
                     | Statement::CreateSchema(_)
                     | Statement::CreateSecret(_)
@@ -1352,6 +1376,7 @@ impl Coordinator {
         self.cancel_pending_peeks(conn.conn_id());
         self.cancel_pending_watchsets(&conn_id);
         self.cancel_pending_copy(&conn_id);
+        self.release_replication_slots(&conn_id);
         self.end_session_for_statement_logging(conn.uuid());
 
         // Queue the builtin table update, but do not wait for it to complete. We explicitly do
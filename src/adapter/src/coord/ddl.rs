@@ -52,6 +52,7 @@ use mz_storage_types::connections::inline::IntoInlineConnection;
 use mz_storage_types::read_policy::ReadPolicy;
 use mz_storage_types::sources::GenericSourceConnection;
 use mz_storage_types::sources::kafka::KAFKA_PROGRESS_DESC;
+use mz_timestamp_oracle::TimestampOracleConfig;
 use serde_json::json;
 use tracing::{Instrument, Level, event, info_span, warn};
 
@@ -200,6 +201,7 @@ impl Coordinator {
         let mut materialized_views_to_drop = vec![];
         let mut continual_tasks_to_drop = vec![];
         let mut views_to_drop = vec![];
+        let mut notifications_to_drop = vec![];
         let mut replication_slots_to_drop: Vec<(PostgresConnection, String)> = vec![];
         let mut secrets_to_drop = vec![];
         let mut vpc_endpoints_to_drop = vec![];
@@ -272,6 +274,9 @@ impl Coordinator {
                                             ct.global_id(),
                                         ));
                                     }
+                                    CatalogItem::Notification(_) => {
+                                        notifications_to_drop.push(*id);
+                                    }
                                     CatalogItem::Secret(_) => {
                                         secrets_to_drop.push(*id);
                                     }
@@ -457,6 +462,16 @@ impl Coordinator {
             }
         }
 
+        // Clean up any kept peek dataflows that rely on dropped relations or clusters.
+        let peek_dataflows_to_drop = self
+            .peek_dataflow_cache
+            .remove_dependents(&collections_to_drop, &clusters_to_drop);
+
+        // Clean up any automatic indexes on dropped relations or clusters.
+        let auto_indexes_to_drop = self
+            .auto_indexes
+            .remove_dependents(&collections_to_drop, &clusters_to_drop);
+
         // Clean up any pending `COPY` statements that rely on dropped relations or clusters.
         for (conn_id, pending_copy) in &self.active_copies {
             let dropping_table = table_gids_to_drop
@@ -646,6 +661,9 @@ impl Coordinator {
                     assert_eq!(should_be_empty, became_empty, "emptiness did not match!");
                 }
             }
+            // Release the holds of replication slots on dropped collections before dropping
+            // them.
+            self.prune_replication_slot_read_holds();
             if !table_gids_to_drop.is_empty() {
                 let ts = self.get_local_write_ts().await;
                 self.drop_tables(table_gids_to_drop, ts.timestamp);
@@ -689,6 +707,14 @@ impl Coordinator {
                     self.cancel_pending_copy(&conn_id);
                 }
             }
+            if !peek_dataflows_to_drop.is_empty() {
+                self.drop_peek_dataflows(peek_dataflows_to_drop);
+            }
+            self.drop_auto_indexes(auto_indexes_to_drop);
+            // Notification subscribes read from their materialized view, so drop them first.
+            for item_id in notifications_to_drop {
+                self.drop_notification(item_id);
+            }
             if !indexes_to_drop.is_empty() {
                 self.drop_indexes(indexes_to_drop);
             }
@@ -1248,7 +1274,8 @@ impl Coordinator {
 
     fn update_pg_timestamp_oracle_config(&self) {
         let config_params = flags::pg_timstamp_oracle_config(self.catalog().system_config());
-        if let Some(config) = self.pg_timestamp_oracle_config.as_ref() {
+        if let Some(TimestampOracleConfig::Postgres(config)) = self.timestamp_oracle_config.as_ref()
+        {
             config_params.apply(config)
         }
     }
@@ -1485,7 +1512,10 @@ impl Coordinator {
                         | CatalogItem::View(_)
                         | CatalogItem::Index(_)
                         | CatalogItem::Type(_)
-                        | CatalogItem::Func(_) => {}
+                        | CatalogItem::Func(_)
+                        | CatalogItem::UserFunc(_)
+                        | CatalogItem::Publication(_)
+                        | CatalogItem::Notification(_) => {}
                     }
                 }
                 Op::DropObjects(drop_object_infos) => {
@@ -1567,7 +1597,10 @@ impl Coordinator {
                                     | CatalogItem::View(_)
                                     | CatalogItem::Index(_)
                                     | CatalogItem::Type(_)
-                                    | CatalogItem::Func(_) => {}
+                                    | CatalogItem::Func(_)
+                                    | CatalogItem::UserFunc(_)
+                                    | CatalogItem::Publication(_)
+                                    | CatalogItem::Notification(_) => {}
                                 }
                             }
                         }
@@ -1593,17 +1626,21 @@ impl Coordinator {
                     | CatalogItem::Sink(_)
                     | CatalogItem::MaterializedView(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Publication(_)
+                    | CatalogItem::Notification(_)
                     | CatalogItem::Log(_)
                     | CatalogItem::View(_)
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
+                    | CatalogItem::UserFunc(_)
                     | CatalogItem::ContinualTask(_) => {}
                 },
                 Op::AlterRole { .. }
                 | Op::AlterRetainHistory { .. }
                 | Op::AlterNetworkPolicy { .. }
                 | Op::AlterAddColumn { .. }
+                | Op::AlterTypeAddValue { .. }
                 | Op::UpdatePrivilege { .. }
                 | Op::UpdateDefaultPrivilege { .. }
                 | Op::GrantRole { .. }
@@ -1616,6 +1653,9 @@ impl Coordinator {
                 | Op::UpdateClusterConfig { .. }
                 | Op::UpdateClusterReplicaConfig { .. }
                 | Op::UpdateSourceReferences { .. }
+                | Op::CreateReplicationSlot { .. }
+                | Op::UpdateReplicationSlot { .. }
+                | Op::DropReplicationSlot { .. }
                 | Op::UpdateSystemConfiguration { .. }
                 | Op::ResetSystemConfiguration { .. }
                 | Op::ResetAllSystemConfiguration { .. }
@@ -0,0 +1,838 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! The index advisor.
+//!
+//! Periodically replays the most frequent slow-path `SELECT`s of the last
+//! `index_advisor_window` from the statement log against hypothetical indexes
+//! and suggests the indexes that would have turned them into fast-path peeks.
+//! Suggestions are reported as [`IndexWouldEnableFastPath`] notices in
+//! `mz_internal.mz_notices`, and together with a cost estimate in
+//! `mz_internal.mz_index_suggestions`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::ControlFlow;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use mz_adapter_types::dyncfgs::{
+    INDEX_ADVISOR_INTERVAL, INDEX_ADVISOR_MAX_EXECUTIONS, INDEX_ADVISOR_MAX_STATEMENTS,
+    INDEX_ADVISOR_MAX_SUGGESTIONS, INDEX_ADVISOR_WINDOW,
+};
+use mz_catalog::builtin::notice::MZ_INDEX_SUGGESTIONS;
+use mz_catalog::builtin::{
+    BuiltinSource, MZ_PREPARED_STATEMENT_HISTORY, MZ_SQL_TEXT, MZ_STATEMENT_EXECUTION_HISTORY,
+};
+use mz_catalog::memory::objects::{CatalogItem, Index};
+use mz_controller_types::ClusterId;
+use mz_expr::{Id, MirRelationExpr, MirScalarExpr, VariadicFunc};
+use mz_ore::cast::{CastFrom, CastLossy};
+use mz_ore::collections::CollectionExt;
+use mz_ore::now::EpochMillis;
+use mz_ore::task;
+use mz_repr::global_id::TransientIdGen;
+use mz_repr::{Datum, Diff, GlobalId, RelationDesc, Row, Timestamp};
+use mz_sql::ast::display::AstDisplay;
+use mz_sql::ast::{
+    CreateIndexStatement, Expr, Ident, IndexMethod, Raw, RawClusterName, RawItemName,
+};
+use mz_sql::names::{FullItemName, ResolvedIds};
+use mz_sql::optimizer_metrics::OptimizerMetrics;
+use mz_sql::plan::{HirRelationExpr, IndexKind, Params, Plan, SelectPlan};
+use mz_sql::session::metadata::SessionMetadata;
+use mz_sql::session::vars::VarInput;
+use mz_sql_parser::ast::{StatementKind, statement_kind_label_value};
+use mz_storage_client::storage_collections::StorageCollections;
+use mz_transform::EmptyStatisticsOracle;
+use mz_transform::dataflow::DataflowMetainfo;
+use mz_transform::notice::{IndexWouldEnableFastPath, OptimizerNotice, RawOptimizerNotice};
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::TimestampContext;
+use crate::catalog::{BuiltinTableUpdate, Catalog};
+use crate::coord::peek::{FastPathPlan, PeekPlan};
+use crate::coord::{Coordinator, Message};
+use crate::optimize::dataflows::ComputeInstanceSnapshot;
+use crate::optimize::{self, Optimize, OptimizerConfig};
+use crate::session::Session;
+use crate::statement_logging::StatementExecutionStrategy;
+
+/// An index that the index advisor suggests creating.
+#[derive(Debug, Clone)]
+pub struct IndexSuggestion {
+    /// The object to index.
+    on_id: GlobalId,
+    /// The cluster that ran the queries that the index would have served.
+    cluster_id: ClusterId,
+    /// The key of the index.
+    key: Vec<MirScalarExpr>,
+    /// The (estimated) number of logged executions the index would have
+    /// served as fast-path peeks.
+    executions: u64,
+    /// The (estimated) number of records the index would need to maintain,
+    /// if known.
+    estimated_records: Option<u64>,
+}
+
+impl IndexSuggestion {
+    /// Executions served per thousand maintained records, if the number of
+    /// records is known.
+    fn score(&self) -> Option<f64> {
+        self.estimated_records.map(|records| {
+            f64::cast_lossy(self.executions) * 1000.0 / f64::cast_lossy(records.max(1))
+        })
+    }
+}
+
+/// A distinct statement from the statement log, together with the session
+/// context needed to plan it again.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct LoggedStatement {
+    sql: String,
+    database: String,
+    search_path: Vec<String>,
+    cluster_id: ClusterId,
+}
+
+/// Everything an index advisor run needs, captured from the [`Coordinator`]
+/// so that the run can happen off the main coordinator loop.
+struct AdvisorContext {
+    catalog: Arc<Catalog>,
+    storage_collections: Arc<dyn StorageCollections<Timestamp = Timestamp> + Send + Sync>,
+    compute_instances: BTreeMap<ClusterId, ComputeInstanceSnapshot>,
+    optimizer_config: OptimizerConfig,
+    metrics: OptimizerMetrics,
+    transient_id_gen: Arc<TransientIdGen>,
+    /// Executions that began before this time are ignored.
+    since: EpochMillis,
+    max_executions: usize,
+    max_statements: usize,
+    max_suggestions: usize,
+}
+
+impl Coordinator {
+    /// Schedules the next index advisor run.
+    pub(crate) fn schedule_index_advisor(&self) {
+        let interval = INDEX_ADVISOR_INTERVAL.get(self.catalog().system_config().dyncfgs());
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "index_advisor", async move {
+            tokio::time::sleep(interval).await;
+            if internal_cmd_tx.send(Message::IndexAdvisorRun).is_err() {
+                // If sending fails, the main thread has shutdown.
+            }
+        });
+    }
+
+    /// Kicks off an index advisor run in the background, if the advisor is
+    /// enabled. The results arrive as [`Message::IndexAdvisorUpdate`].
+    pub(crate) async fn index_advisor_run(&mut self) {
+        let system_config = self.catalog().system_config();
+        if !system_config.enable_index_advisor()
+            || !system_config.enable_mz_notices()
+            || self.controller.read_only()
+        {
+            self.schedule_index_advisor();
+            return;
+        }
+
+        let compute_instances = self
+            .catalog()
+            .user_clusters()
+            .filter_map(|cluster| {
+                let snapshot = self.instance_snapshot(cluster.id).ok()?;
+                Some((cluster.id, snapshot))
+            })
+            .collect();
+        let ctx = AdvisorContext {
+            catalog: self.owned_catalog(),
+            storage_collections: Arc::clone(&self.controller.storage_collections),
+            compute_instances,
+            optimizer_config: OptimizerConfig::from(system_config),
+            metrics: self.optimizer_metrics.clone(),
+            transient_id_gen: Arc::clone(&self.transient_id_gen),
+            since: self.now().saturating_sub(
+                INDEX_ADVISOR_WINDOW
+                    .get(system_config.dyncfgs())
+                    .as_millis()
+                    .try_into()
+                    .unwrap_or(EpochMillis::MAX),
+            ),
+            max_executions: INDEX_ADVISOR_MAX_EXECUTIONS.get(system_config.dyncfgs()),
+            max_statements: INDEX_ADVISOR_MAX_STATEMENTS.get(system_config.dyncfgs()),
+            max_suggestions: INDEX_ADVISOR_MAX_SUGGESTIONS.get(system_config.dyncfgs()),
+        };
+
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "index_advisor_run", async move {
+            let suggestions = advise(ctx).await;
+            if internal_cmd_tx
+                .send(Message::IndexAdvisorUpdate(suggestions))
+                .is_err()
+            {
+                // If sending fails, the main thread has shutdown.
+            }
+        });
+    }
+
+    /// Replaces the suggestions of the previous index advisor run with
+    /// `suggestions` and schedules the next run.
+    pub(crate) async fn index_advisor_update(&mut self, suggestions: Vec<IndexSuggestion>) {
+        let catalog = self.catalog();
+
+        // Objects or clusters might have been dropped while the run was in
+        // progress.
+        let suggestions: Vec<_> = suggestions
+            .into_iter()
+            .filter(|s| {
+                catalog.try_get_entry_by_global_id(&s.on_id).is_some()
+                    && catalog.try_get_cluster(s.cluster_id).is_some()
+            })
+            .collect();
+
+        let raw_notices = suggestions
+            .iter()
+            .map(|s| {
+                RawOptimizerNotice::from(IndexWouldEnableFastPath {
+                    index_on_id: s.on_id,
+                    recommended_key: s.key.clone(),
+                    executions: s.executions,
+                })
+            })
+            .collect::<Vec<_>>();
+        let notice_ids = std::iter::repeat_with(|| self.allocate_transient_id().1)
+            .take(raw_notices.len())
+            .collect();
+        let df_meta = DataflowMetainfo {
+            optimizer_notices: raw_notices,
+            ..Default::default()
+        };
+        let df_meta = catalog.render_notices(df_meta, notice_ids, None);
+
+        let new_suggestions = std::iter::zip(df_meta.optimizer_notices, &suggestions)
+            .map(|(notice, suggestion)| {
+                let row = pack_index_suggestion(catalog, &notice, suggestion);
+                (notice, row)
+            })
+            .collect::<Vec<_>>();
+
+        let suggestions_id = catalog.resolve_builtin_table(&MZ_INDEX_SUGGESTIONS);
+        let old_suggestions = std::mem::take(&mut self.index_advisor_suggestions);
+        let mut updates = Vec::new();
+        for (suggestions, diff) in [
+            (&old_suggestions, Diff::MINUS_ONE),
+            (&new_suggestions, Diff::ONE),
+        ] {
+            let catalog = self.catalog();
+            catalog.state().pack_optimizer_notices(
+                &mut updates,
+                suggestions.iter().map(|(notice, _)| notice),
+                diff,
+            );
+            updates.extend(
+                suggestions
+                    .iter()
+                    .map(|(_, row)| BuiltinTableUpdate::row(suggestions_id, row.clone(), diff)),
+            );
+        }
+        self.index_advisor_suggestions = new_suggestions;
+
+        let _ = self.builtin_table_update().defer(updates);
+        self.schedule_index_advisor();
+    }
+}
+
+/// Packs the `mz_index_suggestions` row for `suggestion`, which is described
+/// by `notice`.
+fn pack_index_suggestion(catalog: &Catalog, notice: &OptimizerNotice, s: &IndexSuggestion) -> Row {
+    let entry = catalog.get_entry_by_global_id(&s.on_id);
+    let full_name = catalog.resolve_full_name(entry.name(), entry.conn_id());
+    let desc = entry
+        .desc(&full_name)
+        .expect("suggestions are only made for objects with descs");
+    let create_sql = index_suggestion_sql(s.cluster_id, full_name, &desc, &s.key);
+
+    Row::pack_slice(&[
+        Datum::String(&notice.id.to_string()),
+        Datum::String(&s.on_id.to_string()),
+        Datum::String(&s.cluster_id.to_string()),
+        Datum::String(&create_sql),
+        Datum::UInt64(s.executions),
+        s.estimated_records
+            .map(Datum::UInt64)
+            .unwrap_or(Datum::Null),
+        s.score().map(Datum::from).unwrap_or(Datum::Null),
+    ])
+}
+
+/// Renders the `CREATE INDEX` statement for a suggested index on the columns
+/// `key` of the object named `on_name`.
+fn index_suggestion_sql(
+    cluster_id: ClusterId,
+    on_name: FullItemName,
+    on_desc: &RelationDesc,
+    key: &[MirScalarExpr],
+) -> String {
+    use mz_sql::ast::Value;
+
+    CreateIndexStatement::<Raw> {
+        name: None,
+        on_name: RawItemName::Name(mz_sql::normalize::unresolve(on_name)),
+        in_cluster: Some(RawClusterName::Resolved(cluster_id.to_string())),
+        method: IndexMethod::Arrangement,
+        key_parts: Some(
+            key.iter()
+                .filter_map(|expr| expr.as_column())
+                .map(|i| match on_desc.get_unambiguous_name(i) {
+                    Some(n) => Expr::Identifier(vec![Ident::new_unchecked(n.to_string())]),
+                    _ => Expr::Value(Value::Number((i + 1).to_string())),
+                })
+                .collect(),
+        ),
+        with_options: vec![],
+        if_not_exists: false,
+    }
+    .to_ast_string_stable()
+}
+
+/// Runs the index advisor: reads the statement log, replays the most frequent
+/// slow-path `SELECT`s against hypothetical indexes, and returns the best
+/// suggestions.
+async fn advise(ctx: AdvisorContext) -> Vec<IndexSuggestion> {
+    let statements = match read_statement_log(&ctx).await {
+        Ok(statements) => statements,
+        Err(err) => {
+            warn!("index advisor: failed to read the statement log: {err}");
+            return Vec::new();
+        }
+    };
+
+    let mut statements = statements.into_iter().collect::<Vec<_>>();
+    statements.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    statements.truncate(ctx.max_statements);
+
+    let ctx = Arc::new(ctx);
+    let replay_ctx = Arc::clone(&ctx);
+    let replayed = task::spawn_blocking(
+        || "index_advisor_replay",
+        move || replay_statements(&replay_ctx, statements),
+    )
+    .await;
+    let Ok(candidates) = replayed else {
+        warn!("index advisor: replaying statements panicked");
+        return Vec::new();
+    };
+
+    // Keep the most frequently useful key per object and cluster.
+    let mut best: BTreeMap<(GlobalId, ClusterId), (Vec<MirScalarExpr>, f64)> = BTreeMap::new();
+    for ((on_id, cluster_id, key), executions) in candidates {
+        let entry = best
+            .entry((on_id, cluster_id))
+            .or_insert((key.clone(), 0.0));
+        if executions > entry.1 {
+            *entry = (key, executions);
+        }
+    }
+
+    let mut suggestions = Vec::with_capacity(best.len());
+    for ((on_id, cluster_id), (key, executions)) in best {
+        let estimated_records = estimate_records(&ctx, on_id).await;
+        suggestions.push(IndexSuggestion {
+            on_id,
+            cluster_id,
+            key,
+            // Executions are weighted by the inverse sample rate, so round up
+            // to not report partial executions.
+            executions: u64::cast_lossy(executions.ceil()),
+            estimated_records,
+        });
+    }
+
+    rank_suggestions(&mut suggestions);
+    suggestions.truncate(ctx.max_suggestions);
+    suggestions
+}
+
+/// Sorts `suggestions` from most to least useful: suggestions with a known
+/// score first, by descending score, then the rest by descending executions.
+fn rank_suggestions(suggestions: &mut [IndexSuggestion]) {
+    suggestions.sort_by(|a, b| match (a.score(), b.score()) {
+        (Some(a), Some(b)) => b.total_cmp(&a),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => b.executions.cmp(&a.executions),
+    });
+}
+
+/// Returns the successfully completed, parameterless, slow-path `SELECT`s in
+/// the statement log that began since `ctx.since`, along with their estimated
+/// number of executions.
+///
+/// The statement log collections are streamed rather than snapshotted, and
+/// only the rows that are needed are kept: at most `ctx.max_executions`
+/// executions in the window, and the prepared statements and SQL texts they
+/// refer to.
+async fn read_statement_log(
+    ctx: &AdvisorContext,
+) -> Result<BTreeMap<LoggedStatement, f64>, anyhow::Error> {
+    let column = |desc: &RelationDesc, name: &str| {
+        desc.get_by_name(&name.into())
+            .map(|(i, _)| i)
+            .expect("known column")
+    };
+
+    let desc = &MZ_STATEMENT_EXECUTION_HISTORY.desc;
+    let prepared_col = column(desc, "prepared_statement_id");
+    let sample_rate_col = column(desc, "sample_rate");
+    let cluster_col = column(desc, "cluster_id");
+    let database_col = column(desc, "database_name");
+    let search_path_col = column(desc, "search_path");
+    let params_col = column(desc, "params");
+    let began_col = column(desc, "began_at");
+    let status_col = column(desc, "finished_status");
+    let strategy_col = column(desc, "execution_strategy");
+
+    // Kept peek dataflows are still built on the slow path the first time.
+    let slow_path = [
+        StatementExecutionStrategy::Standard,
+        StatementExecutionStrategy::CacheMiss,
+        StatementExecutionStrategy::CacheHit,
+    ]
+    .map(|strategy| strategy.name());
+    let mut read_executions = 0;
+    let mut executions: BTreeMap<(Uuid, LoggedStatement), f64> = BTreeMap::new();
+    stream_builtin(ctx, &MZ_STATEMENT_EXECUTION_HISTORY, |row| {
+        let datums = row.unpack();
+        let is = |col: usize, expected: &str| {
+            !datums[col].is_null() && datums[col].unwrap_str() == expected
+        };
+        // Only successful slow-path executions without parameters can be
+        // replayed, and would benefit from an index.
+        let began_at = datums[began_col].unwrap_timestamptz().timestamp_millis();
+        if u64::try_from(began_at).unwrap_or(0) < ctx.since
+            || !is(status_col, "success")
+            || !slow_path.iter().any(|strategy| is(strategy_col, strategy))
+            || datums[params_col]
+                .unwrap_array()
+                .elements()
+                .iter()
+                .next()
+                .is_some()
+            || datums[cluster_col].is_null()
+        {
+            return ControlFlow::Continue(());
+        }
+        let Ok(cluster_id) = ClusterId::from_str(datums[cluster_col].unwrap_str()) else {
+            return ControlFlow::Continue(());
+        };
+        // The SQL text is filled in once the prepared statements are read.
+        let statement = LoggedStatement {
+            sql: String::new(),
+            database: datums[database_col].unwrap_str().to_string(),
+            search_path: datums[search_path_col]
+                .unwrap_list()
+                .iter()
+                .map(|d| d.unwrap_str().to_string())
+                .collect(),
+            cluster_id,
+        };
+        // Sampled executions stand in for `1 / sample_rate` executions.
+        let sample_rate = datums[sample_rate_col].unwrap_float64();
+        if sample_rate > 0.0 {
+            let prepared_id = datums[prepared_col].unwrap_uuid();
+            *executions.entry((prepared_id, statement)).or_insert(0.0) += 1.0 / sample_rate;
+        }
+        read_executions += 1;
+        if read_executions >= ctx.max_executions {
+            debug!(
+                "index advisor: read the maximum of {} executions",
+                ctx.max_executions
+            );
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .await?;
+
+    let prepared_ids: BTreeSet<Uuid> = executions.keys().map(|(id, _)| *id).collect();
+    let desc = &MZ_PREPARED_STATEMENT_HISTORY.desc;
+    let (id_col, hash_col, type_col) = (
+        column(desc, "id"),
+        column(desc, "sql_hash"),
+        column(desc, "statement_type"),
+    );
+    let select = statement_kind_label_value(StatementKind::Select);
+    let mut select_hashes: BTreeMap<Uuid, Vec<u8>> = BTreeMap::new();
+    stream_builtin(ctx, &MZ_PREPARED_STATEMENT_HISTORY, |row| {
+        let datums = row.unpack();
+        let id = datums[id_col].unwrap_uuid();
+        if prepared_ids.contains(&id)
+            && !datums[type_col].is_null()
+            && datums[type_col].unwrap_str() == select
+        {
+            select_hashes.insert(id, datums[hash_col].unwrap_bytes().to_vec());
+        }
+        ControlFlow::Continue(())
+    })
+    .await?;
+
+    let hashes: BTreeSet<&[u8]> = select_hashes.values().map(Vec::as_slice).collect();
+    let desc = &MZ_SQL_TEXT.desc;
+    let (hash_col, sql_col) = (column(desc, "sql_hash"), column(desc, "sql"));
+    let mut sql_by_hash: BTreeMap<Vec<u8>, String> = BTreeMap::new();
+    stream_builtin(ctx, &MZ_SQL_TEXT, |row| {
+        let datums = row.unpack();
+        let hash = datums[hash_col].unwrap_bytes();
+        if hashes.contains(hash) {
+            sql_by_hash.insert(hash.to_vec(), datums[sql_col].unwrap_str().to_string());
+        }
+        ControlFlow::Continue(())
+    })
+    .await?;
+
+    let mut statements = BTreeMap::new();
+    for ((prepared_id, mut statement), executions) in executions {
+        let Some(sql) = select_hashes
+            .get(&prepared_id)
+            .and_then(|hash| sql_by_hash.get(hash))
+        else {
+            continue;
+        };
+        statement.sql = sql.clone();
+        *statements.entry(statement).or_insert(0.0) += executions;
+    }
+
+    Ok(statements)
+}
+
+/// Streams the rows of the builtin collection `builtin` at its latest
+/// readable time into `f`, until `f` breaks.
+async fn stream_builtin<F>(
+    ctx: &AdvisorContext,
+    builtin: &'static BuiltinSource,
+    mut f: F,
+) -> Result<(), anyhow::Error>
+where
+    F: FnMut(&Row) -> ControlFlow<()>,
+{
+    let item_id = ctx.catalog.resolve_builtin_storage_collection(builtin);
+    let gid = ctx.catalog.get_entry(&item_id).latest_global_id();
+    let frontiers = ctx.storage_collections.collection_frontiers(gid)?;
+    let Some(as_of) = frontiers
+        .write_frontier
+        .as_option()
+        .and_then(|upper| upper.step_back())
+    else {
+        // The collection is empty or closed.
+        return Ok(());
+    };
+    let mut cursor = ctx.storage_collections.snapshot_cursor(gid, as_of).await?;
+    while let Some(updates) = cursor.next().await {
+        for ((data, _), _, diff) in updates {
+            if diff <= 0 {
+                continue;
+            }
+            let row = data
+                .map_err(anyhow::Error::msg)?
+                .0
+                .map_err(|err| anyhow::anyhow!("{} contains an error: {err}", builtin.name))?;
+            if f(&row).is_break() {
+                return Ok(());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Replays each of `statements` against hypothetical indexes, and returns
+/// the estimated number of executions that each candidate index, identified
+/// by the indexed object, the cluster, and the key, would have served as a
+/// fast-path peek.
+fn replay_statements(
+    ctx: &AdvisorContext,
+    statements: Vec<(LoggedStatement, f64)>,
+) -> BTreeMap<(GlobalId, ClusterId, Vec<MirScalarExpr>), f64> {
+    let mut candidates = BTreeMap::new();
+    for (statement, executions) in statements {
+        let Some(compute_instance) = ctx.compute_instances.get(&statement.cluster_id) else {
+            continue;
+        };
+        let (session, select) = match plan_select(&ctx.catalog, &statement) {
+            Ok(planned) => planned,
+            Err(err) => {
+                debug!("index advisor: failed to plan {:?}: {err}", statement.sql);
+                continue;
+            }
+        };
+
+        // The statement ran as a slow-path peek when it was logged, but the
+        // indexes might have changed since.
+        let Some((expr, PeekPlan::SlowPath(_))) =
+            optimize_peek(ctx, &session, &select, compute_instance.clone(), None)
+        else {
+            continue;
+        };
+
+        for (on_id, key) in candidate_keys(&expr) {
+            let (_, index_id) = ctx.transient_id_gen.allocate_id();
+            let index = Index {
+                create_sql: String::new(),
+                global_id: index_id,
+                on: on_id,
+                keys: Arc::from(key.clone()),
+                kind: IndexKind::Arrangement,
+                conn_id: None,
+                resolved_ids: ResolvedIds::empty(),
+                cluster_id: statement.cluster_id,
+                custom_logical_compaction_window: None,
+                is_retained_metrics_object: false,
+            };
+            let plan = optimize_peek(
+                ctx,
+                &session,
+                &select,
+                compute_instance.clone(),
+                Some(index),
+            );
+            if let Some((_, PeekPlan::FastPath(FastPathPlan::PeekExisting(_, id, _, _)))) = plan {
+                if id == index_id {
+                    *candidates
+                        .entry((on_id, statement.cluster_id, key))
+                        .or_insert(0.0) += executions;
+                }
+            }
+        }
+    }
+    candidates
+}
+
+/// Plans `statement` in a session that resembles the one that ran it.
+fn plan_select(
+    catalog: &Catalog,
+    statement: &LoggedStatement,
+) -> Result<(Session, SelectPlan), anyhow::Error> {
+    let mut session = Session::<Timestamp>::dummy();
+    let vars = session.vars_mut();
+    vars.set_default("database", VarInput::Flat(&statement.database))?;
+    vars.set_default("search_path", VarInput::SqlSet(&statement.search_path))?;
+    session.start_transaction_implicit(mz_ore::now::to_datetime((catalog.config().now)()), 1);
+
+    let stmt = mz_sql::parse::parse(&statement.sql)?.into_element().ast;
+    let conn_catalog = catalog.for_session(&session);
+    let (stmt, resolved_ids) = mz_sql::names::resolve(&conn_catalog, stmt)?;
+    let pcx = session.pcx();
+    let plan = mz_sql::plan::plan(
+        Some(pcx),
+        &conn_catalog,
+        stmt,
+        &Params::empty(),
+        &resolved_ids,
+    )?;
+    match plan {
+        Plan::Select(select) => Ok((session, select)),
+        _ => Err(anyhow::anyhow!("not a SELECT")),
+    }
+}
+
+/// Optimizes `select` as a peek on `compute_instance`, optionally pretending
+/// that `hypothetical_index` exists. Returns the locally optimized expression
+/// and the resulting peek plan, or `None` if optimization failed.
+fn optimize_peek(
+    ctx: &AdvisorContext,
+    session: &Session,
+    select: &SelectPlan,
+    compute_instance: ComputeInstanceSnapshot,
+    hypothetical_index: Option<Index>,
+) -> Option<(MirRelationExpr, PeekPlan)> {
+    let (_, view_id) = ctx.transient_id_gen.allocate_id();
+    let (_, index_id) = ctx.transient_id_gen.allocate_id();
+    let mut optimizer = optimize::peek::Optimizer::new(
+        Arc::clone(&ctx.catalog),
+        compute_instance,
+        select.finishing.clone(),
+        view_id,
+        index_id,
+        ctx.optimizer_config.clone(),
+        ctx.metrics.clone(),
+    );
+    if let Some(index) = hypothetical_index {
+        optimizer = optimizer.with_hypothetical_index(index);
+    }
+
+    let raw_expr: HirRelationExpr = select.source.clone();
+    let local_mir_plan = optimizer.catch_unwind_optimize(raw_expr).ok()?;
+    let expr = local_mir_plan.expr().clone();
+    let local_mir_plan = local_mir_plan.resolve(
+        TimestampContext::NoTimestamp,
+        session,
+        Box::new(EmptyStatisticsOracle {}),
+    );
+    let global_lir_plan = optimizer.catch_unwind_optimize(local_mir_plan).ok()?;
+    let (peek_plan, _, _) = global_lir_plan.unapply();
+    Some((expr, peek_plan))
+}
+
+/// Returns the index keys that could turn a peek of `expr` into a fast-path
+/// peek, by the id of the user object that they would index.
+///
+/// These are the [`literal_constraint_keys`] of `expr` and, for every user
+/// collection, its default key, which would serve full scans.
+fn candidate_keys(expr: &MirRelationExpr) -> BTreeSet<(GlobalId, Vec<MirScalarExpr>)> {
+    let mut candidates = literal_constraint_keys(expr);
+    expr.visit_pre(|e| {
+        if let MirRelationExpr::Get {
+            id: Id::Global(id),
+            typ,
+            ..
+        } = e
+        {
+            if id.is_user() {
+                let key = typ.default_key().into_iter().map(MirScalarExpr::column);
+                candidates.insert((*id, key.collect()));
+            }
+        }
+    });
+    candidates
+}
+
+/// Returns the index keys that would let a peek of `expr` look up its
+/// literal constraints, by the id of the user object that they would index.
+///
+/// For every filtered user collection, these are the columns that the filter
+/// equates to literals, together and each on its own.
+pub(crate) fn literal_constraint_keys(
+    expr: &MirRelationExpr,
+) -> BTreeSet<(GlobalId, Vec<MirScalarExpr>)> {
+    let mut candidates = BTreeSet::new();
+    expr.visit_pre(|e| {
+        let MirRelationExpr::Filter { input, predicates } = e else {
+            return;
+        };
+        let MirRelationExpr::Get {
+            id: Id::Global(id), ..
+        } = &**input
+        else {
+            return;
+        };
+        if !id.is_user() {
+            return;
+        }
+        let columns = predicates
+            .iter()
+            .filter_map(literal_equality_column)
+            .collect::<BTreeSet<_>>();
+        for column in &columns {
+            candidates.insert((*id, vec![MirScalarExpr::column(*column)]));
+        }
+        if columns.len() > 1 {
+            let key = columns.into_iter().map(MirScalarExpr::column);
+            candidates.insert((*id, key.collect()));
+        }
+    });
+    candidates
+}
+
+/// If `predicate` equates a single column to one or more literals, e.g.,
+/// `#0 = 5` or `#0 = 5 OR #0 = 7`, returns that column.
+fn literal_equality_column(predicate: &MirScalarExpr) -> Option<usize> {
+    let mut columns = predicate
+        .and_or_args(VariadicFunc::Or)
+        .into_iter()
+        .map(|arg| arg.any_expr_eq_literal().and_then(|expr| expr.as_column()));
+    let first = columns.next()??;
+    columns.all(|c| c == Some(first)).then_some(first)
+}
+
+/// Estimates the number of records an index on `on_id` would maintain, from
+/// the statistics of the backing storage collection. Returns `None` for
+/// objects that are not backed by a storage collection, e.g., views.
+async fn estimate_records(ctx: &AdvisorContext, on_id: GlobalId) -> Option<u64> {
+    let entry = ctx.catalog.try_get_entry_by_global_id(&on_id)?;
+    if !matches!(
+        entry.item(),
+        CatalogItem::Table(_) | CatalogItem::Source(_) | CatalogItem::MaterializedView(_)
+    ) {
+        return None;
+    }
+    let frontiers = ctx.storage_collections.collection_frontiers(on_id).ok()?;
+    let stats = ctx
+        .storage_collections
+        .snapshot_stats(on_id, frontiers.read_capabilities)
+        .await
+        .ok()?;
+    Some(u64::cast_from(stats.num_updates))
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_expr::BinaryFunc;
+    use mz_repr::ScalarType;
+
+    use super::*;
+
+    fn eq_literal(column: usize, value: i64) -> MirScalarExpr {
+        MirScalarExpr::column(column).call_binary(
+            MirScalarExpr::literal_ok(Datum::Int64(value), ScalarType::Int64),
+            BinaryFunc::Eq,
+        )
+    }
+
+    #[mz_ore::test]
+    fn test_literal_equality_column() {
+        assert_eq!(literal_equality_column(&eq_literal(2, 5)), Some(2));
+
+        let same_column = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::Or,
+            exprs: vec![eq_literal(1, 5), eq_literal(1, 7)],
+        };
+        assert_eq!(literal_equality_column(&same_column), Some(1));
+
+        let different_columns = MirScalarExpr::CallVariadic {
+            func: VariadicFunc::Or,
+            exprs: vec![eq_literal(1, 5), eq_literal(2, 7)],
+        };
+        assert_eq!(literal_equality_column(&different_columns), None);
+
+        let not_a_column =
+            MirScalarExpr::column(0).call_binary(MirScalarExpr::column(1), BinaryFunc::Eq);
+        assert_eq!(literal_equality_column(&not_a_column), None);
+    }
+
+    #[mz_ore::test]
+    fn test_rank_suggestions() {
+        let suggestion = |executions, estimated_records| IndexSuggestion {
+            on_id: GlobalId::User(1),
+            cluster_id: ClusterId::User(1),
+            key: vec![MirScalarExpr::column(0)],
+            executions,
+            estimated_records,
+        };
+        let mut suggestions = vec![
+            suggestion(100, None),
+            suggestion(10, Some(1_000_000)),
+            suggestion(500, None),
+            suggestion(10, Some(100)),
+        ];
+        rank_suggestions(&mut suggestions);
+        let ranked = suggestions
+            .iter()
+            .map(|s| (s.executions, s.estimated_records))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranked,
+            vec![
+                (10, Some(100)),
+                (10, Some(1_000_000)),
+                (500, None),
+                (100, None),
+            ]
+        );
+    }
+}
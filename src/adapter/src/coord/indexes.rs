@@ -13,7 +13,9 @@ use mz_catalog::memory::objects::{CatalogItem, Index};
 use mz_compute_types::ComputeInstanceId;
 use mz_expr::{CollectionPlan, MirScalarExpr};
 use mz_repr::GlobalId;
-use mz_transform::IndexOracle;
+use mz_sql::plan::IndexKind;
+use mz_transform::lexeme_constraints::LEXEME_INDEX_KEY;
+use mz_transform::{ForeignKey, ForeignKeyOracle, IndexOracle};
 
 use crate::coord::{CollectionIdBundle, Coordinator};
 use crate::optimize::dataflows::DataflowBuilder;
@@ -39,6 +41,21 @@ impl DataflowBuilder<'_> {
 
         // Iteratively extract the largest element, potentially introducing lesser elements.
         while let Some(id) = todo.iter().rev().next().cloned() {
+            // Lexeme and ANN indexes can serve lookups in addition to whatever serves the
+            // collection.
+            let mut lookup_indexes = self
+                .lexeme_indexes_on(id)
+                .chain(self.ann_indexes_on(id))
+                .map(|(id, _)| id)
+                .peekable();
+            if lookup_indexes.peek().is_some() {
+                id_bundle
+                    .compute_ids
+                    .entry(self.compute.instance_id())
+                    .or_default()
+                    .extend(lookup_indexes);
+            }
+
             // Extract available indexes as those that are enabled, and installed on the cluster.
             let mut available_indexes = self.indexes_on(id).map(|(id, _)| id).peekable();
 
@@ -71,7 +88,10 @@ impl DataflowBuilder<'_> {
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
+                    | CatalogItem::UserFunc(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Publication(_)
+                    | CatalogItem::Notification(_)
                     | CatalogItem::Connection(_) => {
                         // Non-indexable thing; no work to do.
                     }
@@ -83,11 +103,32 @@ impl DataflowBuilder<'_> {
         id_bundle
     }
 
+    /// Returns the indexes that arrange the collection `id` and are installed on the cluster.
     pub fn indexes_on(&self, id: GlobalId) -> impl Iterator<Item = (GlobalId, &Index)> {
+        self.installed_indexes_on(id)
+            .filter(|(_idx_id, idx)| idx.kind == IndexKind::Arrangement)
+    }
+
+    /// Returns the lexeme indexes on the collection `id` that are installed on the cluster.
+    pub fn lexeme_indexes_on(&self, id: GlobalId) -> impl Iterator<Item = (GlobalId, &Index)> {
+        self.installed_indexes_on(id)
+            .filter(|(_idx_id, idx)| idx.kind == IndexKind::Lexeme)
+    }
+
+    /// Returns the ANN indexes on the collection `id` that are installed on the cluster.
+    pub fn ann_indexes_on(&self, id: GlobalId) -> impl Iterator<Item = (GlobalId, &Index)> {
+        self.installed_indexes_on(id)
+            .filter(|(_idx_id, idx)| idx.kind == IndexKind::Ann)
+    }
+
+    fn installed_indexes_on(&self, id: GlobalId) -> impl Iterator<Item = (GlobalId, &Index)> {
         self.catalog
             .get_indexes_on(id, self.compute.instance_id())
-            .filter(|(idx_id, _idx)| self.compute.contains_collection(idx_id))
-            .filter(|(idx_id, _idx)| self.replan.map_or(true, |id| idx_id < &id))
+            .filter(|(idx_id, _idx)| self.is_installed(idx_id))
+    }
+
+    fn is_installed(&self, idx_id: &GlobalId) -> bool {
+        self.compute.contains_collection(idx_id) && self.replan.map_or(true, |id| idx_id < &id)
     }
 }
 
@@ -96,9 +137,32 @@ impl IndexOracle for DataflowBuilder<'_> {
         &self,
         id: GlobalId,
     ) -> Box<dyn Iterator<Item = (GlobalId, &[MirScalarExpr])> + '_> {
+        // A lexeme index is also an index on itself, which arranges its lexemes.
+        let lexeme_index = self
+            .catalog
+            .get_index(&id)
+            .filter(|idx| idx.kind == IndexKind::Lexeme && self.is_installed(&id))
+            .map(|_idx| (id, LEXEME_INDEX_KEY.as_slice()));
         Box::new(
             self.indexes_on(id)
-                .map(|(idx_id, idx)| (idx_id, idx.keys.as_ref())),
+                .map(|(idx_id, idx)| (idx_id, idx.keys.as_ref()))
+                .chain(lexeme_index),
         )
     }
+
+    fn lexeme_indexes_on(
+        &self,
+        id: GlobalId,
+    ) -> Box<dyn Iterator<Item = (GlobalId, &MirScalarExpr)> + '_> {
+        Box::new(
+            self.lexeme_indexes_on(id)
+                .map(|(idx_id, idx)| (idx_id, &idx.keys[0])),
+        )
+    }
+}
+
+impl ForeignKeyOracle for DataflowBuilder<'_> {
+    fn foreign_keys_on(&self, id: GlobalId) -> Vec<ForeignKey> {
+        self.catalog.get_foreign_keys_on(id)
+    }
 }
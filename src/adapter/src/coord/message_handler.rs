@@ -131,6 +131,18 @@ impl Coordinator {
             Message::StorageUsagePrune(expired) => {
                 self.storage_usage_prune(expired).boxed_local().await;
             }
+            Message::IndexAdvisorRun => {
+                self.index_advisor_run().boxed_local().await;
+            }
+            Message::IndexAdvisorUpdate(suggestions) => {
+                self.index_advisor_update(suggestions).boxed_local().await;
+            }
+            Message::PeekDataflowCacheExpire => {
+                self.peek_dataflow_cache_expire().boxed_local().await;
+            }
+            Message::AutoIndexExpire => {
+                self.auto_index_expire();
+            }
             Message::RetireExecute {
                 otel_ctx,
                 data,
@@ -390,6 +402,8 @@ impl Coordinator {
                 } else if self.introspection_subscribes.contains_key(&sink_id) {
                     self.handle_introspection_subscribe_batch(sink_id, response)
                         .await;
+                } else if self.active_notifications.contains_key(&sink_id) {
+                    self.handle_notification_batch(sink_id, response).await;
                 } else {
                     // Cancellation may cause us to receive responses for subscribes no longer
                     // tracked, so we quietly ignore them.
@@ -0,0 +1,282 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Support for `LISTEN` and `CREATE NOTIFICATION`.
+//!
+//! Every notification definition is backed by a single subscribe on the
+//! notification's materialized view, owned by the coordinator rather than by
+//! any connection. Whenever the subscribe reports changes, the coordinator
+//! raises one notification per changed timestamp on the notification's
+//! channel, which is delivered to every session currently listening on it.
+//!
+//! # Lifecycle of Notification Subscribes
+//!
+//! * The coordinator calls `install_notification` after a notification is
+//!   created, and `bootstrap_notifications` during bootstrapping to install
+//!   subscribes for all existing notifications.
+//! * The coordinator calls `handle_notification_batch` for each response it
+//!   receives from a notification subscribe. A subscribe that reports an
+//!   error is dropped, and the error is reported to every session listening
+//!   on the notification's channel. A subscribe that finishes while the
+//!   notification and its materialized view are still live is reinstalled.
+//! * The coordinator calls `drop_notification` when a notification is dropped,
+//!   including when it is dropped along with its materialized view.
+
+use anyhow::bail;
+use mz_compute_client::protocol::response::SubscribeBatch;
+use mz_ore::collections::CollectionExt;
+use mz_ore::soft_panic_or_log;
+use mz_repr::optimize::OverrideFrom;
+use mz_repr::{CatalogItemId, GlobalId};
+use mz_sql::ast::UnresolvedItemName;
+use mz_sql::catalog::SessionCatalog;
+use mz_sql::plan::{Params, Plan, SubscribePlan};
+use mz_sql_parser::ast::display::AstDisplay;
+use tracing::{info, warn};
+
+use crate::active_compute_sink::ActiveNotification;
+use crate::coord::Coordinator;
+use crate::notice::AdapterNotice;
+use crate::optimize::Optimize;
+use crate::session::ChannelNotification;
+use crate::{AdapterError, optimize};
+
+impl Coordinator {
+    /// Installs subscribes for all existing notifications.
+    ///
+    /// Meant to be invoked during coordinator bootstrapping.
+    pub(super) async fn bootstrap_notifications(&mut self) {
+        let notifications: Vec<_> = self
+            .catalog()
+            .entries()
+            .filter(|entry| entry.notification().is_some())
+            .map(|entry| entry.id())
+            .collect();
+
+        for item_id in notifications {
+            self.install_notification(item_id).await;
+        }
+    }
+
+    /// Installs the subscribe backing the given notification.
+    pub(super) async fn install_notification(&mut self, item_id: CatalogItemId) {
+        if let Err(error) = self.install_notification_inner(item_id).await {
+            soft_panic_or_log!(
+                "unable to install notification subscribe: {error} (item_id={item_id})"
+            );
+        }
+    }
+
+    async fn install_notification_inner(
+        &mut self,
+        item_id: CatalogItemId,
+    ) -> Result<(), AdapterError> {
+        let entry = self.catalog().get_entry(&item_id);
+        let notification = entry
+            .notification()
+            .expect("installing a notification subscribe for a non-notification");
+        let channel = notification.channel.clone();
+        let on = self.catalog().get_entry(&notification.on);
+        let cluster_id = on
+            .materialized_view()
+            .expect("notifications are only created on materialized views")
+            .cluster_id;
+        let on_name =
+            UnresolvedItemName::from(self.catalog().resolve_full_name(on.name(), on.conn_id()));
+        let sql = format!(
+            "SUBSCRIBE (SELECT * FROM {} WHERE {}) WITH (SNAPSHOT = false)",
+            on_name.to_ast_string_stable(),
+            notification.predicate.as_deref().unwrap_or("true"),
+        );
+
+        let catalog = self.catalog().for_system_session();
+        let plan = plan_notification_subscribe(&catalog, &sql)
+            .map_err(|e| AdapterError::internal("notification", e))?;
+
+        let (_, sink_id) = self.allocate_transient_id();
+        let (_, view_id) = self.allocate_transient_id();
+        info!(%sink_id, %item_id, %channel, "installing notification subscribe");
+
+        let compute_instance = self.instance_snapshot(cluster_id).expect("must exist");
+        let vars = self.catalog().system_config();
+        let overrides = self.catalog.get_cluster(cluster_id).config.features();
+        let optimizer_config = optimize::OptimizerConfig::from(vars).override_from(&overrides);
+        let mut optimizer = optimize::subscribe::Optimizer::new(
+            self.owned_catalog(),
+            compute_instance,
+            view_id,
+            sink_id,
+            None,
+            plan.with_snapshot,
+            None,
+            format!("notification-subscribe-{sink_id}"),
+            optimizer_config,
+            self.optimizer_metrics(),
+        );
+
+        // MIR ⇒ MIR optimization (global)
+        let global_mir_plan = optimizer.catch_unwind_optimize(plan.from)?;
+
+        // Timestamp selection.
+        let id_bundle = global_mir_plan.id_bundle(cluster_id);
+        let read_holds = self.acquire_read_holds(&id_bundle);
+        let as_of = read_holds.least_valid_read();
+        let global_mir_plan = global_mir_plan.resolve(as_of);
+
+        // MIR ⇒ LIR lowering and LIR ⇒ LIR optimization (global)
+        let global_lir_plan = optimizer.catch_unwind_optimize(global_mir_plan)?;
+
+        self.active_notifications.insert(
+            sink_id,
+            ActiveNotification {
+                item_id,
+                cluster_id,
+                channel,
+            },
+        );
+        let (df_desc, _df_meta) = global_lir_plan.unapply();
+        self.ship_dataflow(df_desc, cluster_id, None).await;
+
+        drop(read_holds);
+        Ok(())
+    }
+
+    /// Drops the subscribe backing the given notification.
+    pub(super) fn drop_notification(&mut self, item_id: CatalogItemId) {
+        let to_drop: Vec<_> = self
+            .active_notifications
+            .iter()
+            .filter(|(_, n)| n.item_id == item_id)
+            .map(|(id, _)| *id)
+            .collect();
+
+        for sink_id in to_drop {
+            self.drop_notification_subscribe(sink_id);
+        }
+    }
+
+    fn drop_notification_subscribe(&mut self, sink_id: GlobalId) {
+        let Some(notification) = self.active_notifications.remove(&sink_id) else {
+            soft_panic_or_log!("attempt to remove unknown notification subscribe (id={sink_id})");
+            return;
+        };
+
+        info!(
+            %sink_id,
+            item_id = %notification.item_id,
+            "dropping notification subscribe",
+        );
+
+        if let Err(error) = self
+            .controller
+            .compute
+            .drop_collections(notification.cluster_id, vec![sink_id])
+        {
+            soft_panic_or_log!(
+                "error dropping compute collection for notification subscribe: {error} \
+                 (id={sink_id}, cluster_id={})",
+                notification.cluster_id,
+            );
+        }
+    }
+
+    /// Processes a batch returned by a notification subscribe.
+    ///
+    /// Raises one notification per changed timestamp on the notification's
+    /// channel, and delivers it to every session listening on that channel.
+    ///
+    /// Errors are terminal for a subscribe, so a subscribe that reports one is
+    /// dropped and the error is sent as a notice to the listening sessions.
+    /// It is not reinstalled, as the new subscribe would likely report the
+    /// same error.
+    pub(super) async fn handle_notification_batch(
+        &mut self,
+        sink_id: GlobalId,
+        batch: SubscribeBatch,
+    ) {
+        let Some(notification) = self.active_notifications.get(&sink_id) else {
+            soft_panic_or_log!("updates for unknown notification subscribe (id={sink_id})");
+            return;
+        };
+        let item_id = notification.item_id;
+        let channel = notification.channel.clone();
+
+        let finished = batch.upper.is_empty();
+        match notification.process_response(batch) {
+            Ok(payloads) => {
+                for payload in payloads {
+                    for conn in self.active_conns.values() {
+                        if conn.listen_channels.contains(&channel) {
+                            let _ = conn.notification_tx.send(ChannelNotification {
+                                channel: channel.clone(),
+                                payload: payload.clone(),
+                            });
+                        }
+                    }
+                }
+                if finished {
+                    self.drop_notification_subscribe(sink_id);
+                    if self.notification_is_live(item_id) {
+                        self.install_notification(item_id).await;
+                    }
+                }
+            }
+            Err(error) => {
+                warn!(%sink_id, %item_id, "notification subscribe produced an error: {error}");
+                for conn in self.active_conns.values() {
+                    if conn.listen_channels.contains(&channel) {
+                        let _ = conn.notice_tx.send(AdapterNotice::NotificationFailed {
+                            channel: channel.clone(),
+                            error: error.clone(),
+                        });
+                    }
+                }
+                self.drop_notification_subscribe(sink_id);
+            }
+        }
+    }
+
+    /// Reports whether the given notification still exists and its
+    /// materialized view can still change, i.e. whether a subscribe backing
+    /// it would produce any further notifications.
+    fn notification_is_live(&self, item_id: CatalogItemId) -> bool {
+        let Some(notification) = self
+            .catalog()
+            .try_get_entry(&item_id)
+            .and_then(|entry| entry.notification())
+        else {
+            return false;
+        };
+        let Some(on) = self.catalog().try_get_entry(&notification.on) else {
+            return false;
+        };
+        match self
+            .controller
+            .storage
+            .collection_frontiers(on.latest_global_id())
+        {
+            Ok((_read_frontier, write_frontier)) => !write_frontier.is_empty(),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Plans the subscribe that backs a notification.
+fn plan_notification_subscribe(
+    catalog: &dyn SessionCatalog,
+    sql: &str,
+) -> Result<SubscribePlan, anyhow::Error> {
+    let parsed = mz_sql::parse::parse(sql)?.into_element();
+    let (stmt, resolved_ids) = mz_sql::names::resolve(catalog, parsed.ast)?;
+    let plan = mz_sql::plan::plan(None, catalog, stmt, &Params::empty(), &resolved_ids)?;
+    match plan {
+        Plan::Subscribe(plan) => Ok(plan),
+        _ => bail!("unexpected plan type: {plan:?}"),
+    }
+}
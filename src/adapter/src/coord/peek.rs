@@ -15,6 +15,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::num::NonZeroUsize;
+use std::time::Instant;
 
 use differential_dataflow::consolidation::consolidate;
 use futures::TryFutureExt;
@@ -30,21 +31,27 @@ use mz_controller_types::ClusterId;
 use mz_expr::explain::{HumanizedExplain, HumanizerMode, fmt_text_constant_rows};
 use mz_expr::row::RowCollection;
 use mz_expr::{
-    EvalError, Id, MirRelationExpr, MirScalarExpr, OptimizedMirRelationExpr, RowSetFinishing,
-    permutation_for_arrangement,
+    BinaryFunc, EvalError, Id, MapFilterProject, MirRelationExpr, MirScalarExpr,
+    OptimizedMirRelationExpr, RowSetFinishing, permutation_for_arrangement,
 };
 use mz_ore::cast::CastFrom;
 use mz_ore::str::{StrExt, separated};
 use mz_ore::tracing::OpenTelemetryContext;
+use mz_repr::adt::vector;
 use mz_repr::explain::text::DisplayText;
 use mz_repr::explain::{CompactScalars, IndexUsageType, PlanRenderingContext, UsedIndexes};
-use mz_repr::{Diff, GlobalId, IntoRowIterator, RelationType, Row, RowIterator, preserves_order};
+use mz_repr::{
+    Datum, Diff, GlobalId, IntoRowIterator, RelationType, Row, RowIterator, ScalarType,
+    preserves_order,
+};
 use serde::{Deserialize, Serialize};
 use timely::progress::Timestamp;
 use uuid::Uuid;
 
+use crate::coord::peek_dataflow_cache::PeekDataflowKey;
 use crate::coord::timestamp_selection::TimestampDetermination;
 use crate::optimize::OptimizerError;
+use crate::optimize::index::ANN_INDEX_KEY;
 use crate::statement_logging::{StatementEndedExecutionReason, StatementExecutionStrategy};
 use crate::util::ResultExt;
 use crate::{AdapterError, ExecuteContextExtra, ExecuteResponse};
@@ -63,6 +70,9 @@ pub(crate) struct PendingPeek {
     pub(crate) ctx_extra: ExecuteContextExtra,
     /// Is this a fast-path peek, i.e. one that doesn't require a dataflow?
     pub(crate) is_fast_path: bool,
+    /// Whether the peek was served by a kept dataflow or kept the dataflow it
+    /// built, if the peek dataflow cache was used.
+    pub(crate) cache_strategy: Option<StatementExecutionStrategy>,
 }
 
 /// The response from a `Peek`, with row multiplicities represented in unary.
@@ -83,6 +93,9 @@ pub struct PeekDataflowPlan<T = mz_repr::Timestamp> {
     key: Vec<MirScalarExpr>,
     permutation: Vec<usize>,
     thinned_arity: usize,
+    /// The cache key of the dataflow, if it can be kept around to serve later
+    /// peeks of the same plan.
+    pub(crate) cache_key: Option<PeekDataflowKey<T>>,
 }
 
 impl<T> PeekDataflowPlan<T> {
@@ -104,10 +117,25 @@ impl<T> PeekDataflowPlan<T> {
             key,
             permutation,
             thinned_arity: thinning.len(),
+            cache_key: None,
         }
     }
 }
 
+impl<T: Clone> PeekDataflowPlan<T> {
+    /// Marks the dataflow as reusable by later peeks of the same plan.
+    ///
+    /// The caller must ensure that the dataflow produces correct results at all
+    /// times beyond its `as_of`, i.e., that it is not restricted to a single
+    /// time and that none of its expressions depend on the time or the session
+    /// of the peek.
+    pub fn with_cache_key(mut self, select_id: GlobalId) -> Self {
+        let key = PeekDataflowKey::new(&self.desc, select_id, &self.key, &self.permutation);
+        self.cache_key = Some(key);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Ord, PartialOrd)]
 pub enum FastPathPlan {
     /// The view evaluates to a constant result that can be returned.
@@ -120,6 +148,11 @@ pub enum FastPathPlan {
     PeekExisting(GlobalId, GlobalId, Option<Vec<Row>>, mz_expr::SafeMfpPlan),
     /// The view can be read directly out of Persist.
     PeekPersist(GlobalId, Option<Row>, mz_expr::SafeMfpPlan),
+    /// The view is ordered by the cosine distance to a literal vector, and its nearest rows can be
+    /// approximately read out of the buckets of an ANN index that are near that vector. Only
+    /// planned if the session opts into approximate results.
+    /// (coll_id, idx_id, buckets to look up, mfp to apply)
+    PeekNearest(GlobalId, GlobalId, Vec<Row>, mz_expr::SafeMfpPlan),
 }
 
 impl<'a, T: 'a> DisplayText<PlanRenderingContext<'a, T>> for FastPathPlan {
@@ -157,7 +190,8 @@ impl<'a, T: 'a> DisplayText<PlanRenderingContext<'a, T>> for FastPathPlan {
                     writeln!(f, "{}Error {}", ctx.as_mut(), err.to_string().escaped())
                 }
             }
-            FastPathPlan::PeekExisting(coll_id, idx_id, literal_constraints, mfp) => {
+            FastPathPlan::PeekExisting(coll_id, idx_id, _, mfp)
+            | FastPathPlan::PeekNearest(coll_id, idx_id, _, mfp) => {
                 ctx.as_mut().set();
                 let (map, filter, project) = mfp.as_map_filter_project();
 
@@ -196,14 +230,35 @@ impl<'a, T: 'a> DisplayText<PlanRenderingContext<'a, T>> for FastPathPlan {
                     writeln!(f, "{}Map ({})", ctx.as_mut(), scalars)?;
                     *ctx.as_mut() += 1;
                 }
-                MirRelationExpr::fmt_indexed_filter(
-                    f,
-                    ctx,
-                    coll_id,
-                    idx_id,
-                    literal_constraints.clone(),
-                    None,
-                )?;
+                if let FastPathPlan::PeekExisting(_, _, literal_constraints, _) = self {
+                    MirRelationExpr::fmt_indexed_filter(
+                        f,
+                        ctx,
+                        coll_id,
+                        idx_id,
+                        literal_constraints.clone(),
+                        None,
+                    )?;
+                } else if let FastPathPlan::PeekNearest(_, _, buckets, _) = self {
+                    let humanized_coll = ctx
+                        .humanizer
+                        .humanize_id(*coll_id)
+                        .unwrap_or_else(|| coll_id.to_string());
+                    let humanized_index = ctx
+                        .humanizer
+                        .humanize_id_unqualified(*idx_id)
+                        .unwrap_or_else(|| "[DELETED INDEX]".to_owned());
+                    let buckets = mode.seq(buckets, None);
+                    write!(
+                        f,
+                        "{}ReadIndex on={} {}=[{} buckets=[{}]]",
+                        ctx.as_mut(),
+                        humanized_coll,
+                        humanized_index,
+                        IndexUsageType::NearestNeighbors,
+                        separated("; ", buckets),
+                    )?;
+                }
                 writeln!(f)?;
                 ctx.as_mut().reset();
                 Ok(())
@@ -302,17 +357,93 @@ fn permute_oneshot_mfp_around_index(
     Ok(safe_mfp)
 }
 
+/// Detects a peek that orders the rows of a `Get` by the cosine distance between the key of one of
+/// the `ann_indexes` on it and a literal vector, and keeps only the first few of them.
+///
+/// Returns the id of the index and the buckets to look up in it. The rows in these buckets are
+/// likely, but not guaranteed, to include the nearest rows.
+fn detect_nearest_neighbors(
+    mfp: &MapFilterProject,
+    relation_typ: &RelationType,
+    finishing: Option<&RowSetFinishing>,
+    ann_indexes: &[(GlobalId, MirScalarExpr)],
+) -> Option<(GlobalId, Vec<Row>)> {
+    let finishing = finishing?;
+    let order = finishing.order_by.first()?;
+    // Without a limit, the peek asks for all rows, and not just the nearest ones. Filters could
+    // remove all rows in the buckets we look up.
+    if ann_indexes.is_empty()
+        || finishing.limit.is_none()
+        || order.desc
+        || !mfp.predicates.is_empty()
+    {
+        return None;
+    }
+
+    // Find the expression we order by in terms of the columns of the `Get`.
+    let column = *mfp.projection.get(order.column)?;
+    let mut inlined = mfp.clone();
+    let should_inline = (0..inlined.input_arity + inlined.expressions.len())
+        .map(|c| c >= inlined.input_arity)
+        .collect();
+    inlined.perform_inlining(should_inline);
+    let MirScalarExpr::CallBinary { func, expr1, expr2 } = inlined
+        .expressions
+        .get(column.checked_sub(inlined.input_arity)?)?
+    else {
+        return None;
+    };
+    // The buckets group vectors by their direction, so they only track the cosine distance.
+    if *func != BinaryFunc::VectorCosineDistance {
+        return None;
+    }
+    // The cosine distance is symmetric.
+    let (embedding, literal) = match (expr1.as_literal(), expr2.as_literal()) {
+        (None, Some(Ok(literal))) => (&**expr1, literal),
+        (Some(Ok(literal)), None) => (&**expr2, literal),
+        _ => return None,
+    };
+    let (idx_id, _) = ann_indexes.iter().find(|(_, key)| key == embedding)?;
+    if literal.is_null() {
+        return None;
+    }
+    let query: Vec<f32> = literal
+        .unwrap_array()
+        .elements()
+        .iter()
+        .map(|d| d.unwrap_float32())
+        .collect();
+    // Let the full scan report vectors of the wrong dimensions.
+    if let ScalarType::Vector {
+        dimensions: Some(dimensions),
+    } = embedding.typ(&relation_typ.column_types).scalar_type
+    {
+        dimensions.check(query.len()).ok()?;
+    }
+
+    let buckets = vector::ann_probes(&query)
+        .into_iter()
+        .map(|bucket| Row::pack_slice(&[Datum::Int32(bucket)]))
+        .collect();
+    Some((*idx_id, buckets))
+}
+
 /// Determine if the dataflow plan can be implemented without an actual dataflow.
 ///
 /// If the optimized plan is a `Constant` or a `Get` of a maintained arrangement,
 /// we can avoid building a dataflow (and either just return the results, or peek
 /// out of the arrangement, respectively).
+///
+/// `ann_indexes` lists the ids and keys of the ANN indexes on each collection, which can serve
+/// peeks for the nearest rows to a vector. It must only be non-empty if the peek accepts
+/// approximate results.
 pub fn create_fast_path_plan<T: Timestamp>(
     dataflow_plan: &mut DataflowDescription<OptimizedMirRelationExpr, (), T>,
     view_id: GlobalId,
     finishing: Option<&RowSetFinishing>,
     persist_fast_path_limit: usize,
     persist_fast_path_order: bool,
+    ann_indexes: &BTreeMap<GlobalId, Vec<(GlobalId, MirScalarExpr)>>,
 ) -> Result<Option<FastPathPlan>, OptimizerError> {
     // At this point, `dataflow_plan` contains our best optimized dataflow.
     // We will check the plan to see if there is a fast path to escape full dataflow construction.
@@ -372,6 +503,25 @@ pub fn create_fast_path_plan<T: Timestamp>(
                     typ: relation_typ,
                     ..
                 } => {
+                    // Read the nearest rows out of an ANN index, if the peek asks for them.
+                    let ann_indexes = ann_indexes.get(get_id).map_or(&[][..], |i| i.as_slice());
+                    if let Some((idx_id, buckets)) =
+                        detect_nearest_neighbors(&mfp, relation_typ, finishing, ann_indexes)
+                    {
+                        // The index arranges the bucket, followed by the columns of the row.
+                        let arity = relation_typ.arity();
+                        let mfp = MapFilterProject::compose(
+                            MapFilterProject::new(arity + 1).project(1..=arity),
+                            mfp,
+                        );
+                        return Ok(Some(FastPathPlan::PeekNearest(
+                            *get_id,
+                            idx_id,
+                            buckets,
+                            permute_oneshot_mfp_around_index(mfp, &ANN_INDEX_KEY)?,
+                        )));
+                    }
+
                     // Just grab any arrangement if an arrangement exists
                     for (index_id, IndexImport { desc, .. }) in dataflow_plan.index_imports.iter() {
                         if desc.on_id == *get_id {
@@ -501,6 +651,9 @@ impl FastPathPlan {
                 }
             }
             FastPathPlan::PeekPersist(..) => UsedIndexes::default(),
+            FastPathPlan::PeekNearest(_coll_id, idx_id, _buckets, _mfp) => {
+                UsedIndexes::new([(*idx_id, vec![IndexUsageType::NearestNeighbors])].into())
+            }
         }
     }
 }
@@ -594,6 +747,7 @@ impl crate::coord::Coordinator {
         // differently.
 
         // If we must build the view, ship the dataflow.
+        let mut cache_strategy = None;
         let (peek_command, drop_dataflow, is_fast_path, peek_target, strategy) = match fast_path {
             PeekPlan::FastPath(FastPathPlan::PeekExisting(
                 _coll_id,
@@ -607,6 +761,18 @@ impl crate::coord::Coordinator {
                 PeekTarget::Index { id: idx_id },
                 StatementExecutionStrategy::FastPath,
             ),
+            PeekPlan::FastPath(FastPathPlan::PeekNearest(
+                _coll_id,
+                idx_id,
+                buckets,
+                map_filter_project,
+            )) => (
+                (Some(buckets), timestamp, map_filter_project),
+                None,
+                true,
+                PeekTarget::Index { id: idx_id },
+                StatementExecutionStrategy::FastPath,
+            ),
             PeekPlan::FastPath(FastPathPlan::PeekPersist(
                 coll_id,
                 literal_constraint,
@@ -643,36 +809,89 @@ impl crate::coord::Coordinator {
                 key: index_key,
                 permutation: index_permutation,
                 thinned_arity: index_thinned_arity,
+                cache_key,
             }) => {
-                let output_ids = dataflow.export_ids().collect();
-
-                // Very important: actually create the dataflow (here, so we can destructure).
-                self.controller
-                    .compute
-                    .create_dataflow(compute_instance, dataflow, None)
-                    .unwrap_or_terminate("cannot fail to create dataflows");
-                self.initialize_compute_read_policies(
-                    output_ids,
-                    compute_instance,
-                    // Disable compaction so that nothing can compact before the peek occurs below.
-                    CompactionWindow::DisableCompaction,
-                )
-                .await;
+                // If the dataflow can be kept around, try to serve the peek from
+                // a dataflow kept for an earlier peek of the same plan instead.
+                let cache_key = cache_key.filter(|_| self.peek_dataflow_cache_enabled());
+                let kept = cache_key
+                    .as_ref()
+                    .and_then(|key| self.kept_peek_dataflow(compute_instance, key, timestamp));
+                if let Some(kept) = kept {
+                    if let Some(id) = ctx_extra.contents() {
+                        self.set_transient_index_id(id, kept.index_id);
+                    }
+                    cache_strategy = Some(StatementExecutionStrategy::CacheHit);
+                    (
+                        (None, timestamp, kept.map_filter_project),
+                        None,
+                        false,
+                        PeekTarget::Index { id: kept.index_id },
+                        StatementExecutionStrategy::CacheHit,
+                    )
+                } else {
+                    let output_ids = dataflow.export_ids().collect();
 
-                // Create an identity MFP operator.
-                let mut map_filter_project = mz_expr::MapFilterProject::new(source_arity);
-                map_filter_project.permute_fn(
-                    |c| index_permutation[c],
-                    index_key.len() + index_thinned_arity,
-                );
-                let map_filter_project = mfp_to_safe_plan(map_filter_project)?;
-                (
-                    (None, timestamp, map_filter_project),
-                    Some(index_id),
-                    false,
-                    PeekTarget::Index { id: index_id },
-                    StatementExecutionStrategy::Standard,
-                )
+                    // Very important: actually create the dataflow (here, so we can destructure).
+                    self.controller
+                        .compute
+                        .create_dataflow(compute_instance, dataflow, None)
+                        .unwrap_or_terminate("cannot fail to create dataflows");
+                    // Disable compaction so that nothing can compact before the peek occurs below,
+                    // unless we keep the dataflow, which then needs to compact like an index.
+                    let compaction_window = match cache_key {
+                        Some(_) => CompactionWindow::Default,
+                        None => CompactionWindow::DisableCompaction,
+                    };
+                    self.initialize_compute_read_policies(
+                        output_ids,
+                        compute_instance,
+                        compaction_window,
+                    )
+                    .await;
+
+                    // Create an identity MFP operator.
+                    let mut map_filter_project = mz_expr::MapFilterProject::new(source_arity);
+                    map_filter_project.permute_fn(
+                        |c| index_permutation[c],
+                        index_key.len() + index_thinned_arity,
+                    );
+                    let map_filter_project = mfp_to_safe_plan(map_filter_project)?;
+
+                    if let Some(cache_key) = cache_key {
+                        // Keep the dataflow around instead of dropping it after the peek.
+                        let replaced = self.peek_dataflow_cache.insert(
+                            compute_instance,
+                            cache_key,
+                            index_id,
+                            map_filter_project.clone(),
+                            source_ids.clone(),
+                            Instant::now(),
+                        );
+                        self.drop_peek_dataflows(
+                            replaced
+                                .map(|id| (compute_instance, id))
+                                .into_iter()
+                                .collect(),
+                        );
+                        cache_strategy = Some(StatementExecutionStrategy::CacheMiss);
+                        (
+                            (None, timestamp, map_filter_project),
+                            None,
+                            false,
+                            PeekTarget::Index { id: index_id },
+                            StatementExecutionStrategy::CacheMiss,
+                        )
+                    } else {
+                        (
+                            (None, timestamp, map_filter_project),
+                            Some(index_id),
+                            false,
+                            PeekTarget::Index { id: index_id },
+                            StatementExecutionStrategy::Standard,
+                        )
+                    }
+                }
             }
             _ => {
                 unreachable!()
@@ -699,6 +918,7 @@ impl crate::coord::Coordinator {
                 depends_on: source_ids,
                 ctx_extra: std::mem::take(ctx_extra),
                 is_fast_path,
+                cache_strategy,
             },
         );
         self.client_pending_peeks
@@ -809,6 +1029,7 @@ impl crate::coord::Coordinator {
             depends_on: _,
             ctx_extra,
             is_fast_path,
+            cache_strategy,
         }) = self.remove_pending_peek(&uuid)
         {
             let reason = match notification {
@@ -816,10 +1037,10 @@ impl crate::coord::Coordinator {
                     rows: num_rows,
                     result_size,
                 } => {
-                    let strategy = if is_fast_path {
-                        StatementExecutionStrategy::FastPath
-                    } else {
-                        StatementExecutionStrategy::Standard
+                    let strategy = match cache_strategy {
+                        Some(strategy) => strategy,
+                        None if is_fast_path => StatementExecutionStrategy::FastPath,
+                        None => StatementExecutionStrategy::Standard,
                     };
                     StatementEndedExecutionReason::Success {
                         result_size: Some(result_size),
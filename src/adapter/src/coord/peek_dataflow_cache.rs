@@ -0,0 +1,431 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! The peek dataflow cache.
+//!
+//! A slow-path peek builds a transient dataflow that exports an index, peeks
+//! that index once, and drops the dataflow again. If
+//! `enable_peek_dataflow_cache` is set, the optimizer plans these dataflows
+//! such that they keep up with their inputs (see
+//! [`PeekDataflowPlan::with_cache_key`]), and the coordinator keeps them
+//! around after the peek. A later peek of an equal plan on the same cluster
+//! reads from the kept index instead of building a new dataflow, as long as
+//! the index has not compacted beyond the peek's timestamp.
+//!
+//! Kept dataflows are dropped once they have not been used for
+//! `peek_dataflow_cache_idle_timeout`, when the size of the kept dataflows'
+//! arrangements on a cluster exceeds `peek_dataflow_cache_memory_budget`, and
+//! when one of their dependencies is dropped. The sizes are the ones reported
+//! by the replicas, so dataflows are only kept while
+//! `enable_compute_arrangement_size_reporting` is also set.
+//!
+//! [`PeekDataflowPlan::with_cache_key`]: crate::coord::peek::PeekDataflowPlan::with_cache_key
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::{Duration, Instant};
+
+use mz_adapter_types::dyncfgs::{
+    ENABLE_PEEK_DATAFLOW_CACHE, PEEK_DATAFLOW_CACHE_IDLE_TIMEOUT, PEEK_DATAFLOW_CACHE_MEMORY_BUDGET,
+};
+use mz_compute_types::ComputeInstanceId;
+use mz_compute_types::dataflows::{DataflowDescription, IndexImport};
+use mz_compute_types::dyncfgs::ENABLE_COMPUTE_ARRANGEMENT_SIZE_REPORTING;
+use mz_compute_types::plan::Plan;
+use mz_compute_types::sources::SourceInstanceDesc;
+use mz_expr::{MirScalarExpr, SafeMfpPlan};
+use mz_ore::cast::CastFrom;
+use mz_ore::task;
+use mz_repr::{GlobalId, Timestamp};
+use tracing::debug;
+
+use crate::coord::{Coordinator, Message};
+
+/// The parts of a peek dataflow that determine the contents of the index it
+/// exports.
+///
+/// Peeks whose dataflows have equal keys can be served from the same index.
+/// The transient identifiers and frontiers of the dataflows are not part of
+/// the key, as they differ between otherwise identical peeks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PeekDataflowKey<T = Timestamp> {
+    // Cheap to compare fields come first, so that comparing the keys of
+    // unrelated plans usually stops before it gets to the plans.
+    key: Vec<MirScalarExpr>,
+    permutation: Vec<usize>,
+    source_imports: BTreeMap<GlobalId, (SourceInstanceDesc<()>, bool)>,
+    index_imports: BTreeMap<GlobalId, IndexImport>,
+    /// The plans of the objects to build, with the identifier of the exported
+    /// index replaced by `None`.
+    objects_to_build: Vec<(Option<GlobalId>, Plan<T>)>,
+}
+
+impl<T: Clone> PeekDataflowKey<T> {
+    /// Returns the key of the peek dataflow `desc`, which exports an index
+    /// with the given key and permutation on the object `select_id`.
+    pub(crate) fn new(
+        desc: &DataflowDescription<Plan<T>, (), T>,
+        select_id: GlobalId,
+        key: &[MirScalarExpr],
+        permutation: &[usize],
+    ) -> Self {
+        Self {
+            key: key.to_vec(),
+            permutation: permutation.to_vec(),
+            source_imports: desc
+                .source_imports
+                .iter()
+                .map(|(id, (desc, monotonic, _upper))| (*id, (desc.clone(), *monotonic)))
+                .collect(),
+            index_imports: desc.index_imports.clone(),
+            objects_to_build: desc
+                .objects_to_build
+                .iter()
+                .map(|build| {
+                    let id = (build.id != select_id).then_some(build.id);
+                    (id, build.plan.clone())
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A dataflow that was kept around after a slow-path peek.
+#[derive(Debug, Clone)]
+pub(crate) struct CachedPeekDataflow {
+    /// The cluster the dataflow runs on.
+    instance_id: ComputeInstanceId,
+    /// The plan of the dataflow.
+    key: PeekDataflowKey,
+    /// The index exported by the dataflow.
+    pub(crate) index_id: GlobalId,
+    /// The MFP that turns the arranged rows into the peek's result rows.
+    pub(crate) map_filter_project: SafeMfpPlan,
+    /// All `GlobalId`s that the dataflow depends on.
+    depends_on: BTreeSet<GlobalId>,
+    /// The size of the dataflow's index, in bytes, as last reported by the
+    /// replicas.
+    size_bytes: u64,
+    /// The last time the dataflow served a peek.
+    last_used: Instant,
+}
+
+/// The dataflows kept around after slow-path peeks, by the index they export.
+#[derive(Debug, Default)]
+pub(crate) struct PeekDataflowCache {
+    entries: BTreeMap<GlobalId, CachedPeekDataflow>,
+}
+
+impl PeekDataflowCache {
+    /// Returns the dataflow kept for the plan with the given key on the given
+    /// cluster, and marks it as used.
+    pub(crate) fn get(
+        &mut self,
+        instance_id: ComputeInstanceId,
+        key: &PeekDataflowKey,
+        now: Instant,
+    ) -> Option<&CachedPeekDataflow> {
+        let entry = self
+            .entries
+            .values_mut()
+            .find(|e| e.instance_id == instance_id && e.key == *key)?;
+        entry.last_used = now;
+        Some(entry)
+    }
+
+    /// Keeps the dataflow exporting `index_id` for the plan with the given key
+    /// on the given cluster.
+    ///
+    /// Returns the index of a dataflow previously kept for the same plan, which
+    /// the caller must drop.
+    pub(crate) fn insert(
+        &mut self,
+        instance_id: ComputeInstanceId,
+        key: PeekDataflowKey,
+        index_id: GlobalId,
+        map_filter_project: SafeMfpPlan,
+        depends_on: BTreeSet<GlobalId>,
+        now: Instant,
+    ) -> Option<GlobalId> {
+        let replaced = self
+            .remove_where(|i, e| i == instance_id && e.key == key)
+            .into_iter()
+            .map(|(_, id)| id)
+            .next();
+        let entry = CachedPeekDataflow {
+            instance_id,
+            key,
+            index_id,
+            map_filter_project,
+            depends_on,
+            size_bytes: 0,
+            last_used: now,
+        };
+        self.entries.insert(index_id, entry);
+        replaced
+    }
+
+    /// Returns the clusters and indexes of all kept dataflows.
+    pub(crate) fn indexes(&self) -> Vec<(ComputeInstanceId, GlobalId)> {
+        self.entries
+            .values()
+            .map(|e| (e.instance_id, e.index_id))
+            .collect()
+    }
+
+    /// Records the size of the index exported by a kept dataflow.
+    ///
+    /// Does nothing if the dataflow is not (or no longer) kept.
+    pub(crate) fn record_size(&mut self, index_id: GlobalId, size_bytes: u64) {
+        if let Some(entry) = self.entries.get_mut(&index_id) {
+            entry.size_bytes = size_bytes;
+        }
+    }
+
+    /// Removes the dataflows that have not been used since `idle_timeout`
+    /// before `now`, and then the least recently used dataflows on each
+    /// cluster until the sizes of the remaining ones fit into `budget_bytes`.
+    ///
+    /// Returns the removed dataflows, which the caller must drop.
+    pub(crate) fn evict(
+        &mut self,
+        now: Instant,
+        idle_timeout: Duration,
+        budget_bytes: u64,
+    ) -> Vec<(ComputeInstanceId, GlobalId)> {
+        let mut evicted = self.remove_where(|_, entry| {
+            now.saturating_duration_since(entry.last_used) >= idle_timeout
+        });
+
+        let mut by_instance: BTreeMap<ComputeInstanceId, Vec<(Instant, GlobalId, u64)>> =
+            BTreeMap::new();
+        for entry in self.entries.values() {
+            by_instance.entry(entry.instance_id).or_default().push((
+                entry.last_used,
+                entry.index_id,
+                entry.size_bytes,
+            ));
+        }
+        for (instance_id, mut entries) in by_instance {
+            let mut total: u64 = entries.iter().map(|(_, _, size)| size).sum();
+            entries.sort();
+            for (_, index_id, size) in entries {
+                if total <= budget_bytes {
+                    break;
+                }
+                self.entries.remove(&index_id).expect("entry exists");
+                evicted.push((instance_id, index_id));
+                total -= size;
+            }
+        }
+
+        evicted
+    }
+
+    /// Removes the dataflows that run on one of the given clusters or depend on
+    /// one of the given collections.
+    ///
+    /// Returns the removed dataflows, which the caller must drop.
+    pub(crate) fn remove_dependents(
+        &mut self,
+        collections: &BTreeSet<GlobalId>,
+        clusters: &[ComputeInstanceId],
+    ) -> Vec<(ComputeInstanceId, GlobalId)> {
+        self.remove_where(|instance_id, entry| {
+            clusters.contains(&instance_id) || !entry.depends_on.is_disjoint(collections)
+        })
+    }
+
+    /// Removes all dataflows.
+    ///
+    /// Returns the removed dataflows, which the caller must drop.
+    pub(crate) fn clear(&mut self) -> Vec<(ComputeInstanceId, GlobalId)> {
+        self.remove_where(|_, _| true)
+    }
+
+    fn remove_where<F>(&mut self, mut f: F) -> Vec<(ComputeInstanceId, GlobalId)>
+    where
+        F: FnMut(ComputeInstanceId, &CachedPeekDataflow) -> bool,
+    {
+        let mut removed = Vec::new();
+        self.entries.retain(|index_id, entry| {
+            let remove = f(entry.instance_id, entry);
+            if remove {
+                removed.push((entry.instance_id, *index_id));
+            }
+            !remove
+        });
+        removed
+    }
+}
+
+impl Coordinator {
+    /// Reports whether slow-path peeks may keep their dataflows around.
+    pub(crate) fn peek_dataflow_cache_enabled(&self) -> bool {
+        let dyncfgs = self.catalog().system_config().dyncfgs();
+        ENABLE_PEEK_DATAFLOW_CACHE.get(dyncfgs)
+            && ENABLE_COMPUTE_ARRANGEMENT_SIZE_REPORTING.get(dyncfgs)
+    }
+
+    /// Schedules the next expiration of kept peek dataflows.
+    pub(crate) fn schedule_peek_dataflow_cache_expiration(&self) {
+        // Checking often keeps the memory budget effective for dataflows that
+        // grow quickly, and is cheap when no dataflows are kept.
+        let interval = Duration::from_secs(1);
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "peek_dataflow_cache_expiration", async move {
+            tokio::time::sleep(interval).await;
+            if internal_cmd_tx
+                .send(Message::PeekDataflowCacheExpire)
+                .is_err()
+            {
+                // If sending fails, the main thread has shutdown.
+            }
+        });
+    }
+
+    /// Returns the dataflow kept for the plan with the given key on the given
+    /// cluster, if it can serve a peek at `timestamp`.
+    pub(crate) fn kept_peek_dataflow(
+        &mut self,
+        instance_id: ComputeInstanceId,
+        key: &PeekDataflowKey,
+        timestamp: Timestamp,
+    ) -> Option<CachedPeekDataflow> {
+        let kept = self
+            .peek_dataflow_cache
+            .get(instance_id, key, Instant::now())?
+            .clone();
+        let frontiers = self
+            .controller
+            .compute
+            .collection_frontiers(kept.index_id, Some(instance_id))
+            .ok()?;
+        frontiers
+            .read_frontier
+            .less_equal(&timestamp)
+            .then_some(kept)
+    }
+
+    /// Drops the kept peek dataflows that have been idle for too long or don't
+    /// fit into the memory budget, or all of them if the cache was disabled.
+    pub(crate) async fn peek_dataflow_cache_expire(&mut self) {
+        let evicted = if self.peek_dataflow_cache_enabled() {
+            for (instance_id, index_id) in self.peek_dataflow_cache.indexes() {
+                // Until the replicas report a size for the index, we keep the
+                // last known one.
+                let size_bytes = self
+                    .controller
+                    .compute
+                    .collection_arrangement_size_bytes(instance_id, index_id)
+                    .await;
+                if let Ok(Some(size_bytes)) = size_bytes {
+                    self.peek_dataflow_cache.record_size(index_id, size_bytes);
+                }
+            }
+            let dyncfgs = self.catalog().system_config().dyncfgs();
+            let idle_timeout = PEEK_DATAFLOW_CACHE_IDLE_TIMEOUT.get(dyncfgs);
+            let budget = u64::cast_from(PEEK_DATAFLOW_CACHE_MEMORY_BUDGET.get(dyncfgs));
+            self.peek_dataflow_cache
+                .evict(Instant::now(), idle_timeout, budget)
+        } else {
+            self.peek_dataflow_cache.clear()
+        };
+        self.drop_peek_dataflows(evicted);
+        self.schedule_peek_dataflow_cache_expiration();
+    }
+
+    /// Drops dataflows that were removed from the peek dataflow cache.
+    pub(crate) fn drop_peek_dataflows(&mut self, dataflows: Vec<(ComputeInstanceId, GlobalId)>) {
+        if dataflows.is_empty() {
+            return;
+        }
+        debug!(?dataflows, "dropping kept peek dataflows");
+        self.remove_compute_ids_from_timeline(dataflows.clone());
+        self.drop_indexes(dataflows);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_expr::MapFilterProject;
+
+    use super::*;
+
+    fn mfp() -> SafeMfpPlan {
+        MapFilterProject::new(1)
+            .into_plan()
+            .expect("valid plan")
+            .into_nontemporal()
+            .expect("nontemporal")
+    }
+
+    /// Returns a key that differs for different `column`s.
+    fn key(column: usize) -> PeekDataflowKey {
+        PeekDataflowKey {
+            key: vec![MirScalarExpr::column(column)],
+            permutation: Vec::new(),
+            source_imports: BTreeMap::new(),
+            index_imports: BTreeMap::new(),
+            objects_to_build: Vec::new(),
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_evict() {
+        let cluster = ComputeInstanceId::User(1);
+        let other = ComputeInstanceId::User(2);
+        let start = Instant::now();
+        let later = |secs| start + Duration::from_secs(secs);
+        let mut cache = PeekDataflowCache::default();
+
+        for (id, instance_id, used) in [(1, cluster, 0), (2, cluster, 10), (3, other, 5)] {
+            let index_id = GlobalId::Transient(id);
+            let replaced = cache.insert(
+                instance_id,
+                key(usize::cast_from(id)),
+                index_id,
+                mfp(),
+                BTreeSet::new(),
+                later(used),
+            );
+            assert_eq!(replaced, None);
+            cache.record_size(index_id, 100);
+        }
+
+        // Using a dataflow refreshes it.
+        assert!(cache.get(cluster, &key(1), later(20)).is_some());
+        assert!(cache.get(cluster, &key(4), later(20)).is_none());
+        // Dataflows are only shared within a cluster.
+        assert!(cache.get(other, &key(1), later(20)).is_none());
+
+        // The budget applies per cluster, and evicts the least recently used
+        // dataflows first.
+        let evicted = cache.evict(later(20), Duration::MAX, 100);
+        assert_eq!(evicted, vec![(cluster, GlobalId::Transient(2))]);
+
+        // Idle dataflows are evicted regardless of the budget.
+        let evicted = cache.evict(later(30), Duration::from_secs(20), u64::MAX);
+        assert_eq!(evicted, vec![(other, GlobalId::Transient(3))]);
+
+        // Keeping a new dataflow for the same plan replaces the old one.
+        let replaced = cache.insert(
+            cluster,
+            key(1),
+            GlobalId::Transient(5),
+            mfp(),
+            BTreeSet::from([GlobalId::User(7)]),
+            later(30),
+        );
+        assert_eq!(replaced, Some(GlobalId::Transient(1)));
+
+        let evicted = cache.remove_dependents(&BTreeSet::from([GlobalId::User(7)]), &[]);
+        assert_eq!(evicted, vec![(cluster, GlobalId::Transient(5))]);
+        assert!(cache.clear().is_empty());
+    }
+}
@@ -0,0 +1,398 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Support for durable logical replication slots.
+//!
+//! A replication slot records the position up to which a consumer of the
+//! logical replication protocol has confirmed the changes it was sent. The
+//! slot's log sequence number is a Materialize timestamp, so to let the
+//! consumer resume from its confirmed position the coordinator holds back the
+//! since of every storage collection the slot streams from at that position.
+//!
+//! # Lifecycle of Replication Slot Read Holds
+//!
+//! * `START_REPLICATION` checks that the connection's role may read the
+//!   published relations, determines the storage collections that back them,
+//!   and acquires read holds on the ones the slot does not yet hold. Holds on
+//!   collections the slot no longer streams from are released.
+//! * The replication stream periodically confirms the position that the
+//!   consumer reports as flushed, which advances the slot's confirmed
+//!   position and downgrades its read holds.
+//! * The coordinator calls `bootstrap_replication_slots` during bootstrapping
+//!   to reacquire the holds of all existing slots.
+//! * Dropping a slot releases its holds, and dropping a collection removes it
+//!   from every slot that streams from it.
+//!
+//! # Active Slots
+//!
+//! As in PostgreSQL, a slot streams to at most one connection at a time.
+//! `START_REPLICATION` marks the slot as active for the starting connection
+//! until the stream ends or the connection terminates. While a slot is
+//! active, other connections may neither start, advance, nor drop it, as they
+//! would otherwise race to advance its confirmed position.
+//!
+//! # Privileges
+//!
+//! As in PostgreSQL, only superusers and roles with the `REPLICATION`
+//! attribute may use replication slots. The attribute is not inherited
+//! through role membership. Each slot is owned by the role that created it,
+//! and only superusers and members of that role may start, advance, or drop
+//! it. Starting a
+//! slot also requires `SELECT` on the published relations, as the slot would
+//! otherwise hold back compaction of collections the role cannot read.
+
+use std::collections::BTreeSet;
+
+use mz_adapter_types::connection::ConnectionId;
+use mz_catalog::durable::ReplicationSlot;
+use mz_catalog::memory::objects::CatalogItem;
+use mz_repr::adt::mz_acl_item::AclMode;
+use mz_repr::role_id::RoleId;
+use mz_repr::{GlobalId, Timestamp};
+use mz_sql::catalog::{CatalogError as SqlCatalogError, ErrorMessageObjectDescription};
+use mz_sql::names::{ObjectId, SystemObjectId};
+use mz_sql::rbac::UnauthorizedError;
+use tracing::warn;
+
+use crate::AdapterError;
+use crate::catalog;
+use crate::command::ReplicationSlotRequest;
+use crate::coord::Coordinator;
+use crate::coord::id_bundle::CollectionIdBundle;
+
+impl Coordinator {
+    /// Reacquires the read holds of all existing replication slots.
+    ///
+    /// Meant to be invoked during coordinator bootstrapping, after the storage
+    /// collections have been initialized.
+    pub(super) fn bootstrap_replication_slots(&mut self) {
+        let slots: Vec<_> = self.catalog().replication_slots().cloned().collect();
+        for slot in slots {
+            let id_bundle = CollectionIdBundle {
+                storage_ids: slot.collections.clone(),
+                compute_ids: Default::default(),
+            };
+            let mut read_holds = self.acquire_read_holds(&id_bundle);
+            let since = read_holds.least_valid_read();
+            if !since.less_equal(&Timestamp::from(slot.confirmed_lsn)) {
+                warn!(
+                    "replication slot {} can no longer resume at {}: collections are readable \
+                     from {:?}",
+                    slot.name, slot.confirmed_lsn, since,
+                );
+            }
+            read_holds.downgrade(Timestamp::from(slot.confirmed_lsn));
+            self.replication_slot_read_holds
+                .insert(slot.name.clone(), read_holds);
+        }
+    }
+
+    /// Handles a request concerning a replication slot on behalf of the
+    /// connection `conn_id`, returning the position the slot's consumer should
+    /// resume from.
+    pub(crate) async fn handle_replication_slot_request(
+        &mut self,
+        conn_id: &ConnectionId,
+        request: ReplicationSlotRequest,
+    ) -> Result<Timestamp, AdapterError> {
+        let role_id = self.check_replication_role(conn_id)?;
+        match request {
+            ReplicationSlotRequest::Create { name, plugin } => {
+                let slot = ReplicationSlot {
+                    name,
+                    plugin,
+                    confirmed_lsn: 0,
+                    collections: BTreeSet::new(),
+                    owner_id: role_id,
+                };
+                self.catalog_transact(None, vec![catalog::Op::CreateReplicationSlot { slot }])
+                    .await?;
+                Ok(Timestamp::MIN)
+            }
+            ReplicationSlotRequest::Drop { name } => {
+                self.check_replication_slot_owner(conn_id, &self.replication_slot(&name)?)?;
+                self.check_replication_slot_inactive(&name, None)?;
+                self.catalog_transact(
+                    None,
+                    vec![catalog::Op::DropReplicationSlot { name: name.clone() }],
+                )
+                .await?;
+                self.replication_slot_read_holds.remove(&name);
+                self.active_replication_slots.remove(&name);
+                Ok(Timestamp::MIN)
+            }
+            ReplicationSlotRequest::Start {
+                name,
+                lsn,
+                collections,
+            } => {
+                self.check_replication_slot_owner(conn_id, &self.replication_slot(&name)?)?;
+                self.check_replication_select(conn_id, &collections)?;
+                self.check_replication_slot_inactive(&name, Some(conn_id))?;
+                let start = self
+                    .start_replication_slot(name.clone(), lsn, collections)
+                    .await?;
+                self.active_replication_slots.insert(name, conn_id.clone());
+                Ok(start)
+            }
+            ReplicationSlotRequest::Confirm { name, lsn } => {
+                let mut slot = self.replication_slot(&name)?;
+                self.check_replication_slot_owner(conn_id, &slot)?;
+                self.check_replication_slot_inactive(&name, Some(conn_id))?;
+                if u64::from(lsn) > slot.confirmed_lsn {
+                    slot.confirmed_lsn = lsn.into();
+                    self.catalog_transact(None, vec![catalog::Op::UpdateReplicationSlot { slot }])
+                        .await?;
+                    if let Some(read_holds) = self.replication_slot_read_holds.get_mut(&name) {
+                        read_holds.downgrade(lsn);
+                    }
+                }
+                Ok(lsn)
+            }
+            ReplicationSlotRequest::Release { name } => {
+                if self.active_replication_slots.get(&name) == Some(conn_id) {
+                    self.active_replication_slots.remove(&name);
+                }
+                Ok(Timestamp::MIN)
+            }
+        }
+    }
+
+    /// Marks the replication slots that the connection `conn_id` streams from
+    /// as inactive.
+    ///
+    /// Meant to be invoked when the connection terminates.
+    pub(super) fn release_replication_slots(&mut self, conn_id: &ConnectionId) {
+        self.active_replication_slots
+            .retain(|_, active_conn_id| active_conn_id != conn_id);
+    }
+
+    /// Checks that the replication slot `name` is not streaming to a
+    /// connection other than `conn_id`.
+    fn check_replication_slot_inactive(
+        &self,
+        name: &str,
+        conn_id: Option<&ConnectionId>,
+    ) -> Result<(), AdapterError> {
+        match self.active_replication_slots.get(name) {
+            Some(active_conn_id) if Some(active_conn_id) != conn_id => {
+                Err(AdapterError::ReplicationSlotActive {
+                    name: name.to_string(),
+                    pid: active_conn_id.unhandled(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Points the replication slot `name` at the storage collections that
+    /// back the relations `collections`, and returns the position at which
+    /// streaming starts given that the consumer requested `lsn`.
+    async fn start_replication_slot(
+        &mut self,
+        name: String,
+        lsn: Timestamp,
+        collections: BTreeSet<GlobalId>,
+    ) -> Result<Timestamp, AdapterError> {
+        let mut slot = self.replication_slot(&name)?;
+        // As in PostgreSQL, a consumer cannot rewind past the position it has
+        // already confirmed.
+        let start = std::cmp::max(lsn, Timestamp::from(slot.confirmed_lsn));
+        let storage_ids = self.replication_slot_storage_ids(collections);
+
+        let mut read_holds = self
+            .replication_slot_read_holds
+            .remove(&name)
+            .unwrap_or_default();
+        let held: BTreeSet<_> = read_holds.storage_ids().collect();
+        let id_bundle = CollectionIdBundle {
+            storage_ids: storage_ids.difference(&held).copied().collect(),
+            compute_ids: Default::default(),
+        };
+        let acquired = self.acquire_read_holds(&id_bundle);
+        read_holds.storage_holds.extend(acquired.storage_holds);
+
+        // A start position of zero asks for a snapshot at a time of the
+        // subscribe's choosing, which is always readable.
+        if start != Timestamp::MIN {
+            let since = read_holds.least_valid_read();
+            if !since.less_equal(&start) {
+                // Leave the slot as it was, so that it can still resume from
+                // its previous publications.
+                for id in id_bundle.storage_ids {
+                    read_holds.remove_storage_collection(id);
+                }
+                self.replication_slot_read_holds
+                    .insert(name.clone(), read_holds);
+                return Err(AdapterError::ReplicationSlotPositionUnavailable {
+                    name,
+                    lsn: start,
+                    since,
+                });
+            }
+        }
+        for id in held.difference(&storage_ids) {
+            read_holds.remove_storage_collection(*id);
+        }
+        if slot.confirmed_lsn != 0 {
+            read_holds.downgrade(Timestamp::from(slot.confirmed_lsn));
+        }
+        self.replication_slot_read_holds.insert(name, read_holds);
+
+        if slot.collections != storage_ids {
+            slot.collections = storage_ids;
+            self.catalog_transact(None, vec![catalog::Op::UpdateReplicationSlot { slot }])
+                .await?;
+        }
+        Ok(start)
+    }
+
+    /// Releases the read holds that replication slots have on collections
+    /// that they no longer stream from, e.g. because the collections were
+    /// dropped.
+    pub(super) fn prune_replication_slot_read_holds(&mut self) {
+        let catalog = &self.catalog;
+        self.replication_slot_read_holds.retain(|name, read_holds| {
+            let Some(slot) = catalog.get_replication_slot(name) else {
+                return false;
+            };
+            let stale: Vec<_> = read_holds
+                .storage_ids()
+                .filter(|id| !slot.collections.contains(id))
+                .collect();
+            for id in stale {
+                read_holds.remove_storage_collection(id);
+            }
+            true
+        });
+    }
+
+    /// Checks that the role of the connection `conn_id` may use replication
+    /// slots, and returns it.
+    fn check_replication_role(&self, conn_id: &ConnectionId) -> Result<RoleId, AdapterError> {
+        let conn = self
+            .active_conns
+            .get(conn_id)
+            .expect("connection must exist");
+        let role_id = *conn.authenticated_role_id();
+        let replication = self
+            .catalog()
+            .try_get_role(&role_id)
+            .and_then(|role| role.attributes.replication)
+            .unwrap_or(false);
+        if !conn.user().is_superuser() && !replication {
+            return Err(UnauthorizedError::Replication {
+                action: "use replication slots".into(),
+            }
+            .into());
+        }
+        Ok(role_id)
+    }
+
+    /// Checks that the role of the connection `conn_id` is a member of the
+    /// owner of `slot`.
+    fn check_replication_slot_owner(
+        &self,
+        conn_id: &ConnectionId,
+        slot: &ReplicationSlot,
+    ) -> Result<(), AdapterError> {
+        let conn = self
+            .active_conns
+            .get(conn_id)
+            .expect("connection must exist");
+        let role_id = conn.authenticated_role_id();
+        if conn.user().is_superuser()
+            || self
+                .catalog()
+                .state()
+                .collect_role_membership(role_id)
+                .contains(&slot.owner_id)
+        {
+            return Ok(());
+        }
+        let owner = self.catalog().get_role(&slot.owner_id);
+        Err(UnauthorizedError::RoleMembership {
+            role_names: vec![owner.name.clone()],
+        }
+        .into())
+    }
+
+    /// Checks that the role of the connection `conn_id` has `SELECT` on the
+    /// relations `ids`.
+    fn check_replication_select(
+        &self,
+        conn_id: &ConnectionId,
+        ids: &BTreeSet<GlobalId>,
+    ) -> Result<(), AdapterError> {
+        let conn = self
+            .active_conns
+            .get(conn_id)
+            .expect("connection must exist");
+        if conn.user().is_superuser() || !self.catalog().system_config().enable_rbac_checks() {
+            return Ok(());
+        }
+        let role_id = conn.authenticated_role_id();
+        let role_membership = self.catalog().state().collect_role_membership(role_id);
+        for id in ids {
+            // The relation may have been dropped since the replication
+            // connection resolved it, in which case there is nothing to hold.
+            let Some(entry) = self.catalog().try_get_entry_by_global_id(id) else {
+                continue;
+            };
+            let privileges = role_membership
+                .iter()
+                .flat_map(|role_id| entry.privileges().get_acl_items_for_grantee(role_id))
+                .fold(AclMode::empty(), |acc, item| acc.union(item.acl_mode));
+            if !privileges.contains(AclMode::SELECT) {
+                let object_id = SystemObjectId::Object(ObjectId::Item(entry.id()));
+                return Err(UnauthorizedError::Privilege {
+                    object_description: ErrorMessageObjectDescription::from_sys_id(
+                        &object_id,
+                        &self.catalog().for_system_session(),
+                    ),
+                    role_name: self.catalog().get_role(role_id).name.clone(),
+                    privileges: AclMode::SELECT.to_error_string(),
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    fn replication_slot(&self, name: &str) -> Result<ReplicationSlot, AdapterError> {
+        self.catalog()
+            .get_replication_slot(name)
+            .cloned()
+            .ok_or_else(|| SqlCatalogError::UnknownReplicationSlot(name.to_string()).into())
+    }
+
+    /// Returns the storage collections that the relations `ids` are computed
+    /// from, looking through views.
+    fn replication_slot_storage_ids(&self, ids: BTreeSet<GlobalId>) -> BTreeSet<GlobalId> {
+        let mut storage_ids = BTreeSet::new();
+        let mut todo = ids;
+        while let Some(id) = todo.pop_first() {
+            // The relation may have been dropped since the replication
+            // connection resolved it.
+            let Some(entry) = self.catalog().try_get_entry_by_global_id(&id) else {
+                continue;
+            };
+            match entry.item() {
+                CatalogItem::View(view) => todo.extend(view.optimized_expr.0.depends_on()),
+                item if item.is_storage_collection() => {
+                    storage_ids.insert(id);
+                }
+                // Other relations, like logs, are only available in indexes,
+                // which replication slots do not hold.
+                _ => {}
+            }
+        }
+        storage_ids
+    }
+}
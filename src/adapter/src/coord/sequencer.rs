@@ -243,6 +243,24 @@ impl Coordinator {
                 Plan::CreateSecret(plan) => {
                     self.sequence_create_secret(ctx, plan).await;
                 }
+                Plan::CreatePublication(plan) => {
+                    let result = self
+                        .sequence_create_publication(ctx.session(), plan, resolved_ids)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::CreateNotification(plan) => {
+                    let result = self
+                        .sequence_create_notification(ctx.session(), plan, resolved_ids)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::CreateFunction(plan) => {
+                    let result = self
+                        .sequence_create_function(ctx.session(), plan, resolved_ids)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::CreateSink(plan) => {
                     self.sequence_create_sink(ctx, plan, resolved_ids).await;
                 }
@@ -521,6 +539,12 @@ impl Coordinator {
                     let result = self.sequence_alter_table(ctx.session(), plan).await;
                     ctx.retire(result);
                 }
+                Plan::AlterTypeAddValue(plan) => {
+                    let result = self
+                        .sequence_alter_type_add_value(ctx.session(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::AlterNetworkPolicy(plan) => {
                     let res = self
                         .sequence_alter_network_policy(ctx.session(), plan)
@@ -618,6 +642,14 @@ impl Coordinator {
                         ctx.retire(Ok(ExecuteResponse::Deallocate { all: true }));
                     }
                 },
+                Plan::Listen(plan) => {
+                    let response = self.sequence_listen(ctx.session(), plan);
+                    ctx.retire(Ok(response));
+                }
+                Plan::Unlisten(plan) => {
+                    let response = self.sequence_unlisten(ctx.session(), plan);
+                    ctx.retire(Ok(response));
+                }
                 Plan::Raise(RaisePlan { severity }) => {
                     ctx.session()
                         .add_notice(AdapterNotice::UserRequested { severity });
@@ -24,15 +24,18 @@ use mz_adapter_types::compaction::CompactionWindow;
 use mz_adapter_types::connection::ConnectionId;
 use mz_adapter_types::dyncfgs::{ENABLE_MULTI_REPLICA_SOURCES, ENABLE_SELF_MANAGED_AUTH};
 use mz_catalog::memory::objects::{
-    CatalogItem, Cluster, Connection, DataSourceDesc, Sink, Source, Table, TableDataSource, Type,
+    CatalogItem, Cluster, Connection, DataSourceDesc, Notification, Publication, Sink, Source,
+    Table, TableDataSource, Type, UserFunc,
 };
 use mz_cloud_resources::VpcEndpointConfig;
+use mz_compute_types::ComputeInstanceId;
 use mz_controller_types::ReplicaId;
 use mz_expr::{
     CollectionPlan, MapFilterProject, OptimizedMirRelationExpr, ResultSpec, RowSetFinishing,
 };
 use mz_ore::cast::CastFrom;
 use mz_ore::collections::{CollectionExt, HashSet};
+use mz_ore::str::StrExt;
 use mz_ore::task::{self, JoinHandle, spawn};
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_ore::vec::VecExt;
@@ -44,7 +47,7 @@ use mz_repr::explain::ExprHumanizer;
 use mz_repr::explain::json::json_string;
 use mz_repr::role_id::RoleId;
 use mz_repr::{
-    CatalogItemId, Datum, Diff, GlobalId, IntoRowIterator, RelationVersion,
+    CatalogItemId, Datum, Diff, GlobalId, IntoRowIterator, RelationDesc, RelationVersion,
     RelationVersionSelector, Row, RowArena, RowIterator, Timestamp,
 };
 use mz_sql::ast::{
@@ -60,7 +63,7 @@ use mz_sql::names::{
     Aug, ObjectId, QualifiedItemName, ResolvedDatabaseSpecifier, ResolvedIds, ResolvedItemName,
     SchemaSpecifier, SystemObjectId,
 };
-use mz_sql::plan::{ConnectionDetails, NetworkPolicyRule, StatementContext};
+use mz_sql::plan::{ConnectionDetails, IndexKind, NetworkPolicyRule, StatementContext};
 use mz_sql::pure::{PurifiedSourceExport, generate_subsource_statements};
 use mz_storage_types::sinks::StorageSinkDesc;
 use mz_storage_types::sources::GenericSourceConnection;
@@ -88,7 +91,7 @@ use mz_storage_client::controller::{CollectionDescription, DataSource, ExportDes
 use mz_storage_types::AlterCompatible;
 use mz_storage_types::connections::inline::IntoInlineConnection;
 use mz_storage_types::controller::StorageError;
-use mz_storage_types::stats::RelationPartStats;
+use mz_storage_types::stats::{DistinctBound, RelationPartStats};
 use mz_transform::EmptyStatisticsOracle;
 use mz_transform::dataflow::DataflowMetainfo;
 use mz_transform::notice::{OptimizerNoticeApi, OptimizerNoticeKind, RawOptimizerNotice};
@@ -1132,6 +1135,7 @@ impl Coordinator {
             custom_logical_compaction_window: table.compaction_window,
             is_retained_metrics_object: false,
             data_source,
+            foreign_keys: table.foreign_keys,
         };
         let ops = vec![catalog::Op::CreateItem {
             id: table_id,
@@ -1470,6 +1474,168 @@ impl Coordinator {
         }
     }
 
+    #[instrument]
+    pub(super) async fn sequence_create_publication(
+        &mut self,
+        session: &Session,
+        plan: plan::CreatePublicationPlan,
+        resolved_ids: ResolvedIds,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::CreatePublicationPlan {
+            name,
+            publication,
+            if_not_exists,
+        } = plan;
+        let id_ts = self.get_catalog_write_ts().await;
+        let (item_id, global_id) = self.catalog_mut().allocate_user_id(id_ts).await?;
+        let publication = Publication {
+            create_sql: publication.create_sql,
+            global_id,
+            relations: publication.relations,
+            resolved_ids,
+        };
+        let op = catalog::Op::CreateItem {
+            id: item_id,
+            name: name.clone(),
+            item: CatalogItem::Publication(publication),
+            owner_id: *session.current_role_id(),
+        };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(()) => Ok(ExecuteResponse::CreatedPublication),
+            Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
+                kind:
+                    mz_catalog::memory::error::ErrorKind::Sql(CatalogError::ItemAlreadyExists(_, _)),
+            })) if if_not_exists => {
+                session.add_notice(AdapterNotice::ObjectAlreadyExists {
+                    name: name.item,
+                    ty: "publication",
+                });
+                Ok(ExecuteResponse::CreatedPublication)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_create_notification(
+        &mut self,
+        session: &Session,
+        plan: plan::CreateNotificationPlan,
+        resolved_ids: ResolvedIds,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::CreateNotificationPlan {
+            name,
+            notification,
+            if_not_exists,
+        } = plan;
+        let id_ts = self.get_catalog_write_ts().await;
+        let (item_id, global_id) = self.catalog_mut().allocate_user_id(id_ts).await?;
+        let notification = Notification {
+            create_sql: notification.create_sql,
+            global_id,
+            on: notification.on,
+            channel: notification.channel,
+            predicate: notification.predicate,
+            resolved_ids,
+        };
+        let op = catalog::Op::CreateItem {
+            id: item_id,
+            name: name.clone(),
+            item: CatalogItem::Notification(notification),
+            owner_id: *session.current_role_id(),
+        };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(()) => {
+                self.install_notification(item_id).await;
+                Ok(ExecuteResponse::CreatedNotification)
+            }
+            Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
+                kind:
+                    mz_catalog::memory::error::ErrorKind::Sql(CatalogError::ItemAlreadyExists(_, _)),
+            })) if if_not_exists => {
+                session.add_notice(AdapterNotice::ObjectAlreadyExists {
+                    name: name.item,
+                    ty: "notification",
+                });
+                Ok(ExecuteResponse::CreatedNotification)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_create_function(
+        &mut self,
+        session: &Session,
+        plan: plan::CreateFunctionPlan,
+        resolved_ids: ResolvedIds,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::CreateFunctionPlan {
+            name,
+            function,
+            if_not_exists,
+        } = plan;
+        let id_ts = self.get_catalog_write_ts().await;
+        let (item_id, global_id) = self.catalog_mut().allocate_user_id(id_ts).await?;
+        let function = UserFunc {
+            create_sql: function.create_sql,
+            global_id,
+            details: function.details,
+            resolved_ids,
+        };
+        let op = catalog::Op::CreateItem {
+            id: item_id,
+            name: name.clone(),
+            item: CatalogItem::UserFunc(function),
+            owner_id: *session.current_role_id(),
+        };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(()) => Ok(ExecuteResponse::CreatedFunction),
+            Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
+                kind:
+                    mz_catalog::memory::error::ErrorKind::Sql(CatalogError::ItemAlreadyExists(_, _)),
+            })) if if_not_exists => {
+                session.add_notice(AdapterNotice::ObjectAlreadyExists {
+                    name: name.item,
+                    ty: "function",
+                });
+                Ok(ExecuteResponse::CreatedFunction)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    pub(super) fn sequence_listen(
+        &mut self,
+        session: &Session,
+        plan: plan::ListenPlan,
+    ) -> ExecuteResponse {
+        self.active_conns
+            .get_mut(session.conn_id())
+            .expect("must exist for active sessions")
+            .listen_channels
+            .insert(plan.channel);
+        ExecuteResponse::Listening
+    }
+
+    pub(super) fn sequence_unlisten(
+        &mut self,
+        session: &Session,
+        plan: plan::UnlistenPlan,
+    ) -> ExecuteResponse {
+        let conn = self
+            .active_conns
+            .get_mut(session.conn_id())
+            .expect("must exist for active sessions");
+        match plan.channel {
+            Some(channel) => {
+                conn.listen_channels.remove(&channel);
+            }
+            None => conn.listen_channels.clear(),
+        }
+        ExecuteResponse::Unlistening
+    }
+
     #[instrument]
     pub(super) async fn sequence_comment_on(
         &mut self,
@@ -1668,6 +1834,14 @@ impl Coordinator {
                 }
             }
         }
+        for slot in self.catalog.replication_slots() {
+            if let Some(role_name) = dropped_roles.get(&slot.owner_id) {
+                dependent_objects
+                    .entry(role_name.to_string())
+                    .or_default()
+                    .push(format!("owner of replication slot {}", slot.name.quoted()));
+            }
+        }
         privilege_check(
             self.catalog().system_privileges(),
             dropped_roles,
@@ -3315,7 +3489,10 @@ impl Coordinator {
                 | CatalogItem::Sink(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
+                | CatalogItem::UserFunc(_)
                 | CatalogItem::Secret(_)
+                | CatalogItem::Publication(_)
+                | CatalogItem::Notification(_)
                 | CatalogItem::Connection(_) => unreachable!(),
             };
             match cluster {
@@ -3432,6 +3609,10 @@ impl Coordinator {
                     attributes.login = Some(login);
                 }
 
+                if let Some(replication) = attrs.replication {
+                    attributes.replication = Some(replication);
+                }
+
                 if attrs.nopassword.unwrap_or(false) {
                     attributes.password = None;
                 }
@@ -5057,20 +5238,66 @@ impl Coordinator {
 
         Ok(ExecuteResponse::AlteredObject(ObjectType::Table))
     }
+
+    #[instrument]
+    pub(super) async fn sequence_alter_type_add_value(
+        &mut self,
+        session: &Session,
+        plan: plan::AlterTypeAddValuePlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::AlterTypeAddValuePlan { id, labels } = plan;
+        let ops = vec![catalog::Op::AlterTypeAddValue { id, labels }];
+        self.catalog_transact(Some(session), ops).await?;
+
+        // Objects that use the type were replanned with its new labels, so
+        // their cached expressions are stale, and source exports must decode
+        // upstream values with the new labels.
+        let mut global_ids = BTreeSet::new();
+        let mut source_export_details = BTreeMap::new();
+        for dependent in self.catalog().state().transitive_used_by(id) {
+            let entry = self.catalog().get_entry(&dependent);
+            global_ids.extend(entry.global_ids());
+            if let Some((_, _, details, _)) = entry.source_export_details() {
+                source_export_details.insert(entry.latest_global_id(), details.clone());
+            }
+        }
+        // We don't need to wait for the cache to update.
+        let _fut = self.catalog().invalidate_expression_cache(global_ids);
+
+        if !source_export_details.is_empty() {
+            self.controller
+                .storage
+                .alter_ingestion_export_details(source_export_details)
+                .await
+                .unwrap_or_terminate("altering source export details after txn must succeed");
+        }
+
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Type))
+    }
 }
 
 #[derive(Debug)]
 struct CachedStatisticsOracle {
     cache: BTreeMap<GlobalId, usize>,
+    distinct: BTreeMap<(GlobalId, usize), usize>,
 }
 
 impl CachedStatisticsOracle {
+    /// Collects row counts and distinct value counts for the given collections.
+    ///
+    /// Row counts of storage collections come from persist. Collections that are not storage
+    /// collections (like indexed views) fall back to the sizes of their arrangements, as given in
+    /// `arrangement_records`. Distinct value counts are derived from the stats of the parts of a
+    /// storage collection's snapshot, for collections that have a `desc`.
     pub async fn new<T: TimelyTimestamp>(
         ids: &BTreeSet<GlobalId>,
         as_of: &Antichain<T>,
         storage_collections: &dyn mz_storage_client::storage_collections::StorageCollections<Timestamp = T>,
+        descs: &BTreeMap<GlobalId, RelationDesc>,
+        arrangement_records: &BTreeMap<GlobalId, usize>,
     ) -> Result<Self, StorageError<T>> {
         let mut cache = BTreeMap::new();
+        let mut distinct = BTreeMap::new();
 
         for id in ids {
             let stats = storage_collections.snapshot_stats(*id, as_of.clone()).await;
@@ -5080,13 +5307,57 @@ impl CachedStatisticsOracle {
                     cache.insert(*id, stats.num_updates);
                 }
                 Err(StorageError::IdentifierMissing(id)) => {
-                    ::tracing::debug!("no statistics for {id}")
+                    match arrangement_records.get(&id) {
+                        Some(records) => {
+                            cache.insert(id, *records);
+                        }
+                        None => ::tracing::debug!("no statistics for {id}"),
+                    }
+                    continue;
                 }
                 Err(e) => return Err(e),
             }
+
+            let Some(desc) = descs.get(id) else {
+                continue;
+            };
+            let parts_stats = storage_collections
+                .snapshot_parts_stats(*id, as_of.clone())
+                .await
+                .await?;
+            if parts_stats.parts.is_empty() {
+                continue;
+            }
+
+            // A column only gets a bound if every part has stats for it.
+            let mut bounds: Vec<Option<DistinctBound>> =
+                vec![Some(DistinctBound::default()); desc.arity()];
+            for part in &parts_stats.parts {
+                let Some(stats) = &part.stats else {
+                    bounds.iter_mut().for_each(|bound| *bound = None);
+                    break;
+                };
+                let stats = stats.decode();
+                let stats = RelationPartStats::new(
+                    "",
+                    &parts_stats.metrics.pushdown.part_stats,
+                    desc,
+                    &stats,
+                );
+                for ((col_idx, _, _), bound) in desc.iter_all().zip(bounds.iter_mut()) {
+                    *bound = bound
+                        .zip(stats.col_distinct(col_idx))
+                        .map(|(bound, part_bound)| bound.merge(part_bound));
+                }
+            }
+            for (col, bound) in bounds.into_iter().enumerate() {
+                if let Some(bound) = bound {
+                    distinct.insert((*id, col), bound.count());
+                }
+            }
         }
 
-        Ok(Self { cache })
+        Ok(Self { cache, distinct })
     }
 }
 
@@ -5095,17 +5366,28 @@ impl mz_transform::StatisticsOracle for CachedStatisticsOracle {
         self.cache.get(&id).map(|estimate| *estimate)
     }
 
+    fn distinct_estimate(&self, id: GlobalId, column: usize) -> Option<usize> {
+        self.distinct.get(&(id, column)).copied()
+    }
+
     fn as_map(&self) -> BTreeMap<GlobalId, usize> {
         self.cache.clone()
     }
+
+    fn distinct_map(&self) -> BTreeMap<(GlobalId, usize), usize> {
+        self.distinct.clone()
+    }
 }
 
 impl Coordinator {
+    /// Returns a [`mz_transform::StatisticsOracle`] for optimizing a dataflow that reads from
+    /// `source_ids` at `query_as_of` and is installed on `cluster_id`.
     pub(super) async fn statistics_oracle(
         &self,
         session: &Session,
         source_ids: &BTreeSet<GlobalId>,
         query_as_of: &Antichain<Timestamp>,
+        cluster_id: ComputeInstanceId,
         is_oneshot: bool,
     ) -> Result<Box<dyn mz_transform::StatisticsOracle>, AdapterError> {
         if !session.vars().enable_session_cardinality_estimates() {
@@ -5121,14 +5403,50 @@ impl Coordinator {
             self.catalog().system_config().optimizer_stats_timeout()
         };
 
-        let cached_stats = mz_ore::future::timeout(
-            timeout,
+        let descs: BTreeMap<_, _> = source_ids
+            .iter()
+            .filter_map(|id| {
+                let entry = self.catalog().try_get_entry_by_global_id(id)?;
+                let desc = entry.desc_opt()?.into_owned();
+                Some((*id, desc))
+            })
+            .collect();
+
+        let cached_stats = mz_ore::future::timeout(timeout, async {
+            // Collections that are not in storage can still have an estimate if they are
+            // arranged on the cluster.
+            let mut arrangement_records = BTreeMap::new();
+            for id in source_ids {
+                let index_ids: Vec<_> = self
+                    .catalog()
+                    .state()
+                    .get_indexes_on(*id, cluster_id)
+                    // A lexeme index has a record per lexeme, not per row.
+                    .filter(|(_, index)| index.kind == IndexKind::Arrangement)
+                    .map(|(index_id, _)| index_id)
+                    .collect();
+                for index_id in index_ids {
+                    let records = self
+                        .controller
+                        .compute
+                        .collection_arrangement_records(cluster_id, index_id)
+                        .await;
+                    if let Ok(Some(records)) = records {
+                        arrangement_records.insert(*id, usize::cast_from(records));
+                        break;
+                    }
+                }
+            }
+
             CachedStatisticsOracle::new(
                 source_ids,
                 query_as_of,
                 self.controller.storage_collections.as_ref(),
-            ),
-        )
+                &descs,
+                &arrangement_records,
+            )
+            .await
+        })
         .await;
 
         match cached_stats {
@@ -5145,6 +5463,38 @@ impl Coordinator {
             Err(mz_ore::future::TimeoutError::Inner(e)) => Err(AdapterError::Storage(e)),
         }
     }
+
+    /// Returns a [`mz_transform::StatisticsOracle`] for optimizing a long-lived dataflow on
+    /// `cluster_id` that reads from `source_ids`.
+    ///
+    /// The statistics are taken at the earliest time readable from all sources. Failures to
+    /// collect statistics are not fatal, since the dataflow can be optimized without them.
+    pub(super) async fn ddl_statistics_oracle(
+        &self,
+        session: &Session,
+        source_ids: &BTreeSet<GlobalId>,
+        cluster_id: ComputeInstanceId,
+    ) -> Box<dyn mz_transform::StatisticsOracle> {
+        if !session.vars().enable_session_cardinality_estimates() {
+            return Box::new(EmptyStatisticsOracle);
+        }
+
+        let id_bundle = self
+            .dataflow_builder(cluster_id)
+            .sufficient_collections(source_ids.iter().copied());
+        let read_holds = self.acquire_read_holds(&id_bundle);
+        let as_of = read_holds.least_valid_read();
+
+        let stats = self
+            .statistics_oracle(session, source_ids, &as_of, cluster_id, false)
+            .await;
+        drop(read_holds);
+
+        stats.unwrap_or_else(|e| {
+            warn!("failed to collect optimizer statistics: {e}");
+            Box::new(EmptyStatisticsOracle)
+        })
+    }
 }
 
 /// Checks whether we should emit diagnostic
@@ -5221,6 +5571,7 @@ impl Coordinator {
                 OptimizerNoticeKind::IndexKeyEmpty => {
                     system_vars.enable_notices_for_index_empty_key()
                 }
+                OptimizerNoticeKind::IndexWouldEnableFastPath => system_vars.enable_index_advisor(),
             };
             if notice_enabled {
                 // We don't need to redact the notice parts because
@@ -267,6 +267,16 @@ impl Coordinator {
             Reset => new_config.workload_class = None,
             Unchanged => {}
         }
+        match &options.arrangement_spill {
+            Set(spill) => new_config.arrangement_spill = *spill,
+            Reset => new_config.arrangement_spill = false,
+            Unchanged => {}
+        }
+        match &options.auto_index {
+            Set(auto_index) => new_config.auto_index = *auto_index,
+            Reset => new_config.auto_index = false,
+            Unchanged => {}
+        }
 
         if new_config == config {
             return Ok(StageResult::Response(ExecuteResponse::AlteredObject(
@@ -275,6 +285,7 @@ impl Coordinator {
         }
 
         let new_workload_class = new_config.workload_class.clone();
+        let new_arrangement_spill = new_config.arrangement_spill;
         match (&config.variant, &new_config.variant) {
             (Managed(_), Managed(new_config_managed)) => {
                 let alter_followup = self
@@ -359,6 +370,8 @@ impl Coordinator {
 
         self.controller
             .update_cluster_workload_class(cluster_id, new_workload_class)?;
+        self.controller
+            .update_cluster_arrangement_spill(cluster_id, new_arrangement_spill)?;
 
         Ok(StageResult::Response(ExecuteResponse::AlteredObject(
             ObjectType::Cluster,
@@ -377,6 +390,8 @@ impl Coordinator {
     ) -> Result<StageResult<Box<ClusterStage>>, AdapterError> {
         let cluster = self.catalog.get_cluster(cluster_id);
         let workload_class = cluster.config.workload_class.clone();
+        let arrangement_spill = cluster.config.arrangement_spill;
+        let auto_index = cluster.config.auto_index;
         let mut ops = vec![];
 
         // Gather the ops to remove the non pending replicas
@@ -475,6 +490,8 @@ impl Coordinator {
             config: ClusterConfig {
                 variant: ClusterVariant::Managed(new_config),
                 workload_class: workload_class.clone(),
+                arrangement_spill,
+                auto_index,
             },
         });
         self.catalog_transact(Some(session), ops).await?;
@@ -488,6 +505,8 @@ impl Coordinator {
 
         self.controller
             .update_cluster_workload_class(cluster_id, workload_class)?;
+        self.controller
+            .update_cluster_arrangement_spill(cluster_id, arrangement_spill)?;
 
         Ok(StageResult::Response(ExecuteResponse::AlteredObject(
             ObjectType::Cluster,
@@ -604,6 +623,8 @@ impl Coordinator {
             name,
             variant,
             workload_class,
+            arrangement_spill,
+            auto_index,
         }: CreateClusterPlan,
     ) -> Result<ExecuteResponse, AdapterError> {
         tracing::debug!("sequence_create_cluster");
@@ -640,6 +661,8 @@ impl Coordinator {
         let config = ClusterConfig {
             variant: cluster_variant,
             workload_class,
+            arrangement_spill,
+            auto_index,
         };
         let ops = vec![catalog::Op::CreateCluster {
             id,
@@ -937,6 +960,7 @@ impl Coordinator {
                 mz_controller::clusters::ClusterConfig {
                     arranged_logs: cluster.log_indexes.clone(),
                     workload_class: cluster.config.workload_class.clone(),
+                    arrangement_spill: cluster.config.arrangement_spill,
                 },
             )
             .expect("creating cluster must not fail");
@@ -76,6 +76,7 @@ impl Coordinator {
                 data_source: TableDataSource::TableWrites {
                     defaults: Vec::new(),
                 },
+                foreign_keys: Vec::new(),
             }),
             referenced_by: Vec::new(),
             used_by: Vec::new(),
@@ -288,6 +289,14 @@ impl OptimizerCatalog for ContinualTaskCatalogBootstrap {
     ) -> Box<dyn Iterator<Item = (GlobalId, &mz_catalog::memory::objects::Index)> + '_> {
         self.delegate.get_indexes_on(id, cluster)
     }
+
+    fn get_index(&self, id: &GlobalId) -> Option<&mz_catalog::memory::objects::Index> {
+        self.delegate.get_index(id)
+    }
+
+    fn get_foreign_keys_on(&self, id: GlobalId) -> Vec<mz_transform::ForeignKey> {
+        self.delegate.get_foreign_keys_on(id)
+    }
 }
 
 fn update_create_sql(
@@ -362,4 +371,12 @@ impl OptimizerCatalog for NoIndexCatalog {
     ) -> Box<dyn Iterator<Item = (GlobalId, &mz_catalog::memory::objects::Index)> + '_> {
         Box::new(std::iter::empty())
     }
+
+    fn get_index(&self, _id: &GlobalId) -> Option<&mz_catalog::memory::objects::Index> {
+        None
+    }
+
+    fn get_foreign_keys_on(&self, id: GlobalId) -> Vec<mz_transform::ForeignKey> {
+        self.delegate.get_foreign_keys_on(id)
+    }
 }
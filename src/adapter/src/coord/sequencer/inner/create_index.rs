@@ -9,7 +9,7 @@
 
 use std::collections::BTreeMap;
 
-use maplit::btreemap;
+use maplit::{btreemap, btreeset};
 use mz_catalog::memory::objects::{CatalogItem, Index};
 use mz_ore::instrument;
 use mz_repr::explain::{ExprHumanizerExt, TransientItem};
@@ -52,7 +52,9 @@ impl Staged for CreateIndexStage {
         ctx: &mut ExecuteContext,
     ) -> Result<StageResult<Box<Self>>, AdapterError> {
         match self {
-            CreateIndexStage::Optimize(stage) => coord.create_index_optimize(stage).await,
+            CreateIndexStage::Optimize(stage) => {
+                coord.create_index_optimize(ctx.session(), stage).await
+            }
             CreateIndexStage::Finish(stage) => {
                 coord.create_index_finish(ctx.session(), stage).await
             }
@@ -297,6 +299,7 @@ impl Coordinator {
     #[instrument]
     async fn create_index_optimize(
         &mut self,
+        session: &Session,
         CreateIndexOptimize {
             validity,
             plan,
@@ -305,7 +308,7 @@ impl Coordinator {
         }: CreateIndexOptimize,
     ) -> Result<StageResult<Box<CreateIndexStage>>, AdapterError> {
         let plan::CreateIndexPlan {
-            index: plan::Index { cluster_id, .. },
+            index: plan::Index { on, cluster_id, .. },
             ..
         } = &plan;
 
@@ -323,6 +326,9 @@ impl Coordinator {
         let optimizer_config = optimize::OptimizerConfig::from(self.catalog().system_config())
             .override_from(&self.catalog.get_cluster(*cluster_id).config.features())
             .override_from(&explain_ctx);
+        let stats = self
+            .ddl_statistics_oracle(session, &btreeset! {*on}, *cluster_id)
+            .await;
 
         // Build an optimizer for this INDEX.
         let mut optimizer = optimize::index::Optimizer::new(
@@ -331,7 +337,8 @@ impl Coordinator {
             global_id,
             optimizer_config,
             self.optimizer_metrics(),
-        );
+        )
+        .with_stats(stats);
         let span = Span::current();
         Ok(StageResult::Handle(mz_ore::task::spawn_blocking(
             || "optimize create index",
@@ -344,7 +351,7 @@ impl Coordinator {
                     let _dispatch_guard = explain_ctx.dispatch_guard();
 
                     let index_plan =
-                        optimize::index::Index::new(plan.name.clone(), plan.index.on, plan.index.keys.clone());
+                        optimize::index::Index::new(plan.name.clone(), plan.index.on, plan.index.keys.clone(), plan.index.kind);
 
                     // MIR ⇒ MIR optimization (global)
                     let global_mir_plan = optimizer.catch_unwind_optimize(index_plan)?;
@@ -424,6 +431,7 @@ impl Coordinator {
                             create_sql,
                             on,
                             keys,
+                            kind,
                             cluster_id,
                             compaction_window,
                         },
@@ -444,6 +452,7 @@ impl Coordinator {
                 create_sql,
                 global_id,
                 keys: keys.into(),
+                kind,
                 on,
                 conn_id: None,
                 resolved_ids,
@@ -13,6 +13,7 @@ use maplit::btreemap;
 use maplit::btreeset;
 use mz_adapter_types::compaction::CompactionWindow;
 use mz_catalog::memory::objects::{CatalogItem, MaterializedView};
+use mz_compute_types::plan::summary::PlanSummary;
 use mz_expr::{CollectionPlan, ResultSpec};
 use mz_ore::collections::CollectionExt;
 use mz_ore::instrument;
@@ -30,6 +31,7 @@ use mz_sql::session::metadata::SessionMetadata;
 use mz_sql_parser::ast;
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_storage_client::controller::{CollectionDescription, DataSource};
+use mz_transform::dataflow::DataflowMetainfo;
 use std::collections::BTreeMap;
 use timely::progress::Antichain;
 use tracing::Span;
@@ -46,6 +48,7 @@ use crate::error::AdapterError;
 use crate::explain::explain_dataflow;
 use crate::explain::explain_plan;
 use crate::explain::optimizer_trace::OptimizerTrace;
+use crate::explain::plan_diff::render_plan_diff;
 use crate::optimize::dataflows::dataflow_import_id_bundle;
 use crate::optimize::{self, Optimize};
 use crate::session::Session;
@@ -70,7 +73,9 @@ impl Staged for CreateMaterializedViewStage {
     ) -> Result<StageResult<Box<Self>>, AdapterError> {
         match self {
             CreateMaterializedViewStage::Optimize(stage) => {
-                coord.create_materialized_view_optimize(stage).await
+                coord
+                    .create_materialized_view_optimize(ctx.session(), stage)
+                    .await
             }
             CreateMaterializedViewStage::Finish(stage) => {
                 coord
@@ -408,6 +413,7 @@ impl Coordinator {
     #[instrument]
     async fn create_materialized_view_optimize(
         &mut self,
+        session: &Session,
         CreateMaterializedViewOptimize {
             validity,
             plan,
@@ -422,6 +428,7 @@ impl Coordinator {
                     column_names,
                     cluster_id,
                     non_null_assertions,
+                    expr,
                     refresh_schedule,
                     ..
                 },
@@ -445,6 +452,9 @@ impl Coordinator {
             .override_from(&self.catalog.get_cluster(*cluster_id).config.features())
             .override_from(&explain_ctx);
         let force_non_monotonic = Default::default();
+        let stats = self
+            .ddl_statistics_oracle(session, &expr.depends_on(), *cluster_id)
+            .await;
 
         // Build an optimizer for this MATERIALIZED VIEW.
         let mut optimizer = optimize::materialized_view::Optimizer::new(
@@ -459,7 +469,8 @@ impl Coordinator {
             optimizer_config,
             self.optimizer_metrics(),
             force_non_monotonic,
-        );
+        )
+        .with_stats(stats);
 
         let span = Span::current();
         Ok(StageResult::Handle(mz_ore::task::spawn_blocking(
@@ -487,12 +498,13 @@ impl Coordinator {
                     let stage = match pipeline() {
                         Ok((local_mir_plan, global_mir_plan, global_lir_plan)) => {
                             if let ExplainContext::Plan(explain_ctx) = explain_ctx {
-                                let (_, df_meta) = global_lir_plan.unapply();
+                                let (df_desc, df_meta) = global_lir_plan.unapply();
                                 CreateMaterializedViewStage::Explain(
                                     CreateMaterializedViewExplain {
                                         validity,
                                         global_id,
                                         plan,
+                                        df_desc: Some(df_desc),
                                         df_meta,
                                         explain_ctx,
                                     },
@@ -528,6 +540,7 @@ impl Coordinator {
                                         global_id,
                                         validity,
                                         plan,
+                                        df_desc: None,
                                         df_meta: Default::default(),
                                         explain_ctx,
                                     },
@@ -829,12 +842,14 @@ impl Coordinator {
                         },
                     ..
                 },
+            df_desc,
             df_meta,
             explain_ctx:
                 ExplainPlanContext {
                     config,
                     format,
                     stage,
+                    replan,
                     optimizer_trace,
                     ..
                 },
@@ -859,6 +874,59 @@ impl Coordinator {
             .override_from(&target_cluster.config.features())
             .override_from(&config.features);
 
+        if stage == ExplainStage::PlanDiff {
+            let (Some(id), Some(df_desc)) = (replan, df_desc) else {
+                coord_bail!("cannot EXPLAIN {stage} without a stored plan");
+            };
+            let Some(old_desc) = self.catalog().try_get_physical_plan(&id).cloned() else {
+                tracing::error!("cannot find physical plan for materialized view {id} in catalog");
+                coord_bail!("cannot find physical plan for materialized view in catalog");
+            };
+            let Some(old_meta) = self.catalog().try_get_dataflow_metainfo(&id) else {
+                coord_bail!("cannot find dataflow metainformation for materialized view {id}");
+            };
+
+            // Leave out optimizer notices from both renderings, so that they
+            // don't show up as plan changes.
+            let old_meta = DataflowMetainfo {
+                optimizer_notices: Vec::new(),
+                index_usage_types: old_meta.index_usage_types.clone(),
+                statistics: old_meta.statistics.clone(),
+            };
+            let new_meta = DataflowMetainfo {
+                optimizer_notices: Vec::new(),
+                index_usage_types: df_meta.index_usage_types,
+                statistics: df_meta.statistics,
+            };
+
+            let summary_diff =
+                PlanSummary::from_dataflow(&old_desc).diff(&PlanSummary::from_dataflow(&df_desc));
+            let old_plan = explain_dataflow(
+                old_desc,
+                format,
+                &config,
+                &features,
+                &session_catalog,
+                BTreeMap::new(),
+                Some(target_cluster.name.as_str()),
+                &old_meta,
+            )?;
+            let new_plan = explain_dataflow(
+                df_desc,
+                format,
+                &config,
+                &features,
+                &expr_humanizer,
+                BTreeMap::new(),
+                Some(target_cluster.name.as_str()),
+                &new_meta,
+            )?;
+
+            let diff = render_plan_diff(&summary_diff, &session_catalog, &old_plan, &new_plan);
+            let row = Row::pack_slice(&[Datum::from(diff.as_str())]);
+            return Ok(StageResult::Response(Self::send_immediate_rows(row)));
+        }
+
         let rows = optimizer_trace
             .into_rows(
                 format,
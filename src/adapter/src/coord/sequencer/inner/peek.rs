@@ -10,6 +10,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Instant;
 
 use http::Uri;
 use itertools::Either;
@@ -36,8 +37,10 @@ use tracing::{Instrument, Span};
 
 use crate::active_compute_sink::{ActiveComputeSink, ActiveCopyTo};
 use crate::command::ExecuteResponse;
+use crate::coord::auto_index::query_shapes;
 use crate::coord::id_bundle::CollectionIdBundle;
-use crate::coord::peek::{self, PeekDataflowPlan, PeekPlan, PlannedPeek};
+use crate::coord::peek::{self, FastPathPlan, PeekDataflowPlan, PeekPlan, PlannedPeek};
+use crate::coord::read_policy::ReadHolds;
 use crate::coord::sequencer::inner::{check_log_reads, return_if_err};
 use crate::coord::timeline::TimelineContext;
 use crate::coord::timestamp_selection::{
@@ -282,9 +285,11 @@ impl Coordinator {
             .instance_snapshot(cluster.id())
             .expect("compute instance does not exist");
         let (_, view_id) = self.allocate_transient_id();
-        let optimizer_config = optimize::OptimizerConfig::from(self.catalog().system_config())
+        let mut optimizer_config = optimize::OptimizerConfig::from(self.catalog().system_config())
             .override_from(&self.catalog.get_cluster(cluster.id()).config.features())
             .override_from(&explain_ctx);
+        optimizer_config.approximate_nearest_neighbors =
+            session.vars().enable_approximate_nearest_neighbors();
 
         if cluster.replicas().next().is_none() && explain_ctx.needs_cluster() {
             return Err(AdapterError::NoClusterReplicasAvailable {
@@ -473,7 +478,7 @@ impl Coordinator {
             timeline_context,
             oracle_read_ts,
             real_time_recency_ts,
-            optimizer,
+            mut optimizer,
             explain_ctx,
         }: PeekStageTimestampReadHold,
     ) -> Result<StageResult<Box<PeekStage>>, AdapterError> {
@@ -504,6 +509,24 @@ impl Coordinator {
             (&explain_ctx).into(),
         )?;
 
+        // Offer the automatic indexes that are readable at the chosen
+        // timestamp to the optimizer. They are deliberately kept out of
+        // `id_bundle`, which must only contain catalog objects.
+        let mut auto_index_holds = ReadHolds::new();
+        if optimizer.is_left()
+            && matches!(explain_ctx, ExplainContext::None)
+            && self.auto_index_enabled(cluster_id)
+        {
+            let timestamp = determination.timestamp_context.timestamp_or_default();
+            let (indexes, read_holds) =
+                self.auto_indexes_for_peek(cluster_id, &source_ids, timestamp);
+            for index in indexes {
+                optimizer =
+                    optimizer.map_left(|optimizer| optimizer.with_hypothetical_index(index));
+            }
+            auto_index_holds = read_holds;
+        }
+
         let stage = PeekStage::Optimize(PeekStageOptimize {
             validity,
             plan,
@@ -514,6 +537,7 @@ impl Coordinator {
             determination,
             optimizer,
             explain_ctx,
+            auto_index_holds,
         });
         Ok(StageResult::Immediate(Box::new(stage)))
     }
@@ -532,15 +556,28 @@ impl Coordinator {
             determination,
             mut optimizer,
             explain_ctx,
+            auto_index_holds,
         }: PeekStageOptimize,
     ) -> Result<StageResult<Box<PeekStage>>, AdapterError> {
         // Generate data structures that can be moved to another task where we will perform possibly
         // expensive optimizations.
         let timestamp_context = determination.timestamp_context.clone();
+        let cluster_id = match optimizer.as_ref() {
+            Either::Left(optimizer) => optimizer.cluster_id(),
+            Either::Right(optimizer) => optimizer.cluster_id(),
+        };
         let stats = self
-            .statistics_oracle(session, &source_ids, &timestamp_context.antichain(), true)
+            .statistics_oracle(
+                session,
+                &source_ids,
+                &timestamp_context.antichain(),
+                cluster_id,
+                true,
+            )
             .await
             .unwrap_or_else(|_| Box::new(EmptyStatisticsOracle));
+        let collect_auto_index_shapes =
+            matches!(explain_ctx, ExplainContext::None) && self.auto_index_enabled(cluster_id);
         let session = session.meta();
         let now = self.catalog().config().now.clone();
         let catalog = self.owned_catalog();
@@ -559,6 +596,7 @@ impl Coordinator {
             || "optimize peek",
             move || {
                 span.in_scope(|| {
+                    let mut auto_index_shapes = Vec::new();
                     let pipeline = || -> Result<Either<optimize::peek::GlobalLirPlan, optimize::copy_to::GlobalLirPlan>, AdapterError> {
                         let _dispatch_guard = explain_ctx.dispatch_guard();
 
@@ -569,6 +607,9 @@ impl Coordinator {
                             Either::Left(optimizer) => {
                                 // HIR ⇒ MIR lowering and MIR optimization (local)
                                 let local_mir_plan = optimizer.catch_unwind_optimize(raw_expr)?;
+                                if collect_auto_index_shapes {
+                                    auto_index_shapes = query_shapes(cluster_id, local_mir_plan.expr());
+                                }
                                 // Attach resolved context required to continue the pipeline.
                                 let local_mir_plan = local_mir_plan.resolve(timestamp_context.clone(), &session, stats);
                                 // MIR optimization (global), MIR ⇒ LIR lowering, and LIR optimization (global)
@@ -651,6 +692,8 @@ impl Coordinator {
                                         global_lir_plan,
                                         optimization_finished_at,
                                         insights_ctx,
+                                        auto_index_holds,
+                                        auto_index_shapes,
                                     })
                                 }
                                 ExplainContext::None => PeekStage::Finish(PeekStageFinish {
@@ -667,6 +710,8 @@ impl Coordinator {
                                     global_lir_plan,
                                     optimization_finished_at,
                                     insights_ctx,
+                                    auto_index_holds,
+                                    auto_index_shapes,
                                 }),
                                 ExplainContext::Pushdown => {
                                     let (plan, _, _) = global_lir_plan.unapply();
@@ -821,6 +866,8 @@ impl Coordinator {
             global_lir_plan,
             optimization_finished_at,
             insights_ctx,
+            auto_index_holds,
+            auto_index_shapes,
         }: PeekStageFinish,
     ) -> Result<StageResult<Box<PeekStage>>, AdapterError> {
         if let Some(id) = ctx.extra.contents() {
@@ -837,6 +884,26 @@ impl Coordinator {
         let (peek_plan, df_meta, typ) = global_lir_plan.unapply();
         let source_arity = typ.arity();
 
+        match &peek_plan {
+            // Automatic indexes can be dropped while the peek was optimized.
+            PeekPlan::FastPath(FastPathPlan::PeekExisting(_, index_id, ..))
+                if index_id.is_transient() =>
+            {
+                if !self.auto_indexes.touch(*index_id, Instant::now()) {
+                    return Err(AdapterError::ChangedPlan(
+                        "automatic index dropped while planning the peek".into(),
+                    ));
+                }
+            }
+            PeekPlan::SlowPath(_) if !auto_index_shapes.is_empty() => {
+                if let Some(fingerprint) = ctx.extra().fingerprint() {
+                    self.auto_index_observe(cluster_id, fingerprint, auto_index_shapes)
+                        .await;
+                }
+            }
+            _ => {}
+        }
+
         self.emit_optimizer_notices(&*session, &df_meta.optimizer_notices);
 
         let target_cluster = self.catalog().get_cluster(cluster_id);
@@ -933,6 +1000,8 @@ impl Coordinator {
                 max_query_result_size,
             )
             .await?;
+        // The peek holds its own read holds from here on.
+        drop(auto_index_holds);
 
         if ctx.session().vars().emit_timestamp_notice() {
             let explanation =
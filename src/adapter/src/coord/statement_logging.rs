@@ -41,13 +41,20 @@ use crate::statement_logging::{
 
 use super::Message;
 
+/// The fingerprint of a statement: the SHA-256 hash of its redacted SQL text, as recorded in
+/// `mz_sql_text`. Statements that only differ in their literals share a fingerprint.
+pub type StatementFingerprint = [u8; 32];
+
 /// Metadata required for logging a prepared statement.
 #[derive(Debug)]
 pub enum PreparedStatementLoggingInfo {
     /// The statement has already been logged; we don't need to log it
     /// again if a future execution hits the sampling rate; we merely
     /// need to reference the corresponding UUID.
-    AlreadyLogged { uuid: Uuid },
+    AlreadyLogged {
+        uuid: Uuid,
+        fingerprint: StatementFingerprint,
+    },
     /// The statement has not yet been logged; if a future execution
     /// hits the sampling rate, we need to log it at that point.
     StillToLog {
@@ -56,6 +63,8 @@ pub enum PreparedStatementLoggingInfo {
         /// The SQL text of the statement, redacted to follow our data management
         /// policy
         redacted_sql: String,
+        /// The fingerprint of the statement
+        fingerprint: StatementFingerprint,
         /// When the statement was prepared
         prepared_at: EpochMillis,
         /// The name with which the statement was prepared
@@ -92,10 +101,13 @@ impl PreparedStatementLoggingInfo {
             }
             _ => raw_sql,
         };
+        let redacted_sql = stmt.map(|s| s.to_ast_string_redacted()).unwrap_or_default();
+        let fingerprint = Sha256::digest(redacted_sql.as_bytes()).into();
 
         PreparedStatementLoggingInfo::StillToLog {
             sql,
-            redacted_sql: stmt.map(|s| s.to_ast_string_redacted()).unwrap_or_default(),
+            redacted_sql,
+            fingerprint,
             prepared_at,
             name,
             session_id,
@@ -104,6 +116,14 @@ impl PreparedStatementLoggingInfo {
             _sealed: sealed::Private,
         }
     }
+
+    /// Returns the fingerprint of the statement.
+    pub fn fingerprint(&self) -> StatementFingerprint {
+        match self {
+            PreparedStatementLoggingInfo::AlreadyLogged { fingerprint, .. }
+            | PreparedStatementLoggingInfo::StillToLog { fingerprint, .. } => *fingerprint,
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, Ord, Eq, PartialOrd, PartialEq)]
@@ -306,10 +326,11 @@ impl Coordinator {
         let mut out = None;
 
         let uuid = match logging {
-            PreparedStatementLoggingInfo::AlreadyLogged { uuid } => *uuid,
+            PreparedStatementLoggingInfo::AlreadyLogged { uuid, .. } => *uuid,
             PreparedStatementLoggingInfo::StillToLog {
                 sql,
                 redacted_sql,
+                fingerprint,
                 prepared_at,
                 name,
                 session_id,
@@ -359,7 +380,8 @@ impl Coordinator {
                     },
                 ));
 
-                *logging = PreparedStatementLoggingInfo::AlreadyLogged { uuid };
+                let fingerprint = *fingerprint;
+                *logging = PreparedStatementLoggingInfo::AlreadyLogged { uuid, fingerprint };
                 uuid
             }
         };
@@ -28,10 +28,7 @@ use mz_repr::{CatalogItemId, GlobalId, Timestamp};
 use mz_sql::names::{ResolvedDatabaseSpecifier, SchemaSpecifier};
 use mz_storage_types::sources::Timeline;
 use mz_timestamp_oracle::batching_oracle::BatchingTimestampOracle;
-use mz_timestamp_oracle::postgres_oracle::{
-    PostgresTimestampOracle, PostgresTimestampOracleConfig,
-};
-use mz_timestamp_oracle::{self, TimestampOracle, WriteTimestamp};
+use mz_timestamp_oracle::{self, TimestampOracle, TimestampOracleConfig, WriteTimestamp};
 use timely::progress::Timestamp as TimelyTimestamp;
 use tracing::{Instrument, debug, error, info};
 
@@ -200,7 +197,7 @@ impl Coordinator {
             timeline,
             Timestamp::minimum(),
             self.catalog().config().now.clone(),
-            self.pg_timestamp_oracle_config.clone(),
+            self.timestamp_oracle_config.clone(),
             &mut self.global_timelines,
             self.read_only_controllers,
         )
@@ -214,13 +211,13 @@ impl Coordinator {
         timeline: &'a Timeline,
         initially: Timestamp,
         now: NowFn,
-        pg_oracle_config: Option<PostgresTimestampOracleConfig>,
+        oracle_config: Option<TimestampOracleConfig>,
         global_timelines: &'a mut BTreeMap<Timeline, TimelineState<Timestamp>>,
         read_only: bool,
     ) -> &'a mut TimelineState<Timestamp> {
         if !global_timelines.contains_key(timeline) {
             info!(
-                "opening a new CRDB/postgres TimestampOracle for timeline {:?}",
+                "opening a new durable TimestampOracle for timeline {:?}",
                 timeline,
             );
 
@@ -238,23 +235,17 @@ impl Coordinator {
                 NowFn::from(|| Timestamp::minimum().into())
             };
 
-            let pg_oracle_config = pg_oracle_config.expect(
-                        "missing --timestamp-oracle-url even though the crdb-backed timestamp oracle was configured");
+            let oracle_config = oracle_config.expect(
+                "missing --timestamp-oracle-url even though the durable timestamp oracle was configured",
+            );
 
-            let batching_metrics = Arc::clone(&pg_oracle_config.metrics);
+            let batching_metrics = Arc::clone(oracle_config.metrics());
 
-            let pg_oracle: Arc<dyn TimestampOracle<mz_repr::Timestamp> + Send + Sync> = Arc::new(
-                PostgresTimestampOracle::open(
-                    pg_oracle_config,
-                    timeline.to_string(),
-                    initially,
-                    now_fn,
-                    read_only,
-                )
-                .await,
-            );
+            let durable_oracle = oracle_config
+                .open(timeline.to_string(), initially, now_fn, read_only)
+                .await;
 
-            let batching_oracle = BatchingTimestampOracle::new(batching_metrics, pg_oracle);
+            let batching_oracle = BatchingTimestampOracle::new(batching_metrics, durable_oracle);
 
             let oracle: Arc<dyn TimestampOracle<mz_repr::Timestamp> + Send + Sync> =
                 Arc::new(batching_oracle);
@@ -374,7 +365,10 @@ impl Coordinator {
                         | CatalogItem::Sink(_)
                         | CatalogItem::Type(_)
                         | CatalogItem::Func(_)
+                        | CatalogItem::UserFunc(_)
                         | CatalogItem::Secret(_)
+                        | CatalogItem::Publication(_)
+                        | CatalogItem::Notification(_)
                         | CatalogItem::Connection(_)
                         | CatalogItem::Log(_) => {}
                     }
@@ -527,7 +521,10 @@ impl Coordinator {
                     CatalogItem::Sink(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
+                    | CatalogItem::UserFunc(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Publication(_)
+                    | CatalogItem::Notification(_)
                     | CatalogItem::Connection(_) => {}
                 }
             }
@@ -24,6 +24,7 @@ use mz_pgwire_common::{ErrorResponse, Severity};
 use mz_repr::adt::timestamp::TimestampError;
 use mz_repr::explain::ExplainError;
 use mz_repr::{NotNullViolation, Timestamp};
+use mz_sql::catalog::CatalogError as SqlCatalogError;
 use mz_sql::plan::PlanError;
 use mz_sql::rbac;
 use mz_sql::session::vars::VarError;
@@ -118,6 +119,18 @@ pub enum AdapterError {
     ReadWriteUnavailable,
     /// The recursion limit of some operation was exceeded.
     RecursionLimit(RecursionLimitError),
+    /// A replication slot is streaming to another connection.
+    ReplicationSlotActive {
+        name: String,
+        pid: u32,
+    },
+    /// A replication slot cannot resume at the requested position because
+    /// the collections it streams from have been compacted past it.
+    ReplicationSlotPositionUnavailable {
+        name: String,
+        lsn: Timestamp,
+        since: Antichain<Timestamp>,
+    },
     /// A query in a transaction referenced a relation outside the first query's
     /// time domain.
     RelationOutsideTimeDomain {
@@ -341,6 +354,10 @@ impl AdapterError {
             AdapterError::UnallowedOnCluster { cluster, .. } => (cluster == MZ_CATALOG_SERVER_CLUSTER.name).then(||
                 format!("The transaction is executing on the {cluster} cluster, maybe having been routed there by the first statement in the transaction.")
             ),
+            AdapterError::ReplicationSlotPositionUnavailable { since, .. } => Some(format!(
+                "The collections the slot streams from are only readable at or after {:?}.",
+                since.elements(),
+            )),
             AdapterError::InputNotReadableAtRefreshAtTime(oracle_read_ts, least_valid_read) => {
                 Some(format!(
                     "The requested REFRESH AT time is {}, \
@@ -453,6 +470,12 @@ impl AdapterError {
                     VarError::RequiresUnsafeMode { .. } => SqlState::CANT_CHANGE_RUNTIME_PARAM,
                     VarError::RequiresFeatureFlag { .. } => SqlState::CANT_CHANGE_RUNTIME_PARAM,
                 },
+                mz_catalog::memory::error::ErrorKind::Sql(
+                    SqlCatalogError::ReplicationSlotAlreadyExists(_),
+                ) => SqlState::DUPLICATE_OBJECT,
+                mz_catalog::memory::error::ErrorKind::Sql(
+                    SqlCatalogError::UnknownReplicationSlot(_),
+                ) => SqlState::UNDEFINED_OBJECT,
                 _ => SqlState::INTERNAL_ERROR,
             },
             AdapterError::ChangedPlan(_) => SqlState::FEATURE_NOT_SUPPORTED,
@@ -467,6 +490,9 @@ impl AdapterError {
             AdapterError::Eval(EvalError::NullCharacterNotPermitted) => {
                 SqlState::PROGRAM_LIMIT_EXCEEDED
             }
+            AdapterError::Eval(EvalError::WasmUdfError { .. }) => {
+                SqlState::EXTERNAL_ROUTINE_EXCEPTION
+            }
             AdapterError::Eval(_) => SqlState::INTERNAL_ERROR,
             AdapterError::Explain(_) => SqlState::INTERNAL_ERROR,
             AdapterError::IdExhaustionError => SqlState::INTERNAL_ERROR,
@@ -507,6 +533,10 @@ impl AdapterError {
                 SqlState::IDLE_IN_TRANSACTION_SESSION_TIMEOUT
             }
             AdapterError::RecursionLimit(_) => SqlState::INTERNAL_ERROR,
+            AdapterError::ReplicationSlotActive { .. } => SqlState::OBJECT_IN_USE,
+            AdapterError::ReplicationSlotPositionUnavailable { .. } => {
+                SqlState::OBJECT_NOT_IN_PREREQUISITE_STATE
+            }
             AdapterError::RelationOutsideTimeDomain { .. } => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::ResourceExhaustion { .. } => SqlState::INSUFFICIENT_RESOURCES,
             AdapterError::ResultSize(_) => SqlState::OUT_OF_MEMORY,
@@ -680,6 +710,20 @@ impl fmt::Display for AdapterError {
                 )
             }
             AdapterError::RecursionLimit(e) => e.fmt(f),
+            AdapterError::ReplicationSlotActive { name, pid } => {
+                write!(
+                    f,
+                    "replication slot {} is active for PID {pid}",
+                    name.quoted()
+                )
+            }
+            AdapterError::ReplicationSlotPositionUnavailable { name, lsn, .. } => {
+                write!(
+                    f,
+                    "replication slot {} cannot stream changes after {lsn}",
+                    name.quoted()
+                )
+            }
             AdapterError::RelationOutsideTimeDomain { .. } => {
                 write!(
                     f,
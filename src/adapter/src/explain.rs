@@ -33,6 +33,7 @@ pub(crate) mod insights;
 pub(crate) mod lir;
 pub(crate) mod mir;
 pub(crate) mod optimizer_trace;
+pub(crate) mod plan_diff;
 
 /// Newtype struct for wrapping types that should
 /// implement the [`mz_repr::explain::Explain`] trait.
@@ -53,7 +54,7 @@ pub(crate) fn explain_dataflow<T>(
     config: &ExplainConfig,
     features: &OptimizerFeatures,
     humanizer: &dyn ExprHumanizer,
-    cardinality_stats: BTreeMap<GlobalId, usize>,
+    mut cardinality_stats: BTreeMap<GlobalId, usize>,
     target_cluster: Option<&str>,
     dataflow_metainfo: &DataflowMetainfo<Arc<OptimizerNotice>>,
 ) -> Result<String, AdapterError>
@@ -63,6 +64,10 @@ where
     // Collect the list of indexes used by the dataflow at this point.
     let used_indexes = dataflow_metainfo.used_indexes(&plan);
 
+    // Collect the statistics the dataflow was optimized with.
+    let used_statistics = dataflow_metainfo.used_statistics();
+    cardinality_stats.extend(dataflow_metainfo.cardinality_stats());
+
     let optimizer_notices = OptimizerNotice::explain(
         &dataflow_metainfo.optimizer_notices,
         humanizer,
@@ -76,6 +81,7 @@ where
         humanizer,
         cardinality_stats,
         used_indexes,
+        used_statistics,
         finishing: Default::default(),
         duration: Default::default(),
         target_cluster,
@@ -110,6 +116,7 @@ where
         humanizer,
         cardinality_stats,
         used_indexes: Default::default(),
+        used_statistics: Default::default(),
         finishing: Default::default(),
         duration: Default::default(),
         target_cluster,
@@ -137,7 +137,9 @@ impl PlanInsights {
                         Some(ctx.optimizer_config.features.persist_fast_path_limit);
                     continue;
                 }
-                let idx_name = if let FastPathPlan::PeekExisting(_, idx_id, _, _) = plan {
+                let idx_name = if let FastPathPlan::PeekExisting(_, idx_id, _, _)
+                | FastPathPlan::PeekNearest(_, idx_id, _, _) = plan
+                {
                     let idx_entry = ctx.catalog.get_entry_by_global_id(&idx_id);
                     Some(FastPathCluster {
                         index: structured_name(humanizer, idx_id),
@@ -210,7 +212,7 @@ fn fast_path_insights(humanizer: &dyn ExprHumanizer, plan: FastPathPlan) -> Plan
     let mut insights = PlanInsights::default();
     match plan {
         FastPathPlan::Constant { .. } => (),
-        FastPathPlan::PeekExisting(_, id, _, _) => {
+        FastPathPlan::PeekExisting(_, id, _, _) | FastPathPlan::PeekNearest(_, id, _, _) => {
             add_import_insights(&mut insights, humanizer, id, ImportType::Compute)
         }
         FastPathPlan::PeekPersist(id, _, _) => {
@@ -351,6 +351,7 @@ impl OptimizerTrace {
             humanizer,
             cardinality_stats: Default::default(), // empty stats
             used_indexes: Default::default(),
+            used_statistics: Default::default(),
             finishing: row_set_finishing.clone(),
             duration: Default::default(),
             target_cluster,
@@ -372,8 +373,9 @@ impl OptimizerTrace {
             config,
             features,
             humanizer,
-            cardinality_stats: Default::default(), // empty stats
+            cardinality_stats: dataflow_metainfo.cardinality_stats(),
             used_indexes: Default::default(),
+            used_statistics: dataflow_metainfo.used_statistics(),
             finishing: row_set_finishing,
             duration: Default::default(),
             target_cluster,
@@ -0,0 +1,91 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! `EXPLAIN PLAN DIFF` support.
+
+use mz_compute_types::plan::summary::PlanSummaryDiff;
+use mz_repr::GlobalId;
+use mz_repr::explain::ExprHumanizer;
+use similar::{ChangeTag, TextDiff};
+
+/// Renders the difference between two physical plans.
+///
+/// The output starts with the changes to the join implementations and the
+/// arrangements, which are the ones most likely to affect resource usage,
+/// followed by a line diff of the rendered plans.
+pub(crate) fn render_plan_diff(
+    summary_diff: &PlanSummaryDiff,
+    humanizer: &dyn ExprHumanizer,
+    old_plan: &str,
+    new_plan: &str,
+) -> String {
+    let humanize = |id: &GlobalId| {
+        humanizer
+            .humanize_id(*id)
+            .unwrap_or_else(|| "[DELETED INDEX]".to_owned())
+    };
+
+    let mut lines = vec!["Join Implementations:".to_string()];
+    if summary_diff.joins_changed() {
+        lines.extend(
+            summary_diff
+                .removed_joins
+                .iter()
+                .map(|j| format!("  - {j}")),
+        );
+        lines.extend(summary_diff.added_joins.iter().map(|j| format!("  + {j}")));
+    } else {
+        lines.push("  (unchanged)".to_string());
+    }
+
+    lines.push("Arrangements:".to_string());
+    if summary_diff.arrangements_changed() {
+        lines.extend(
+            summary_diff
+                .removed_arrangements
+                .iter()
+                .map(|k| format!("  - {k}")),
+        );
+        lines.extend(
+            summary_diff
+                .added_arrangements
+                .iter()
+                .map(|k| format!("  + {k}")),
+        );
+        lines.extend(
+            summary_diff
+                .removed_indexes
+                .iter()
+                .map(|id| format!("  - index {}", humanize(id))),
+        );
+        lines.extend(
+            summary_diff
+                .added_indexes
+                .iter()
+                .map(|id| format!("  + index {}", humanize(id))),
+        );
+    } else {
+        lines.push("  (unchanged)".to_string());
+    }
+
+    lines.push("Physical Plan:".to_string());
+    for change in TextDiff::from_lines(old_plan, new_plan).iter_all_changes() {
+        let sign = match change.tag() {
+            ChangeTag::Delete => '-',
+            ChangeTag::Insert => '+',
+            ChangeTag::Equal => ' ',
+        };
+        let line = format!("  {sign} {}", change.value());
+        lines.push(line.trim_end().to_string());
+    }
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    out
+}
@@ -26,6 +26,7 @@ use mz_timestamp_oracle::postgres_oracle::PostgresTimestampOracleParameters;
 pub fn compute_config(config: &SystemVars) -> ComputeParameters {
     ComputeParameters {
         workload_class: None,
+        enable_arrangement_spill: None,
         max_result_size: Some(config.max_result_size()),
         tracing: tracing_config(config),
         grpc_client: grpc_client_config(config),
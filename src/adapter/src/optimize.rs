@@ -63,7 +63,7 @@ pub mod view;
 use std::fmt::Debug;
 
 use mz_adapter_types::connection::ConnectionId;
-use mz_adapter_types::dyncfgs::PERSIST_FAST_PATH_ORDER;
+use mz_adapter_types::dyncfgs::{ENABLE_PEEK_DATAFLOW_CACHE, PERSIST_FAST_PATH_ORDER};
 use mz_catalog::memory::objects::{CatalogCollectionEntry, CatalogEntry, Index};
 use mz_compute_types::dataflows::DataflowDescription;
 use mz_compute_types::plan::Plan;
@@ -76,7 +76,7 @@ use mz_repr::{CatalogItemId, GlobalId};
 use mz_sql::names::{FullItemName, QualifiedItemName};
 use mz_sql::plan::PlanError;
 use mz_sql::session::vars::SystemVars;
-use mz_transform::{MaybeShouldPanic, TransformCtx, TransformError};
+use mz_transform::{ForeignKey, MaybeShouldPanic, TransformCtx, TransformError};
 
 // Alias types
 // -----------
@@ -181,9 +181,15 @@ pub struct OptimizerConfig {
     /// Show the slow path plan even if a fast path plan was created. Useful for debugging.
     /// Enforced if `timing` is set.
     pub no_fast_path: bool,
+    /// Answer peeks for the nearest rows to a vector approximately from ANN indexes, which may
+    /// miss some of the nearest rows. Bound from the session.
+    pub approximate_nearest_neighbors: bool,
     // If set, allow some additional queries down the Persist fast path when we believe
     // the orderings are compatible.
     persist_fast_path_order: bool,
+    // If set, plan slow-path peek dataflows such that they can be kept around to
+    // serve later peeks of the same plan.
+    keep_peek_dataflows: bool,
     /// Optimizer feature flags.
     pub features: OptimizerFeatures,
 }
@@ -202,7 +208,9 @@ impl From<&SystemVars> for OptimizerConfig {
             mode: OptimizeMode::Execute,
             replan: None,
             no_fast_path: false,
+            approximate_nearest_neighbors: false,
             persist_fast_path_order: PERSIST_FAST_PATH_ORDER.get(vars.dyncfgs()),
+            keep_peek_dataflows: ENABLE_PEEK_DATAFLOW_CACHE.get(vars.dyncfgs()),
             features: OptimizerFeatures::from(vars),
         }
     }
@@ -241,6 +249,7 @@ impl From<&OptimizerConfig> for mz_sql::plan::HirToMirConfig {
         Self {
             enable_new_outer_join_lowering: config.features.enable_new_outer_join_lowering,
             enable_variadic_left_join_lowering: config.features.enable_variadic_left_join_lowering,
+            enable_join_elimination: config.features.enable_join_elimination,
         }
     }
 }
@@ -264,6 +273,13 @@ pub trait OptimizerCatalog: Debug + Send + Sync {
         id: GlobalId,
         cluster: ClusterId,
     ) -> Box<dyn Iterator<Item = (GlobalId, &Index)> + '_>;
+
+    /// Returns the index with the given id, if it is known in the catalog.
+    fn get_index(&self, id: &GlobalId) -> Option<&Index>;
+
+    /// Returns the foreign keys declared on the given object, once for each
+    /// [`GlobalId`] of the referenced object.
+    fn get_foreign_keys_on(&self, id: GlobalId) -> Vec<ForeignKey>;
 }
 
 // OptimizerError
@@ -323,6 +323,7 @@ impl<'s> Optimize<LocalMirPlan<Resolved<'s>>> for Optimizer {
         let mut transform_ctx = TransformCtx::global(
             &df_builder,
             &*stats,
+            &df_builder,
             &self.config.features,
             &self.typecheck_ctx,
             &mut df_meta,
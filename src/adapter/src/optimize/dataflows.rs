@@ -42,6 +42,7 @@ use mz_sql::rbac;
 use mz_sql::session::metadata::SessionMetadata;
 use mz_transform::analysis::DerivedBuilder;
 use mz_transform::analysis::monotonic::Monotonic;
+use mz_transform::lexeme_constraints::{LEXEME_INDEX_KEY, lexeme_collection};
 
 use crate::catalog::CatalogState;
 use crate::coord::id_bundle::CollectionIdBundle;
@@ -232,6 +233,32 @@ impl<'a> DataflowBuilder<'a> {
                     _ => unreachable!(),
                 }
             }
+
+            // A lexeme index is imported as an index on itself, which arranges the lexemes of
+            // `id`. `LexemeConstraints` plans lookups into it, and pruning removes it otherwise.
+            let lexeme_indexes: Vec<_> = self
+                .lexeme_indexes_on(*id)
+                .map(|(index_id, idx)| (index_id, idx.keys[0].clone()))
+                .collect();
+            if !lexeme_indexes.is_empty() {
+                let entry = self.catalog.get_entry(id);
+                let desc = entry
+                    .desc(
+                        &self
+                            .catalog
+                            .resolve_full_name(entry.name(), entry.conn_id()),
+                    )
+                    .expect("indexes can only be built on items with descs");
+                for (index_id, document) in lexeme_indexes {
+                    let input = MirRelationExpr::global_get(*id, desc.typ().clone());
+                    let typ = lexeme_collection(input, document).typ();
+                    let index_desc = IndexDesc {
+                        on_id: index_id,
+                        key: LEXEME_INDEX_KEY.clone(),
+                    };
+                    dataflow.import_index(index_id, index_desc, typ, monotonic);
+                }
+            }
             Ok(())
         })
     }
@@ -393,6 +420,8 @@ impl<'a> DataflowBuilder<'a> {
                     self.monotonic_object_inner(*on, memo, features)
                 }
                 CatalogItem::Secret(_)
+                | CatalogItem::Publication(_)
+                | CatalogItem::Notification(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Connection(_)
                 | CatalogItem::Table(_)
@@ -400,6 +429,7 @@ impl<'a> DataflowBuilder<'a> {
                 | CatalogItem::MaterializedView(_)
                 | CatalogItem::Sink(_)
                 | CatalogItem::Func(_)
+                | CatalogItem::UserFunc(_)
                 | CatalogItem::ContinualTask(_) => Ok(false),
             }
         })?;
@@ -25,20 +25,23 @@
 //!
 //! See also MaterializeInc/materialize#22940.
 
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 use std::time::{Duration, Instant};
 
 use mz_compute_types::dataflows::IndexDesc;
 use mz_compute_types::plan::Plan;
+use mz_expr::{MirRelationExpr, MirScalarExpr, OptimizedMirRelationExpr, UnaryFunc, func};
 use mz_repr::GlobalId;
 use mz_repr::explain::trace_plan;
 use mz_sql::names::QualifiedItemName;
 use mz_sql::optimizer_metrics::OptimizerMetrics;
-use mz_transform::TransformCtx;
+use mz_sql::plan::IndexKind;
 use mz_transform::dataflow::DataflowMetainfo;
+use mz_transform::lexeme_constraints::{LEXEME_INDEX_KEY, lexeme_collection};
 use mz_transform::normalize_lets::normalize_lets;
 use mz_transform::notice::{IndexAlreadyExists, IndexKeyEmpty};
 use mz_transform::typecheck::{SharedContext as TypecheckContext, empty_context};
+use mz_transform::{EmptyStatisticsOracle, StatisticsOracle, TransformCtx};
 
 use crate::optimize::dataflows::{
     ComputeInstanceSnapshot, DataflowBuilder, ExprPrepStyle, prep_relation_expr, prep_scalar_expr,
@@ -48,6 +51,20 @@ use crate::optimize::{
     OptimizerConfig, OptimizerError, trace_plan,
 };
 
+/// The key of the arrangement that an ANN index maintains: the bucket column of
+/// [`ann_collection`].
+pub(crate) static ANN_INDEX_KEY: LazyLock<Vec<MirScalarExpr>> =
+    LazyLock::new(|| vec![MirScalarExpr::column(0)]);
+
+/// Returns the collection that an ANN index on `embedding` arranges: each row of `input`, preceded
+/// by the approximate nearest-neighbor bucket of `embedding` in that row.
+pub(crate) fn ann_collection(input: MirRelationExpr, embedding: MirScalarExpr) -> MirRelationExpr {
+    let arity = input.arity();
+    input
+        .map_one(embedding.call_unary(UnaryFunc::VectorAnnBucket(func::VectorAnnBucket)))
+        .project(std::iter::once(arity).chain(0..arity).collect())
+}
+
 pub struct Optimizer {
     /// A typechecking context to use throughout the optimizer pipeline.
     typecheck_ctx: TypecheckContext,
@@ -63,6 +80,8 @@ pub struct Optimizer {
     metrics: OptimizerMetrics,
     /// The time spent performing optimization so far.
     duration: Duration,
+    /// Statistics about the collections read by the dataflow.
+    stats: Box<dyn StatisticsOracle>,
 }
 
 impl Optimizer {
@@ -81,8 +100,14 @@ impl Optimizer {
             config,
             metrics,
             duration: Default::default(),
+            stats: Box::new(EmptyStatisticsOracle),
         }
     }
+
+    /// Uses the given statistics to inform the optimization of the dataflow.
+    pub fn with_stats(self, stats: Box<dyn StatisticsOracle>) -> Self {
+        Self { stats, ..self }
+    }
 }
 
 /// A wrapper of index parts needed to start the optimization process.
@@ -90,12 +115,23 @@ pub struct Index {
     name: QualifiedItemName,
     on: GlobalId,
     keys: Vec<mz_expr::MirScalarExpr>,
+    kind: IndexKind,
 }
 
 impl Index {
     /// Construct a new [`Index`]. Arguments are recorded as-is.
-    pub fn new(name: QualifiedItemName, on: GlobalId, keys: Vec<mz_expr::MirScalarExpr>) -> Self {
-        Self { name, on, keys }
+    pub fn new(
+        name: QualifiedItemName,
+        on: GlobalId,
+        keys: Vec<mz_expr::MirScalarExpr>,
+        kind: IndexKind,
+    ) -> Self {
+        Self {
+            name,
+            on,
+            keys,
+            kind,
+        }
     }
 }
 
@@ -157,11 +193,55 @@ impl Optimize<Index> for Optimizer {
         df_builder.import_into_dataflow(&index.on, &mut df_desc, &self.config.features)?;
         df_builder.maybe_reoptimize_imported_views(&mut df_desc, &self.config)?;
 
-        let index_desc = IndexDesc {
-            on_id: index.on,
-            key: index.keys.clone(),
-        };
-        df_desc.export_index(self.exported_index_id, index_desc, on_desc.typ().clone());
+        match index.kind {
+            IndexKind::Arrangement => {
+                let index_desc = IndexDesc {
+                    on_id: index.on,
+                    key: index.keys.clone(),
+                };
+                df_desc.export_index(self.exported_index_id, index_desc, on_desc.typ().clone());
+            }
+            IndexKind::Lexeme => {
+                // A lexeme index arranges the lexemes of its document by building them as a
+                // collection under its own id, which it then exports as an index on itself.
+                let on = MirRelationExpr::global_get(index.on, on_desc.typ().clone());
+                let lexemes = lexeme_collection(on, index.keys[0].clone());
+                let lexemes_typ = lexemes.typ();
+                df_desc.insert_plan(
+                    self.exported_index_id,
+                    OptimizedMirRelationExpr::declare_optimized(
+                        lexemes.arrange_by(&[LEXEME_INDEX_KEY.clone()]),
+                    ),
+                );
+                let index_desc = IndexDesc {
+                    on_id: self.exported_index_id,
+                    key: LEXEME_INDEX_KEY.clone(),
+                };
+                df_desc
+                    .index_exports
+                    .insert(self.exported_index_id, (index_desc, lexemes_typ));
+            }
+            IndexKind::Ann => {
+                // An ANN index arranges the rows by the bucket of their embedding, which it builds
+                // as a collection under its own id, like a lexeme index.
+                let on = MirRelationExpr::global_get(index.on, on_desc.typ().clone());
+                let buckets = ann_collection(on, index.keys[0].clone());
+                let buckets_typ = buckets.typ();
+                df_desc.insert_plan(
+                    self.exported_index_id,
+                    OptimizedMirRelationExpr::declare_optimized(
+                        buckets.arrange_by(&[ANN_INDEX_KEY.clone()]),
+                    ),
+                );
+                let index_desc = IndexDesc {
+                    on_id: self.exported_index_id,
+                    key: ANN_INDEX_KEY.clone(),
+                };
+                df_desc
+                    .index_exports
+                    .insert(self.exported_index_id, (index_desc, buckets_typ));
+            }
+        }
 
         // Prepare expressions in the assembled dataflow.
         let style = ExprPrepStyle::Index;
@@ -174,7 +254,8 @@ impl Optimize<Index> for Optimizer {
         let mut df_meta = DataflowMetainfo::default();
         let mut transform_ctx = TransformCtx::global(
             &df_builder,
-            &mz_transform::EmptyStatisticsOracle, // TODO: wire proper stats
+            &*self.stats,
+            &df_builder,
             &self.config.features,
             &self.typecheck_ctx,
             &mut df_meta,
@@ -195,8 +276,13 @@ impl Optimize<Index> for Optimizer {
 
         // Emit a notice for each available index identical to the one we are
         // currently optimizing.
-        for (index_id, idx) in df_builder
-            .indexes_on(index.on)
+        let same_kind = match index.kind {
+            IndexKind::Arrangement => df_builder.indexes_on(index.on).collect::<Vec<_>>(),
+            IndexKind::Lexeme => df_builder.lexeme_indexes_on(index.on).collect(),
+            IndexKind::Ann => df_builder.ann_indexes_on(index.on).collect(),
+        };
+        for (index_id, idx) in same_kind
+            .into_iter()
             .filter(|(_id, idx)| idx.keys.as_ref() == &index.keys)
         {
             df_meta.push_optimizer_notice_dedup(IndexAlreadyExists {
@@ -39,10 +39,10 @@ use mz_repr::refresh_schedule::RefreshSchedule;
 use mz_repr::{ColumnName, GlobalId, RelationDesc};
 use mz_sql::optimizer_metrics::OptimizerMetrics;
 use mz_sql::plan::HirRelationExpr;
-use mz_transform::TransformCtx;
 use mz_transform::dataflow::DataflowMetainfo;
 use mz_transform::normalize_lets::normalize_lets;
 use mz_transform::typecheck::{SharedContext as TypecheckContext, empty_context};
+use mz_transform::{EmptyStatisticsOracle, StatisticsOracle, TransformCtx};
 use timely::progress::Antichain;
 
 use crate::optimize::dataflows::{
@@ -97,6 +97,8 @@ pub struct Optimizer {
     /// CT sink connection directly. This would allow us to replace this field
     /// with something derived directly from that sink connection.
     force_source_non_monotonic: BTreeSet<GlobalId>,
+    /// Statistics about the collections read by the dataflow.
+    stats: Box<dyn StatisticsOracle>,
 }
 
 impl Optimizer {
@@ -127,8 +129,14 @@ impl Optimizer {
             metrics,
             duration: Default::default(),
             force_source_non_monotonic,
+            stats: Box::new(EmptyStatisticsOracle),
         }
     }
+
+    /// Uses the given statistics to inform the optimization of the dataflow.
+    pub fn with_stats(self, stats: Box<dyn StatisticsOracle>) -> Self {
+        Self { stats, ..self }
+    }
 }
 
 /// The (sealed intermediate) result after HIR ⇒ MIR lowering and decorrelation
@@ -283,7 +291,8 @@ impl Optimize<LocalMirPlan> for Optimizer {
         // Construct TransformCtx for global optimization.
         let mut transform_ctx = TransformCtx::global(
             &df_builder,
-            &mz_transform::EmptyStatisticsOracle, // TODO: wire proper stats
+            &*self.stats,
+            &df_builder,
             &self.config.features,
             &self.typecheck_ctx,
             &mut df_meta,
@@ -9,37 +9,42 @@
 
 //! Optimizer implementation for `SELECT` statements.
 
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use mz_adapter_types::connection::ConnectionId;
+use mz_catalog::memory::objects::{CatalogCollectionEntry, CatalogEntry, Index};
 use mz_compute_types::ComputeInstanceId;
 use mz_compute_types::dataflows::IndexDesc;
 use mz_compute_types::plan::Plan;
+use mz_controller_types::ClusterId;
 use mz_expr::{MirRelationExpr, MirScalarExpr, OptimizedMirRelationExpr, RowSetFinishing};
 use mz_ore::soft_assert_or_log;
 use mz_repr::explain::trace_plan;
-use mz_repr::{GlobalId, RelationType, Timestamp};
+use mz_repr::{CatalogItemId, GlobalId, RelationType, Timestamp};
+use mz_sql::names::{FullItemName, QualifiedItemName};
 use mz_sql::optimizer_metrics::OptimizerMetrics;
 use mz_sql::plan::HirRelationExpr;
 use mz_sql::session::metadata::SessionMetadata;
 use mz_transform::dataflow::DataflowMetainfo;
 use mz_transform::normalize_lets::normalize_lets;
 use mz_transform::typecheck::{SharedContext as TypecheckContext, empty_context};
-use mz_transform::{StatisticsOracle, TransformCtx};
+use mz_transform::{ForeignKey, StatisticsOracle, TransformCtx};
 use timely::progress::Antichain;
 use tracing::debug_span;
 
 use crate::TimestampContext;
-use crate::catalog::Catalog;
+use crate::catalog::{Catalog, CatalogState};
 use crate::coord::peek::{PeekDataflowPlan, PeekPlan, create_fast_path_plan};
 use crate::optimize::dataflows::{
     ComputeInstanceSnapshot, DataflowBuilder, EvalTime, ExprPrepStyle, prep_relation_expr,
     prep_scalar_expr,
 };
 use crate::optimize::{
-    MirDataflowDescription, Optimize, OptimizeMode, OptimizerConfig, OptimizerError,
-    optimize_mir_local, trace_plan,
+    MirDataflowDescription, Optimize, OptimizeMode, OptimizerCatalog, OptimizerConfig,
+    OptimizerError, optimize_mir_local, trace_plan,
 };
 
 pub struct Optimizer {
@@ -59,6 +64,9 @@ pub struct Optimizer {
     config: OptimizerConfig,
     /// Optimizer metrics.
     metrics: OptimizerMetrics,
+    /// Indexes that do not exist, but that the optimizer should plan against
+    /// as if they did. See [`Optimizer::with_hypothetical_index`].
+    hypothetical_indexes: BTreeMap<GlobalId, Index>,
     /// The time spent performing optimization so far.
     duration: Duration,
 }
@@ -82,10 +90,21 @@ impl Optimizer {
             index_id,
             config,
             metrics,
+            hypothetical_indexes: Default::default(),
             duration: Default::default(),
         }
     }
 
+    /// Plans as if `index` existed on the cluster that runs the peek.
+    ///
+    /// Used by the index advisor to find out whether an index that does not
+    /// exist yet would turn a slow-path peek into a fast-path peek.
+    pub fn with_hypothetical_index(mut self, index: Index) -> Self {
+        self.compute_instance.insert_collection(index.global_id);
+        self.hypothetical_indexes.insert(index.global_id, index);
+        self
+    }
+
     pub fn cluster_id(&self) -> ComputeInstanceId {
         self.compute_instance.instance_id()
     }
@@ -198,6 +217,13 @@ impl Optimize<HirRelationExpr> for Optimizer {
     }
 }
 
+impl<T> LocalMirPlan<T> {
+    /// The locally optimized expression.
+    pub fn expr(&self) -> &MirRelationExpr {
+        &self.expr
+    }
+}
+
 impl LocalMirPlan<Unresolved> {
     /// Produces the [`LocalMirPlan`] with [`Resolved`] contextual information
     /// required for the next stage.
@@ -249,10 +275,13 @@ impl<'s> Optimize<LocalMirPlan<Resolved<'s>>> for Optimizer {
             .collect();
 
         // The assembled dataflow contains a view and an index of that view.
+        let catalog = HypotheticalIndexCatalog {
+            delegate: self.catalog.state(),
+            indexes: &self.hypothetical_indexes,
+        };
         let mut df_builder = {
-            let catalog = self.catalog.state();
             let compute = self.compute_instance.clone();
-            DataflowBuilder::new(catalog, compute).with_config(&self.config)
+            DataflowBuilder::new(&catalog, compute).with_config(&self.config)
         };
 
         let debug_name = format!("oneshot-select-{}", self.select_id);
@@ -266,6 +295,15 @@ impl<'s> Optimize<LocalMirPlan<Resolved<'s>>> for Optimizer {
         )?;
         df_builder.maybe_reoptimize_imported_views(&mut df_desc, &self.config)?;
 
+        // The dataflow can only be kept around to serve later peeks if its
+        // results depend neither on the session nor on the time of the peek.
+        let keep_dataflow = self.config.keep_peek_dataflows
+            && self.config.mode == OptimizeMode::Execute
+            && !df_desc
+                .objects_to_build
+                .iter()
+                .any(|build| contains_unmaterializable(&build.plan));
+
         // Resolve all unmaterializable function calls except mz_now(), because
         // we don't yet have a timestamp.
         let style = ExprPrepStyle::OneShot {
@@ -303,18 +341,41 @@ impl<'s> Optimize<LocalMirPlan<Resolved<'s>>> for Optimizer {
         // will return `None` and we use the default (empty) `until`. Otherwise,
         // we expect to be able to set `until = as_of + 1` without an overflow, unless
         // we query at the maximum timestamp. In this case, the default empty `until`
-        // is the correct choice.
+        // is the correct choice. Dataflows that we keep around must keep up
+        // with their inputs, so they also use the default.
         if let Some(until) = timestamp_ctx
             .timestamp()
             .and_then(Timestamp::try_step_forward)
+            .filter(|_| !keep_dataflow)
         {
             df_desc.until = Antichain::from_elem(until);
         }
 
+        // The ANN indexes on the collections that the dataflow reads, which can serve peeks for the
+        // nearest rows to a vector, if the session accepts approximate results.
+        let mut ann_indexes = BTreeMap::new();
+        let imported_ids = df_desc.source_imports.keys().chain(
+            df_desc
+                .index_imports
+                .values()
+                .map(|import| &import.desc.on_id),
+        );
+        let imported_ids = imported_ids.filter(|_| self.config.approximate_nearest_neighbors);
+        for id in imported_ids {
+            let indexes: Vec<_> = df_builder
+                .ann_indexes_on(*id)
+                .map(|(idx_id, idx)| (idx_id, idx.keys[0].clone()))
+                .collect();
+            if !indexes.is_empty() {
+                ann_indexes.insert(*id, indexes);
+            }
+        }
+
         // Construct TransformCtx for global optimization.
         let mut transform_ctx = TransformCtx::global(
             &df_builder,
             &*stats,
+            &df_builder,
             &self.config.features,
             &self.typecheck_ctx,
             &mut df_meta,
@@ -331,6 +392,7 @@ impl<'s> Optimize<LocalMirPlan<Resolved<'s>>> for Optimizer {
             Some(&self.finishing),
             self.config.features.persist_fast_path_limit,
             self.config.persist_fast_path_order,
+            &ann_indexes,
         ) {
             Ok(maybe_fast_path_plan) => maybe_fast_path_plan.is_some(),
             Err(OptimizerError::UnsafeMfpPlan) => {
@@ -385,6 +447,7 @@ impl<'s> Optimize<LocalMirPlan<Resolved<'s>>> for Optimizer {
             Some(&self.finishing),
             self.config.features.persist_fast_path_limit,
             self.config.persist_fast_path_order,
+            &ann_indexes,
         )? {
             Some(plan) if !self.config.no_fast_path => {
                 if self.config.mode == OptimizeMode::Explain {
@@ -428,7 +491,12 @@ impl<'s> Optimize<LocalMirPlan<Resolved<'s>>> for Optimizer {
                 trace_plan(&df_desc);
 
                 // Build the PeekPlan
-                PeekPlan::SlowPath(PeekDataflowPlan::new(df_desc, self.index_id(), &typ))
+                let plan = PeekDataflowPlan::new(df_desc, self.index_id(), &typ);
+                if keep_dataflow {
+                    PeekPlan::SlowPath(plan.with_cache_key(self.select_id))
+                } else {
+                    PeekPlan::SlowPath(plan)
+                }
             }
         };
 
@@ -454,3 +522,60 @@ impl GlobalLirPlan {
         (self.peek_plan, self.df_meta, self.typ)
     }
 }
+
+/// Returns whether `expr` contains calls to unmaterializable functions, whose
+/// results depend on the session or the time of the peek.
+fn contains_unmaterializable(expr: &MirRelationExpr) -> bool {
+    let mut contains = false;
+    expr.visit_scalars(&mut |e| contains = contains || e.contains_unmaterializable());
+    contains
+}
+
+/// An [`OptimizerCatalog`] that reports a set of hypothetical indexes in
+/// addition to the indexes that exist in the wrapped catalog.
+#[derive(Debug)]
+struct HypotheticalIndexCatalog<'a> {
+    delegate: &'a CatalogState,
+    indexes: &'a BTreeMap<GlobalId, Index>,
+}
+
+impl OptimizerCatalog for HypotheticalIndexCatalog<'_> {
+    fn get_entry(&self, id: &GlobalId) -> CatalogCollectionEntry {
+        OptimizerCatalog::get_entry(self.delegate, id)
+    }
+
+    fn get_entry_by_item_id(&self, id: &CatalogItemId) -> &CatalogEntry {
+        OptimizerCatalog::get_entry_by_item_id(self.delegate, id)
+    }
+
+    fn resolve_full_name(
+        &self,
+        name: &QualifiedItemName,
+        conn_id: Option<&ConnectionId>,
+    ) -> FullItemName {
+        OptimizerCatalog::resolve_full_name(self.delegate, name, conn_id)
+    }
+
+    fn get_indexes_on(
+        &self,
+        id: GlobalId,
+        cluster: ClusterId,
+    ) -> Box<dyn Iterator<Item = (GlobalId, &Index)> + '_> {
+        let hypothetical = self
+            .indexes
+            .iter()
+            .filter(move |(_, idx)| idx.on == id && idx.cluster_id == cluster)
+            .map(|(idx_id, idx)| (*idx_id, idx));
+        Box::new(OptimizerCatalog::get_indexes_on(self.delegate, id, cluster).chain(hypothetical))
+    }
+
+    fn get_index(&self, id: &GlobalId) -> Option<&Index> {
+        self.indexes
+            .get(id)
+            .or_else(|| OptimizerCatalog::get_index(self.delegate, id))
+    }
+
+    fn get_foreign_keys_on(&self, id: GlobalId) -> Vec<ForeignKey> {
+        OptimizerCatalog::get_foreign_keys_on(self.delegate, id)
+    }
+}
@@ -270,6 +270,7 @@ impl Optimize<SubscribeFrom> for Optimizer {
         let mut transform_ctx = TransformCtx::global(
             &df_builder,
             &mz_transform::EmptyStatisticsOracle, // TODO: wire proper stats
+            &df_builder,
             &self.config.features,
             &self.typecheck_ctx,
             &mut df_meta,
@@ -101,6 +101,8 @@ where
     vars: SessionVars,
     notices_tx: mpsc::UnboundedSender<AdapterNotice>,
     notices_rx: mpsc::UnboundedReceiver<AdapterNotice>,
+    notifications_tx: mpsc::UnboundedSender<ChannelNotification>,
+    notifications_rx: mpsc::UnboundedReceiver<ChannelNotification>,
     next_transaction_id: TransactionId,
     secret_key: u32,
     external_metadata_rx: Option<watch::Receiver<ExternalUserMetadata>>,
@@ -312,6 +314,7 @@ impl<T: TimestampManipulation> Session<T> {
         metrics: SessionMetrics,
     ) -> Session<T> {
         let (notices_tx, notices_rx) = mpsc::unbounded_channel();
+        let (notifications_tx, notifications_rx) = mpsc::unbounded_channel();
         let default_cluster = INTERNAL_USER_NAME_TO_DEFAULT_CLUSTER.get(&user);
         let user = User {
             name: user,
@@ -338,6 +341,8 @@ impl<T: TimestampManipulation> Session<T> {
             vars,
             notices_tx,
             notices_rx,
+            notifications_tx,
+            notifications_rx,
             next_transaction_id: 0,
             secret_key: rand::thread_rng().r#gen(),
             external_metadata_rx,
@@ -541,6 +546,24 @@ impl<T: TimestampManipulation> Session<T> {
         notices
     }
 
+    /// Returns a channel on which to send asynchronous notifications to the
+    /// session.
+    pub fn retain_notification_transmitter(&self) -> UnboundedSender<ChannelNotification> {
+        self.notifications_tx.clone()
+    }
+
+    /// Awaits a notification on one of the channels the session is listening
+    /// on.
+    ///
+    /// This method is cancel safe.
+    pub async fn recv_notification(&mut self) -> ChannelNotification {
+        // This method is cancel safe because recv is cancel safe.
+        self.notifications_rx
+            .recv()
+            .await
+            .expect("Session also holds a sender, so recv won't ever return None")
+    }
+
     /// Returns Some if the notice should be reported, otherwise None.
     fn notice_filter(&self, notice: AdapterNotice) -> Option<AdapterNotice> {
         // Filter out low threshold severity.
@@ -904,6 +927,15 @@ impl<T: TimestampManipulation> Session<T> {
     }
 }
 
+/// An asynchronous notification for a channel a session is listening on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelNotification {
+    /// The name of the channel on which the notification was raised.
+    pub channel: String,
+    /// The payload of the notification.
+    pub payload: String,
+}
+
 /// A prepared statement.
 #[derive(Derivative, Clone)]
 #[derivative(Debug)]
@@ -73,6 +73,12 @@ pub enum StatementExecutionStrategy {
     /// The statement was determined to be constant by
     /// environmentd, and not sent to a cluster.
     Constant,
+    /// The statement was executed by spinning up a dataflow, which was kept
+    /// around to serve later executions of the same plan.
+    CacheMiss,
+    /// The statement was executed by reading from a dataflow that was kept
+    /// around from an earlier execution of the same plan.
+    CacheHit,
 }
 
 impl StatementExecutionStrategy {
@@ -82,6 +88,8 @@ impl StatementExecutionStrategy {
             Self::FastPath => "fast-path",
             Self::PersistFastPath => "persist-fast-path",
             Self::Constant => "constant",
+            Self::CacheMiss => "cache-miss",
+            Self::CacheHit => "cache-hit",
         }
     }
 }
@@ -213,7 +221,10 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::CreatedClusterReplica
             | ExecuteResponse::CreatedIndex
             | ExecuteResponse::CreatedIntrospectionSubscribe
+            | ExecuteResponse::CreatedFunction
+            | ExecuteResponse::CreatedNotification
             | ExecuteResponse::CreatedSecret
+            | ExecuteResponse::CreatedPublication
             | ExecuteResponse::CreatedSink
             | ExecuteResponse::CreatedSource
             | ExecuteResponse::CreatedTable
@@ -234,6 +245,7 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(_)
+            | ExecuteResponse::Listening
             | ExecuteResponse::Prepare
             | ExecuteResponse::Raised
             | ExecuteResponse::ReassignOwned
@@ -243,6 +255,7 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::StartedTransaction
             | ExecuteResponse::TransactionCommitted { .. }
             | ExecuteResponse::TransactionRolledBack { .. }
+            | ExecuteResponse::Unlistening
             | ExecuteResponse::Updated(_)
             | ExecuteResponse::ValidatedConnection { .. } => {
                 StatementEndedExecutionReason::Success {
@@ -157,10 +157,17 @@ pub fn analyze_audited_statement(
         StatementKind::CreateConnection => Some((StatementAction::Create, ObjectType::Connection)),
         StatementKind::CreateDatabase => Some((StatementAction::Create, ObjectType::Database)),
         StatementKind::CreateIndex => Some((StatementAction::Create, ObjectType::Index)),
+        StatementKind::CreateFunction => Some((StatementAction::Create, ObjectType::Func)),
         StatementKind::CreateMaterializedView => {
             Some((StatementAction::Create, ObjectType::MaterializedView))
         }
+        StatementKind::CreateNotification => {
+            Some((StatementAction::Create, ObjectType::Notification))
+        }
         StatementKind::CreateRole => Some((StatementAction::Create, ObjectType::Role)),
+        StatementKind::CreatePublication => {
+            Some((StatementAction::Create, ObjectType::Publication))
+        }
         StatementKind::CreateSchema => Some((StatementAction::Create, ObjectType::Schema)),
         StatementKind::CreateSecret => Some((StatementAction::Create, ObjectType::Secret)),
         StatementKind::CreateSink => Some((StatementAction::Create, ObjectType::Sink)),
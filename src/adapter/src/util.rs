@@ -23,7 +23,8 @@ use mz_sql::session::metadata::SessionMetadata;
 use mz_sql::session::vars::Var;
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
-    CreateIndexStatement, FetchStatement, Ident, Raw, RawClusterName, RawItemName, Statement,
+    CreateIndexStatement, FetchStatement, Ident, IndexMethod, Raw, RawClusterName, RawItemName,
+    Statement,
 };
 use mz_storage_types::controller::StorageError;
 use mz_transform::TransformError;
@@ -207,6 +208,7 @@ pub fn index_sql(
         name: Some(Ident::new_unchecked(index_name)),
         on_name: RawItemName::Name(mz_sql::normalize::unresolve(view_name)),
         in_cluster: Some(RawClusterName::Resolved(cluster_id.to_string())),
+        method: IndexMethod::Arrangement,
         key_parts: Some(
             keys.iter()
                 .map(|i| match view_desc.get_unambiguous_name(*i) {
@@ -106,6 +106,7 @@ async fn datadriven() {
                                             data_source: TableDataSource::TableWrites {
                                                 defaults: vec![],
                                             },
+                                            foreign_keys: vec![],
                                         }),
                                         owner_id: MZ_SYSTEM_ROLE_ID,
                                     }],
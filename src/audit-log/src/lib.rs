@@ -114,6 +114,8 @@ pub enum ObjectType {
     Index,
     MaterializedView,
     NetworkPolicy,
+    Notification,
+    Publication,
     Role,
     Secret,
     Schema,
@@ -137,6 +139,8 @@ impl ObjectType {
             ObjectType::Index => "Index",
             ObjectType::MaterializedView => "Materialized View",
             ObjectType::NetworkPolicy => "Network Policy",
+            ObjectType::Notification => "Notification",
+            ObjectType::Publication => "Publication",
             ObjectType::Role => "Role",
             ObjectType::Schema => "Schema",
             ObjectType::Secret => "Secret",
@@ -34,9 +34,9 @@ use mz_catalog::durable::debug::{
     AuditLogCollection, ClusterCollection, ClusterIntrospectionSourceIndexCollection,
     ClusterReplicaCollection, Collection, CollectionTrace, CollectionType, CommentCollection,
     ConfigCollection, DatabaseCollection, DebugCatalogState, DefaultPrivilegeCollection,
-    IdAllocatorCollection, ItemCollection, NetworkPolicyCollection, RoleAuthCollection,
-    RoleCollection, SchemaCollection, SettingCollection, SourceReferencesCollection,
-    StorageCollectionMetadataCollection, SystemConfigurationCollection,
+    IdAllocatorCollection, ItemCollection, NetworkPolicyCollection, ReplicationSlotCollection,
+    RoleAuthCollection, RoleCollection, SchemaCollection, SettingCollection,
+    SourceReferencesCollection, StorageCollectionMetadataCollection, SystemConfigurationCollection,
     SystemItemMappingCollection, SystemPrivilegeCollection, Trace, TxnWalShardCollection,
     UnfinalizedShardsCollection,
 };
@@ -290,6 +290,7 @@ macro_rules! for_collection {
             CollectionType::IdAlloc => $fn::<IdAllocatorCollection>($($arg),*).await?,
             CollectionType::Item => $fn::<ItemCollection>($($arg),*).await?,
             CollectionType::NetworkPolicy => $fn::<NetworkPolicyCollection>($($arg),*).await?,
+            CollectionType::ReplicationSlot => $fn::<ReplicationSlotCollection>($($arg),*).await?,
             CollectionType::Role => $fn::<RoleCollection>($($arg),*).await?,
             CollectionType::RoleAuth => $fn::<RoleAuthCollection>($($arg),*).await?,
             CollectionType::Schema => $fn::<SchemaCollection>($($arg),*).await?,
@@ -436,6 +437,7 @@ async fn dump(
         id_allocator,
         items,
         network_policies,
+        replication_slots,
         roles,
         role_auth,
         schemas,
@@ -490,6 +492,13 @@ async fn dump(
         stats_only,
         consolidate,
     );
+    dump_col(
+        &mut data,
+        replication_slots,
+        &ignore,
+        stats_only,
+        consolidate,
+    );
     dump_col(&mut data, roles, &ignore, stats_only, consolidate);
     dump_col(&mut data, role_auth, &ignore, stats_only, consolidate);
     dump_col(&mut data, schemas, &ignore, stats_only, consolidate);
@@ -686,7 +695,10 @@ async fn upgrade_check(
             | CatalogItem::Index(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
+            | CatalogItem::UserFunc(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Publication(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Connection(_) => None,
         });
 
@@ -104,6 +104,12 @@ impl RustType<crate::objects::audit_log_event_v1::ObjectType> for mz_audit_log::
             mz_audit_log::ObjectType::NetworkPolicy => {
                 crate::objects::audit_log_event_v1::ObjectType::NetworkPolicy
             }
+            mz_audit_log::ObjectType::Notification => {
+                crate::objects::audit_log_event_v1::ObjectType::Notification
+            }
+            mz_audit_log::ObjectType::Publication => {
+                crate::objects::audit_log_event_v1::ObjectType::Publication
+            }
             mz_audit_log::ObjectType::Role => crate::objects::audit_log_event_v1::ObjectType::Role,
             mz_audit_log::ObjectType::Secret => {
                 crate::objects::audit_log_event_v1::ObjectType::Secret
@@ -157,6 +163,12 @@ impl RustType<crate::objects::audit_log_event_v1::ObjectType> for mz_audit_log::
             crate::objects::audit_log_event_v1::ObjectType::NetworkPolicy => {
                 Ok(mz_audit_log::ObjectType::NetworkPolicy)
             }
+            crate::objects::audit_log_event_v1::ObjectType::Notification => {
+                Ok(mz_audit_log::ObjectType::Notification)
+            }
+            crate::objects::audit_log_event_v1::ObjectType::Publication => {
+                Ok(mz_audit_log::ObjectType::Publication)
+            }
             crate::objects::audit_log_event_v1::ObjectType::Role => {
                 Ok(mz_audit_log::ObjectType::Role)
             }
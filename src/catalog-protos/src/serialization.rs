@@ -118,6 +118,7 @@ impl RustType<crate::objects::RoleAttributes> for RoleAttributes {
             inherit: self.inherit,
             superuser: self.superuser,
             login: self.login,
+            replication: self.replication,
         }
     }
 
@@ -127,6 +128,7 @@ impl RustType<crate::objects::RoleAttributes> for RoleAttributes {
         attributes.inherit = proto.inherit;
         attributes.superuser = proto.superuser;
         attributes.login = proto.login;
+        attributes.replication = proto.replication;
 
         Ok(attributes)
     }
@@ -220,6 +222,8 @@ impl RustType<crate::objects::CatalogItemType> for CatalogItemType {
             CatalogItemType::Secret => crate::objects::CatalogItemType::Secret,
             CatalogItemType::Connection => crate::objects::CatalogItemType::Connection,
             CatalogItemType::ContinualTask => crate::objects::CatalogItemType::ContinualTask,
+            CatalogItemType::Publication => crate::objects::CatalogItemType::Publication,
+            CatalogItemType::Notification => crate::objects::CatalogItemType::Notification,
         }
     }
 
@@ -236,6 +240,8 @@ impl RustType<crate::objects::CatalogItemType> for CatalogItemType {
             crate::objects::CatalogItemType::Secret => CatalogItemType::Secret,
             crate::objects::CatalogItemType::Connection => CatalogItemType::Connection,
             crate::objects::CatalogItemType::ContinualTask => CatalogItemType::ContinualTask,
+            crate::objects::CatalogItemType::Publication => CatalogItemType::Publication,
+            crate::objects::CatalogItemType::Notification => CatalogItemType::Notification,
             crate::objects::CatalogItemType::Unknown => {
                 return Err(TryFromProtoError::unknown_enum_variant("CatalogItemType"));
             }
@@ -264,6 +270,8 @@ impl RustType<crate::objects::ObjectType> for ObjectType {
             ObjectType::Func => crate::objects::ObjectType::Func,
             ObjectType::ContinualTask => crate::objects::ObjectType::ContinualTask,
             ObjectType::NetworkPolicy => crate::objects::ObjectType::NetworkPolicy,
+            ObjectType::Publication => crate::objects::ObjectType::Publication,
+            ObjectType::Notification => crate::objects::ObjectType::Notification,
         }
     }
 
@@ -286,6 +294,8 @@ impl RustType<crate::objects::ObjectType> for ObjectType {
             crate::objects::ObjectType::Func => Ok(ObjectType::Func),
             crate::objects::ObjectType::ContinualTask => Ok(ObjectType::ContinualTask),
             crate::objects::ObjectType::NetworkPolicy => Ok(ObjectType::NetworkPolicy),
+            crate::objects::ObjectType::Publication => Ok(ObjectType::Publication),
+            crate::objects::ObjectType::Notification => Ok(ObjectType::Notification),
             crate::objects::ObjectType::Unknown => Err(TryFromProtoError::unknown_enum_variant(
                 "ObjectType::Unknown",
             )),
@@ -467,6 +477,12 @@ impl RustType<crate::objects::comment_key::Object> for CommentObjectId {
             CommentObjectId::NetworkPolicy(network_policy_id) => {
                 crate::objects::comment_key::Object::NetworkPolicy(network_policy_id.into_proto())
             }
+            CommentObjectId::Publication(item_id) => {
+                crate::objects::comment_key::Object::Publication(item_id.into_proto())
+            }
+            CommentObjectId::Notification(item_id) => {
+                crate::objects::comment_key::Object::Notification(item_id.into_proto())
+            }
             CommentObjectId::Schema((database, schema)) => {
                 crate::objects::comment_key::Object::Schema(crate::objects::ResolvedSchema {
                     database: Some(database.into_proto()),
@@ -524,6 +540,12 @@ impl RustType<crate::objects::comment_key::Object> for CommentObjectId {
             crate::objects::comment_key::Object::NetworkPolicy(global_id) => {
                 CommentObjectId::NetworkPolicy(global_id.into_rust()?)
             }
+            crate::objects::comment_key::Object::Publication(item_id) => {
+                CommentObjectId::Publication(item_id.into_rust()?)
+            }
+            crate::objects::comment_key::Object::Notification(item_id) => {
+                CommentObjectId::Notification(item_id.into_rust()?)
+            }
             crate::objects::comment_key::Object::Role(role_id) => {
                 CommentObjectId::Role(role_id.into_rust()?)
             }
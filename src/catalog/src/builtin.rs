@@ -965,6 +965,66 @@ pub const TYPE_UUID_ARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_TSVECTOR: BuiltinType<NameReference> = BuiltinType {
+    name: "tsvector",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_TSVECTOR_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::TsVector,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 3610,
+            typreceive_oid: 3639,
+        }),
+    },
+};
+
+pub const TYPE_TSVECTOR_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_tsvector",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_TSVECTOR_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_TSVECTOR.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_TSQUERY: BuiltinType<NameReference> = BuiltinType {
+    name: "tsquery",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_TSQUERY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::TsQuery,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 3612,
+            typreceive_oid: 3641,
+        }),
+    },
+};
+
+pub const TYPE_TSQUERY_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_tsquery",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_TSQUERY_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_TSQUERY.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
 pub const TYPE_JSONB: BuiltinType<NameReference> = BuiltinType {
     name: "jsonb",
     schema: PG_CATALOG_SCHEMA,
@@ -1051,6 +1111,20 @@ pub const TYPE_ANYNONARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_ANYENUM: BuiltinType<NameReference> = BuiltinType {
+    name: "anyenum",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_ANYENUM_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Pseudo,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 3504,
+            typreceive_oid: 0,
+        }),
+    },
+};
+
 pub const TYPE_ANYRANGE: BuiltinType<NameReference> = BuiltinType {
     name: "anyrange",
     schema: PG_CATALOG_SCHEMA,
@@ -1720,6 +1794,60 @@ pub const TYPE_MZ_ACL_ITEM_ARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_VECTOR: BuiltinType<NameReference> = BuiltinType {
+    name: "vector",
+    schema: MZ_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_VECTOR_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Vector,
+        array_id: None,
+        pg_metadata: None,
+    },
+};
+
+pub const TYPE_VECTOR_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_vector",
+    schema: MZ_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_VECTOR_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_VECTOR.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_GEOMETRY: BuiltinType<NameReference> = BuiltinType {
+    name: "geometry",
+    schema: MZ_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_GEOMETRY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Geometry,
+        array_id: None,
+        pg_metadata: None,
+    },
+};
+
+pub const TYPE_GEOMETRY_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_geometry",
+    schema: MZ_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_GEOMETRY_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_GEOMETRY.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
 pub const TYPE_ACL_ITEM: BuiltinType<NameReference> = BuiltinType {
     name: "aclitem",
     schema: PG_CATALOG_SCHEMA,
@@ -2011,6 +2139,14 @@ pub static MZ_ARRANGEMENT_HEAP_ALLOCATIONS_RAW: LazyLock<BuiltinLog> =
         access: vec![PUBLIC_SELECT],
     });
 
+pub static MZ_ARRANGEMENT_SPILLED_SIZE_RAW: LazyLock<BuiltinLog> = LazyLock::new(|| BuiltinLog {
+    name: "mz_arrangement_spilled_size_raw",
+    schema: MZ_INTROSPECTION_SCHEMA,
+    oid: oid::LOG_MZ_ARRANGEMENT_SPILLED_SIZE_RAW_OID,
+    variant: LogVariant::Compute(ComputeLog::ArrangementSpilledSize),
+    access: vec![PUBLIC_SELECT],
+});
+
 pub static MZ_MESSAGE_BATCH_COUNTS_RECEIVED_RAW: LazyLock<BuiltinLog> =
     LazyLock::new(|| BuiltinLog {
         name: "mz_message_batch_counts_received_raw",
@@ -3056,6 +3192,132 @@ pub static MZ_NETWORK_POLICY_RULES: LazyLock<BuiltinTable> = LazyLock::new(|| Bu
     is_retained_metrics_object: false,
     access: vec![PUBLIC_SELECT],
 });
+
+pub static MZ_PUBLICATIONS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_publications",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_PUBLICATIONS_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("oid", ScalarType::Oid.nullable(false))
+        .with_column("schema_id", ScalarType::String.nullable(false))
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .finish(),
+    column_comments: BTreeMap::from_iter([
+        ("id", "The unique ID of the publication."),
+        (
+            "oid",
+            "A [PostgreSQL-compatible oid][`oid`] for the publication.",
+        ),
+        (
+            "schema_id",
+            "The ID of the schema to which the publication belongs. Corresponds to `mz_schemas.id`.",
+        ),
+        ("name", "The name of the publication."),
+        (
+            "owner_id",
+            "The role ID of the owner of the publication. Corresponds to `mz_roles.id`.",
+        ),
+    ]),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_PUBLICATION_TABLES: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_publication_tables",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_PUBLICATION_TABLES_OID,
+    desc: RelationDesc::builder()
+        .with_column("publication_id", ScalarType::String.nullable(false))
+        .with_column("object_id", ScalarType::String.nullable(false))
+        .finish(),
+    column_comments: BTreeMap::from_iter([
+        (
+            "publication_id",
+            "The ID of the publication. Corresponds to `mz_publications.id`.",
+        ),
+        (
+            "object_id",
+            "The ID of the table or materialized view that is a member of the publication.",
+        ),
+    ]),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_NOTIFICATIONS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_notifications",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_NOTIFICATIONS_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("oid", ScalarType::Oid.nullable(false))
+        .with_column("schema_id", ScalarType::String.nullable(false))
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("on_id", ScalarType::String.nullable(false))
+        .with_column("channel", ScalarType::String.nullable(false))
+        .with_column("predicate", ScalarType::String.nullable(true))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .finish(),
+    column_comments: BTreeMap::from_iter([
+        ("id", "The unique ID of the notification."),
+        (
+            "oid",
+            "A [PostgreSQL-compatible oid][`oid`] for the notification.",
+        ),
+        (
+            "schema_id",
+            "The ID of the schema to which the notification belongs. Corresponds to `mz_schemas.id`.",
+        ),
+        ("name", "The name of the notification."),
+        (
+            "on_id",
+            "The ID of the materialized view whose changes trigger the notification. Corresponds to `mz_materialized_views.id`.",
+        ),
+        (
+            "channel",
+            "The channel on which the notification is delivered to `LISTEN`ing sessions.",
+        ),
+        (
+            "predicate",
+            "The `WHEN` predicate that changed rows must satisfy, if any.",
+        ),
+        (
+            "owner_id",
+            "The role ID of the owner of the notification. Corresponds to `mz_roles.id`.",
+        ),
+    ]),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_REPLICATION_SLOTS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_replication_slots",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_REPLICATION_SLOTS_OID,
+    desc: RelationDesc::builder()
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("plugin", ScalarType::String.nullable(false))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .with_column("confirmed_lsn", ScalarType::MzTimestamp.nullable(false))
+        .finish(),
+    column_comments: BTreeMap::from_iter([
+        ("name", "The name of the replication slot."),
+        ("plugin", "The output plugin of the replication slot."),
+        (
+            "owner_id",
+            "The role ID of the owner of the replication slot. Corresponds to `mz_roles.id`.",
+        ),
+        (
+            "confirmed_lsn",
+            "The position up to which the slot's consumer has confirmed changes. The slot holds back compaction of the collections it streams from at this time.",
+        ),
+    ]),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
 /// PostgreSQL-specific metadata about types that doesn't make sense to expose
 /// in the `mz_types` table as part of our public, stable API.
 pub static MZ_TYPE_PG_METADATA: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
@@ -4245,7 +4507,7 @@ pub static MZ_RECENT_ACTIVITY_LOG: LazyLock<BuiltinView> = LazyLock::new(|| Buil
         ),
         (
             "execution_strategy",
-            "For `SELECT` queries, the strategy for executing the query. `constant` means computed in the control plane without the involvement of a cluster, `fast-path` means read by a cluster directly from an in-memory index, and `standard` means computed by a temporary dataflow.",
+            "For `SELECT` queries, the strategy for executing the query. `constant` means computed in the control plane without the involvement of a cluster, `fast-path` means read by a cluster directly from an in-memory index, `standard` means computed by a temporary dataflow, `cache-miss` means computed by a dataflow that is kept around for later executions of the same query, and `cache-hit` means read from such a dataflow.",
         ),
         (
             "transaction_id",
@@ -8592,6 +8854,7 @@ pub static MZ_ARRANGEMENT_SIZES_PER_WORKER: LazyLock<BuiltinView> = LazyLock::ne
             .with_column("size", ScalarType::Int64.nullable(false))
             .with_column("capacity", ScalarType::Int64.nullable(false))
             .with_column("allocations", ScalarType::Int64.nullable(false))
+            .with_column("spilled_size", ScalarType::Int64.nullable(false))
             .finish(),
         column_comments: BTreeMap::new(),
         sql: "
@@ -8645,6 +8908,16 @@ heap_allocations_cte AS (
     GROUP BY
         operator_id, worker_id
 ),
+spilled_size_cte AS (
+    SELECT
+        operator_id,
+        worker_id,
+        pg_catalog.count(*) AS spilled_size
+    FROM
+        mz_introspection.mz_arrangement_spilled_size_raw
+    GROUP BY
+        operator_id, worker_id
+),
 batcher_records_cte AS (
     SELECT
         operator_id,
@@ -8692,7 +8965,8 @@ SELECT
     batches_cte.batches,
     COALESCE(heap_size_cte.size, 0) + COALESCE(batcher_size_cte.size, 0) AS size,
     COALESCE(heap_capacity_cte.capacity, 0) + COALESCE(batcher_capacity_cte.capacity, 0) AS capacity,
-    COALESCE(heap_allocations_cte.allocations, 0) + COALESCE(batcher_allocations_cte.allocations, 0) AS allocations
+    COALESCE(heap_allocations_cte.allocations, 0) + COALESCE(batcher_allocations_cte.allocations, 0) AS allocations,
+    COALESCE(spilled_size_cte.spilled_size, 0) AS spilled_size
 FROM batches_cte
 LEFT OUTER JOIN records_cte USING (operator_id, worker_id)
 LEFT OUTER JOIN heap_size_cte USING (operator_id, worker_id)
@@ -8701,7 +8975,8 @@ LEFT OUTER JOIN heap_allocations_cte USING (operator_id, worker_id)
 LEFT OUTER JOIN batcher_records_cte USING (operator_id, worker_id)
 LEFT OUTER JOIN batcher_size_cte USING (operator_id, worker_id)
 LEFT OUTER JOIN batcher_capacity_cte USING (operator_id, worker_id)
-LEFT OUTER JOIN batcher_allocations_cte USING (operator_id, worker_id)",
+LEFT OUTER JOIN batcher_allocations_cte USING (operator_id, worker_id)
+LEFT OUTER JOIN spilled_size_cte USING (operator_id, worker_id)",
         access: vec![PUBLIC_SELECT],
     }
 });
@@ -8747,6 +9022,13 @@ pub static MZ_ARRANGEMENT_SIZES: LazyLock<BuiltinView> = LazyLock::new(|| Builti
             }
             .nullable(false),
         )
+        .with_column(
+            "spilled_size",
+            ScalarType::Numeric {
+                max_scale: Some(NumericMaxScale::ZERO),
+            }
+            .nullable(false),
+        )
         .with_key(vec![0])
         .finish(),
     column_comments: BTreeMap::from_iter([
@@ -8765,6 +9047,10 @@ pub static MZ_ARRANGEMENT_SIZES: LazyLock<BuiltinView> = LazyLock::new(|| Builti
             "allocations",
             "The number of separate memory allocations backing the arrangement.",
         ),
+        (
+            "spilled_size",
+            "The size in bytes of the arrangement that is stored on disk rather than in memory.",
+        ),
     ]),
     sql: "
 SELECT
@@ -8773,7 +9059,8 @@ SELECT
     pg_catalog.sum(batches) AS batches,
     pg_catalog.sum(size) AS size,
     pg_catalog.sum(capacity) AS capacity,
-    pg_catalog.sum(allocations) AS allocations
+    pg_catalog.sum(allocations) AS allocations,
+    pg_catalog.sum(spilled_size) AS spilled_size
 FROM mz_introspection.mz_arrangement_sizes_per_worker
 GROUP BY operator_id",
     access: vec![PUBLIC_SELECT],
@@ -10797,6 +11084,56 @@ pub static MZ_SHOW_SECRETS: LazyLock<BuiltinView> = LazyLock::new(|| BuiltinView
     access: vec![PUBLIC_SELECT],
 });
 
+pub static MZ_SHOW_PUBLICATIONS: LazyLock<BuiltinView> = LazyLock::new(|| BuiltinView {
+    name: "mz_show_publications",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::VIEW_MZ_SHOW_PUBLICATIONS_OID,
+    desc: RelationDesc::builder()
+        .with_column("schema_id", ScalarType::String.nullable(false))
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("comment", ScalarType::String.nullable(false))
+        .finish(),
+    column_comments: BTreeMap::new(),
+    sql: "WITH comments AS (
+        SELECT id, comment
+        FROM mz_internal.mz_comments
+        WHERE object_type = 'publication' AND object_sub_id IS NULL
+    )
+    SELECT schema_id, name, COALESCE(comment, '') as comment
+    FROM mz_internal.mz_publications publications
+    LEFT JOIN comments ON publications.id = comments.id",
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_SHOW_NOTIFICATIONS: LazyLock<BuiltinView> = LazyLock::new(|| BuiltinView {
+    name: "mz_show_notifications",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::VIEW_MZ_SHOW_NOTIFICATIONS_OID,
+    desc: RelationDesc::builder()
+        .with_column("schema_id", ScalarType::String.nullable(false))
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("on", ScalarType::String.nullable(false))
+        .with_column("channel", ScalarType::String.nullable(false))
+        .with_column("comment", ScalarType::String.nullable(false))
+        .finish(),
+    column_comments: BTreeMap::new(),
+    sql: "WITH comments AS (
+        SELECT id, comment
+        FROM mz_internal.mz_comments
+        WHERE object_type = 'notification' AND object_sub_id IS NULL
+    )
+    SELECT
+        notifications.schema_id,
+        notifications.name,
+        mvs.name AS \"on\",
+        notifications.channel,
+        COALESCE(comment, '') as comment
+    FROM mz_internal.mz_notifications notifications
+    JOIN mz_catalog.mz_materialized_views mvs ON notifications.on_id = mvs.id
+    LEFT JOIN comments ON notifications.id = comments.id",
+    access: vec![PUBLIC_SELECT],
+});
+
 pub static MZ_SHOW_COLUMNS: LazyLock<BuiltinView> = LazyLock::new(|| BuiltinView {
     name: "mz_show_columns",
     schema: MZ_INTERNAL_SCHEMA,
@@ -13500,6 +13837,7 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Type(&TYPE_ANY),
         Builtin::Type(&TYPE_ANYARRAY),
         Builtin::Type(&TYPE_ANYELEMENT),
+        Builtin::Type(&TYPE_ANYENUM),
         Builtin::Type(&TYPE_ANYNONARRAY),
         Builtin::Type(&TYPE_ANYRANGE),
         Builtin::Type(&TYPE_BOOL),
@@ -13552,6 +13890,10 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Type(&TYPE_TIMESTAMPTZ_ARRAY),
         Builtin::Type(&TYPE_UUID),
         Builtin::Type(&TYPE_UUID_ARRAY),
+        Builtin::Type(&TYPE_TSVECTOR),
+        Builtin::Type(&TYPE_TSVECTOR_ARRAY),
+        Builtin::Type(&TYPE_TSQUERY),
+        Builtin::Type(&TYPE_TSQUERY_ARRAY),
         Builtin::Type(&TYPE_VARCHAR),
         Builtin::Type(&TYPE_VARCHAR_ARRAY),
         Builtin::Type(&TYPE_INT2_VECTOR),
@@ -13587,6 +13929,10 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Type(&TYPE_ACL_ITEM),
         Builtin::Type(&TYPE_ACL_ITEM_ARRAY),
         Builtin::Type(&TYPE_INTERNAL),
+        Builtin::Type(&TYPE_VECTOR),
+        Builtin::Type(&TYPE_VECTOR_ARRAY),
+        Builtin::Type(&TYPE_GEOMETRY),
+        Builtin::Type(&TYPE_GEOMETRY_ARRAY),
     ];
     for (schema, funcs) in &[
         (PG_CATALOG_SCHEMA, &*mz_sql::func::PG_CATALOG_BUILTINS),
@@ -13629,6 +13975,7 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Log(&MZ_DATAFLOW_SHUTDOWN_DURATIONS_HISTOGRAM_RAW),
         Builtin::Log(&MZ_ARRANGEMENT_HEAP_CAPACITY_RAW),
         Builtin::Log(&MZ_ARRANGEMENT_HEAP_ALLOCATIONS_RAW),
+        Builtin::Log(&MZ_ARRANGEMENT_SPILLED_SIZE_RAW),
         Builtin::Log(&MZ_ARRANGEMENT_HEAP_SIZE_RAW),
         Builtin::Log(&MZ_SCHEDULING_ELAPSED_RAW),
         Builtin::Log(&MZ_COMPUTE_OPERATOR_DURATIONS_HISTOGRAM_RAW),
@@ -13700,6 +14047,10 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Table(&MZ_CONTINUAL_TASKS),
         Builtin::Table(&MZ_NETWORK_POLICIES),
         Builtin::Table(&MZ_NETWORK_POLICY_RULES),
+        Builtin::Table(&MZ_PUBLICATIONS),
+        Builtin::Table(&MZ_PUBLICATION_TABLES),
+        Builtin::Table(&MZ_NOTIFICATIONS),
+        Builtin::Table(&MZ_REPLICATION_SLOTS),
         Builtin::View(&MZ_RELATIONS),
         Builtin::View(&MZ_OBJECT_OID_ALIAS),
         Builtin::View(&MZ_OBJECTS),
@@ -13908,6 +14259,8 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::View(&MZ_HYDRATION_STATUSES),
         Builtin::View(&MZ_SHOW_CLUSTER_REPLICAS),
         Builtin::View(&MZ_SHOW_NETWORK_POLICIES),
+        Builtin::View(&MZ_SHOW_PUBLICATIONS),
+        Builtin::View(&MZ_SHOW_NOTIFICATIONS),
         Builtin::View(&MZ_CLUSTER_DEPLOYMENT_LINEAGE),
         Builtin::Index(&MZ_SHOW_DATABASES_IND),
         Builtin::Index(&MZ_SHOW_SCHEMAS_IND),
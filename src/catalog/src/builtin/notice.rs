@@ -57,6 +57,54 @@ pub static MZ_OPTIMIZER_NOTICES: LazyLock<BuiltinTable> = LazyLock::new(|| {
     }
 });
 
+/// Indexes suggested by the index advisor, which replays logged `SELECT`s
+/// against hypothetical indexes. Each row corresponds to an
+/// `IndexWouldEnableFastPath` notice in [`MZ_OPTIMIZER_NOTICES`].
+pub static MZ_INDEX_SUGGESTIONS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_index_suggestions",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_INDEX_SUGGESTIONS_OID,
+    desc: RelationDesc::builder()
+        .with_column("notice_id", ScalarType::String.nullable(false))
+        .with_column("object_id", ScalarType::String.nullable(false))
+        .with_column("cluster_id", ScalarType::String.nullable(false))
+        .with_column("create_sql", ScalarType::String.nullable(false))
+        .with_column("executions", ScalarType::UInt64.nullable(false))
+        .with_column("estimated_records", ScalarType::UInt64.nullable(true))
+        .with_column("score", ScalarType::Float64.nullable(true))
+        .with_key(vec![0])
+        .finish(),
+    column_comments: BTreeMap::from_iter([
+        (
+            "notice_id",
+            "The ID of the notice that describes this suggestion. Corresponds to `mz_notices.id`.",
+        ),
+        (
+            "object_id",
+            "The ID of the object to index. Corresponds to `mz_objects.id`.",
+        ),
+        (
+            "cluster_id",
+            "The ID of the cluster that ran the queries. Corresponds to `mz_clusters.id`.",
+        ),
+        ("create_sql", "The `CREATE INDEX` statement to run."),
+        (
+            "executions",
+            "The estimated number of logged executions that would have been fast-path peeks.",
+        ),
+        (
+            "estimated_records",
+            "The estimated number of records the index would need to maintain. `NULL` if unknown.",
+        ),
+        (
+            "score",
+            "Executions per thousand maintained records; higher is better. `NULL` if `estimated_records` is unknown.",
+        ),
+    ]),
+    is_retained_metrics_object: false,
+    access: vec![MONITOR_SELECT],
+});
+
 /// An [`MZ_NOTICES`] that is made safe to be viewed by Materialize staff
 /// because it binds the `redacted_~` from [`MZ_NOTICES`] as `~`.
 ///
@@ -219,6 +267,7 @@ pub const MZ_NOTICES_IND: BuiltinIndex = BuiltinIndex {
 pub(super) fn builtins() -> impl Iterator<Item = Builtin<NameReference>> {
     [
         Builtin::Table(&MZ_OPTIMIZER_NOTICES),
+        Builtin::Table(&MZ_INDEX_SUGGESTIONS),
         Builtin::View(&MZ_NOTICES),
         Builtin::View(&MZ_NOTICES_REDACTED),
         Builtin::Index(&MZ_NOTICES_IND),
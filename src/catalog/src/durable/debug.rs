@@ -64,6 +64,7 @@ pub enum CollectionType {
     IdAlloc,
     Item,
     NetworkPolicy,
+    ReplicationSlot,
     Role,
     RoleAuth,
     Schema,
@@ -208,6 +209,14 @@ collection_impl!({
     trace_field: network_policies,
     update: StateUpdateKind::NetworkPolicy,
 });
+collection_impl!({
+    name: ReplicationSlotCollection,
+    key: proto::ReplicationSlotKey,
+    value: proto::ReplicationSlotValue,
+    collection_type: CollectionType::ReplicationSlot,
+    trace_field: replication_slots,
+    update: StateUpdateKind::ReplicationSlot,
+});
 collection_impl!({
     name: RoleCollection,
     key: proto::RoleKey,
@@ -339,6 +348,7 @@ pub struct Trace {
     pub id_allocator: CollectionTrace<IdAllocatorCollection>,
     pub items: CollectionTrace<ItemCollection>,
     pub network_policies: CollectionTrace<NetworkPolicyCollection>,
+    pub replication_slots: CollectionTrace<ReplicationSlotCollection>,
     pub roles: CollectionTrace<RoleCollection>,
     pub role_auth: CollectionTrace<RoleAuthCollection>,
     pub schemas: CollectionTrace<SchemaCollection>,
@@ -366,6 +376,7 @@ impl Trace {
             id_allocator: CollectionTrace::new(),
             items: CollectionTrace::new(),
             network_policies: CollectionTrace::new(),
+            replication_slots: CollectionTrace::new(),
             roles: CollectionTrace::new(),
             role_auth: CollectionTrace::new(),
             schemas: CollectionTrace::new(),
@@ -393,6 +404,7 @@ impl Trace {
             id_allocator,
             items,
             network_policies,
+            replication_slots,
             roles,
             role_auth,
             schemas,
@@ -416,6 +428,7 @@ impl Trace {
         id_allocator.sort();
         items.sort();
         network_policies.sort();
+        replication_slots.sort();
         roles.sort();
         role_auth.sort();
         schemas.sort();
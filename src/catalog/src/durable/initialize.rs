@@ -403,6 +403,8 @@ pub(crate) async fn initialize(
             ObjectType::Func => mz_audit_log::ObjectType::Func,
             ObjectType::ContinualTask => mz_audit_log::ObjectType::ContinualTask,
             ObjectType::NetworkPolicy => mz_audit_log::ObjectType::NetworkPolicy,
+            ObjectType::Publication => mz_audit_log::ObjectType::Publication,
+            ObjectType::Notification => mz_audit_log::ObjectType::Notification,
         };
         audit_events.push((
             mz_audit_log::EventType::Grant,
@@ -800,6 +802,8 @@ fn default_cluster_config(args: &BootstrapArgs) -> Result<ClusterConfig, Catalog
             schedule: Default::default(),
         }),
         workload_class: None,
+        arrangement_spill: false,
+        auto_index: false,
     })
 }
 
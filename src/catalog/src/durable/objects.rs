@@ -29,7 +29,7 @@ pub mod serialization;
 pub(crate) mod state_update;
 
 use std::cmp::Ordering;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use mz_audit_log::VersionedEvent;
 use mz_controller::clusters::ReplicaLogging;
@@ -334,6 +334,8 @@ impl DurableType for Cluster {
 pub struct ClusterConfig {
     pub variant: ClusterVariant,
     pub workload_class: Option<String>,
+    pub arrangement_spill: bool,
+    pub auto_index: bool,
 }
 
 #[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord)]
@@ -637,6 +639,50 @@ impl DurableType for SourceReferences {
     }
 }
 
+/// A logical replication slot, which records how far a consumer of the
+/// replication protocol has confirmed the changes to `collections`.
+#[derive(Debug, Clone, Ord, PartialOrd, PartialEq, Eq)]
+pub struct ReplicationSlot {
+    pub name: String,
+    pub plugin: String,
+    pub confirmed_lsn: u64,
+    pub collections: BTreeSet<GlobalId>,
+    pub owner_id: RoleId,
+}
+
+impl DurableType for ReplicationSlot {
+    type Key = ReplicationSlotKey;
+    type Value = ReplicationSlotValue;
+
+    fn into_key_value(self) -> (Self::Key, Self::Value) {
+        (
+            ReplicationSlotKey { name: self.name },
+            ReplicationSlotValue {
+                plugin: self.plugin,
+                confirmed_lsn: self.confirmed_lsn,
+                collections: self.collections,
+                owner_id: self.owner_id,
+            },
+        )
+    }
+
+    fn from_key_value(key: Self::Key, value: Self::Value) -> Self {
+        Self {
+            name: key.name,
+            plugin: value.plugin,
+            confirmed_lsn: value.confirmed_lsn,
+            collections: value.collections,
+            owner_id: value.owner_id,
+        }
+    }
+
+    fn key(&self) -> Self::Key {
+        ReplicationSlotKey {
+            name: self.name.clone(),
+        }
+    }
+}
+
 /// A newtype wrapper for [`CatalogItemId`] that is only for the "system" namespace.
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, PartialEq, Eq)]
 pub struct SystemCatalogItemId(u64);
@@ -1168,6 +1214,7 @@ pub struct Snapshot {
         BTreeMap<proto::ServerConfigurationKey, proto::ServerConfigurationValue>,
     pub default_privileges: BTreeMap<proto::DefaultPrivilegesKey, proto::DefaultPrivilegesValue>,
     pub source_references: BTreeMap<proto::SourceReferencesKey, proto::SourceReferencesValue>,
+    pub replication_slots: BTreeMap<proto::ReplicationSlotKey, proto::ReplicationSlotValue>,
     pub system_privileges: BTreeMap<proto::SystemPrivilegesKey, proto::SystemPrivilegesValue>,
     pub storage_collection_metadata:
         BTreeMap<proto::StorageCollectionMetadataKey, proto::StorageCollectionMetadataValue>,
@@ -1302,6 +1349,19 @@ pub struct SourceReferencesValue {
     pub(crate) updated_at: u64,
 }
 
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct ReplicationSlotKey {
+    pub(crate) name: String,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct ReplicationSlotValue {
+    pub(crate) plugin: String,
+    pub(crate) confirmed_lsn: u64,
+    pub(crate) collections: BTreeSet<GlobalId>,
+    pub(crate) owner_id: RoleId,
+}
+
 #[derive(Clone, Copy, Debug, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
 pub struct SchemaKey {
     pub(crate) id: SchemaId,
@@ -1363,6 +1423,8 @@ fn item_type(create_sql: &str) -> CatalogItemType {
         Some("FUNCTION") => CatalogItemType::Func,
         Some("SECRET") => CatalogItemType::Secret,
         Some("CONNECTION") => CatalogItemType::Connection,
+        Some("PUBLICATION") => CatalogItemType::Publication,
+        Some("NOTIFICATION") => CatalogItemType::Notification,
         _ => panic!("unexpected create sql: {}", create_sql),
     }
 }
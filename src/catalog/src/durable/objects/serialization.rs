@@ -19,11 +19,12 @@ use crate::durable::objects::{
     ConfigKey, ConfigValue, DatabaseKey, DatabaseValue, DefaultPrivilegesKey,
     DefaultPrivilegesValue, GidMappingKey, GidMappingValue, IdAllocKey, IdAllocValue,
     IntrospectionSourceIndexCatalogItemId, IntrospectionSourceIndexGlobalId, ItemKey, ItemValue,
-    NetworkPolicyKey, NetworkPolicyValue, RoleKey, RoleValue, SchemaKey, SchemaValue,
-    ServerConfigurationKey, ServerConfigurationValue, SettingKey, SettingValue, SourceReference,
-    SourceReferencesKey, SourceReferencesValue, StorageCollectionMetadataKey,
-    StorageCollectionMetadataValue, SystemCatalogItemId, SystemGlobalId, SystemPrivilegesKey,
-    SystemPrivilegesValue, TxnWalShardValue, UnfinalizedShardKey,
+    NetworkPolicyKey, NetworkPolicyValue, ReplicationSlotKey, ReplicationSlotValue, RoleKey,
+    RoleValue, SchemaKey, SchemaValue, ServerConfigurationKey, ServerConfigurationValue,
+    SettingKey, SettingValue, SourceReference, SourceReferencesKey, SourceReferencesValue,
+    StorageCollectionMetadataKey, StorageCollectionMetadataValue, SystemCatalogItemId,
+    SystemGlobalId, SystemPrivilegesKey, SystemPrivilegesValue, TxnWalShardValue,
+    UnfinalizedShardKey,
 };
 use crate::durable::{
     ClusterConfig, ClusterVariant, ClusterVariantManaged, ReplicaConfig, ReplicaLocation,
@@ -54,6 +55,8 @@ impl RustType<proto::ClusterConfig> for ClusterConfig {
         proto::ClusterConfig {
             variant: Some(self.variant.into_proto()),
             workload_class: self.workload_class.clone(),
+            arrangement_spill: self.arrangement_spill,
+            auto_index: self.auto_index,
         }
     }
 
@@ -61,6 +64,8 @@ impl RustType<proto::ClusterConfig> for ClusterConfig {
         Ok(Self {
             variant: proto.variant.into_rust_if_some("ClusterConfig::variant")?,
             workload_class: proto.workload_class,
+            arrangement_spill: proto.arrangement_spill,
+            auto_index: proto.auto_index,
         })
     }
 }
@@ -855,6 +860,44 @@ impl RustType<proto::SourceReferencesValue> for SourceReferencesValue {
     }
 }
 
+impl RustType<proto::ReplicationSlotKey> for ReplicationSlotKey {
+    fn into_proto(&self) -> proto::ReplicationSlotKey {
+        proto::ReplicationSlotKey {
+            name: self.name.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::ReplicationSlotKey) -> Result<Self, TryFromProtoError> {
+        Ok(ReplicationSlotKey { name: proto.name })
+    }
+}
+
+impl RustType<proto::ReplicationSlotValue> for ReplicationSlotValue {
+    fn into_proto(&self) -> proto::ReplicationSlotValue {
+        proto::ReplicationSlotValue {
+            plugin: self.plugin.clone(),
+            confirmed_lsn: self.confirmed_lsn,
+            collections: self.collections.iter().map(|id| id.into_proto()).collect(),
+            owner_id: Some(self.owner_id.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: proto::ReplicationSlotValue) -> Result<Self, TryFromProtoError> {
+        Ok(ReplicationSlotValue {
+            plugin: proto.plugin,
+            confirmed_lsn: proto.confirmed_lsn,
+            collections: proto
+                .collections
+                .into_iter()
+                .map(|id| id.into_rust())
+                .collect::<Result<_, _>>()?,
+            owner_id: proto
+                .owner_id
+                .into_rust_if_some("ReplicationSlotValue::owner_id")?,
+        })
+    }
+}
+
 impl RustType<proto::SourceReference> for SourceReference {
     fn into_proto(&self) -> proto::SourceReference {
         proto::SourceReference {
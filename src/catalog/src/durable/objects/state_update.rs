@@ -146,6 +146,7 @@ impl StateUpdate {
             configs,
             settings,
             source_references,
+            replication_slots,
             system_gid_mapping,
             system_configurations,
             default_privileges,
@@ -178,6 +179,7 @@ impl StateUpdate {
             from_batch(system_configurations, StateUpdateKind::SystemConfiguration);
         let default_privileges = from_batch(default_privileges, StateUpdateKind::DefaultPrivilege);
         let source_references = from_batch(source_references, StateUpdateKind::SourceReferences);
+        let replication_slots = from_batch(replication_slots, StateUpdateKind::ReplicationSlot);
         let system_privileges = from_batch(system_privileges, StateUpdateKind::SystemPrivilege);
         let storage_collection_metadata = from_batch(
             storage_collection_metadata,
@@ -201,6 +203,7 @@ impl StateUpdate {
             .chain(configs)
             .chain(settings)
             .chain(source_references)
+            .chain(replication_slots)
             .chain(system_object_mappings)
             .chain(system_configurations)
             .chain(default_privileges)
@@ -233,6 +236,7 @@ pub enum StateUpdateKind {
     ),
     Item(proto::ItemKey, proto::ItemValue),
     NetworkPolicy(proto::NetworkPolicyKey, proto::NetworkPolicyValue),
+    ReplicationSlot(proto::ReplicationSlotKey, proto::ReplicationSlotValue),
     Role(proto::RoleKey, proto::RoleValue),
     RoleAuth(proto::RoleAuthKey, proto::RoleAuthValue),
     Schema(proto::SchemaKey, proto::SchemaValue),
@@ -269,6 +273,7 @@ impl StateUpdateKind {
             }
             StateUpdateKind::Item(_, _) => Some(CollectionType::Item),
             StateUpdateKind::NetworkPolicy(_, _) => Some(CollectionType::NetworkPolicy),
+            StateUpdateKind::ReplicationSlot(_, _) => Some(CollectionType::ReplicationSlot),
             StateUpdateKind::Role(_, _) => Some(CollectionType::Role),
             StateUpdateKind::RoleAuth(_, _) => Some(CollectionType::RoleAuth),
             StateUpdateKind::Schema(_, _) => Some(CollectionType::Schema),
@@ -474,6 +479,10 @@ impl TryFrom<&StateUpdateKind> for Option<memory::objects::StateUpdateKind> {
                 let policy = into_durable(key, value)?;
                 Some(memory::objects::StateUpdateKind::NetworkPolicy(policy))
             }
+            StateUpdateKind::ReplicationSlot(key, value) => {
+                let slot = into_durable(key, value)?;
+                Some(memory::objects::StateUpdateKind::ReplicationSlot(slot))
+            }
             StateUpdateKind::Role(key, value) => {
                 let role = into_durable(key, value)?;
                 Some(memory::objects::StateUpdateKind::Role(role))
@@ -699,6 +708,14 @@ impl RustType<proto::StateUpdateKind> for StateUpdateKind {
                         },
                     )
                 }
+                StateUpdateKind::ReplicationSlot(key, value) => {
+                    proto::state_update_kind::Kind::ReplicationSlot(
+                        proto::state_update_kind::ReplicationSlot {
+                            key: Some(key),
+                            value: Some(value),
+                        },
+                    )
+                }
                 StateUpdateKind::SystemConfiguration(key, value) => {
                     proto::state_update_kind::Kind::ServerConfiguration(
                         proto::state_update_kind::ServerConfiguration {
@@ -998,6 +1015,18 @@ impl RustType<proto::StateUpdateKind> for StateUpdateKind {
                         )
                     })?,
                 ),
+                proto::state_update_kind::Kind::ReplicationSlot(
+                    proto::state_update_kind::ReplicationSlot { key, value },
+                ) => StateUpdateKind::ReplicationSlot(
+                    key.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::ReplicationSlot::key")
+                    })?,
+                    value.ok_or_else(|| {
+                        TryFromProtoError::missing_field(
+                            "state_update_kind::ReplicationSlot::value",
+                        )
+                    })?,
+                ),
                 proto::state_update_kind::Kind::NetworkPolicy(
                     proto::state_update_kind::NetworkPolicy { key, value },
                 ) => StateUpdateKind::NetworkPolicy(
@@ -829,6 +829,9 @@ impl<U: ApplyUpdate<StateUpdateKind>> PersistHandle<StateUpdateKind, U> {
                     StateUpdateKind::NetworkPolicy(key, value) => {
                         apply(&mut snapshot.network_policies, key, value, diff);
                     }
+                    StateUpdateKind::ReplicationSlot(key, value) => {
+                        apply(&mut snapshot.replication_slots, key, value, diff);
+                    }
                     StateUpdateKind::Role(key, value) => {
                         apply(&mut snapshot.roles, key, value, diff);
                     }
@@ -1972,6 +1975,9 @@ impl Trace {
                 StateUpdateKind::NetworkPolicy(k, v) => {
                     trace.network_policies.values.push(((k, v), ts, diff))
                 }
+                StateUpdateKind::ReplicationSlot(k, v) => {
+                    trace.replication_slots.values.push(((k, v), ts, diff))
+                }
                 StateUpdateKind::Role(k, v) => trace.roles.values.push(((k, v), ts, diff)),
                 StateUpdateKind::Schema(k, v) => trace.schemas.values.push(((k, v), ts, diff)),
                 StateUpdateKind::Setting(k, v) => trace.settings.values.push(((k, v), ts, diff)),
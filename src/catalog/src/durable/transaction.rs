@@ -52,16 +52,17 @@ use crate::durable::objects::{
     Database, DatabaseKey, DatabaseValue, DefaultPrivilegesKey, DefaultPrivilegesValue,
     DurableType, GidMappingKey, GidMappingValue, IdAllocKey, IdAllocValue,
     IntrospectionSourceIndex, Item, ItemKey, ItemValue, NetworkPolicyKey, NetworkPolicyValue,
-    ReplicaConfig, Role, RoleKey, RoleValue, Schema, SchemaKey, SchemaValue,
-    ServerConfigurationKey, ServerConfigurationValue, SettingKey, SettingValue, SourceReference,
-    SourceReferencesKey, SourceReferencesValue, StorageCollectionMetadataKey,
-    StorageCollectionMetadataValue, SystemObjectDescription, SystemObjectMapping,
-    SystemPrivilegesKey, SystemPrivilegesValue, TxnWalShardValue, UnfinalizedShardKey,
+    ReplicaConfig, ReplicationSlotKey, ReplicationSlotValue, Role, RoleKey, RoleValue, Schema,
+    SchemaKey, SchemaValue, ServerConfigurationKey, ServerConfigurationValue, SettingKey,
+    SettingValue, SourceReference, SourceReferencesKey, SourceReferencesValue,
+    StorageCollectionMetadataKey, StorageCollectionMetadataValue, SystemObjectDescription,
+    SystemObjectMapping, SystemPrivilegesKey, SystemPrivilegesValue, TxnWalShardValue,
+    UnfinalizedShardKey,
 };
 use crate::durable::{
     AUDIT_LOG_ID_ALLOC_KEY, BUILTIN_MIGRATION_SHARD_KEY, CATALOG_CONTENT_VERSION_KEY, CatalogError,
     DATABASE_ID_ALLOC_KEY, DefaultPrivilege, DurableCatalogError, DurableCatalogState,
-    EXPRESSION_CACHE_SHARD_KEY, NetworkPolicy, OID_ALLOC_KEY, SCHEMA_ID_ALLOC_KEY,
+    EXPRESSION_CACHE_SHARD_KEY, NetworkPolicy, OID_ALLOC_KEY, ReplicationSlot, SCHEMA_ID_ALLOC_KEY,
     STORAGE_USAGE_ID_ALLOC_KEY, SYSTEM_CLUSTER_ID_ALLOC_KEY, SYSTEM_ITEM_ALLOC_KEY,
     SYSTEM_REPLICA_ID_ALLOC_KEY, Snapshot, SystemConfiguration, USER_ITEM_ALLOC_KEY,
     USER_NETWORK_POLICY_ID_ALLOC_KEY, USER_REPLICA_ID_ALLOC_KEY, USER_ROLE_ID_ALLOC_KEY,
@@ -95,6 +96,7 @@ pub struct Transaction<'a> {
     system_configurations: TableTransaction<ServerConfigurationKey, ServerConfigurationValue>,
     default_privileges: TableTransaction<DefaultPrivilegesKey, DefaultPrivilegesValue>,
     source_references: TableTransaction<SourceReferencesKey, SourceReferencesValue>,
+    replication_slots: TableTransaction<ReplicationSlotKey, ReplicationSlotValue>,
     system_privileges: TableTransaction<SystemPrivilegesKey, SystemPrivilegesValue>,
     network_policies: TableTransaction<NetworkPolicyKey, NetworkPolicyValue>,
     storage_collection_metadata:
@@ -128,6 +130,7 @@ impl<'a> Transaction<'a> {
             configs,
             settings,
             source_references,
+            replication_slots,
             system_object_mappings,
             system_configurations,
             default_privileges,
@@ -178,6 +181,7 @@ impl<'a> Transaction<'a> {
             configs: TableTransaction::new(configs)?,
             settings: TableTransaction::new(settings)?,
             source_references: TableTransaction::new(source_references)?,
+            replication_slots: TableTransaction::new(replication_slots)?,
             system_gid_mapping: TableTransaction::new(system_object_mappings)?,
             system_configurations: TableTransaction::new(system_configurations)?,
             default_privileges: TableTransaction::new(default_privileges)?,
@@ -848,6 +852,7 @@ impl<'a> Transaction<'a> {
             LogVariant::Compute(ComputeLog::HydrationTime) => 29,
             LogVariant::Compute(ComputeLog::LirMapping) => 30,
             LogVariant::Compute(ComputeLog::DataflowGlobal) => 31,
+            LogVariant::Compute(ComputeLog::ArrangementSpilledSize) => 32,
         };
 
         let mut id: u64 = u64::from(cluster_variant) << 56;
@@ -2089,6 +2094,54 @@ impl<'a> Transaction<'a> {
         Ok(())
     }
 
+    /// Creates the replication slot `slot`.
+    ///
+    /// Returns an error if a slot with the same name already exists.
+    pub fn insert_replication_slot(&mut self, slot: ReplicationSlot) -> Result<(), CatalogError> {
+        let name = slot.name.clone();
+        let (key, value) = slot.into_key_value();
+        match self.replication_slots.insert(key, value, self.op_id) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SqlCatalogError::ReplicationSlotAlreadyExists(name).into()),
+        }
+    }
+
+    /// Overwrites the durable state of the existing replication slot `slot`.
+    ///
+    /// Returns an error if no slot with that name exists.
+    pub fn update_replication_slot(&mut self, slot: ReplicationSlot) -> Result<(), CatalogError> {
+        let name = slot.name.clone();
+        let (key, value) = slot.into_key_value();
+        if self
+            .replication_slots
+            .update_by_key(key, value, self.op_id)?
+        {
+            Ok(())
+        } else {
+            Err(SqlCatalogError::UnknownReplicationSlot(name).into())
+        }
+    }
+
+    /// Removes the replication slot named `name`.
+    ///
+    /// Returns an error if no slot with that name exists.
+    pub fn remove_replication_slot(&mut self, name: &str) -> Result<(), CatalogError> {
+        let key = ReplicationSlotKey {
+            name: name.to_string(),
+        };
+        match self.replication_slots.delete_by_key(key, self.op_id) {
+            Some(_) => Ok(()),
+            None => Err(SqlCatalogError::UnknownReplicationSlot(name.to_string()).into()),
+        }
+    }
+
+    pub fn get_replication_slots(&self) -> impl Iterator<Item = ReplicationSlot> + use<'_> {
+        self.replication_slots
+            .items()
+            .into_iter()
+            .map(|(k, v)| DurableType::from_key_value(k.clone(), v.clone()))
+    }
+
     /// Upserts persisted system configuration `name` to `value`.
     pub fn upsert_system_config(&mut self, name: &str, value: String) -> Result<(), CatalogError> {
         let key = ServerConfigurationKey {
@@ -2283,6 +2336,7 @@ impl<'a> Transaction<'a> {
             system_configurations,
             default_privileges,
             source_references,
+            replication_slots,
             system_privileges,
             audit_log_updates,
             storage_collection_metadata,
@@ -2372,6 +2426,11 @@ impl<'a> Transaction<'a> {
                 StateUpdateKind::SourceReferences,
                 self.op_id,
             ))
+            .chain(get_collection_op_updates(
+                replication_slots,
+                StateUpdateKind::ReplicationSlot,
+                self.op_id,
+            ))
             .chain(get_collection_op_updates(
                 storage_collection_metadata,
                 StateUpdateKind::StorageCollectionMetadata,
@@ -2434,6 +2493,7 @@ impl<'a> Transaction<'a> {
             id_allocator: self.id_allocator.pending(),
             configs: self.configs.pending(),
             source_references: self.source_references.pending(),
+            replication_slots: self.replication_slots.pending(),
             settings: self.settings.pending(),
             system_gid_mapping: self.system_gid_mapping.pending(),
             system_configurations: self.system_configurations.pending(),
@@ -2479,6 +2539,7 @@ impl<'a> Transaction<'a> {
             id_allocator,
             configs,
             source_references,
+            replication_slots,
             settings,
             system_gid_mapping,
             system_configurations,
@@ -2506,6 +2567,7 @@ impl<'a> Transaction<'a> {
         differential_dataflow::consolidation::consolidate_updates(configs);
         differential_dataflow::consolidation::consolidate_updates(settings);
         differential_dataflow::consolidation::consolidate_updates(source_references);
+        differential_dataflow::consolidation::consolidate_updates(replication_slots);
         differential_dataflow::consolidation::consolidate_updates(system_gid_mapping);
         differential_dataflow::consolidation::consolidate_updates(system_configurations);
         differential_dataflow::consolidation::consolidate_updates(default_privileges);
@@ -2718,6 +2780,8 @@ pub struct TransactionBatch {
         proto::SourceReferencesValue,
         Diff,
     )>,
+    pub(crate) replication_slots:
+        Vec<(proto::ReplicationSlotKey, proto::ReplicationSlotValue, Diff)>,
     pub(crate) system_privileges: Vec<(
         proto::SystemPrivilegesKey,
         proto::SystemPrivilegesValue,
@@ -2752,6 +2816,7 @@ impl TransactionBatch {
             configs,
             settings,
             source_references,
+            replication_slots,
             system_gid_mapping,
             system_configurations,
             default_privileges,
@@ -2776,6 +2841,7 @@ impl TransactionBatch {
             && configs.is_empty()
             && settings.is_empty()
             && source_references.is_empty()
+            && replication_slots.is_empty()
             && system_gid_mapping.is_empty()
             && system_configurations.is_empty()
             && default_privileges.is_empty()
@@ -2853,6 +2919,7 @@ mod unique_name {
         ServerConfigurationValue,
         SettingValue,
         SourceReferencesValue,
+        ReplicationSlotValue,
         StorageCollectionMetadataValue,
         SystemPrivilegesValue,
         TxnWalShardValue,
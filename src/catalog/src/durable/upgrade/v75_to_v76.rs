@@ -0,0 +1,18 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::durable::upgrade::MigrationAction;
+use crate::durable::upgrade::{objects_v75 as v75, objects_v76 as v76};
+
+/// In v76, we added the notification object type. No existing entries need to change.
+pub fn upgrade(
+    _snapshot: Vec<v75::StateUpdateKind>,
+) -> Vec<MigrationAction<v75::StateUpdateKind, v76::StateUpdateKind>> {
+    Vec::new()
+}
@@ -0,0 +1,18 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::durable::upgrade::MigrationAction;
+use crate::durable::upgrade::{objects_v76 as v76, objects_v77 as v77};
+
+/// In v77, we added the replication slot collection. No existing entries need to change.
+pub fn upgrade(
+    _snapshot: Vec<v76::StateUpdateKind>,
+) -> Vec<MigrationAction<v76::StateUpdateKind, v77::StateUpdateKind>> {
+    Vec::new()
+}
@@ -15,9 +15,11 @@ use std::sync::Arc;
 
 use bytes::Bytes;
 use mz_compute_types::dataflows::DataflowDescription;
+use mz_compute_types::plan::summary::PlanSummary;
 use mz_durable_cache::{DurableCache, DurableCacheCodec};
 use mz_dyncfg::ConfigSet;
 use mz_expr::OptimizedMirRelationExpr;
+use mz_ore::cast::CastFrom;
 use mz_ore::channel::trigger;
 use mz_ore::soft_panic_or_log;
 use mz_ore::task::spawn;
@@ -69,6 +71,67 @@ impl GlobalExpressions {
     }
 }
 
+/// Tags the cached values of [`GlobalExpressions`] that start with a plan summary.
+const GLOBAL_EXPRESSIONS_MAGIC: [u8; 4] = *b"MZGE";
+
+/// The format of the plan summaries in cached [`GlobalExpressions`].
+///
+/// Bump this whenever the meaning of [`PlanSummary`] changes, so that other versions treat the
+/// summaries as unknown instead of comparing them.
+const PLAN_SUMMARY_FORMAT: u64 = 1;
+
+/// A [`PlanSummary`] as stored in the cache, which other versions are able to read.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedPlanSummary {
+    format: u64,
+    summary: PlanSummary,
+}
+
+/// Encodes `expressions` as a cached value.
+///
+/// Expressions carry no compatibility guarantees across versions, so the value starts with a
+/// self-describing summary of the physical plan, which other versions can read without decoding
+/// the expressions: [`GLOBAL_EXPRESSIONS_MAGIC`], the length of the JSON-encoded
+/// [`CachedPlanSummary`] as a little-endian `u32`, the summary, and then the bincode-encoded
+/// expressions.
+fn encode_global_expressions(expressions: &GlobalExpressions) -> Result<Bytes, anyhow::Error> {
+    let summary = serde_json::to_vec(&CachedPlanSummary {
+        format: PLAN_SUMMARY_FORMAT,
+        summary: PlanSummary::from_dataflow(&expressions.physical_plan),
+    })?;
+    let mut value = Vec::from(GLOBAL_EXPRESSIONS_MAGIC);
+    value.extend(u32::try_from(summary.len())?.to_le_bytes());
+    value.extend(summary);
+    bincode::serialize_into(&mut value, expressions)?;
+    Ok(Bytes::from(value))
+}
+
+/// Splits a value encoded by [`encode_global_expressions`] into the encoded plan summary and the
+/// encoded expressions.
+fn split_global_expressions(value: &[u8]) -> Option<(&[u8], &[u8])> {
+    let value = value.strip_prefix(&GLOBAL_EXPRESSIONS_MAGIC)?;
+    let (len, value) = value.split_first_chunk::<4>()?;
+    let len = usize::cast_from(u32::from_le_bytes(*len));
+    (len <= value.len()).then(|| value.split_at(len))
+}
+
+/// Decodes a value encoded by [`encode_global_expressions`] in this version.
+fn decode_global_expressions(value: &[u8]) -> Result<GlobalExpressions, anyhow::Error> {
+    let (_summary, expressions) =
+        split_global_expressions(value).ok_or_else(|| anyhow::anyhow!("missing plan summary"))?;
+    Ok(bincode::deserialize(expressions)?)
+}
+
+/// Decodes the plan summary of a value encoded by [`encode_global_expressions`] in any version.
+///
+/// Returns `None` if the value has no plan summary, e.g., because it was written by a version
+/// that did not write plan summaries, or if the summary has a different format.
+fn decode_plan_summary(value: &[u8]) -> Option<PlanSummary> {
+    let (summary, _expressions) = split_global_expressions(value)?;
+    let summary: CachedPlanSummary = serde_json::from_slice(summary).ok()?;
+    (summary.format == PLAN_SUMMARY_FORMAT).then_some(summary.summary)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Arbitrary)]
 struct CacheKey {
     build_version: String,
@@ -220,8 +283,7 @@ impl ExpressionCache {
                         }
                     }
                     ExpressionType::Global => {
-                        let expressions: GlobalExpressions = match bincode::deserialize(expressions)
-                        {
+                        let expressions = match decode_global_expressions(expressions) {
                             Ok(expressions) => expressions,
                             Err(err) => {
                                 soft_panic_or_log!(
@@ -318,8 +380,8 @@ impl ExpressionCache {
             );
         }
         for (id, expressions) in new_global_expressions {
-            let expressions = match bincode::serialize(&expressions) {
-                Ok(expressions) => Bytes::from(expressions),
+            let expressions = match encode_global_expressions(&expressions) {
+                Ok(expressions) => expressions,
                 Err(err) => {
                     soft_panic_or_log!(
                         "unable to serialize global expressions: {expressions:?}: {err:?}"
@@ -419,6 +481,36 @@ impl ExpressionCacheHandle {
     }
 }
 
+/// Returns the plan summaries of the global expressions of every build version in the cache
+/// backed by `shard_id`, keyed by build version.
+///
+/// Expressions carry no compatibility guarantees across versions, so this only reads the
+/// version-independent plan summaries. The summary of an entry is `None` if it is unknown, e.g.,
+/// because the entry was written by a version that uses a different summary format. No entries
+/// are added to or removed from the cache.
+pub async fn plan_summaries_by_version(
+    persist: &PersistClient,
+    shard_id: ShardId,
+) -> BTreeMap<Version, BTreeMap<GlobalId, Option<PlanSummary>>> {
+    let durable_cache: DurableCache<ExpressionCodec> =
+        DurableCache::new(persist, shard_id, "expressions").await;
+
+    let mut summaries_by_version: BTreeMap<_, BTreeMap<_, _>> = BTreeMap::new();
+    for (key, expressions) in durable_cache.entries_local() {
+        if key.expr_type != ExpressionType::Global {
+            continue;
+        }
+        let Ok(build_version) = key.build_version.parse::<Version>() else {
+            continue;
+        };
+        summaries_by_version
+            .entry(build_version)
+            .or_default()
+            .insert(key.id, decode_plan_summary(expressions));
+    }
+    summaries_by_version
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::{BTreeMap, BTreeSet};
@@ -428,6 +520,7 @@ mod tests {
 
     use bytes::Bytes;
     use mz_compute_types::dataflows::DataflowDescription;
+    use mz_compute_types::plan::summary::PlanSummary;
     use mz_durable_cache::DurableCacheCodec;
     use mz_dyncfg::ConfigSet;
     use mz_expr::OptimizedMirRelationExpr;
@@ -448,7 +541,8 @@ mod tests {
 
     use crate::expr_cache::{
         CacheKey, ExpressionCacheConfig, ExpressionCacheHandle, ExpressionCodec, GlobalExpressions,
-        LocalExpressions,
+        LocalExpressions, decode_global_expressions, decode_plan_summary,
+        encode_global_expressions, plan_summaries_by_version,
     };
 
     impl Arbitrary for LocalExpressions {
@@ -788,6 +882,31 @@ mod tests {
             );
         }
 
+        {
+            // Read the plan summaries of all versions at once.
+            let summaries = |exps: &BTreeMap<GlobalId, GlobalExpressions>| {
+                exps.iter()
+                    .map(|(id, exp)| (*id, Some(PlanSummary::from_dataflow(&exp.physical_plan))))
+                    .collect::<BTreeMap<_, _>>()
+            };
+            let mut summaries_by_version = plan_summaries_by_version(&persist, shard_id).await;
+            assert_eq!(
+                summaries_by_version
+                    .remove(&first_version)
+                    .unwrap_or_default(),
+                summaries(&global_exps),
+                "previous version plan summaries should be readable"
+            );
+            assert_eq!(
+                summaries_by_version
+                    .remove(&second_version)
+                    .unwrap_or_default(),
+                summaries(&new_gen_global_exps),
+                "new version plan summaries should be readable"
+            );
+            assert!(summaries_by_version.is_empty());
+        }
+
         {
             // Open the cache at a new version and clear previous versions.
             remove_prior_versions = true;
@@ -864,12 +983,14 @@ mod tests {
             let global_tree: ArbitraryTimeout<GlobalExpressions> = ArbitraryTimeout::new();
             let val = global_tree.generate();
 
-            let bincode_val = Bytes::from(bincode::serialize(&val).expect("must serialize"));
-            let (encoded_key, encoded_val) = ExpressionCodec::encode(&key, &bincode_val);
+            let encoded = encode_global_expressions(&val).expect("must serialize");
+            let (encoded_key, encoded_val) = ExpressionCodec::encode(&key, &encoded);
             let (decoded_key, decoded_val) = ExpressionCodec::decode(&encoded_key, &encoded_val);
-            let decoded_val: GlobalExpressions = bincode::deserialize(&decoded_val).expect("global expressions should roundtrip");
+            let decoded_summary = decode_plan_summary(&decoded_val);
+            let decoded_val = decode_global_expressions(&decoded_val).expect("global expressions should roundtrip");
 
             assert_eq!(key, decoded_key);
+            assert_eq!(Some(PlanSummary::from_dataflow(&val.physical_plan)), decoded_summary);
             assert_eq!(val, decoded_val);
         }
     }
@@ -35,14 +35,15 @@ use mz_repr::{
 };
 use mz_sql::ast::display::AstDisplay;
 use mz_sql::ast::{
-    ColumnDef, ColumnOption, ColumnOptionDef, ColumnVersioned, Expr, Raw, RawDataType, Statement,
-    UnresolvedItemName, Value, WithOptionValue,
+    ColumnDef, ColumnOption, ColumnOptionDef, ColumnVersioned, CreateTypeAs, Expr, Raw,
+    RawDataType, Statement, UnresolvedItemName, Value, WithOptionValue,
 };
 use mz_sql::catalog::{
-    CatalogClusterReplica, CatalogError as SqlCatalogError, CatalogItem as SqlCatalogItem,
-    CatalogItemType as SqlCatalogItemType, CatalogItemType, CatalogSchema, CatalogTypeDetails,
-    DefaultPrivilegeAclItem, DefaultPrivilegeObject, IdReference, RoleAttributes, RoleMembership,
-    RoleVars, SystemObjectType,
+    CatalogClusterReplica, CatalogError as SqlCatalogError, CatalogFuncDetails,
+    CatalogItem as SqlCatalogItem, CatalogItemType as SqlCatalogItemType, CatalogItemType,
+    CatalogSchema, CatalogType, CatalogTypeDetails, DefaultPrivilegeAclItem,
+    DefaultPrivilegeObject, IdReference, RoleAttributes, RoleMembership, RoleVars,
+    SystemObjectType,
 };
 use mz_sql::names::{
     Aug, CommentObjectId, DatabaseId, DependencyIds, FullItemName, QualifiedItemName,
@@ -51,8 +52,8 @@ use mz_sql::names::{
 use mz_sql::plan::{
     ClusterSchedule, ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, ConnectionDetails,
     CreateClusterManagedPlan, CreateClusterPlan, CreateClusterVariant, CreateSourcePlan,
-    HirRelationExpr, Ingestion as PlanIngestion, NetworkPolicyRule, PlanError, WebhookBodyFormat,
-    WebhookHeaders, WebhookValidation,
+    ForeignKey, HirRelationExpr, IndexKind, Ingestion as PlanIngestion, NetworkPolicyRule,
+    PlanError, WebhookBodyFormat, WebhookHeaders, WebhookValidation,
 };
 use mz_sql::rbac;
 use mz_sql::session::vars::OwnedVarInput;
@@ -442,10 +443,14 @@ impl Cluster {
             }
         };
         let workload_class = self.config.workload_class.clone();
+        let arrangement_spill = self.config.arrangement_spill;
+        let auto_index = self.config.auto_index;
         Ok(CreateClusterPlan {
             name,
             variant,
             workload_class,
+            arrangement_spill,
+            auto_index,
         })
     }
 }
@@ -699,9 +704,12 @@ impl mz_sql::catalog::CatalogCollectionItem for CatalogCollectionEntry {
             CatalogItem::Index(index) => index.global_id,
             CatalogItem::Type(ty) => ty.global_id,
             CatalogItem::Func(func) => func.global_id,
+            CatalogItem::UserFunc(func) => func.global_id,
             CatalogItem::Secret(secret) => secret.global_id,
             CatalogItem::Connection(conn) => conn.global_id,
             CatalogItem::ContinualTask(ct) => ct.global_id,
+            CatalogItem::Publication(publication) => publication.global_id,
+            CatalogItem::Notification(notification) => notification.global_id,
             CatalogItem::Table(table) => match self.version {
                 RelationVersionSelector::Latest => {
                     let (_version, gid) = table
@@ -780,6 +788,10 @@ impl mz_sql::catalog::CatalogItem for CatalogCollectionEntry {
         self.entry.type_details()
     }
 
+    fn func_details(&self) -> Option<&CatalogFuncDetails> {
+        self.entry.func_details()
+    }
+
     fn references(&self) -> &ResolvedIds {
         self.entry.references()
     }
@@ -859,9 +871,12 @@ pub enum CatalogItem {
     Index(Index),
     Type(Type),
     Func(Func),
+    UserFunc(UserFunc),
     Secret(Secret),
     Connection(Connection),
     ContinualTask(ContinualTask),
+    Publication(Publication),
+    Notification(Notification),
 }
 
 impl From<CatalogEntry> for durable::Item {
@@ -904,6 +919,8 @@ pub struct Table {
     pub is_retained_metrics_object: bool,
     /// Where data for this table comes from, e.g. `INSERT` statements or an upstream source.
     pub data_source: TableDataSource,
+    /// `FOREIGN KEY` constraints of this table, derived from the `create_sql`.
+    pub foreign_keys: Vec<ForeignKey>,
 }
 
 impl Table {
@@ -1418,6 +1435,8 @@ pub struct Index {
     pub on: GlobalId,
     /// Keys of the index.
     pub keys: Arc<[MirScalarExpr]>,
+    /// How the index arranges the collection it is on.
+    pub kind: IndexKind,
     /// If created in the `TEMPORARY` schema, the [`ConnectionId`] for that session.
     pub conn_id: Option<ConnectionId>,
     /// Other catalog objects referenced by this index, e.g. the object we're indexing.
@@ -1462,6 +1481,19 @@ pub struct Func {
     pub global_id: GlobalId,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct UserFunc {
+    /// Parse-able SQL that defines this function.
+    pub create_sql: String,
+    /// [`GlobalId`] used to reference this function from outside the catalog.
+    pub global_id: GlobalId,
+    /// The signature of the function.
+    #[serde(skip)]
+    pub details: CatalogFuncDetails,
+    /// Other catalog objects referenced by this function.
+    pub resolved_ids: ResolvedIds,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Secret {
     /// Parse-able SQL that defines this secret.
@@ -1470,6 +1502,34 @@ pub struct Secret {
     pub global_id: GlobalId,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct Publication {
+    /// Parse-able SQL that defines this publication.
+    pub create_sql: String,
+    /// [`GlobalId`] used to reference this publication from outside the catalog.
+    pub global_id: GlobalId,
+    /// The tables and materialized views whose changes this publication emits.
+    pub relations: Vec<CatalogItemId>,
+    /// Other objects this publication depends on.
+    pub resolved_ids: ResolvedIds,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    /// Parse-able SQL that defines this notification.
+    pub create_sql: String,
+    /// [`GlobalId`] used to reference this notification from outside the catalog.
+    pub global_id: GlobalId,
+    /// The materialized view whose changes trigger this notification.
+    pub on: CatalogItemId,
+    /// The channel on which listeners receive this notification.
+    pub channel: String,
+    /// The SQL text of the `WHEN` predicate, if any.
+    pub predicate: Option<String>,
+    /// Other objects this notification depends on.
+    pub resolved_ids: ResolvedIds,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Connection {
     /// Parse-able SQL that defines this connection.
@@ -1600,10 +1660,14 @@ impl CatalogItem {
             CatalogItem::MaterializedView(_) => mz_sql::catalog::CatalogItemType::MaterializedView,
             CatalogItem::Index(_) => mz_sql::catalog::CatalogItemType::Index,
             CatalogItem::Type(_) => mz_sql::catalog::CatalogItemType::Type,
-            CatalogItem::Func(_) => mz_sql::catalog::CatalogItemType::Func,
+            CatalogItem::Func(_) | CatalogItem::UserFunc(_) => {
+                mz_sql::catalog::CatalogItemType::Func
+            }
             CatalogItem::Secret(_) => mz_sql::catalog::CatalogItemType::Secret,
             CatalogItem::Connection(_) => mz_sql::catalog::CatalogItemType::Connection,
             CatalogItem::ContinualTask(_) => mz_sql::catalog::CatalogItemType::ContinualTask,
+            CatalogItem::Publication(_) => mz_sql::catalog::CatalogItemType::Publication,
+            CatalogItem::Notification(_) => mz_sql::catalog::CatalogItemType::Notification,
         }
     }
 
@@ -1618,9 +1682,12 @@ impl CatalogItem {
             CatalogItem::ContinualTask(ct) => ct.global_id,
             CatalogItem::Index(index) => index.global_id,
             CatalogItem::Func(func) => func.global_id,
+            CatalogItem::UserFunc(func) => func.global_id,
             CatalogItem::Type(ty) => ty.global_id,
             CatalogItem::Secret(secret) => secret.global_id,
             CatalogItem::Connection(conn) => conn.global_id,
+            CatalogItem::Publication(publication) => publication.global_id,
+            CatalogItem::Notification(notification) => notification.global_id,
             CatalogItem::Table(table) => {
                 return itertools::Either::Left(table.collections.values().copied());
             }
@@ -1641,9 +1708,12 @@ impl CatalogItem {
             CatalogItem::ContinualTask(ct) => ct.global_id,
             CatalogItem::Index(index) => index.global_id,
             CatalogItem::Func(func) => func.global_id,
+            CatalogItem::UserFunc(func) => func.global_id,
             CatalogItem::Type(ty) => ty.global_id,
             CatalogItem::Secret(secret) => secret.global_id,
             CatalogItem::Connection(conn) => conn.global_id,
+            CatalogItem::Publication(publication) => publication.global_id,
+            CatalogItem::Notification(notification) => notification.global_id,
             CatalogItem::Table(table) => table.global_id_writes(),
         }
     }
@@ -1661,7 +1731,10 @@ impl CatalogItem {
             | CatalogItem::Index(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
+            | CatalogItem::UserFunc(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Publication(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Connection(_) => false,
         }
     }
@@ -1688,9 +1761,12 @@ impl CatalogItem {
             CatalogItem::Type(typ) => typ.desc.as_ref().map(Cow::Borrowed),
             CatalogItem::ContinualTask(ct) => Some(Cow::Borrowed(&ct.desc)),
             CatalogItem::Func(_)
+            | CatalogItem::UserFunc(_)
             | CatalogItem::Index(_)
             | CatalogItem::Sink(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Publication(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Connection(_) => None,
         }
     }
@@ -1746,6 +1822,7 @@ impl CatalogItem {
         static EMPTY: LazyLock<ResolvedIds> = LazyLock::new(ResolvedIds::empty);
         match self {
             CatalogItem::Func(_) => &*EMPTY,
+            CatalogItem::UserFunc(func) => &func.resolved_ids,
             CatalogItem::Index(idx) => &idx.resolved_ids,
             CatalogItem::Sink(sink) => &sink.resolved_ids,
             CatalogItem::Source(source) => &source.resolved_ids,
@@ -1757,6 +1834,8 @@ impl CatalogItem {
             CatalogItem::Secret(_) => &*EMPTY,
             CatalogItem::Connection(connection) => &connection.resolved_ids,
             CatalogItem::ContinualTask(ct) => &ct.resolved_ids,
+            CatalogItem::Publication(publication) => &publication.resolved_ids,
+            CatalogItem::Notification(notification) => &notification.resolved_ids,
         }
     }
 
@@ -1771,6 +1850,7 @@ impl CatalogItem {
             // TODO(jkosh44) This isn't really correct for functions. They may use other objects in
             // their implementation. However, currently there's no way to get that information.
             CatalogItem::Func(_) => {}
+            CatalogItem::UserFunc(_) => {}
             CatalogItem::Index(_) => {}
             CatalogItem::Sink(_) => {}
             CatalogItem::Source(_) => {}
@@ -1784,6 +1864,8 @@ impl CatalogItem {
             CatalogItem::ContinualTask(ct) => uses.extend(ct.dependencies.0.iter().copied()),
             CatalogItem::Secret(_) => {}
             CatalogItem::Connection(_) => {}
+            CatalogItem::Publication(_) => {}
+            CatalogItem::Notification(_) => {}
         }
         uses
     }
@@ -1800,8 +1882,11 @@ impl CatalogItem {
             | CatalogItem::Sink(_)
             | CatalogItem::MaterializedView(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Publication(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
+            | CatalogItem::UserFunc(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => None,
         }
@@ -1872,6 +1957,16 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Secret(i))
             }
+            CatalogItem::Publication(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Publication(i))
+            }
+            CatalogItem::Notification(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Notification(i))
+            }
             CatalogItem::Connection(i) => {
                 let mut i = i.clone();
                 i.create_sql = do_rewrite(i.create_sql)?;
@@ -1883,6 +1978,11 @@ impl CatalogItem {
                 Ok(CatalogItem::Type(i))
             }
             CatalogItem::Func(i) => Ok(CatalogItem::Func(i.clone())),
+            CatalogItem::UserFunc(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::UserFunc(i))
+            }
             CatalogItem::ContinualTask(i) => {
                 let mut i = i.clone();
                 i.create_sql = do_rewrite(i.create_sql)?;
@@ -1950,6 +2050,21 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Secret(i))
             }
+            CatalogItem::Publication(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Publication(i))
+            }
+            CatalogItem::Notification(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Notification(i))
+            }
+            CatalogItem::UserFunc(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::UserFunc(i))
+            }
             CatalogItem::Func(_) | CatalogItem::Type(_) => {
                 unreachable!("{}s cannot be renamed", self.typ())
             }
@@ -2073,6 +2188,32 @@ impl CatalogItem {
         Ok(next_version)
     }
 
+    /// Replaces the labels of an enum type with `labels`.
+    pub fn set_enum_labels(&mut self, labels: Vec<String>) -> Result<(), PlanError> {
+        let CatalogItem::Type(Type { details, .. }) = self else {
+            return Err(PlanError::Unstructured("expected type".to_string()));
+        };
+        let CatalogType::Enum { labels: existing } = &mut details.typ else {
+            return Err(PlanError::Unstructured("expected enum type".to_string()));
+        };
+        *existing = labels.clone();
+
+        let update = |ast: &mut Statement<Raw>| match ast {
+            Statement::CreateType(stmt) => match &mut stmt.as_type {
+                CreateTypeAs::Enum { labels: existing } => {
+                    *existing = labels;
+                    Ok(())
+                }
+                _ => Err(()),
+            },
+            _ => Err(()),
+        };
+
+        self.update_sql(update).map_err(|()| {
+            PlanError::Unstructured("expected CREATE TYPE .. AS ENUM statement".to_string())
+        })
+    }
+
     /// Updates the create_sql field of this item. Returns an error if this is a builtin item,
     /// otherwise returns f's result.
     pub fn update_sql<F, T>(&mut self, f: F) -> Result<T, ()>
@@ -2088,6 +2229,9 @@ impl CatalogItem {
             | CatalogItem::MaterializedView(MaterializedView { create_sql, .. })
             | CatalogItem::Index(Index { create_sql, .. })
             | CatalogItem::Secret(Secret { create_sql, .. })
+            | CatalogItem::Publication(Publication { create_sql, .. })
+            | CatalogItem::Notification(Notification { create_sql, .. })
+            | CatalogItem::UserFunc(UserFunc { create_sql, .. })
             | CatalogItem::Connection(Connection { create_sql, .. })
             | CatalogItem::ContinualTask(ContinualTask { create_sql, .. }) => Some(create_sql),
             CatalogItem::Func(_) | CatalogItem::Log(_) => None,
@@ -2123,7 +2267,10 @@ impl CatalogItem {
             | CatalogItem::Sink(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
+            | CatalogItem::UserFunc(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Publication(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => None,
         }
@@ -2149,7 +2296,10 @@ impl CatalogItem {
             | CatalogItem::View(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
+            | CatalogItem::UserFunc(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Publication(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Connection(_) => None,
         }
     }
@@ -2167,7 +2317,10 @@ impl CatalogItem {
             | CatalogItem::Sink(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
+            | CatalogItem::UserFunc(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Publication(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => None,
         }
@@ -2189,7 +2342,10 @@ impl CatalogItem {
             | CatalogItem::Sink(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
+            | CatalogItem::UserFunc(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Publication(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => return None,
         };
@@ -2215,7 +2371,10 @@ impl CatalogItem {
             | CatalogItem::Sink(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
+            | CatalogItem::UserFunc(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Publication(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Connection(_) => return None,
         };
         Some(custom_logical_compaction_window.unwrap_or(CompactionWindow::Default))
@@ -2235,7 +2394,10 @@ impl CatalogItem {
             | CatalogItem::Sink(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
+            | CatalogItem::UserFunc(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Publication(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => false,
         }
@@ -2284,6 +2446,19 @@ impl CatalogItem {
             CatalogItem::Secret(secret) => {
                 (secret.create_sql.clone(), secret.global_id, BTreeMap::new())
             }
+            CatalogItem::Publication(publication) => (
+                publication.create_sql.clone(),
+                publication.global_id,
+                BTreeMap::new(),
+            ),
+            CatalogItem::Notification(notification) => (
+                notification.create_sql.clone(),
+                notification.global_id,
+                BTreeMap::new(),
+            ),
+            CatalogItem::UserFunc(func) => {
+                (func.create_sql.clone(), func.global_id, BTreeMap::new())
+            }
             CatalogItem::Connection(connection) => (
                 connection.create_sql.clone(),
                 connection.global_id,
@@ -2330,6 +2505,17 @@ impl CatalogItem {
                 (create_sql, typ.global_id, BTreeMap::new())
             }
             CatalogItem::Secret(secret) => (secret.create_sql, secret.global_id, BTreeMap::new()),
+            CatalogItem::Publication(publication) => (
+                publication.create_sql,
+                publication.global_id,
+                BTreeMap::new(),
+            ),
+            CatalogItem::Notification(notification) => (
+                notification.create_sql,
+                notification.global_id,
+                BTreeMap::new(),
+            ),
+            CatalogItem::UserFunc(func) => (func.create_sql, func.global_id, BTreeMap::new()),
             CatalogItem::Connection(connection) => {
                 (connection.create_sql, connection.global_id, BTreeMap::new())
             }
@@ -2412,6 +2598,22 @@ impl CatalogEntry {
         }
     }
 
+    /// Returns the inner [`Publication`] if this entry is a publication, else `None`.
+    pub fn publication(&self) -> Option<&Publication> {
+        match self.item() {
+            CatalogItem::Publication(publication) => Some(publication),
+            _ => None,
+        }
+    }
+
+    /// Returns the inner [`Notification`] if this entry is a notification, else `None`.
+    pub fn notification(&self) -> Option<&Notification> {
+        match self.item() {
+            CatalogItem::Notification(notification) => Some(notification),
+            _ => None,
+        }
+    }
+
     pub fn connection(&self) -> Result<&Connection, SqlCatalogError> {
         match self.item() {
             CatalogItem::Connection(connection) => Ok(connection),
@@ -2535,7 +2737,10 @@ impl CatalogEntry {
             | CatalogItem::Index(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
+            | CatalogItem::UserFunc(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Publication(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => None,
         }
@@ -2927,6 +3132,8 @@ impl DefaultPrivileges {
 pub struct ClusterConfig {
     pub variant: ClusterVariant,
     pub workload_class: Option<String>,
+    pub arrangement_spill: bool,
+    pub auto_index: bool,
 }
 
 impl ClusterConfig {
@@ -2943,6 +3150,8 @@ impl From<ClusterConfig> for durable::ClusterConfig {
         Self {
             variant: config.variant.into(),
             workload_class: config.workload_class,
+            arrangement_spill: config.arrangement_spill,
+            auto_index: config.auto_index,
         }
     }
 }
@@ -2952,6 +3161,8 @@ impl From<durable::ClusterConfig> for ClusterConfig {
         Self {
             variant: config.variant.into(),
             workload_class: config.workload_class,
+            arrangement_spill: config.arrangement_spill,
+            auto_index: config.auto_index,
         }
     }
 }
@@ -3260,6 +3471,9 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
                 create_sql.as_deref().unwrap_or("<builtin>")
             }
             CatalogItem::Secret(Secret { create_sql, .. }) => create_sql,
+            CatalogItem::Publication(Publication { create_sql, .. }) => create_sql,
+            CatalogItem::Notification(Notification { create_sql, .. }) => create_sql,
+            CatalogItem::UserFunc(UserFunc { create_sql, .. }) => create_sql,
             CatalogItem::Connection(Connection { create_sql, .. }) => create_sql,
             CatalogItem::Func(_) => "<builtin>",
             CatalogItem::Log(_) => "<builtin>",
@@ -3299,6 +3513,14 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         }
     }
 
+    fn func_details(&self) -> Option<&CatalogFuncDetails> {
+        if let CatalogItem::UserFunc(UserFunc { details, .. }) = self.item() {
+            Some(details)
+        } else {
+            None
+        }
+    }
+
     fn references(&self) -> &ResolvedIds {
         self.references()
     }
@@ -3392,6 +3614,7 @@ pub enum StateUpdateKind {
     IntrospectionSourceIndex(durable::objects::IntrospectionSourceIndex),
     ClusterReplica(durable::objects::ClusterReplica),
     SourceReferences(durable::objects::SourceReferences),
+    ReplicationSlot(durable::objects::ReplicationSlot),
     SystemObjectMapping(durable::objects::SystemObjectMapping),
     // Temporary items are not actually updated via the durable catalog, but this allows us to
     // model them the same way as all other items.
@@ -3470,6 +3693,7 @@ pub enum BootstrapStateUpdateKind {
     IntrospectionSourceIndex(durable::objects::IntrospectionSourceIndex),
     ClusterReplica(durable::objects::ClusterReplica),
     SourceReferences(durable::objects::SourceReferences),
+    ReplicationSlot(durable::objects::ReplicationSlot),
     SystemObjectMapping(durable::objects::SystemObjectMapping),
     Item(durable::objects::Item),
     Comment(durable::objects::Comment),
@@ -3498,6 +3722,9 @@ impl From<BootstrapStateUpdateKind> for StateUpdateKind {
             BootstrapStateUpdateKind::SourceReferences(kind) => {
                 StateUpdateKind::SourceReferences(kind)
             }
+            BootstrapStateUpdateKind::ReplicationSlot(kind) => {
+                StateUpdateKind::ReplicationSlot(kind)
+            }
             BootstrapStateUpdateKind::Cluster(kind) => StateUpdateKind::Cluster(kind),
             BootstrapStateUpdateKind::NetworkPolicy(kind) => StateUpdateKind::NetworkPolicy(kind),
             BootstrapStateUpdateKind::IntrospectionSourceIndex(kind) => {
@@ -3551,6 +3778,9 @@ impl TryFrom<StateUpdateKind> for BootstrapStateUpdateKind {
             StateUpdateKind::SourceReferences(kind) => {
                 Ok(BootstrapStateUpdateKind::SourceReferences(kind))
             }
+            StateUpdateKind::ReplicationSlot(kind) => {
+                Ok(BootstrapStateUpdateKind::ReplicationSlot(kind))
+            }
             StateUpdateKind::SystemObjectMapping(kind) => {
                 Ok(BootstrapStateUpdateKind::SystemObjectMapping(kind))
             }
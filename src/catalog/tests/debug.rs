@@ -91,6 +91,7 @@ impl Debug for StableTrace<'_> {
             id_allocator,
             items,
             network_policies,
+            replication_slots,
             roles,
             role_auth,
             schemas,
@@ -134,6 +135,7 @@ impl Debug for StableTrace<'_> {
             .field("id_allocator", id_allocator)
             .field("items", items)
             .field("network_policies", network_policies)
+            .field("replication_slots", replication_slots)
             .field("roles", roles)
             .field("role_auth", role_auth)
             .field("schemas", schemas)
@@ -84,6 +84,7 @@ impl Debug for StableSnapshot<'_> {
             configs,
             settings,
             source_references,
+            replication_slots,
             system_object_mappings,
             system_configurations,
             default_privileges,
@@ -112,6 +113,7 @@ impl Debug for StableSnapshot<'_> {
             .field("configs", &configs)
             .field("settings", &settings)
             .field("source_references", source_references)
+            .field("replication_slots", replication_slots)
             .field("system_object_mappings", system_object_mappings)
             .field("system_configurations", system_configurations)
             .field("default_privileges", default_privileges)
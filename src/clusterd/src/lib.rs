@@ -29,7 +29,7 @@ use mz_ore::metrics::{MetricsRegistry, register_runtime_metrics};
 use mz_ore::netio::{Listener, SocketAddr};
 use mz_ore::now::SYSTEM_TIME;
 use mz_persist_client::cache::PersistClientCache;
-use mz_persist_client::cfg::PersistConfig;
+use mz_persist_client::cfg::{BlobEncryptionCliArgs, PersistConfig};
 use mz_persist_client::rpc::{GrpcPubSubClient, PersistPubSubClient, PersistPubSubClientConfig};
 use mz_service::emit_boot_diagnostics;
 use mz_service::grpc::{GrpcServer, GrpcServerMetrics, MAX_GRPC_MESSAGE_SIZE};
@@ -93,6 +93,9 @@ struct Args {
         default_value = "http://localhost:6879"
     )]
     persist_pubsub_url: String,
+    /// Client-side encryption of persist blobs.
+    #[clap(flatten)]
+    persist_blob_encryption: BlobEncryptionCliArgs,
 
     // === Cloud options. ===
     /// An external ID to be supplied to all AWS AssumeRole operations.
@@ -290,6 +293,21 @@ async fn run(args: Args) -> Result<(), anyhow::Error> {
         PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), mz_dyncfgs::all_dyncfgs());
     persist_cfg.is_cc_active = args.is_cc;
     persist_cfg.announce_memory_limit = args.announce_memory_limit;
+    let blob_secrets_reader = Arc::clone(&secrets_reader);
+    persist_cfg.blob_encryption = args
+        .persist_blob_encryption
+        .load(move |id| {
+            let secrets_reader = Arc::clone(&blob_secrets_reader);
+            async move { secrets_reader.read(id.parse()?).await }
+        })
+        .await
+        .context("loading blob encryption keys")?;
+    // Replicas with local scratch disk get a persistent blob cache, which
+    // avoids refetching everything from blob storage after a restart.
+    persist_cfg.blob_cache_disk_dir = args
+        .scratch_directory
+        .as_ref()
+        .map(|dir| dir.join("persist-blob-cache"));
     // Start with compaction disabled, will get enabled once a cluster receives AllowWrites.
     persist_cfg.disable_compaction();
 
@@ -168,6 +168,9 @@ pub struct ComputeController<T: ComputeControllerTimestamp> {
     /// class of the workload that compute instance is running (e.g.,
     /// `production` or `staging`).
     instance_workload_classes: Arc<Mutex<BTreeMap<ComputeInstanceId, Option<String>>>>,
+    /// A map from an instance ID to whether that compute instance spills large arrangement
+    /// batches to disk.
+    instance_arrangement_spill: BTreeMap<ComputeInstanceId, bool>,
     build_info: &'static BuildInfo,
     /// A handle providing access to storage collections.
     storage_collections: StorageCollections<T>,
@@ -280,6 +283,7 @@ impl<T: ComputeControllerTimestamp> ComputeController<T> {
         Self {
             instances: BTreeMap::new(),
             instance_workload_classes,
+            instance_arrangement_spill: BTreeMap::new(),
             build_info,
             storage_collections,
             initialized: false,
@@ -425,6 +429,42 @@ impl<T: ComputeControllerTimestamp> ComputeController<T> {
         Ok(res)
     }
 
+    /// Returns the number of records in the arrangement of the given collection, as reported by
+    /// the replicas of the given instance.
+    ///
+    /// Returns `None` if no replica has reported an arrangement size for the collection yet.
+    pub async fn collection_arrangement_records(
+        &self,
+        instance_id: ComputeInstanceId,
+        collection_id: GlobalId,
+    ) -> Result<Option<u64>, anyhow::Error> {
+        let instance = self.instance(instance_id)?;
+
+        let res = instance
+            .call_sync(move |i| i.collection_arrangement_records(collection_id))
+            .await?;
+
+        Ok(res)
+    }
+
+    /// Returns the number of bytes allocated for the arrangement of the given collection, as
+    /// reported by the replicas of the given instance.
+    ///
+    /// Returns `None` if no replica has reported an arrangement size for the collection yet.
+    pub async fn collection_arrangement_size_bytes(
+        &self,
+        instance_id: ComputeInstanceId,
+        collection_id: GlobalId,
+    ) -> Result<Option<u64>, anyhow::Error> {
+        let instance = self.instance(instance_id)?;
+
+        let res = instance
+            .call_sync(move |i| i.collection_arrangement_size_bytes(collection_id))
+            .await?;
+
+        Ok(res)
+    }
+
     /// Returns `true` if all non-transient, non-excluded collections are hydrated on any of the
     /// provided replicas.
     ///
@@ -469,6 +509,7 @@ impl<T: ComputeControllerTimestamp> ComputeController<T> {
         let Self {
             instances,
             instance_workload_classes,
+            instance_arrangement_spill,
             build_info: _,
             storage_collections: _,
             initialized,
@@ -500,6 +541,10 @@ impl<T: ComputeControllerTimestamp> ComputeController<T> {
             .iter()
             .map(|(id, wc)| (id.to_string(), format!("{wc:?}")))
             .collect();
+        let instance_arrangement_spill: BTreeMap<_, _> = instance_arrangement_spill
+            .iter()
+            .map(|(id, spill)| (id.to_string(), spill))
+            .collect();
 
         fn field(
             key: &str,
@@ -512,6 +557,7 @@ impl<T: ComputeControllerTimestamp> ComputeController<T> {
         let map = serde_json::Map::from_iter([
             field("instances", instances_dump)?,
             field("instance_workload_classes", instance_workload_classes)?,
+            field("instance_arrangement_spill", instance_arrangement_spill)?,
             field("initialized", initialized)?,
             field("read_only", read_only)?,
             field("stashed_response", format!("{stashed_response:?}"))?,
@@ -533,6 +579,7 @@ where
         id: ComputeInstanceId,
         arranged_logs: BTreeMap<LogVariant, GlobalId>,
         workload_class: Option<String>,
+        enable_arrangement_spill: bool,
     ) -> Result<(), InstanceExists> {
         if self.instances.contains_key(&id) {
             return Err(InstanceExists(id));
@@ -568,6 +615,8 @@ where
             .lock()
             .expect("lock poisoned")
             .insert(id, workload_class.clone());
+        self.instance_arrangement_spill
+            .insert(id, enable_arrangement_spill);
 
         let instance = self.instances.get_mut(&id).expect("instance just added");
         if self.initialized {
@@ -580,6 +629,7 @@ where
 
         let mut config_params = self.config.clone();
         config_params.workload_class = Some(workload_class);
+        config_params.enable_arrangement_spill = Some(enable_arrangement_spill);
         instance.call(|i| i.update_configuration(config_params));
 
         Ok(())
@@ -605,6 +655,25 @@ where
         Ok(())
     }
 
+    /// Updates whether a compute instance spills large arrangement batches to disk.
+    pub fn update_instance_arrangement_spill(
+        &mut self,
+        id: ComputeInstanceId,
+        enable_arrangement_spill: bool,
+    ) -> Result<(), InstanceMissing> {
+        let instance = self.instance_mut(id)?;
+        let params = ComputeParameters {
+            enable_arrangement_spill: Some(enable_arrangement_spill),
+            ..Default::default()
+        };
+        instance.call(|i| i.update_configuration(params));
+
+        self.instance_arrangement_spill
+            .insert(id, enable_arrangement_spill);
+
+        Ok(())
+    }
+
     /// Remove a compute instance.
     ///
     /// # Panics
@@ -619,6 +688,7 @@ where
             .lock()
             .expect("lock poisoned")
             .remove(&id);
+        self.instance_arrangement_spill.remove(&id);
     }
 
     /// Returns the compute controller's config set.
@@ -637,10 +707,12 @@ where
             .expect("lock poisoned");
 
         // Forward updates to existing clusters.
-        // Workload classes are cluster-specific, so we need to overwrite them here.
+        // Workload classes and arrangement spilling are cluster-specific, so we need to overwrite
+        // them here.
         for (id, instance) in self.instances.iter_mut() {
             let mut params = config_params.clone();
             params.workload_class = Some(instance_workload_classes[id].clone());
+            params.enable_arrangement_spill = Some(self.instance_arrangement_spill[id]);
             instance.call(|i| i.update_configuration(params));
         }
 
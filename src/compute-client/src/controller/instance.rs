@@ -66,8 +66,8 @@ use crate::metrics::{InstanceMetrics, ReplicaCollectionMetrics, ReplicaMetrics,
 use crate::protocol::command::{ComputeCommand, ComputeParameters, Peek, PeekTarget};
 use crate::protocol::history::ComputeCommandHistory;
 use crate::protocol::response::{
-    ComputeResponse, CopyToResponse, FrontiersResponse, OperatorHydrationStatus, PeekResponse,
-    StatusResponse, SubscribeBatch, SubscribeResponse,
+    ArrangementSizeStatus, ComputeResponse, CopyToResponse, FrontiersResponse,
+    OperatorHydrationStatus, PeekResponse, StatusResponse, SubscribeBatch, SubscribeResponse,
 };
 use crate::service::{ComputeClient, ComputeGrpcClient};
 
@@ -788,6 +788,85 @@ impl<T: ComputeControllerTimestamp> Instance<T> {
         );
     }
 
+    fn update_arrangement_size_status(
+        &mut self,
+        replica_id: ReplicaId,
+        status: ArrangementSizeStatus,
+    ) {
+        let Some(replica) = self.replicas.get_mut(&replica_id) else {
+            tracing::error!(
+                %replica_id, ?status,
+                "status update for an unknown replica"
+            );
+            return;
+        };
+        let Some(collection) = replica.collections.get_mut(&status.collection_id) else {
+            tracing::error!(
+                %replica_id, ?status,
+                "status update for an unknown collection"
+            );
+            return;
+        };
+
+        collection
+            .arrangement_sizes
+            .insert(status.worker_id, (status.records, status.size_bytes));
+    }
+
+    /// Returns the number of records in the arrangement of the given collection, as last
+    /// reported by the replicas.
+    ///
+    /// Replicas maintain the same arrangement, so we return the largest size reported by any
+    /// replica. Returns `None` if no replica has reported a size yet.
+    #[mz_ore::instrument(level = "debug")]
+    pub fn collection_arrangement_records(
+        &self,
+        collection_id: GlobalId,
+    ) -> Result<Option<u64>, CollectionLookupError> {
+        self.collection_arrangement_size(collection_id, |(records, _)| records)
+    }
+
+    /// Returns the number of bytes allocated for the arrangement of the given collection, as
+    /// last reported by the replicas.
+    ///
+    /// Returns the largest size reported by any replica, or `None` if no replica has reported a
+    /// size yet.
+    #[mz_ore::instrument(level = "debug")]
+    pub fn collection_arrangement_size_bytes(
+        &self,
+        collection_id: GlobalId,
+    ) -> Result<Option<u64>, CollectionLookupError> {
+        self.collection_arrangement_size(collection_id, |(_, size_bytes)| size_bytes)
+    }
+
+    /// Sums the per-worker arrangement sizes selected by `f` for each replica, and returns the
+    /// largest sum.
+    fn collection_arrangement_size(
+        &self,
+        collection_id: GlobalId,
+        f: impl Fn((u64, u64)) -> u64,
+    ) -> Result<Option<u64>, CollectionLookupError> {
+        let mut result = None;
+        for replica_state in self.replicas.values() {
+            let collection_state = replica_state
+                .collections
+                .get(&collection_id)
+                .ok_or(CollectionLookupError::CollectionMissing(collection_id))?;
+
+            if collection_state.arrangement_sizes.is_empty() {
+                continue;
+            }
+            let size = collection_state
+                .arrangement_sizes
+                .values()
+                .map(|size| f(*size))
+                .sum();
+            result = Some(result.map_or(size, |r: u64| r.max(size)));
+        }
+
+        Ok(result)
+    }
+
     /// Returns `true` if the given collection is hydrated on at least one
     /// replica.
     ///
@@ -2174,6 +2253,9 @@ where
             StatusResponse::OperatorHydration(status) => {
                 self.update_operator_hydration_status(replica_id, status)
             }
+            StatusResponse::ArrangementSize(status) => {
+                self.update_arrangement_size_status(replica_id, status)
+            }
         }
     }
 
@@ -2966,6 +3048,11 @@ struct ReplicaCollectionState<T: ComputeControllerTimestamp> {
     ///
     /// If this is `None`, wallclock lag is not tracked for this collection.
     wallclock_lag_max: Option<WallclockLag>,
+    /// The number of records in and the bytes allocated for the collection's arrangement, by
+    /// worker.
+    ///
+    /// Only populated for indexes.
+    arrangement_sizes: BTreeMap<usize, (u64, u64)>,
 }
 
 impl<T: ComputeControllerTimestamp> ReplicaCollectionState<T> {
@@ -2984,6 +3071,7 @@ impl<T: ComputeControllerTimestamp> ReplicaCollectionState<T> {
             introspection,
             input_read_holds,
             wallclock_lag_max: Some(WallclockLag::MIN),
+            arrangement_sizes: BTreeMap::new(),
         }
     }
 
@@ -297,6 +297,8 @@ pub enum ComputeLog {
     ArrangementHeapCapacity,
     /// Arrangement heap allocations.
     ArrangementHeapAllocations,
+    /// Arrangement sizes spilled to disk.
+    ArrangementSpilledSize,
     /// A histogram over dataflow shutdown durations.
     ShutdownDuration,
     /// Counts of errors in exported collections.
@@ -322,6 +324,7 @@ impl RustType<ProtoComputeLog> for ComputeLog {
                 ComputeLog::ArrangementHeapSize => ArrangementHeapSize(()),
                 ComputeLog::ArrangementHeapCapacity => ArrangementHeapCapacity(()),
                 ComputeLog::ArrangementHeapAllocations => ArrangementHeapAllocations(()),
+                ComputeLog::ArrangementSpilledSize => ArrangementSpilledSize(()),
                 ComputeLog::ShutdownDuration => ShutdownDuration(()),
                 ComputeLog::ErrorCount => ErrorCount(()),
                 ComputeLog::HydrationTime => HydrationTime(()),
@@ -342,6 +345,7 @@ impl RustType<ProtoComputeLog> for ComputeLog {
             Some(ArrangementHeapSize(())) => Ok(ComputeLog::ArrangementHeapSize),
             Some(ArrangementHeapCapacity(())) => Ok(ComputeLog::ArrangementHeapCapacity),
             Some(ArrangementHeapAllocations(())) => Ok(ComputeLog::ArrangementHeapAllocations),
+            Some(ArrangementSpilledSize(())) => Ok(ComputeLog::ArrangementSpilledSize),
             Some(ShutdownDuration(())) => Ok(ComputeLog::ShutdownDuration),
             Some(ErrorCount(())) => Ok(ComputeLog::ErrorCount),
             Some(HydrationTime(())) => Ok(ComputeLog::HydrationTime),
@@ -465,12 +469,11 @@ impl LogVariant {
             | LogVariant::Differential(DifferentialLog::BatcherAllocations)
             | LogVariant::Compute(ComputeLog::ArrangementHeapSize)
             | LogVariant::Compute(ComputeLog::ArrangementHeapCapacity)
-            | LogVariant::Compute(ComputeLog::ArrangementHeapAllocations) => {
-                RelationDesc::builder()
-                    .with_column("operator_id", ScalarType::UInt64.nullable(false))
-                    .with_column("worker_id", ScalarType::UInt64.nullable(false))
-                    .finish()
-            }
+            | LogVariant::Compute(ComputeLog::ArrangementHeapAllocations)
+            | LogVariant::Compute(ComputeLog::ArrangementSpilledSize) => RelationDesc::builder()
+                .with_column("operator_id", ScalarType::UInt64.nullable(false))
+                .with_column("worker_id", ScalarType::UInt64.nullable(false))
+                .finish(),
 
             LogVariant::Compute(ComputeLog::DataflowCurrent) => RelationDesc::builder()
                 .with_column("export_id", ScalarType::String.nullable(false))
@@ -451,6 +451,10 @@ pub struct ComputeParameters {
     /// When `Some(x)`, a `workload_class=x` label is applied to all metrics
     /// exported by the metrics registry associated with the compute instance.
     pub workload_class: Option<Option<String>>,
+    /// Whether the compute instance spills large arrangement batches to disk.
+    ///
+    /// This is a cluster option. Replicas without a scratch directory never spill.
+    pub enable_arrangement_spill: Option<bool>,
     /// The maximum allowed size in bytes for results of peeks and subscribes.
     ///
     /// Peeks and subscribes that would return results larger than this maximum return the
@@ -476,6 +480,7 @@ impl ComputeParameters {
     pub fn update(&mut self, other: ComputeParameters) {
         let ComputeParameters {
             workload_class,
+            enable_arrangement_spill,
             max_result_size,
             tracing,
             grpc_client,
@@ -485,6 +490,9 @@ impl ComputeParameters {
         if workload_class.is_some() {
             self.workload_class = workload_class;
         }
+        if enable_arrangement_spill.is_some() {
+            self.enable_arrangement_spill = enable_arrangement_spill;
+        }
         if max_result_size.is_some() {
             self.max_result_size = max_result_size;
         }
@@ -505,6 +513,7 @@ impl RustType<ProtoComputeParameters> for ComputeParameters {
     fn into_proto(&self) -> ProtoComputeParameters {
         ProtoComputeParameters {
             workload_class: self.workload_class.into_proto(),
+            enable_arrangement_spill: self.enable_arrangement_spill,
             max_result_size: self.max_result_size.into_proto(),
             tracing: Some(self.tracing.into_proto()),
             grpc_client: Some(self.grpc_client.into_proto()),
@@ -515,6 +524,7 @@ impl RustType<ProtoComputeParameters> for ComputeParameters {
     fn from_proto(proto: ProtoComputeParameters) -> Result<Self, TryFromProtoError> {
         Ok(Self {
             workload_class: proto.workload_class.into_rust()?,
+            enable_arrangement_spill: proto.enable_arrangement_spill,
             max_result_size: proto.max_result_size.into_rust()?,
             tracing: proto
                 .tracing
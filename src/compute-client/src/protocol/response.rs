@@ -589,6 +589,8 @@ impl Arbitrary for SubscribeBatch<mz_repr::Timestamp> {
 pub enum StatusResponse {
     /// Reports the hydration status of dataflow operators.
     OperatorHydration(OperatorHydrationStatus),
+    /// Reports the number of records in the arrangement of an index.
+    ArrangementSize(ArrangementSizeStatus),
 }
 
 impl RustType<ProtoStatusResponse> for StatusResponse {
@@ -597,6 +599,7 @@ impl RustType<ProtoStatusResponse> for StatusResponse {
 
         let kind = match self {
             Self::OperatorHydration(status) => Kind::OperatorHydration(status.into_proto()),
+            Self::ArrangementSize(status) => Kind::ArrangementSize(status.into_proto()),
         };
         ProtoStatusResponse { kind: Some(kind) }
     }
@@ -608,6 +611,7 @@ impl RustType<ProtoStatusResponse> for StatusResponse {
             Some(Kind::OperatorHydration(status)) => {
                 Ok(Self::OperatorHydration(status.into_rust()?))
             }
+            Some(Kind::ArrangementSize(status)) => Ok(Self::ArrangementSize(status.into_rust()?)),
             None => Err(TryFromProtoError::missing_field(
                 "ProtoStatusResponse::kind",
             )),
@@ -650,6 +654,41 @@ impl RustType<ProtoOperatorHydrationStatus> for OperatorHydrationStatus {
     }
 }
 
+/// An update about the size of the arrangement of an index on a worker.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Arbitrary)]
+pub struct ArrangementSizeStatus {
+    /// The ID of the index exported by the dataflow.
+    pub collection_id: GlobalId,
+    /// The ID of the worker that maintains the arrangement.
+    pub worker_id: usize,
+    /// The number of updates in the worker's share of the arrangement.
+    pub records: u64,
+    /// The number of bytes allocated for the worker's share of the arrangement.
+    pub size_bytes: u64,
+}
+
+impl RustType<ProtoArrangementSizeStatus> for ArrangementSizeStatus {
+    fn into_proto(&self) -> ProtoArrangementSizeStatus {
+        ProtoArrangementSizeStatus {
+            collection_id: Some(self.collection_id.into_proto()),
+            worker_id: self.worker_id.into_proto(),
+            records: self.records,
+            size_bytes: self.size_bytes,
+        }
+    }
+
+    fn from_proto(proto: ProtoArrangementSizeStatus) -> Result<Self, TryFromProtoError> {
+        Ok(Self {
+            collection_id: proto
+                .collection_id
+                .into_rust_if_some("ProtoArrangementSizeStatus::collection_id")?,
+            worker_id: proto.worker_id.into_rust()?,
+            records: proto.records,
+            size_bytes: proto.size_bytes,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use mz_ore::assert_ok;
@@ -97,6 +97,38 @@ pub const ENABLE_COLUMNAR_LGALLOC: Config<bool> = Config::new(
     "Enable allocating aligned regions in columnar from lgalloc.",
 );
 
+/// The batch size in bytes above which cold arrangement batches are spilled.
+pub const COMPUTE_ARRANGEMENT_SPILL_THRESHOLD_BYTES: Config<usize> = Config::new(
+    "compute_arrangement_spill_threshold_bytes",
+    16 << 20,
+    "Arrangement batches with at least this many bytes are spilled to disk once they are no longer \
+    written to, on clusters with ARRANGEMENT SPILL enabled.",
+);
+
+/// The number of bytes of blocks of spilled arrangement batches each worker keeps resident.
+pub const COMPUTE_ARRANGEMENT_SPILL_CACHE_BYTES: Config<usize> = Config::new(
+    "compute_arrangement_spill_cache_bytes",
+    256 << 20,
+    "The size in bytes of the per-worker block cache for spilled arrangement batches, on clusters \
+    with ARRANGEMENT SPILL enabled.",
+);
+
+/// Whether to report the sizes of index arrangements to the controller.
+pub const ENABLE_COMPUTE_ARRANGEMENT_SIZE_REPORTING: Config<bool> = Config::new(
+    "enable_compute_arrangement_size_reporting",
+    false,
+    "Report the number of records in index arrangements to the controller, for use as \
+     cardinality estimates by the optimizer.",
+);
+
+/// The interval at which the sizes of index arrangements are reported.
+pub const COMPUTE_ARRANGEMENT_SIZE_REPORTING_INTERVAL: Config<Duration> = Config::new(
+    "compute_arrangement_size_reporting_interval",
+    Duration::from_secs(10),
+    "The interval at which the number of records in index arrangements is reported to the \
+     controller, if enabled.",
+);
+
 /// The interval at which the compute server performs maintenance tasks.
 pub const COMPUTE_SERVER_MAINTENANCE_INTERVAL: Config<Duration> = Config::new(
     "compute_server_maintenance_interval",
@@ -244,6 +276,10 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&ENABLE_LGALLOC_EAGER_RECLAMATION)
         .add(&ENABLE_COLUMNATION_LGALLOC)
         .add(&ENABLE_COLUMNAR_LGALLOC)
+        .add(&COMPUTE_ARRANGEMENT_SPILL_THRESHOLD_BYTES)
+        .add(&COMPUTE_ARRANGEMENT_SPILL_CACHE_BYTES)
+        .add(&ENABLE_COMPUTE_ARRANGEMENT_SIZE_REPORTING)
+        .add(&COMPUTE_ARRANGEMENT_SIZE_REPORTING_INTERVAL)
         .add(&COMPUTE_SERVER_MAINTENANCE_INTERVAL)
         .add(&DATAFLOW_MAX_INFLIGHT_BYTES)
         .add(&DATAFLOW_MAX_INFLIGHT_BYTES_CC)
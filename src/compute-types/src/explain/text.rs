@@ -34,7 +34,7 @@ use mz_repr::explain::{
 
 use crate::plan::join::delta_join::{DeltaPathPlan, DeltaStagePlan};
 use crate::plan::join::linear_join::LinearStagePlan;
-use crate::plan::join::{DeltaJoinPlan, JoinClosure, LinearJoinPlan};
+use crate::plan::join::{AsOfJoinPlan, DeltaJoinPlan, JoinClosure, LinearJoinPlan};
 use crate::plan::reduce::{
     AccumulablePlan, BasicPlan, CollationPlan, HierarchicalPlan, SingleBasicPlan,
 };
@@ -236,6 +236,10 @@ impl DisplayText<PlanRenderingContext<'_, Plan>> for Plan {
                         writeln!(f, "{}Join::Delta{}", ctx.indent, annotations)?;
                         ctx.indented(|ctx| plan.fmt_text(f, ctx))?;
                     }
+                    JoinPlan::AsOf(plan) => {
+                        writeln!(f, "{}Join::AsOf{}", ctx.indent, annotations)?;
+                        ctx.indented(|ctx| plan.fmt_text(f, ctx))?;
+                    }
                 }
                 ctx.indented(|ctx| {
                     for input in inputs {
@@ -364,6 +368,25 @@ impl DisplayText<PlanRenderingContext<'_, Plan>> for Plan {
                             write!(f, " offset={}", plan.offset)?;
                         }
                     }
+                    TopKPlan::Ordered(plan) => {
+                        write!(f, "{}TopK::Ordered", ctx.indent)?;
+                        if plan.group_key.len() > 0 {
+                            let group_by = mode.seq(&plan.group_key, None);
+                            let group_by = CompactScalars(group_by);
+                            write!(f, " group_by=[{}]", group_by)?;
+                        }
+                        if plan.order_key.len() > 0 {
+                            let order_by = mode.seq(&plan.order_key, None);
+                            let order_by = separated(", ", order_by);
+                            write!(f, " order_by=[{}]", order_by)?;
+                        }
+                        if let Some(limit) = &plan.limit {
+                            write!(f, " limit={}", limit)?;
+                        }
+                        if &plan.offset > &0 {
+                            write!(f, " offset={}", plan.offset)?;
+                        }
+                    }
                 }
                 writeln!(f, "{}", annotations)?;
                 ctx.indented(|ctx| input.fmt_text(f, ctx))?;
@@ -550,6 +573,28 @@ impl DisplayText<PlanRenderingContext<'_, Plan>> for LinearStagePlan {
     }
 }
 
+impl DisplayText<PlanRenderingContext<'_, Plan>> for AsOfJoinPlan {
+    fn fmt_text(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        ctx: &mut PlanRenderingContext<'_, Plan>,
+    ) -> fmt::Result {
+        let mode = HumanizedExplain::new(ctx.config.redacted);
+        let left_key = CompactScalars(mode.seq(&self.left_key, None));
+        let right_key = CompactScalars(mode.seq(&self.right_key, None));
+        writeln!(f, "{}left_key=[{}]", ctx.indent, left_key)?;
+        writeln!(f, "{}right_key=[{}]", ctx.indent, right_key)?;
+        writeln!(
+            f,
+            "{}match=({} {} {})",
+            ctx.indent,
+            mode.expr(&self.left_time, None),
+            self.comparison,
+            mode.expr(&self.right_time, None)
+        )
+    }
+}
+
 impl DisplayText<PlanRenderingContext<'_, Plan>> for DeltaJoinPlan {
     fn fmt_text(
         &self,
@@ -46,6 +46,7 @@ pub mod interpret;
 pub mod join;
 pub mod reduce;
 pub mod render_plan;
+pub mod summary;
 pub mod threshold;
 pub mod top_k;
 pub mod transform;
@@ -156,14 +156,17 @@ impl<T> Interpreter<T> for SingleTimeMonotonic<'_, T> {
         &self,
         _ctx: &Context<Self::Domain>,
         inputs: Vec<Self::Domain>,
-        _plan: &JoinPlan,
+        plan: &JoinPlan,
     ) -> Self::Domain {
         // When we see a join, we must consider that the inputs could have
         // been `Plan::Get`s on arrangements. These are not in general safe
         // wrt. producing physically monotonic data. So here, we conservatively
         // judge that output of a join to be physically monotonic iff all
-        // inputs are physically monotonic.
-        PhysicallyMonotonic(inputs.iter().all(|monotonic| monotonic.0))
+        // inputs are physically monotonic. ASOF joins retract a match once a
+        // closer one arrives, so their output is never physically monotonic.
+        PhysicallyMonotonic(
+            !matches!(plan, JoinPlan::AsOf(_)) && inputs.iter().all(|monotonic| monotonic.0),
+        )
     }
 
     fn reduce(
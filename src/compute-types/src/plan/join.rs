@@ -36,9 +36,11 @@ use proptest::prelude::*;
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
+pub mod asof_join;
 pub mod delta_join;
 pub mod linear_join;
 
+pub use asof_join::AsOfJoinPlan;
 pub use delta_join::DeltaJoinPlan;
 pub use linear_join::LinearJoinPlan;
 
@@ -51,6 +53,8 @@ pub enum JoinPlan {
     Linear(LinearJoinPlan),
     /// A join implemented by a delta join.
     Delta(DeltaJoinPlan),
+    /// A join implemented by an ASOF join.
+    AsOf(AsOfJoinPlan),
 }
 
 impl RustType<ProtoJoinPlan> for JoinPlan {
@@ -60,6 +64,7 @@ impl RustType<ProtoJoinPlan> for JoinPlan {
             kind: Some(match self {
                 JoinPlan::Linear(inner) => Linear(inner.into_proto()),
                 JoinPlan::Delta(inner) => Delta(inner.into_proto()),
+                JoinPlan::AsOf(inner) => AsOf(inner.into_proto()),
             }),
         }
     }
@@ -72,6 +77,7 @@ impl RustType<ProtoJoinPlan> for JoinPlan {
         Ok(match kind {
             Linear(inner) => JoinPlan::Linear(inner.into_rust()?),
             Delta(inner) => JoinPlan::Delta(inner.into_rust()?),
+            AsOf(inner) => JoinPlan::AsOf(inner.into_rust()?),
         })
     }
 }
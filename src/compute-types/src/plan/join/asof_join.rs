@@ -0,0 +1,134 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Planning of ASOF joins.
+
+use mz_expr::{AsOfComparison, AsOfMatch, JoinInputMapper, MirScalarExpr};
+use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+use crate::plan::join::ProtoAsOfJoinPlan;
+
+/// A plan for the execution of an ASOF join.
+///
+/// Each row of the first input is matched with at most one row of the second
+/// input with the same key: the one whose time is closest to its own among
+/// those satisfying `comparison`. Rows of the first input without a match are
+/// not part of the output, as in an inner join. All expressions are in terms of
+/// the columns of their respective input.
+///
+/// An output row has the product of the multiplicities of the rows it joins.
+/// The rendering keeps the rows of each key in time order and only revisits
+/// the rows of the first input whose match an update may change, so the cost
+/// of an update is logarithmic in the number of rows with its key plus the
+/// number of changed matches. Rows with the same key are on the same worker,
+/// so an empty key puts all rows on a single worker.
+#[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
+pub struct AsOfJoinPlan {
+    /// The key expressions to use for the first input.
+    pub left_key: Vec<MirScalarExpr>,
+    /// The key expressions to use for the second input.
+    pub right_key: Vec<MirScalarExpr>,
+    /// The time expression to use for the first input.
+    pub left_time: MirScalarExpr,
+    /// The time expression to use for the second input.
+    pub right_time: MirScalarExpr,
+    /// How the time of the first input must compare to the time of the second input.
+    pub comparison: AsOfComparison,
+}
+
+impl RustType<ProtoAsOfJoinPlan> for AsOfJoinPlan {
+    fn into_proto(&self) -> ProtoAsOfJoinPlan {
+        ProtoAsOfJoinPlan {
+            left_key: self.left_key.into_proto(),
+            right_key: self.right_key.into_proto(),
+            left_time: Some(self.left_time.into_proto()),
+            right_time: Some(self.right_time.into_proto()),
+            comparison: Some(self.comparison.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoAsOfJoinPlan) -> Result<Self, TryFromProtoError> {
+        Ok(AsOfJoinPlan {
+            left_key: proto.left_key.into_rust()?,
+            right_key: proto.right_key.into_rust()?,
+            left_time: proto
+                .left_time
+                .into_rust_if_some("ProtoAsOfJoinPlan::left_time")?,
+            right_time: proto
+                .right_time
+                .into_rust_if_some("ProtoAsOfJoinPlan::right_time")?,
+            comparison: proto
+                .comparison
+                .into_rust_if_some("ProtoAsOfJoinPlan::comparison")?,
+        })
+    }
+}
+
+impl AsOfJoinPlan {
+    /// Create a new ASOF join plan from the equivalences and match condition of a two-input join.
+    ///
+    /// Each equivalence class is turned into pairs of key expressions, one for each input, that
+    /// together require all members of the class to be equal.
+    pub fn create_from(
+        equivalences: &[Vec<MirScalarExpr>],
+        asof: &AsOfMatch,
+        input_mapper: &JoinInputMapper,
+    ) -> Result<Self, String> {
+        if input_mapper.total_inputs() != 2 {
+            return Err(format!(
+                "ASOF join with {} inputs",
+                input_mapper.total_inputs()
+            ));
+        }
+        // Expressions without column references can be evaluated on either input.
+        let on_input = |expr: &MirScalarExpr, index: usize| {
+            expr.support().is_empty() || input_mapper.is_localized(expr, index)
+        };
+
+        let mut left_key = Vec::new();
+        let mut right_key = Vec::new();
+        for class in equivalences {
+            let lefts = class.iter().filter(|e| on_input(e, 0)).collect::<Vec<_>>();
+            let rights = class.iter().filter(|e| on_input(e, 1)).collect::<Vec<_>>();
+            if lefts.is_empty()
+                || rights.is_empty()
+                || class.iter().any(|e| !on_input(e, 0) && !on_input(e, 1))
+            {
+                return Err(format!(
+                    "ASOF join equivalence class does not relate its inputs: {class:?}"
+                ));
+            }
+            for left in &lefts {
+                left_key.push(input_mapper.map_expr_to_local((*left).clone()));
+                right_key.push(input_mapper.map_expr_to_local(rights[0].clone()));
+            }
+            for right in &rights[1..] {
+                left_key.push(input_mapper.map_expr_to_local(lefts[0].clone()));
+                right_key.push(input_mapper.map_expr_to_local((*right).clone()));
+            }
+        }
+
+        if !on_input(&asof.left_time, 0) || !on_input(&asof.right_time, 1) {
+            return Err(format!(
+                "ASOF join times do not refer to their own inputs: {} and {}",
+                asof.left_time, asof.right_time
+            ));
+        }
+
+        Ok(AsOfJoinPlan {
+            left_key,
+            right_key,
+            left_time: input_mapper.map_expr_to_local(asof.left_time.clone()),
+            right_time: input_mapper.map_expr_to_local(asof.right_time.clone()),
+            comparison: asof.comparison,
+        })
+    }
+}
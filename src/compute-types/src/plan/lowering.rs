@@ -11,7 +11,7 @@
 
 use std::collections::BTreeMap;
 
-use mz_expr::JoinImplementation::{DeltaQuery, Differential, IndexedFilter, Unimplemented};
+use mz_expr::JoinImplementation::{AsOf, DeltaQuery, Differential, IndexedFilter, Unimplemented};
 use mz_expr::{
     AggregateExpr, Id, JoinInputMapper, MapFilterProject, MirRelationExpr, MirScalarExpr,
     OptimizedMirRelationExpr, TableFunc, permutation_for_arrangement,
@@ -22,7 +22,7 @@ use mz_repr::optimize::OptimizerFeatures;
 use timely::progress::Timestamp;
 
 use crate::dataflows::{BuildDesc, DataflowDescription, IndexImport};
-use crate::plan::join::{DeltaJoinPlan, JoinPlan, LinearJoinPlan};
+use crate::plan::join::{AsOfJoinPlan, DeltaJoinPlan, JoinPlan, LinearJoinPlan};
 use crate::plan::reduce::{KeyValPlan, ReducePlan};
 use crate::plan::threshold::ThresholdPlan;
 use crate::plan::top_k::TopKPlan;
@@ -37,6 +37,11 @@ pub(super) struct Context {
     debug_info: LirDebugInfo,
     /// Whether to enable fusion of MFPs in reductions.
     enable_reduce_mfp_fusion: bool,
+    /// Whether to enable ordered TopK plans.
+    enable_ordered_top_k: bool,
+    /// Whether we are lowering the values of a `LetRec`, which are rendered in recursive
+    /// scopes.
+    in_recursion: bool,
 }
 
 impl Context {
@@ -49,6 +54,8 @@ impl Context {
                 id: GlobalId::Transient(0),
             },
             enable_reduce_mfp_fusion: features.enable_reduce_mfp_fusion,
+            enable_ordered_top_k: features.enable_ordered_top_k,
+            in_recursion: false,
         }
     }
 
@@ -308,6 +315,7 @@ impl Context {
                 // Arrangements made available cannot be used by prior bindings,
                 // as we cannot circulate an arrangement through a `Variable` yet.
                 let mut lir_values = Vec::with_capacity(values.len());
+                let in_recursion = std::mem::replace(&mut self.in_recursion, true);
                 for (id, value) in ids.iter().zip(values) {
                     let (mut lir_value, mut v_keys) = self.lower_mir_expr(value)?;
                     // If `v_keys` does not contain an unarranged collection, we must form it.
@@ -372,6 +380,7 @@ impl Context {
                     assert_none!(pre_existing);
                     lir_values.push(lir_value);
                 }
+                self.in_recursion = in_recursion;
                 // As we exit the iterative scope, we must leave all arrangements behind,
                 // as they reference a timestamp coordinate that must be stripped off.
                 for id in ids.iter() {
@@ -620,6 +629,13 @@ impl Context {
                         );
                         (JoinPlan::Delta(djp), missing)
                     }
+                    AsOf(asof) => {
+                        // ASOF joins arrange their inputs themselves, and leave `mfp` to be
+                        // applied to their output.
+                        let ajp = AsOfJoinPlan::create_from(equivalences, asof, &input_mapper)?;
+                        let missing = vec![Default::default(); inputs.len()];
+                        (JoinPlan::AsOf(ajp), missing)
+                    }
                     // Other plans are errors, and should be reported as such.
                     Unimplemented => return Err("unimplemented join".to_string()),
                 };
@@ -728,6 +744,7 @@ This is not expected to cause incorrect results, but could indicate a performanc
                     arity,
                     *monotonic,
                     *expected_group_size,
+                    self.enable_ordered_top_k && !self.in_recursion,
                 );
 
                 // We don't have an MFP here -- install an operator to permute the
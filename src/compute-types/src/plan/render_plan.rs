@@ -23,7 +23,7 @@ use mz_repr::{Diff, GlobalId, Row};
 use proptest::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::plan::join::{DeltaJoinPlan, JoinPlan, LinearJoinPlan};
+use crate::plan::join::{AsOfJoinPlan, DeltaJoinPlan, JoinPlan, LinearJoinPlan};
 use crate::plan::reduce::{BucketedPlan, HierarchicalPlan, KeyValPlan, ReducePlan};
 use crate::plan::threshold::ThresholdPlan;
 use crate::plan::top_k::{MonotonicTopKPlan, TopKPlan};
@@ -896,6 +896,9 @@ impl<'a, T> std::fmt::Display for RenderPlanExprHumanizer<'a, T> {
 
                     Ok(())
                 }
+                JoinPlan::AsOf(AsOfJoinPlan { comparison, .. }) => {
+                    write!(f, "Join::AsOf({comparison}) {} » {}", inputs[0], inputs[1])
+                }
             },
             Reduce {
                 input,
@@ -942,6 +945,7 @@ impl<'a, T> std::fmt::Display for RenderPlanExprHumanizer<'a, T> {
                         write!(f, "MonotonicTopK")?
                     }
                     TopKPlan::Basic(..) => write!(f, "Basic")?,
+                    TopKPlan::Ordered(..) => write!(f, "Ordered")?,
                 };
                 write!(f, " {input}")
             }
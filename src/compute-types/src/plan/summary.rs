@@ -0,0 +1,221 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Summaries of physical plans that can be compared across optimizer runs.
+//!
+//! Two plans for the same dataflow rarely compare equal as a whole: transient
+//! IDs and LIR node IDs differ between optimizer runs. The properties that
+//! matter most for resource usage, however, are the join implementations and
+//! the arrangements a dataflow builds. A [`PlanSummary`] captures exactly those,
+//! in a form that does not depend on such IDs.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use mz_expr::MirScalarExpr;
+use mz_ore::str::separated;
+use mz_repr::GlobalId;
+use serde::{Deserialize, Serialize};
+
+use crate::dataflows::DataflowDescription;
+use crate::plan::join::JoinPlan;
+use crate::plan::{Plan, PlanNode};
+
+/// The join implementations and arrangements of a physical plan.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PlanSummary {
+    /// A description of each join implementation in the plan, sorted.
+    pub joins: Vec<String>,
+    /// The keys of each arrangement built by the plan, sorted.
+    pub arrangements: Vec<String>,
+    /// The indexes imported by the plan.
+    pub imported_indexes: BTreeSet<GlobalId>,
+}
+
+impl PlanSummary {
+    /// Summarizes all objects built by `dataflow`.
+    pub fn from_dataflow<T>(dataflow: &DataflowDescription<Plan<T>>) -> Self {
+        let mut summary = PlanSummary {
+            imported_indexes: dataflow.index_imports.keys().copied().collect(),
+            ..Default::default()
+        };
+
+        let mut todo: Vec<_> = dataflow.objects_to_build.iter().map(|b| &b.plan).collect();
+        while let Some(plan) = todo.pop() {
+            match &plan.node {
+                PlanNode::Join { plan, .. } => summary.joins.push(describe_join(plan)),
+                PlanNode::ArrangeBy { forms, .. } => {
+                    for (key, _, _) in &forms.arranged {
+                        summary.arrangements.push(describe_key(key));
+                    }
+                }
+                _ => (),
+            }
+            todo.extend(plan.node.children());
+        }
+
+        summary.joins.sort();
+        summary.arrangements.sort();
+        summary
+    }
+
+    /// Returns the changes needed to get from `self` to `new`.
+    pub fn diff(&self, new: &PlanSummary) -> PlanSummaryDiff {
+        PlanSummaryDiff {
+            removed_joins: multiset_difference(&self.joins, &new.joins),
+            added_joins: multiset_difference(&new.joins, &self.joins),
+            removed_arrangements: multiset_difference(&self.arrangements, &new.arrangements),
+            added_arrangements: multiset_difference(&new.arrangements, &self.arrangements),
+            removed_indexes: self
+                .imported_indexes
+                .difference(&new.imported_indexes)
+                .copied()
+                .collect(),
+            added_indexes: new
+                .imported_indexes
+                .difference(&self.imported_indexes)
+                .copied()
+                .collect(),
+        }
+    }
+}
+
+/// The changes between two [`PlanSummary`]s.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlanSummaryDiff {
+    /// Join implementations only present in the old plan.
+    pub removed_joins: Vec<String>,
+    /// Join implementations only present in the new plan.
+    pub added_joins: Vec<String>,
+    /// Arrangements only built by the old plan.
+    pub removed_arrangements: Vec<String>,
+    /// Arrangements only built by the new plan.
+    pub added_arrangements: Vec<String>,
+    /// Indexes only imported by the old plan.
+    pub removed_indexes: Vec<GlobalId>,
+    /// Indexes only imported by the new plan.
+    pub added_indexes: Vec<GlobalId>,
+}
+
+impl PlanSummaryDiff {
+    /// Whether the join implementations of the two plans differ.
+    pub fn joins_changed(&self) -> bool {
+        !self.removed_joins.is_empty() || !self.added_joins.is_empty()
+    }
+
+    /// Whether the arrangements of the two plans differ, including imported indexes.
+    pub fn arrangements_changed(&self) -> bool {
+        !self.removed_arrangements.is_empty()
+            || !self.added_arrangements.is_empty()
+            || !self.removed_indexes.is_empty()
+            || !self.added_indexes.is_empty()
+    }
+
+    /// Whether the two plans have the same summary.
+    pub fn is_empty(&self) -> bool {
+        !self.joins_changed() && !self.arrangements_changed()
+    }
+}
+
+fn describe_key(key: &[MirScalarExpr]) -> String {
+    format!("[{}]", separated(", ", key))
+}
+
+fn describe_join(plan: &JoinPlan) -> String {
+    match plan {
+        JoinPlan::Linear(plan) => {
+            let source = match &plan.source_key {
+                Some(key) => format!("%{}{}", plan.source_relation, describe_key(key)),
+                None => format!("%{}", plan.source_relation),
+            };
+            let stages = plan.stage_plans.iter().map(|stage| {
+                format!(
+                    "%{}{}",
+                    stage.lookup_relation,
+                    describe_key(&stage.lookup_key)
+                )
+            });
+            format!(
+                "Differential {}",
+                separated(" » ", std::iter::once(source).chain(stages))
+            )
+        }
+        JoinPlan::Delta(plan) => {
+            let paths = plan.path_plans.iter().map(|path| {
+                let source = format!(
+                    "%{}{}",
+                    path.source_relation,
+                    describe_key(&path.source_key)
+                );
+                let stages = path.stage_plans.iter().map(|stage| {
+                    format!(
+                        "%{}{}",
+                        stage.lookup_relation,
+                        describe_key(&stage.lookup_key)
+                    )
+                });
+                separated(" » ", std::iter::once(source).chain(stages)).to_string()
+            });
+            format!("Delta {}", separated(" | ", paths))
+        }
+        JoinPlan::AsOf(plan) => format!(
+            "AsOf {} {}",
+            describe_key(&plan.left_key),
+            describe_key(&plan.right_key)
+        ),
+    }
+}
+
+/// Returns the elements of `a` that are not matched by an element of `b`,
+/// respecting multiplicities.
+fn multiset_difference(a: &[String], b: &[String]) -> Vec<String> {
+    let mut counts: BTreeMap<&String, usize> = BTreeMap::new();
+    for x in b {
+        *counts.entry(x).or_default() += 1;
+    }
+    a.iter()
+        .filter(|x| match counts.get_mut(x) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                false
+            }
+            _ => true,
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_summary_diff() {
+        let old = PlanSummary {
+            joins: vec!["Differential %0 » %1[#0]".into()],
+            arrangements: vec!["[#0]".into(), "[#0]".into(), "[#1]".into()],
+            imported_indexes: BTreeSet::from([GlobalId::User(1)]),
+        };
+        assert!(old.diff(&old).is_empty());
+
+        let new = PlanSummary {
+            joins: vec!["Delta %0[#0] » %1[#0] | %1[#0] » %0[#0]".into()],
+            arrangements: vec!["[#0]".into(), "[#1]".into()],
+            imported_indexes: BTreeSet::from([GlobalId::User(1), GlobalId::User(2)]),
+        };
+        let diff = old.diff(&new);
+        assert!(diff.joins_changed());
+        assert!(diff.arrangements_changed());
+        assert_eq!(diff.removed_joins, old.joins);
+        assert_eq!(diff.added_joins, new.joins);
+        assert_eq!(diff.removed_arrangements, vec!["[#0]".to_string()]);
+        assert!(diff.added_arrangements.is_empty());
+        assert!(diff.removed_indexes.is_empty());
+        assert_eq!(diff.added_indexes, vec![GlobalId::User(2)]);
+    }
+}
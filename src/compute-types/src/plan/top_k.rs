@@ -16,6 +16,8 @@
 //! * A [MonotonicTop1Plan] maintains a single row per key and is suitable for monotonic inputs.
 //! * A [MonotonicTopKPlan] maintains up to K rows per key and is suitable for monotonic inputs.
 //! * A [BasicTopKPlan] maintains up to K rows per key and can handle retractions.
+//! * An [OrderedTopKPlan] maintains all rows per key in an order-statistic tree and can
+//!   handle retractions.
 
 use mz_expr::ColumnOrder;
 use mz_proto::{ProtoType, RustType, TryFromProtoError};
@@ -35,6 +37,8 @@ pub enum TopKPlan {
     MonotonicTopK(MonotonicTopKPlan),
     /// A plan for generic TopK operations.
     Basic(BasicTopKPlan),
+    /// A plan for generic TopK operations backed by an arrangement in TopK order.
+    Ordered(OrderedTopKPlan),
 }
 
 impl TopKPlan {
@@ -48,6 +52,8 @@ impl TopKPlan {
     /// * `arity` - The number of columns in the input and output.
     /// * `monotonic` - `true` if the input is monotonic.
     /// * `expected_group_size` - A hint about how many rows will have the same group key.
+    /// * `enable_ordered` - `true` if an [OrderedTopKPlan] may be selected for
+    ///   non-monotonic inputs, which requires that the TopK is not in a recursive scope.
     pub(crate) fn create_from(
         group_key: Vec<usize>,
         order_key: Vec<ColumnOrder>,
@@ -56,9 +62,11 @@ impl TopKPlan {
         arity: usize,
         monotonic: bool,
         expected_group_size: Option<u64>,
+        enable_ordered: bool,
     ) -> Self {
         // Capture whether the limit is a literal integer first.
         let limit_as_int64 = limit.as_ref().and_then(|l| l.as_literal_int64());
+        let limit_as_usize = limit_as_int64.and_then(|l| usize::try_from(l).ok());
 
         if monotonic && offset == 0 && limit_as_int64 == Some(1) {
             TopKPlan::MonotonicTop1(MonotonicTop1Plan {
@@ -82,6 +90,16 @@ impl TopKPlan {
                 arity,
                 must_consolidate: false,
             })
+        } else if enable_ordered && limit.is_some() == limit_as_usize.is_some() {
+            // The ordered plan supports only literal, non-negative limits. Other
+            // limits must be evaluated per group, which the basic plan does.
+            TopKPlan::Ordered(OrderedTopKPlan {
+                group_key,
+                order_key,
+                offset,
+                limit: limit_as_usize,
+                arity,
+            })
         } else {
             // A plan for all other inputs
             TopKPlan::Basic(BasicTopKPlan {
@@ -124,6 +142,31 @@ impl TopKPlan {
             TopKPlan::MonotonicTopK(plan) => {
                 plan.must_consolidate = must_consolidate;
             }
+            TopKPlan::Ordered(plan) => {
+                if plan.offset == 0 {
+                    *self = if plan.limit == Some(1) {
+                        TopKPlan::MonotonicTop1(MonotonicTop1Plan {
+                            group_key: plan.group_key.clone(),
+                            order_key: plan.order_key.clone(),
+                            must_consolidate,
+                        })
+                    } else {
+                        TopKPlan::MonotonicTopK(MonotonicTopKPlan {
+                            group_key: plan.group_key.clone(),
+                            order_key: plan.order_key.clone(),
+                            limit: plan.limit.map(|l| {
+                                let l = i64::try_from(l).expect("limit fits in an i64");
+                                mz_expr::MirScalarExpr::literal_ok(
+                                    mz_repr::Datum::Int64(l),
+                                    mz_repr::ScalarType::Int64,
+                                )
+                            }),
+                            arity: plan.arity,
+                            must_consolidate,
+                        })
+                    }
+                }
+            }
         }
     }
 }
@@ -137,6 +180,7 @@ impl RustType<ProtoTopKPlan> for TopKPlan {
                 TopKPlan::Basic(plan) => Some(Basic(plan.into_proto())),
                 TopKPlan::MonotonicTop1(plan) => Some(MonotonicTop1(plan.into_proto())),
                 TopKPlan::MonotonicTopK(plan) => Some(MonotonicTopK(plan.into_proto())),
+                TopKPlan::Ordered(plan) => Some(Ordered(plan.into_proto())),
             },
         }
     }
@@ -148,6 +192,7 @@ impl RustType<ProtoTopKPlan> for TopKPlan {
             Some(Basic(plan)) => Ok(TopKPlan::Basic(plan.into_rust()?)),
             Some(MonotonicTop1(plan)) => Ok(TopKPlan::MonotonicTop1(plan.into_rust()?)),
             Some(MonotonicTopK(plan)) => Ok(TopKPlan::MonotonicTopK(plan.into_rust()?)),
+            Some(Ordered(plan)) => Ok(TopKPlan::Ordered(plan.into_rust()?)),
             None => Err(TryFromProtoError::missing_field("ProtoTopKPlan::kind")),
         }
     }
@@ -291,6 +336,58 @@ impl RustType<ProtoBasicTopKPlan> for BasicTopKPlan {
     }
 }
 
+/// A plan for generic TopKs with a literal limit, backed by an order-statistic tree per group.
+///
+/// Each group's rows are kept in a balanced tree ordered by `order_key` that counts the rows
+/// in each subtree, so that the rank of a row and the row at a rank can be found in
+/// logarithmic time. An update emits the rows entering and leaving the `offset`/`limit`
+/// window around the rank of the updated row, so its cost is logarithmic in the group size
+/// rather than linear. Unlike [BasicTopKPlan], the state is one copy of the input,
+/// independent of the limit, which avoids the stack of arrangements for large limits on
+/// inputs with retractions.
+///
+/// The rendering applies the updates of each time in order, so lowering only selects this
+/// plan outside of recursive scopes.
+#[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
+pub struct OrderedTopKPlan {
+    /// The columns that form the key for each group.
+    pub group_key: Vec<usize>,
+    /// Ordering that is used within each group.
+    pub order_key: Vec<mz_expr::ColumnOrder>,
+    /// A lower bound on the per-group ordinal position of the records to
+    /// produce from each group.
+    ///
+    /// This can be set to zero to have no effect.
+    pub offset: usize,
+    /// Optionally, an upper bound on the number of records to produce from
+    /// each group.
+    pub limit: Option<usize>,
+    /// The number of columns in the input and output.
+    pub arity: usize,
+}
+
+impl RustType<ProtoOrderedTopKPlan> for OrderedTopKPlan {
+    fn into_proto(&self) -> ProtoOrderedTopKPlan {
+        ProtoOrderedTopKPlan {
+            group_key: self.group_key.into_proto(),
+            order_key: self.order_key.into_proto(),
+            offset: self.offset.into_proto(),
+            limit: self.limit.into_proto(),
+            arity: self.arity.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoOrderedTopKPlan) -> Result<Self, TryFromProtoError> {
+        Ok(OrderedTopKPlan {
+            group_key: proto.group_key.into_rust()?,
+            order_key: proto.order_key.into_rust()?,
+            offset: proto.offset.into_rust()?,
+            limit: proto.limit.into_rust()?,
+            arity: proto.arity.into_rust()?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use mz_ore::assert_ok;
@@ -18,15 +18,15 @@ use bytesize::ByteSize;
 use differential_dataflow::Hashable;
 use differential_dataflow::IntoOwned;
 use differential_dataflow::lattice::Lattice;
-use differential_dataflow::trace::{Cursor, TraceReader};
+use differential_dataflow::trace::{BatchReader, Cursor, TraceReader};
 use mz_compute_client::logging::LoggingConfig;
 use mz_compute_client::protocol::command::{
     ComputeCommand, ComputeParameters, InstanceConfig, Peek, PeekTarget,
 };
 use mz_compute_client::protocol::history::ComputeCommandHistory;
 use mz_compute_client::protocol::response::{
-    ComputeResponse, CopyToResponse, FrontiersResponse, OperatorHydrationStatus, PeekResponse,
-    StatusResponse, SubscribeResponse,
+    ArrangementSizeStatus, ComputeResponse, CopyToResponse, FrontiersResponse,
+    OperatorHydrationStatus, PeekResponse, StatusResponse, SubscribeResponse,
 };
 use mz_compute_types::dataflows::DataflowDescription;
 use mz_compute_types::plan::LirId;
@@ -72,6 +72,7 @@ use crate::logging::initialize::LoggingTraces;
 use crate::metrics::{CollectionMetrics, WorkerMetrics};
 use crate::render::{LinearJoinSpec, StartSignal};
 use crate::server::{ComputeInstanceContext, ResponseSender};
+use crate::spill::SpillConfig;
 
 mod peek_result_iterator;
 
@@ -172,6 +173,17 @@ pub struct ComputeState {
     /// perform maintenance with every `step_or_park` invocation.
     pub server_maintenance_interval: Duration,
 
+    /// Whether the cluster option to spill large arrangement batches to disk is enabled.
+    enable_arrangement_spill: bool,
+    /// The arrangement spill configuration last applied to this worker.
+    spill_config: Option<SpillConfig>,
+
+    /// Interval at which to report the sizes of index arrangements, or `None` if they are not
+    /// reported.
+    arrangement_size_reporting_interval: Option<Duration>,
+    /// The last time the sizes of index arrangements were reported.
+    last_arrangement_size_report: Instant,
+
     /// The [`mz_ore::now::SYSTEM_TIME`] at which the replica was started.
     ///
     /// Used to compute `replica_expiration`.
@@ -223,6 +235,10 @@ impl ComputeState {
             read_only_tx,
             read_only_rx,
             server_maintenance_interval: Duration::ZERO,
+            enable_arrangement_spill: false,
+            spill_config: None,
+            arrangement_size_reporting_interval: None,
+            last_arrangement_size_report: Instant::now(),
             init_system_time: mz_ore::now::SYSTEM_TIME(),
             replica_expiration: Antichain::default(),
         }
@@ -309,10 +325,34 @@ impl ComputeState {
         let enable_columnar_lgalloc = ENABLE_COLUMNAR_LGALLOC.get(config);
         mz_timely_util::containers::set_enable_columnar_lgalloc(enable_columnar_lgalloc);
 
+        // Spilling is only reconfigured when its configuration changes, so that a worker that
+        // disabled spilling after failing to create a spill file does not retry on every
+        // unrelated configuration update.
+        let spill_config = match &self.context.scratch_directory {
+            Some(path) if self.enable_arrangement_spill => Some(SpillConfig {
+                path: path.clone(),
+                threshold_bytes: COMPUTE_ARRANGEMENT_SPILL_THRESHOLD_BYTES.get(config),
+                cache_bytes: COMPUTE_ARRANGEMENT_SPILL_CACHE_BYTES.get(config),
+            }),
+            _ => None,
+        };
+        if spill_config != self.spill_config {
+            info!(?spill_config, "configuring arrangement spilling");
+            crate::spill::set_config(spill_config.clone());
+            self.spill_config = spill_config;
+        }
+
         // Remember the maintenance interval locally to avoid reading it from the config set on
         // every server iteration.
         self.server_maintenance_interval = COMPUTE_SERVER_MAINTENANCE_INTERVAL.get(config);
 
+        self.arrangement_size_reporting_interval =
+            if ENABLE_COMPUTE_ARRANGEMENT_SIZE_REPORTING.get(config) {
+                Some(COMPUTE_ARRANGEMENT_SIZE_REPORTING_INTERVAL.get(config))
+            } else {
+                None
+            };
+
         let overflowing_behavior = ORE_OVERFLOWING_BEHAVIOR.get(config);
         match overflowing_behavior.parse() {
             Ok(behavior) => mz_ore::overflowing::set_behavior(behavior),
@@ -445,6 +485,7 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
 
         let ComputeParameters {
             workload_class,
+            enable_arrangement_spill,
             max_result_size,
             tracing,
             grpc_client: _grpc_client,
@@ -454,6 +495,9 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
         if let Some(v) = workload_class {
             self.compute_state.metrics.set_workload_class(v);
         }
+        if let Some(v) = enable_arrangement_spill {
+            self.compute_state.enable_arrangement_spill = v;
+        }
         if let Some(v) = max_result_size {
             self.compute_state.max_result_size = v;
         }
@@ -853,6 +897,63 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
         }
     }
 
+    /// Report changes to the sizes of index arrangements.
+    ///
+    /// The controller uses the reported sizes as cardinality estimates when optimizing queries
+    /// that read from the indexes. Counting records walks the batches of every index trace, so
+    /// this only does work if enabled, and at most once per reporting interval. Only sizes that
+    /// changed since they were last reported are sent.
+    pub fn report_arrangement_sizes(&mut self) {
+        let Some(interval) = self.compute_state.arrangement_size_reporting_interval else {
+            return;
+        };
+        let now = Instant::now();
+        if now < self.compute_state.last_arrangement_size_report + interval {
+            return;
+        }
+        self.compute_state.last_arrangement_size_report = now;
+
+        let worker_id = self.timely_worker.index();
+        let mut updates = Vec::new();
+        for (id, collection) in self.compute_state.collections.iter_mut() {
+            // The compute protocol forbids reporting `Status` about collections that have advanced
+            // to the empty frontier.
+            if collection.reported_frontiers().all_empty() {
+                continue;
+            }
+            let Some(bundle) = self.compute_state.traces.get_mut(id) else {
+                continue;
+            };
+
+            let (mut records, mut size_bytes) = (0, 0);
+            bundle.oks_mut().map_batches(|batch| {
+                records += u64::cast_from(batch.len());
+                let mut callback = |_size, capacity| size_bytes += u64::cast_from(capacity);
+                batch.storage.keys.heap_size(&mut callback);
+                batch.storage.keys_offs.heap_size(&mut callback);
+                batch.storage.vals.heap_size(&mut callback);
+                batch.storage.vals_offs.heap_size(&mut callback);
+                batch.storage.times.heap_size(&mut callback);
+                batch.storage.diffs.heap_size(&mut callback);
+            });
+
+            if collection.reported_arrangement_size != Some((records, size_bytes)) {
+                collection.reported_arrangement_size = Some((records, size_bytes));
+                updates.push(ArrangementSizeStatus {
+                    collection_id: *id,
+                    worker_id,
+                    records,
+                    size_bytes,
+                });
+            }
+        }
+
+        for status in updates {
+            let response = ComputeResponse::Status(StatusResponse::ArrangementSize(status));
+            self.send_compute_response(response);
+        }
+    }
+
     /// Report per-worker metrics.
     pub(crate) fn report_metrics(&self) {
         if let Some(expiration) = self.compute_state.replica_expiration.as_option() {
@@ -1593,6 +1694,11 @@ pub struct CollectionState {
     ///
     /// `None` for collections with compute frontiers equal to their write frontiers.
     pub compute_probe: Option<probe::Handle<Timestamp>>,
+    /// The number of records and the allocated bytes of the collection's arrangement that have
+    /// been reported to the controller.
+    ///
+    /// Only `Some` if the collection is an index whose size was reported.
+    reported_arrangement_size: Option<(u64, u64)>,
     /// Logging state maintained for this collection.
     logging: Option<CollectionLogging>,
     /// Metrics tracked for this collection.
@@ -1613,6 +1719,7 @@ impl CollectionState {
             sink_write_frontier: None,
             input_probes: Default::default(),
             compute_probe: None,
+            reported_arrangement_size: None,
             logging: None,
             metrics,
         }
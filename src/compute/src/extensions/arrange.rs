@@ -24,7 +24,7 @@ use timely::progress::Timestamp;
 
 use crate::logging::compute::{
     ArrangementHeapAllocations, ArrangementHeapCapacity, ArrangementHeapSize,
-    ArrangementHeapSizeOperator, ComputeEvent, ComputeEventBuilder,
+    ArrangementHeapSizeOperator, ArrangementSpilledSize, ComputeEvent, ComputeEventBuilder,
 };
 use crate::typedefs::{KeyAgent, KeyValAgent, RowAgent, RowRowAgent, RowValAgent};
 
@@ -235,7 +235,8 @@ pub trait ArrangementSize {
 ///
 /// * `arranged`: The arrangement to inspect.
 /// * `logic`: Closure that calculates the heap size/capacity/allocations for a trace. The return
-///    value are size and capacity in bytes, and number of allocations, all in absolute values.
+///    value are size and capacity in bytes, number of allocations, and spilled size in bytes, all
+///    in absolute values.
 fn log_arrangement_size_inner<G, Tr, L>(
     arranged: Arranged<G, TraceAgent<Tr>>,
     mut logic: L,
@@ -245,7 +246,7 @@ where
     G::Timestamp: Timestamp + Lattice + Ord,
     Tr: TraceReader + 'static,
     Tr::Time: Timestamp + Lattice + Ord + Clone + 'static,
-    L: FnMut(&Tr) -> (usize, usize, usize) + 'static,
+    L: FnMut(&Tr) -> (usize, usize, usize, usize) + 'static,
 {
     let scope = arranged.stream.scope();
     let Some(logger) = scope.logger_for::<ComputeEventBuilder>("materialize/compute") else {
@@ -254,7 +255,8 @@ where
     let operator_id = arranged.trace.operator().global_id;
     let trace = Rc::downgrade(&arranged.trace.trace_box_unstable());
 
-    let (mut old_size, mut old_capacity, mut old_allocations) = (0isize, 0isize, 0isize);
+    let (mut old_size, mut old_capacity, mut old_allocations, mut old_spilled) =
+        (0isize, 0isize, 0isize, 0isize);
 
     let stream = arranged
         .stream
@@ -274,7 +276,7 @@ where
                     return;
                 };
 
-                let (size, capacity, allocations) = logic(&trace.borrow().trace);
+                let (size, capacity, allocations, spilled) = logic(&trace.borrow().trace);
 
                 let size = size.try_into().expect("must fit");
                 if size != old_size {
@@ -304,9 +306,20 @@ where
                     ));
                 }
 
+                let spilled = spilled.try_into().expect("must fit");
+                if spilled != old_spilled {
+                    logger.log(&ComputeEvent::ArrangementSpilledSize(
+                        ArrangementSpilledSize {
+                            operator_id,
+                            delta_size: spilled - old_spilled,
+                        },
+                    ));
+                }
+
                 old_size = size;
                 old_capacity = capacity;
                 old_allocations = allocations;
+                old_spilled = spilled;
             }
         });
     Arranged {
@@ -340,7 +353,7 @@ where
                 batch.storage.times.heap_size(&mut callback);
                 batch.storage.diffs.heap_size(&mut callback);
             });
-            (size, capacity, allocations)
+            (size, capacity, allocations, 0)
         })
     }
 }
@@ -367,7 +380,7 @@ where
                 batch.storage.times.heap_size(&mut callback);
                 batch.storage.diffs.heap_size(&mut callback);
             });
-            (size, capacity, allocations)
+            (size, capacity, allocations, 0)
         })
     }
 }
@@ -382,7 +395,7 @@ where
 {
     fn log_arrangement_size(self) -> Self {
         log_arrangement_size_inner(self, |trace| {
-            let (mut size, mut capacity, mut allocations) = (0, 0, 0);
+            let (mut size, mut capacity, mut allocations, mut spilled) = (0, 0, 0, 0);
             let mut callback = |siz, cap| {
                 size += siz;
                 capacity += cap;
@@ -395,8 +408,9 @@ where
                 batch.storage.vals_offs.heap_size(&mut callback);
                 batch.storage.times.heap_size(&mut callback);
                 batch.storage.diffs.heap_size(&mut callback);
+                batch.storage.keys.spilled_size(|siz| spilled += siz);
             });
-            (size, capacity, allocations)
+            (size, capacity, allocations, spilled)
         })
     }
 }
@@ -410,7 +424,7 @@ where
 {
     fn log_arrangement_size(self) -> Self {
         log_arrangement_size_inner(self, |trace| {
-            let (mut size, mut capacity, mut allocations) = (0, 0, 0);
+            let (mut size, mut capacity, mut allocations, mut spilled) = (0, 0, 0, 0);
             let mut callback = |siz, cap| {
                 size += siz;
                 capacity += cap;
@@ -423,8 +437,10 @@ where
                 batch.storage.vals_offs.heap_size(&mut callback);
                 batch.storage.times.heap_size(&mut callback);
                 batch.storage.diffs.heap_size(&mut callback);
+                batch.storage.keys.spilled_size(|siz| spilled += siz);
+                batch.storage.vals.spilled_size(|siz| spilled += siz);
             });
-            (size, capacity, allocations)
+            (size, capacity, allocations, spilled)
         })
     }
 }
@@ -438,7 +454,7 @@ where
 {
     fn log_arrangement_size(self) -> Self {
         log_arrangement_size_inner(self, |trace| {
-            let (mut size, mut capacity, mut allocations) = (0, 0, 0);
+            let (mut size, mut capacity, mut allocations, mut spilled) = (0, 0, 0, 0);
             let mut callback = |siz, cap| {
                 size += siz;
                 capacity += cap;
@@ -449,8 +465,9 @@ where
                 batch.storage.keys_offs.heap_size(&mut callback);
                 batch.storage.times.heap_size(&mut callback);
                 batch.storage.diffs.heap_size(&mut callback);
+                batch.storage.keys.spilled_size(|siz| spilled += siz);
             });
-            (size, capacity, allocations)
+            (size, capacity, allocations, spilled)
         })
     }
 }
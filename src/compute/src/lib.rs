@@ -22,4 +22,5 @@ mod metrics;
 mod render;
 mod row_spine;
 mod sink;
+mod spill;
 mod typedefs;
@@ -118,6 +118,15 @@ pub struct ArrangementHeapAllocations {
     pub delta_allocations: isize,
 }
 
+/// A change in the number of bytes an arrangement has spilled to disk.
+#[derive(Debug, Clone, PartialOrd, PartialEq, Columnar)]
+pub struct ArrangementSpilledSize {
+    /// Operator index
+    pub operator_id: usize,
+    /// Delta of the spilled size in bytes of the arrangement.
+    pub delta_size: isize,
+}
+
 /// Announcing an operator that manages an arrangement.
 #[derive(Debug, Clone, PartialOrd, PartialEq, Columnar)]
 pub struct ArrangementHeapSizeOperator {
@@ -199,6 +208,8 @@ pub enum ComputeEvent {
     ArrangementHeapCapacity(ArrangementHeapCapacity),
     /// Arrangement heap size update
     ArrangementHeapAllocations(ArrangementHeapAllocations),
+    /// Arrangement spilled size update
+    ArrangementSpilledSize(ArrangementSpilledSize),
     /// Arrangement size operator address
     ArrangementHeapSizeOperator(ArrangementHeapSizeOperator),
     /// Arrangement size operator dropped
@@ -339,6 +350,7 @@ pub(super) fn construct<S: Scheduler + 'static, G: Scope<Timestamp = Timestamp>>
         let (mut arrangement_heap_capacity_out, arrangement_heap_capacity) = demux.new_output();
         let (mut arrangement_heap_allocations_out, arrangement_heap_allocations) =
             demux.new_output();
+        let (mut arrangement_spilled_size_out, arrangement_spilled_size) = demux.new_output();
         let (mut error_count_out, error_count) = demux.new_output();
         let (mut hydration_time_out, hydration_time) = demux.new_output();
         let (mut lir_mapping_out, lir_mapping) = demux.new_output();
@@ -356,6 +368,7 @@ pub(super) fn construct<S: Scheduler + 'static, G: Scope<Timestamp = Timestamp>>
                 let mut arrangement_heap_size = arrangement_heap_size_out.activate();
                 let mut arrangement_heap_capacity = arrangement_heap_capacity_out.activate();
                 let mut arrangement_heap_allocations = arrangement_heap_allocations_out.activate();
+                let mut arrangement_spilled_size = arrangement_spilled_size_out.activate();
                 let mut error_count = error_count_out.activate();
                 let mut hydration_time = hydration_time_out.activate();
                 let mut lir_mapping = lir_mapping_out.activate();
@@ -372,6 +385,7 @@ pub(super) fn construct<S: Scheduler + 'static, G: Scope<Timestamp = Timestamp>>
                         arrangement_heap_size: arrangement_heap_size.session(&cap),
                         arrangement_heap_capacity: arrangement_heap_capacity.session(&cap),
                         arrangement_heap_allocations: arrangement_heap_allocations.session(&cap),
+                        arrangement_spilled_size: arrangement_spilled_size.session(&cap),
                         error_count: error_count.session(&cap),
                         hydration_time: hydration_time.session(&cap),
                         lir_mapping: lir_mapping.session_with_builder(&cap),
@@ -486,6 +500,11 @@ pub(super) fn construct<S: Scheduler + 'static, G: Scope<Timestamp = Timestamp>>
             .as_collection()
             .map(move |d| arrangement_heap_datum_to_row(&mut packer, d));
 
+        let mut packer = PermutedRowPacker::new(ComputeLog::ArrangementSpilledSize);
+        let arrangement_spilled_size = arrangement_spilled_size
+            .as_collection()
+            .map(move |d| arrangement_heap_datum_to_row(&mut packer, d));
+
         let mut packer = PermutedRowPacker::new(ComputeLog::ErrorCount);
         let error_count = error_count.as_collection().map({
             let mut scratch = String::new();
@@ -555,6 +574,7 @@ pub(super) fn construct<S: Scheduler + 'static, G: Scope<Timestamp = Timestamp>>
             (ArrangementHeapSize, arrangement_heap_size),
             (ArrangementHeapCapacity, arrangement_heap_capacity),
             (ArrangementHeapAllocations, arrangement_heap_allocations),
+            (ArrangementSpilledSize, arrangement_spilled_size),
             (ErrorCount, error_count),
             (HydrationTime, hydration_time),
             (LirMapping, lir_mapping),
@@ -667,6 +687,7 @@ struct ArrangementSizeState {
     size: isize,
     capacity: isize,
     count: isize,
+    spilled: isize,
 }
 
 /// Bundled output sessions used by the demux operator.
@@ -680,6 +701,7 @@ struct DemuxOutput<'a> {
     arrangement_heap_size: OutputSessionVec<'a, Update<ArrangementHeapDatum>>,
     arrangement_heap_capacity: OutputSessionVec<'a, Update<ArrangementHeapDatum>>,
     arrangement_heap_allocations: OutputSessionVec<'a, Update<ArrangementHeapDatum>>,
+    arrangement_spilled_size: OutputSessionVec<'a, Update<ArrangementHeapDatum>>,
     hydration_time: OutputSessionVec<'a, Update<HydrationTimeDatum>>,
     error_count: OutputSessionVec<'a, Update<ErrorCountDatum>>,
     lir_mapping: OutputSessionColumnar<'a, Update<LirMappingDatum>>,
@@ -790,6 +812,7 @@ impl<A: Scheduler> DemuxHandler<'_, '_, A> {
             ArrangementHeapSize(inner) => self.handle_arrangement_heap_size(inner),
             ArrangementHeapCapacity(inner) => self.handle_arrangement_heap_capacity(inner),
             ArrangementHeapAllocations(inner) => self.handle_arrangement_heap_allocations(inner),
+            ArrangementSpilledSize(inner) => self.handle_arrangement_spilled_size(inner),
             ArrangementHeapSizeOperator(inner) => self.handle_arrangement_heap_size_operator(inner),
             ArrangementHeapSizeOperatorDrop(inner) => {
                 self.handle_arrangement_heap_size_operator_dropped(inner)
@@ -1191,6 +1214,27 @@ impl<A: Scheduler> DemuxHandler<'_, '_, A> {
         state.count += delta_allocations;
     }
 
+    /// Update the spilled size for an arrangement.
+    fn handle_arrangement_spilled_size(
+        &mut self,
+        ArrangementSpilledSizeReference {
+            operator_id,
+            delta_size,
+        }: <ArrangementSpilledSize as Columnar>::Ref<'_>,
+    ) {
+        let ts = self.ts();
+        let Some(state) = self.state.arrangement_size.get_mut(&operator_id) else {
+            return;
+        };
+
+        let datum = ArrangementHeapDatum { operator_id };
+        self.output
+            .arrangement_spilled_size
+            .give((datum, ts, Diff::cast_from(delta_size)));
+
+        state.spilled += delta_size;
+    }
+
     /// Indicate that a new arrangement exists, start maintaining the heap size state.
     fn handle_arrangement_heap_size_operator(
         &mut self,
@@ -1240,6 +1284,9 @@ impl<A: Scheduler> DemuxHandler<'_, '_, A> {
             self.output
                 .arrangement_heap_allocations
                 .give((datum, ts, diff));
+
+            let diff = -Diff::cast_from(state.spilled);
+            self.output.arrangement_spilled_size.give((datum, ts, diff));
         }
         self.shared_state
             .arrangement_size_activators
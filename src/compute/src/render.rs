@@ -1166,6 +1166,9 @@ where
                     mz_compute_types::plan::join::JoinPlan::Delta(delta_plan) => {
                         self.render_delta_join(inputs, delta_plan)
                     }
+                    mz_compute_types::plan::join::JoinPlan::AsOf(asof_plan) => {
+                        self.render_asof_join(inputs, asof_plan)
+                    }
                 }
             }
             Reduce {
@@ -1361,6 +1364,9 @@ where
     /// Steps the timestamp back so that logical compaction to the output will
     /// not conflate `self` with any historical times.
     fn step_back(&self) -> Self;
+    /// Whether timestamps are totally ordered, so that operators can apply the
+    /// updates of each timestamp in turn, in [`Ord`] order.
+    fn is_totally_ordered() -> bool;
 }
 
 impl RenderTimestamp for mz_repr::Timestamp {
@@ -1382,6 +1388,9 @@ impl RenderTimestamp for mz_repr::Timestamp {
     fn step_back(&self) -> Self {
         self.saturating_sub(1)
     }
+    fn is_totally_ordered() -> bool {
+        true
+    }
 }
 
 impl RenderTimestamp for Product<mz_repr::Timestamp, PointStamp<u64>> {
@@ -1411,6 +1420,9 @@ impl RenderTimestamp for Product<mz_repr::Timestamp, PointStamp<u64>> {
         }
         Product::new(self.outer.saturating_sub(1), PointStamp::new(vec))
     }
+    fn is_totally_ordered() -> bool {
+        false
+    }
 }
 
 /// A signal that can be awaited by operators to suspend them prior to startup.
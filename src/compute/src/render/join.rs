@@ -11,6 +11,7 @@
 //!
 //! Consult [mz_compute_types::plan::join::JoinPlan] documentation for details.
 
+mod asof_join;
 mod delta_join;
 mod linear_join;
 mod mz_join_core;
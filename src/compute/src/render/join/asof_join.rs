@@ -0,0 +1,632 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! ASOF join execution dataflow construction.
+//!
+//! Consult [AsOfJoinPlan] documentation for details.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+use columnar::Columnar;
+use differential_dataflow::consolidation::consolidate;
+use differential_dataflow::containers::{Columnation, Region};
+use differential_dataflow::lattice::Lattice;
+use differential_dataflow::operators::arrange::TraceAgent;
+use differential_dataflow::trace::{BatchReader, Cursor, TraceReader};
+use differential_dataflow::{AsCollection, Collection, IntoOwned};
+use mz_compute_types::plan::join::AsOfJoinPlan;
+use mz_expr::{AsOfComparison, MirScalarExpr};
+use mz_repr::{Datum, DatumVec, Diff, Row, RowArena, SharedRow};
+use mz_storage_types::errors::DataflowError;
+use mz_timely_util::operator::CollectionExt;
+use serde::{Deserialize, Serialize};
+use timely::PartialOrder;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::Scope;
+use timely::dataflow::channels::pact::Pipeline;
+use timely::dataflow::operators::{FrontierNotificator, InputCapability, Operator};
+use timely::progress::{Antichain, Timestamp};
+
+use crate::extensions::arrange::MzArrange;
+use crate::extensions::reduce::MzReduce;
+use crate::render::RenderTimestamp;
+use crate::render::context::{CollectionBundle, Context};
+use crate::row_spine::{RowRowBuilder, RowValBatcher, RowValBuilder, RowValSpine};
+use crate::typedefs::RowRowSpine;
+
+impl<G> Context<G>
+where
+    G: Scope,
+    G::Timestamp: RenderTimestamp,
+    <G::Timestamp as Columnar>::Container: Clone + Send,
+{
+    /// Renders `MirRelationExpr::Join` with an ASOF join implementation.
+    ///
+    /// Both inputs are arranged by their join key, with the rows of each key ordered by time.
+    /// As the rows of the second input change, only the rows of the first input whose closest
+    /// match may have changed are revisited. Rows of the first input without a match produce no
+    /// output, which gives the join inner semantics, and each output row has the product of the
+    /// multiplicities of its input rows.
+    pub(crate) fn render_asof_join(
+        &self,
+        inputs: Vec<CollectionBundle<G>>,
+        join_plan: AsOfJoinPlan,
+    ) -> CollectionBundle<G> {
+        let mut inputs = inputs.into_iter();
+        let (left, left_errs) = inputs
+            .next()
+            .expect("ASOF join has two inputs")
+            .as_specific_collection(None, &self.config_set);
+        let (right, right_errs) = inputs
+            .next()
+            .expect("ASOF join has two inputs")
+            .as_specific_collection(None, &self.config_set);
+
+        let (oks, errs) = self.scope.clone().region_named("Join(AsOf)", |inner| {
+            let AsOfJoinPlan {
+                left_key,
+                right_key,
+                left_time,
+                right_time,
+                comparison,
+            } = join_plan;
+
+            // The ordered implementation looks up the closest match of a row of the first input
+            // by seeking forward through the rows of the second input, so these must be ordered
+            // in the direction of the comparison, and the rows of the first input whose match
+            // may change in the opposite direction.
+            let ordered = G::Timestamp::is_totally_ordered();
+            let right_descending =
+                ordered && matches!(comparison, AsOfComparison::Gt | AsOfComparison::Gte);
+            let left_descending = ordered && !right_descending;
+
+            let (left, left_key_errs) = key_with_time(
+                &left.enter_region(inner),
+                true,
+                left_descending,
+                left_key,
+                left_time,
+            );
+            let (right, right_key_errs) = key_with_time(
+                &right.enter_region(inner),
+                false,
+                right_descending,
+                right_key,
+                right_time,
+            );
+
+            let oks = if ordered {
+                asof_join_ordered(&left, &right, comparison)
+            } else {
+                asof_join_reduce(&left, &right, comparison)
+            };
+
+            let errs = left_errs
+                .enter_region(inner)
+                .concat(&right_errs.enter_region(inner))
+                .concat(&left_key_errs)
+                .concat(&right_key_errs);
+            (oks.leave_region(), errs.leave_region())
+        });
+
+        CollectionBundle::from_collections(oks, errs)
+    }
+}
+
+/// The arrangement of the keyed rows of one input of an ASOF join.
+type AsOfTrace<T> = TraceAgent<RowValSpine<AsOfRow, T, Diff>>;
+
+/// Maintains the output of an ASOF join of the keyed rows of its inputs, applying the updates of
+/// each timestamp once it is complete.
+///
+/// Both inputs are arranged by key. The rows of the second input are ordered in the direction of
+/// the comparison, so that the closest match of a row of the first input is found by seeking a
+/// cursor to its time. A changed row of the first input is matched with such a lookup, and a
+/// changed row of the second input only revisits the rows of the first input from its time up to
+/// the first row whose match it cannot affect. An update thus costs a number of seeks that is
+/// logarithmic in the number of rows with its key, plus the number of rows of the first input
+/// whose match it changes. All rows with the same key, including the empty key, are on the same
+/// worker.
+///
+/// Applying the updates of each timestamp in turn requires totally ordered timestamps.
+fn asof_join_ordered<G>(
+    left: &Collection<G, (Row, AsOfRow), Diff>,
+    right: &Collection<G, (Row, AsOfRow), Diff>,
+    comparison: AsOfComparison,
+) -> Collection<G, Row, Diff>
+where
+    G: Scope,
+    G::Timestamp: RenderTimestamp,
+    <G::Timestamp as Columnar>::Container: Clone + Send,
+{
+    // Rows with a null time neither have nor are a match.
+    let arrange = |input: &Collection<G, (Row, AsOfRow), Diff>, name: &str| {
+        input
+            .filter(|(_key, row)| !row.time().is_null())
+            .mz_arrange::<
+                RowValBatcher<AsOfRow, _, _>,
+                RowValBuilder<AsOfRow, _, _>,
+                RowValSpine<AsOfRow, _, _>,
+            >(name)
+    };
+    let left = arrange(left, "Arranged AsOfJoin left input");
+    let right = arrange(right, "Arranged AsOfJoin right input");
+
+    let mut left_trace = left.trace;
+    let mut right_trace = right.trace;
+    left.stream
+        .binary_frontier(
+            &right.stream,
+            Pipeline,
+            Pipeline,
+            "AsOfJoin",
+            |_capability, _info| {
+                let mut notificator = FrontierNotificator::default();
+                // The rows of both inputs that changed at each timestamp, until the timestamp is
+                // complete.
+                let mut pending: BTreeMap<G::Timestamp, Vec<(Row, AsOfRow)>> = BTreeMap::new();
+                let mut left_datums = DatumVec::new();
+                let mut right_datums = DatumVec::new();
+                let mut row_builder = Row::default();
+                move |input1, input2, output| {
+                    let mut stash = |capability: &InputCapability<_>,
+                                     batches: &mut Vec<
+                        <AsOfTrace<G::Timestamp> as TraceReader>::Batch,
+                    >| {
+                        for batch in batches.drain(..) {
+                            let mut cursor = batch.cursor();
+                            while let Some(key) = cursor.get_key(&batch) {
+                                while let Some(row) = cursor.get_val(&batch) {
+                                    cursor.map_times(&batch, |time, _diff| {
+                                        pending
+                                            .entry(time.into_owned())
+                                            .or_insert_with_key(|time| {
+                                                notificator.notify_at(capability.delayed(time));
+                                                Vec::new()
+                                            })
+                                            .push((key.into_owned(), row.clone()));
+                                    });
+                                    cursor.step_val(&batch);
+                                }
+                                cursor.step_key(&batch);
+                            }
+                        }
+                    };
+                    while let Some((capability, data)) = input1.next() {
+                        stash(&capability, data);
+                    }
+                    while let Some((capability, data)) = input2.next() {
+                        stash(&capability, data);
+                    }
+
+                    let mut processed = None;
+                    notificator.for_each(&[input1.frontier(), input2.frontier()], |cap, _| {
+                        let Some(updates) = pending.remove(cap.time()) else {
+                            return;
+                        };
+                        let time = cap.time().clone();
+                        let mut by_key: BTreeMap<Row, BTreeSet<AsOfRow>> = BTreeMap::new();
+                        for (key, row) in updates {
+                            by_key.entry(key).or_default().insert(row);
+                        }
+                        let (mut left_cursor, left_storage) = left_trace.cursor();
+                        let (mut right_cursor, right_storage) = right_trace.cursor();
+                        let mut changes = Vec::new();
+                        for (key, rows) in by_key {
+                            let mut lefts =
+                                KeyCursor::new(&mut left_cursor, &left_storage, &key, &time);
+                            let mut rights =
+                                KeyCursor::new(&mut right_cursor, &right_storage, &key, &time);
+                            let mut affected = BTreeSet::new();
+                            for row in rows {
+                                if row.is_left() {
+                                    affected.insert(row);
+                                } else {
+                                    lefts.affected_by(
+                                        &mut rights,
+                                        row.time(),
+                                        comparison,
+                                        &mut affected,
+                                    );
+                                }
+                            }
+                            for left in &affected {
+                                let (old_left, new_left) = lefts.counts(left);
+                                let (old_right, new_right) =
+                                    rights.closest_matches(left.time(), comparison);
+                                let mut emit = |right: AsOfRow, diff: Diff| {
+                                    let left = left_datums.borrow_with(&left.0);
+                                    let right = right_datums.borrow_with(&right.0);
+                                    let mut packer = row_builder.packer();
+                                    packer.extend(&left[3..]);
+                                    packer.extend(&right[3..]);
+                                    changes.push((row_builder.clone(), diff));
+                                };
+                                if let Some((right, old_right)) = old_right {
+                                    emit(right, -(old_left * old_right));
+                                }
+                                if let Some((right, new_right)) = new_right {
+                                    emit(right, new_left * new_right);
+                                }
+                            }
+                        }
+                        consolidate(&mut changes);
+                        output.session(&cap).give_iterator(
+                            changes
+                                .into_iter()
+                                .map(|(row, diff)| (row, time.clone(), diff)),
+                        );
+                        processed = Some(time);
+                    });
+
+                    // Lookups distinguish the updates at the next time to process from all
+                    // earlier updates, which may be compacted up to the last processed time.
+                    if let Some(time) = processed {
+                        let frontier = Antichain::from_elem(time);
+                        for trace in [&mut left_trace, &mut right_trace] {
+                            trace.set_logical_compaction(frontier.borrow());
+                            trace.set_physical_compaction(frontier.borrow());
+                        }
+                    }
+                }
+            },
+        )
+        .as_collection()
+}
+
+/// Maintains the output of an ASOF join of the keyed rows of its inputs with a reduction that
+/// recomputes all matches of a key whenever any row with that key changes.
+///
+/// Unlike [`asof_join_ordered`], this supports partially ordered timestamps, as in iterative
+/// scopes, at a cost proportional to the number of rows with the key of an update.
+fn asof_join_reduce<G>(
+    left: &Collection<G, (Row, AsOfRow), Diff>,
+    right: &Collection<G, (Row, AsOfRow), Diff>,
+    comparison: AsOfComparison,
+) -> Collection<G, Row, Diff>
+where
+    G: Scope,
+    G::Timestamp: RenderTimestamp,
+    <G::Timestamp as Columnar>::Container: Clone + Send,
+{
+    let mut left_datums = DatumVec::new();
+    let mut right_datums = DatumVec::new();
+    left.concat(right)
+        .mz_arrange::<
+            RowValBatcher<AsOfRow, _, _>,
+            RowValBuilder<AsOfRow, _, _>,
+            RowValSpine<AsOfRow, _, _>,
+        >("Arranged AsOfJoin input")
+        .mz_reduce_abelian::<_, _, _, RowRowBuilder<_, _>, RowRowSpine<_, _>>(
+            "AsOfJoin",
+            move |_key, source, target: &mut Vec<(Row, Diff)>| {
+                // Values are ordered by their flag, which puts the rows of the second input
+                // first, and then by time, with ties broken by the rest of the row. Rows with a
+                // null time come last among the rows of each input.
+                let split = source.partition_point(|(row, _)| !row.is_left());
+                let (rights, lefts) = source.split_at(split);
+                let rights = &rights[..rights.partition_point(|(row, _)| !row.time().is_null())];
+                if lefts.is_empty() || rights.is_empty() {
+                    return;
+                }
+                let right_times = rights.iter().map(|(row, _)| row.time()).collect::<Vec<_>>();
+
+                let mut row_builder = SharedRow::get();
+                for (left, left_diff) in lefts {
+                    let left_time = left.time();
+                    if left_time.is_null() {
+                        // Rows with a null time come last.
+                        break;
+                    }
+                    if let Some(index) = comparison.closest_match(left_time, &right_times) {
+                        let (right, right_diff) = &rights[index];
+                        let left = left_datums.borrow_with(&left.0);
+                        let right = right_datums.borrow_with(&right.0);
+                        let mut packer = row_builder.packer();
+                        packer.extend(&left[3..]);
+                        packer.extend(&right[3..]);
+                        target.push((row_builder.clone(), *left_diff * *right_diff));
+                    }
+                }
+            },
+        )
+        .as_collection(|_key, val| val.into_owned())
+}
+
+/// A cursor into the arrangement of one input of an ASOF join, restricted to the rows of one
+/// key, that distinguishes the contents of the arrangement before and at the time being
+/// processed.
+struct KeyCursor<'a, T>
+where
+    T: Timestamp + Lattice + Columnation,
+{
+    cursor: &'a mut <AsOfTrace<T> as TraceReader>::Cursor,
+    storage: &'a <AsOfTrace<T> as TraceReader>::Storage,
+    /// Whether the arrangement contains the key.
+    found: bool,
+    time: &'a T,
+}
+
+impl<'a, T> KeyCursor<'a, T>
+where
+    T: Timestamp + Lattice + Columnation,
+{
+    fn new(
+        cursor: &'a mut <AsOfTrace<T> as TraceReader>::Cursor,
+        storage: &'a <AsOfTrace<T> as TraceReader>::Storage,
+        key: &Row,
+        time: &'a T,
+    ) -> Self {
+        cursor.rewind_keys(storage);
+        cursor.seek_key(storage, IntoOwned::borrow_as(key));
+        let found = cursor
+            .get_key(storage)
+            .is_some_and(|k| k == IntoOwned::borrow_as(key));
+        KeyCursor {
+            cursor,
+            storage,
+            found,
+            time,
+        }
+    }
+
+    /// Returns the multiplicity of the current row before and at the time being processed.
+    fn current_counts(&mut self) -> (Diff, Diff) {
+        let (mut before, mut at) = (Diff::ZERO, Diff::ZERO);
+        self.cursor.map_times(self.storage, |time, diff| {
+            if time.less_than(self.time) {
+                before += diff;
+            }
+            if time.less_equal(self.time) {
+                at += diff;
+            }
+        });
+        (before, at)
+    }
+
+    /// Positions the cursor at the first row that orders at or after `probe`.
+    fn seek(&mut self, probe: &AsOfRow) {
+        self.cursor.rewind_vals(self.storage);
+        self.cursor.seek_val(self.storage, probe);
+    }
+
+    /// Returns the multiplicity of `row` before and at the time being processed.
+    fn counts(&mut self, row: &AsOfRow) -> (Diff, Diff) {
+        if !self.found {
+            return (Diff::ZERO, Diff::ZERO);
+        }
+        self.seek(row);
+        match self.cursor.get_val(self.storage) {
+            Some(current) if current == row => self.current_counts(),
+            _ => (Diff::ZERO, Diff::ZERO),
+        }
+    }
+
+    /// Returns the row of the second input that is the closest match for `time` before and at
+    /// the time being processed, with its multiplicity.
+    ///
+    /// The rows must be ordered in the direction of `comparison`. Among rows with equal times,
+    /// the first one in that direction is chosen, which is the last one for `>` and `>=`, and
+    /// the first one for `<` and `<=`, as in [`AsOfComparison::closest_match`].
+    fn closest_matches(
+        &mut self,
+        time: Datum,
+        comparison: AsOfComparison,
+    ) -> (Option<(AsOfRow, Diff)>, Option<(AsOfRow, Diff)>) {
+        let (mut before, mut at) = (None, None);
+        if !self.found {
+            return (before, at);
+        }
+        let strict = matches!(comparison, AsOfComparison::Gt | AsOfComparison::Lt);
+        let descending = matches!(comparison, AsOfComparison::Gt | AsOfComparison::Gte);
+        self.seek(&AsOfRow::probe(false, descending, time));
+        while let Some(row) = self.cursor.get_val(self.storage) {
+            if before.is_some() && at.is_some() {
+                break;
+            }
+            if !strict || row.time() != time {
+                let (count_before, count_at) = self.current_counts();
+                if before.is_none() && !count_before.is_zero() {
+                    before = Some((row.clone(), count_before));
+                }
+                if at.is_none() && !count_at.is_zero() {
+                    at = Some((row.clone(), count_at));
+                }
+            }
+            self.cursor.step_val(self.storage);
+        }
+        (before, at)
+    }
+
+    /// Adds the rows of the first input whose match may change when the rows of the second input
+    /// with `time` change to `affected`.
+    ///
+    /// The rows of the first input must be ordered against the direction of `comparison`. These
+    /// are the rows with `time`, followed by the rows up to the first one whose match is not at
+    /// `time` either before or at the time being processed. The matches of all further rows are
+    /// closer to them than `time`.
+    fn affected_by(
+        &mut self,
+        rights: &mut KeyCursor<'_, T>,
+        time: Datum,
+        comparison: AsOfComparison,
+        affected: &mut BTreeSet<AsOfRow>,
+    ) {
+        if !self.found {
+            return;
+        }
+        let descending = matches!(comparison, AsOfComparison::Lt | AsOfComparison::Lte);
+        self.seek(&AsOfRow::probe(true, descending, time));
+        while let Some(left) = self.cursor.get_val(self.storage) {
+            if left.time() != time {
+                let (before, at) = rights.closest_matches(left.time(), comparison);
+                let matches_time =
+                    |m: Option<(AsOfRow, Diff)>| m.is_some_and(|(right, _)| right.time() == time);
+                if !matches_time(before) && !matches_time(at) {
+                    break;
+                }
+            }
+            affected.insert(left.clone());
+            self.cursor.step_val(self.storage);
+        }
+    }
+}
+
+/// Keys the rows of one input of an ASOF join by `key`.
+///
+/// The value is `is_left` and `descending`, followed by the value of `time` and the row itself.
+fn key_with_time<G>(
+    input: &Collection<G, Row, Diff>,
+    is_left: bool,
+    descending: bool,
+    key: Vec<MirScalarExpr>,
+    time: MirScalarExpr,
+) -> (
+    Collection<G, (Row, AsOfRow), Diff>,
+    Collection<G, DataflowError, Diff>,
+)
+where
+    G: Scope,
+{
+    type CB<C> = CapacityContainerBuilder<C>;
+    let mut datums = DatumVec::new();
+    input.map_fallible::<CB<_>, CB<_>, _, _, _>("AsOfJoinKeyPreparation", move |row| {
+        let temp_storage = RowArena::new();
+        let datums_local = datums.borrow_with(&row);
+        let mut row_builder = SharedRow::get();
+        row_builder
+            .packer()
+            .try_extend(key.iter().map(|e| e.eval(&datums_local, &temp_storage)))?;
+        let key_row = row_builder.clone();
+        let time = time.eval(&datums_local, &temp_storage)?;
+        let mut packer = row_builder.packer();
+        packer.push(Datum::from(is_left));
+        packer.push(Datum::from(descending));
+        packer.push(time);
+        packer.extend(&*datums_local);
+        Ok::<_, DataflowError>((key_row, AsOfRow(row_builder.clone())))
+    })
+}
+
+/// A row of either input of an ASOF join, prefixed by a flag indicating whether it came
+/// from the first input, a flag indicating whether it orders by descending time, and its time.
+///
+/// Unlike [`Row`], which orders by its encoding, this orders by its datums, so that an
+/// arrangement holds the rows of each input in time order. Rows that order by descending time
+/// also order ties by the rest of their datums in descending order.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AsOfRow(Row);
+
+impl AsOfRow {
+    /// Whether the row came from the first input.
+    fn is_left(&self) -> bool {
+        self.0.iter().next() == Some(Datum::True)
+    }
+
+    /// The time of the row.
+    fn time(&self) -> Datum<'_> {
+        self.0.iter().nth(2).expect("ASOF join rows have a time")
+    }
+
+    /// A row that orders before all rows of the same input and direction with `time`, and
+    /// after all rows of that input that order before `time`.
+    fn probe(is_left: bool, descending: bool, time: Datum) -> Self {
+        AsOfRow(Row::pack_slice(&[
+            Datum::from(is_left),
+            Datum::from(descending),
+            time,
+        ]))
+    }
+}
+
+impl Ord for AsOfRow {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let mut left = self.0.iter();
+        let mut right = other.0.iter();
+        let flags = left.by_ref().take(2).cmp(right.by_ref().take(2));
+        if flags != Ordering::Equal {
+            return flags;
+        }
+        let descending = self.0.iter().nth(1) == Some(Datum::True);
+        let mut left = left.peekable();
+        let mut right = right.peekable();
+        let time = left.next().cmp(&right.next());
+        // Probes, which have nothing but a time, order before the rows with their time in
+        // either direction.
+        let rest = match (left.peek().is_none(), right.peek().is_none()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) if descending => left.cmp(right).reverse(),
+            (false, false) => left.cmp(right),
+        };
+        if descending {
+            time.reverse().then(rest)
+        } else {
+            time.then(rest)
+        }
+    }
+}
+
+impl PartialOrd for AsOfRow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for AsOfRow {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for AsOfRow {}
+
+impl Columnation for AsOfRow {
+    type InnerRegion = AsOfRowRegion;
+}
+
+#[derive(Default)]
+pub struct AsOfRowRegion {
+    row_region: <Row as Columnation>::InnerRegion,
+}
+
+impl Region for AsOfRowRegion {
+    type Item = AsOfRow;
+
+    unsafe fn copy(&mut self, item: &Self::Item) -> Self::Item {
+        AsOfRow(unsafe { self.row_region.copy(&item.0) })
+    }
+
+    fn clear(&mut self) {
+        self.row_region.clear();
+    }
+
+    fn reserve_items<'a, I>(&mut self, items: I)
+    where
+        Self: 'a,
+        I: Iterator<Item = &'a Self::Item> + Clone,
+    {
+        self.row_region.reserve_items(items.map(|item| &item.0));
+    }
+
+    fn reserve_regions<'a, I>(&mut self, regions: I)
+    where
+        Self: 'a,
+        I: Iterator<Item = &'a Self> + Clone,
+    {
+        self.row_region
+            .reserve_regions(regions.map(|region| &region.row_region));
+    }
+
+    fn heap_size(&self, callback: impl FnMut(usize, usize)) {
+        self.row_region.heap_size(callback);
+    }
+}
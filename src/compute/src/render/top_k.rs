@@ -13,6 +13,7 @@
 
 use columnar::Columnar;
 use differential_dataflow::IntoOwned;
+use differential_dataflow::consolidation::consolidate;
 use differential_dataflow::containers::Columnation;
 use differential_dataflow::hashable::Hashable;
 use differential_dataflow::lattice::Lattice;
@@ -20,7 +21,7 @@ use differential_dataflow::operators::arrange::{Arranged, TraceAgent};
 use differential_dataflow::trace::{Batch, Builder, Trace, TraceReader};
 use differential_dataflow::{AsCollection, Collection};
 use mz_compute_types::plan::top_k::{
-    BasicTopKPlan, MonotonicTop1Plan, MonotonicTopKPlan, TopKPlan,
+    BasicTopKPlan, MonotonicTop1Plan, MonotonicTopKPlan, OrderedTopKPlan, TopKPlan,
 };
 use mz_expr::func::CastUint64ToInt64;
 use mz_expr::{BinaryFunc, EvalError, MirScalarExpr, UnaryFunc};
@@ -35,11 +36,16 @@ use std::rc::Rc;
 use timely::Container;
 use timely::container::{CapacityContainerBuilder, PushInto};
 use timely::dataflow::Scope;
-use timely::dataflow::channels::pact::Pipeline;
-use timely::dataflow::operators::Operator;
+use timely::dataflow::channels::pact::{Exchange, Pipeline};
+use timely::dataflow::operators::{Capability, Operator};
 
 use crate::extensions::arrange::{ArrangementSize, KeyCollection, MzArrange};
 use crate::extensions::reduce::MzReduce;
+use crate::logging::compute::{
+    ArrangementHeapAllocations, ArrangementHeapCapacity, ArrangementHeapSize,
+    ArrangementHeapSizeOperator, ArrangementHeapSizeOperatorDrop, ComputeEvent,
+    ComputeEventBuilder, Logger,
+};
 use crate::render::Pairer;
 use crate::render::context::{CollectionBundle, Context};
 use crate::render::errors::MaybeValidatingRow;
@@ -77,6 +83,8 @@ where
                 TopKPlan::MonotonicTop1(MonotonicTop1Plan { .. }) => None,
                 TopKPlan::MonotonicTopK(MonotonicTopKPlan { limit, .. }) => Some(limit),
                 TopKPlan::Basic(BasicTopKPlan { limit, .. }) => Some(limit),
+                // Ordered plans have literal, non-negative limits.
+                TopKPlan::Ordered(OrderedTopKPlan { .. }) => None,
             };
             if let Some(limit) = limit_err {
                 if let Some(expr) = limit {
@@ -238,6 +246,18 @@ where
                     err_collection = err_collection.concat(&errs);
                     oks
                 }
+                TopKPlan::Ordered(OrderedTopKPlan {
+                    group_key,
+                    order_key,
+                    offset,
+                    limit,
+                    arity,
+                }) => {
+                    let (oks, errs) = self
+                        .render_topk_ordered(ok_input, group_key, order_key, offset, limit, arity);
+                    err_collection = err_collection.concat(&errs);
+                    oks
+                }
             };
 
             // Extract the results from the region.
@@ -445,6 +465,165 @@ where
         (oks.concat(&input), errs)
     }
 
+    /// Renders a TopK that maintains each group's rows in an [`ordered::Tree`], ordered by
+    /// `order_key`.
+    ///
+    /// The operator applies the updates of each time once the input frontier has passed it, in
+    /// time order. For each update it looks up the rank of the updated row and emits the rows
+    /// entering and leaving the window of ranks selected by `offset` and `limit`, in time
+    /// logarithmic in the group's size plus the number of rows entering or leaving the window.
+    ///
+    /// The trees are operator-local state rather than an arrangement. The operator reports their
+    /// size through the arrangement size logging, so that it is visible in memory introspection.
+    ///
+    /// Applying updates in time order requires totally ordered timestamps. Lowering does not
+    /// plan ordered TopKs in recursive scopes, and should it happen, we render the plan as a
+    /// single stage of the basic TopK instead.
+    fn render_topk_ordered<S>(
+        &self,
+        collection: Collection<S, Row, Diff>,
+        group_key: Vec<usize>,
+        order_key: Vec<mz_expr::ColumnOrder>,
+        offset: usize,
+        limit: Option<usize>,
+        arity: usize,
+    ) -> (Collection<S, Row, Diff>, Collection<S, DataflowError, Diff>)
+    where
+        S: Scope<Timestamp = G::Timestamp>,
+    {
+        if !<G::Timestamp as crate::render::RenderTimestamp>::is_totally_ordered() {
+            let limit = limit.map(|limit| {
+                let limit = i64::try_from(limit).expect("limit fits in an i64");
+                MirScalarExpr::literal_ok(Datum::Int64(limit), ScalarType::Int64)
+            });
+            return self.build_topk(
+                collection,
+                group_key,
+                order_key,
+                offset,
+                limit,
+                arity,
+                vec![],
+            );
+        }
+
+        let layout = ordered::Layout::new(&order_key, arity);
+        let mut datum_vec = mz_repr::DatumVec::new();
+        let collection = collection.map({
+            let layout = layout.clone();
+            move |row| {
+                let datums = datum_vec.borrow_with(&row);
+                let group_row = SharedRow::pack(group_key.iter().map(|i| datums[*i]));
+                (group_row, layout.pack(&datums))
+            }
+        });
+
+        // The window of ranks to produce from each group.
+        let lo = u64::cast_from(offset);
+        let hi = limit.map_or(u64::MAX, |limit| lo.saturating_add(u64::cast_from(limit)));
+
+        let error_logger = self.error_logger();
+        let logger = collection
+            .scope()
+            .logger_for::<ComputeEventBuilder>("materialize/compute");
+        let exchange =
+            Exchange::new(|update: &((Row, Row), S::Timestamp, Diff)| (update.0).0.hashed().into());
+        let results =
+            collection
+                .inner
+                .unary_frontier(exchange, "OrderedTopK", move |_cap, info| {
+                    let mut pending: BTreeMap<S::Timestamp, (Capability<S::Timestamp>, Vec<_>)> =
+                        BTreeMap::new();
+                    let mut groups: BTreeMap<Row, ordered::Tree> = BTreeMap::new();
+                    // The heap size and allocations of `groups`, which are reported as if
+                    // the operator maintained an arrangement.
+                    let (mut size, mut allocations) = (0, 0);
+                    let footprint = |key: &Row, tree: &ordered::Tree| {
+                        let (size, allocations) = tree.heap_size();
+                        (size + key.byte_len(), allocations + 1)
+                    };
+                    let mut size_logger = logger
+                        .map(|logger| StateSizeLogger::new(logger, info.global_id, &info.address));
+                    let mut datum_vec = DatumVec::new();
+                    let mut cmp = {
+                        let layout = layout.clone();
+                        move |l: &Row, r: &Row| layout.compare(l, r)
+                    };
+
+                    move |input, output| {
+                        input.for_each(|cap, data| {
+                            for (group_row_and_row, time, diff) in data.drain(..) {
+                                let (_, updates) = pending
+                                    .entry(time.clone())
+                                    .or_insert_with(|| (cap.delayed(&time), Vec::new()));
+                                updates.push((group_row_and_row, diff));
+                            }
+                        });
+
+                        // Apply complete times in order.
+                        while let Some(entry) = pending.first_entry() {
+                            if input.frontier().less_equal(entry.key()) {
+                                break;
+                            }
+                            let (cap, mut updates) = entry.remove();
+                            consolidate(&mut updates);
+                            let mut session = output.session(&cap);
+                            for ((group_row, row), diff) in updates {
+                                let mut tree = match groups.remove(&group_row) {
+                                    Some(tree) => {
+                                        let (tree_size, tree_allocations) =
+                                            footprint(&group_row, &tree);
+                                        size -= tree_size;
+                                        allocations -= tree_allocations;
+                                        tree
+                                    }
+                                    None => ordered::Tree::default(),
+                                };
+                                let valid = tree.update(
+                                    &row,
+                                    diff.into_inner(),
+                                    lo,
+                                    hi,
+                                    &mut cmp,
+                                    |row, diff| {
+                                        let row = layout.unpack(row, &mut datum_vec);
+                                        session.give((
+                                            Ok(row),
+                                            cap.time().clone(),
+                                            Diff::from(diff),
+                                        ))
+                                    },
+                                );
+                                if !valid {
+                                    let message = "Negative multiplicities in TopK";
+                                    let row = layout.unpack(&row, &mut datum_vec);
+                                    error_logger
+                                        .log(message, &format!("k={group_row:?}, v={row:?}"));
+                                    let err =
+                                        DataflowError::from(EvalError::Internal(message.into()));
+                                    session.give((Err(err), cap.time().clone(), Diff::ONE));
+                                }
+                                if !tree.is_empty() {
+                                    let (tree_size, tree_allocations) =
+                                        footprint(&group_row, &tree);
+                                    size += tree_size;
+                                    allocations += tree_allocations;
+                                    groups.insert(group_row, tree);
+                                }
+                            }
+                        }
+                        if let Some(size_logger) = &mut size_logger {
+                            size_logger.update(size, allocations);
+                        }
+                    }
+                });
+
+        type CB<C> = CapacityContainerBuilder<C>;
+        results
+            .as_collection()
+            .map_fallible::<CB<_>, CB<_>, _, _, _>("Demuxing Errors", |result| result)
+    }
+
     fn render_top1_monotonic<S>(
         &self,
         collection: Collection<S, Row, Diff>,
@@ -1028,3 +1207,547 @@ pub mod monoids {
         }
     }
 }
+
+/// Reports the heap size of operator-local state through the arrangement size logging, so that
+/// operators that keep their state outside of arrangements still show up in memory
+/// introspection.
+struct StateSizeLogger {
+    logger: Logger,
+    operator_id: usize,
+    size: isize,
+    allocations: isize,
+}
+
+impl StateSizeLogger {
+    /// Announces the operator with the given global id and address as holding state.
+    fn new(logger: Logger, operator_id: usize, address: &[usize]) -> Self {
+        logger.log(&ComputeEvent::ArrangementHeapSizeOperator(
+            ArrangementHeapSizeOperator {
+                operator_id,
+                address: address.to_vec(),
+            },
+        ));
+        StateSizeLogger {
+            logger,
+            operator_id,
+            size: 0,
+            allocations: 0,
+        }
+    }
+
+    /// Reports the current heap size in bytes and number of allocations of the state.
+    fn update(&mut self, size: usize, allocations: usize) {
+        let operator_id = self.operator_id;
+        let size = isize::try_from(size).expect("must fit");
+        if size != self.size {
+            // The state doesn't track its spare capacity, so report its size as its capacity.
+            self.logger
+                .log(&ComputeEvent::ArrangementHeapSize(ArrangementHeapSize {
+                    operator_id,
+                    delta_size: size - self.size,
+                }));
+            self.logger.log(&ComputeEvent::ArrangementHeapCapacity(
+                ArrangementHeapCapacity {
+                    operator_id,
+                    delta_capacity: size - self.size,
+                },
+            ));
+            self.size = size;
+        }
+        let allocations = isize::try_from(allocations).expect("must fit");
+        if allocations != self.allocations {
+            self.logger.log(&ComputeEvent::ArrangementHeapAllocations(
+                ArrangementHeapAllocations {
+                    operator_id,
+                    delta_allocations: allocations - self.allocations,
+                },
+            ));
+            self.allocations = allocations;
+        }
+    }
+}
+
+impl Drop for StateSizeLogger {
+    fn drop(&mut self) {
+        self.logger
+            .log(&ComputeEvent::ArrangementHeapSizeOperatorDrop(
+                ArrangementHeapSizeOperatorDrop {
+                    operator_id: self.operator_id,
+                },
+            ));
+    }
+}
+
+/// Types for maintaining the rows of a TopK group in the order of its `order_key`.
+pub mod ordered {
+    use std::cmp::Ordering;
+
+    use differential_dataflow::hashable::Hashable;
+    use mz_expr::ColumnOrder;
+    use mz_repr::{Datum, DatumVec, Row, SharedRow};
+
+    /// Describes how the rows of a TopK are laid out, so that they can be compared in the
+    /// order of the TopK by decoding only their ordered columns.
+    #[derive(Clone, Debug)]
+    pub struct Layout {
+        /// The direction of each ordered column, which come first in laid out rows.
+        directions: Vec<Direction>,
+        /// The input column stored at each position, ordered columns first.
+        permutation: Vec<usize>,
+        /// The position at which each input column is stored.
+        inverse: Vec<usize>,
+    }
+
+    /// The direction in which a column is ordered.
+    #[derive(Clone, Copy, Debug)]
+    struct Direction {
+        desc: bool,
+        nulls_last: bool,
+    }
+
+    impl Layout {
+        /// Lays out rows of `arity` columns to order by `order_key`.
+        ///
+        /// Columns that `order_key` mentions again are dropped from it, as their earlier
+        /// mention already determines the order.
+        pub fn new(order_key: &[ColumnOrder], arity: usize) -> Self {
+            let mut directions = Vec::new();
+            let mut permutation = Vec::with_capacity(arity);
+            for order in order_key {
+                if !permutation.contains(&order.column) {
+                    permutation.push(order.column);
+                    directions.push(Direction {
+                        desc: order.desc,
+                        nulls_last: order.nulls_last,
+                    });
+                }
+            }
+            let ordered = permutation.clone();
+            permutation.extend((0..arity).filter(|column| !ordered.contains(column)));
+            let mut inverse = vec![0; arity];
+            for (position, column) in permutation.iter().enumerate() {
+                inverse[*column] = position;
+            }
+            Layout {
+                directions,
+                permutation,
+                inverse,
+            }
+        }
+
+        /// Lays out the datums of an input row.
+        pub fn pack(&self, datums: &[Datum]) -> Row {
+            SharedRow::pack(self.permutation.iter().map(|column| datums[*column]))
+        }
+
+        /// Recovers the input row from a laid out row.
+        pub fn unpack(&self, row: &Row, datum_vec: &mut DatumVec) -> Row {
+            let datums = datum_vec.borrow_with(row);
+            SharedRow::pack(self.inverse.iter().map(|position| datums[*position]))
+        }
+
+        /// Compares two laid out rows by the ordered columns in their directions, with ties
+        /// broken by the remaining columns.
+        ///
+        /// This matches [`mz_expr::compare_columns`] with a tiebreaker that compares the rows.
+        pub fn compare(&self, left: &Row, right: &Row) -> Ordering {
+            let mut left = left.iter();
+            let mut right = right.iter();
+            for direction in &self.directions {
+                let l = left
+                    .next()
+                    .expect("laid out rows have their ordered columns");
+                let r = right
+                    .next()
+                    .expect("laid out rows have their ordered columns");
+                let cmp = match (l, r) {
+                    (Datum::Null, Datum::Null) => Ordering::Equal,
+                    (Datum::Null, _) if direction.nulls_last => Ordering::Greater,
+                    (Datum::Null, _) => Ordering::Less,
+                    (_, Datum::Null) if direction.nulls_last => Ordering::Less,
+                    (_, Datum::Null) => Ordering::Greater,
+                    (l, r) if direction.desc => r.cmp(&l),
+                    (l, r) => l.cmp(&r),
+                };
+                if cmp != Ordering::Equal {
+                    return cmp;
+                }
+            }
+            left.cmp(right)
+        }
+    }
+
+    /// A multiset of rows, ordered by a caller-provided comparison, that can locate rows by
+    /// their rank in logarithmic time.
+    ///
+    /// The tree is a treap whose priorities are the hashes of the rows. Each node tracks the
+    /// number of rows in its subtree, counting multiplicities.
+    #[derive(Debug, Default)]
+    pub struct Tree {
+        root: Option<Box<Node>>,
+        /// The heap size of all nodes, in bytes.
+        size: usize,
+        /// The number of nodes.
+        nodes: usize,
+    }
+
+    #[derive(Debug)]
+    struct Node {
+        row: Row,
+        count: u64,
+        total: u64,
+        priority: u64,
+        left: Option<Box<Node>>,
+        right: Option<Box<Node>>,
+    }
+
+    impl Node {
+        /// The heap size in bytes of a node holding `row`, including the row's own allocation.
+        fn heap_size(row: &Row) -> usize {
+            std::mem::size_of::<Node>() - std::mem::size_of::<Row>() + row.byte_len()
+        }
+
+        fn update_total(&mut self) {
+            self.total = self.count + total(&self.left) + total(&self.right);
+        }
+    }
+
+    fn total(node: &Option<Box<Node>>) -> u64 {
+        node.as_ref().map_or(0, |node| node.total)
+    }
+
+    /// Splits `node` into the rows ordered before `row`, also including `row` itself if
+    /// `inclusive`, and all other rows.
+    fn split<C>(
+        node: Option<Box<Node>>,
+        row: &Row,
+        inclusive: bool,
+        cmp: &mut C,
+    ) -> (Option<Box<Node>>, Option<Box<Node>>)
+    where
+        C: FnMut(&Row, &Row) -> Ordering,
+    {
+        let Some(mut node) = node else {
+            return (None, None);
+        };
+        let goes_left = match cmp(&node.row, row) {
+            Ordering::Less => true,
+            Ordering::Equal => inclusive,
+            Ordering::Greater => false,
+        };
+        if goes_left {
+            let (left, right) = split(node.right.take(), row, inclusive, cmp);
+            node.right = left;
+            node.update_total();
+            (Some(node), right)
+        } else {
+            let (left, right) = split(node.left.take(), row, inclusive, cmp);
+            node.left = right;
+            node.update_total();
+            (left, Some(node))
+        }
+    }
+
+    /// Merges two trees, where all rows in `left` are ordered before all rows in `right`.
+    fn merge(left: Option<Box<Node>>, right: Option<Box<Node>>) -> Option<Box<Node>> {
+        match (left, right) {
+            (None, node) | (node, None) => node,
+            (Some(mut left), Some(mut right)) => {
+                if left.priority >= right.priority {
+                    left.right = merge(left.right.take(), Some(right));
+                    left.update_total();
+                    Some(left)
+                } else {
+                    right.left = merge(Some(left), right.left.take());
+                    right.update_total();
+                    Some(right)
+                }
+            }
+        }
+    }
+
+    /// Calls `f` with each row with ranks in `[lo, hi)` and the number of its copies
+    /// in that range. `offset` is the rank of the first row in `node`.
+    fn visit<F>(node: Option<&Node>, offset: u64, lo: u64, hi: u64, f: &mut F)
+    where
+        F: FnMut(&Row, u64),
+    {
+        let Some(node) = node else {
+            return;
+        };
+        if offset >= hi || offset.saturating_add(node.total) <= lo {
+            return;
+        }
+        visit(node.left.as_deref(), offset, lo, hi, f);
+        let start = offset + total(&node.left);
+        let end = start + node.count;
+        let copies = end.min(hi).saturating_sub(start.max(lo));
+        if copies > 0 {
+            f(&node.row, copies);
+        }
+        visit(node.right.as_deref(), end, lo, hi, f);
+    }
+
+    impl Tree {
+        /// The number of rows in the tree, counting multiplicities.
+        pub fn len(&self) -> u64 {
+            total(&self.root)
+        }
+
+        /// Returns `true` if the tree contains no rows.
+        pub fn is_empty(&self) -> bool {
+            self.root.is_none()
+        }
+
+        /// Returns the number of rows ordered before `row`, and the multiplicity of `row`.
+        pub fn rank<C>(&self, row: &Row, cmp: &mut C) -> (u64, u64)
+        where
+            C: FnMut(&Row, &Row) -> Ordering,
+        {
+            let mut rank = 0;
+            let mut node = self.root.as_deref();
+            while let Some(n) = node {
+                match cmp(&n.row, row) {
+                    Ordering::Less => {
+                        rank += total(&n.left) + n.count;
+                        node = n.right.as_deref();
+                    }
+                    Ordering::Equal => return (rank + total(&n.left), n.count),
+                    Ordering::Greater => node = n.left.as_deref(),
+                }
+            }
+            (rank, 0)
+        }
+
+        /// Returns the number of bytes the tree's nodes occupy on the heap, and the number of
+        /// nodes.
+        pub fn heap_size(&self) -> (usize, usize) {
+            (self.size, self.nodes)
+        }
+
+        /// Calls `f` with each row with ranks in `[lo, hi)`, in order, along with the number
+        /// of its copies in that range.
+        pub fn for_each_in_range<F>(&self, lo: u64, hi: u64, mut f: F)
+        where
+            F: FnMut(&Row, u64),
+        {
+            visit(self.root.as_deref(), 0, lo, hi, &mut f);
+        }
+
+        /// Adds `diff` copies of `row` to the tree, and calls `f` with the changes to the rows
+        /// with ranks in `[lo, hi)`.
+        ///
+        /// Returns `false`, leaving the tree unchanged, if this would make the multiplicity of
+        /// `row` negative.
+        pub fn update<C, F>(
+            &mut self,
+            row: &Row,
+            diff: i64,
+            lo: u64,
+            hi: u64,
+            cmp: &mut C,
+            mut f: F,
+        ) -> bool
+        where
+            C: FnMut(&Row, &Row) -> Ordering,
+            F: FnMut(&Row, i64),
+        {
+            let (rank, count) = self.rank(row, cmp);
+            let Some(new_count) = count.checked_add_signed(diff) else {
+                return false;
+            };
+            let copies = diff.unsigned_abs();
+            let signed = |copies: u64| i64::try_from(copies).expect("copies fit in an i64");
+            if diff > 0 {
+                // New copies are inserted at `rank`, and shift all rows at or after it.
+                self.for_each_in_range(lo.max(rank).max(hi.saturating_sub(copies)), hi, |r, n| {
+                    f(r, -signed(n))
+                });
+                self.for_each_in_range(
+                    rank.max(lo.saturating_sub(copies)),
+                    lo.min(hi.saturating_sub(copies)),
+                    |r, n| f(r, signed(n)),
+                );
+                let inserted = rank
+                    .saturating_add(copies)
+                    .min(hi)
+                    .saturating_sub(rank.max(lo));
+                if inserted > 0 {
+                    f(row, signed(inserted));
+                }
+            } else if diff < 0 {
+                // Copies are removed at `rank`, and shift all rows after them.
+                let removed = rank
+                    .saturating_add(copies)
+                    .min(hi)
+                    .saturating_sub(rank.max(lo));
+                if removed > 0 {
+                    f(row, -signed(removed));
+                }
+                let after = rank + copies;
+                self.for_each_in_range(lo.max(after), hi.min(lo.saturating_add(copies)), |r, n| {
+                    f(r, -signed(n))
+                });
+                self.for_each_in_range(
+                    hi.max(after).max(lo.saturating_add(copies)),
+                    hi.saturating_add(copies),
+                    |r, n| f(r, signed(n)),
+                );
+            } else {
+                return true;
+            }
+
+            let (left, rest) = split(self.root.take(), row, false, cmp);
+            let (middle, right) = split(rest, row, true, cmp);
+            let middle = match (middle, new_count) {
+                (Some(node), 0) => {
+                    self.size -= Node::heap_size(&node.row);
+                    self.nodes -= 1;
+                    None
+                }
+                (None, 0) => None,
+                (Some(mut node), count) => {
+                    node.count = count;
+                    node.update_total();
+                    Some(node)
+                }
+                (None, count) => {
+                    self.size += Node::heap_size(row);
+                    self.nodes += 1;
+                    Some(Box::new(Node {
+                        row: row.clone(),
+                        count,
+                        total: count,
+                        priority: row.hashed(),
+                        left: None,
+                        right: None,
+                    }))
+                }
+            };
+            self.root = merge(merge(left, middle), right);
+            true
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::collections::BTreeMap;
+
+        use mz_expr::ColumnOrder;
+        use mz_repr::{Datum, DatumVec, Row};
+
+        use super::{Layout, Tree};
+
+        #[mz_ore::test]
+        fn test_layout_order() {
+            // Order by the second column descending with nulls first, then by the first.
+            let order_key = vec![
+                ColumnOrder {
+                    column: 1,
+                    desc: true,
+                    nulls_last: false,
+                },
+                ColumnOrder {
+                    column: 0,
+                    desc: false,
+                    nulls_last: true,
+                },
+            ];
+            let layout = Layout::new(&order_key, 3);
+            let rows = [
+                [Datum::Int32(1), Datum::Null, Datum::Int32(0)],
+                [Datum::Int32(1), Datum::Int32(5), Datum::Int32(0)],
+                [Datum::Int32(2), Datum::Int32(5), Datum::Int32(0)],
+                [Datum::Null, Datum::Int32(5), Datum::Int32(0)],
+                [Datum::Null, Datum::Int32(5), Datum::Int32(1)],
+                [Datum::Int32(0), Datum::Int32(-3), Datum::Int32(0)],
+            ];
+            let mut ordered = rows
+                .iter()
+                .rev()
+                .map(|row| layout.pack(row))
+                .collect::<Vec<_>>();
+            ordered.sort_by(|l, r| layout.compare(l, r));
+
+            let mut datum_vec = DatumVec::new();
+            let unpacked = ordered
+                .iter()
+                .map(|row| layout.unpack(row, &mut datum_vec))
+                .collect::<Vec<_>>();
+            let expected = rows
+                .iter()
+                .map(|row| Row::pack_slice(row))
+                .collect::<Vec<_>>();
+            assert_eq!(unpacked, expected);
+        }
+
+        /// Checks the window changes and lookups of the tree against recomputing them.
+        #[mz_ore::test]
+        fn test_window_changes() {
+            let mut cmp = |l: &Row, r: &Row| l.cmp(r);
+            let updates: [(i64, i64); 12] = [
+                (5, 1),
+                (3, 2),
+                (8, 1),
+                (1, 1),
+                (3, -1),
+                (6, 3),
+                (5, -1),
+                (2, 2),
+                (6, -2),
+                (9, 1),
+                (1, -1),
+                (3, -1),
+            ];
+            for (lo, hi) in [(0, 1), (0, 3), (1, 3), (2, u64::MAX), (4, 5)] {
+                let mut tree = Tree::default();
+                let mut output = BTreeMap::<i64, i64>::new();
+                let mut expected = BTreeMap::<i64, i64>::new();
+                for (value, diff) in updates {
+                    let row = Row::pack_slice(&[Datum::Int64(value)]);
+                    assert!(tree.update(&row, diff, lo, hi, &mut cmp, |row, diff| {
+                        *output.entry(row.unpack_first().unwrap_int64()).or_default() += diff;
+                    }));
+                    *expected.entry(value).or_default() += diff;
+                    expected.retain(|_, diff| *diff != 0);
+
+                    let mut window = BTreeMap::new();
+                    let mut sorted = Vec::new();
+                    for (value, count) in &expected {
+                        let count = u64::try_from(*count).unwrap();
+                        let rank = u64::try_from(sorted.len()).unwrap();
+                        let copies = (rank + count).min(hi).saturating_sub(rank.max(lo));
+                        if copies > 0 {
+                            window.insert(*value, i64::try_from(copies).unwrap());
+                        }
+                        let row = Row::pack_slice(&[Datum::Int64(*value)]);
+                        assert_eq!(tree.rank(&row, &mut cmp), (rank, count));
+                        sorted.extend(std::iter::repeat_n(*value, usize::try_from(count).unwrap()));
+                    }
+                    output.retain(|_, diff| *diff != 0);
+                    assert_eq!(output, window, "window [{lo}, {hi})");
+                    assert_eq!(tree.len(), u64::try_from(sorted.len()).unwrap());
+                    let mut all = Vec::new();
+                    tree.for_each_in_range(0, u64::MAX, |row, n| {
+                        let value = row.unpack_first().unwrap_int64();
+                        all.extend(std::iter::repeat_n(value, usize::try_from(n).unwrap()));
+                    });
+                    assert_eq!(all, sorted);
+                    assert_eq!(tree.heap_size().1, expected.len());
+                }
+                // Retracting all rows frees all nodes.
+                for (value, diff) in expected {
+                    let row = Row::pack_slice(&[Datum::Int64(value)]);
+                    assert!(tree.update(&row, -diff, lo, hi, &mut cmp, |_, _| {}));
+                }
+                assert!(tree.is_empty());
+                assert_eq!(tree.heap_size(), (0, 0));
+            }
+
+            let mut tree = Tree::default();
+            let row = Row::pack_slice(&[Datum::Int64(1)]);
+            assert!(!tree.update(&row, -1, 0, 1, &mut cmp, |_, _| panic!()));
+            assert!(tree.is_empty());
+        }
+    }
+}
@@ -124,6 +124,12 @@ mod container {
         pub fn heap_size(&self, callback: impl FnMut(usize, usize)) {
             self.bytes.heap_size(callback)
         }
+
+        /// Visit contained batches that were spilled to disk to determine their size.
+        #[inline]
+        pub fn spilled_size(&self, callback: impl FnMut(usize)) {
+            self.bytes.spilled_size(callback)
+        }
     }
 
     impl BatchContainer for DatumContainer {
@@ -342,13 +348,13 @@ mod bytes_container {
     use differential_dataflow::trace::implementations::BatchContainer;
     use timely::container::PushInto;
 
-    use mz_ore::region::Region;
+    use crate::spill::SpillableBytes;
 
     /// A slice container with four bytes overhead per slice.
     pub struct BytesContainer {
         /// Total length of `batches`, maintained because recomputation is expensive.
         length: usize,
-        batches: Vec<BytesBatch>,
+        batches: Vec<SpillableBytes>,
     }
 
     impl BytesContainer {
@@ -357,12 +363,22 @@ mod bytes_container {
         pub fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
             // Calculate heap size for local, stash, and stash entries
             callback(
-                self.batches.len() * std::mem::size_of::<BytesBatch>(),
-                self.batches.capacity() * std::mem::size_of::<BytesBatch>(),
+                self.batches.len() * std::mem::size_of::<SpillableBytes>(),
+                self.batches.capacity() * std::mem::size_of::<SpillableBytes>(),
             );
             for batch in self.batches.iter() {
-                batch.offsets.heap_size(&mut callback);
-                callback(batch.storage.len(), batch.storage.capacity());
+                batch.heap_size(&mut callback);
+            }
+        }
+
+        /// Visit contained batches that were spilled to disk to determine their size.
+        #[inline]
+        pub fn spilled_size(&self, mut callback: impl FnMut(usize)) {
+            for batch in self.batches.iter() {
+                let spilled = batch.spilled_size();
+                if spilled > 0 {
+                    callback(spilled);
+                }
             }
         }
     }
@@ -374,7 +390,7 @@ mod bytes_container {
         fn with_capacity(size: usize) -> Self {
             Self {
                 length: 0,
-                batches: vec![BytesBatch::with_capacities(size, size)],
+                batches: vec![with_capacities(size, size)],
             }
         }
 
@@ -382,16 +398,16 @@ mod bytes_container {
             let mut item_cap = 1;
             let mut byte_cap = 0;
             for batch in cont1.batches.iter() {
-                item_cap += batch.offsets.len() - 1;
-                byte_cap += batch.storage.len();
+                item_cap += batch.len();
+                byte_cap += batch.byte_len();
             }
             for batch in cont2.batches.iter() {
-                item_cap += batch.offsets.len() - 1;
-                byte_cap += batch.storage.len();
+                item_cap += batch.len();
+                byte_cap += batch.byte_len();
             }
             Self {
                 length: 0,
-                batches: vec![BytesBatch::with_capacities(item_cap, byte_cap)],
+                batches: vec![with_capacities(item_cap, byte_cap)],
             }
         }
 
@@ -422,9 +438,9 @@ mod bytes_container {
                 let success = batch.try_push(item);
                 if !success {
                     // double the lengths from `batch`.
-                    let item_cap = 2 * batch.offsets.len();
-                    let byte_cap = std::cmp::max(2 * batch.storage.capacity(), item.len());
-                    let mut new_batch = BytesBatch::with_capacities(item_cap, byte_cap);
+                    let item_cap = 2 * (batch.len() + 1);
+                    let byte_cap = std::cmp::max(2 * batch.capacity(), item.len());
+                    let mut new_batch = with_capacities(item_cap, byte_cap);
                     assert!(new_batch.try_push(item));
                     self.batches.push(new_batch);
                 }
@@ -432,49 +448,9 @@ mod bytes_container {
         }
     }
 
-    /// A batch of slice storage.
-    ///
-    /// The backing storage for this batch will not be resized.
-    pub struct BytesBatch {
-        offsets: crate::row_spine::OffsetOptimized,
-        storage: Region<u8>,
-        len: usize,
-    }
-
-    impl BytesBatch {
-        /// Either accepts the slice and returns true,
-        /// or does not and returns false.
-        fn try_push(&mut self, slice: &[u8]) -> bool {
-            if self.storage.len() + slice.len() <= self.storage.capacity() {
-                self.storage.extend_from_slice(slice);
-                self.offsets.push(self.storage.len());
-                self.len += 1;
-                true
-            } else {
-                false
-            }
-        }
-        fn index(&self, index: usize) -> &[u8] {
-            let lower = self.offsets.index(index);
-            let upper = self.offsets.index(index + 1);
-            &self.storage[lower..upper]
-        }
-        #[inline(always)]
-        fn len(&self) -> usize {
-            debug_assert_eq!(self.len, self.offsets.len() - 1);
-            self.len
-        }
-
-        fn with_capacities(item_cap: usize, byte_cap: usize) -> Self {
-            // TODO: be wary of `byte_cap` greater than 2^32.
-            let mut offsets = crate::row_spine::OffsetOptimized::with_capacity(item_cap + 1);
-            offsets.push(0);
-            Self {
-                offsets,
-                storage: Region::new_auto(byte_cap.next_power_of_two()),
-                len: 0,
-            }
-        }
+    /// Storage for a batch of slices, which will not be resized.
+    fn with_capacities(item_cap: usize, byte_cap: usize) -> SpillableBytes {
+        SpillableBytes::with_capacities(item_cap, byte_cap.next_power_of_two())
     }
 }
 
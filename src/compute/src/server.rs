@@ -355,9 +355,13 @@ impl<'w, A: Allocate + 'static> Worker<'w, A> {
                 // Report frontier information back the coordinator.
                 if let Some(mut compute_state) = self.activate_compute() {
                     compute_state.compute_state.traces.maintenance();
+                    // SAFETY: We are between steps of the worker, so no operator holds
+                    // references to the items of arrangement batches.
+                    unsafe { crate::spill::maintain() };
                     // Report operator hydration before frontiers, as reporting frontiers may
                     // affect hydration reporting.
                     compute_state.report_operator_hydration();
+                    compute_state.report_arrangement_sizes();
                     compute_state.report_frontiers();
                     compute_state.report_dropped_collections();
                     compute_state.report_metrics();
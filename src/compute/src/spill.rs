@@ -0,0 +1,676 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Tiered storage for arrangement batches.
+//!
+//! The bytes of the items of `Row`-formatted arrangement batches start out in memory, on the heap
+//! or in lgalloc. Once a large batch is cold, that is, once a maintenance pass finds that it was
+//! not written to since the previous pass, [`maintain`] writes its items to a file in the
+//! replica's scratch directory and frees their memory. In a spine, the large batches that wait to
+//! be merged, which are its oldest, are the ones that spill.
+//!
+//! Spilled items are stored in a columnar layout. They are split into blocks of about
+//! [`BLOCK_BYTES`], each holding a column of the end offsets of its items followed by a column of
+//! their bytes, and only the first item of each block stays in memory, in place of the offsets of
+//! all items. Items pushed to a batch after it spilled stay in memory until the batch is cold
+//! again, and are then appended to its file as further blocks.
+//!
+//! Spilled items are read back through a memory mapping of their file and a per-worker block
+//! cache of bounded size. Each maintenance pass releases the least recently used blocks from their
+//! mappings until the resident blocks fit in the cache, and the operating system pages released
+//! blocks back in from the file on their next access. Between passes, the kernel can reclaim the
+//! clean pages of spilled blocks under memory pressure. A batch only records a read when it reads
+//! in a different block than its previous read, so that scans and lookups within a block are free
+//! of cache bookkeeping. Each pass forgets the last block of each batch, so that a block that is
+//! read in a pass counts as used in that pass.
+//!
+//! Only the key and value bytes of `Row` spines spill. Times, diffs, and columnar containers stay
+//! in memory.
+//!
+//! Spilling is enabled per cluster, with the `ARRANGEMENT SPILL` cluster option, and only on
+//! replicas with a scratch directory, that is, replicas of clusters with disk. Running replicas
+//! apply changes to the option and to the spill dyncfgs, see [`set_config`].
+
+use std::cell::{Cell, RefCell, UnsafeCell};
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::os::unix::fs::FileExt;
+use std::path::{Path, PathBuf};
+use std::rc::{Rc, Weak};
+
+use differential_dataflow::trace::implementations::BatchContainer;
+use memmap2::{MmapRaw, UncheckedAdvice};
+use mz_ore::cast::CastFrom;
+use mz_ore::region::Region;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::row_spine::OffsetOptimized;
+
+/// The target size of the blocks of spilled items, in bytes. Blocks are larger if they hold a
+/// single larger item.
+const BLOCK_BYTES: usize = 1 << 18;
+
+/// The size of an entry in the offsets column of a block.
+const OFFSET_BYTES: usize = std::mem::size_of::<u32>();
+
+/// Configuration for spilling arrangement batches to disk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpillConfig {
+    /// Directory in which to create spill files.
+    pub path: PathBuf,
+    /// Cold batches with at least this many bytes are spilled.
+    pub threshold_bytes: usize,
+    /// The number of bytes of spilled blocks to keep resident per worker.
+    pub cache_bytes: usize,
+}
+
+thread_local! {
+    static CONFIG: RefCell<Option<SpillConfig>> = const { RefCell::new(None) };
+    static CACHE: RefCell<BlockCache> = RefCell::new(BlockCache::default());
+    /// Blocks read since the last maintenance pass, see [`SpillableBytes::index`].
+    static READS: RefCell<Vec<(Weak<Batch>, usize)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Set the spill configuration for the current worker, or disable spilling if `None`.
+///
+/// Disabling spilling leaves spilled batches in their files, and releases all of their blocks on
+/// the next maintenance pass.
+pub fn set_config(config: Option<SpillConfig>) {
+    let capacity = config.as_ref().map_or(0, |config| config.cache_bytes);
+    CACHE.with_borrow_mut(|cache| cache.capacity = capacity);
+    CONFIG.with_borrow_mut(|current| *current = config);
+}
+
+/// Spill the cold batches of the current worker, and release the least recently used blocks of
+/// spilled batches until the resident blocks fit in the configured cache.
+///
+/// # Safety
+///
+/// Spilling a batch frees the memory of its items, and appending to the file of a spilled batch
+/// maps it anew. The caller must ensure that no references to the items of the batches of the
+/// current worker are live. This holds between steps of the worker, as operators cannot retain
+/// such references across steps.
+pub unsafe fn maintain() {
+    let config = CONFIG.with_borrow(|config| config.clone());
+    let reads = READS.take();
+    // SAFETY: Forwarded from the caller.
+    let result = CACHE.with_borrow_mut(|cache| unsafe { cache.maintain(config.as_ref(), reads) });
+    if let Err(err) = result {
+        // Disable spilling on this worker until its spill configuration changes, to avoid
+        // repeatedly failing on a full or missing scratch directory.
+        warn!(%err, "failed to spill batch, disabling spilling");
+        set_config(None);
+    }
+}
+
+/// The bytes of the items of an arrangement batch, which spill to disk once the batch is large
+/// and cold.
+///
+/// The storage is append-only, and its capacity is never resized, matching the expectations of
+/// `BytesContainer`.
+pub struct SpillableBytes {
+    batch: Rc<Batch>,
+}
+
+/// The shared state of a [`SpillableBytes`], which the [`BlockCache`] refers to.
+struct Batch {
+    /// The items of the batch. Only modified with exclusive access to the [`SpillableBytes`], or
+    /// in [`maintain`].
+    items: UnsafeCell<Items>,
+    /// Whether items were pushed since the last maintenance pass.
+    written: Cell<bool>,
+    /// The spilled block that the batch was last read in, or `usize::MAX`.
+    last_block: Cell<usize>,
+}
+
+struct Items {
+    /// The number of bytes the items can hold.
+    capacity: usize,
+    /// The spilled items, which precede all items in memory.
+    spilled: Option<SpilledItems>,
+    /// The items in memory, if any.
+    memory: Option<MemoryItems>,
+}
+
+impl SpillableBytes {
+    /// Create storage for at least `item_cap` items with `byte_cap` bytes.
+    pub fn with_capacities(item_cap: usize, byte_cap: usize) -> Self {
+        let batch = Rc::new(Batch {
+            items: UnsafeCell::new(Items {
+                capacity: byte_cap,
+                spilled: None,
+                memory: Some(MemoryItems::with_capacities(item_cap, byte_cap)),
+            }),
+            written: Cell::new(false),
+            last_block: Cell::new(usize::MAX),
+        });
+        // Smaller batches never reach the threshold, so the cache need not know about them.
+        let candidate = CONFIG.with_borrow(|config| {
+            config
+                .as_ref()
+                .is_some_and(|config| byte_cap >= config.threshold_bytes)
+        });
+        if candidate {
+            CACHE.with_borrow_mut(|cache| cache.candidates.push(Rc::downgrade(&batch)));
+        }
+        Self { batch }
+    }
+
+    fn items(&self) -> &Items {
+        // SAFETY: The items are only modified with exclusive access to `self`, or in `maintain`,
+        // whose caller ensures that no references derived from this one are live.
+        unsafe { &*self.batch.items.get() }
+    }
+
+    /// Either accepts the slice and returns true, or does not and returns false.
+    pub fn try_push(&mut self, slice: &[u8]) -> bool {
+        // SAFETY: Exclusive access to `self` excludes references to the items, and `maintain` is
+        // not called concurrently.
+        let items = unsafe { &mut *self.batch.items.get() };
+        let spilled_bytes = items.spilled.as_ref().map_or(0, |spilled| spilled.bytes);
+        let remaining = items.capacity - spilled_bytes;
+        let memory = match &mut items.memory {
+            Some(memory) => memory,
+            None if slice.len() <= remaining => items
+                .memory
+                .insert(MemoryItems::with_capacities(0, remaining)),
+            None => return false,
+        };
+        let success = memory.try_push(slice);
+        if success {
+            self.batch.written.set(true);
+        }
+        success
+    }
+
+    /// Returns the item at `index`.
+    pub fn index(&self, mut index: usize) -> &[u8] {
+        let items = self.items();
+        if let Some(spilled) = &items.spilled {
+            if index < spilled.len {
+                let mut block = self.batch.last_block.get();
+                if !spilled
+                    .blocks
+                    .get(block)
+                    .is_some_and(|block| block.contains(index))
+                {
+                    block = spilled
+                        .blocks
+                        .partition_point(|block| block.first_item <= index)
+                        - 1;
+                    self.batch.last_block.set(block);
+                    READS.with_borrow_mut(|reads| reads.push((Rc::downgrade(&self.batch), block)));
+                }
+                return spilled.index(block, index);
+            }
+            index -= spilled.len;
+        }
+        items
+            .memory
+            .as_ref()
+            .expect("items after the spilled ones are in memory")
+            .index(index)
+    }
+
+    /// The number of items.
+    pub fn len(&self) -> usize {
+        let items = self.items();
+        items.spilled.as_ref().map_or(0, |spilled| spilled.len)
+            + items.memory.as_ref().map_or(0, |memory| memory.len())
+    }
+
+    /// The number of bytes of the items.
+    pub fn byte_len(&self) -> usize {
+        let items = self.items();
+        items.spilled.as_ref().map_or(0, |spilled| spilled.bytes)
+            + items.memory.as_ref().map_or(0, |memory| memory.bytes.len())
+    }
+
+    /// The number of bytes the items can hold.
+    pub fn capacity(&self) -> usize {
+        self.items().capacity
+    }
+
+    /// Visit the allocations in memory to determine their size and capacity.
+    pub fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
+        let items = self.items();
+        if let Some(spilled) = &items.spilled {
+            let size_of_block = std::mem::size_of::<Block>();
+            callback(
+                spilled.blocks.len() * size_of_block,
+                spilled.blocks.capacity() * size_of_block,
+            );
+        }
+        if let Some(memory) = &items.memory {
+            memory.offsets.heap_size(&mut callback);
+            callback(memory.bytes.len(), memory.bytes.capacity());
+        }
+    }
+
+    /// The number of bytes of spilled blocks.
+    pub fn spilled_size(&self) -> usize {
+        self.items()
+            .spilled
+            .as_ref()
+            .map_or(0, |spilled| spilled.mmap.len())
+    }
+
+    /// Whether some items are spilled.
+    #[cfg(test)]
+    fn is_spilled(&self) -> bool {
+        self.items().spilled.is_some()
+    }
+}
+
+/// Items in memory.
+struct MemoryItems {
+    offsets: OffsetOptimized,
+    bytes: Region<u8>,
+}
+
+impl MemoryItems {
+    fn with_capacities(item_cap: usize, byte_cap: usize) -> Self {
+        // TODO: be wary of `byte_cap` greater than 2^32.
+        let mut offsets = OffsetOptimized::with_capacity(item_cap + 1);
+        offsets.push(0);
+        Self {
+            offsets,
+            bytes: Region::new_auto(byte_cap),
+        }
+    }
+
+    fn try_push(&mut self, slice: &[u8]) -> bool {
+        if self.bytes.len() + slice.len() <= self.bytes.capacity() {
+            self.bytes.extend_from_slice(slice);
+            self.offsets.push(self.bytes.len());
+            true
+        } else {
+            false
+        }
+    }
+
+    fn index(&self, index: usize) -> &[u8] {
+        let lower = self.offsets.index(index);
+        let upper = self.offsets.index(index + 1);
+        &self.bytes[lower..upper]
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+}
+
+/// Items in an unlinked spill file, which is mapped into memory.
+struct SpilledItems {
+    /// Identifies the file in the [`BlockCache`].
+    id: Uuid,
+    file: File,
+    mmap: MmapRaw,
+    /// The blocks of the file, in the order of their items.
+    blocks: Vec<Block>,
+    /// The number of items.
+    len: usize,
+    /// The number of bytes of the items.
+    bytes: usize,
+}
+
+/// A block of spilled items: a column of their end offsets, relative to the end of the column,
+/// followed by a column of their bytes.
+struct Block {
+    /// The index of the first item of the block.
+    first_item: usize,
+    /// The number of items in the block.
+    items: usize,
+    /// The position of the block in the file.
+    offset: usize,
+    /// The size of the block in bytes.
+    size: usize,
+}
+
+impl Block {
+    fn contains(&self, index: usize) -> bool {
+        self.first_item <= index && index < self.first_item + self.items
+    }
+}
+
+impl SpilledItems {
+    /// Write `memory` to a new file in `path` and map it into memory.
+    ///
+    /// The file is unlinked immediately, so its space is reclaimed when it is closed, including
+    /// when the process terminates.
+    fn create(path: &Path, memory: &MemoryItems) -> std::io::Result<Self> {
+        let id = Uuid::new_v4();
+        let path = path.join(format!("spill-{id}"));
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        std::fs::remove_file(&path)?;
+        let blocks = write_blocks(&file, 0, 0, memory)?;
+        let mmap = MmapRaw::map_raw(&file)?;
+        Ok(Self {
+            id,
+            file,
+            mmap,
+            blocks,
+            len: memory.len(),
+            bytes: memory.bytes.len(),
+        })
+    }
+
+    /// Append `memory` to the file as further blocks, and map the file anew.
+    fn append(&mut self, memory: &MemoryItems) -> std::io::Result<()> {
+        let offset = self
+            .blocks
+            .last()
+            .map_or(0, |block| block.offset + block.size);
+        let blocks = write_blocks(&self.file, offset, self.len, memory)?;
+        self.mmap = MmapRaw::map_raw(&self.file)?;
+        self.blocks.extend(blocks);
+        self.len += memory.len();
+        self.bytes += memory.bytes.len();
+        Ok(())
+    }
+
+    /// Returns the item at `index`, which is in `block`.
+    fn index(&self, block: usize, index: usize) -> &[u8] {
+        let block = &self.blocks[block];
+        // SAFETY: The mapping is only replaced in `maintain`, whose caller ensures that no
+        // references into it are live, and released blocks are paged back in from the file.
+        let bytes = unsafe { std::slice::from_raw_parts(self.mmap.as_ptr(), self.mmap.len()) };
+        let offsets = &bytes[block.offset..];
+        let end = |item: usize| {
+            let position = item * OFFSET_BYTES;
+            let end = offsets[position..position + OFFSET_BYTES]
+                .try_into()
+                .expect("offsets are four bytes");
+            usize::cast_from(u32::from_le_bytes(end))
+        };
+        let item = index - block.first_item;
+        let lower = if item == 0 { 0 } else { end(item - 1) };
+        let upper = end(item);
+        let data = block.offset + block.items * OFFSET_BYTES;
+        &bytes[data + lower..data + upper]
+    }
+
+    /// Release the memory backing `block`, without discarding its contents.
+    fn release(&self, block: usize) {
+        let block = &self.blocks[block];
+        // SAFETY: The mapping is a shared file mapping, for which `MADV_DONTNEED` drops the pages
+        // from this process without discarding their contents. Later accesses fault the pages
+        // back in from the file.
+        let result = unsafe {
+            self.mmap
+                .unchecked_advise_range(UncheckedAdvice::DontNeed, block.offset, block.size)
+        };
+        if let Err(err) = result {
+            warn!(%err, "failed to release spilled block");
+        }
+    }
+}
+
+/// Write the items in `memory` to `file` as blocks, starting at `offset`, and return the blocks.
+///
+/// `first_item` is the index of the first item in `memory` among all spilled items.
+fn write_blocks(
+    file: &File,
+    mut offset: usize,
+    first_item: usize,
+    memory: &MemoryItems,
+) -> std::io::Result<Vec<Block>> {
+    let mut blocks = Vec::new();
+    let mut buffer = Vec::new();
+    let mut first = 0;
+    while first < memory.len() {
+        // Take items until the block is full, but at least one.
+        let start = memory.offsets.index(first);
+        let mut last = first + 1;
+        while last < memory.len() && memory.offsets.index(last + 1) - start <= BLOCK_BYTES {
+            last += 1;
+        }
+        buffer.clear();
+        for item in first..last {
+            let end = memory.offsets.index(item + 1) - start;
+            let end = u32::try_from(end).expect("spilled blocks fit in u32 offsets");
+            buffer.extend_from_slice(&end.to_le_bytes());
+        }
+        buffer.extend_from_slice(&memory.bytes[start..memory.offsets.index(last)]);
+        file.write_all_at(&buffer, u64::cast_from(offset))?;
+        blocks.push(Block {
+            first_item: first_item + first,
+            items: last - first,
+            offset,
+            size: buffer.len(),
+        });
+        offset += buffer.len();
+        first = last;
+    }
+    Ok(blocks)
+}
+
+/// The batches of a worker that may spill, and a least-recently-used set of the resident blocks
+/// of its spilled batches.
+#[derive(Default)]
+struct BlockCache {
+    /// The maximum number of bytes of resident blocks.
+    capacity: usize,
+    /// A logical clock, advanced on every maintenance pass.
+    tick: u64,
+    /// Batches that are large enough to spill.
+    candidates: Vec<Weak<Batch>>,
+    /// Resident blocks, keyed by the identifier of their file and their index.
+    resident: BTreeMap<(Uuid, usize), ResidentBlock>,
+}
+
+/// The cache's view of a resident block.
+struct ResidentBlock {
+    batch: Weak<Batch>,
+    /// The maintenance pass during which the block was last read.
+    last_used: u64,
+    /// The size of the block in bytes.
+    size: usize,
+}
+
+impl BlockCache {
+    /// Spill cold candidates, record `reads` of blocks, and release the least recently used
+    /// blocks until the resident blocks fit in `capacity`.
+    ///
+    /// # Safety
+    ///
+    /// See [`maintain`].
+    unsafe fn maintain(
+        &mut self,
+        config: Option<&SpillConfig>,
+        reads: Vec<(Weak<Batch>, usize)>,
+    ) -> std::io::Result<()> {
+        self.tick += 1;
+        let tick = self.tick;
+
+        let mut result = Ok(());
+        self.candidates.retain(|batch| {
+            let Some(batch) = batch.upgrade() else {
+                return false;
+            };
+            if batch.written.replace(false) || result.is_err() {
+                return true;
+            }
+            let Some(config) = config else {
+                return true;
+            };
+            // SAFETY: The caller ensures that no references to the items are live.
+            let items = unsafe { &mut *batch.items.get() };
+            let Some(memory) = &items.memory else {
+                return true;
+            };
+            let spilled_bytes = items.spilled.as_ref().map_or(0, |spilled| spilled.bytes);
+            if memory.len() == 0 || spilled_bytes + memory.bytes.len() < config.threshold_bytes {
+                return true;
+            }
+            let spilled = match &mut items.spilled {
+                Some(spilled) => spilled.append(memory).map(|()| {
+                    // The file was mapped anew, without any resident blocks.
+                    let id = spilled.id;
+                    self.resident.retain(|(file, _), _| *file != id);
+                }),
+                None => SpilledItems::create(&config.path, memory)
+                    .map(|spilled| items.spilled = Some(spilled)),
+            };
+            match spilled {
+                Ok(()) => {
+                    items.memory = None;
+                    batch.last_block.set(usize::MAX);
+                }
+                Err(err) => result = Err(err),
+            }
+            true
+        });
+
+        for (batch, block) in reads {
+            let Some(strong) = batch.upgrade() else {
+                continue;
+            };
+            // SAFETY: The caller ensures that no mutable references to the items are live.
+            let items = unsafe { &*strong.items.get() };
+            let Some(spilled) = &items.spilled else {
+                continue;
+            };
+            let size = spilled.blocks[block].size;
+            let resident = ResidentBlock {
+                batch,
+                last_used: tick,
+                size,
+            };
+            self.resident.insert((spilled.id, block), resident);
+        }
+
+        // Blocks of dropped batches no longer hold memory. Forget the last block of each batch,
+        // so that its next read records a use of its block in the next pass.
+        self.resident
+            .retain(|_, resident| match resident.batch.upgrade() {
+                Some(batch) => {
+                    batch.last_block.set(usize::MAX);
+                    true
+                }
+                None => false,
+            });
+
+        let mut resident_bytes: usize = self.resident.values().map(|resident| resident.size).sum();
+        if resident_bytes > self.capacity {
+            let mut by_use: Vec<_> = self
+                .resident
+                .iter()
+                .map(|(key, resident)| (resident.last_used, *key))
+                .collect();
+            by_use.sort();
+            for (_, key) in by_use {
+                if resident_bytes <= self.capacity {
+                    break;
+                }
+                let resident = self.resident.remove(&key).expect("block is resident");
+                if let Some(batch) = resident.batch.upgrade() {
+                    // SAFETY: The caller ensures that no mutable references to the items are
+                    // live.
+                    let items = unsafe { &*batch.items.get() };
+                    if let Some(spilled) = &items.spilled {
+                        spilled.release(key.1);
+                    }
+                }
+                resident_bytes -= resident.size;
+            }
+        }
+        result
+    }
+
+    /// The number of bytes of resident blocks.
+    #[cfg(test)]
+    fn resident_bytes(&self) -> usize {
+        self.resident.values().map(|resident| resident.size).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(threshold_bytes: usize, cache_bytes: usize) -> SpillConfig {
+        SpillConfig {
+            path: std::env::temp_dir(),
+            threshold_bytes,
+            cache_bytes,
+        }
+    }
+
+    /// Items of varying lengths, some of them empty.
+    fn item(index: usize) -> Vec<u8> {
+        (0..index % 301)
+            .map(|byte| u8::try_from((byte + index) % 256).unwrap())
+            .collect()
+    }
+
+    /// Run a maintenance pass.
+    fn maintain_now() {
+        // SAFETY: The tests hold no references to items across maintenance passes.
+        unsafe { maintain() }
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `mmap`
+    fn test_spill_cold_batches() {
+        let capacity = 4 * BLOCK_BYTES;
+        set_config(Some(config(1024, 2 * BLOCK_BYTES)));
+        let mut small = SpillableBytes::with_capacities(0, 512);
+        let mut batch = SpillableBytes::with_capacities(0, capacity);
+        for index in 0..3 {
+            assert!(small.try_push(&item(index)));
+        }
+        let mut len = 0;
+        while batch.byte_len() < capacity / 2 {
+            assert!(batch.try_push(&item(len)));
+            len += 1;
+        }
+
+        // The first pass finds the batch written to, and the second spills it.
+        maintain_now();
+        assert!(!batch.is_spilled());
+        maintain_now();
+        assert!(batch.is_spilled());
+        assert!(!small.is_spilled());
+
+        // Items pushed after spilling stay in memory until the batch is cold again.
+        while batch.try_push(&item(len)) {
+            len += 1;
+        }
+        assert_eq!(batch.len(), len);
+        maintain_now();
+        maintain_now();
+        assert_eq!(batch.len(), len);
+        assert_eq!(batch.byte_len(), batch.spilled_size() - len * OFFSET_BYTES);
+
+        for _ in 0..2 {
+            for index in 0..len {
+                assert_eq!(batch.index(index), &item(index)[..]);
+            }
+            maintain_now();
+            assert!(
+                CACHE.with_borrow(|cache| cache.resident_bytes()) <= 2 * BLOCK_BYTES,
+                "cache must not exceed its capacity"
+            );
+        }
+
+        drop(batch);
+        maintain_now();
+        CACHE.with_borrow(|cache| {
+            assert_eq!(cache.candidates.len(), 0);
+            assert_eq!(cache.resident.len(), 0);
+        });
+        set_config(None);
+    }
+}
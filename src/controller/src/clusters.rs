@@ -53,6 +53,8 @@ pub struct ClusterConfig {
     /// An optional arbitrary string that describes the class of the workload
     /// this cluster is running (e.g., `production` or `staging`).
     pub workload_class: Option<String>,
+    /// Whether the cluster's replicas spill large arrangement batches to disk.
+    pub arrangement_spill: bool,
 }
 
 /// The status of a cluster.
@@ -354,8 +356,12 @@ where
     ) -> Result<(), anyhow::Error> {
         self.storage
             .create_instance(id, config.workload_class.clone());
-        self.compute
-            .create_instance(id, config.arranged_logs, config.workload_class)?;
+        self.compute.create_instance(
+            id,
+            config.arranged_logs,
+            config.workload_class,
+            config.arrangement_spill,
+        )?;
         Ok(())
     }
 
@@ -372,6 +378,17 @@ where
         Ok(())
     }
 
+    /// Updates whether the replicas of a cluster spill large arrangement batches to disk.
+    pub fn update_cluster_arrangement_spill(
+        &mut self,
+        id: ClusterId,
+        arrangement_spill: bool,
+    ) -> Result<(), anyhow::Error> {
+        self.compute
+            .update_instance_arrangement_spill(id, arrangement_spill)?;
+        Ok(())
+    }
+
     /// Drops the specified cluster.
     ///
     /// # Panics
@@ -628,6 +645,7 @@ where
         let aws_connection_role_arn = self.connection_context().aws_connection_role_arn.clone();
         let persist_pubsub_url = self.persist_pubsub_url.clone();
         let secrets_args = self.secrets_args.to_flags();
+        let persist_blob_encryption_args = self.persist_blob_encryption_args.to_flags();
         let service = self.orchestrator.ensure_service(
             &service_name,
             ServiceConfig {
@@ -675,6 +693,7 @@ where
                     }
 
                     args.extend(secrets_args.clone());
+                    args.extend(persist_blob_encryption_args.clone());
 
                     args
                 }),
@@ -47,6 +47,7 @@ use mz_ore::task::AbortOnDropHandle;
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_persist_client::PersistLocation;
 use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::cfg::BlobEncryptionCliArgs;
 use mz_persist_types::Codec64;
 use mz_proto::RustType;
 use mz_repr::{Datum, GlobalId, Row, TimestampManipulation};
@@ -101,6 +102,9 @@ pub struct ControllerConfig {
     pub metrics_registry: MetricsRegistry,
     /// The URL for Persist PubSub.
     pub persist_pubsub_url: String,
+    /// Arguments for client-side encryption of persist blobs, to thread
+    /// through to clusterd.
+    pub persist_blob_encryption_args: BlobEncryptionCliArgs,
     /// Arguments for secrets readers.
     pub secrets_args: SecretsReaderCliArgs,
     /// The connection context, to thread through to clusterd, with cli flags.
@@ -178,6 +182,9 @@ pub struct Controller<T: ComputeControllerTimestamp = mz_repr::Timestamp> {
     /// The URL for Persist PubSub.
     persist_pubsub_url: String,
 
+    /// Arguments for client-side encryption of persist blobs.
+    persist_blob_encryption_args: BlobEncryptionCliArgs,
+
     /// Arguments for secrets readers.
     secrets_args: SecretsReaderCliArgs,
 
@@ -250,6 +257,7 @@ impl<T: ComputeControllerTimestamp> Controller<T> {
             metrics_rx: _,
             now: _,
             persist_pubsub_url: _,
+            persist_blob_encryption_args: _,
             secrets_args: _,
             unfulfilled_watch_sets_by_object: _,
             unfulfilled_watch_sets,
@@ -713,6 +721,7 @@ where
             metrics_rx,
             now: config.now,
             persist_pubsub_url: config.persist_pubsub_url,
+            persist_blob_encryption_args: config.persist_blob_encryption_args,
             secrets_args: config.secrets_args,
             unfulfilled_watch_sets_by_object: BTreeMap::new(),
             unfulfilled_watch_sets: BTreeMap::new(),
@@ -15,13 +15,14 @@ use std::time::Duration;
 
 use mz_adapter::ResultExt;
 use mz_catalog::durable::{BootstrapArgs, CatalogError, Metrics, OpenableDurableCatalogState};
+use mz_catalog::expr_cache::plan_summaries_by_version;
 use mz_ore::channel::trigger;
 use mz_ore::exit;
 use mz_ore::halt;
 use mz_persist_client::PersistClient;
 use mz_repr::Timestamp;
 use mz_sql::catalog::EnvironmentId;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::BUILD_INFO;
 use crate::deployment::state::DeploymentState;
@@ -252,6 +253,19 @@ pub async fn preflight_0dt(
                 .await;
             }
 
+            // Report plans that changed compared to the deployment we are
+            // about to replace, so that regressions surface before the
+            // cutover rather than as an OOMing cluster after it.
+            check_plan_changes(
+                boot_ts,
+                persist_client.clone(),
+                environment_id.clone(),
+                deploy_generation,
+                Arc::clone(&catalog_metrics),
+                bootstrap_args.clone(),
+            )
+            .await;
+
             // Announce that we're ready to promote.
             let promoted = deployment_state.set_ready_to_promote();
             info!("announced as ready to promote; waiting for promotion");
@@ -344,6 +358,108 @@ async fn check_ddl_changes(
     }
 }
 
+/// Reports the dataflows whose physical plan in this version uses different
+/// join implementations or arrangements than in the previous version.
+///
+/// Both deployments write summaries of their plans to the expression cache, so
+/// by the time we are caught up it holds the summaries of both versions. Plans
+/// whose summary in either version is unknown, e.g., because the versions use
+/// different summary formats, are counted but not compared. The check is purely
+/// informational and never blocks promotion.
+async fn check_plan_changes(
+    boot_ts: Timestamp,
+    persist_client: PersistClient,
+    environment_id: EnvironmentId,
+    deploy_generation: u64,
+    catalog_metrics: Arc<Metrics>,
+    bootstrap_args: BootstrapArgs,
+) {
+    let openable_adapter_storage = mz_catalog::durable::persist_backed_catalog_state(
+        persist_client.clone(),
+        environment_id.organization_id(),
+        BUILD_INFO.semver_version(),
+        Some(deploy_generation),
+        catalog_metrics,
+    )
+    .await
+    .expect("incompatible catalog/persist version");
+
+    let (mut catalog, _audit_logs) = openable_adapter_storage
+        .open_savepoint(boot_ts, &bootstrap_args)
+        .await
+        .unwrap_or_terminate("can open in savepoint mode");
+
+    let shard_id = match catalog.transaction().await {
+        Ok(txn) => txn.get_expression_cache_shard(),
+        Err(err) => {
+            warn!(%err, "unable to check for plan changes");
+            return;
+        }
+    };
+    let Some(shard_id) = shard_id else {
+        info!("no expression cache; skipping plan change check");
+        return;
+    };
+
+    // Must match the version the adapter uses to key the expression cache.
+    let build_version = if BUILD_INFO.is_dev() {
+        BUILD_INFO
+            .semver_version_build()
+            .expect("build ID is not available on your platform!")
+    } else {
+        BUILD_INFO.semver_version()
+    };
+
+    let mut summaries_by_version = plan_summaries_by_version(&persist_client, shard_id).await;
+    let Some(new_summaries) = summaries_by_version.remove(&build_version) else {
+        info!(%build_version, "no cached plans for this version; skipping plan change check");
+        return;
+    };
+    let Some((old_version, old_summaries)) = summaries_by_version.pop_last() else {
+        info!("no cached plans for a previous version; skipping plan change check");
+        return;
+    };
+
+    let mut compared = 0;
+    let mut changed = 0;
+    let mut unknown = 0;
+    for (id, new) in &new_summaries {
+        let Some(old) = old_summaries.get(id) else {
+            continue;
+        };
+        let (Some(old), Some(new)) = (old, new) else {
+            unknown += 1;
+            continue;
+        };
+        compared += 1;
+        let diff = old.diff(new);
+        if diff.is_empty() {
+            continue;
+        }
+        changed += 1;
+        warn!(
+            %id,
+            %old_version,
+            %build_version,
+            removed_joins = ?diff.removed_joins,
+            added_joins = ?diff.added_joins,
+            removed_arrangements = ?diff.removed_arrangements,
+            added_arrangements = ?diff.added_arrangements,
+            removed_indexes = ?diff.removed_indexes,
+            added_indexes = ?diff.added_indexes,
+            "physical plan changed across versions",
+        );
+    }
+    info!(
+        %old_version,
+        %build_version,
+        compared,
+        changed,
+        unknown,
+        "checked plans for changes",
+    );
+}
+
 /// Gets and returns the next user item ID and user replica ID that would be
 /// allocated as of the current catalog state.
 async fn get_next_ids(
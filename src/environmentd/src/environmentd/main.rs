@@ -62,7 +62,7 @@ use mz_ore::task::RuntimeExt;
 use mz_ore::url::SensitiveUrl;
 use mz_persist_client::PersistLocation;
 use mz_persist_client::cache::PersistClientCache;
-use mz_persist_client::cfg::PersistConfig;
+use mz_persist_client::cfg::{BlobEncryptionCliArgs, PersistConfig};
 use mz_persist_client::rpc::{
     MetricsSameProcessPubSubSender, PersistGrpcPubSubServer, PubSubClientConnection, PubSubSender,
 };
@@ -134,6 +134,20 @@ pub struct Args {
         action = ArgAction::Set,
     )]
     http_listen_addr: SocketAddr,
+    /// The address on which to listen for untrusted Arrow Flight SQL
+    /// connections.
+    ///
+    /// Connections on this address are subject to encryption, authentication,
+    /// and authorization as specified by the `--tls-mode` and `--frontegg-auth`
+    /// options.
+    #[clap(
+        long,
+        env = "FLIGHT_LISTEN_ADDR",
+        value_name = "HOST:PORT",
+        default_value = "127.0.0.1:6881",
+        action = ArgAction::Set,
+    )]
+    flight_listen_addr: SocketAddr,
     /// The address on which to listen for trusted SQL connections.
     ///
     /// Connections to this address are not subject to encryption, authentication,
@@ -382,7 +396,8 @@ pub struct Args {
     deploy_generation: u64,
 
     /// Can be provided in place of both persist_consensus_url and
-    /// timestamp_oracle_url in order to point both at the same backend
+    /// timestamp_oracle_url in order to point both at the same backend. May be
+    /// a `file://` URL for a self-contained single-node deployment.
     #[clap(
         long,
         env = "METADATA_BACKEND_URL",
@@ -421,6 +436,11 @@ pub struct Args {
     /// of threads returned by [`num_cpus::get`].
     #[clap(long, env = "PERSIST_ISOLATED_RUNTIME_THREADS")]
     persist_isolated_runtime_threads: Option<isize>,
+    /// Client-side encryption of persist blobs.
+    ///
+    /// These arguments are passed through to `clusterd`.
+    #[clap(flatten)]
+    persist_blob_encryption: BlobEncryptionCliArgs,
     /// The interval in seconds at which to collect storage usage information.
     #[clap(
         long,
@@ -436,8 +456,9 @@ pub struct Args {
     storage_usage_retention_period: Option<Duration>,
 
     // === Adapter options. ===
-    /// The PostgreSQL URL for the Postgres-backed timestamp oracle.
-    #[clap(long, env = "TIMESTAMP_ORACLE_URL", value_name = "POSTGRES_URL")]
+    /// The URL for the durable timestamp oracle: either a PostgreSQL URL, or a
+    /// `file://` URL naming a local directory.
+    #[clap(long, env = "TIMESTAMP_ORACLE_URL", value_name = "URL")]
     timestamp_oracle_url: Option<SensitiveUrl>,
     /// Availability zones in which storage and compute resources may be
     /// deployed.
@@ -702,6 +723,27 @@ pub fn main() {
     }
 }
 
+/// Derives the URL of one metadata store (e.g. `consensus`) from
+/// `--metadata-backend-url`.
+///
+/// For Postgres/CRDB, each store is a schema in the same database. For a local
+/// `file://` backend, each store is a subdirectory.
+fn metadata_backend_url_for(metadata_backend_url: &SensitiveUrl, store: &str) -> SensitiveUrl {
+    if metadata_backend_url.scheme() == "file" {
+        let path = std::path::Path::new(metadata_backend_url.path()).join(store);
+        let url = Url::from_directory_path(&path).expect("file URL paths are absolute");
+        SensitiveUrl(url)
+    } else {
+        SensitiveUrl(
+            Url::parse_with_params(
+                metadata_backend_url.0.as_ref(),
+                &[("options", format!("--search_path={store}"))],
+            )
+            .unwrap(),
+        )
+    }
+}
+
 fn run(mut args: Args) -> Result<(), anyhow::Error> {
     mz_ore::panic::install_enhanced_handler();
     let envd_start = Instant::now();
@@ -989,6 +1031,13 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
             persist_config.isolated_runtime_worker_threads = threads;
         }
     };
+    let blob_secrets_reader = Arc::clone(&secrets_reader);
+    persist_config.blob_encryption = runtime
+        .block_on(args.persist_blob_encryption.load(move |id| {
+            let secrets_reader = Arc::clone(&blob_secrets_reader);
+            async move { secrets_reader.read(id.parse()?).await }
+        }))
+        .context("loading blob encryption keys")?;
 
     let _server = runtime.spawn_named(
         || "persist::rpc::server",
@@ -1023,29 +1072,13 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
     let consensus_uri = args.persist_consensus_url.unwrap_or_else(|| {
         args.metadata_backend_url
             .as_ref()
-            .map(|metadata_backend_url| {
-                SensitiveUrl(
-                    Url::parse_with_params(
-                        metadata_backend_url.0.as_ref(),
-                        &[("options", "--search_path=consensus")],
-                    )
-                    .unwrap(),
-                )
-            })
+            .map(|metadata_backend_url| metadata_backend_url_for(metadata_backend_url, "consensus"))
             .expect("either --persist-consensus-url or --metadata-backend-url must be provided")
     });
     let timestamp_oracle_url = args.timestamp_oracle_url.or_else(|| {
         args.metadata_backend_url
             .as_ref()
-            .map(|metadata_backend_url| {
-                SensitiveUrl(
-                    Url::parse_with_params(
-                        metadata_backend_url.0.as_ref(),
-                        &[("options", "--search_path=tsoracle")],
-                    )
-                    .unwrap(),
-                )
-            })
+            .map(|metadata_backend_url| metadata_backend_url_for(metadata_backend_url, "tsoracle"))
     });
 
     let persist_clients = Arc::new(persist_clients);
@@ -1072,6 +1105,7 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         now: SYSTEM_TIME.clone(),
         metrics_registry: metrics_registry.clone(),
         persist_pubsub_url: args.persist_pubsub_url,
+        persist_blob_encryption_args: args.persist_blob_encryption,
         connection_context,
         // When serialized to args in the controller, only the relevant flags will be passed
         // through, so we just set all of them
@@ -1104,6 +1138,7 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         let listeners = Listeners::bind(ListenersConfig {
             sql_listen_addr: args.sql_listen_addr,
             http_listen_addr: args.http_listen_addr,
+            flight_listen_addr: args.flight_listen_addr,
             internal_sql_listen_addr: args.internal_sql_listen_addr,
             internal_http_listen_addr: args.internal_http_listen_addr,
         })
@@ -0,0 +1,740 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! An Arrow Flight SQL server.
+//!
+//! Flight SQL clients (e.g., ADBC, or `pyarrow.flight`) receive query results
+//! as Arrow record batches, which avoids the cost of encoding and decoding
+//! every datum in pgwire's text format.
+//!
+//! Clients authenticate with a `Handshake` that carries the user's credentials
+//! in a basic `authorization` header, exactly as they would present them over
+//! pgwire, and receive a bearer token that identifies the resulting session in
+//! later calls. Sessions are scoped to the HTTP/2 connection that created
+//! them, just as pgwire sessions are scoped to their connection.
+//!
+//! Queries are planned by `GetFlightInfo` and executed by `DoGet`. `SUBSCRIBE`
+//! is executed like any other query, producing a stream that lasts until the
+//! subscription completes or the client drops it. Statements that don't
+//! return rows are executed by `DoPut`.
+
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Context;
+use arrow::datatypes::Schema;
+use arrow::record_batch::RecordBatch;
+use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::error::FlightError;
+use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+use arrow_flight::sql::server::{FlightSqlService, PeekableFlightDataStream};
+use arrow_flight::sql::{
+    CommandStatementQuery, CommandStatementUpdate, ProstMessageExt, SqlInfo, TicketStatementQuery,
+};
+use arrow_flight::{
+    FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest, HandshakeResponse, Ticket,
+};
+use async_trait::async_trait;
+use futures::future::{BoxFuture, FutureExt, TryFutureExt, pending};
+use futures::stream::{self, Stream, TryStreamExt};
+use headers::HeaderMapExt;
+use headers::authorization::{Authorization, Basic, Bearer};
+use hyper_openssl::SslStream;
+use hyper_openssl::client::legacy::MaybeHttpsStream;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::service::TowerToHyperService;
+use mz_adapter::client::RecordFirstRowStream;
+use mz_adapter::session::{EndTransactionAction, SessionConfig, TransactionStatus};
+use mz_adapter::statement_logging::{StatementEndedExecutionReason, StatementExecutionStrategy};
+use mz_adapter::{
+    AdapterError, ExecuteResponse, ExecuteResponseKind, PeekResponseUnary, SessionClient,
+    verify_datum_desc,
+};
+use mz_arrow_util::builder::ArrowBuilder;
+use mz_auth::password::Password;
+use mz_frontegg_auth::Authenticator as FronteggAuthentication;
+use mz_ore::cast::CastFrom;
+use mz_ore::now::{SYSTEM_TIME, epoch_to_uuid_v7};
+use mz_pgwire_common::{ConnectionCounter, ConnectionHandle};
+use mz_repr::user::InternalUserMetadata;
+use mz_repr::{RelationDesc, RowIterator};
+use mz_server_core::{Connection, ConnectionHandler, Server};
+use mz_sql::ast::{Raw, Statement, StatementKind};
+use mz_sql::parse::StatementParseResult;
+use mz_sql::plan::Plan;
+use mz_sql::session::metadata::SessionMetadata;
+use mz_sql::session::vars::{Value, Var, VarInput, WELCOME_MESSAGE};
+use openssl::ssl::Ssl;
+use prost::Message;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::{ReceiverStream, UnboundedReceiverStream};
+use tonic::metadata::MetadataMap;
+use tonic::{Request, Response, Status, Streaming};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::http::ReloadingTlsConfig;
+
+/// The name of the portal that statements are bound to.
+const EMPTY_PORTAL: &str = "";
+
+/// The maximum number of rows in each record batch sent to clients.
+///
+/// The Flight encoder splits batches whose encoding exceeds the maximum gRPC
+/// message size, so this only bounds the latency of the first batch and the
+/// memory used to build each batch.
+const MAX_BATCH_ROWS: usize = 8192;
+
+/// Configuration for a [`FlightServer`].
+pub struct FlightConfig {
+    /// The TLS configuration for the server.
+    ///
+    /// If not present, then TLS is not enabled.
+    pub tls: Option<ReloadingTlsConfig>,
+    /// A client for the adapter with which the server will communicate.
+    pub adapter_client: mz_adapter::Client,
+    /// The Frontegg authentication configuration.
+    ///
+    /// If present, users must present a valid Frontegg API token as their
+    /// password.
+    pub frontegg: Option<FronteggAuthentication>,
+    /// Whether to use self-hosted authentication.
+    pub use_self_hosted_auth: bool,
+    /// Global connection limit and count.
+    pub active_connection_counter: ConnectionCounter,
+    /// Helm chart version.
+    pub helm_chart_version: Option<String>,
+}
+
+/// A server that communicates with clients via Arrow Flight SQL.
+pub struct FlightServer {
+    tls: Option<ReloadingTlsConfig>,
+    adapter_client: mz_adapter::Client,
+    frontegg: Option<FronteggAuthentication>,
+    use_self_hosted_auth: bool,
+    active_connection_counter: ConnectionCounter,
+    helm_chart_version: Option<String>,
+}
+
+impl FlightServer {
+    /// Constructs a new server.
+    pub fn new(config: FlightConfig) -> FlightServer {
+        FlightServer {
+            tls: config.tls,
+            adapter_client: config.adapter_client,
+            frontegg: config.frontegg,
+            use_self_hosted_auth: config.use_self_hosted_auth,
+            active_connection_counter: config.active_connection_counter,
+            helm_chart_version: config.helm_chart_version,
+        }
+    }
+}
+
+impl Server for FlightServer {
+    const NAME: &'static str = "flight";
+
+    fn handle_connection(&self, conn: Connection) -> ConnectionHandler {
+        let tls_config = self.tls.clone();
+        let mut service = FlightSqlServer {
+            adapter_client: self.adapter_client.clone(),
+            frontegg: self.frontegg.clone(),
+            use_self_hosted_auth: self.use_self_hosted_auth,
+            active_connection_counter: self.active_connection_counter.clone(),
+            helm_chart_version: self.helm_chart_version.clone(),
+            peer_addr: None,
+            sessions: Mutex::new(BTreeMap::new()),
+        };
+        let mut conn = TokioIo::new(conn);
+        Box::pin(async move {
+            let direct_peer_addr = conn.inner().peer_addr().context("fetching peer addr")?;
+            let peer_addr = conn
+                .inner_mut()
+                .take_proxy_header_address()
+                .await
+                .map(|a| a.source)
+                .unwrap_or(direct_peer_addr);
+            service.peer_addr = Some(peer_addr.ip());
+
+            let conn = match tls_config {
+                Some(tls_config) => {
+                    let mut ssl_stream =
+                        SslStream::new(Ssl::new(&tls_config.context.get())?, conn)?;
+                    if let Err(e) = Pin::new(&mut ssl_stream).accept().await {
+                        let _ = ssl_stream.get_mut().inner_mut().shutdown().await;
+                        return Err(e.into());
+                    }
+                    MaybeHttpsStream::Https(ssl_stream)
+                }
+                _ => MaybeHttpsStream::Http(conn),
+            };
+            let service = TowerToHyperService::new(FlightServiceServer::new(service));
+            let http = hyper::server::conn::http2::Builder::new(TokioExecutor::new());
+            http.serve_connection(conn, service).err_into().await
+        })
+    }
+}
+
+/// The Flight SQL service for a single connection.
+struct FlightSqlServer {
+    adapter_client: mz_adapter::Client,
+    frontegg: Option<FronteggAuthentication>,
+    use_self_hosted_auth: bool,
+    active_connection_counter: ConnectionCounter,
+    helm_chart_version: Option<String>,
+    peer_addr: Option<IpAddr>,
+    /// The sessions authenticated on this connection, by bearer token.
+    sessions: Mutex<BTreeMap<String, Arc<tokio::sync::Mutex<FlightSession>>>>,
+}
+
+/// A session authenticated by a `Handshake`.
+struct FlightSession {
+    client: SessionClient,
+    _connection_guard: Option<ConnectionHandle>,
+    /// Resolves when the user's authentication expires.
+    expired: BoxFuture<'static, ()>,
+}
+
+impl FlightSession {
+    fn check_expired(&mut self) -> Result<(), Status> {
+        match (&mut self.expired).now_or_never() {
+            Some(()) => Err(Status::unauthenticated("authentication expired")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FlightSqlServer {
+    /// Authenticates `user` like the pgwire server does, and starts a session
+    /// for them.
+    async fn authenticate(&self, user: &str, password: &str) -> Result<FlightSession, Status> {
+        // The Flight server cannot be used to connect to any system users.
+        if mz_adapter::catalog::is_reserved_role_name(user) {
+            return Err(Status::permission_denied(format!(
+                "unauthorized login to user '{user}'"
+            )));
+        }
+
+        let conn_id = self.adapter_client.new_conn_id().map_err(adapter_status)?;
+        let now = SYSTEM_TIME.clone();
+        let uuid = epoch_to_uuid_v7(&(now)());
+        let (mut session, expired) = if let Some(frontegg) = &self.frontegg {
+            let mut auth_session = frontegg.authenticate(user, password).await.map_err(|err| {
+                warn!(?err, "flight connection failed authentication");
+                Status::unauthenticated("invalid password")
+            })?;
+            let session = self.adapter_client.new_session(SessionConfig {
+                conn_id,
+                uuid,
+                user: auth_session.user().into(),
+                client_ip: self.peer_addr,
+                external_metadata_rx: Some(auth_session.external_metadata_rx()),
+                internal_user_metadata: None,
+                helm_chart_version: self.helm_chart_version.clone(),
+            });
+            let expired = async move { auth_session.expired().await };
+            (session, expired.boxed())
+        } else if self.use_self_hosted_auth {
+            let auth_response = self
+                .adapter_client
+                .authenticate(&user.to_string(), &Password(password.to_string()))
+                .await
+                .map_err(|err| {
+                    warn!(?err, "flight connection failed authentication");
+                    Status::unauthenticated("invalid password")
+                })?;
+            let session = self.adapter_client.new_session(SessionConfig {
+                conn_id,
+                uuid,
+                user: user.to_string(),
+                client_ip: self.peer_addr,
+                external_metadata_rx: None,
+                internal_user_metadata: Some(InternalUserMetadata {
+                    superuser: auth_response.superuser,
+                }),
+                helm_chart_version: self.helm_chart_version.clone(),
+            });
+            // No frontegg check, so auth session lasts indefinitely.
+            (session, pending().boxed())
+        } else {
+            let session = self.adapter_client.new_session(SessionConfig {
+                conn_id,
+                uuid,
+                user: user.to_string(),
+                client_ip: self.peer_addr,
+                external_metadata_rx: None,
+                internal_user_metadata: None,
+                helm_chart_version: self.helm_chart_version.clone(),
+            });
+            // No frontegg check, so auth session lasts indefinitely.
+            (session, pending().boxed())
+        };
+
+        let connection_guard = self
+            .active_connection_counter
+            .allocate_connection(session.user())
+            .map_err(|e| adapter_status(e.into()))?;
+        session
+            .vars_mut()
+            .set_default(WELCOME_MESSAGE.name(), VarInput::Flat(&false.format()))
+            .expect("known to exist");
+        let client = self
+            .adapter_client
+            .startup(session)
+            .await
+            .map_err(adapter_status)?;
+        Ok(FlightSession {
+            client,
+            _connection_guard: connection_guard,
+            expired,
+        })
+    }
+
+    /// Returns the session identified by the bearer token in `metadata`.
+    fn session(
+        &self,
+        metadata: &MetadataMap,
+    ) -> Result<Arc<tokio::sync::Mutex<FlightSession>>, Status> {
+        let headers = metadata.clone().into_headers();
+        let Some(Authorization(bearer)) = headers.typed_get::<Authorization<Bearer>>() else {
+            return Err(Status::unauthenticated("missing bearer token"));
+        };
+        let sessions = self.sessions.lock().expect("lock poisoned");
+        sessions
+            .get(bearer.token())
+            .map(Arc::clone)
+            .ok_or_else(|| Status::unauthenticated("invalid bearer token"))
+    }
+}
+
+#[async_trait]
+impl FlightSqlService for FlightSqlServer {
+    type FlightService = FlightSqlServer;
+
+    async fn do_handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<
+        Response<Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>>,
+        Status,
+    > {
+        let headers = request.metadata().clone().into_headers();
+        let Some(Authorization(basic)) = headers.typed_get::<Authorization<Basic>>() else {
+            return Err(Status::unauthenticated("missing basic authorization"));
+        };
+        let session = self
+            .authenticate(basic.username(), basic.password())
+            .await?;
+
+        let token = Uuid::new_v4().to_string();
+        self.sessions
+            .lock()
+            .expect("lock poisoned")
+            .insert(token.clone(), Arc::new(tokio::sync::Mutex::new(session)));
+
+        let authorization = format!("Bearer {token}")
+            .parse()
+            .map_err(|_| Status::internal("invalid bearer token"))?;
+        let output = stream::iter([Ok(HandshakeResponse {
+            protocol_version: 0,
+            payload: token.into(),
+        })]);
+        let mut response: Response<
+            Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>,
+        > = Response::new(Box::pin(output));
+        response
+            .metadata_mut()
+            .insert("authorization", authorization);
+        Ok(response)
+    }
+
+    async fn get_flight_info_statement(
+        &self,
+        query: CommandStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let session = self.session(request.metadata())?;
+        let mut session = session.lock().await;
+        session.check_expired()?;
+        let client = &mut session.client;
+
+        let (stmt, sql) = parse(client, &query.query)?;
+        client
+            .prepare(EMPTY_PORTAL.into(), Some(stmt), sql, vec![])
+            .await
+            .map_err(adapter_status)?;
+        let desc = client
+            .get_prepared_statement(EMPTY_PORTAL)
+            .await
+            .map_err(adapter_status)?
+            .desc()
+            .relation_desc
+            .clone();
+        let schema = arrow_schema(desc.as_ref())?;
+
+        let ticket = TicketStatementQuery {
+            statement_handle: query.query.into(),
+        };
+        let endpoint =
+            FlightEndpoint::new().with_ticket(Ticket::new(ticket.as_any().encode_to_vec()));
+        let info = FlightInfo::new()
+            .try_with_schema(&schema)
+            .map_err(|e| Status::internal(e.to_string()))?
+            .with_endpoint(endpoint)
+            .with_descriptor(request.into_inner());
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_statement(
+        &self,
+        ticket: TicketStatementQuery,
+        request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let session = self.session(request.metadata())?;
+        let mut session = session.lock_owned().await;
+        session.check_expired()?;
+
+        let query = String::from_utf8(ticket.statement_handle.to_vec())
+            .map_err(|_| Status::invalid_argument("invalid statement handle"))?;
+        let desc = declare(&mut session.client, &query).await?;
+        let schema = match arrow_schema(desc.as_ref()) {
+            Ok(schema) => schema,
+            Err(status) => {
+                end_transaction(&mut session.client, false).await;
+                return Err(status);
+            }
+        };
+
+        // Execute the statement in a task that owns the session, so that a
+        // long-lived `SUBSCRIBE` keeps the session busy until the client
+        // drops the stream.
+        let (tx, rx) = mpsc::channel(1);
+        mz_ore::task::spawn(|| "flight_do_get", async move {
+            let client = &mut session.client;
+            let res = execute_query(client, desc, &tx).await;
+            let succeeded = res.is_ok();
+            if let Err(status) = res {
+                let _ = tx.send(Err(status.into())).await;
+            }
+            end_transaction(client, succeeded).await;
+        });
+
+        let stream = FlightDataEncoderBuilder::new()
+            .with_schema(Arc::new(schema))
+            .build(ReceiverStream::new(rx))
+            .map_err(Status::from);
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    async fn do_put_statement_update(
+        &self,
+        ticket: CommandStatementUpdate,
+        request: Request<PeekableFlightDataStream>,
+    ) -> Result<i64, Status> {
+        let session = self.session(request.metadata())?;
+        let mut session = session.lock().await;
+        session.check_expired()?;
+        let client = &mut session.client;
+
+        let desc = declare(client, &ticket.query).await?;
+        let res = execute_update(client, desc).await;
+        end_transaction(client, res.is_ok()).await;
+        res
+    }
+
+    async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
+}
+
+/// Parses `query`, which must contain exactly one statement.
+fn parse(client: &SessionClient, query: &str) -> Result<(Statement<Raw>, String), Status> {
+    let mut stmts = client
+        .parse(query)
+        .map_err(Status::invalid_argument)?
+        .map_err(|e| adapter_status(e.into()))?;
+    if stmts.len() != 1 {
+        return Err(Status::invalid_argument(format!(
+            "query must contain exactly 1 statement, but contains {}",
+            stmts.len()
+        )));
+    }
+    let StatementParseResult { ast: stmt, sql } = stmts.pop().expect("known to exist");
+
+    // Statements whose responses require further interaction with the client
+    // have no equivalent in Flight SQL.
+    let kind: StatementKind = (&stmt).into();
+    let prohibited = Plan::generated_from(&kind)
+        .into_iter()
+        .flat_map(ExecuteResponse::generated_from)
+        .any(|response| match response {
+            ExecuteResponseKind::Fetch
+            | ExecuteResponseKind::CopyFrom
+            | ExecuteResponseKind::DeclaredCursor
+            | ExecuteResponseKind::ClosedCursor => true,
+            // Various statements generate `PeekPlan` (`SELECT`, `COPY`,
+            // `EXPLAIN`, `SHOW`), which has both `SendRows` and `CopyTo` as
+            // its possible response types, but only `COPY` produces the
+            // latter.
+            ExecuteResponseKind::CopyTo => matches!(kind, StatementKind::Copy),
+            _ => false,
+        });
+    if prohibited {
+        return Err(Status::unimplemented(format!(
+            "unsupported via Flight SQL: {}",
+            sql
+        )));
+    }
+    Ok((stmt, sql.to_string()))
+}
+
+/// Parses `query`, starts an implicit transaction for it, and binds it to the
+/// unnamed portal.
+///
+/// Returns the description of the rows the statement returns, if any.
+async fn declare(client: &mut SessionClient, query: &str) -> Result<Option<RelationDesc>, Status> {
+    let (stmt, sql) = parse(client, query)?;
+    if matches!(client.session().transaction(), TransactionStatus::Failed(_)) {
+        return Err(adapter_status(AdapterError::Unstructured(anyhow::anyhow!(
+            "current transaction is aborted, commands ignored until end of transaction block"
+        ))));
+    }
+    client.start_transaction(Some(1)).map_err(adapter_status)?;
+    if let Err(err) = client.declare(EMPTY_PORTAL.into(), stmt, sql).await {
+        end_transaction(client, false).await;
+        return Err(adapter_status(err));
+    }
+    let desc = client
+        .session()
+        .get_portal_unverified(EMPTY_PORTAL)
+        .map(|portal| portal.desc.relation_desc.clone())
+        .expect("unnamed portal should be present");
+    Ok(desc)
+}
+
+/// Ends the implicit transaction of a statement, mirroring the PostgreSQL
+/// simple query protocol.
+async fn end_transaction(client: &mut SessionClient, succeeded: bool) {
+    match client.session().transaction() {
+        TransactionStatus::Default | TransactionStatus::Failed(_) => {}
+        TransactionStatus::Started(_) | TransactionStatus::InTransactionImplicit(_) => {
+            let action = if succeeded {
+                EndTransactionAction::Commit
+            } else {
+                EndTransactionAction::Rollback
+            };
+            if let Err(err) = client.end_transaction(action).await {
+                warn!(?err, "flight statement failed to end its transaction");
+            }
+        }
+        // Explicit transactions move to failed.
+        TransactionStatus::InTransaction(_) => {
+            if !succeeded {
+                client.fail_transaction();
+            }
+        }
+    }
+}
+
+/// Executes the statement bound to the unnamed portal, sending the rows it
+/// returns to `tx`.
+async fn execute_query(
+    client: &mut SessionClient,
+    desc: Option<RelationDesc>,
+    tx: &mpsc::Sender<Result<RecordBatch, FlightError>>,
+) -> Result<(), Status> {
+    let (res, execute_started) = client
+        .execute(EMPTY_PORTAL.into(), pending(), None)
+        .await
+        .map_err(adapter_status)?;
+    let desc = match desc {
+        Some(desc) => desc,
+        // Statements that don't return rows produce an empty stream.
+        None => return Ok(()),
+    };
+
+    match res {
+        ExecuteResponse::SendingRows {
+            future: rows,
+            instance_id,
+            strategy,
+        } => {
+            let rows = tokio::select! {
+                rows = rows => rows,
+                _ = tx.closed() => return Err(Status::cancelled("query canceled")),
+            };
+            match rows {
+                PeekResponseUnary::Rows(rows) => {
+                    RecordFirstRowStream::record(
+                        execute_started,
+                        client,
+                        Some(instance_id),
+                        Some(strategy),
+                    );
+                    send_rows(&desc, rows, tx).await.map(|_| ())
+                }
+                PeekResponseUnary::Error(e) => Err(Status::unknown(e)),
+                PeekResponseUnary::Canceled => Err(adapter_status(AdapterError::Canceled)),
+            }
+        }
+        ExecuteResponse::SendingRowsImmediate { rows } => {
+            send_rows(&desc, rows, tx).await.map(|_| ())
+        }
+        ExecuteResponse::Subscribing {
+            rx,
+            ctx_extra,
+            instance_id,
+        } => {
+            let mut rx = RecordFirstRowStream::new(
+                Box::new(UnboundedReceiverStream::new(rx)),
+                execute_started,
+                client,
+                Some(instance_id),
+                None,
+            );
+            let mut result_size = 0;
+            let mut rows_returned = 0;
+            let (res, reason) = loop {
+                let res = tokio::select! {
+                    res = rx.recv() => res,
+                    // We consider the client dropping the stream to be a
+                    // cancellation, matching the behavior for pgwire.
+                    _ = tx.closed() => {
+                        break (Ok(()), StatementEndedExecutionReason::Canceled);
+                    }
+                };
+                match res {
+                    Some(PeekResponseUnary::Rows(rows)) => {
+                        rows_returned += rows.count();
+                        match send_rows(&desc, rows, tx).await {
+                            Ok(size) => result_size += size,
+                            Err(status) => {
+                                let error = status.message().to_string();
+                                break (
+                                    Err(status),
+                                    StatementEndedExecutionReason::Errored { error },
+                                );
+                            }
+                        }
+                    }
+                    Some(PeekResponseUnary::Error(error)) => {
+                        break (
+                            Err(Status::unknown(error.clone())),
+                            StatementEndedExecutionReason::Errored { error },
+                        );
+                    }
+                    Some(PeekResponseUnary::Canceled) => {
+                        break (
+                            Err(adapter_status(AdapterError::Canceled)),
+                            StatementEndedExecutionReason::Canceled,
+                        );
+                    }
+                    None => {
+                        break (
+                            Ok(()),
+                            StatementEndedExecutionReason::Success {
+                                result_size: Some(u64::cast_from(result_size)),
+                                rows_returned: Some(u64::cast_from(rows_returned)),
+                                execution_strategy: Some(StatementExecutionStrategy::Standard),
+                            },
+                        );
+                    }
+                }
+            };
+            client.retire_execute(ctx_extra, reason);
+            res
+        }
+        res => Err(Status::internal(format!(
+            "unexpected response to a query: {:?}",
+            ExecuteResponseKind::from(res)
+        ))),
+    }
+}
+
+/// Executes the statement bound to the unnamed portal, which must not return
+/// rows.
+///
+/// Returns the number of rows the statement affected.
+async fn execute_update(
+    client: &mut SessionClient,
+    desc: Option<RelationDesc>,
+) -> Result<i64, Status> {
+    if desc.is_some() {
+        return Err(Status::invalid_argument(
+            "statement returns rows and must be executed as a query",
+        ));
+    }
+    let (res, _) = client
+        .execute(EMPTY_PORTAL.into(), pending(), None)
+        .await
+        .map_err(adapter_status)?;
+    let count = match res {
+        ExecuteResponse::Inserted(n)
+        | ExecuteResponse::Updated(n)
+        | ExecuteResponse::Deleted(n)
+        | ExecuteResponse::Copied(n) => n,
+        _ => 0,
+    };
+    i64::try_from(count).map_err(|_| Status::internal("row count out of range"))
+}
+
+/// Sends `rows` to `tx` as record batches.
+///
+/// Returns the total size of the rows, in bytes.
+async fn send_rows(
+    desc: &RelationDesc,
+    mut rows: Box<dyn RowIterator + Send + Sync>,
+    tx: &mpsc::Sender<Result<RecordBatch, FlightError>>,
+) -> Result<usize, Status> {
+    verify_datum_desc(desc, &mut rows).map_err(adapter_status)?;
+    let mut result_size = 0;
+    let mut remaining = rows.count();
+    while remaining > 0 {
+        let batch_rows = std::cmp::min(remaining, MAX_BATCH_ROWS);
+        let mut builder = ArrowBuilder::new(desc, batch_rows, 0)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        for _ in 0..batch_rows {
+            let row = rows.next().expect("known to exist");
+            builder
+                .add_row_ref(row)
+                .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        }
+        remaining -= batch_rows;
+        result_size += builder.row_size_bytes();
+        let batch = builder
+            .to_record_batch()
+            .map_err(|e| Status::internal(e.to_string()))?;
+        if tx.send(Ok(batch)).await.is_err() {
+            return Err(Status::cancelled("query canceled"));
+        }
+    }
+    Ok(result_size)
+}
+
+/// Returns the Arrow schema of rows described by `desc`.
+fn arrow_schema(desc: Option<&RelationDesc>) -> Result<Schema, Status> {
+    match desc {
+        Some(desc) => {
+            let builder = ArrowBuilder::new(desc, 0, 0)
+                .map_err(|e| Status::invalid_argument(e.to_string()))?;
+            Ok(builder.schema())
+        }
+        None => Ok(Schema::empty()),
+    }
+}
+
+fn adapter_status(err: AdapterError) -> Status {
+    match err {
+        AdapterError::Canceled => Status::cancelled(err.to_string()),
+        AdapterError::UserSessionsDisallowed | AdapterError::NetworkPolicyDenied(_) => {
+            Status::permission_denied(err.to_string())
+        }
+        err => Status::unknown(err.to_string()),
+    }
+}
@@ -1382,7 +1382,10 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::CreatedTable { .. }
         | ExecuteResponse::CreatedIndex { .. }
         | ExecuteResponse::CreatedIntrospectionSubscribe
+        | ExecuteResponse::CreatedFunction
+        | ExecuteResponse::CreatedNotification
         | ExecuteResponse::CreatedSecret { .. }
+        | ExecuteResponse::CreatedPublication { .. }
         | ExecuteResponse::CreatedSource { .. }
         | ExecuteResponse::CreatedSink { .. }
         | ExecuteResponse::CreatedView { .. }
@@ -1401,6 +1404,8 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::GrantedPrivilege
         | ExecuteResponse::GrantedRole
         | ExecuteResponse::Inserted(_)
+        | ExecuteResponse::Listening
+        | ExecuteResponse::Unlistening
         | ExecuteResponse::Copied(_)
         | ExecuteResponse::Raised
         | ExecuteResponse::ReassignOwned
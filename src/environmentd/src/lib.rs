@@ -62,12 +62,14 @@ use tracing::{Instrument, info, info_span};
 
 use crate::deployment::preflight::{PreflightInput, PreflightOutput};
 use crate::deployment::state::DeploymentState;
+use crate::flight::{FlightConfig, FlightServer};
 use crate::http::{HttpConfig, HttpServer, InternalRouteConfig};
 
 pub use crate::http::{SqlResponse, WebSocketAuth, WebSocketResponse};
 
 mod deployment;
 pub mod environmentd;
+mod flight;
 pub mod http;
 mod telemetry;
 #[cfg(feature = "test")]
@@ -206,6 +208,8 @@ pub struct ListenersConfig {
     pub sql_listen_addr: SocketAddr,
     /// The IP address and port to listen for HTTP connections on.
     pub http_listen_addr: SocketAddr,
+    /// The IP address and port to listen for Arrow Flight SQL connections on.
+    pub flight_listen_addr: SocketAddr,
     /// The IP address and port to listen for pgwire connections from the cloud
     /// system on.
     pub internal_sql_listen_addr: SocketAddr,
@@ -227,6 +231,7 @@ pub struct Listeners {
     // Drop order matters for these fields.
     sql: (ListenerHandle, Pin<Box<dyn ConnectionStream>>),
     http: (ListenerHandle, Pin<Box<dyn ConnectionStream>>),
+    flight: (ListenerHandle, Pin<Box<dyn ConnectionStream>>),
     internal_sql: (ListenerHandle, Pin<Box<dyn ConnectionStream>>),
     internal_http: (ListenerHandle, Pin<Box<dyn ConnectionStream>>),
 }
@@ -247,17 +252,20 @@ impl Listeners {
         ListenersConfig {
             sql_listen_addr,
             http_listen_addr,
+            flight_listen_addr,
             internal_sql_listen_addr,
             internal_http_listen_addr,
         }: ListenersConfig,
     ) -> Result<Listeners, io::Error> {
         let sql = mz_server_core::listen(&sql_listen_addr).await?;
         let http = mz_server_core::listen(&http_listen_addr).await?;
+        let flight = mz_server_core::listen(&flight_listen_addr).await?;
         let internal_sql = mz_server_core::listen(&internal_sql_listen_addr).await?;
         let internal_http = mz_server_core::listen(&internal_http_listen_addr).await?;
         Ok(Listeners {
             sql,
             http,
+            flight,
             internal_sql,
             internal_http,
         })
@@ -269,6 +277,7 @@ impl Listeners {
         Listeners::bind(ListenersConfig {
             sql_listen_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
             http_listen_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+            flight_listen_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
             internal_sql_listen_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
             internal_http_listen_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
         })
@@ -287,6 +296,7 @@ impl Listeners {
         let Listeners {
             sql: (sql_listener, sql_conns),
             http: (http_listener, http_conns),
+            flight: (flight_listener, flight_conns),
             internal_sql: (internal_sql_listener, internal_sql_conns),
             internal_http: (internal_http_listener, internal_http_conns),
         } = self;
@@ -825,6 +835,25 @@ impl Listeners {
             })
         });
 
+        // Launch Arrow Flight SQL server.
+        task::spawn(|| "flight_server", {
+            let flight_server = FlightServer::new(FlightConfig {
+                tls: http_tls.clone(),
+                adapter_client: adapter_client.clone(),
+                frontegg: config.frontegg.clone(),
+                use_self_hosted_auth: config.self_hosted_auth,
+                active_connection_counter: active_connection_counter.clone(),
+                helm_chart_version: config.helm_chart_version.clone(),
+            });
+            mz_server_core::serve(ServeConfig {
+                conns: flight_conns,
+                server: flight_server,
+                // `environmentd` does not currently need to dynamically
+                // configure graceful termination behavior.
+                dyncfg: None,
+            })
+        });
+
         let (authenticator_kind, authenticator_rx) = match &config.frontegg {
             Some(_) => (AuthenticatorKind::Frontegg, authenticator_frontegg_rx),
             None => (AuthenticatorKind::None, authenticator_none_rx),
@@ -898,6 +927,7 @@ impl Listeners {
         Ok(Server {
             sql_listener,
             http_listener,
+            flight_listener,
             internal_sql_listener,
             internal_http_listener,
             _adapter_handle: adapter_handle,
@@ -912,6 +942,10 @@ impl Listeners {
         self.http.0.local_addr()
     }
 
+    pub fn flight_local_addr(&self) -> SocketAddr {
+        self.flight.0.local_addr()
+    }
+
     pub fn internal_sql_local_addr(&self) -> SocketAddr {
         self.internal_sql.0.local_addr()
     }
@@ -940,6 +974,7 @@ pub struct Server {
     // Drop order matters for these fields.
     sql_listener: ListenerHandle,
     http_listener: ListenerHandle,
+    flight_listener: ListenerHandle,
     internal_sql_listener: ListenerHandle,
     internal_http_listener: ListenerHandle,
     _adapter_handle: mz_adapter::Handle,
@@ -954,6 +989,10 @@ impl Server {
         self.http_listener.local_addr()
     }
 
+    pub fn flight_local_addr(&self) -> SocketAddr {
+        self.flight_listener.local_addr()
+    }
+
     pub fn internal_sql_local_addr(&self) -> SocketAddr {
         self.internal_sql_listener.local_addr()
     }
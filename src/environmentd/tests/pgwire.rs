@@ -20,7 +20,7 @@ use mz_adapter::session::DEFAULT_DATABASE_NAME;
 use mz_environmentd::test_util::{self, PostgresErrorExt};
 use mz_ore::collections::CollectionExt;
 use mz_ore::retry::Retry;
-use mz_ore::{assert_err, assert_ok};
+use mz_ore::{assert_contains, assert_err, assert_ok};
 use mz_pgrepr::{Numeric, Record};
 use postgres::SimpleQueryMessage;
 use postgres::binary_copy::BinaryCopyOutIter;
@@ -28,6 +28,7 @@ use postgres::error::SqlState;
 use postgres::types::Type;
 use postgres_array::{Array, Dimension};
 use tokio::sync::mpsc;
+use tokio_postgres::config::ReplicationMode;
 
 #[mz_ore::test]
 fn test_bind_params() {
@@ -404,6 +405,63 @@ async fn test_conn_startup() {
     }
 }
 
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+async fn test_replication_slot_active() {
+    let server = test_util::TestHarness::default().start().await;
+    server
+        .enable_feature_flags(&["enable_logical_replication"])
+        .await;
+    let client = server.connect().internal().await.unwrap();
+    client
+        .batch_execute("CREATE TABLE t (a int); CREATE PUBLICATION p FOR TABLE t")
+        .await
+        .unwrap();
+
+    let server = &server;
+    let connect_replication = || async move {
+        let builder = server.connect().internal();
+        let mut config = builder.as_pg_config().clone();
+        config.replication_mode(ReplicationMode::Logical);
+        builder.with_config(config).await.unwrap()
+    };
+    const START: &str = "START_REPLICATION SLOT s LOGICAL 0/0 \
+        (proto_version '1', publication_names 'p')";
+
+    let first = connect_replication().await;
+    first
+        .simple_query("CREATE_REPLICATION_SLOT s LOGICAL pgoutput")
+        .await
+        .unwrap();
+    let stream = first.copy_both_simple::<bytes::Bytes>(START).await.unwrap();
+
+    // A second consumer may neither start nor drop the slot while the first
+    // streams from it.
+    let second = connect_replication().await;
+    let err = second.simple_query(START).await.unwrap_err();
+    assert_eq!(err.code(), Some(&SqlState::OBJECT_IN_USE));
+    assert_contains!(
+        err.unwrap_db_error().message(),
+        "replication slot \"s\" is active"
+    );
+    let err = second
+        .simple_query("DROP_REPLICATION_SLOT s")
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Some(&SqlState::OBJECT_IN_USE));
+
+    // Once the first consumer disconnects, the second may take over.
+    drop(stream);
+    drop(first);
+    Retry::default()
+        .max_duration(Duration::from_secs(30))
+        .retry_async(|_| async move {
+            let third = connect_replication().await;
+            third.copy_both_simple::<bytes::Bytes>(START).await
+        })
+        .await
+        .unwrap();
+}
+
 #[mz_ore::test]
 fn test_conn_user() {
     let server = test_util::TestHarness::default().start_blocking();
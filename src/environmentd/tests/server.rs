@@ -21,8 +21,12 @@ use std::time::{Duration, Instant};
 use std::{iter, thread};
 
 use anyhow::bail;
+use arrow::array::AsArray;
+use arrow::datatypes::{DataType, Int64Type};
+use arrow::record_batch::RecordBatch;
+use arrow_flight::sql::client::FlightSqlServiceClient;
 use chrono::{DateTime, Utc};
-use futures::FutureExt;
+use futures::{FutureExt, StreamExt, TryStreamExt};
 use http::Request;
 use itertools::Itertools;
 use jsonwebtoken::{DecodingKey, EncodingKey};
@@ -4690,3 +4694,87 @@ fn test_builtin_connection_alterations_are_preserved_across_restarts() {
         );
     }
 }
+
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // too slow
+async fn test_flight_sql() {
+    let server = test_util::TestHarness::default().start().await;
+    let channel = tonic::transport::Channel::from_shared(format!(
+        "http://{}",
+        server.inner.flight_local_addr()
+    ))
+    .unwrap()
+    .connect()
+    .await
+    .unwrap();
+    let mut client = FlightSqlServiceClient::new(channel);
+
+    // Statements require an authenticated session.
+    assert_err!(client.execute("SELECT 1".into(), None).await);
+    client.handshake("materialize", "").await.unwrap();
+
+    let affected = client
+        .execute_update("CREATE TABLE t (a int8)".into(), None)
+        .await
+        .unwrap();
+    assert_eq!(affected, 0);
+    let affected = client
+        .execute_update("INSERT INTO t VALUES (1), (2), (3)".into(), None)
+        .await
+        .unwrap();
+    assert_eq!(affected, 3);
+    // Statements that return rows must be executed as queries.
+    assert_err!(client.execute_update("SELECT 1".into(), None).await);
+
+    let info = client
+        .execute("SELECT a FROM t ORDER BY a".into(), None)
+        .await
+        .unwrap();
+    let schema = info.clone().try_decode_schema().unwrap();
+    assert_eq!(schema.fields().len(), 1);
+    assert_eq!(schema.field(0).name(), "a");
+    assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    let batches: Vec<RecordBatch> = client
+        .do_get(ticket)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    let values: Vec<i64> = batches
+        .iter()
+        .flat_map(|batch| {
+            batch
+                .column(0)
+                .as_primitive::<Int64Type>()
+                .values()
+                .to_vec()
+        })
+        .collect();
+    assert_eq!(values, vec![1, 2, 3]);
+
+    // `SUBSCRIBE` streams batches until the client drops the stream.
+    let info = client.execute("SUBSCRIBE t".into(), None).await.unwrap();
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    let mut stream = client.do_get(ticket).await.unwrap();
+    let batch = stream.next().await.unwrap().unwrap();
+    assert_eq!(batch.num_rows(), 3);
+    assert_eq!(
+        batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect::<Vec<_>>(),
+        vec!["mz_timestamp", "mz_diff", "a"]
+    );
+    drop(stream);
+
+    // Dropping the stream cancels the subscription and frees up the session.
+    let affected = client
+        .execute_update("INSERT INTO t VALUES (4)".into(), None)
+        .await
+        .unwrap();
+    assert_eq!(affected, 1);
+}
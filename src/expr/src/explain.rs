@@ -19,7 +19,7 @@ use mz_repr::explain::ExplainError::LinearChainsPlusRecursive;
 use mz_repr::explain::text::DisplayText;
 use mz_repr::explain::{
     AnnotatedPlan, Explain, ExplainConfig, ExplainError, ExprHumanizer, ScalarOps,
-    UnsupportedFormat, UsedIndexes,
+    UnsupportedFormat, UsedIndexes, UsedStatistics,
 };
 use mz_repr::optimize::OptimizerFeatures;
 
@@ -45,6 +45,7 @@ pub struct ExplainContext<'a> {
     pub humanizer: &'a dyn ExprHumanizer,
     pub cardinality_stats: BTreeMap<GlobalId, usize>,
     pub used_indexes: UsedIndexes,
+    pub used_statistics: UsedStatistics,
     pub finishing: Option<RowSetFinishing>,
     pub duration: Duration,
     // Cluster against which the explained plan is optimized.
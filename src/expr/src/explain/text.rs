@@ -26,8 +26,9 @@ use mz_sql_parser::ast::Ident;
 
 use crate::explain::{ExplainMultiPlan, ExplainSinglePlan};
 use crate::{
-    AccessStrategy, AggregateExpr, EvalError, Id, JoinImplementation, JoinInputCharacteristics,
-    LocalId, MapFilterProject, MirRelationExpr, MirScalarExpr, RowSetFinishing,
+    AccessStrategy, AggregateExpr, AsOfMatch, EvalError, Id, JoinImplementation,
+    JoinInputCharacteristics, LocalId, MapFilterProject, MirRelationExpr, MirScalarExpr,
+    RowSetFinishing,
 };
 
 impl<'a, T: 'a> DisplayText for ExplainSinglePlan<'a, T>
@@ -64,6 +65,11 @@ where
             self.context.used_indexes.fmt_text(f, &mut ctx)?;
         }
 
+        if !self.context.used_statistics.is_empty() {
+            writeln!(f)?;
+            self.context.used_statistics.fmt_text(f, &mut ctx)?;
+        }
+
         if let Some(target_cluster) = self.context.target_cluster {
             writeln!(f)?;
             writeln!(f, "Target cluster: {}", target_cluster)?;
@@ -162,6 +168,11 @@ where
             self.context.used_indexes.fmt_text(f, &mut ctx)?;
         }
 
+        if !self.context.used_statistics.is_empty() {
+            writeln!(f)?;
+            self.context.used_statistics.fmt_text(f, &mut ctx)?;
+        }
+
         if let Some(target_cluster) = self.context.target_cluster {
             writeln!(f)?;
             writeln!(f, "Target cluster: {}", target_cluster)?;
@@ -543,6 +554,7 @@ impl MirRelationExpr {
                 implementation:
                     implementation @ (JoinImplementation::Differential(..)
                     | JoinImplementation::DeltaQuery(..)
+                    | JoinImplementation::AsOf(..)
                     | JoinImplementation::Unimplemented),
             } => {
                 let has_equivalences = !equivalences.is_empty();
@@ -563,6 +575,17 @@ impl MirRelationExpr {
                 if let Some(name) = implementation.name() {
                     write!(f, " type={}", name)?;
                 }
+                if let JoinImplementation::AsOf(AsOfMatch {
+                    left_time,
+                    right_time,
+                    comparison,
+                }) = implementation
+                {
+                    let cols = self.column_names(ctx);
+                    let left_time = mode.expr(left_time, cols);
+                    let right_time = mode.expr(right_time, cols);
+                    write!(f, " match=({} {} {})", left_time, comparison, right_time)?;
+                }
 
                 self.fmt_analyses(f, ctx)?;
 
@@ -681,7 +704,7 @@ impl MirRelationExpr {
                             JoinImplementation::IndexedFilter(_, _, _, _) => {
                                 unreachable!() // because above we matched the other implementations
                             }
-                            JoinImplementation::Unimplemented => {}
+                            JoinImplementation::AsOf(..) | JoinImplementation::Unimplemented => {}
                         }
                         Ok(())
                     })?;
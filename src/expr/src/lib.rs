@@ -43,11 +43,11 @@ pub use relation::func::{
 };
 pub use relation::join_input_mapper::JoinInputMapper;
 pub use relation::{
-    AccessStrategy, AggregateExpr, CollectionPlan, ColumnOrder, JoinImplementation,
-    JoinInputCharacteristics, LetRecLimit, MirRelationExpr, ProtoAggregateExpr, ProtoAggregateFunc,
-    ProtoColumnOrder, ProtoRowSetFinishing, ProtoTableFunc, RECURSION_LIMIT, RowSetFinishing,
-    WindowFrame, WindowFrameBound, WindowFrameUnits, canonicalize, compare_columns,
-    non_nullable_columns,
+    AccessStrategy, AggregateExpr, AsOfComparison, AsOfMatch, CollectionPlan, ColumnOrder,
+    JoinImplementation, JoinInputCharacteristics, LetRecLimit, MirRelationExpr, ProtoAggregateExpr,
+    ProtoAggregateFunc, ProtoAsOfComparison, ProtoColumnOrder, ProtoRowSetFinishing,
+    ProtoTableFunc, RECURSION_LIMIT, RowSetFinishing, WindowFrame, WindowFrameBound,
+    WindowFrameUnits, canonicalize, compare_columns, non_nullable_columns,
 };
 pub use scalar::func::{self, BinaryFunc, UnaryFunc, UnmaterializableFunc, VariadicFunc};
 pub use scalar::{
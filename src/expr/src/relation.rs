@@ -1324,6 +1324,13 @@ impl MirRelationExpr {
         }
     }
 
+    /// Checks if `self` is a `Join` implemented as an ASOF join.
+    ///
+    /// See [`JoinImplementation::is_asof`] for why transformations need to know this.
+    pub fn is_asof_join(&self) -> bool {
+        matches!(self, MirRelationExpr::Join { implementation, .. } if implementation.is_asof())
+    }
+
     /// Constructs the expression for getting a local collection.
     pub fn local_get(id: LocalId, typ: RelationType) -> Self {
         MirRelationExpr::Get {
@@ -1909,6 +1916,14 @@ impl MirRelationExpr {
                             f(k)?;
                         }
                     }
+                    JoinImplementation::AsOf(AsOfMatch {
+                        left_time,
+                        right_time,
+                        comparison: _,
+                    }) => {
+                        f(left_time)?;
+                        f(right_time)?;
+                    }
                     JoinImplementation::Unimplemented => {} // No scalar exprs
                 }
             }
@@ -2038,6 +2053,14 @@ impl MirRelationExpr {
                             f(k)?;
                         }
                     }
+                    JoinImplementation::AsOf(AsOfMatch {
+                        left_time,
+                        right_time,
+                        comparison: _,
+                    }) => {
+                        f(left_time)?;
+                        f(right_time)?;
+                    }
                     JoinImplementation::Unimplemented => {} // No scalar exprs
                 }
             }
@@ -3391,6 +3414,14 @@ pub enum JoinImplementation {
         Vec<MirScalarExpr>,
         #[mzreflect(ignore)] Vec<Row>,
     ),
+    /// Join each row of the first input with the row of the second input that is closest in
+    /// time among the rows with equal keys, as described by the [`AsOfMatch`].
+    ///
+    /// Only produced when lowering an `ASOF JOIN`; the join must have exactly two inputs and its
+    /// equivalences must relate expressions over the first input to expressions over the second.
+    /// Rows of the first input without a match are dropped, and each output row has the product
+    /// of the multiplicities of the rows it joins.
+    AsOf(AsOfMatch),
     /// No implementation yet selected.
     Unimplemented,
 }
@@ -3416,9 +3447,137 @@ impl JoinImplementation {
             Self::Differential(..) => Some("differential"),
             Self::DeltaQuery(..) => Some("delta"),
             Self::IndexedFilter(..) => Some("indexed_filter"),
+            Self::AsOf(..) => Some("asof"),
             Self::Unimplemented => None,
         }
     }
+
+    /// Returns `true` iff the value is [`JoinImplementation::AsOf`].
+    ///
+    /// Unlike other implementations, an ASOF join is not an inner join of its inputs, and
+    /// transformations must not change its inputs or equivalences in ways that are only
+    /// valid for inner joins.
+    pub fn is_asof(&self) -> bool {
+        matches!(self, Self::AsOf(..))
+    }
+}
+
+/// The time comparison of an [`JoinImplementation::AsOf`] join.
+///
+/// Each row of the first input is matched with the row of the second input whose time is
+/// closest to its own among those for which `left_time <comparison> right_time` holds.
+#[derive(
+    Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Hash, MzReflect, Arbitrary,
+)]
+pub struct AsOfMatch {
+    /// The time of a row of the first input, in terms of the join's output columns.
+    pub left_time: MirScalarExpr,
+    /// The time of a row of the second input, in terms of the join's output columns.
+    pub right_time: MirScalarExpr,
+    /// How the time of the first input must compare to the time of the second input.
+    pub comparison: AsOfComparison,
+}
+
+/// The comparison operators allowed in the match condition of an ASOF join.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Hash,
+    MzReflect,
+    Arbitrary,
+)]
+pub enum AsOfComparison {
+    /// Match the latest right time strictly before the left time.
+    Gt,
+    /// Match the latest right time at or before the left time.
+    Gte,
+    /// Match the earliest right time strictly after the left time.
+    Lt,
+    /// Match the earliest right time at or after the left time.
+    Lte,
+}
+
+impl AsOfComparison {
+    /// The comparison that holds after swapping the left and right operands.
+    pub fn flip(self) -> Self {
+        match self {
+            AsOfComparison::Gt => AsOfComparison::Lt,
+            AsOfComparison::Gte => AsOfComparison::Lte,
+            AsOfComparison::Lt => AsOfComparison::Gt,
+            AsOfComparison::Lte => AsOfComparison::Gte,
+        }
+    }
+
+    /// Returns the position of the closest match for `left_time` in `right_times`, which must be
+    /// sorted, or `None` if no time satisfies the comparison.
+    ///
+    /// Among equal right times, the last one is chosen for `Gt` and `Gte`, and the first one for
+    /// `Lt` and `Lte`, so sorting ties by the rest of their row makes the choice deterministic.
+    pub fn closest_match(self, left_time: Datum, right_times: &[Datum]) -> Option<usize> {
+        match self {
+            AsOfComparison::Gt => right_times
+                .partition_point(|t| *t < left_time)
+                .checked_sub(1),
+            AsOfComparison::Gte => right_times
+                .partition_point(|t| *t <= left_time)
+                .checked_sub(1),
+            AsOfComparison::Lt => {
+                let pos = right_times.partition_point(|t| *t <= left_time);
+                (pos < right_times.len()).then_some(pos)
+            }
+            AsOfComparison::Lte => {
+                let pos = right_times.partition_point(|t| *t < left_time);
+                (pos < right_times.len()).then_some(pos)
+            }
+        }
+    }
+}
+
+impl fmt::Display for AsOfComparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            AsOfComparison::Gt => ">",
+            AsOfComparison::Gte => ">=",
+            AsOfComparison::Lt => "<",
+            AsOfComparison::Lte => "<=",
+        })
+    }
+}
+
+impl RustType<ProtoAsOfComparison> for AsOfComparison {
+    fn into_proto(&self) -> ProtoAsOfComparison {
+        use proto_as_of_comparison::Kind::*;
+        ProtoAsOfComparison {
+            kind: Some(match self {
+                AsOfComparison::Gt => Gt(()),
+                AsOfComparison::Gte => Gte(()),
+                AsOfComparison::Lt => Lt(()),
+                AsOfComparison::Lte => Lte(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoAsOfComparison) -> Result<Self, TryFromProtoError> {
+        use proto_as_of_comparison::Kind::*;
+        Ok(match proto.kind {
+            Some(Gt(())) => AsOfComparison::Gt,
+            Some(Gte(())) => AsOfComparison::Gte,
+            Some(Lt(())) => AsOfComparison::Lt,
+            Some(Lte(())) => AsOfComparison::Lte,
+            None => {
+                return Err(TryFromProtoError::missing_field(
+                    "ProtoAsOfComparison::kind",
+                ));
+            }
+        })
+    }
 }
 
 /// Characteristics of a join order candidate collection.
@@ -3490,6 +3649,23 @@ impl JoinInputCharacteristics {
         }
     }
 
+    /// Whether the join input described by `self` has a unique key on the join key.
+    pub fn unique_key(&self) -> bool {
+        match self {
+            Self::V1(jic) => jic.unique_key,
+            Self::V2(jic) => jic.unique_key,
+        }
+    }
+
+    /// The estimated cardinality of the join input described by `self`, if known.
+    pub fn cardinality(&self) -> Option<usize> {
+        let cardinality = match self {
+            Self::V1(jic) => jic.cardinality,
+            Self::V2(jic) => jic.cardinality,
+        };
+        cardinality.map(|std::cmp::Reverse(c)| c)
+    }
+
     /// Returns the `FilterCharacteristics` for the join input described by `self`.
     pub fn filters(&mut self) -> &mut FilterCharacteristics {
         match self {
@@ -26,6 +26,7 @@ use mz_ore::str::separated;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::date::Date;
+use mz_repr::adt::geometry::{Geometry, GridCell};
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
 use mz_repr::adt::regex::Regex as ReprRegex;
@@ -3278,6 +3279,81 @@ fn generate_series_ts<T: TimestampLike>(
     Ok(trsi.map(move |i| (Row::pack_slice(&[conv(i)]), Diff::ONE)))
 }
 
+/// Emits the level and indexes of each of the given grid cells.
+fn grid_cells(cells: Vec<GridCell>) -> impl Iterator<Item = (Row, Diff)> {
+    cells.into_iter().map(|cell| {
+        let row = Row::pack_slice(&[
+            Datum::Int64(cell.level),
+            Datum::Int64(cell.x),
+            Datum::Int64(cell.y),
+        ]);
+        (row, Diff::ONE)
+    })
+}
+
+/// Returns the start and end of every window of length `size`, starting at a
+/// multiple of `slide` since the Unix epoch, that contains `ts`.
+///
+/// Windows are emitted from the latest to the earliest.
+fn time_window(
+    ts: Datum,
+    size: Interval,
+    slide: Interval,
+    tz: bool,
+) -> Result<impl Iterator<Item = (Row, Diff)>, EvalError> {
+    let micros = if tz {
+        ts.unwrap_timestamptz().timestamp_micros()
+    } else {
+        ts.unwrap_timestamp().and_utc().timestamp_micros()
+    };
+    let positive_micros = |interval: Interval| {
+        i64::try_from(interval.as_microseconds())
+            .ok()
+            .filter(|micros| *micros > 0)
+            .ok_or_else(|| {
+                EvalError::InvalidParameterValue("window size and slide must be positive".into())
+            })
+    };
+    let windows = TableFunc::hop_windows_per_row(size, slide);
+    let size = positive_micros(size)?;
+    let slide = positive_micros(slide)?;
+    let max_windows = TableFunc::MAX_HOP_WINDOWS_PER_ROW;
+    if windows > max_windows {
+        return Err(EvalError::InvalidParameterValue(
+            format!(
+                "window size and slide assign each row to {windows} windows, \
+                 which exceeds the limit of {max_windows}"
+            )
+            .into(),
+        ));
+    }
+    let to_datum = |micros: i64| -> Result<Datum<'static>, EvalError> {
+        let dt = DateTime::from_timestamp_micros(micros).ok_or(EvalError::TimestampOutOfRange)?;
+        Ok(if tz {
+            Datum::from(CheckedTimestamp::from_timestamplike(dt)?)
+        } else {
+            Datum::from(CheckedTimestamp::from_timestamplike(dt.naive_utc())?)
+        })
+    };
+
+    let mut windows = Vec::with_capacity(usize::cast_from(windows));
+    let mut start = micros - micros.rem_euclid(slide);
+    loop {
+        let end = start
+            .checked_add(size)
+            .ok_or(EvalError::TimestampOutOfRange)?;
+        if end <= micros {
+            break;
+        }
+        let row = Row::pack_slice(&[to_datum(start)?, to_datum(end)?]);
+        windows.push((row, Diff::ONE));
+        start = start
+            .checked_sub(slide)
+            .ok_or(EvalError::TimestampOutOfRange)?;
+    }
+    Ok(windows.into_iter())
+}
+
 fn generate_subscripts_array(
     a: Datum,
     dim: i32,
@@ -3760,11 +3836,42 @@ pub enum TableFunc {
         relation: RelationType,
     },
     RegexpMatches,
+    /// Assigns a timestamp to the fixed-size, non-overlapping window that
+    /// contains it, emitting the window's start and end.
+    ///
+    /// `TUMBLE` and `HOP` plan to a `FlatMap` over this function or
+    /// [`TableFunc::HopWindow`], rather than to a dedicated windowing operator.
+    /// Planning validates the constant `size` and `slide`.
+    TumbleWindow {
+        size: Interval,
+        /// Whether the input is a `timestamp with time zone`.
+        tz: bool,
+    },
+    /// Assigns a timestamp to each of the fixed-size windows, starting every
+    /// `slide`, that contain it, emitting each window's start and end.
+    HopWindow {
+        size: Interval,
+        slide: Interval,
+        /// Whether the input is a `timestamp with time zone`.
+        tz: bool,
+    },
+    /// Emits the grid cells that cover the envelope of a geometry, see
+    /// [`Geometry::grid_cover`].
+    ///
+    /// This function is not intended to be called directly by end users, but
+    /// is used to plan joins on spatial containment.
+    GeometryGridCover,
+    /// Emits the grid cells that contain the anchor of a geometry, see
+    /// [`Geometry::grid_anchor`].
+    ///
+    /// This function is not intended to be called directly by end users, but
+    /// is used to plan joins on spatial containment.
+    GeometryGridAnchor,
 }
 
 impl RustType<ProtoTableFunc> for TableFunc {
     fn into_proto(&self) -> ProtoTableFunc {
-        use proto_table_func::{Kind, ProtoWrap};
+        use proto_table_func::{Kind, ProtoHopWindow, ProtoTumbleWindow, ProtoWrap};
 
         ProtoTableFunc {
             kind: Some(match self {
@@ -3795,6 +3902,17 @@ impl RustType<ProtoTableFunc> for TableFunc {
                     })
                 }
                 TableFunc::RegexpMatches => Kind::RegexpMatches(()),
+                TableFunc::TumbleWindow { size, tz } => Kind::TumbleWindow(ProtoTumbleWindow {
+                    size: Some(size.into_proto()),
+                    tz: *tz,
+                }),
+                TableFunc::HopWindow { size, slide, tz } => Kind::HopWindow(ProtoHopWindow {
+                    size: Some(size.into_proto()),
+                    slide: Some(slide.into_proto()),
+                    tz: *tz,
+                }),
+                TableFunc::GeometryGridCover => Kind::GeometryGridCover(()),
+                TableFunc::GeometryGridAnchor => Kind::GeometryGridAnchor(()),
             }),
         }
     }
@@ -3840,11 +3958,42 @@ impl RustType<ProtoTableFunc> for TableFunc {
                     .into_rust_if_some("ProtoTabletizedScalar::relation")?,
             },
             Kind::RegexpMatches(_) => TableFunc::RegexpMatches,
+            Kind::TumbleWindow(v) => TableFunc::TumbleWindow {
+                size: v.size.into_rust_if_some("ProtoTumbleWindow::size")?,
+                tz: v.tz,
+            },
+            Kind::HopWindow(v) => TableFunc::HopWindow {
+                size: v.size.into_rust_if_some("ProtoHopWindow::size")?,
+                slide: v.slide.into_rust_if_some("ProtoHopWindow::slide")?,
+                tz: v.tz,
+            },
+            Kind::GeometryGridCover(()) => TableFunc::GeometryGridCover,
+            Kind::GeometryGridAnchor(()) => TableFunc::GeometryGridAnchor,
         })
     }
 }
 
 impl TableFunc {
+    /// The largest number of windows that [`TableFunc::HopWindow`] may assign
+    /// a row to.
+    ///
+    /// Planning rejects `HOP`s above this limit, so a query never reaches
+    /// evaluation with more windows per row. Evaluation checks it again
+    /// rather than emitting more windows should it ever be handed such a
+    /// function. This is a constant rather than a
+    /// configuration parameter because existing catalog items are planned
+    /// again on every restart, and must not start failing to plan. For the
+    /// same reason, it must never be lowered.
+    pub const MAX_HOP_WINDOWS_PER_ROW: u64 = 10_000;
+
+    /// Returns the number of windows of length `size`, starting every `slide`,
+    /// that each timestamp belongs to, i.e., `size / slide` rounded up.
+    pub fn hop_windows_per_row(size: Interval, slide: Interval) -> u64 {
+        let size = size.as_microseconds().max(0);
+        let slide = slide.as_microseconds().max(1);
+        u64::try_from((size + slide - 1) / slide).unwrap_or(u64::MAX)
+    }
+
     pub fn eval<'a>(
         &'a self,
         datums: &'a [Datum<'a>],
@@ -3920,6 +4069,20 @@ impl TableFunc {
                 Ok(Box::new(std::iter::once((r, Diff::ONE))))
             }
             TableFunc::RegexpMatches => Ok(Box::new(regexp_matches(datums)?)),
+            TableFunc::TumbleWindow { size, tz } => {
+                Ok(Box::new(time_window(datums[0], *size, *size, *tz)?))
+            }
+            TableFunc::HopWindow { size, slide, tz } => {
+                Ok(Box::new(time_window(datums[0], *size, *slide, *tz)?))
+            }
+            TableFunc::GeometryGridCover => {
+                let geometry = Geometry::from_ewkb(datums[0].unwrap_bytes())?;
+                Ok(Box::new(grid_cells(geometry.grid_cover())))
+            }
+            TableFunc::GeometryGridAnchor => {
+                let geometry = Geometry::from_ewkb(datums[0].unwrap_bytes())?;
+                Ok(Box::new(grid_cells(geometry.grid_anchor())))
+            }
         }
     }
 
@@ -4055,6 +4218,27 @@ impl TableFunc {
 
                 (column_types, keys)
             }
+            TableFunc::TumbleWindow { tz, .. } | TableFunc::HopWindow { tz, .. } => {
+                let typ = if *tz {
+                    ScalarType::TimestampTz { precision: None }
+                } else {
+                    ScalarType::Timestamp { precision: None }
+                };
+                let column_types = vec![typ.clone().nullable(false), typ.nullable(false)];
+                let keys = vec![vec![0], vec![1]];
+                (column_types, keys)
+            }
+            TableFunc::GeometryGridCover => {
+                let column_types = vec![ScalarType::Int64.nullable(false); 3];
+                // All cells of a cover are on the same level.
+                let keys = vec![vec![1, 2]];
+                (column_types, keys)
+            }
+            TableFunc::GeometryGridAnchor => {
+                let column_types = vec![ScalarType::Int64.nullable(false); 3];
+                let keys = vec![vec![0]];
+                (column_types, keys)
+            }
         };
 
         if !keys.is_empty() {
@@ -4085,6 +4269,10 @@ impl TableFunc {
             TableFunc::Wrap { width, .. } => *width,
             TableFunc::TabletizedScalar { relation, .. } => relation.column_types.len(),
             TableFunc::RegexpMatches => 1,
+            TableFunc::TumbleWindow { .. } => 2,
+            TableFunc::HopWindow { .. } => 2,
+            TableFunc::GeometryGridCover => 3,
+            TableFunc::GeometryGridAnchor => 3,
         }
     }
 
@@ -4106,7 +4294,11 @@ impl TableFunc {
             | TableFunc::UnnestArray { .. }
             | TableFunc::UnnestList { .. }
             | TableFunc::UnnestMap { .. }
-            | TableFunc::RegexpMatches => true,
+            | TableFunc::RegexpMatches
+            | TableFunc::TumbleWindow { .. }
+            | TableFunc::HopWindow { .. }
+            | TableFunc::GeometryGridCover
+            | TableFunc::GeometryGridAnchor => true,
             TableFunc::Wrap { .. } => false,
             TableFunc::TabletizedScalar { .. } => false,
         }
@@ -4136,6 +4328,10 @@ impl TableFunc {
             TableFunc::Wrap { .. } => true,
             TableFunc::TabletizedScalar { .. } => true,
             TableFunc::RegexpMatches => true,
+            TableFunc::TumbleWindow { .. } => true,
+            TableFunc::HopWindow { .. } => true,
+            TableFunc::GeometryGridCover => true,
+            TableFunc::GeometryGridAnchor => true,
         }
     }
 }
@@ -4162,14 +4358,25 @@ impl fmt::Display for TableFunc {
             TableFunc::Wrap { width, .. } => write!(f, "wrap{}", width),
             TableFunc::TabletizedScalar { name, .. } => f.write_str(name),
             TableFunc::RegexpMatches => write!(f, "regexp_matches(_, _, _)"),
+            TableFunc::TumbleWindow { size, .. } => write!(f, "tumble(_, {})", size),
+            TableFunc::HopWindow { size, slide, .. } => {
+                write!(f, "hop(_, {}, {})", size, slide)
+            }
+            TableFunc::GeometryGridCover => f.write_str("geometry_grid_cover"),
+            TableFunc::GeometryGridAnchor => f.write_str("geometry_grid_anchor"),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{AggregateFunc, ProtoAggregateFunc, ProtoTableFunc, TableFunc};
-    use mz_ore::assert_ok;
+    use chrono::DateTime;
+    use mz_ore::{assert_err, assert_ok};
+    use mz_repr::Datum;
+    use mz_repr::adt::interval::Interval;
+    use mz_repr::adt::timestamp::CheckedTimestamp;
+
+    use super::{AggregateFunc, ProtoAggregateFunc, ProtoTableFunc, TableFunc, time_window};
     use mz_proto::protobuf_roundtrip;
     use proptest::prelude::*;
 
@@ -4192,4 +4399,32 @@ mod tests {
             assert_eq!(actual.unwrap(), expect);
         }
     }
+
+    #[mz_ore::test]
+    fn time_window_limit() {
+        let ts = CheckedTimestamp::from_timestamplike(
+            DateTime::from_timestamp_micros(12_500_000)
+                .unwrap()
+                .naive_utc(),
+        )
+        .unwrap();
+        let ts = Datum::from(ts);
+        let second = Interval::new(0, 0, 1_000_000);
+
+        assert_eq!(
+            TableFunc::hop_windows_per_row(Interval::new(0, 0, 2_500_000), second),
+            3
+        );
+        assert_eq!(time_window(ts, second, second, false).unwrap().count(), 1);
+        assert_eq!(
+            time_window(ts, Interval::new(0, 0, 10_000_000), second, false)
+                .unwrap()
+                .count(),
+            10
+        );
+
+        // Rather than emitting a million windows, evaluation fails.
+        let micro = Interval::new(0, 0, 1);
+        assert_err!(time_window(ts, second, micro, false));
+    }
 }
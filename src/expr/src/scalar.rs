@@ -27,9 +27,11 @@ use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::array::InvalidArrayError;
 use mz_repr::adt::date::DateError;
 use mz_repr::adt::datetime::DateTimeUnits;
+use mz_repr::adt::geometry::GeometryError;
 use mz_repr::adt::range::InvalidRangeError;
 use mz_repr::adt::regex::Regex;
 use mz_repr::adt::timestamp::TimestampError;
+use mz_repr::adt::vector::VectorDimensionsMismatchError;
 use mz_repr::strconv::{ParseError, ParseHexError};
 use mz_repr::{ColumnType, Datum, Row, RowArena, ScalarType, arb_datum};
 use proptest::prelude::*;
@@ -2694,6 +2696,15 @@ pub enum EvalError {
     AclArrayNullElement,
     MzAclArrayNullElement,
     PrettyError(Box<str>),
+    VectorDimensionsMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    DifferentVectorDimensions(usize, usize),
+    WasmUdfError {
+        function: Box<str>,
+        message: Box<str>,
+    },
 }
 
 impl fmt::Display for EvalError {
@@ -2902,6 +2913,15 @@ impl fmt::Display for EvalError {
             EvalError::MzAclArrayNullElement => {
                 write!(f, "MZ_ACL arrays must not contain null values")
             }
+            EvalError::VectorDimensionsMismatch { expected, actual } => {
+                write!(f, "expected {expected} dimensions, not {actual}")
+            }
+            EvalError::DifferentVectorDimensions(a, b) => {
+                write!(f, "different vector dimensions {a} and {b}")
+            }
+            EvalError::WasmUdfError { function, message } => {
+                write!(f, "error in function {function}: {message}")
+            }
         }
     }
 }
@@ -2959,6 +2979,21 @@ impl From<ParseHexError> for EvalError {
     }
 }
 
+impl From<VectorDimensionsMismatchError> for EvalError {
+    fn from(e: VectorDimensionsMismatchError) -> EvalError {
+        EvalError::VectorDimensionsMismatch {
+            expected: usize::cast_from(e.expected),
+            actual: e.actual,
+        }
+    }
+}
+
+impl From<GeometryError> for EvalError {
+    fn from(e: GeometryError) -> EvalError {
+        EvalError::InvalidParameterValue(e.to_string().into())
+    }
+}
+
 impl From<InvalidArrayError> for EvalError {
     fn from(e: InvalidArrayError) -> EvalError {
         EvalError::InvalidArray(e)
@@ -3160,6 +3195,22 @@ impl RustType<ProtoEvalError> for EvalError {
             EvalError::AclArrayNullElement => AclArrayNullElement(()),
             EvalError::MzAclArrayNullElement => MzAclArrayNullElement(()),
             EvalError::InvalidIanaTimezoneId(s) => InvalidIanaTimezoneId(s.into_proto()),
+            EvalError::VectorDimensionsMismatch { expected, actual } => {
+                VectorDimensionsMismatch(ProtoVectorDimensions {
+                    expected: expected.into_proto(),
+                    actual: actual.into_proto(),
+                })
+            }
+            EvalError::DifferentVectorDimensions(a, b) => {
+                DifferentVectorDimensions(ProtoVectorDimensions {
+                    expected: a.into_proto(),
+                    actual: b.into_proto(),
+                })
+            }
+            EvalError::WasmUdfError { function, message } => WasmUdfError(ProtoWasmUdfError {
+                function: function.into_proto(),
+                message: message.into_proto(),
+            }),
         };
         ProtoEvalError { kind: Some(kind) }
     }
@@ -3287,6 +3338,18 @@ impl RustType<ProtoEvalError> for EvalError {
                 MzAclArrayNullElement(()) => Ok(EvalError::MzAclArrayNullElement),
                 InvalidIanaTimezoneId(s) => Ok(EvalError::InvalidIanaTimezoneId(s.into())),
                 PrettyError(s) => Ok(EvalError::PrettyError(s.into())),
+                VectorDimensionsMismatch(v) => Ok(EvalError::VectorDimensionsMismatch {
+                    expected: v.expected.into_rust()?,
+                    actual: v.actual.into_rust()?,
+                }),
+                DifferentVectorDimensions(v) => Ok(EvalError::DifferentVectorDimensions(
+                    v.expected.into_rust()?,
+                    v.actual.into_rust()?,
+                )),
+                WasmUdfError(v) => Ok(EvalError::WasmUdfError {
+                    function: v.function.into(),
+                    message: v.message.into(),
+                }),
             },
             None => Err(TryFromProtoError::missing_field("ProtoEvalError::kind")),
         }
@@ -42,6 +42,7 @@ use mz_proto::chrono::any_naive_datetime;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::date::Date;
+use mz_repr::adt::geometry::Geometry;
 use mz_repr::adt::interval::{Interval, RoundBehavior};
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::mz_acl_item::{AclItem, AclMode, MzAclItem};
@@ -50,6 +51,7 @@ use mz_repr::adt::range::{self, Range, RangeBound, RangeOps};
 use mz_repr::adt::regex::{Regex, any_regex};
 use mz_repr::adt::system::Oid;
 use mz_repr::adt::timestamp::{CheckedTimestamp, TimestampLike};
+use mz_repr::adt::vector;
 use mz_repr::role_id::RoleId;
 use mz_repr::{ColumnName, ColumnType, Datum, DatumType, Row, RowArena, ScalarType, strconv};
 use mz_sql_parser::ast::display::FormatMode;
@@ -75,8 +77,11 @@ mod binary;
 mod encoding;
 pub(crate) mod format;
 pub(crate) mod impls;
+mod text_search;
+mod wasm;
 
 pub use impls::*;
+pub use wasm::{WASM_UDF_FUEL_LIMIT, WASM_UDF_MAX_MEMORY_BYTES, WasmUdf};
 
 /// The maximum size of a newly allocated string. Chosen to be the smallest number to keep our tests
 /// passing without changing. 100MiB is probably higher than what we want, but it's better than no
@@ -3318,6 +3323,173 @@ fn starts_with<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     Datum::from(a.starts_with(b))
 }
 
+fn to_tsvector<'a>(
+    config: Datum<'a>,
+    document: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let config = text_search::lookup_config(config.unwrap_str())?;
+    let tsvector = config.to_tsvector(document.unwrap_str());
+    Ok(Datum::String(
+        temp_storage.push_string(tsvector.to_string()),
+    ))
+}
+
+fn to_tsquery<'a>(
+    config: Datum<'a>,
+    query: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let config = text_search::lookup_config(config.unwrap_str())?;
+    let tsquery = config.to_tsquery(query.unwrap_str())?;
+    Ok(Datum::String(temp_storage.push_string(tsquery.to_string())))
+}
+
+fn ts_match<'a>(tsvector: Datum<'a>, tsquery: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let tsvector = strconv::parse_tsvector(tsvector.unwrap_str())?;
+    let tsquery = strconv::parse_tsquery(tsquery.unwrap_str())?;
+    Ok(Datum::from(tsquery.matches(&tsvector)))
+}
+
+fn ts_rank<'a>(tsvector: Datum<'a>, tsquery: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let tsvector = strconv::parse_tsvector(tsvector.unwrap_str())?;
+    let tsquery = strconv::parse_tsquery(tsquery.unwrap_str())?;
+    Ok(Datum::from(tsquery.rank(&tsvector)))
+}
+
+/// Returns the elements of two vectors, which must have the same dimensions.
+fn vector_operands<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<(Vec<f32>, Vec<f32>), EvalError> {
+    let a: Vec<f32> = a
+        .unwrap_array()
+        .elements()
+        .iter()
+        .map(|d| d.unwrap_float32())
+        .collect();
+    let b: Vec<f32> = b
+        .unwrap_array()
+        .elements()
+        .iter()
+        .map(|d| d.unwrap_float32())
+        .collect();
+    if a.len() != b.len() {
+        return Err(EvalError::DifferentVectorDimensions(a.len(), b.len()));
+    }
+    Ok((a, b))
+}
+
+fn vector_l2_distance<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let (a, b) = vector_operands(a, b)?;
+    Ok(Datum::from(vector::l2_distance(&a, &b)))
+}
+
+fn vector_cosine_distance<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let (a, b) = vector_operands(a, b)?;
+    Ok(Datum::from(vector::cosine_distance(&a, &b)))
+}
+
+fn vector_inner_product<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let (a, b) = vector_operands(a, b)?;
+    Ok(Datum::from(vector::inner_product(&a, &b)))
+}
+
+fn vector_negative_inner_product<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let (a, b) = vector_operands(a, b)?;
+    Ok(Datum::from(-vector::inner_product(&a, &b)))
+}
+
+/// Decodes the geometries held by two datums.
+fn geometry_operands<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<(Geometry, Geometry), EvalError> {
+    let a = Geometry::from_ewkb(a.unwrap_bytes())?;
+    let b = Geometry::from_ewkb(b.unwrap_bytes())?;
+    Ok((a, b))
+}
+
+fn st_contains<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let (a, b) = geometry_operands(a, b)?;
+    Ok(Datum::from(a.contains(&b)?))
+}
+
+fn st_intersects<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let (a, b) = geometry_operands(a, b)?;
+    Ok(Datum::from(a.intersects(&b)?))
+}
+
+fn st_distance<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let (a, b) = geometry_operands(a, b)?;
+    Ok(Datum::from(a.distance(&b)?))
+}
+
+fn st_set_srid<'a>(
+    a: Datum<'a>,
+    srid: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let geometry = Geometry::from_ewkb(a.unwrap_bytes())?.with_srid(srid.unwrap_int32());
+    Ok(Datum::Bytes(temp_storage.push_bytes(geometry.to_ewkb())))
+}
+
+fn st_make_point<'a>(
+    x: Datum<'a>,
+    y: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let point = Geometry::point(x.unwrap_float64(), y.unwrap_float64())?;
+    Ok(Datum::Bytes(temp_storage.push_bytes(point.to_ewkb())))
+}
+
+fn st_make_line<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let (a, b) = geometry_operands(a, b)?;
+    let line = Geometry::make_line(&a, &b)?;
+    Ok(Datum::Bytes(temp_storage.push_bytes(line.to_ewkb())))
+}
+
+/// Converts a user-supplied geohash precision to a `usize`. Negative
+/// precisions map to a value that the geohash functions reject as out of range.
+fn geohash_precision(precision: Datum) -> usize {
+    usize::try_from(precision.unwrap_int32()).unwrap_or(usize::MAX)
+}
+
+fn st_geohash<'a>(
+    a: Datum<'a>,
+    precision: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let geometry = Geometry::from_ewkb(a.unwrap_bytes())?;
+    // As in PostGIS, a precision of zero chooses the precision from the
+    // geometry's extent.
+    let precision = match geohash_precision(precision) {
+        0 => None,
+        precision => Some(precision),
+    };
+    Ok(match geometry.geohash(precision)? {
+        Some(hash) => Datum::String(temp_storage.push_string(hash)),
+        None => Datum::Null,
+    })
+}
+
+fn st_geohash_cells<'a>(
+    a: Datum<'a>,
+    precision: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let geometry = Geometry::from_ewkb(a.unwrap_bytes())?;
+    let cells = geometry.geohash_cells(geohash_precision(precision))?;
+    let mut row = Row::default();
+    let mut packer = row.packer();
+    packer.try_push_array(
+        &[ArrayDimension {
+            lower_bound: 1,
+            length: cells.len(),
+        }],
+        cells.iter().map(|cell| Datum::String(cell)),
+    )?;
+    Ok(temp_storage.push_unary_row(row))
+}
+
 #[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
 pub enum BinaryFunc {
     AddInt16,
@@ -3514,6 +3686,22 @@ pub enum BinaryFunc {
     PrettySql,
     RegexpReplace { regex: Regex, limit: usize },
     StartsWith,
+    ToTsVector,
+    ToTsQuery,
+    TsMatch,
+    TsRank,
+    VectorL2Distance,
+    VectorCosineDistance,
+    VectorInnerProduct,
+    VectorNegativeInnerProduct,
+    StContains,
+    StIntersects,
+    StDistance,
+    StSetSrid,
+    StMakePoint,
+    StMakeLine,
+    StGeoHash,
+    StGeoHashCells,
 }
 
 impl BinaryFunc {
@@ -3783,6 +3971,22 @@ impl BinaryFunc {
                 regexp_replace_static(a, b, regex, *limit, temp_storage)
             }
             BinaryFunc::StartsWith => Ok(starts_with(a, b)),
+            BinaryFunc::ToTsVector => to_tsvector(a, b, temp_storage),
+            BinaryFunc::ToTsQuery => to_tsquery(a, b, temp_storage),
+            BinaryFunc::TsMatch => ts_match(a, b),
+            BinaryFunc::TsRank => ts_rank(a, b),
+            BinaryFunc::VectorL2Distance => vector_l2_distance(a, b),
+            BinaryFunc::VectorCosineDistance => vector_cosine_distance(a, b),
+            BinaryFunc::VectorInnerProduct => vector_inner_product(a, b),
+            BinaryFunc::VectorNegativeInnerProduct => vector_negative_inner_product(a, b),
+            BinaryFunc::StContains => st_contains(a, b),
+            BinaryFunc::StIntersects => st_intersects(a, b),
+            BinaryFunc::StDistance => st_distance(a, b),
+            BinaryFunc::StSetSrid => st_set_srid(a, b, temp_storage),
+            BinaryFunc::StMakePoint => st_make_point(a, b, temp_storage),
+            BinaryFunc::StMakeLine => st_make_line(a, b, temp_storage),
+            BinaryFunc::StGeoHash => st_geohash(a, b, temp_storage),
+            BinaryFunc::StGeoHashCells => st_geohash_cells(a, b, temp_storage),
         }
     }
 
@@ -3978,6 +4182,20 @@ impl BinaryFunc {
             RegexpReplace { .. } => ScalarType::String.nullable(in_nullable),
 
             StartsWith => ScalarType::Bool.nullable(in_nullable),
+
+            ToTsVector => ScalarType::TsVector.nullable(in_nullable),
+            ToTsQuery => ScalarType::TsQuery.nullable(in_nullable),
+            TsMatch => ScalarType::Bool.nullable(in_nullable),
+            TsRank => ScalarType::Float32.nullable(in_nullable),
+            VectorL2Distance
+            | VectorCosineDistance
+            | VectorInnerProduct
+            | VectorNegativeInnerProduct => ScalarType::Float64.nullable(in_nullable),
+            StContains | StIntersects => ScalarType::Bool.nullable(in_nullable),
+            StDistance => ScalarType::Float64.nullable(true),
+            StSetSrid | StMakePoint | StMakeLine => ScalarType::Geometry.nullable(in_nullable),
+            StGeoHash => ScalarType::String.nullable(true),
+            StGeoHashCells => ScalarType::Array(Box::new(ScalarType::String)).nullable(in_nullable),
         }
     }
 
@@ -4183,7 +4401,21 @@ impl BinaryFunc {
             | ParseIdent
             | PrettySql
             | RegexpReplace { .. }
-            | StartsWith => false,
+            | StartsWith
+            | ToTsVector
+            | ToTsQuery
+            | TsMatch
+            | TsRank
+            | VectorL2Distance
+            | VectorCosineDistance
+            | VectorInnerProduct
+            | VectorNegativeInnerProduct
+            | StContains
+            | StIntersects
+            | StSetSrid
+            | StMakePoint
+            | StMakeLine
+            | StGeoHashCells => false,
 
             JsonbGetInt64
             | JsonbGetInt64Stringify
@@ -4198,7 +4430,9 @@ impl BinaryFunc {
             | ListLengthMax { .. }
             | ArrayLength
             | ArrayLower
-            | ArrayUpper => true,
+            | ArrayUpper
+            | StDistance
+            | StGeoHash => true,
         }
     }
 
@@ -4342,7 +4576,11 @@ impl BinaryFunc {
             | RangeAdjacent
             | RangeUnion
             | RangeIntersection
-            | RangeDifference => true,
+            | RangeDifference
+            | TsMatch
+            | VectorL2Distance
+            | VectorCosineDistance
+            | VectorNegativeInnerProduct => true,
             ToCharTimestamp
             | ToCharTimestampTz
             | AgeTimestamp
@@ -4398,7 +4636,19 @@ impl BinaryFunc {
             | ParseIdent
             | PrettySql
             | RegexpReplace { .. }
-            | StartsWith => false,
+            | StartsWith
+            | ToTsVector
+            | ToTsQuery
+            | TsRank
+            | VectorInnerProduct
+            | StContains
+            | StIntersects
+            | StDistance
+            | StSetSrid
+            | StMakePoint
+            | StMakeLine
+            | StGeoHash
+            | StGeoHashCells => false,
         }
     }
 
@@ -4715,6 +4965,22 @@ impl BinaryFunc {
             BinaryFunc::PrettySql => (false, false),
             BinaryFunc::RegexpReplace { .. } => (false, false),
             BinaryFunc::StartsWith => (false, false),
+            BinaryFunc::ToTsVector
+            | BinaryFunc::ToTsQuery
+            | BinaryFunc::TsMatch
+            | BinaryFunc::TsRank => (false, false),
+            BinaryFunc::VectorL2Distance
+            | BinaryFunc::VectorCosineDistance
+            | BinaryFunc::VectorInnerProduct
+            | BinaryFunc::VectorNegativeInnerProduct => (false, false),
+            BinaryFunc::StContains
+            | BinaryFunc::StIntersects
+            | BinaryFunc::StDistance
+            | BinaryFunc::StSetSrid
+            | BinaryFunc::StMakePoint
+            | BinaryFunc::StMakeLine
+            | BinaryFunc::StGeoHash
+            | BinaryFunc::StGeoHashCells => (false, false),
         }
     }
 }
@@ -4933,6 +5199,22 @@ impl fmt::Display for BinaryFunc {
                 limit
             ),
             BinaryFunc::StartsWith => f.write_str("starts_with"),
+            BinaryFunc::ToTsVector => f.write_str("to_tsvector"),
+            BinaryFunc::ToTsQuery => f.write_str("to_tsquery"),
+            BinaryFunc::TsMatch => f.write_str("@@"),
+            BinaryFunc::TsRank => f.write_str("ts_rank"),
+            BinaryFunc::VectorL2Distance => f.write_str("<->"),
+            BinaryFunc::VectorCosineDistance => f.write_str("<=>"),
+            BinaryFunc::VectorInnerProduct => f.write_str("inner_product"),
+            BinaryFunc::VectorNegativeInnerProduct => f.write_str("<#>"),
+            BinaryFunc::StContains => f.write_str("st_contains"),
+            BinaryFunc::StIntersects => f.write_str("st_intersects"),
+            BinaryFunc::StDistance => f.write_str("st_distance"),
+            BinaryFunc::StSetSrid => f.write_str("st_setsrid"),
+            BinaryFunc::StMakePoint => f.write_str("st_makepoint"),
+            BinaryFunc::StMakeLine => f.write_str("st_makeline"),
+            BinaryFunc::StGeoHash => f.write_str("st_geohash"),
+            BinaryFunc::StGeoHashCells => f.write_str("st_geohash_cells"),
         }
     }
 }
@@ -5143,6 +5425,22 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::RangeIntersection).boxed(),
             Just(BinaryFunc::RangeDifference).boxed(),
             Just(BinaryFunc::ParseIdent).boxed(),
+            Just(BinaryFunc::ToTsVector).boxed(),
+            Just(BinaryFunc::ToTsQuery).boxed(),
+            Just(BinaryFunc::TsMatch).boxed(),
+            Just(BinaryFunc::TsRank).boxed(),
+            Just(BinaryFunc::VectorL2Distance).boxed(),
+            Just(BinaryFunc::VectorCosineDistance).boxed(),
+            Just(BinaryFunc::VectorInnerProduct).boxed(),
+            Just(BinaryFunc::VectorNegativeInnerProduct).boxed(),
+            Just(BinaryFunc::StContains).boxed(),
+            Just(BinaryFunc::StIntersects).boxed(),
+            Just(BinaryFunc::StDistance).boxed(),
+            Just(BinaryFunc::StSetSrid).boxed(),
+            Just(BinaryFunc::StMakePoint).boxed(),
+            Just(BinaryFunc::StMakeLine).boxed(),
+            Just(BinaryFunc::StGeoHash).boxed(),
+            Just(BinaryFunc::StGeoHashCells).boxed(),
         ])
     }
 }
@@ -5356,6 +5654,22 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 })
             }
             BinaryFunc::StartsWith => StartsWith(()),
+            BinaryFunc::ToTsVector => ToTsVector(()),
+            BinaryFunc::ToTsQuery => ToTsQuery(()),
+            BinaryFunc::TsMatch => TsMatch(()),
+            BinaryFunc::TsRank => TsRank(()),
+            BinaryFunc::VectorL2Distance => VectorL2Distance(()),
+            BinaryFunc::VectorCosineDistance => VectorCosineDistance(()),
+            BinaryFunc::VectorInnerProduct => VectorInnerProduct(()),
+            BinaryFunc::VectorNegativeInnerProduct => VectorNegativeInnerProduct(()),
+            BinaryFunc::StContains => StContains(()),
+            BinaryFunc::StIntersects => StIntersects(()),
+            BinaryFunc::StDistance => StDistance(()),
+            BinaryFunc::StSetSrid => StSetSrid(()),
+            BinaryFunc::StMakePoint => StMakePoint(()),
+            BinaryFunc::StMakeLine => StMakeLine(()),
+            BinaryFunc::StGeoHash => StGeoHash(()),
+            BinaryFunc::StGeoHashCells => StGeoHashCells(()),
         };
         ProtoBinaryFunc { kind: Some(kind) }
     }
@@ -5570,6 +5884,22 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                     limit: inner.limit.into_rust()?,
                 }),
                 StartsWith(()) => Ok(BinaryFunc::StartsWith),
+                ToTsVector(()) => Ok(BinaryFunc::ToTsVector),
+                ToTsQuery(()) => Ok(BinaryFunc::ToTsQuery),
+                TsMatch(()) => Ok(BinaryFunc::TsMatch),
+                TsRank(()) => Ok(BinaryFunc::TsRank),
+                VectorL2Distance(()) => Ok(BinaryFunc::VectorL2Distance),
+                VectorCosineDistance(()) => Ok(BinaryFunc::VectorCosineDistance),
+                VectorInnerProduct(()) => Ok(BinaryFunc::VectorInnerProduct),
+                VectorNegativeInnerProduct(()) => Ok(BinaryFunc::VectorNegativeInnerProduct),
+                StContains(()) => Ok(BinaryFunc::StContains),
+                StIntersects(()) => Ok(BinaryFunc::StIntersects),
+                StDistance(()) => Ok(BinaryFunc::StDistance),
+                StSetSrid(()) => Ok(BinaryFunc::StSetSrid),
+                StMakePoint(()) => Ok(BinaryFunc::StMakePoint),
+                StMakeLine(()) => Ok(BinaryFunc::StMakeLine),
+                StGeoHash(()) => Ok(BinaryFunc::StGeoHash),
+                StGeoHashCells(()) => Ok(BinaryFunc::StGeoHashCells),
             }
         } else {
             Err(TryFromProtoError::missing_field("ProtoBinaryFunc::kind"))
@@ -6068,7 +6398,34 @@ derive_unary!(
     KafkaMurmur2String,
     SeahashBytes,
     SeahashString,
-    Reverse
+    Reverse,
+    CastStringToEnum,
+    CastEnumToString,
+    CastStringToTsVector,
+    CastTsVectorToString,
+    CastStringToTsQuery,
+    CastTsQueryToString,
+    TsVectorToArray,
+    CastStringToVector,
+    CastVectorToString,
+    CastVectorToVector,
+    CastArrayToVector,
+    CastVectorToArray,
+    VectorDims,
+    VectorNorm,
+    VectorAnnBucket,
+    CastStringToGeometry,
+    CastGeometryToString,
+    CastBytesToGeometry,
+    CastGeometryToBytes,
+    StAsText,
+    StAsEwkt,
+    StAsBinary,
+    StX,
+    StY,
+    StSrid,
+    StGeometryType,
+    StMakePolygon
 );
 
 impl UnaryFunc {
@@ -6367,6 +6724,33 @@ impl Arbitrary for UnaryFunc {
                 .boxed(),
             CastArrayToListOneDim::arbitrary().prop_map_into().boxed(),
             CastMapToString::arbitrary().prop_map_into().boxed(),
+            CastStringToEnum::arbitrary().prop_map_into().boxed(),
+            CastEnumToString::arbitrary().prop_map_into().boxed(),
+            CastStringToTsVector::arbitrary().prop_map_into().boxed(),
+            CastTsVectorToString::arbitrary().prop_map_into().boxed(),
+            CastStringToTsQuery::arbitrary().prop_map_into().boxed(),
+            CastTsQueryToString::arbitrary().prop_map_into().boxed(),
+            TsVectorToArray::arbitrary().prop_map_into().boxed(),
+            CastStringToVector::arbitrary().prop_map_into().boxed(),
+            CastVectorToString::arbitrary().prop_map_into().boxed(),
+            CastVectorToVector::arbitrary().prop_map_into().boxed(),
+            CastArrayToVector::arbitrary().prop_map_into().boxed(),
+            CastVectorToArray::arbitrary().prop_map_into().boxed(),
+            VectorDims::arbitrary().prop_map_into().boxed(),
+            VectorNorm::arbitrary().prop_map_into().boxed(),
+            VectorAnnBucket::arbitrary().prop_map_into().boxed(),
+            CastStringToGeometry::arbitrary().prop_map_into().boxed(),
+            CastGeometryToString::arbitrary().prop_map_into().boxed(),
+            CastBytesToGeometry::arbitrary().prop_map_into().boxed(),
+            CastGeometryToBytes::arbitrary().prop_map_into().boxed(),
+            StAsText::arbitrary().prop_map_into().boxed(),
+            StAsEwkt::arbitrary().prop_map_into().boxed(),
+            StAsBinary::arbitrary().prop_map_into().boxed(),
+            StX::arbitrary().prop_map_into().boxed(),
+            StY::arbitrary().prop_map_into().boxed(),
+            StSrid::arbitrary().prop_map_into().boxed(),
+            StGeometryType::arbitrary().prop_map_into().boxed(),
+            StMakePolygon::arbitrary().prop_map_into().boxed(),
             CastInt2VectorToString::arbitrary().prop_map_into().boxed(),
             CastRangeToString::arbitrary().prop_map_into().boxed(),
             CeilFloat32::arbitrary().prop_map_into().boxed(),
@@ -6895,6 +7279,39 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::SeahashBytes(_) => SeahashBytes(()),
             UnaryFunc::SeahashString(_) => SeahashString(()),
             UnaryFunc::Reverse(_) => Reverse(()),
+            UnaryFunc::CastStringToEnum(func) => CastStringToEnum(func.return_ty.into_proto()),
+            UnaryFunc::CastEnumToString(func) => CastEnumToString(func.ty.into_proto()),
+            UnaryFunc::CastStringToTsVector(_) => CastStringToTsVector(()),
+            UnaryFunc::CastTsVectorToString(_) => CastTsVectorToString(()),
+            UnaryFunc::CastStringToTsQuery(_) => CastStringToTsQuery(()),
+            UnaryFunc::CastTsQueryToString(_) => CastTsQueryToString(()),
+            UnaryFunc::TsVectorToArray(_) => TsVectorToArray(()),
+            UnaryFunc::CastStringToVector(func) => CastStringToVector(ProtoCastToVector {
+                dimensions: func.dimensions.into_proto(),
+            }),
+            UnaryFunc::CastVectorToString(_) => CastVectorToString(()),
+            UnaryFunc::CastVectorToVector(func) => CastVectorToVector(ProtoCastToVector {
+                dimensions: func.dimensions.into_proto(),
+            }),
+            UnaryFunc::CastArrayToVector(func) => CastArrayToVector(ProtoCastToVector {
+                dimensions: func.dimensions.into_proto(),
+            }),
+            UnaryFunc::CastVectorToArray(_) => CastVectorToArray(()),
+            UnaryFunc::VectorDims(_) => VectorDims(()),
+            UnaryFunc::VectorNorm(_) => VectorNorm(()),
+            UnaryFunc::VectorAnnBucket(_) => VectorAnnBucket(()),
+            UnaryFunc::CastStringToGeometry(_) => CastStringToGeometry(()),
+            UnaryFunc::CastGeometryToString(_) => CastGeometryToString(()),
+            UnaryFunc::CastBytesToGeometry(_) => CastBytesToGeometry(()),
+            UnaryFunc::CastGeometryToBytes(_) => CastGeometryToBytes(()),
+            UnaryFunc::StAsText(_) => StAsText(()),
+            UnaryFunc::StAsEwkt(_) => StAsEwkt(()),
+            UnaryFunc::StAsBinary(_) => StAsBinary(()),
+            UnaryFunc::StX(_) => StX(()),
+            UnaryFunc::StY(_) => StY(()),
+            UnaryFunc::StSrid(_) => StSrid(()),
+            UnaryFunc::StGeometryType(_) => StGeometryType(()),
+            UnaryFunc::StMakePolygon(_) => StMakePolygon(()),
         };
         ProtoUnaryFunc { kind: Some(kind) }
     }
@@ -7394,6 +7811,48 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 SeahashBytes(()) => Ok(impls::SeahashBytes.into()),
                 SeahashString(()) => Ok(impls::SeahashString.into()),
                 Reverse(()) => Ok(impls::Reverse.into()),
+                CastStringToEnum(return_ty) => Ok(impls::CastStringToEnum {
+                    return_ty: return_ty.into_rust()?,
+                }
+                .into()),
+                CastEnumToString(ty) => Ok(impls::CastEnumToString {
+                    ty: ty.into_rust()?,
+                }
+                .into()),
+                CastStringToTsVector(()) => Ok(impls::CastStringToTsVector.into()),
+                CastTsVectorToString(()) => Ok(impls::CastTsVectorToString.into()),
+                CastStringToTsQuery(()) => Ok(impls::CastStringToTsQuery.into()),
+                CastTsQueryToString(()) => Ok(impls::CastTsQueryToString.into()),
+                TsVectorToArray(()) => Ok(impls::TsVectorToArray.into()),
+                CastStringToVector(func) => Ok(impls::CastStringToVector {
+                    dimensions: func.dimensions.into_rust()?,
+                }
+                .into()),
+                CastVectorToString(()) => Ok(impls::CastVectorToString.into()),
+                CastVectorToVector(func) => Ok(impls::CastVectorToVector {
+                    dimensions: func.dimensions.into_rust()?,
+                }
+                .into()),
+                CastArrayToVector(func) => Ok(impls::CastArrayToVector {
+                    dimensions: func.dimensions.into_rust()?,
+                }
+                .into()),
+                CastVectorToArray(()) => Ok(impls::CastVectorToArray.into()),
+                VectorDims(()) => Ok(impls::VectorDims.into()),
+                VectorNorm(()) => Ok(impls::VectorNorm.into()),
+                VectorAnnBucket(()) => Ok(impls::VectorAnnBucket.into()),
+                CastStringToGeometry(()) => Ok(impls::CastStringToGeometry.into()),
+                CastGeometryToString(()) => Ok(impls::CastGeometryToString.into()),
+                CastBytesToGeometry(()) => Ok(impls::CastBytesToGeometry.into()),
+                CastGeometryToBytes(()) => Ok(impls::CastGeometryToBytes.into()),
+                StAsText(()) => Ok(impls::StAsText.into()),
+                StAsEwkt(()) => Ok(impls::StAsEwkt.into()),
+                StAsBinary(()) => Ok(impls::StAsBinary.into()),
+                StX(()) => Ok(impls::StX.into()),
+                StY(()) => Ok(impls::StY.into()),
+                StSrid(()) => Ok(impls::StSrid.into()),
+                StGeometryType(()) => Ok(impls::StGeometryType.into()),
+                StMakePolygon(()) => Ok(impls::StMakePolygon.into()),
             }
         } else {
             Err(TryFromProtoError::missing_field("ProtoUnaryFunc::kind"))
@@ -8131,6 +8590,19 @@ where
             None => Ok::<_, EvalError>(buf.write_null()),
         }),
         MzAclItem => Ok(strconv::format_mz_acl_item(buf, d.unwrap_mz_acl_item())),
+        Enum { labels, .. } => Ok(strconv::format_string(
+            buf,
+            &labels[usize::cast_from(d.unwrap_uint32())],
+        )),
+        TsVector | TsQuery => Ok(strconv::format_string(buf, d.unwrap_str())),
+        Geometry => Ok(strconv::format_geometry(buf, d.unwrap_bytes())),
+        Vector { .. } => Ok(strconv::format_vector(
+            buf,
+            d.unwrap_array()
+                .elements()
+                .iter()
+                .map(|d| d.unwrap_float32()),
+        )),
     }
 }
 
@@ -9042,6 +9514,23 @@ fn array_fill<'a>(
     })?)
 }
 
+/// Constructs the rectangular polygon spanning `xmin`, `ymin`, `xmax`, and
+/// `ymax`, with an optional SRID.
+fn st_make_envelope<'a>(
+    ds: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let srid = ds.get(4).map_or(0, |d| d.unwrap_int32());
+    let envelope = Geometry::envelope_polygon(
+        ds[0].unwrap_float64(),
+        ds[1].unwrap_float64(),
+        ds[2].unwrap_float64(),
+        ds[3].unwrap_float64(),
+        srid,
+    )?;
+    Ok(Datum::Bytes(temp_storage.push_bytes(envelope.to_ewkb())))
+}
+
 #[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
 pub enum VariadicFunc {
     Coalesce,
@@ -9106,6 +9595,8 @@ pub enum VariadicFunc {
     TimezoneTime,
     RegexpSplitToArray,
     RegexpReplace,
+    StMakeEnvelope,
+    WasmUdf(WasmUdf),
 }
 
 impl VariadicFunc {
@@ -9214,6 +9705,8 @@ impl VariadicFunc {
 
                 string_to_array(ds[0], ds[1], null_string, temp_storage)
             }
+            VariadicFunc::StMakeEnvelope => st_make_envelope(&ds, temp_storage),
+            VariadicFunc::WasmUdf(udf) => udf.eval(&ds, temp_storage),
         }
     }
 
@@ -9261,7 +9754,9 @@ impl VariadicFunc {
             | VariadicFunc::TimezoneTime
             | VariadicFunc::RegexpSplitToArray
             | VariadicFunc::StringToArray
-            | VariadicFunc::RegexpReplace => false,
+            | VariadicFunc::RegexpReplace
+            | VariadicFunc::StMakeEnvelope
+            | VariadicFunc::WasmUdf(_) => false,
         }
     }
 
@@ -9367,6 +9862,8 @@ impl VariadicFunc {
             }
             RegexpReplace => ScalarType::String.nullable(in_nullable),
             StringToArray => ScalarType::Array(Box::new(ScalarType::String)).nullable(true),
+            StMakeEnvelope => ScalarType::Geometry.nullable(in_nullable),
+            VariadicFunc::WasmUdf(udf) => udf.return_type.clone().nullable(true),
         }
     }
 
@@ -9442,7 +9939,8 @@ impl VariadicFunc {
             | ArrayFill { .. }
             | TimezoneTime
             | RegexpSplitToArray
-            | RegexpReplace => false,
+            | RegexpReplace
+            | StMakeEnvelope => false,
             Coalesce
             | Greatest
             | Least
@@ -9450,7 +9948,8 @@ impl VariadicFunc {
             | ArrayIndex { .. }
             | StringToArray
             | ListIndex
-            | RegexpMatch => true,
+            | RegexpMatch
+            | VariadicFunc::WasmUdf(_) => true,
         }
     }
 
@@ -9557,7 +10056,9 @@ impl VariadicFunc {
             | VariadicFunc::TimezoneTime
             | VariadicFunc::RegexpSplitToArray
             | VariadicFunc::StringToArray
-            | VariadicFunc::RegexpReplace => false,
+            | VariadicFunc::RegexpReplace
+            | VariadicFunc::StMakeEnvelope
+            | VariadicFunc::WasmUdf(_) => false,
         }
     }
 }
@@ -9616,6 +10117,8 @@ impl fmt::Display for VariadicFunc {
             VariadicFunc::RegexpSplitToArray => f.write_str("regexp_split_to_array"),
             VariadicFunc::RegexpReplace => f.write_str("regexp_replace"),
             VariadicFunc::StringToArray => f.write_str("string_to_array"),
+            VariadicFunc::StMakeEnvelope => f.write_str("st_makeenvelope"),
+            VariadicFunc::WasmUdf(udf) => f.write_str(&udf.name),
         }
     }
 }
@@ -9686,6 +10189,7 @@ impl Arbitrary for VariadicFunc {
             ScalarType::arbitrary()
                 .prop_map(|elem_type| VariadicFunc::ArrayFill { elem_type })
                 .boxed(),
+            Just(VariadicFunc::StMakeEnvelope).boxed(),
         ])
     }
 }
@@ -9693,7 +10197,7 @@ impl Arbitrary for VariadicFunc {
 impl RustType<ProtoVariadicFunc> for VariadicFunc {
     fn into_proto(&self) -> ProtoVariadicFunc {
         use crate::scalar::proto_variadic_func::Kind::*;
-        use crate::scalar::proto_variadic_func::ProtoRecordCreate;
+        use crate::scalar::proto_variadic_func::{ProtoRecordCreate, ProtoWasmUdf};
         let kind = match self {
             VariadicFunc::Coalesce => Coalesce(()),
             VariadicFunc::Greatest => Greatest(()),
@@ -9739,13 +10243,19 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
             VariadicFunc::RegexpSplitToArray => RegexpSplitToArray(()),
             VariadicFunc::RegexpReplace => RegexpReplace(()),
             VariadicFunc::StringToArray => StringToArray(()),
+            VariadicFunc::StMakeEnvelope => StMakeEnvelope(()),
+            VariadicFunc::WasmUdf(udf) => WasmUdf(ProtoWasmUdf {
+                name: udf.name.clone(),
+                module: udf.module.to_vec(),
+                return_type: Some(udf.return_type.into_proto()),
+            }),
         };
         ProtoVariadicFunc { kind: Some(kind) }
     }
 
     fn from_proto(proto: ProtoVariadicFunc) -> Result<Self, TryFromProtoError> {
         use crate::scalar::proto_variadic_func::Kind::*;
-        use crate::scalar::proto_variadic_func::ProtoRecordCreate;
+        use crate::scalar::proto_variadic_func::{ProtoRecordCreate, ProtoWasmUdf};
         if let Some(kind) = proto.kind {
             match kind {
                 Coalesce(()) => Ok(VariadicFunc::Coalesce),
@@ -9806,6 +10316,16 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
                 RegexpSplitToArray(()) => Ok(VariadicFunc::RegexpSplitToArray),
                 RegexpReplace(()) => Ok(VariadicFunc::RegexpReplace),
                 StringToArray(()) => Ok(VariadicFunc::StringToArray),
+                StMakeEnvelope(()) => Ok(VariadicFunc::StMakeEnvelope),
+                WasmUdf(ProtoWasmUdf {
+                    name,
+                    module,
+                    return_type,
+                }) => Ok(VariadicFunc::WasmUdf(self::WasmUdf::new(
+                    name,
+                    module.into(),
+                    return_type.into_rust_if_some("ProtoWasmUdf::return_type")?,
+                ))),
             }
         } else {
             Err(TryFromProtoError::missing_field(
@@ -13,8 +13,10 @@ mod byte;
 mod char;
 mod date;
 mod datum;
+mod enums;
 mod float32;
 mod float64;
+mod geometry;
 mod int16;
 mod int2vector;
 mod int32;
@@ -32,6 +34,7 @@ mod range;
 mod record;
 mod regproc;
 mod string;
+mod text_search;
 mod time;
 mod timestamp;
 mod uint16;
@@ -39,6 +42,7 @@ mod uint32;
 mod uint64;
 mod uuid;
 mod varchar;
+mod vector;
 
 pub use crate::scalar::func::impls::array::*;
 pub use crate::scalar::func::impls::boolean::*;
@@ -46,8 +50,10 @@ pub use crate::scalar::func::impls::byte::*;
 pub use crate::scalar::func::impls::char::*;
 pub use crate::scalar::func::impls::date::*;
 pub use crate::scalar::func::impls::datum::*;
+pub use crate::scalar::func::impls::enums::*;
 pub use crate::scalar::func::impls::float32::*;
 pub use crate::scalar::func::impls::float64::*;
+pub use crate::scalar::func::impls::geometry::*;
 pub use crate::scalar::func::impls::int2vector::*;
 pub use crate::scalar::func::impls::int16::*;
 pub use crate::scalar::func::impls::int32::*;
@@ -65,6 +71,7 @@ pub use crate::scalar::func::impls::range::*;
 pub use crate::scalar::func::impls::record::*;
 pub use crate::scalar::func::impls::regproc::*;
 pub use crate::scalar::func::impls::string::*;
+pub use crate::scalar::func::impls::text_search::*;
 pub use crate::scalar::func::impls::time::*;
 pub use crate::scalar::func::impls::timestamp::*;
 pub use crate::scalar::func::impls::uint16::*;
@@ -72,3 +79,4 @@ pub use crate::scalar::func::impls::uint32::*;
 pub use crate::scalar::func::impls::uint64::*;
 pub use crate::scalar::func::impls::uuid::*;
 pub use crate::scalar::func::impls::varchar::*;
+pub use crate::scalar::func::impls::vector::*;
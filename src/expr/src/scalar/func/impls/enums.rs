@@ -0,0 +1,102 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt;
+
+use mz_lowertest::MzReflect;
+use mz_ore::cast::CastFrom;
+use mz_repr::{ColumnType, ScalarType, strconv};
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+use crate::EvalError;
+use crate::scalar::func::EagerUnaryFunc;
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastStringToEnum {
+    // Target enum's type, which carries the enum's labels.
+    pub return_ty: ScalarType,
+}
+
+impl<'a> EagerUnaryFunc<'a> for CastStringToEnum {
+    type Input = &'a str;
+    type Output = Result<u32, EvalError>;
+
+    fn call(&self, a: &'a str) -> Result<u32, EvalError> {
+        match &self.return_ty {
+            ScalarType::Enum { labels, .. } => Ok(strconv::parse_enum(labels, a)?),
+            ty => Err(EvalError::Internal(
+                format!("CastStringToEnum called with non-enum type {ty:?}").into(),
+            )),
+        }
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        self.return_ty.clone().nullable(input.nullable)
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(CastEnumToString {
+            ty: self.return_ty.clone(),
+        })
+    }
+}
+
+impl fmt::Display for CastStringToEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("text_to_enum")
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastEnumToString {
+    // Source enum's type, which carries the enum's labels.
+    pub ty: ScalarType,
+}
+
+impl<'a> EagerUnaryFunc<'a> for CastEnumToString {
+    type Input = u32;
+    type Output = Result<String, EvalError>;
+
+    fn call(&self, a: u32) -> Result<String, EvalError> {
+        match &self.ty {
+            ScalarType::Enum { labels, .. } => labels
+                .get(usize::cast_from(a))
+                .cloned()
+                .ok_or_else(|| EvalError::Internal(format!("invalid enum value {a}").into())),
+            ty => Err(EvalError::Internal(
+                format!("CastEnumToString called with non-enum type {ty:?}").into(),
+            )),
+        }
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::String.nullable(input.nullable)
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(CastStringToEnum {
+            return_ty: self.ty.clone(),
+        })
+    }
+}
+
+impl fmt::Display for CastEnumToString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("enum_to_text")
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt;
+
+use mz_lowertest::MzReflect;
+use mz_repr::adt::geometry::{Geometry, Shape};
+use mz_repr::{ColumnType, ScalarType, strconv};
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+use crate::EvalError;
+use crate::scalar::func::EagerUnaryFunc;
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastStringToGeometry;
+
+impl<'a> EagerUnaryFunc<'a> for CastStringToGeometry {
+    type Input = &'a str;
+    type Output = Result<Vec<u8>, EvalError>;
+
+    fn call(&self, a: &'a str) -> Result<Vec<u8>, EvalError> {
+        Ok(strconv::parse_geometry(a)?.to_ewkb())
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::Geometry.nullable(input.nullable)
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(CastGeometryToString)
+    }
+}
+
+impl fmt::Display for CastStringToGeometry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("text_to_geometry")
+    }
+}
+
+sqlfunc!(
+    #[sqlname = "geometry_to_text"]
+    #[preserves_uniqueness = true]
+    #[inverse = to_unary!(super::CastStringToGeometry)]
+    fn cast_geometry_to_string(a: &'a [u8]) -> String {
+        let mut buf = String::new();
+        strconv::format_geometry(&mut buf, a);
+        buf
+    }
+);
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastBytesToGeometry;
+
+impl<'a> EagerUnaryFunc<'a> for CastBytesToGeometry {
+    type Input = &'a [u8];
+    type Output = Result<Vec<u8>, EvalError>;
+
+    fn call(&self, a: &'a [u8]) -> Result<Vec<u8>, EvalError> {
+        // Round trip through the parsed geometry to validate the input and to
+        // canonicalize its byte order.
+        Ok(Geometry::from_ewkb(a)?.to_ewkb())
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::Geometry.nullable(input.nullable)
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(CastGeometryToBytes)
+    }
+}
+
+impl fmt::Display for CastBytesToGeometry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("bytea_to_geometry")
+    }
+}
+
+sqlfunc!(
+    #[sqlname = "geometry_to_bytea"]
+    #[preserves_uniqueness = true]
+    #[inverse = to_unary!(super::CastBytesToGeometry)]
+    fn cast_geometry_to_bytes(a: &'a [u8]) -> Vec<u8> {
+        // Geometries are stored as canonical EWKB.
+        a.to_vec()
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "st_astext"]
+    fn st_as_text(a: &'a [u8]) -> Result<String, EvalError> {
+        Ok(Geometry::from_ewkb(a)?.to_string())
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "st_asewkt"]
+    #[preserves_uniqueness = true]
+    fn st_as_ewkt(a: &'a [u8]) -> Result<String, EvalError> {
+        Ok(Geometry::from_ewkb(a)?.to_ewkt())
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "st_asbinary"]
+    fn st_as_binary(a: &'a [u8]) -> Result<Vec<u8>, EvalError> {
+        Ok(Geometry::from_ewkb(a)?.to_wkb())
+    }
+);
+
+/// Returns the coordinates of `a`, which must be a point.
+fn point_coords(a: &[u8], func: &str) -> Result<(f64, f64), EvalError> {
+    match Geometry::from_ewkb(a)?.shape {
+        Shape::Point(c) => Ok((c.x, c.y)),
+        _ => Err(EvalError::InvalidParameterValue(
+            format!("argument to {func}() must have type POINT").into(),
+        )),
+    }
+}
+
+sqlfunc!(
+    #[sqlname = "st_x"]
+    fn st_x(a: &'a [u8]) -> Result<f64, EvalError> {
+        Ok(point_coords(a, "ST_X")?.0)
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "st_y"]
+    fn st_y(a: &'a [u8]) -> Result<f64, EvalError> {
+        Ok(point_coords(a, "ST_Y")?.1)
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "st_srid"]
+    fn st_srid(a: &'a [u8]) -> Result<i32, EvalError> {
+        Ok(Geometry::from_ewkb(a)?.srid)
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "st_geometrytype"]
+    fn st_geometry_type(a: &'a [u8]) -> Result<String, EvalError> {
+        Ok(format!("ST_{}", Geometry::from_ewkb(a)?.shape.type_name()))
+    }
+);
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct StMakePolygon;
+
+impl<'a> EagerUnaryFunc<'a> for StMakePolygon {
+    type Input = &'a [u8];
+    type Output = Result<Vec<u8>, EvalError>;
+
+    fn call(&self, a: &'a [u8]) -> Result<Vec<u8>, EvalError> {
+        let shell = Geometry::from_ewkb(a)?;
+        Ok(Geometry::make_polygon(&shell)?.to_ewkb())
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::Geometry.nullable(input.nullable)
+    }
+}
+
+impl fmt::Display for StMakePolygon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("st_makepolygon")
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt;
+
+use mz_lowertest::MzReflect;
+use mz_repr::{ArrayRustType, ColumnType, ScalarType, strconv};
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+use crate::EvalError;
+use crate::scalar::func::EagerUnaryFunc;
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastStringToTsVector;
+
+impl<'a> EagerUnaryFunc<'a> for CastStringToTsVector {
+    type Input = &'a str;
+    type Output = Result<String, EvalError>;
+
+    fn call(&self, a: &'a str) -> Result<String, EvalError> {
+        Ok(strconv::parse_tsvector(a)?.to_string())
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::TsVector.nullable(input.nullable)
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(CastTsVectorToString)
+    }
+}
+
+impl fmt::Display for CastStringToTsVector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("text_to_tsvector")
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastTsVectorToString;
+
+impl<'a> EagerUnaryFunc<'a> for CastTsVectorToString {
+    type Input = &'a str;
+    type Output = &'a str;
+
+    fn call(&self, a: &'a str) -> &'a str {
+        // tsvectors are stored in their canonical text form.
+        a
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::String.nullable(input.nullable)
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(CastStringToTsVector)
+    }
+}
+
+impl fmt::Display for CastTsVectorToString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("tsvector_to_text")
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastStringToTsQuery;
+
+impl<'a> EagerUnaryFunc<'a> for CastStringToTsQuery {
+    type Input = &'a str;
+    type Output = Result<String, EvalError>;
+
+    fn call(&self, a: &'a str) -> Result<String, EvalError> {
+        Ok(strconv::parse_tsquery(a)?.to_string())
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::TsQuery.nullable(input.nullable)
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(CastTsQueryToString)
+    }
+}
+
+impl fmt::Display for CastStringToTsQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("text_to_tsquery")
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastTsQueryToString;
+
+impl<'a> EagerUnaryFunc<'a> for CastTsQueryToString {
+    type Input = &'a str;
+    type Output = &'a str;
+
+    fn call(&self, a: &'a str) -> &'a str {
+        // tsqueries are stored in their canonical text form.
+        a
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::String.nullable(input.nullable)
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(CastStringToTsQuery)
+    }
+}
+
+impl fmt::Display for CastTsQueryToString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("tsquery_to_text")
+    }
+}
+
+/// Returns the lexemes of a tsvector, without their positions.
+///
+/// Unnesting the result is how a lexeme index (`CREATE INDEX ... USING LEXEME`)
+/// arranges the rows of a collection by the lexemes of their document.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct TsVectorToArray;
+
+impl<'a> EagerUnaryFunc<'a> for TsVectorToArray {
+    type Input = &'a str;
+    type Output = Result<ArrayRustType<String>, EvalError>;
+
+    fn call(&self, a: &'a str) -> Result<ArrayRustType<String>, EvalError> {
+        let tsvector = strconv::parse_tsvector(a)?;
+        Ok(ArrayRustType(
+            tsvector
+                .lexemes()
+                .iter()
+                .map(|lexeme| lexeme.word.clone())
+                .collect(),
+        ))
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::Array(Box::new(ScalarType::String)).nullable(input.nullable)
+    }
+}
+
+impl fmt::Display for TsVectorToArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("tsvector_to_array")
+    }
+}
@@ -0,0 +1,530 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt;
+
+use mz_lowertest::MzReflect;
+use mz_ore::cast::CastFrom;
+use mz_repr::adt::vector::{self, MAX_DIMENSIONS, VectorDimensions};
+use mz_repr::{ColumnType, Datum, RowArena, ScalarType, strconv};
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+use crate::scalar::func::{LazyUnaryFunc, array_create_scalar, stringify_datum};
+use crate::{EvalError, MirScalarExpr};
+
+/// Returns an error if a vector with `len` elements does not satisfy the
+/// `dimensions` of its target type.
+fn check_dimensions(dimensions: Option<VectorDimensions>, len: usize) -> Result<(), EvalError> {
+    match dimensions {
+        Some(dimensions) => Ok(dimensions.check(len)?),
+        None => Ok(()),
+    }
+}
+
+/// Packs `elems` into a vector datum.
+fn vector_create<'a>(elems: &[f32], temp_storage: &'a RowArena) -> Result<Datum<'a>, EvalError> {
+    let datums: Vec<_> = elems.iter().map(|e| Datum::from(*e)).collect();
+    array_create_scalar(&datums, temp_storage)
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastStringToVector {
+    pub dimensions: Option<VectorDimensions>,
+}
+
+impl LazyUnaryFunc for CastStringToVector {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let elems = strconv::parse_vector(a.unwrap_str())?;
+        check_dimensions(self.dimensions, elems.len())?;
+        vector_create(&elems, temp_storage)
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::Vector {
+            dimensions: self.dimensions,
+        }
+        .nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastVectorToString)
+    }
+
+    fn is_monotone(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for CastStringToVector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.dimensions {
+            Some(dimensions) => write!(f, "text_to_vector[dims={}]", dimensions.into_u32()),
+            None => f.write_str("text_to_vector"),
+        }
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastVectorToString;
+
+impl LazyUnaryFunc for CastVectorToString {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let mut buf = String::new();
+        stringify_datum(&mut buf, a, &ScalarType::Vector { dimensions: None })?;
+        Ok(Datum::String(temp_storage.push_string(buf)))
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::String.nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastStringToVector { dimensions: None })
+    }
+
+    fn is_monotone(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for CastVectorToString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("vector_to_text")
+    }
+}
+
+/// Applies the dimensions of the target type to a vector.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastVectorToVector {
+    pub dimensions: Option<VectorDimensions>,
+}
+
+impl LazyUnaryFunc for CastVectorToVector {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        check_dimensions(self.dimensions, a.unwrap_array().elements().iter().count())?;
+        Ok(a)
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::Vector {
+            dimensions: self.dimensions,
+        }
+        .nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn could_error(&self) -> bool {
+        self.dimensions.is_some()
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        None
+    }
+
+    fn is_monotone(&self) -> bool {
+        true // A noop is trivially monotone.
+    }
+}
+
+impl fmt::Display for CastVectorToVector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.dimensions {
+            Some(dimensions) => write!(f, "vector_to_vector[dims={}]", dimensions.into_u32()),
+            None => f.write_str("vector_to_vector"),
+        }
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastArrayToVector {
+    pub dimensions: Option<VectorDimensions>,
+}
+
+impl LazyUnaryFunc for CastArrayToVector {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let array = a.unwrap_array();
+        if array.dims().len() > 1 {
+            return Err(EvalError::InvalidParameterValue("array must be 1-D".into()));
+        }
+        let mut elems = Vec::new();
+        for elem in array.elements().iter() {
+            if elem.is_null() {
+                return Err(EvalError::InvalidParameterValue(
+                    "array must not contain nulls".into(),
+                ));
+            }
+            let elem = elem.unwrap_float32();
+            if !elem.is_finite() {
+                return Err(EvalError::InvalidParameterValue(
+                    "vector elements must be finite".into(),
+                ));
+            }
+            elems.push(elem);
+        }
+        if elems.is_empty() {
+            return Err(EvalError::InvalidParameterValue(
+                "vector must have at least 1 dimension".into(),
+            ));
+        }
+        if elems.len() > usize::cast_from(MAX_DIMENSIONS) {
+            return Err(EvalError::InvalidParameterValue(
+                format!("vector cannot have more than {MAX_DIMENSIONS} dimensions").into(),
+            ));
+        }
+        check_dimensions(self.dimensions, elems.len())?;
+        vector_create(&elems, temp_storage)
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::Vector {
+            dimensions: self.dimensions,
+        }
+        .nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastVectorToArray)
+    }
+
+    fn is_monotone(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for CastArrayToVector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.dimensions {
+            Some(dimensions) => write!(f, "array_to_vector[dims={}]", dimensions.into_u32()),
+            None => f.write_str("array_to_vector"),
+        }
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastVectorToArray;
+
+impl LazyUnaryFunc for CastVectorToArray {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        // Vectors are represented as one-dimensional arrays.
+        a.eval(datums, temp_storage)
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::Array(Box::new(ScalarType::Float32)).nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn could_error(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastArrayToVector { dimensions: None })
+    }
+
+    fn is_monotone(&self) -> bool {
+        true // A noop is trivially monotone.
+    }
+}
+
+impl fmt::Display for CastVectorToArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("vector_to_array")
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct VectorDims;
+
+impl LazyUnaryFunc for VectorDims {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let dims = a.unwrap_array().elements().iter().count();
+        // Vectors have at most `MAX_DIMENSIONS` elements.
+        Ok(Datum::Int32(
+            i32::try_from(dims).expect("vector dimensions fit"),
+        ))
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::Int32.nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn could_error(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        None
+    }
+
+    fn is_monotone(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for VectorDims {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("vector_dims")
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct VectorNorm;
+
+impl LazyUnaryFunc for VectorNorm {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let elems: Vec<f32> = a
+            .unwrap_array()
+            .elements()
+            .iter()
+            .map(|d| d.unwrap_float32())
+            .collect();
+        Ok(Datum::from(vector::norm(&elems)))
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::Float64.nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn could_error(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        None
+    }
+
+    fn is_monotone(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for VectorNorm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("vector_norm")
+    }
+}
+
+/// Returns the approximate nearest-neighbor bucket of a vector, which ANN
+/// indexes arrange their rows by.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct VectorAnnBucket;
+
+impl LazyUnaryFunc for VectorAnnBucket {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let elems: Vec<f32> = a
+            .unwrap_array()
+            .elements()
+            .iter()
+            .map(|d| d.unwrap_float32())
+            .collect();
+        Ok(Datum::Int32(vector::ann_bucket(&elems)))
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::Int32.nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn could_error(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        None
+    }
+
+    fn is_monotone(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for VectorAnnBucket {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("vector_ann_bucket")
+    }
+}
@@ -0,0 +1,426 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Text search configurations, which turn documents and query operands into
+//! lexemes.
+//!
+//! Unlike PostgreSQL, configurations are not catalog objects. The built-in
+//! `simple` and `english` configurations are hardcoded, and are referred to by
+//! name.
+
+use mz_repr::adt::text_search::{
+    Lexeme, LexemePosition, MAX_LEXEME_LENGTH, MAX_POSITION, MAX_QUERY_DEPTH, TsQuery, TsQueryNode,
+    TsVector, Weight,
+};
+use mz_repr::strconv;
+use rust_stemmers::{Algorithm, Stemmer};
+use uncased::UncasedStr;
+
+use crate::EvalError;
+
+/// A text search configuration.
+pub trait Config {
+    /// Normalizes a single token into a lexeme, or returns `None` if the token
+    /// is a stop word.
+    fn normalize(&self, token: &str) -> Option<String>;
+
+    /// Converts a document into a [`TsVector`].
+    fn to_tsvector(&self, document: &str) -> TsVector {
+        let mut lexemes = vec![];
+        for (i, token) in tokenize(document).enumerate() {
+            // Positions count every token, including stop words, so that
+            // phrase queries account for the words between lexemes.
+            let position = u16::try_from(i + 1)
+                .unwrap_or(MAX_POSITION)
+                .min(MAX_POSITION);
+            if let Some(word) = self.normalize(&token) {
+                if word.len() > MAX_LEXEME_LENGTH {
+                    continue;
+                }
+                lexemes.push(Lexeme {
+                    word,
+                    positions: vec![LexemePosition {
+                        position,
+                        weight: Weight::D,
+                    }],
+                });
+            }
+        }
+        TsVector::new(lexemes)
+    }
+
+    /// Parses `query` as a [`TsQuery`] and normalizes its operands.
+    ///
+    /// Operands that normalize to multiple lexemes match the lexemes as a
+    /// phrase. Operands that are stop words are removed from the query.
+    fn to_tsquery(&self, query: &str) -> Result<TsQuery, EvalError> {
+        let query = strconv::parse_tsquery(query)?;
+        let root = query
+            .root
+            .and_then(|root| normalize_node(self, root).map(|(node, _)| node));
+        // Operands that turn into phrases make the query deeper.
+        if root
+            .as_ref()
+            .is_some_and(|root| root.depth() > MAX_QUERY_DEPTH)
+        {
+            return Err(EvalError::InvalidParameterValue(
+                format!("tsquery cannot be nested more than {MAX_QUERY_DEPTH} levels deep").into(),
+            ));
+        }
+        Ok(TsQuery { root })
+    }
+}
+
+/// Normalizes the operands of `node`.
+///
+/// Returns `None` if the node consists only of stop words. Otherwise, returns
+/// the normalized node along with the distance covered by stop words removed
+/// from the end of the node, which is folded into the distance of an
+/// enclosing phrase operator.
+fn normalize_node<C: Config + ?Sized>(config: &C, node: TsQueryNode) -> Option<(TsQueryNode, u16)> {
+    match node {
+        TsQueryNode::Lexeme {
+            word,
+            prefix,
+            weights,
+        } => {
+            let lexemes: Vec<_> = tokenize(&word)
+                .filter_map(|token| config.normalize(&token))
+                .map(|word| TsQueryNode::Lexeme {
+                    word,
+                    prefix,
+                    weights: weights.clone(),
+                })
+                .collect();
+            lexemes
+                .into_iter()
+                .reduce(|left, right| TsQueryNode::Phrase {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    distance: 1,
+                })
+                .map(|node| (node, 0))
+        }
+        TsQueryNode::Not(node) => {
+            normalize_node(config, *node).map(|(node, _)| (TsQueryNode::Not(Box::new(node)), 0))
+        }
+        TsQueryNode::And(left, right) => {
+            match (
+                normalize_node(config, *left),
+                normalize_node(config, *right),
+            ) {
+                (Some((left, _)), Some((right, _))) => {
+                    Some((TsQueryNode::And(Box::new(left), Box::new(right)), 0))
+                }
+                (Some((node, _)), None) | (None, Some((node, _))) => Some((node, 0)),
+                (None, None) => None,
+            }
+        }
+        TsQueryNode::Or(left, right) => {
+            match (
+                normalize_node(config, *left),
+                normalize_node(config, *right),
+            ) {
+                (Some((left, _)), Some((right, _))) => {
+                    Some((TsQueryNode::Or(Box::new(left), Box::new(right)), 0))
+                }
+                (Some((node, _)), None) | (None, Some((node, _))) => Some((node, 0)),
+                (None, None) => None,
+            }
+        }
+        TsQueryNode::Phrase {
+            left,
+            right,
+            distance,
+        } => match (
+            normalize_node(config, *left),
+            normalize_node(config, *right),
+        ) {
+            (Some((left, skipped)), Some((right, trailing))) => Some((
+                TsQueryNode::Phrase {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    distance: distance.saturating_add(skipped).min(MAX_POSITION),
+                },
+                trailing,
+            )),
+            (Some((left, skipped)), None) => Some((left, skipped.saturating_add(distance))),
+            (None, Some((right, trailing))) => Some((right, trailing)),
+            (None, None) => None,
+        },
+    }
+}
+
+/// Splits `s` into lowercase tokens at every character that is not
+/// alphanumeric.
+fn tokenize(s: &str) -> impl Iterator<Item = String> + '_ {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+}
+
+/// The `simple` configuration, which lowercases tokens and has no stop words.
+struct SimpleConfig;
+
+impl Config for SimpleConfig {
+    fn normalize(&self, token: &str) -> Option<String> {
+        Some(token.to_owned())
+    }
+}
+
+/// The `english` configuration, which drops English stop words and reduces
+/// words to their stems with the Snowball English (Porter2) stemmer.
+struct EnglishConfig;
+
+impl Config for EnglishConfig {
+    fn normalize(&self, token: &str) -> Option<String> {
+        if ENGLISH_STOP_WORDS.binary_search(&token).is_ok() {
+            return None;
+        }
+        // Like PostgreSQL, only stem words made of ASCII letters, and leave
+        // numbers and other words as is.
+        if token.bytes().all(|b| b.is_ascii_lowercase()) {
+            Some(english_stem(token))
+        } else {
+            Some(token.to_owned())
+        }
+    }
+}
+
+/// Returns the text search configuration named `s`.
+pub fn lookup_config(s: &str) -> Result<&'static dyn Config, EvalError> {
+    let name = UncasedStr::new(s.strip_prefix("pg_catalog.").unwrap_or(s));
+    if name == "simple" {
+        Ok(&SimpleConfig)
+    } else if name == "english" {
+        Ok(&EnglishConfig)
+    } else {
+        Err(EvalError::InvalidParameterValue(
+            format!("text search configuration \"{s}\" does not exist").into(),
+        ))
+    }
+}
+
+/// The stop words of the `english` configuration, from PostgreSQL's
+/// `english.stop`. Must be kept sorted.
+const ENGLISH_STOP_WORDS: &[&str] = &[
+    "a",
+    "about",
+    "above",
+    "after",
+    "again",
+    "against",
+    "all",
+    "am",
+    "an",
+    "and",
+    "any",
+    "are",
+    "as",
+    "at",
+    "be",
+    "because",
+    "been",
+    "before",
+    "being",
+    "below",
+    "between",
+    "both",
+    "but",
+    "by",
+    "can",
+    "did",
+    "do",
+    "does",
+    "doing",
+    "don",
+    "down",
+    "during",
+    "each",
+    "few",
+    "for",
+    "from",
+    "further",
+    "had",
+    "has",
+    "have",
+    "having",
+    "he",
+    "her",
+    "here",
+    "hers",
+    "herself",
+    "him",
+    "himself",
+    "his",
+    "how",
+    "i",
+    "if",
+    "in",
+    "into",
+    "is",
+    "it",
+    "its",
+    "itself",
+    "just",
+    "me",
+    "more",
+    "most",
+    "my",
+    "myself",
+    "no",
+    "nor",
+    "not",
+    "now",
+    "of",
+    "off",
+    "on",
+    "once",
+    "only",
+    "or",
+    "other",
+    "our",
+    "ours",
+    "ourselves",
+    "out",
+    "over",
+    "own",
+    "s",
+    "same",
+    "she",
+    "should",
+    "so",
+    "some",
+    "such",
+    "t",
+    "than",
+    "that",
+    "the",
+    "their",
+    "theirs",
+    "them",
+    "themselves",
+    "then",
+    "there",
+    "these",
+    "they",
+    "this",
+    "those",
+    "through",
+    "to",
+    "too",
+    "under",
+    "until",
+    "up",
+    "very",
+    "was",
+    "we",
+    "were",
+    "what",
+    "when",
+    "where",
+    "which",
+    "while",
+    "who",
+    "whom",
+    "why",
+    "will",
+    "with",
+    "you",
+    "your",
+    "yours",
+    "yourself",
+    "yourselves",
+];
+
+/// Reduces an English word, made of lowercase ASCII letters, to its stem with
+/// the Snowball English (Porter2) stemmer, which is the algorithm behind
+/// PostgreSQL's `english_stem` dictionary.
+fn english_stem(word: &str) -> String {
+    Stemmer::create(Algorithm::English).stem(word).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_english_stem() {
+        for (word, stem) in [
+            ("cats", "cat"),
+            ("rats", "rat"),
+            ("running", "run"),
+            ("jumps", "jump"),
+            ("quickly", "quick"),
+            ("ponies", "poni"),
+            ("ties", "tie"),
+            ("caresses", "caress"),
+            ("hopping", "hop"),
+            ("hoping", "hope"),
+            ("generously", "generous"),
+            ("relational", "relat"),
+            ("conditional", "condit"),
+            ("happiness", "happi"),
+            ("searching", "search"),
+            ("supernovae", "supernova"),
+            ("feed", "feed"),
+            ("agreed", "agre"),
+            ("skies", "sky"),
+            ("succeeding", "succeed"),
+            ("controlling", "control"),
+            ("gas", "gas"),
+            ("gaps", "gap"),
+            ("by", "by"),
+            ("cry", "cri"),
+            ("say", "say"),
+        ] {
+            assert_eq!(english_stem(word), stem, "word: {word}");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_stop_words_sorted() {
+        assert!(ENGLISH_STOP_WORDS.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[mz_ore::test]
+    fn test_to_tsvector() {
+        let config = lookup_config("english").unwrap();
+        assert_eq!(
+            config
+                .to_tsvector("The Fat Rats ate the fat cats")
+                .to_string(),
+            "'ate':4 'cat':7 'fat':2,6 'rat':3"
+        );
+        let config = lookup_config("pg_catalog.simple").unwrap();
+        assert_eq!(
+            config.to_tsvector("The Fat Rats").to_string(),
+            "'fat':2 'rats':3 'the':1"
+        );
+        assert!(lookup_config("klingon").is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_to_tsquery() {
+        let config = lookup_config("english").unwrap();
+        for (query, expected) in [
+            ("Fat & Rats", "'fat' & 'rat'"),
+            ("the & cats", "'cat'"),
+            ("fat <-> the <-> cats", "'fat' <2> 'cat'"),
+            ("the", ""),
+            ("super-novae:*", "'super':* <-> 'nova':*"),
+        ] {
+            assert_eq!(
+                config.to_tsquery(query).unwrap().to_string(),
+                expected,
+                "query: {query}"
+            );
+        }
+    }
+}
@@ -0,0 +1,564 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Scalar functions implemented by user-supplied WebAssembly modules.
+//!
+//! A module must export a memory named `memory` and the following functions:
+//!
+//!   * `mz_alloc(len: i32) -> i32`, which returns a pointer to `len` bytes of
+//!     writable memory.
+//!   * `mz_udf(ptr: i32, len: i32) -> i64`, which evaluates the function on
+//!     the arguments encoded at `ptr` and returns the location of the encoded
+//!     result, packed as `ptr << 32 | len`.
+//!
+//! Arguments are encoded back to back, in order, without any framing. A
+//! result is encoded as a tag byte followed by a payload: `0` for `NULL` (no
+//! payload), `1` for a value (the encoded value), or `2` for an error (a UTF-8
+//! error message spanning the rest of the result). Values are encoded as
+//! follows, with all integers in little-endian byte order:
+//!
+//!   * `bool`: one byte, `0` or `1`.
+//!   * `smallint`, `integer`, `bigint`: two, four, or eight bytes.
+//!   * `real`, `double precision`: the IEEE 754 bits, four or eight bytes.
+//!   * `text`, `bytea`: a four-byte length, followed by that many bytes.
+//!     `text` must be valid UTF-8.
+//!
+//! Functions are strict: if any argument is `NULL`, the result is `NULL` and
+//! the module is not called.
+//!
+//! Modules run in an interpreter with no imports available, a bounded memory,
+//! and a bounded amount of fuel per call. Every call runs in a fresh instance
+//! of the module, so nothing a call leaves behind in the module's memory or
+//! globals is visible to later calls. A result therefore only depends on the
+//! module and the arguments, and not on which rows the same operator, worker,
+//! or replica evaluated before.
+//!
+//! Compiled modules are cached per process, which on clusterd means per
+//! replica, and every copy of a [`WasmUdf`] keeps a handle to its compiled
+//! module, so instantiating a module for a call does not recompile it.
+
+use std::num::NonZeroUsize;
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
+
+use mz_lowertest::MzReflect;
+use mz_ore::cast::{CastFrom, ReinterpretCast};
+use mz_repr::{Datum, RowArena, ScalarType};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::EvalError;
+
+/// The amount of fuel available to a single call. Roughly, one unit of fuel
+/// is consumed per executed instruction.
+pub const WASM_UDF_FUEL_LIMIT: u64 = 10_000_000;
+
+/// The maximum size of the linear memory of a module, in bytes.
+pub const WASM_UDF_MAX_MEMORY_BYTES: usize = 64 << 20;
+
+/// The maximum number of compiled modules kept in the per-process cache.
+const MAX_CACHED_MODULES: NonZeroUsize = NonZeroUsize::new(128).expect("non-zero");
+
+const MEMORY_EXPORT: &str = "memory";
+const ALLOC_EXPORT: &str = "mz_alloc";
+const ENTRY_EXPORT: &str = "mz_udf";
+
+const RESULT_NULL: u8 = 0;
+const RESULT_VALUE: u8 = 1;
+const RESULT_ERROR: u8 = 2;
+
+/// The engine that compiles and runs all modules. Fuel metering is what
+/// bounds the running time of a call.
+static ENGINE: LazyLock<wasmi::Engine> = LazyLock::new(|| {
+    let mut config = wasmi::Config::default();
+    config.consume_fuel(true);
+    wasmi::Engine::new(&config)
+});
+
+/// Compiled modules, keyed by the SHA-256 digest of their bytes, evicting the
+/// least recently used module when full.
+///
+/// This is only consulted the first time a copy of a function is called, not
+/// once per call.
+static MODULES: LazyLock<Mutex<lru::LruCache<[u8; 32], wasmi::Module>>> =
+    LazyLock::new(|| Mutex::new(lru::LruCache::new(MAX_CACHED_MODULES)));
+
+/// A scalar function implemented by a WebAssembly module.
+///
+/// The module is carried along with the function, so that it travels to
+/// clusterd inside of the dataflow description of any dataflow that calls
+/// the function.
+#[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
+pub struct WasmUdf {
+    /// The fully qualified name of the function.
+    pub name: String,
+    /// The binary WebAssembly module.
+    pub module: Arc<[u8]>,
+    /// The SHA-256 digest of `module`.
+    digest: [u8; 32],
+    /// The type of the values returned by the function.
+    pub return_type: ScalarType,
+    /// The compiled module used to evaluate this copy of the function.
+    #[serde(skip)]
+    #[mzreflect(ignore)]
+    compiled: CompiledSlot,
+}
+
+impl WasmUdf {
+    /// Constructs a new function from its module, without validating the
+    /// module. See [`WasmUdf::validate`].
+    pub fn new(name: String, module: Arc<[u8]>, return_type: ScalarType) -> WasmUdf {
+        let digest = Sha256::digest(&module).into();
+        WasmUdf {
+            name,
+            module,
+            digest,
+            return_type,
+            compiled: CompiledSlot::default(),
+        }
+    }
+
+    /// Reports whether values of type `typ` can be passed to or returned from
+    /// a WebAssembly function.
+    pub fn supports_type(typ: &ScalarType) -> bool {
+        matches!(
+            typ,
+            ScalarType::Bool
+                | ScalarType::Int16
+                | ScalarType::Int32
+                | ScalarType::Int64
+                | ScalarType::Float32
+                | ScalarType::Float64
+                | ScalarType::String
+                | ScalarType::Bytes
+        )
+    }
+
+    /// Checks that the module compiles, has no imports, and exports the
+    /// memory and functions that the ABI requires.
+    pub fn validate(&self) -> Result<(), String> {
+        self.instantiate()?;
+        Ok(())
+    }
+
+    /// Evaluates the function on the specified non-`NULL` arguments.
+    pub fn eval<'a>(
+        &self,
+        args: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+    ) -> Result<Datum<'a>, EvalError> {
+        let mut encoded = Vec::new();
+        for arg in args {
+            encode_datum(*arg, &mut encoded).map_err(|e| self.error(e))?;
+        }
+        let result = self.call(&encoded).map_err(|e| self.error(e))?;
+        match result.split_first() {
+            Some((&RESULT_NULL, [])) => Ok(Datum::Null),
+            Some((&RESULT_VALUE, value)) => {
+                decode_datum(value, &self.return_type, temp_storage).map_err(|e| self.error(e))
+            }
+            Some((&RESULT_ERROR, message)) => Err(self.error(String::from_utf8_lossy(message))),
+            _ => Err(self.error("function returned a malformed result")),
+        }
+    }
+
+    fn error(&self, message: impl ToString) -> EvalError {
+        EvalError::WasmUdfError {
+            function: self.name.clone().into(),
+            message: message.to_string().into(),
+        }
+    }
+
+    /// Calls the module's entry point on the encoded arguments, in a fresh
+    /// instance, and returns the encoded result.
+    ///
+    /// Reusing an instance across calls would let state that a call leaves in
+    /// the module's memory or globals, like the position of a bump allocator,
+    /// affect later calls. Whether a row then succeeds or traps would depend
+    /// on the rows evaluated before it, which differ between replicas and
+    /// between the insertion and the retraction of the same row.
+    fn call(&self, args: &[u8]) -> Result<Vec<u8>, String> {
+        self.instantiate()?.call(args)
+    }
+
+    /// Instantiates a fresh copy of the module in a new store.
+    fn instantiate(&self) -> Result<Sandbox, String> {
+        let module = match self.compiled.0.get() {
+            Some(module) => module.clone(),
+            None => {
+                let module = self.compile().map_err(|e| e.to_string())?;
+                // Racing initializations of the slot store equivalent modules.
+                let _ = self.compiled.0.set(module.clone());
+                module
+            }
+        };
+        Sandbox::new(&module)
+    }
+
+    /// Returns the compiled module, compiling it if it is not already cached.
+    fn compile(&self) -> Result<wasmi::Module, wasmi::Error> {
+        if let Some(module) = MODULES.lock().expect("lock poisoned").get(&self.digest) {
+            return Ok(module.clone());
+        }
+        // Compile without holding the lock, so that a large module doesn't
+        // stall every other operator that is creating an instance. Racing
+        // compilations of the same module produce equivalent results.
+        let module = wasmi::Module::new(&ENGINE, &self.module[..])?;
+        MODULES
+            .lock()
+            .expect("lock poisoned")
+            .put(self.digest, module.clone());
+        Ok(module)
+    }
+}
+
+/// The compiled module of one copy of a [`WasmUdf`], if it has been called.
+///
+/// This is not part of the identity of the function: it compares, hashes,
+/// and serializes as nothing. A compiled module holds no state of any
+/// instance, so clones share it.
+#[derive(Clone, Default)]
+struct CompiledSlot(OnceLock<wasmi::Module>);
+
+impl std::fmt::Debug for CompiledSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CompiledSlot")
+    }
+}
+
+impl PartialEq for CompiledSlot {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CompiledSlot {}
+
+impl PartialOrd for CompiledSlot {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CompiledSlot {
+    fn cmp(&self, _: &Self) -> std::cmp::Ordering {
+        std::cmp::Ordering::Equal
+    }
+}
+
+impl std::hash::Hash for CompiledSlot {
+    fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+}
+
+/// An instantiated module, the store that owns its state, and the exports
+/// required by the ABI.
+struct Sandbox {
+    store: wasmi::Store<wasmi::StoreLimits>,
+    memory: wasmi::Memory,
+    alloc: wasmi::TypedFunc<i32, i32>,
+    entry: wasmi::TypedFunc<(i32, i32), i64>,
+}
+
+impl Sandbox {
+    /// Instantiates `module` in a new store, and looks up the exports required
+    /// by the ABI.
+    fn new(module: &wasmi::Module) -> Result<Sandbox, String> {
+        let (store, instance) = Self::instantiate(module).map_err(|e| e.to_string())?;
+        let memory = instance
+            .get_memory(&store, MEMORY_EXPORT)
+            .ok_or_else(|| format!("module does not export a memory named {MEMORY_EXPORT}"))?;
+        let alloc = instance
+            .get_typed_func(&store, ALLOC_EXPORT)
+            .map_err(|e| format!("module does not export {ALLOC_EXPORT}(i32) -> i32: {e}"))?;
+        let entry = instance
+            .get_typed_func(&store, ENTRY_EXPORT)
+            .map_err(|e| format!("module does not export {ENTRY_EXPORT}(i32, i32) -> i64: {e}"))?;
+        Ok(Sandbox {
+            store,
+            memory,
+            alloc,
+            entry,
+        })
+    }
+
+    fn instantiate(
+        module: &wasmi::Module,
+    ) -> Result<(wasmi::Store<wasmi::StoreLimits>, wasmi::Instance), wasmi::Error> {
+        let limits = wasmi::StoreLimitsBuilder::new()
+            .memory_size(WASM_UDF_MAX_MEMORY_BYTES)
+            .instances(1)
+            .build();
+        let mut store = wasmi::Store::new(&ENGINE, limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(WASM_UDF_FUEL_LIMIT)?;
+        // No imports are linked, so the module has no way to observe or
+        // affect anything outside of its own memory.
+        let linker = wasmi::Linker::new(&ENGINE);
+        let instance = linker.instantiate(&mut store, module)?.start(&mut store)?;
+        Ok((store, instance))
+    }
+
+    /// Calls the entry point on the encoded arguments, with a full tank of
+    /// fuel, and returns the encoded result.
+    ///
+    /// Consumes the instance, which must not observe more than one call.
+    fn call(mut self, args: &[u8]) -> Result<Vec<u8>, String> {
+        let Sandbox {
+            store,
+            memory,
+            alloc,
+            entry,
+        } = &mut self;
+        store
+            .set_fuel(WASM_UDF_FUEL_LIMIT)
+            .map_err(|e| e.to_string())?;
+
+        let trap = |e: wasmi::Error| match e.as_trap_code() {
+            Some(wasmi::core::TrapCode::OutOfFuel) => {
+                format!("exceeded the fuel limit of {WASM_UDF_FUEL_LIMIT}")
+            }
+            _ => e.to_string(),
+        };
+
+        let len = i32::try_from(args.len()).map_err(|_| "arguments too large".to_string())?;
+        let ptr = alloc.call(&mut *store, len).map_err(trap)?;
+        memory
+            .write(
+                &mut *store,
+                usize::cast_from(u32::reinterpret_cast(ptr)),
+                args,
+            )
+            .map_err(|e| e.to_string())?;
+
+        let packed = entry.call(&mut *store, (ptr, len)).map_err(trap)?;
+        let packed = u64::reinterpret_cast(packed);
+        let ptr = usize::cast_from(packed >> 32);
+        let len = usize::cast_from(packed & 0xffff_ffff);
+        // The location comes from the module, so check it against the memory
+        // before copying anything out.
+        ptr.checked_add(len)
+            .and_then(|end| memory.data(&*store).get(ptr..end))
+            .map(|result| result.to_vec())
+            .ok_or_else(|| "function returned a result outside of its memory".to_string())
+    }
+}
+
+fn encode_datum(datum: Datum, buf: &mut Vec<u8>) -> Result<(), String> {
+    match datum {
+        Datum::False => buf.push(0),
+        Datum::True => buf.push(1),
+        Datum::Int16(i) => buf.extend(i.to_le_bytes()),
+        Datum::Int32(i) => buf.extend(i.to_le_bytes()),
+        Datum::Int64(i) => buf.extend(i.to_le_bytes()),
+        Datum::Float32(f) => buf.extend(f.to_bits().to_le_bytes()),
+        Datum::Float64(f) => buf.extend(f.to_bits().to_le_bytes()),
+        Datum::String(s) => encode_bytes(s.as_bytes(), buf)?,
+        Datum::Bytes(b) => encode_bytes(b, buf)?,
+        d => return Err(format!("cannot pass {d:?} to a WebAssembly function")),
+    }
+    Ok(())
+}
+
+fn encode_bytes(bytes: &[u8], buf: &mut Vec<u8>) -> Result<(), String> {
+    let len = u32::try_from(bytes.len()).map_err(|_| "argument too large".to_string())?;
+    buf.extend(len.to_le_bytes());
+    buf.extend(bytes);
+    Ok(())
+}
+
+fn decode_datum<'a>(
+    buf: &[u8],
+    typ: &ScalarType,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, String> {
+    fn fixed<const N: usize>(buf: &[u8]) -> Result<[u8; N], String> {
+        buf.try_into()
+            .map_err(|_| format!("expected a {N}-byte result, got {} bytes", buf.len()))
+    }
+
+    fn variable(buf: &[u8]) -> Result<&[u8], String> {
+        let (len, rest) = buf
+            .split_first_chunk::<4>()
+            .ok_or_else(|| "truncated result".to_string())?;
+        if usize::cast_from(u32::from_le_bytes(*len)) != rest.len() {
+            return Err("result length does not match its prefix".into());
+        }
+        Ok(rest)
+    }
+
+    let datum = match typ {
+        ScalarType::Bool => match fixed::<1>(buf)? {
+            [0] => Datum::False,
+            [1] => Datum::True,
+            [b] => return Err(format!("invalid boolean result: {b}")),
+        },
+        ScalarType::Int16 => Datum::Int16(i16::from_le_bytes(fixed(buf)?)),
+        ScalarType::Int32 => Datum::Int32(i32::from_le_bytes(fixed(buf)?)),
+        ScalarType::Int64 => Datum::Int64(i64::from_le_bytes(fixed(buf)?)),
+        // NaNs are canonicalized, so that their bit patterns, which
+        // WebAssembly leaves unspecified, cannot make results differ.
+        ScalarType::Float32 => {
+            let f = f32::from_bits(u32::from_le_bytes(fixed(buf)?));
+            Datum::from(if f.is_nan() { f32::NAN } else { f })
+        }
+        ScalarType::Float64 => {
+            let f = f64::from_bits(u64::from_le_bytes(fixed(buf)?));
+            Datum::from(if f.is_nan() { f64::NAN } else { f })
+        }
+        ScalarType::String => {
+            let s = std::str::from_utf8(variable(buf)?)
+                .map_err(|_| "function returned invalid UTF-8".to_string())?;
+            Datum::String(temp_storage.push_string(s.to_owned()))
+        }
+        ScalarType::Bytes => Datum::Bytes(temp_storage.push_bytes(variable(buf)?.to_vec())),
+        typ => return Err(format!("cannot return {typ:?} from a WebAssembly function")),
+    };
+    Ok(datum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the sum of its two `integer` arguments.
+    const ADD_MODULE: &str = concat!(
+        "0061736d01000000010c0260017f017f60027f7f017e03030200010503010001071e0306",
+        "6d656d6f72790200086d7a5f616c6c6f630000066d7a5f75646600010a2b020500418008",
+        "0b230041801041013a0000418110200028020020002802046a3602004285808080808002",
+        "0b",
+    );
+
+    /// Loops forever.
+    const SPIN_MODULE: &str = concat!(
+        "0061736d01000000010c0260017f017f60027f7f017e03030200010503010001071e0306",
+        "6d656d6f72790200086d7a5f616c6c6f630000066d7a5f75646600010a0f020500418008",
+        "0b0700037e0c000b0b",
+    );
+
+    /// Always returns the error `boom`.
+    const FAIL_MODULE: &str = concat!(
+        "0061736d01000000010c0260017f017f60027f7f017e03030200010503010001071e0306",
+        "6d656d6f72790200086d7a5f616c6c6f630000066d7a5f75646600010a12020500418008",
+        "0b0a0042858080808080030b0b0c01004180180b0502626f6f6d",
+    );
+
+    /// Claims to return a result of 4 GiB - 1 bytes, at offset 0.
+    const WILD_MODULE: &str = concat!(
+        "0061736d01000000010c0260017f017f60027f7f017e03030200010503010001071e0306",
+        "6d656d6f72790200086d7a5f616c6c6f630000066d7a5f75646600010a10020500418008",
+        "0b080042ffffffff0f0b",
+    );
+
+    /// Returns how many times it has been called, counting in a global.
+    const COUNTER_MODULE: &str = concat!(
+        "0061736d01000000010c0260017f017f60027f7f017e030302000105030100010606017f",
+        "0141000b071e03066d656d6f72790200086d7a5f616c6c6f630000066d7a5f7564660001",
+        "0a290205004180080b2100230041016a240041801041013a000041811023003602004285",
+        "8080808080020b",
+    );
+
+    fn udf(module: &str) -> WasmUdf {
+        let module = hex::decode(module).unwrap();
+        WasmUdf::new("f".into(), module.into(), ScalarType::Int32)
+    }
+
+    #[mz_ore::test]
+    fn roundtrip_datums() {
+        let arena = RowArena::new();
+        let cases = [
+            (Datum::True, ScalarType::Bool),
+            (Datum::Int16(-2), ScalarType::Int16),
+            (Datum::Int32(1 << 20), ScalarType::Int32),
+            (Datum::Int64(i64::MIN), ScalarType::Int64),
+            (Datum::from(1.5f32), ScalarType::Float32),
+            (Datum::from(-0.25f64), ScalarType::Float64),
+            (Datum::String("héllo"), ScalarType::String),
+            (Datum::Bytes(&[0, 1, 2]), ScalarType::Bytes),
+        ];
+        for (datum, typ) in cases {
+            let mut buf = Vec::new();
+            encode_datum(datum, &mut buf).unwrap();
+            assert_eq!(decode_datum(&buf, &typ, &arena).unwrap(), datum);
+        }
+    }
+
+    #[mz_ore::test]
+    fn decode_malformed() {
+        let arena = RowArena::new();
+        assert!(decode_datum(&[2], &ScalarType::Bool, &arena).is_err());
+        assert!(decode_datum(&[0, 0], &ScalarType::Int32, &arena).is_err());
+        assert!(decode_datum(&[5, 0, 0, 0, b'a'], &ScalarType::String, &arena).is_err());
+        assert!(decode_datum(&[2, 0, 0, 0, 0xff, 0xfe], &ScalarType::String, &arena).is_err());
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn eval() {
+        let arena = RowArena::new();
+
+        let add = udf(ADD_MODULE);
+        assert_eq!(add.validate(), Ok(()));
+        let args = [Datum::Int32(40), Datum::Int32(2)];
+        assert_eq!(add.eval(&args, &arena), Ok(Datum::Int32(42)));
+
+        let spin = udf(SPIN_MODULE);
+        assert_eq!(spin.validate(), Ok(()));
+        assert_eq!(
+            spin.eval(&args, &arena).unwrap_err().to_string(),
+            format!("error in function f: exceeded the fuel limit of {WASM_UDF_FUEL_LIMIT}")
+        );
+
+        let fail = udf(FAIL_MODULE);
+        assert_eq!(
+            fail.eval(&args, &arena).unwrap_err().to_string(),
+            "error in function f: boom"
+        );
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn call_isolation() {
+        let arena = RowArena::new();
+        let args = [Datum::Int32(1), Datum::Int32(2)];
+
+        // No state carries over between calls, on the same copy or on clones
+        // that share its compiled module.
+        let counter = udf(COUNTER_MODULE);
+        for _ in 0..3 {
+            assert_eq!(counter.eval(&args, &arena), Ok(Datum::Int32(1)));
+        }
+        assert!(counter.compiled.0.get().is_some());
+        let clone = counter.clone();
+        assert_eq!(counter, clone);
+        assert_eq!(clone.eval(&args, &arena), Ok(Datum::Int32(1)));
+
+        // A call after a trap behaves like any other.
+        let spin = udf(SPIN_MODULE);
+        assert!(spin.eval(&args, &arena).is_err());
+        assert!(spin.eval(&args, &arena).is_err());
+
+        // A result outside of memory is rejected without copying it.
+        let wild = udf(WILD_MODULE);
+        assert_eq!(
+            wild.eval(&args, &arena).unwrap_err().to_string(),
+            "error in function f: function returned a result outside of its memory"
+        );
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn invalid_module() {
+        let udf = WasmUdf::new("f".into(), Arc::from(&b"\0asm"[..]), ScalarType::Int32);
+        assert!(udf.validate().is_err());
+        let arena = RowArena::new();
+        assert!(matches!(
+            udf.eval(&[Datum::Int32(1)], &arena),
+            Err(EvalError::WasmUdfError { .. })
+        ));
+    }
+}
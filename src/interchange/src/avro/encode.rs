@@ -18,9 +18,12 @@ use itertools::Itertools;
 use mz_avro::Schema;
 use mz_avro::types::{DecimalValue, ToAvro, Value};
 use mz_ore::cast::CastFrom;
+use mz_repr::adt::geometry::Geometry;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::numeric::{self, NUMERIC_AGG_MAX_PRECISION, NUMERIC_DATUM_MAX_PRECISION};
-use mz_repr::{CatalogItemId, ColumnName, ColumnType, Datum, RelationDesc, Row, ScalarType};
+use mz_repr::{
+    CatalogItemId, ColumnName, ColumnType, Datum, RelationDesc, Row, ScalarType, strconv,
+};
 use serde_json::json;
 
 use crate::encode::{Encode, TypedDatum, column_names_and_types};
@@ -359,11 +362,14 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                 }
                 ScalarType::Jsonb => Value::Json(JsonbRef::from_datum(datum).to_serde_json()),
                 ScalarType::Uuid => Value::Uuid(datum.unwrap_uuid()),
-                ty @ (ScalarType::Array(..) | ScalarType::Int2Vector | ScalarType::List { .. }) => {
+                ty @ (ScalarType::Array(..)
+                | ScalarType::Int2Vector
+                | ScalarType::Vector { .. }
+                | ScalarType::List { .. }) => {
                     let list = match ty {
-                        ScalarType::Array(_) | ScalarType::Int2Vector => {
-                            datum.unwrap_array().elements()
-                        }
+                        ScalarType::Array(_)
+                        | ScalarType::Int2Vector
+                        | ScalarType::Vector { .. } => datum.unwrap_array().elements(),
                         ScalarType::List { .. } => datum.unwrap_list(),
                         _ => unreachable!(),
                     };
@@ -418,6 +424,17 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                 ScalarType::MzTimestamp => Value::String(datum.unwrap_mz_timestamp().to_string()),
                 ScalarType::Range { .. } => Value::String(datum.unwrap_range().to_string()),
                 ScalarType::MzAclItem => Value::String(datum.unwrap_mz_acl_item().to_string()),
+                ScalarType::Enum { labels, .. } => {
+                    Value::String(strconv::format_enum(labels, datum.unwrap_uint32()).into_owned())
+                }
+                ScalarType::TsVector | ScalarType::TsQuery => {
+                    Value::String(datum.unwrap_str().to_owned())
+                }
+                ScalarType::Geometry => {
+                    let geometry = Geometry::from_ewkb(datum.unwrap_bytes())
+                        .expect("geometry datums are valid EWKB");
+                    Value::String(geometry.to_ewkt())
+                }
             };
             if typ.nullable {
                 val = Value::Union {
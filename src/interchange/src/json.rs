@@ -12,9 +12,10 @@ use std::fmt;
 
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::char;
+use mz_repr::adt::geometry::Geometry;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::numeric::{NUMERIC_AGG_MAX_PRECISION, NUMERIC_DATUM_MAX_PRECISION};
-use mz_repr::{CatalogItemId, ColumnName, ColumnType, Datum, RelationDesc, ScalarType};
+use mz_repr::{CatalogItemId, ColumnName, ColumnType, Datum, RelationDesc, ScalarType, strconv};
 use serde_json::{Map, json};
 
 use crate::avro::DocTarget;
@@ -159,7 +160,7 @@ impl ToJson for TypedDatum<'_> {
             }
             ScalarType::Jsonb => JsonbRef::from_datum(datum).to_serde_json(),
             ScalarType::Uuid => json!(datum.unwrap_uuid()),
-            ty @ (ScalarType::Array(..) | ScalarType::Int2Vector) => {
+            ty @ (ScalarType::Array(..) | ScalarType::Int2Vector | ScalarType::Vector { .. }) => {
                 let array = datum.unwrap_array();
                 let dims = array.dims().into_iter().collect::<Vec<_>>();
                 let mut datums = array.elements().iter();
@@ -231,6 +232,15 @@ impl ToJson for TypedDatum<'_> {
                 json!(datum.unwrap_range().to_string())
             }
             ScalarType::MzAclItem => json!(datum.unwrap_mz_acl_item().to_string()),
+            ScalarType::Enum { labels, .. } => {
+                json!(strconv::format_enum(labels, datum.unwrap_uint32()))
+            }
+            ScalarType::TsVector | ScalarType::TsQuery => json!(datum.unwrap_str()),
+            ScalarType::Geometry => {
+                let geometry = Geometry::from_ewkb(datum.unwrap_bytes())
+                    .expect("geometry datums are valid EWKB");
+                json!(geometry.to_ewkt())
+            }
         };
         // We don't need to recurse into map or object here because those already recursively call
         // .json() with the number policy to generate the member Values.
@@ -316,7 +326,10 @@ fn build_row_schema_field_type(
             "type": "string",
             "logicalType": "uuid",
         }),
-        ty @ (ScalarType::Array(..) | ScalarType::Int2Vector | ScalarType::List { .. }) => {
+        ty @ (ScalarType::Array(..)
+        | ScalarType::Int2Vector
+        | ScalarType::Vector { .. }
+        | ScalarType::List { .. }) => {
             let inner = build_row_schema_field_type(
                 type_namer,
                 custom_names,
@@ -395,6 +408,12 @@ fn build_row_schema_field_type(
         // https://debezium.io/documentation/reference/stable/connectors/postgresql.html
         ScalarType::Range { .. } => json!("string"),
         ScalarType::MzAclItem => json!("string"),
+        // Avro enum symbols are restricted to identifier-like names, which
+        // enum labels need not be, so labels are encoded as strings.
+        ScalarType::Enum { .. } => json!("string"),
+        ScalarType::TsVector | ScalarType::TsQuery => json!("string"),
+        // Geometries are encoded as extended well-known text.
+        ScalarType::Geometry => json!("string"),
     };
     if typ.nullable {
         // Should be revisited if we ever support a different kind of union scheme.
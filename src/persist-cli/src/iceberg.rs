@@ -0,0 +1,174 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Periodically exports a shard of Materialize source data as an Iceberg
+//! table.
+//!
+//! Unless `--consolidate` is given, the table references the parts of the
+//! shard in place, which persist only keeps around while the exporter holds
+//! leases on them. Such a table is only readable while the exporter runs, so
+//! `--once` always consolidates the snapshot into the table's location.
+
+#![allow(missing_docs)]
+
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use mz_ore::metrics::MetricsRegistry;
+use mz_ore::now::SYSTEM_TIME;
+use mz_ore::url::SensitiveUrl;
+use mz_persist::cfg::BlobConfig;
+use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::cfg::PersistConfig;
+use mz_persist_client::iceberg::{IcebergExportMode, IcebergExporter};
+use mz_persist_client::rpc::PubSubClientConnection;
+use mz_persist_client::{Diagnostics, PersistLocation, ShardId};
+use mz_repr::Timestamp;
+use mz_storage_types::StorageDiff;
+use mz_storage_types::sources::SourceData;
+use tracing::{info, warn};
+
+#[derive(Debug, clap::Parser)]
+pub struct Args {
+    /// Handle to the persist consensus system.
+    #[clap(long, value_name = "CONSENSUS_URI")]
+    consensus_uri: SensitiveUrl,
+
+    /// Handle to the persist blob storage.
+    #[clap(long, value_name = "BLOB_URI")]
+    blob_uri: SensitiveUrl,
+
+    /// Id of the persist shard to export.
+    #[clap(long, value_name = "SHARD_ID")]
+    shard_id: String,
+
+    /// Handle to the blob storage to write the table into.
+    #[clap(long, value_name = "TABLE_URI")]
+    table_uri: SensitiveUrl,
+
+    /// The location of the persist blob storage, as seen by the engines
+    /// reading the table. Defaults to `--blob-uri` without its query.
+    #[clap(long, value_name = "URL")]
+    blob_location: Option<String>,
+
+    /// The location of the table, as seen by the engines reading it.
+    /// Defaults to `--table-uri` without its query.
+    #[clap(long, value_name = "URL")]
+    table_location: Option<String>,
+
+    /// Duration between subsequent exports.
+    #[clap(long, value_parser = humantime::parse_duration, value_name = "I", default_value = "5m")]
+    interval: Duration,
+
+    /// Write a consolidated copy of the shard into the table, instead of
+    /// referencing its parts in place, so that the table stays readable after
+    /// the exporter exits.
+    #[clap(long)]
+    consolidate: bool,
+
+    /// Export a single consolidated version of the table and exit.
+    #[clap(long)]
+    once: bool,
+}
+
+pub async fn run(args: Args) -> Result<(), anyhow::Error> {
+    let shard_id = ShardId::from_str(&args.shard_id).map_err(anyhow::Error::msg)?;
+    let location = PersistLocation {
+        blob_uri: args.blob_uri.clone(),
+        consensus_uri: args.consensus_uri.clone(),
+    };
+    let cfg =
+        PersistConfig::new_default_configs(&mz_persist_client::BUILD_INFO, SYSTEM_TIME.clone());
+    let persist = PersistClientCache::new(cfg.clone(), &MetricsRegistry::new(), |_, _| {
+        PubSubClientConnection::noop()
+    })
+    .open(location)
+    .await?;
+
+    let diagnostics = Diagnostics::from_purpose("persistcli export-iceberg");
+    let (_, key_schema, val_schema) = persist
+        .latest_schema::<SourceData, (), Timestamp, StorageDiff>(shard_id, diagnostics.clone())
+        .await?
+        .ok_or_else(|| anyhow!("shard {} has no registered schema", shard_id))?;
+    let reader = persist
+        .open_leased_reader::<SourceData, (), Timestamp, StorageDiff>(
+            shard_id,
+            Arc::new(key_schema),
+            Arc::new(val_schema),
+            diagnostics,
+            false,
+        )
+        .await?;
+
+    let table = BlobConfig::try_from(
+        &args.table_uri,
+        Box::new(cfg.clone()),
+        persist.metrics().s3_blob.clone(),
+        Arc::clone(&cfg.configs),
+    )
+    .await?
+    .open()
+    .await?;
+    let blob_location = args
+        .blob_location
+        .unwrap_or_else(|| external_location(&args.blob_uri));
+    let table_location = args
+        .table_location
+        .unwrap_or_else(|| external_location(&args.table_uri));
+
+    let mode = if args.consolidate || args.once {
+        IcebergExportMode::Consolidated
+    } else {
+        IcebergExportMode::Referenced
+    };
+    let mut exporter =
+        IcebergExporter::new(reader, mode, table, blob_location, table_location).await?;
+    if args.once {
+        let export = exporter.export().await;
+        exporter.expire().await;
+        match export? {
+            Some(export) => info!(
+                "exported version {} as of {:?}: {} records",
+                export.version,
+                export.as_of.elements(),
+                export.record_count,
+            ),
+            None => info!("shard {} has no readable contents yet", shard_id),
+        }
+        return Ok(());
+    }
+
+    // Exiting would release the leases on the parts that the table references,
+    // so keep going on errors and retry at the next interval.
+    let mut interval = tokio::time::interval(args.interval);
+    loop {
+        interval.tick().await;
+        match exporter.export().await {
+            Ok(Some(export)) => info!(
+                "exported version {} as of {:?}: {} records, {} referenced parts, {} rewritten parts",
+                export.version,
+                export.as_of.elements(),
+                export.record_count,
+                export.referenced_parts,
+                export.rewritten_parts,
+            ),
+            Ok(None) => info!("shard {} has no readable contents yet", shard_id),
+            Err(err) => warn!("exporting shard {}: {:#}", shard_id, err),
+        }
+    }
+}
+
+/// Returns `uri` without the query parameters that only persist understands.
+fn external_location(uri: &SensitiveUrl) -> String {
+    let mut url = uri.0.clone();
+    url.set_query(None);
+    url.to_string()
+}
@@ -27,6 +27,7 @@ use futures_util::{FutureExt, stream};
 use mz_dyncfg::Config;
 use mz_ore::cast::CastFrom;
 use mz_ore::instrument;
+use mz_persist::encrypt::BlobEncryptionKeys;
 use mz_persist::indexed::encoding::{BatchColumnarFormat, BlobTraceBatchPart, BlobTraceUpdates};
 use mz_persist::location::Blob;
 use mz_persist_types::arrow::{ArrayBound, ArrayOrd};
@@ -355,6 +356,9 @@ pub struct BatchBuilderConfig {
     /// continue to generate one run per part for unordered batches.
     /// See the config definition for details.
     pub(crate) max_runs: Option<usize>,
+    /// The keys that blobs are encrypted with, if blob encryption is enabled,
+    /// so that parts can record which key they were written with.
+    pub(crate) encryption_keys: Option<BlobEncryptionKeys>,
 }
 
 // TODO: Remove this once we're comfortable that there aren't any bugs.
@@ -451,6 +455,16 @@ impl BatchBuilderConfig {
                 limit @ 2.. => Some(limit),
                 _ => None,
             },
+            encryption_keys: value.blob_encryption_keys(),
+        }
+    }
+
+    /// The id of the key that blobs written now are encrypted with, if blob
+    /// encryption is enabled.
+    pub(crate) async fn encryption_key_id(&self) -> Option<String> {
+        match &self.encryption_keys {
+            Some(keys) => Some(keys.refreshed_active_id().await),
+            None => None,
         }
     }
 }
@@ -888,6 +902,7 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
             let blob = Arc::clone(&blob);
             let metrics = Arc::clone(&metrics);
             let writer_key = cfg.writer_key.clone();
+            let encryption_cfg = cfg.clone();
             // Don't spill "unordered" runs to S3, since we'll split them up into many single-element
             // runs below.
             let run_length_limit = (order == RunOrder::Unordered)
@@ -896,6 +911,7 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
             let merge_fn = move |parts| {
                 let blob = Arc::clone(&blob);
                 let writer_key = writer_key.clone();
+                let encryption_cfg = encryption_cfg.clone();
                 let metrics = Arc::clone(&metrics);
                 let handle = mz_ore::task::spawn(
                     || "batch::spill_run",
@@ -909,6 +925,7 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
                             blob.as_ref(),
                             &writer_key,
                             HollowRun { parts },
+                            encryption_cfg.encryption_key_id().await,
                             &*metrics,
                         )
                         .await;
@@ -1079,6 +1096,7 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
     ) -> BatchPart<T> {
         let partial_key = PartialBatchKey::new(&cfg.writer_key, &PartId::new());
         let key = partial_key.complete(&shard_metrics.shard_id);
+        let encryption_key_id = cfg.encryption_key_id().await;
         let goodbytes = updates.updates.goodbytes();
         let metrics_ = Arc::clone(&metrics);
         let schema_id = write_schemas.id;
@@ -1226,6 +1244,7 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
             schema_id,
             // Field has been deprecated but kept around to roundtrip state.
             deprecated_schema_id: None,
+            encryption_key_id,
         })
     }
 
@@ -1329,6 +1348,11 @@ impl<T: Timestamp> PartDeletes<T> {
     // Adds the part to the set to be deleted and returns true if it was newly
     // inserted.
     pub fn add(&mut self, part: &RunPart<T>) -> bool {
+        if part.is_foreign() {
+            // The blobs of a fork's foreign parts are owned, and eventually
+            // deleted, by the shard it was forked from.
+            return true;
+        }
         match part {
             RunPart::Many(r) => self.hollow_runs.insert(r.key.clone(), r.clone()).is_none(),
             RunPart::Single(BatchPart::Hollow(x)) => self.blob_keys.insert(x.key.clone()),
@@ -24,6 +24,7 @@ use mz_ore::metrics::MetricsRegistry;
 use mz_ore::task::{AbortOnDropHandle, JoinHandle};
 use mz_ore::url::SensitiveUrl;
 use mz_persist::cfg::{BlobConfig, ConsensusConfig};
+use mz_persist::encrypt::EncryptedBlob;
 use mz_persist::location::{
     BLOB_GET_LIVENESS_KEY, Blob, CONSENSUS_HEAD_LIVENESS_KEY, Consensus, ExternalError, Tasked,
     VersionedData,
@@ -35,7 +36,7 @@ use tracing::debug;
 
 use crate::async_runtime::IsolatedRuntime;
 use crate::error::{CodecConcreteType, CodecMismatch};
-use crate::internal::cache::BlobMemCache;
+use crate::internal::cache::{BlobDiskCache, BlobMemCache};
 use crate::internal::machine::retry_external;
 use crate::internal::metrics::{LockMetrics, Metrics, MetricsBlob, MetricsConsensus, ShardMetrics};
 use crate::internal::state::TypedState;
@@ -225,6 +226,25 @@ impl PersistClientCache {
                 )
                 .await;
                 // This is intentionally "outside" (wrapping) MetricsBlob so
+                // that disk cache hits are not included in blob metrics, and
+                // "inside" EncryptedBlob so that blobs are stored encrypted
+                // on local disk.
+                let blob: Arc<dyn Blob> = match &self.cfg.blob_cache_disk_dir {
+                    Some(dir) => {
+                        let metrics = Arc::clone(&self.metrics);
+                        let keys = self.cfg.blob_encryption.clone();
+                        BlobDiskCache::open(&self.cfg, dir.clone(), metrics, keys, blob).await
+                    }
+                    None => blob,
+                };
+                // This is intentionally "outside" (wrapping) MetricsBlob so
+                // that blob metrics reflect the bytes actually stored, and
+                // "inside" BlobMemCache so that cache hits skip decryption.
+                let blob: Arc<dyn Blob> = match self.cfg.blob_encryption_keys() {
+                    Some(keys) => Arc::new(EncryptedBlob::new(blob, keys)),
+                    None => blob,
+                };
+                // This is intentionally "outside" (wrapping) MetricsBlob so
                 // that we don't include cached responses in blob metrics.
                 let blob = BlobMemCache::new(&self.cfg, Arc::clone(&self.metrics), blob);
                 Arc::clone(&x.insert((RttLatencyTask(task.abort_on_drop()), blob)).1)
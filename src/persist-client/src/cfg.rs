@@ -11,6 +11,7 @@
 
 //! The tunable knobs for persist.
 
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -20,6 +21,9 @@ use mz_dyncfg::{Config, ConfigDefault, ConfigSet, ConfigUpdates};
 use mz_ore::instrument;
 use mz_ore::now::NowFn;
 use mz_persist::cfg::BlobKnobs;
+use mz_persist::encrypt::{
+    BLOB_ENCRYPTION_ACTIVE_KEY, BlobEncryptionKeySource, BlobEncryptionKeys,
+};
 use mz_persist::retry::Retry;
 use mz_postgres_client::PostgresClientKnobs;
 use proptest_derive::Arbitrary;
@@ -132,6 +136,12 @@ pub struct PersistConfig {
     /// Number of worker threads to create for the [`crate::IsolatedRuntime`], defaults to the
     /// number of threads.
     pub isolated_runtime_worker_threads: usize,
+    /// If set, blob contents are encrypted client-side with these keys before
+    /// being written to blob storage.
+    pub blob_encryption: Option<BlobEncryptionKeys>,
+    /// If set, a directory on local disk in which to cache blobs, in addition
+    /// to the in-memory cache. Entries survive process restarts.
+    pub blob_cache_disk_dir: Option<PathBuf>,
 }
 
 // Impl Deref to ConfigSet for convenience of accessing the dynamic configs.
@@ -173,6 +183,8 @@ impl PersistConfig {
             writer_lease_duration: 60 * Duration::from_secs(60),
             critical_downgrade_interval: Duration::from_secs(30),
             isolated_runtime_worker_threads: num_cpus::get(),
+            blob_encryption: None,
+            blob_cache_disk_dir: None,
             // TODO: This doesn't work with the process orchestrator. Instead,
             // separate --log-prefix into --service-name and --enable-log-prefix
             // options, where the first is always provided and the second is
@@ -181,6 +193,23 @@ impl PersistConfig {
         }
     }
 
+    /// The keys to encrypt blobs with, if blob encryption is enabled, with the
+    /// active key following the `persist_blob_encryption_active_key` dyncfg.
+    pub(crate) fn blob_encryption_keys(&self) -> Option<BlobEncryptionKeys> {
+        let keys = self.blob_encryption.as_ref()?;
+        let active_key = BLOB_ENCRYPTION_ACTIVE_KEY.handle(self);
+        Some(keys.clone().with_active_key_config(active_key))
+    }
+
+    /// The id of the key that blobs written now are encrypted with, if blob
+    /// encryption is enabled. See [BlobEncryptionKeys::refreshed_active_id].
+    pub(crate) async fn blob_encryption_key_id(&self) -> Option<String> {
+        match self.blob_encryption_keys() {
+            Some(keys) => Some(keys.refreshed_active_id().await),
+            None => None,
+        }
+    }
+
     pub(crate) fn set_config<T: ConfigDefault>(&self, cfg: &Config<T>, val: T) {
         let mut updates = ConfigUpdates::default();
         updates.add(cfg, val);
@@ -316,6 +345,7 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&crate::internal::cache::BLOB_CACHE_MEM_LIMIT_BYTES)
         .add(&crate::internal::cache::BLOB_CACHE_SCALE_WITH_THREADS)
         .add(&crate::internal::cache::BLOB_CACHE_SCALE_FACTOR_BYTES)
+        .add(&crate::internal::cache::BLOB_CACHE_DISK_LIMIT_BYTES)
         .add(&crate::internal::compact::COMPACTION_MINIMUM_TIMEOUT)
         .add(&crate::internal::compact::COMPACTION_USE_MOST_RECENT_SCHEMA)
         .add(&crate::internal::compact::COMPACTION_CHECK_PROCESS_FLAG)
@@ -355,6 +385,106 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&crate::write::COMBINE_INLINE_WRITES)
 }
 
+/// Command-line arguments for client-side encryption of persist blobs.
+///
+/// Every process that opens persist for a given environment must be configured
+/// with the same keys, so `environmentd` passes these through to `clusterd`.
+/// The keys themselves are read from the secrets controller, which every such
+/// process already has access to. These are only the initial keys: keys are
+/// rotated at runtime with the `persist_blob_encryption_active_key` dyncfg,
+/// and keys that blobs are encrypted with are read from the secrets controller
+/// on demand.
+#[derive(clap::Parser, Clone, Debug, Default)]
+pub struct BlobEncryptionCliArgs {
+    /// The ids of the secrets holding the blob encryption keys to load at
+    /// startup. If set, blob contents are encrypted before being written to
+    /// blob storage. Keys that are missing here are loaded when first needed.
+    #[clap(
+        long,
+        env = "PERSIST_BLOB_ENCRYPTION_KEYS",
+        value_delimiter = ',',
+        requires = "persist_blob_encryption_active_key"
+    )]
+    pub persist_blob_encryption_keys: Vec<String>,
+    /// The id of the secret in `--persist-blob-encryption-keys` to encrypt
+    /// newly written blobs with, until the `persist_blob_encryption_active_key`
+    /// dyncfg selects a different one.
+    #[clap(
+        long,
+        env = "PERSIST_BLOB_ENCRYPTION_ACTIVE_KEY",
+        requires = "persist_blob_encryption_keys"
+    )]
+    pub persist_blob_encryption_active_key: Option<String>,
+    /// Whether to reject blobs that are not encrypted, instead of reading them
+    /// as plaintext. Enable only once every existing blob has been encrypted,
+    /// e.g. with `persistcli admin reencrypt-blobs`.
+    #[clap(
+        long,
+        env = "PERSIST_BLOB_ENCRYPTION_STRICT",
+        requires = "persist_blob_encryption_active_key"
+    )]
+    pub persist_blob_encryption_strict: bool,
+}
+
+impl BlobEncryptionCliArgs {
+    /// Loads the configured keys, if blob encryption is enabled.
+    ///
+    /// `read_secret` returns the contents of the secret with the given id,
+    /// which are parsed with [BlobEncryptionKey::parse]. The returned key ring
+    /// keeps it to load further keys at runtime.
+    ///
+    /// [BlobEncryptionKey::parse]: mz_persist::encrypt::BlobEncryptionKey::parse
+    pub async fn load<F, Fut>(
+        &self,
+        read_secret: F,
+    ) -> Result<Option<BlobEncryptionKeys>, anyhow::Error>
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, anyhow::Error>> + Send + 'static,
+    {
+        let active = match (
+            self.persist_blob_encryption_keys.is_empty(),
+            &self.persist_blob_encryption_active_key,
+        ) {
+            (false, Some(active)) => active,
+            (true, None) => return Ok(None),
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "blob encryption requires both keys and an active key"
+                ));
+            }
+        };
+        let source = BlobEncryptionKeySource::new(read_secret);
+        let mut keys = Vec::with_capacity(self.persist_blob_encryption_keys.len());
+        for id in &self.persist_blob_encryption_keys {
+            keys.push(source.read(id).await?);
+        }
+        let keys = BlobEncryptionKeys::from_keys(keys, active)?
+            .with_strict(self.persist_blob_encryption_strict)
+            .with_source(source);
+        Ok(Some(keys))
+    }
+
+    /// Turn this struct back into arguments. Useful for passing through to
+    /// other services.
+    pub fn to_flags(&self) -> Vec<String> {
+        let mut flags = vec![];
+        if !self.persist_blob_encryption_keys.is_empty() {
+            flags.push(format!(
+                "--persist-blob-encryption-keys={}",
+                self.persist_blob_encryption_keys.join(",")
+            ));
+        }
+        if let Some(active) = &self.persist_blob_encryption_active_key {
+            flags.push(format!("--persist-blob-encryption-active-key={}", active));
+        }
+        if self.persist_blob_encryption_strict {
+            flags.push("--persist-blob-encryption-strict".into());
+        }
+        flags
+    }
+}
+
 impl PersistConfig {
     pub(crate) const DEFAULT_FALLBACK_ROLLUP_THRESHOLD_MULTIPLIER: usize = 3;
 
@@ -11,6 +11,7 @@
 
 use std::any::Any;
 use std::fmt::Debug;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -23,6 +24,7 @@ use mz_dyncfg::{Config, ConfigSet};
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::SYSTEM_TIME;
 use mz_ore::url::SensitiveUrl;
+use mz_persist::encrypt::{BlobEncryptionKeys, EncryptedBlob};
 use mz_persist::location::{Blob, Consensus, ExternalError};
 use mz_persist_types::codec_impls::TodoSchema;
 use mz_persist_types::{Codec, Codec64};
@@ -77,6 +79,15 @@ pub(crate) enum Command {
     /// Attempt to ensure that all the files referenced by consensus are available
     /// in Blob.
     RestoreBlob(RestoreBlobArgs),
+    /// Create a shard that starts out with the data of another shard, without
+    /// copying it.
+    ForkShard(ForkShardArgs),
+    /// Unregister a fork from the shard it was forked from, which GC of that
+    /// shard otherwise does once the fork no longer references its data.
+    ReleaseFork(ReleaseForkArgs),
+    /// Rewrite every blob that state records as not encrypted with the active
+    /// blob encryption key so that it is.
+    ReencryptBlobs(ReencryptBlobsArgs),
 }
 
 /// Manually completes all fueled compactions in a shard.
@@ -123,6 +134,63 @@ pub(crate) struct RestoreBlobArgs {
     concurrency: usize,
 }
 
+/// Creates a shard that references the data of another shard.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct ForkShardArgs {
+    /// The shard to fork.
+    #[clap(flatten)]
+    state: StateArgs,
+
+    /// The shard to create as a fork.
+    #[clap(long)]
+    target_shard_id: String,
+
+    /// The time as of which the fork can be read.
+    #[clap(long)]
+    as_of: u64,
+}
+
+/// Unregisters a fork from its source.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct ReleaseForkArgs {
+    /// The shard that was forked.
+    #[clap(flatten)]
+    state: StateArgs,
+
+    /// The fork to release.
+    #[clap(long)]
+    target_shard_id: String,
+}
+
+/// Rewrites blobs so that they are encrypted with the active key.
+///
+/// Walks the live states of every shard in consensus and only rewrites the
+/// blobs whose recorded encryption key id isn't the active key, instead of
+/// fetching every blob in blob storage.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct ReencryptBlobsArgs {
+    #[clap(flatten)]
+    state: StoreArgs,
+
+    /// A directory containing one blob encryption key per file, named by the
+    /// id of the secret that holds it, e.g. the secrets of the environment
+    /// mounted into the pod running this command.
+    #[clap(long)]
+    keys_dir: PathBuf,
+
+    /// The id of the key in `--keys-dir` to encrypt blobs with.
+    #[clap(long)]
+    active_key: String,
+
+    /// Only rewrite blobs whose keys start with this prefix, e.g. a shard id.
+    #[clap(long, default_value = "")]
+    prefix: String,
+
+    /// The number of concurrent rewrites to run at once.
+    #[clap(long, default_value_t = 16)]
+    concurrency: usize,
+}
+
 /// Runs the given read-write admin command.
 pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
     match command.command {
@@ -374,6 +442,147 @@ pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
                 bail!("referenced blobs were not restored: {not_restored:#?}")
             }
         }
+        Command::ReencryptBlobs(args) => {
+            let ReencryptBlobsArgs {
+                state:
+                    StoreArgs {
+                        consensus_uri,
+                        blob_uri,
+                    },
+                keys_dir,
+                active_key,
+                prefix,
+                concurrency,
+            } = args;
+            let commit = command.commit;
+            let keys = BlobEncryptionKeys::load_dir(&keys_dir, &active_key)?;
+            let active_key = keys.active_id();
+            let configs = all_dyncfgs(ConfigSet::default());
+            let cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), configs);
+            let metrics_registry = MetricsRegistry::new();
+            let metrics = Arc::new(Metrics::new(&cfg, &metrics_registry));
+            let consensus =
+                make_consensus(&cfg, &consensus_uri, commit, Arc::clone(&metrics)).await?;
+            // Intentionally not setting `cfg.blob_encryption`: we need the
+            // concrete EncryptedBlob to call reencrypt.
+            let blob = make_blob(&cfg, &blob_uri, commit, Arc::clone(&metrics)).await?;
+            let blob = Arc::new(EncryptedBlob::new(blob, keys));
+            let versions = StateVersions::new(
+                cfg.clone(),
+                Arc::clone(&consensus),
+                Arc::clone(&blob) as Arc<dyn Blob>,
+                Arc::clone(&metrics),
+            );
+
+            // A blob key starts with the id of its shard, so skip the shards
+            // that can't have a blob matching the prefix.
+            let shard_ids: Vec<_> = consensus
+                .list_keys()
+                .try_filter(|shard| {
+                    let matches = shard.starts_with(&prefix) || prefix.starts_with(shard.as_str());
+                    async move { matches }
+                })
+                .try_collect()
+                .await?;
+            let mut blob_keys = vec![];
+            for shard_id in shard_ids {
+                let shard_id = ShardId::from_str(&shard_id).expect("invalid shard id");
+                let shard_blob_keys = crate::internal::reencrypt::blobs_to_reencrypt(
+                    &versions,
+                    blob.as_ref(),
+                    shard_id,
+                    &active_key,
+                    &metrics,
+                )
+                .await;
+                blob_keys.extend(
+                    shard_blob_keys
+                        .into_iter()
+                        .filter(|key| key.starts_with(&prefix)),
+                );
+            }
+            info!("Checking {} blobs for reencryption.", blob_keys.len());
+            let blob = &blob;
+            let rewritten = stream::iter(blob_keys)
+                .map(|key| async move { blob.reencrypt(&key).await })
+                .buffer_unordered(concurrency)
+                .try_fold(0usize, |count, rewritten| async move {
+                    Ok(count + usize::from(rewritten))
+                })
+                .await?;
+            info!(
+                "Reencrypted {rewritten} blobs with key {active_key}{}.",
+                if commit { "" } else { " (dry run)" }
+            );
+            info_log_non_zero_metrics(&metrics_registry.gather());
+        }
+        Command::ForkShard(args) => {
+            let source = ShardId::from_str(&args.state.shard_id).expect("invalid shard id");
+            let target = ShardId::from_str(&args.target_shard_id).expect("invalid shard id");
+            let expected_version = command
+                .expected_version
+                .as_ref()
+                .map(|v| Version::parse(v))
+                .transpose()?;
+            let configs = all_dyncfgs(ConfigSet::default());
+            // TODO: Fetch the latest values of these configs from Launch Darkly.
+            let cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), configs);
+            let metrics_registry = MetricsRegistry::new();
+            let client = make_client(
+                cfg,
+                &metrics_registry,
+                source,
+                &args.state.consensus_uri,
+                &args.state.blob_uri,
+                command.commit,
+                expected_version,
+            )
+            .await?;
+            let () = client
+                .fork_shard::<crate::cli::inspect::K, crate::cli::inspect::V, u64, i64>(
+                    source,
+                    target,
+                    Antichain::from_elem(args.as_of),
+                    Diagnostics::from_purpose("persist-cli fork shard"),
+                )
+                .await?;
+            info!("forked {source} into {target} as of {}", args.as_of);
+            info_log_non_zero_metrics(&metrics_registry.gather());
+        }
+        Command::ReleaseFork(args) => {
+            let source = ShardId::from_str(&args.state.shard_id).expect("invalid shard id");
+            let target = ShardId::from_str(&args.target_shard_id).expect("invalid shard id");
+            let expected_version = command
+                .expected_version
+                .as_ref()
+                .map(|v| Version::parse(v))
+                .transpose()?;
+            let configs = all_dyncfgs(ConfigSet::default());
+            // TODO: Fetch the latest values of these configs from Launch Darkly.
+            let cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), configs);
+            let metrics_registry = MetricsRegistry::new();
+            let client = make_client(
+                cfg,
+                &metrics_registry,
+                source,
+                &args.state.consensus_uri,
+                &args.state.blob_uri,
+                command.commit,
+                expected_version,
+            )
+            .await?;
+            let released = client
+                .release_fork::<crate::cli::inspect::K, crate::cli::inspect::V, u64, i64>(
+                    source,
+                    target,
+                    Diagnostics::from_purpose("persist-cli release fork"),
+                )
+                .await?;
+            if !released {
+                warn!("{target} is not a fork of {source}");
+            }
+            info_log_non_zero_metrics(&metrics_registry.gather());
+        }
     }
     Ok(())
 }
@@ -639,6 +848,50 @@ where
     Ok(machine)
 }
 
+/// Returns a [PersistClient] for operating on `shard_id`, after checking that
+/// it's safe to do so with this version of the tool.
+async fn make_client(
+    cfg: PersistConfig,
+    metrics_registry: &MetricsRegistry,
+    shard_id: ShardId,
+    consensus_uri: &SensitiveUrl,
+    blob_uri: &SensitiveUrl,
+    commit: bool,
+    expected_version: Option<Version>,
+) -> anyhow::Result<PersistClient> {
+    let metrics = Arc::new(Metrics::new(&cfg, metrics_registry));
+    let consensus = make_consensus(&cfg, consensus_uri, commit, Arc::clone(&metrics)).await?;
+    let blob = make_blob(&cfg, blob_uri, commit, Arc::clone(&metrics)).await?;
+    let _machine = make_machine(
+        &cfg,
+        Arc::clone(&consensus),
+        Arc::clone(&blob),
+        Arc::clone(&metrics),
+        shard_id,
+        commit,
+        expected_version,
+    )
+    .await?;
+
+    let isolated_runtime = Arc::new(IsolatedRuntime::new(metrics_registry, None));
+    let pubsub_sender: Arc<dyn PubSubSender> = Arc::new(NoopPubSubSender);
+    let shared_states = Arc::new(StateCache::new(
+        &cfg,
+        Arc::clone(&metrics),
+        Arc::clone(&pubsub_sender),
+    ));
+    let client = PersistClient::new(
+        cfg,
+        blob,
+        consensus,
+        metrics,
+        isolated_runtime,
+        shared_states,
+        pubsub_sender,
+    )?;
+    Ok(client)
+}
+
 async fn force_gc(
     cfg: PersistConfig,
     metrics_registry: &MetricsRegistry,
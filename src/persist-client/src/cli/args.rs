@@ -22,6 +22,7 @@ use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::SYSTEM_TIME;
 use mz_ore::url::SensitiveUrl;
 use mz_persist::cfg::{BlobConfig, ConsensusConfig};
+use mz_persist::encrypt::EncryptedBlob;
 use mz_persist::location::{
     Blob, BlobMetadata, CaSResult, Consensus, ExternalError, ResultStream, SeqNo, Tasked,
     VersionedData,
@@ -164,6 +165,10 @@ pub(super) async fn make_blob(
     };
     let blob = Arc::new(MetricsBlob::new(blob, Arc::clone(&metrics)));
     let blob = Arc::new(Tasked(blob));
+    let blob: Arc<dyn Blob> = match &cfg.blob_encryption {
+        Some(keys) => Arc::new(EncryptedBlob::new(blob, keys.clone())),
+        None => blob,
+    };
     Ok(blob)
 }
 
@@ -79,6 +79,25 @@ pub enum InvalidUsage<T> {
     CodecMismatch(Box<CodecMismatch>),
     /// An invalid usage of [crate::batch::Batch::rewrite_ts].
     InvalidRewrite(String),
+    /// A fork was requested at a time the source shard cannot be read at.
+    InvalidForkAsOf {
+        /// The requested as_of of the fork.
+        as_of: Antichain<T>,
+        /// The current since of the source shard.
+        since: Antichain<T>,
+        /// The current upper of the source shard.
+        upper: Antichain<T>,
+    },
+    /// The source of a fork is itself a fork that still references the blobs
+    /// of another shard.
+    ForkOfFork(ShardId),
+    /// The target of a fork already has state.
+    ForkTargetExists(ShardId),
+    /// A fork was released while it still references the blobs of the shard
+    /// it was forked from.
+    ForkInUse(ShardId),
+    /// Attempted to finalize a shard that forks still reference the blobs of.
+    ForkedShardFinalization(ShardId),
 }
 
 impl<T: Debug> std::fmt::Display for InvalidUsage<T> {
@@ -126,6 +145,31 @@ impl<T: Debug> std::fmt::Display for InvalidUsage<T> {
             }
             InvalidUsage::CodecMismatch(err) => std::fmt::Display::fmt(err, f),
             InvalidUsage::InvalidRewrite(err) => write!(f, "invalid rewrite: {err}"),
+            InvalidUsage::InvalidForkAsOf {
+                as_of,
+                since,
+                upper,
+            } => write!(
+                f,
+                "fork as_of {as_of:?} not readable with since {since:?} and upper {upper:?}"
+            ),
+            InvalidUsage::ForkOfFork(shard_id) => write!(
+                f,
+                "shard {shard_id} references the blobs of another shard and cannot be forked"
+            ),
+            InvalidUsage::ForkTargetExists(shard_id) => {
+                write!(f, "fork target {shard_id} already exists")
+            }
+            InvalidUsage::ForkInUse(shard_id) => {
+                write!(
+                    f,
+                    "fork {shard_id} still references the blobs of its source"
+                )
+            }
+            InvalidUsage::ForkedShardFinalization(shard_id) => write!(
+                f,
+                "cannot finalize {shard_id} while forks reference its blobs"
+            ),
         }
     }
 }
@@ -0,0 +1,1073 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Export of persist shards as read-only Apache Iceberg tables.
+//!
+//! Persist batch parts are Parquet files, so external engines (Spark, DuckDB,
+//! ...) can read them directly, given metadata that describes which of them
+//! make up the contents of a shard. [IcebergExporter] periodically writes this
+//! metadata as an Iceberg (format version 2) table describing a snapshot of a
+//! shard as of its `since`.
+//!
+//! Each row of the table is an update `(k_s, v_s, t, d)` in the structured
+//! format of persist parts. How the rows relate to the contents of the shard
+//! depends on the [IcebergExportMode]:
+//!
+//! - [IcebergExportMode::Consolidated]: the snapshot is consolidated across
+//!   all parts and written into the table's location, so there is exactly one
+//!   row per distinct `(k_s, v_s)`, with a non-zero multiplicity `d`. The
+//!   table doesn't depend on persist's blobs, and stays readable after the
+//!   exporter exits. The exporter holds the whole snapshot in memory.
+//! - [IcebergExportMode::Referenced]: parts are referenced in place whenever
+//!   external engines can read them as-is, and otherwise rewritten
+//!   (consolidated and forwarded to the `since`) into the table's location.
+//!   The contents of the shard are obtained by summing the `d` column grouped
+//!   by `k_s` and `v_s`. The exporter holds a lease on the parts referenced
+//!   by its two most recent exports, so that queries planned against the
+//!   previous version of the table keep working for one export interval. Once
+//!   the exporter stops, persist is free to delete the referenced parts, so
+//!   the table is only readable while the exporter runs.
+//!
+//! The table is laid out as expected by Iceberg's file-system catalogs:
+//!
+//! - `metadata/version-hint.text`: the current version `N`.
+//! - `metadata/vN.metadata.json`: the table metadata of version `N`.
+//! - `metadata/snap-N.avro`, `metadata/manifest-N.avro`: the manifest list
+//!   and manifest of version `N`.
+//! - `data/N-<uuid>.parquet`: parts rewritten by version `N`.
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail};
+use arrow::array::Array;
+use arrow::datatypes::{DataType, Field};
+use bytes::Bytes;
+use differential_dataflow::consolidation::consolidate_updates;
+use differential_dataflow::difference::Semigroup;
+use differential_dataflow::lattice::Lattice;
+use differential_dataflow::trace::Description;
+use mz_ore::cast::CastFrom;
+use mz_persist::file::FileBlob;
+use mz_persist::indexed::encoding::{BatchColumnarFormat, BlobTraceBatchPart, BlobTraceUpdates};
+use mz_persist::location::Blob;
+use mz_persist_types::part::PartBuilder;
+use mz_persist_types::schema::SchemaId;
+use mz_persist_types::{Codec, Codec64};
+use serde_json::{Value, json};
+use timely::PartialOrder;
+use timely::progress::{Antichain, Timestamp};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use crate::batch::BatchBuilderConfig;
+use crate::fetch::{LeasedBatchPart, fetch_leased_part};
+use crate::internal::state::BatchPart;
+use crate::read::ReadHandle;
+
+const VERSION_HINT_KEY: &str = "metadata/version-hint.text";
+
+/// The number of most recent exports whose parts are leased.
+const LEASED_EXPORTS: usize = 2;
+
+/// The maximum number of rows in a data file of a consolidated export.
+const CONSOLIDATED_FILE_MAX_RECORDS: usize = 1_000_000;
+
+/// How an [IcebergExporter] writes the contents of a shard. See the
+/// [module docs](self) for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IcebergExportMode {
+    /// Write a consolidated copy of the snapshot.
+    Consolidated,
+    /// Reference the parts of the snapshot in place, where possible.
+    Referenced,
+}
+
+/// Periodically exports a snapshot of a shard as an Iceberg table. See the
+/// [module docs](self) for details.
+#[derive(Debug)]
+pub struct IcebergExporter<K: Codec, V: Codec, T, D> {
+    reader: ReadHandle<K, V, T, D>,
+    mode: IcebergExportMode,
+    /// Where the table is written.
+    table: Arc<dyn Blob>,
+    /// The location of persist's blob, as seen by external engines.
+    blob_location: String,
+    /// The location of `table`, as seen by external engines.
+    table_location: String,
+    table_uuid: Uuid,
+    version: u64,
+    /// The parts referenced by the most recent exports, newest last.
+    leases: VecDeque<Vec<LeasedBatchPart<T>>>,
+}
+
+/// The result of a successful [IcebergExporter::export].
+#[derive(Debug)]
+pub struct IcebergExport<T> {
+    /// The version of the table that was written.
+    pub version: u64,
+    /// The time as of which the contents of the shard were exported.
+    pub as_of: Antichain<T>,
+    /// The number of parts referenced in place.
+    pub referenced_parts: usize,
+    /// The number of parts that had to be rewritten.
+    pub rewritten_parts: usize,
+    /// The total number of rows in the table.
+    pub record_count: usize,
+}
+
+/// A Parquet file that is part of the table.
+#[derive(Debug)]
+struct DataFile {
+    path: String,
+    record_count: usize,
+    size_bytes: usize,
+}
+
+impl<K, V, T, D> IcebergExporter<K, V, T, D>
+where
+    K: Debug + Codec + Ord,
+    V: Debug + Codec + Ord,
+    T: Timestamp + Lattice + Codec64 + Sync,
+    D: Semigroup + Codec64 + Send + Sync,
+{
+    /// Returns an exporter of the shard of `reader` into the table stored in
+    /// `table`, continuing from the latest version of the table, if any.
+    ///
+    /// External engines will read the table at `table_location` and the parts
+    /// that it references at `blob_location`.
+    pub async fn new(
+        reader: ReadHandle<K, V, T, D>,
+        mode: IcebergExportMode,
+        table: Arc<dyn Blob>,
+        blob_location: String,
+        table_location: String,
+    ) -> Result<Self, anyhow::Error> {
+        let (table_uuid, version) = match table.get(VERSION_HINT_KEY).await? {
+            Some(hint) => {
+                let version: u64 = String::from_utf8(hint.into_contiguous())?.trim().parse()?;
+                let metadata = table
+                    .get(&metadata_key(version))
+                    .await?
+                    .ok_or_else(|| anyhow!("missing metadata for version {}", version))?;
+                let metadata: Value = serde_json::from_slice(&metadata.into_contiguous())?;
+                let table_uuid = metadata["table-uuid"]
+                    .as_str()
+                    .ok_or_else(|| anyhow!("missing table-uuid in version {}", version))?
+                    .parse()?;
+                (table_uuid, version)
+            }
+            None => (Uuid::new_v4(), 0),
+        };
+        Ok(IcebergExporter {
+            reader,
+            mode,
+            table,
+            blob_location,
+            table_location,
+            table_uuid,
+            version,
+            leases: VecDeque::new(),
+        })
+    }
+
+    /// Writes a new version of the table with the contents of the shard as of
+    /// its current `since`.
+    ///
+    /// Returns None if the shard has no readable contents yet.
+    pub async fn export(&mut self) -> Result<Option<IcebergExport<T>>, anyhow::Error> {
+        // Follow the since of the other readers of the shard, so that the
+        // exporter doesn't hold back compaction by more than one export.
+        let mut as_of = self.reader.since().clone();
+        if let Some(since) = self
+            .reader
+            .machine
+            .applier
+            .since_without_reader(&self.reader.reader_id)
+        {
+            as_of.join_assign(&since);
+        }
+        self.reader.downgrade_since(&as_of).await;
+        let upper = self.reader.machine.applier.clone_upper();
+        if !PartialOrder::less_than(&as_of, &upper) {
+            debug!(
+                "{} not exporting as_of {:?} with upper {:?}",
+                self.reader.shard_id(),
+                as_of.elements(),
+                upper.elements()
+            );
+            return Ok(None);
+        }
+        let parts = self
+            .reader
+            .snapshot(as_of.clone())
+            .await
+            .map_err(|since| anyhow!("as_of {:?} not valid for {:?}", as_of, since))?;
+
+        let version = self.version + 1;
+        let schema_id = self.schema_id();
+        let mut data_files = Vec::with_capacity(parts.len());
+        let (mut referenced_parts, mut rewritten_parts) = (0, 0);
+        match self.mode {
+            IcebergExportMode::Consolidated => {
+                let mut updates = Vec::new();
+                for part in parts.iter() {
+                    updates.append(&mut self.fetch(part).await?);
+                }
+                consolidate_updates(&mut updates);
+                for updates in updates.chunks(CONSOLIDATED_FILE_MAX_RECORDS) {
+                    data_files.push(self.write_data_file(updates, &as_of, version).await?);
+                }
+                rewritten_parts = parts.len();
+            }
+            IcebergExportMode::Referenced => {
+                for part in parts.iter() {
+                    match self.reference(part, &as_of, schema_id) {
+                        Some(data_file) => {
+                            referenced_parts += 1;
+                            data_files.push(data_file);
+                        }
+                        None => {
+                            rewritten_parts += 1;
+                            let mut updates = self.fetch(part).await?;
+                            consolidate_updates(&mut updates);
+                            if !updates.is_empty() {
+                                data_files
+                                    .push(self.write_data_file(&updates, &as_of, version).await?);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        let record_count = data_files.iter().map(|x| x.record_count).sum();
+        self.write_version(version, &as_of, &data_files).await?;
+        self.version = version;
+
+        // The leases of older exports can be released once the new version
+        // is visible. A consolidated export doesn't need its parts anymore.
+        let parts = match self.mode {
+            IcebergExportMode::Consolidated => Vec::new(),
+            IcebergExportMode::Referenced => parts,
+        };
+        self.leases.push_back(parts);
+        while self.leases.len() > LEASED_EXPORTS {
+            self.leases.pop_front();
+        }
+        self.delete_old_versions().await?;
+
+        info!(
+            "{} exported version {} as_of {:?}: {} referenced parts, {} rewritten parts, {} records",
+            self.reader.shard_id(),
+            version,
+            as_of.elements(),
+            referenced_parts,
+            rewritten_parts,
+            record_count
+        );
+        Ok(Some(IcebergExport {
+            version,
+            as_of,
+            referenced_parts,
+            rewritten_parts,
+            record_count,
+        }))
+    }
+
+    /// Politely expires the reader of this exporter, releasing its leases.
+    pub async fn expire(self) {
+        self.reader.expire().await
+    }
+
+    /// The id of the schema of the reader, if it is the latest schema of the
+    /// shard.
+    fn schema_id(&self) -> Option<SchemaId> {
+        let (id, key, val) = self.reader.machine.latest_schema()?;
+        let schemas = &self.reader.read_schemas;
+        let matches = K::encode_schema(&key) == K::encode_schema(&schemas.key)
+            && V::encode_schema(&val) == V::encode_schema(&schemas.val);
+        matches.then_some(id)
+    }
+
+    /// Returns the part as a [DataFile], if external engines can read it as
+    /// part of the snapshot as of `as_of`.
+    fn reference(
+        &self,
+        part: &LeasedBatchPart<T>,
+        as_of: &Antichain<T>,
+        schema_id: Option<SchemaId>,
+    ) -> Option<DataFile> {
+        let BatchPart::Hollow(hollow) = &part.part else {
+            return None;
+        };
+        // Encrypted blobs are only readable by persist.
+        if self.reader.cfg.blob_encryption.is_some() {
+            return None;
+        }
+        // Updates beyond `as_of` need to be filtered out.
+        if hollow.ts_rewrite.is_some() || !PartialOrder::less_equal(part.desc.upper(), as_of) {
+            return None;
+        }
+        // The structured columns have to exist and match the table's schema.
+        match hollow.format {
+            Some(BatchColumnarFormat::Structured | BatchColumnarFormat::Both(2..)) => {}
+            _ => return None,
+        }
+        if schema_id.is_none() || hollow.schema_id != schema_id {
+            return None;
+        }
+        // Iceberg requires a row count for every file.
+        let record_count = hollow.stats.as_ref()?.decode().key.len;
+        let key = hollow.key.complete(&self.reader.shard_id());
+        Some(DataFile {
+            path: object_url(&self.blob_location, &key.to_string()),
+            record_count,
+            size_bytes: hollow.encoded_size_bytes,
+        })
+    }
+
+    /// Returns the updates of the part, forwarded to the `as_of` of the
+    /// snapshot it is part of.
+    async fn fetch(
+        &mut self,
+        part: &LeasedBatchPart<T>,
+    ) -> Result<Vec<((K, V), T, D)>, anyhow::Error> {
+        let reader = &mut self.reader;
+        let fetched = fetch_leased_part(
+            &reader.cfg,
+            part,
+            reader.blob.as_ref(),
+            Arc::clone(&reader.metrics),
+            &reader.metrics.read.snapshot,
+            &reader.machine.applier.shard_metrics,
+            &reader.reader_id,
+            reader.read_schemas.clone(),
+            &mut reader.schema_cache,
+        )
+        .await;
+        let mut updates = Vec::new();
+        for ((k, v), t, d) in fetched {
+            let k = k.map_err(|err| anyhow!("decoding key: {}", err))?;
+            let v = v.map_err(|err| anyhow!("decoding val: {}", err))?;
+            updates.push(((k, v), t, d));
+        }
+        Ok(updates)
+    }
+
+    /// Writes `updates` to a new file in the table's data directory.
+    async fn write_data_file(
+        &self,
+        updates: &[((K, V), T, D)],
+        as_of: &Antichain<T>,
+        version: u64,
+    ) -> Result<DataFile, anyhow::Error> {
+        let reader = &self.reader;
+        let schemas = &reader.read_schemas;
+        let mut builder = PartBuilder::new(schemas.key.as_ref(), schemas.val.as_ref());
+        for ((k, v), t, d) in updates.iter() {
+            builder.push(k, v, t.clone(), d.clone());
+        }
+        let batch = BlobTraceBatchPart {
+            desc: Description::new(
+                Antichain::from_elem(T::minimum()),
+                Antichain::new(),
+                as_of.clone(),
+            ),
+            index: 0,
+            updates: BlobTraceUpdates::from_part(builder.finish()),
+        };
+        let encoding_config =
+            BatchBuilderConfig::new(&reader.cfg, reader.shard_id()).encoding_config;
+        let mut buf = Vec::new();
+        batch.encode(&mut buf, &reader.metrics.columnar, &encoding_config);
+
+        let key = format!("data/{}-{}.parquet", version, Uuid::new_v4());
+        let data_file = DataFile {
+            path: object_url(&self.table_location, &key),
+            record_count: updates.len(),
+            size_bytes: buf.len(),
+        };
+        self.table.set(&key, Bytes::from(buf)).await?;
+        Ok(data_file)
+    }
+
+    /// Writes the manifest, manifest list, and table metadata of `version`,
+    /// and then makes it the current version.
+    async fn write_version(
+        &self,
+        version: u64,
+        as_of: &Antichain<T>,
+        data_files: &[DataFile],
+    ) -> Result<(), anyhow::Error> {
+        let (schema, name_mapping, last_column_id) = self.table_schema()?;
+        // Each version of the table has exactly one snapshot, so the version
+        // doubles as its id and sequence number.
+        let snapshot_id = i64::try_from(version)?;
+        let now_ms = (self.reader.cfg.now)();
+
+        let manifest_key = format!("metadata/manifest-{}.avro", version);
+        let manifest = avro::manifest(&schema, snapshot_id, data_files)?;
+        let manifest_len = manifest.len();
+        self.table.set(&manifest_key, Bytes::from(manifest)).await?;
+
+        let manifest_list_key = format!("metadata/snap-{}.avro", version);
+        let manifest_list = avro::manifest_list(
+            &object_url(&self.table_location, &manifest_key),
+            manifest_len,
+            snapshot_id,
+            data_files,
+        )?;
+        self.table
+            .set(&manifest_list_key, Bytes::from(manifest_list))
+            .await?;
+
+        let record_count: usize = data_files.iter().map(|x| x.record_count).sum();
+        let size_bytes: usize = data_files.iter().map(|x| x.size_bytes).sum();
+        let metadata = json!({
+            "format-version": 2,
+            "table-uuid": self.table_uuid.to_string(),
+            "location": self.table_location,
+            "last-sequence-number": snapshot_id,
+            "last-updated-ms": now_ms,
+            "last-column-id": last_column_id,
+            "current-schema-id": 0,
+            "schemas": [schema],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": []}],
+            "last-partition-id": 999,
+            "default-sort-order-id": 0,
+            "sort-orders": [{"order-id": 0, "fields": []}],
+            "properties": {
+                "schema.name-mapping.default": name_mapping.to_string(),
+                "materialize.shard-id": self.reader.shard_id().to_string(),
+                "materialize.as-of": format!("{:?}", as_of.elements()),
+            },
+            "current-snapshot-id": snapshot_id,
+            "refs": {"main": {"snapshot-id": snapshot_id, "type": "branch"}},
+            "snapshots": [{
+                "snapshot-id": snapshot_id,
+                "sequence-number": snapshot_id,
+                "timestamp-ms": now_ms,
+                "manifest-list": object_url(&self.table_location, &manifest_list_key),
+                "summary": {
+                    "operation": "overwrite",
+                    "added-data-files": data_files.len().to_string(),
+                    "added-records": record_count.to_string(),
+                    "added-files-size": size_bytes.to_string(),
+                    "total-data-files": data_files.len().to_string(),
+                    "total-records": record_count.to_string(),
+                    "total-files-size": size_bytes.to_string(),
+                },
+                "schema-id": 0,
+            }],
+            "snapshot-log": [{"snapshot-id": snapshot_id, "timestamp-ms": now_ms}],
+            "metadata-log": [],
+        });
+        self.table
+            .set(
+                &metadata_key(version),
+                Bytes::from(serde_json::to_vec(&metadata)?),
+            )
+            .await?;
+        self.table
+            .set(VERSION_HINT_KEY, Bytes::from(version.to_string()))
+            .await?;
+        Ok(())
+    }
+
+    /// Deletes the files of versions whose parts are no longer leased.
+    async fn delete_old_versions(&self) -> Result<(), anyhow::Error> {
+        let oldest_leased = (self.version + 1).saturating_sub(u64::cast_from(LEASED_EXPORTS));
+        for prefix in ["metadata/", "data/"] {
+            let mut keys = Vec::new();
+            self.table
+                .list_keys_and_metadata(prefix, &mut |metadata| {
+                    if key_version(metadata.key).is_some_and(|v| v < oldest_leased) {
+                        keys.push(metadata.key.to_owned());
+                    }
+                })
+                .await?;
+            for key in keys {
+                self.table.delete(&key).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the Iceberg schema and name mapping of the table, as well as
+    /// the highest field id used by them.
+    fn table_schema(&self) -> Result<(Value, Value, i32), anyhow::Error> {
+        let schemas = &self.reader.read_schemas;
+        let part = PartBuilder::<K, K::Schema, V, V::Schema>::new(
+            schemas.key.as_ref(),
+            schemas.val.as_ref(),
+        )
+        .finish();
+        let columns = [
+            ("k_s", part.key.data_type()),
+            ("v_s", part.val.data_type()),
+            ("t", part.time.data_type()),
+            ("d", part.diff.data_type()),
+        ];
+        let mut builder = SchemaBuilder::default();
+        let mut fields = Vec::new();
+        let mut mappings = Vec::new();
+        for (name, data_type) in columns {
+            if let Some((field, mapping)) = builder.field(name, data_type, false)? {
+                fields.push(field);
+                mappings.push(mapping);
+            }
+        }
+        let schema = json!({"type": "struct", "schema-id": 0, "fields": fields});
+        Ok((schema, Value::Array(mappings), builder.last_id))
+    }
+}
+
+/// Assigns Iceberg field ids to the (nested) columns of a persist part.
+///
+/// The Parquet files written by persist don't have field ids, so the columns
+/// are identified by a name mapping instead.
+#[derive(Debug, Default)]
+struct SchemaBuilder {
+    last_id: i32,
+}
+
+impl SchemaBuilder {
+    fn next_id(&mut self) -> i32 {
+        self.last_id += 1;
+        self.last_id
+    }
+
+    /// Returns the Iceberg field and name mapping of a column, or None if the
+    /// column never has any data.
+    fn field(
+        &mut self,
+        name: &str,
+        data_type: &DataType,
+        nullable: bool,
+    ) -> Result<Option<(Value, Value)>, anyhow::Error> {
+        if *data_type == DataType::Null {
+            return Ok(None);
+        }
+        let id = self.next_id();
+        let (typ, nested) = self.data_type(data_type)?;
+        let field = json!({"id": id, "name": name, "required": !nullable, "type": typ});
+        let mut mapping = json!({"field-id": id, "names": [name]});
+        if let Some(nested) = nested {
+            mapping["fields"] = nested;
+        }
+        Ok(Some((field, mapping)))
+    }
+
+    /// Returns the Iceberg type of a column and the name mappings of its
+    /// nested columns, if any.
+    fn data_type(&mut self, data_type: &DataType) -> Result<(Value, Option<Value>), anyhow::Error> {
+        let typ = match data_type {
+            DataType::Boolean => "boolean".to_owned(),
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::UInt8
+            | DataType::UInt16 => "int".to_owned(),
+            DataType::Int64 | DataType::UInt32 => "long".to_owned(),
+            DataType::Float32 => "float".to_owned(),
+            DataType::Float64 => "double".to_owned(),
+            DataType::Utf8 | DataType::LargeUtf8 => "string".to_owned(),
+            DataType::Binary | DataType::LargeBinary => "binary".to_owned(),
+            DataType::FixedSizeBinary(len) => format!("fixed[{}]", len),
+            DataType::Date32 => "date".to_owned(),
+            DataType::Decimal128(precision, scale) => format!("decimal({}, {})", precision, scale),
+            DataType::Struct(fields) => {
+                let mut iceberg_fields = Vec::new();
+                let mut mappings = Vec::new();
+                for field in fields.iter() {
+                    if let Some((field, mapping)) =
+                        self.field(field.name(), field.data_type(), field.is_nullable())?
+                    {
+                        iceberg_fields.push(field);
+                        mappings.push(mapping);
+                    }
+                }
+                let typ = json!({"type": "struct", "fields": iceberg_fields});
+                return Ok((typ, Some(Value::Array(mappings))));
+            }
+            DataType::List(element) | DataType::LargeList(element) => {
+                let (id, element_type, mapping) = self.child("element", element)?;
+                let typ = json!({
+                    "type": "list",
+                    "element-id": id,
+                    "element": element_type,
+                    "element-required": !element.is_nullable(),
+                });
+                return Ok((typ, Some(json!([mapping]))));
+            }
+            DataType::Map(entries, _) => {
+                let DataType::Struct(entries) = entries.data_type() else {
+                    bail!("unexpected map entries type: {}", entries.data_type());
+                };
+                let entries: Vec<_> = entries.iter().collect();
+                let [key, value] = &entries[..] else {
+                    bail!("unexpected map entries: {:?}", entries);
+                };
+                let (key_id, key_type, key_mapping) = self.child("key", key)?;
+                let (value_id, value_type, value_mapping) = self.child("value", value)?;
+                let typ = json!({
+                    "type": "map",
+                    "key-id": key_id,
+                    "key": key_type,
+                    "value-id": value_id,
+                    "value": value_type,
+                    "value-required": !value.is_nullable(),
+                });
+                return Ok((typ, Some(json!([key_mapping, value_mapping]))));
+            }
+            data_type => bail!("no Iceberg equivalent for type {}", data_type),
+        };
+        Ok((Value::String(typ), None))
+    }
+
+    /// Returns the id, Iceberg type, and name mapping of the element of a list
+    /// or the key or value of a map, which Iceberg names `name`.
+    fn child(&mut self, name: &str, field: &Field) -> Result<(i32, Value, Value), anyhow::Error> {
+        let id = self.next_id();
+        let (typ, nested) = self.data_type(field.data_type())?;
+        let mut names = vec![name];
+        if field.name() != name {
+            names.push(field.name());
+        }
+        let mut mapping = json!({"field-id": id, "names": names});
+        if let Some(nested) = nested {
+            mapping["fields"] = nested;
+        }
+        Ok((id, typ, mapping))
+    }
+}
+
+fn metadata_key(version: u64) -> String {
+    format!("metadata/v{}.metadata.json", version)
+}
+
+/// Returns the version of the table that a metadata or data file was written
+/// by, if any.
+fn key_version(key: &str) -> Option<u64> {
+    let name = key.rsplit('/').next()?;
+    let name = name.trim_start_matches(|c: char| !c.is_ascii_digit());
+    let digits = name
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(name.len());
+    name[..digits].parse().ok()
+}
+
+/// Returns the URL of the object with the given key in the blob at
+/// `location`.
+fn object_url(location: &str, key: &str) -> String {
+    let location = location.trim_end_matches('/');
+    if location.starts_with("file:") {
+        format!("{}/{}", location, FileBlob::replace_forward_slashes(key))
+    } else {
+        format!("{}/{}", location, key)
+    }
+}
+
+/// A minimal writer of the Avro object container files used for Iceberg
+/// manifests.
+///
+/// Iceberg identifies the fields of manifests by `field-id` attributes in the
+/// Avro schema and stores its own metadata in the file header, neither of
+/// which `mz_avro` can write.
+mod avro {
+    use serde_json::{Value, json};
+    use uuid::Uuid;
+
+    use super::DataFile;
+
+    /// Iceberg's `ADDED` manifest entry status.
+    const STATUS_ADDED: i32 = 1;
+    /// Iceberg's `DATA` content type for manifests and data files.
+    const CONTENT_DATA: i32 = 0;
+
+    pub(super) fn long(buf: &mut Vec<u8>, x: i64) {
+        // Zig-zag encoding, followed by a variable-length encoding.
+        let mut x = u64::from_ne_bytes(((x << 1) ^ (x >> 63)).to_ne_bytes());
+        while x >= 0x80 {
+            buf.push(u8::try_from(x & 0x7f).expect("7 bits") | 0x80);
+            x >>= 7;
+        }
+        buf.push(u8::try_from(x).expect("7 bits"));
+    }
+
+    fn int(buf: &mut Vec<u8>, x: i32) {
+        long(buf, i64::from(x))
+    }
+
+    fn len(buf: &mut Vec<u8>, x: usize) -> Result<(), anyhow::Error> {
+        long(buf, i64::try_from(x)?);
+        Ok(())
+    }
+
+    fn bytes(buf: &mut Vec<u8>, x: &[u8]) -> Result<(), anyhow::Error> {
+        len(buf, x.len())?;
+        buf.extend_from_slice(x);
+        Ok(())
+    }
+
+    /// Encodes the `null` branch of a `["null", T]` union.
+    fn null(buf: &mut Vec<u8>) {
+        long(buf, 0)
+    }
+
+    /// Encodes the `T` branch of a `["null", T]` union, which must be followed
+    /// by the value.
+    fn non_null(buf: &mut Vec<u8>) {
+        long(buf, 1)
+    }
+
+    /// Returns a container file with a single block of the given encoded
+    /// records.
+    fn container_file(
+        schema: &Value,
+        metadata: &[(&str, String)],
+        records: &[Vec<u8>],
+    ) -> Result<Vec<u8>, anyhow::Error> {
+        let sync = *Uuid::new_v4().as_bytes();
+        let mut buf = b"Obj\x01".to_vec();
+        len(&mut buf, metadata.len() + 2)?;
+        bytes(&mut buf, b"avro.schema")?;
+        bytes(&mut buf, schema.to_string().as_bytes())?;
+        bytes(&mut buf, b"avro.codec")?;
+        bytes(&mut buf, b"null")?;
+        for (key, val) in metadata {
+            bytes(&mut buf, key.as_bytes())?;
+            bytes(&mut buf, val.as_bytes())?;
+        }
+        long(&mut buf, 0);
+        buf.extend_from_slice(&sync);
+        if !records.is_empty() {
+            let data = records.concat();
+            len(&mut buf, records.len())?;
+            bytes(&mut buf, &data)?;
+            buf.extend_from_slice(&sync);
+        }
+        Ok(buf)
+    }
+
+    /// Returns a manifest that adds the given data files in the given
+    /// snapshot of a table with the given schema.
+    pub(super) fn manifest(
+        table_schema: &Value,
+        snapshot_id: i64,
+        data_files: &[DataFile],
+    ) -> Result<Vec<u8>, anyhow::Error> {
+        let schema = json!({
+            "type": "record",
+            "name": "manifest_entry",
+            "fields": [
+                {"name": "status", "type": "int", "field-id": 0},
+                {"name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1},
+                {"name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3},
+                {"name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4},
+                {"name": "data_file", "field-id": 2, "type": {
+                    "type": "record",
+                    "name": "r2",
+                    "fields": [
+                        {"name": "content", "type": "int", "field-id": 134},
+                        {"name": "file_path", "type": "string", "field-id": 100},
+                        {"name": "file_format", "type": "string", "field-id": 101},
+                        {"name": "partition", "field-id": 102, "type": {
+                            "type": "record",
+                            "name": "r102",
+                            "fields": [],
+                        }},
+                        {"name": "record_count", "type": "long", "field-id": 103},
+                        {"name": "file_size_in_bytes", "type": "long", "field-id": 104},
+                    ],
+                }},
+            ],
+        });
+        let metadata = [
+            ("schema", table_schema.to_string()),
+            ("schema-id", "0".to_owned()),
+            ("partition-spec", "[]".to_owned()),
+            ("partition-spec-id", "0".to_owned()),
+            ("format-version", "2".to_owned()),
+            ("content", "data".to_owned()),
+        ];
+        let mut records = Vec::with_capacity(data_files.len());
+        for data_file in data_files {
+            let mut buf = Vec::new();
+            int(&mut buf, STATUS_ADDED);
+            non_null(&mut buf);
+            long(&mut buf, snapshot_id);
+            // Sequence numbers of added files are inherited from the manifest
+            // list.
+            null(&mut buf);
+            null(&mut buf);
+            int(&mut buf, CONTENT_DATA);
+            bytes(&mut buf, data_file.path.as_bytes())?;
+            bytes(&mut buf, b"PARQUET")?;
+            len(&mut buf, data_file.record_count)?;
+            len(&mut buf, data_file.size_bytes)?;
+            records.push(buf);
+        }
+        container_file(&schema, &metadata, &records)
+    }
+
+    /// Returns a manifest list with the single manifest at `manifest_path`,
+    /// which adds the given data files in the given snapshot.
+    pub(super) fn manifest_list(
+        manifest_path: &str,
+        manifest_len: usize,
+        snapshot_id: i64,
+        data_files: &[DataFile],
+    ) -> Result<Vec<u8>, anyhow::Error> {
+        let schema = json!({
+            "type": "record",
+            "name": "manifest_file",
+            "fields": [
+                {"name": "manifest_path", "type": "string", "field-id": 500},
+                {"name": "manifest_length", "type": "long", "field-id": 501},
+                {"name": "partition_spec_id", "type": "int", "field-id": 502},
+                {"name": "content", "type": "int", "field-id": 517},
+                {"name": "sequence_number", "type": "long", "field-id": 515},
+                {"name": "min_sequence_number", "type": "long", "field-id": 516},
+                {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+                {"name": "added_files_count", "type": "int", "field-id": 504},
+                {"name": "existing_files_count", "type": "int", "field-id": 505},
+                {"name": "deleted_files_count", "type": "int", "field-id": 506},
+                {"name": "added_rows_count", "type": "long", "field-id": 512},
+                {"name": "existing_rows_count", "type": "long", "field-id": 513},
+                {"name": "deleted_rows_count", "type": "long", "field-id": 514},
+            ],
+        });
+        let metadata = [
+            ("snapshot-id", snapshot_id.to_string()),
+            ("parent-snapshot-id", "null".to_owned()),
+            ("sequence-number", snapshot_id.to_string()),
+            ("format-version", "2".to_owned()),
+        ];
+        let mut buf = Vec::new();
+        bytes(&mut buf, manifest_path.as_bytes())?;
+        len(&mut buf, manifest_len)?;
+        int(&mut buf, 0);
+        int(&mut buf, CONTENT_DATA);
+        long(&mut buf, snapshot_id);
+        long(&mut buf, snapshot_id);
+        long(&mut buf, snapshot_id);
+        int(&mut buf, i32::try_from(data_files.len())?);
+        int(&mut buf, 0);
+        int(&mut buf, 0);
+        len(&mut buf, data_files.iter().map(|x| x.record_count).sum())?;
+        long(&mut buf, 0);
+        long(&mut buf, 0);
+        container_file(&schema, &metadata, &[buf])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::datatypes::Fields;
+    use mz_dyncfg::ConfigUpdates;
+    use mz_persist::mem::{MemBlob, MemBlobConfig};
+    use mz_persist_types::codec_impls::StringSchema;
+
+    use crate::tests::new_test_client;
+    use crate::{Diagnostics, ShardId};
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn avro_long() {
+        let encode = |x| {
+            let mut buf = Vec::new();
+            avro::long(&mut buf, x);
+            buf
+        };
+        assert_eq!(encode(0), vec![0x00u8]);
+        assert_eq!(encode(-1), vec![0x01u8]);
+        assert_eq!(encode(1), vec![0x02u8]);
+        assert_eq!(encode(-64), vec![0x7fu8]);
+        assert_eq!(encode(64), vec![0x80u8, 0x01]);
+        let mut min = vec![0xffu8; 9];
+        min.push(0x01);
+        assert_eq!(encode(i64::MIN), min);
+    }
+
+    #[mz_ore::test]
+    fn schema_builder() {
+        let mut builder = SchemaBuilder::default();
+        let data_type = DataType::Struct(Fields::from(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Null, true),
+            Field::new_list("c", Field::new("item", DataType::Utf8, true), true),
+        ]));
+        let (field, mapping) = builder
+            .field("k_s", &data_type, false)
+            .expect("supported")
+            .expect("not null");
+        assert_eq!(
+            field,
+            json!({"id": 1, "name": "k_s", "required": true, "type": {
+                "type": "struct",
+                "fields": [
+                    {"id": 2, "name": "a", "required": true, "type": "long"},
+                    {"id": 3, "name": "c", "required": false, "type": {
+                        "type": "list",
+                        "element-id": 4,
+                        "element": "string",
+                        "element-required": false,
+                    }},
+                ],
+            }})
+        );
+        assert_eq!(
+            mapping,
+            json!({"field-id": 1, "names": ["k_s"], "fields": [
+                {"field-id": 2, "names": ["a"]},
+                {"field-id": 3, "names": ["c"], "fields": [
+                    {"field-id": 4, "names": ["element", "item"]},
+                ]},
+            ]})
+        );
+        assert_eq!(builder.last_id, 4);
+
+        assert!(builder.field("x", &DataType::UInt64, false).is_err());
+    }
+
+    #[mz_ore::test]
+    fn key_version() {
+        assert_eq!(super::key_version("metadata/v12.metadata.json"), Some(12));
+        assert_eq!(super::key_version("metadata/snap-3.avro"), Some(3));
+        assert_eq!(super::key_version("metadata/manifest-3.avro"), Some(3));
+        assert_eq!(super::key_version("data/7-abc.parquet"), Some(7));
+        assert_eq!(super::key_version(VERSION_HINT_KEY), None);
+    }
+
+    #[mz_persist_proc::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn iceberg_export(dyncfgs: ConfigUpdates) {
+        let data = [
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+            (("2".to_owned(), "two".to_owned()), 2, 1),
+            (("1".to_owned(), "one".to_owned()), 3, -1),
+            (("3".to_owned(), "three".to_owned()), 4, 1),
+        ];
+        let client = new_test_client(&dyncfgs).await;
+        let shard_id = ShardId::new();
+        let (mut write, mut read) = client
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        write.expect_compare_and_append(&data[..2], 0, 3).await;
+        write.expect_compare_and_append(&data[2..], 3, 5).await;
+
+        let open_exporter = |table: Arc<dyn Blob>, mode: IcebergExportMode| {
+            let client = client.clone();
+            async move {
+                let reader = client
+                    .open_leased_reader::<String, String, u64, i64>(
+                        shard_id,
+                        Arc::new(StringSchema),
+                        Arc::new(StringSchema),
+                        Diagnostics::for_tests(),
+                        false,
+                    )
+                    .await
+                    .expect("codecs match");
+                IcebergExporter::new(
+                    reader,
+                    mode,
+                    table,
+                    "mem://blob".into(),
+                    "mem://table".into(),
+                )
+                .await
+                .expect("table is valid")
+            }
+        };
+        let get_json = |table: Arc<dyn Blob>, key: String| async move {
+            let value = table.get(&key).await.expect("get").expect("exists");
+            serde_json::from_slice::<Value>(&value.into_contiguous()).expect("valid json")
+        };
+
+        // The exporter follows the since of the other readers of the shard.
+        let table: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let mut exporter = open_exporter(Arc::clone(&table), IcebergExportMode::Referenced).await;
+        read.downgrade_since(&Antichain::from_elem(3)).await;
+        let export = exporter.export().await.expect("export").expect("readable");
+        assert_eq!(export.version, 1);
+        assert_eq!(export.as_of, Antichain::from_elem(3));
+        assert!(export.referenced_parts + export.rewritten_parts > 0);
+        assert!(export.record_count > 0);
+
+        let metadata = get_json(Arc::clone(&table), metadata_key(1)).await;
+        assert_eq!(metadata["current-snapshot-id"], json!(1));
+        assert_eq!(
+            metadata["properties"]["materialize.shard-id"],
+            json!(shard_id.to_string())
+        );
+        let fields = metadata["schemas"][0]["fields"]
+            .as_array()
+            .expect("fields")
+            .iter()
+            .map(|x| (x["name"].clone(), x["type"].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![
+                (json!("k_s"), json!("string")),
+                (json!("v_s"), json!("string")),
+                (json!("t"), json!("long")),
+                (json!("d"), json!("long")),
+            ]
+        );
+
+        // Nothing to do while the since doesn't advance.
+        read.downgrade_since(&Antichain::from_elem(5)).await;
+        let export = exporter.export().await.expect("export");
+        assert!(export.is_none());
+        write.expect_compare_and_append(&[], 5, 6).await;
+        let export = exporter.export().await.expect("export").expect("readable");
+        assert_eq!(export.version, 2);
+        assert_eq!(export.as_of, Antichain::from_elem(5));
+        // Only updates in the same part are consolidated.
+        assert!(export.record_count >= 2);
+
+        // A new exporter continues from the previous version of the table,
+        // and the files of unleased versions are cleaned up.
+        exporter.expire().await;
+        let mut exporter = open_exporter(Arc::clone(&table), IcebergExportMode::Referenced).await;
+        let export = exporter.export().await.expect("export").expect("readable");
+        assert_eq!(export.version, 3);
+        let metadata3 = get_json(Arc::clone(&table), metadata_key(3)).await;
+        assert_eq!(metadata3["table-uuid"], metadata["table-uuid"]);
+        let hint = table
+            .get(VERSION_HINT_KEY)
+            .await
+            .expect("get")
+            .expect("exists");
+        assert_eq!(hint.into_contiguous(), b"3");
+        let mut versions = Vec::new();
+        table
+            .list_keys_and_metadata("", &mut |x| versions.extend(super::key_version(x.key)))
+            .await
+            .expect("list");
+        assert!(versions.iter().all(|v| *v >= 2), "{:?}", versions);
+        exporter.expire().await;
+
+        // A consolidated export only has the contents of the shard, all of
+        // them in the table's location.
+        let table: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let mut exporter = open_exporter(Arc::clone(&table), IcebergExportMode::Consolidated).await;
+        let export = exporter.export().await.expect("export").expect("readable");
+        assert_eq!(export.as_of, Antichain::from_elem(5));
+        assert_eq!(export.referenced_parts, 0);
+        assert_eq!(export.record_count, 2);
+        exporter.expire().await;
+    }
+}
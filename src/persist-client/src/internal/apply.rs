@@ -29,15 +29,16 @@ use crate::error::{CodecMismatch, InvalidUsage};
 use crate::internal::gc::GcReq;
 use crate::internal::maintenance::RoutineMaintenance;
 use crate::internal::metrics::{CmdMetrics, Metrics, ShardMetrics};
-use crate::internal::paths::{PartialRollupKey, RollupId};
+use crate::internal::paths::{PartialBatchKey, PartialRollupKey, RollupId};
 use crate::internal::state::{
-    EncodedSchemas, ExpiryMetrics, HollowBatch, ROLLUP_THRESHOLD, Since, SnapshotErr,
+    EncodedSchemas, ExpiryMetrics, HollowBatch, ROLLUP_THRESHOLD, RunPart, Since, SnapshotErr,
     StateCollections, TypedState, Upper,
 };
 use crate::internal::state_diff::StateDiff;
 use crate::internal::state_versions::{EncodedRollup, StateVersions};
 use crate::internal::trace::FueledMergeReq;
 use crate::internal::watch::StateWatch;
+use crate::read::LeasedReaderId;
 use crate::rpc::{PUBSUB_PUSH_DIFF_ENABLED, PubSubSender};
 use crate::schema::SchemaCache;
 use crate::{Diagnostics, PersistConfig, ShardId};
@@ -186,6 +187,15 @@ where
             })
     }
 
+    /// A point-in-time read of [StateCollections::since_without_reader] from
+    /// the current state.
+    pub fn since_without_reader(&self, reader_id: &LeasedReaderId) -> Option<Antichain<T>> {
+        self.state
+            .read_lock(&self.metrics.locks.applier_read_cacheable, |state| {
+                state.collections.since_without_reader(reader_id)
+            })
+    }
+
     /// A point-in-time read of `seqno` from the current state.
     ///
     /// Due to sharing state with other handles, successive reads to this fn or any other may
@@ -262,6 +272,41 @@ where
             })
     }
 
+    /// Returns the forks of this shard, see [StateCollections::register_fork].
+    pub fn forks(&self) -> BTreeMap<ShardId, SeqNo> {
+        self.state
+            .read_lock(&self.metrics.locks.applier_read_cacheable, |state| {
+                state.collections.forks.clone()
+            })
+    }
+
+    /// Returns the parts of this shard that GC kept around for its forks, see
+    /// [StateCollections::hold_fork_parts].
+    pub fn fork_held_parts(&self) -> BTreeMap<PartialBatchKey, RunPart<T>> {
+        self.state
+            .read_lock(&self.metrics.locks.applier_read_cacheable, |state| {
+                state.collections.fork_held_parts.clone()
+            })
+    }
+
+    /// Returns an error if this shard still has forks that may reference its
+    /// blobs, or parts that GC kept around for them.
+    ///
+    /// Once the since of the shard is empty, no new forks can be created, so
+    /// this stays true for the remaining lifetime of the shard.
+    pub fn check_no_forks(&self) -> Result<(), InvalidUsage<T>> {
+        self.state
+            .read_lock(&self.metrics.locks.applier_read_cacheable, |state| {
+                if !state.collections.forks.is_empty()
+                    || !state.collections.fork_held_parts.is_empty()
+                {
+                    Err(InvalidUsage::ForkedShardFinalization(state.shard_id))
+                } else {
+                    Ok(())
+                }
+            })
+    }
+
     /// Returns all rollups that are <= the given `seqno`.
     ///
     /// Due to sharing state with other handles, successive reads to this fn or any other may
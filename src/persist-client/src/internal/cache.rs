@@ -7,16 +7,22 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-//! In-process caches of [Blob].
+//! In-process and on-disk caches of [Blob].
 
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
 use async_trait::async_trait;
 use bytes::Bytes;
 use mz_dyncfg::{Config, ConfigSet};
 use mz_ore::bytes::SegmentedBytes;
 use mz_ore::cast::CastFrom;
+use mz_persist::encrypt::{BlobEncryptionKeys, ENCRYPTION_KEY_ID_PREFIX_LEN, encryption_key_id};
 use mz_persist::location::{Blob, BlobMetadata, ExternalError};
+use tracing::{debug, warn};
+use uuid::Uuid;
 
 use crate::cfg::PersistConfig;
 use crate::internal::metrics::Metrics;
@@ -170,6 +176,371 @@ impl Blob for BlobMemCache {
     }
 }
 
+/// Persistent on-disk cache for [Blob].
+///
+/// This is intended to sit "below" [BlobMemCache] on processes with access to
+/// local scratch disk. Each cached blob is stored as a file in `dir`, named by
+/// the hex-encoded blob key, and the directory is rescanned on startup so that
+/// the cache survives process restarts.
+///
+/// Each file starts with a header containing the length and a CRC32 checksum
+/// of the blob, which is validated on every read. Entries that fail
+/// validation are discarded and the blob is refetched from the wrapped [Blob].
+///
+/// If blobs are encrypted, the cache sits below [EncryptedBlob] and stores
+/// ciphertext. Reencrypting a blob rewrites it in place with a new master key,
+/// so an entry may hold a stale ciphertext of the blob. That's harmless while
+/// its master key is still known, as it decrypts to the same value, but once
+/// the key is removed the entry is treated as invalid and refetched.
+///
+/// [EncryptedBlob]: mz_persist::encrypt::EncryptedBlob
+#[derive(Debug)]
+pub struct BlobDiskCache {
+    /// [`ConfigSet`] of dynamic configs.
+    cfg: Arc<ConfigSet>,
+    dir: PathBuf,
+    metrics: Arc<Metrics>,
+    cache: Mutex<lru::Lru<String, ()>>,
+    /// The master keys that cached blobs may be encrypted with, if blobs are
+    /// encrypted.
+    keys: Option<BlobEncryptionKeys>,
+    blob: Arc<dyn Blob>,
+}
+
+pub(crate) const BLOB_CACHE_DISK_LIMIT_BYTES: Config<usize> = Config::new(
+    "persist_blob_cache_disk_limit_bytes",
+    // 8GiB
+    8 * 1024 * 1024 * 1024,
+    "Capacity of the on-disk blob cache in bytes, used only if the process has \
+    a scratch directory (Materialize).",
+);
+
+impl BlobDiskCache {
+    /// The length of the header prepended to each cached blob: a u64 length
+    /// followed by a u32 CRC32 of the blob, both little-endian.
+    const HEADER_LEN: usize = 12;
+
+    /// Returns `blob` wrapped in an on-disk cache stored in `dir`, reloading
+    /// any entries left there by a previous process.
+    ///
+    /// `keys` are the master keys that the values of `blob` may be encrypted
+    /// with, if any.
+    ///
+    /// The cache is an optimization, so if `dir` is unusable this logs a
+    /// warning and returns `blob` unchanged.
+    pub async fn open(
+        cfg: &PersistConfig,
+        dir: PathBuf,
+        metrics: Arc<Metrics>,
+        keys: Option<BlobEncryptionKeys>,
+        blob: Arc<dyn Blob>,
+    ) -> Arc<dyn Blob> {
+        let scan_dir = dir.clone();
+        let scan_keys = keys.clone();
+        let entries = mz_ore::task::spawn_blocking(
+            || "persist::blob_disk_cache::scan",
+            move || Self::scan(&scan_dir, scan_keys.as_ref()),
+        )
+        .await
+        .map_err(anyhow::Error::from)
+        .and_then(|res| res.map_err(anyhow::Error::from));
+        let entries = match entries {
+            Ok(entries) => entries,
+            Err(err) => {
+                warn!(
+                    "disabling persist disk blob cache at {}: {}",
+                    dir.display(),
+                    err
+                );
+                return blob;
+            }
+        };
+
+        let eviction_metrics = Arc::clone(&metrics);
+        let eviction_dir = dir.clone();
+        let capacity_bytes = BLOB_CACHE_DISK_LIMIT_BYTES.get(&cfg.configs);
+        let mut cache = lru::Lru::new(capacity_bytes, move |key: String, (), _| {
+            eviction_metrics.blob_cache_disk.evictions.inc();
+            Self::remove_file(&eviction_dir.join(hex::encode(key)));
+        });
+        // Entries are sorted by last modification, so the most recently
+        // written ones end up the most recently used.
+        for (key, weight) in entries {
+            cache.insert(key, (), weight);
+        }
+        let blob = BlobDiskCache {
+            cfg: Arc::clone(&cfg.configs),
+            dir,
+            metrics,
+            cache: Mutex::new(cache),
+            keys,
+            blob,
+        };
+        blob.resize_and_update_size_metrics(&mut blob.cache.lock().expect("lock poisoned"));
+        Arc::new(blob)
+    }
+
+    /// Returns the (key, weight) of every valid-looking entry in `dir`,
+    /// ordered from least to most recently modified, cleaning up anything
+    /// else, e.g. files left over from an interrupted write.
+    ///
+    /// This validates the header of each entry against the size of its file
+    /// and, if blobs are encrypted, that its master key is still in `keys`.
+    /// The checksum is only validated when an entry is read, as reading the
+    /// entire cache would delay startup.
+    fn scan(
+        dir: &Path,
+        keys: Option<&BlobEncryptionKeys>,
+    ) -> Result<Vec<(String, usize)>, io::Error> {
+        std::fs::create_dir_all(dir)?;
+        let mut entries = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            let key = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| hex::decode(name).ok())
+                .and_then(|key| String::from_utf8(key).ok());
+            let len = usize::cast_from(metadata.len());
+            let valid = || match Self::validate_prefix_sync(&entry.path(), len, keys) {
+                Ok(valid) => valid,
+                Err(err) => {
+                    warn!("failed to validate {}: {}", entry.path().display(), err);
+                    false
+                }
+            };
+            match key {
+                Some(key) if metadata.is_file() && len >= Self::HEADER_LEN && valid() => {
+                    let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                    entries.push((modified, key, len));
+                }
+                _ => {
+                    debug!("removing unexpected file {:?}", entry.path());
+                    Self::remove_file(&entry.path());
+                }
+            }
+        }
+        entries.sort();
+        Ok(entries
+            .into_iter()
+            .map(|(_, key, len)| (key, len))
+            .collect())
+    }
+
+    fn resize_and_update_size_metrics(&self, cache: &mut lru::Lru<String, ()>) {
+        cache.update_capacity(BLOB_CACHE_DISK_LIMIT_BYTES.get(&self.cfg));
+        self.metrics
+            .blob_cache_disk
+            .size_blobs
+            .set(u64::cast_from(cache.entry_count()));
+        self.metrics
+            .blob_cache_disk
+            .size_bytes
+            .set(u64::cast_from(cache.entry_weight()));
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(hex::encode(key))
+    }
+
+    fn remove_file(path: &Path) {
+        if let Err(err) = std::fs::remove_file(path) {
+            if err.kind() != io::ErrorKind::NotFound {
+                warn!("failed to remove {}: {}", path.display(), err);
+            }
+        }
+    }
+
+    /// Validates the header of the cached blob at `path`, whose file is
+    /// `file_len` bytes long, and whether its master key is still in `keys`.
+    fn validate_prefix_sync(
+        path: &Path,
+        file_len: usize,
+        keys: Option<&BlobEncryptionKeys>,
+    ) -> Result<bool, io::Error> {
+        let mut prefix = Vec::with_capacity(Self::HEADER_LEN + ENCRYPTION_KEY_ID_PREFIX_LEN);
+        std::fs::File::open(path)?
+            .take(u64::cast_from(prefix.capacity()))
+            .read_to_end(&mut prefix)?;
+        let Some((header, value)) = prefix.split_at_checked(Self::HEADER_LEN) else {
+            return Ok(false);
+        };
+        let len = u64::from_le_bytes(header[0..8].try_into().expect("8 bytes"));
+        if len != u64::cast_from(file_len - Self::HEADER_LEN) {
+            return Ok(false);
+        }
+        Ok(Self::has_known_key(value, keys))
+    }
+
+    /// Whether `value`, or a prefix of it, is encrypted with a master key in
+    /// `keys` or, unless `keys` is strict, not encrypted at all.
+    fn has_known_key(value: &[u8], keys: Option<&BlobEncryptionKeys>) -> bool {
+        let Some(keys) = keys else {
+            return true;
+        };
+        match encryption_key_id(value) {
+            Ok(Some(id)) => keys.contains(&id),
+            Ok(None) => !keys.strict(),
+            Err(_) => false,
+        }
+    }
+
+    /// Reads and validates the cached blob at `path`.
+    fn read_sync(path: &Path) -> Result<Bytes, io::Error> {
+        let mut buf = std::fs::read(path)?;
+        if buf.len() < Self::HEADER_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "truncated header",
+            ));
+        }
+        let data = buf.split_off(Self::HEADER_LEN);
+        let len = u64::from_le_bytes(buf[0..8].try_into().expect("8 bytes"));
+        let checksum = u32::from_le_bytes(buf[8..12].try_into().expect("4 bytes"));
+        if len != u64::cast_from(data.len()) || checksum != crc32fast::hash(&data) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "checksum mismatch",
+            ));
+        }
+        Ok(Bytes::from(data))
+    }
+
+    /// Writes `value` to `path`, via a temporary file so that a concurrent
+    /// reader never observes a partial write.
+    fn write_sync(path: &Path, value: SegmentedBytes) -> Result<(), io::Error> {
+        let mut hasher = crc32fast::Hasher::new();
+        let len = u64::cast_from(value.len());
+        let segments: Vec<_> = value.into_segments().collect();
+        for segment in segments.iter() {
+            hasher.update(segment);
+        }
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(format!(".{}.tmp", Uuid::new_v4()));
+        let tmp_path = PathBuf::from(tmp_path);
+        let res = (|| {
+            let mut file = std::io::BufWriter::new(std::fs::File::create(&tmp_path)?);
+            file.write_all(&len.to_le_bytes())?;
+            file.write_all(&hasher.finalize().to_le_bytes())?;
+            for segment in segments.iter() {
+                file.write_all(segment)?;
+            }
+            file.into_inner().map_err(|err| err.into_error())?;
+            std::fs::rename(&tmp_path, path)
+        })();
+        if res.is_err() {
+            Self::remove_file(&tmp_path);
+        }
+        res
+    }
+
+    /// Removes `key` from the cache, including its file.
+    fn evict(&self, key: &str) {
+        let mut cache = self.cache.lock().expect("lock poisoned");
+        if cache.remove(key).is_some() {
+            Self::remove_file(&self.path(key));
+        }
+        self.resize_and_update_size_metrics(&mut cache);
+    }
+}
+
+#[async_trait]
+impl Blob for BlobDiskCache {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        // Blobs are write-once modify-never, except for reencryption, so as
+        // with BlobMemCache, anything in the cache that passes validation and
+        // whose master key is still known decrypts to the value in the
+        // backing store.
+        let cached = self.cache.lock().expect("lock poisoned").get(key).is_some();
+        if cached {
+            let path = self.path(key);
+            let res = mz_ore::task::spawn_blocking(
+                || "persist::blob_disk_cache::get",
+                move || Self::read_sync(&path),
+            )
+            .await?;
+            match res {
+                Ok(value) if !Self::has_known_key(&value, self.keys.as_ref()) => {
+                    debug!(
+                        "discarding disk blob cache entry {} encrypted with a removed key",
+                        key
+                    );
+                    self.evict(key);
+                }
+                Ok(value) => {
+                    self.metrics.blob_cache_disk.hits_blobs.inc();
+                    self.metrics
+                        .blob_cache_disk
+                        .hits_bytes
+                        .inc_by(u64::cast_from(value.len()));
+                    return Ok(Some(SegmentedBytes::from(value)));
+                }
+                Err(err) => {
+                    if err.kind() == io::ErrorKind::InvalidData {
+                        self.metrics.blob_cache_disk.checksum_failures.inc();
+                        warn!("discarding corrupt disk blob cache entry {}: {}", key, err);
+                    } else if err.kind() != io::ErrorKind::NotFound {
+                        warn!("failed to read disk blob cache entry {}: {}", key, err);
+                    }
+                    self.evict(key);
+                }
+            }
+        }
+        self.metrics.blob_cache_disk.misses_blobs.inc();
+
+        let res = self.blob.get(key).await?;
+        if let Some(value) = res.as_ref() {
+            // As with BlobMemCache, skip blobs that would immediately evict
+            // themselves.
+            let weight = Self::HEADER_LEN + value.len();
+            let capacity = self.cache.lock().expect("lock poisoned").capacity();
+            if weight <= capacity {
+                let path = self.path(key);
+                let value = value.clone();
+                let written = mz_ore::task::spawn_blocking(
+                    || "persist::blob_disk_cache::set",
+                    move || Self::write_sync(&path, value),
+                )
+                .await?;
+                match written {
+                    Ok(()) => {
+                        let mut cache = self.cache.lock().expect("lock poisoned");
+                        cache.insert(key.to_owned(), (), weight);
+                        self.resize_and_update_size_metrics(&mut cache);
+                    }
+                    Err(err) => warn!("failed to write disk blob cache entry {}: {}", key, err),
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        // Writes are served by BlobMemCache. The disk cache is populated only
+        // on reads, which keeps local disk IO off the write path.
+        self.blob.set(key, value).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        let res = self.blob.delete(key).await;
+        self.evict(key);
+        res
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        self.blob.restore(key).await
+    }
+}
+
 mod lru {
     use std::borrow::Borrow;
     use std::collections::BTreeMap;
@@ -410,12 +781,23 @@ mod lru {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
+    use bytes::Bytes;
     use mz_ore::assert_none;
+    use mz_ore::metrics::MetricsRegistry;
+    use mz_persist::encrypt::{BlobEncryptionKey, BlobEncryptionKeys, EncryptedBlob};
+    use mz_persist::location::Blob;
+    use mz_persist::mem::{MemBlob, MemBlobConfig};
     use proptest::arbitrary::any;
     use proptest::proptest;
     use proptest_derive::Arbitrary;
 
+    use crate::cfg::PersistConfig;
+    use crate::internal::metrics::Metrics;
+
     use super::lru::*;
+    use super::{BLOB_CACHE_DISK_LIMIT_BYTES, BlobDiskCache};
 
     #[derive(Debug, Arbitrary)]
     enum LruOp {
@@ -555,4 +937,164 @@ mod tests {
         assert_eq!(cache.entry_weight(), 2);
         assert_eq!(cache.keys(), &["j", "i"]);
     }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn blob_disk_cache() {
+        let temp_dir = tempfile::tempdir().expect("tempdir");
+        let dir = temp_dir.path().join("cache");
+        let cfg = PersistConfig::new_for_tests();
+        let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
+        let inner: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let get = |blob: Arc<dyn Blob>, key: &'static str| async move {
+            blob.get(key)
+                .await
+                .expect("get")
+                .map(|x| Bytes::from(x.into_contiguous()))
+        };
+
+        inner.set("a", Bytes::from("aaaa")).await.expect("set");
+        inner.set("b/c", Bytes::from("bbbb")).await.expect("set");
+
+        // A miss populates the cache and subsequent reads are hits.
+        let cache = BlobDiskCache::open(
+            &cfg,
+            dir.clone(),
+            Arc::clone(&metrics),
+            None,
+            Arc::clone(&inner),
+        )
+        .await;
+        assert_eq!(
+            get(Arc::clone(&cache), "a").await,
+            Some(Bytes::from("aaaa"))
+        );
+        assert_eq!(
+            get(Arc::clone(&cache), "a").await,
+            Some(Bytes::from("aaaa"))
+        );
+        assert_eq!(
+            get(Arc::clone(&cache), "b/c").await,
+            Some(Bytes::from("bbbb"))
+        );
+        assert_eq!(get(Arc::clone(&cache), "missing").await, None);
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 1);
+        assert_eq!(metrics.blob_cache_disk.misses_blobs.get(), 3);
+        assert_eq!(metrics.blob_cache_disk.size_blobs.get(), 2);
+
+        // Entries survive a restart, even if the backing blob is gone.
+        let empty: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let cache = BlobDiskCache::open(
+            &cfg,
+            dir.clone(),
+            Arc::clone(&metrics),
+            None,
+            Arc::clone(&empty),
+        )
+        .await;
+        assert_eq!(metrics.blob_cache_disk.size_blobs.get(), 2);
+        assert_eq!(
+            get(Arc::clone(&cache), "b/c").await,
+            Some(Bytes::from("bbbb"))
+        );
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 2);
+
+        // Corrupt entries are discarded and refetched.
+        let path = dir.join(hex::encode("a"));
+        let mut contents = std::fs::read(&path).expect("read");
+        *contents.last_mut().expect("non-empty") ^= 0xff;
+        std::fs::write(&path, contents).expect("write");
+        let cache = BlobDiskCache::open(
+            &cfg,
+            dir.clone(),
+            Arc::clone(&metrics),
+            None,
+            Arc::clone(&inner),
+        )
+        .await;
+        assert_eq!(
+            get(Arc::clone(&cache), "a").await,
+            Some(Bytes::from("aaaa"))
+        );
+        assert_eq!(metrics.blob_cache_disk.checksum_failures.get(), 1);
+        assert_eq!(
+            get(Arc::clone(&cache), "a").await,
+            Some(Bytes::from("aaaa"))
+        );
+        assert_eq!(metrics.blob_cache_disk.checksum_failures.get(), 1);
+
+        // Deletes remove the entry from disk.
+        cache.delete("a").await.expect("delete");
+        assert!(!path.exists());
+        assert_eq!(get(Arc::clone(&cache), "a").await, None);
+
+        // Shrinking the limit evicts entries on the next insert.
+        inner.set("d", Bytes::from("dddd")).await.expect("set");
+        cfg.set_config(
+            &BLOB_CACHE_DISK_LIMIT_BYTES,
+            BlobDiskCache::HEADER_LEN + "dddd".len(),
+        );
+        assert_eq!(
+            get(Arc::clone(&cache), "d").await,
+            Some(Bytes::from("dddd"))
+        );
+        assert_eq!(metrics.blob_cache_disk.size_blobs.get(), 1);
+        assert_eq!(metrics.blob_cache_disk.evictions.get(), 1);
+        assert!(!dir.join(hex::encode("b/c")).exists());
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn blob_disk_cache_reencrypted() {
+        let temp_dir = tempfile::tempdir().expect("tempdir");
+        let dir = temp_dir.path().join("cache");
+        let cfg = PersistConfig::new_for_tests();
+        let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
+        let inner: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let keys = BlobEncryptionKeys::new(BlobEncryptionKey::generate("k1").expect("key"));
+        let open = |inner: Arc<dyn Blob>| {
+            let (cfg, dir, metrics, keys) = (&cfg, dir.clone(), Arc::clone(&metrics), keys.clone());
+            async move {
+                let cache = BlobDiskCache::open(cfg, dir, metrics, Some(keys.clone()), inner).await;
+                Arc::new(EncryptedBlob::new(cache, keys))
+            }
+        };
+        let get = |blob: Arc<EncryptedBlob>| async move {
+            blob.get("a")
+                .await
+                .expect("get")
+                .map(|x| Bytes::from(x.into_contiguous()))
+        };
+
+        // Populate the cache with a value encrypted with k1.
+        let encrypted = EncryptedBlob::new(Arc::clone(&inner), keys.clone());
+        encrypted.set("a", Bytes::from("aaaa")).await.expect("set");
+        let cache = open(Arc::clone(&inner)).await;
+        assert_eq!(get(Arc::clone(&cache)).await, Some(Bytes::from("aaaa")));
+        assert_eq!(metrics.blob_cache_disk.size_blobs.get(), 1);
+
+        // Reencrypting the blob with k2 leaves the cached ciphertext stale,
+        // which is served while k1 is still known.
+        keys.rotate(BlobEncryptionKey::generate("k2").expect("key"))
+            .expect("rotate");
+        assert!(encrypted.reencrypt("a").await.expect("reencrypt"));
+        assert_eq!(get(Arc::clone(&cache)).await, Some(Bytes::from("aaaa")));
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 1);
+
+        // Once k1 is removed, the stale entry is refetched.
+        keys.remove("k1").expect("remove");
+        assert_eq!(get(Arc::clone(&cache)).await, Some(Bytes::from("aaaa")));
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 1);
+        assert_eq!(get(Arc::clone(&cache)).await, Some(Bytes::from("aaaa")));
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 2);
+
+        // Entries whose key was removed while the process was down are
+        // discarded on startup.
+        keys.rotate(BlobEncryptionKey::generate("k3").expect("key"))
+            .expect("rotate");
+        keys.remove("k2").expect("remove");
+        let _cache = open(Arc::clone(&inner)).await;
+        assert_eq!(metrics.blob_cache_disk.size_blobs.get(), 0);
+        assert!(!dir.join(hex::encode("a")).exists());
+    }
 }
@@ -343,6 +343,8 @@ impl<T: Timestamp + Codec64> RustType<ProtoStateDiff> for StateDiff<T> {
             critical_readers,
             writers,
             schemas,
+            forks,
+            fork_held_parts,
             since,
             legacy_batches,
             hollow_batches,
@@ -368,6 +370,8 @@ impl<T: Timestamp + Codec64> RustType<ProtoStateDiff> for StateDiff<T> {
         );
         field_diffs_into_proto(ProtoStateField::Writers, writers, &mut writer);
         field_diffs_into_proto(ProtoStateField::Schemas, schemas, &mut writer);
+        field_diffs_into_proto(ProtoStateField::Forks, forks, &mut writer);
+        field_diffs_into_proto(ProtoStateField::ForkHeldParts, fork_held_parts, &mut writer);
         field_diffs_into_proto(ProtoStateField::Since, since, &mut writer);
         field_diffs_into_proto(ProtoStateField::LegacyBatches, legacy_batches, &mut writer);
         field_diffs_into_proto(ProtoStateField::HollowBatches, hollow_batches, &mut writer);
@@ -462,6 +466,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoStateDiff> for StateDiff<T> {
                                 Ok(HollowRollup {
                                     key: v.into_rust()?,
                                     encoded_size_bytes: None,
+                                    encryption_key_id: None,
                                 })
                             },
                         )?
@@ -498,6 +503,20 @@ impl<T: Timestamp + Codec64> RustType<ProtoStateDiff> for StateDiff<T> {
                             |v| v.into_rust(),
                         )?
                     }
+                    ProtoStateField::Forks => field_diff_into_rust::<String, u64, _, _, _, _>(
+                        diff,
+                        &mut state_diff.forks,
+                        |k| k.into_rust(),
+                        |v| v.into_rust(),
+                    )?,
+                    ProtoStateField::ForkHeldParts => {
+                        field_diff_into_rust::<String, ProtoHollowBatchPart, _, _, _, _>(
+                            diff,
+                            &mut state_diff.fork_held_parts,
+                            |k| k.into_rust(),
+                            |v| v.into_rust(),
+                        )?
+                    }
                     ProtoStateField::Since => {
                         field_diff_into_rust::<(), ProtoU64Antichain, _, _, _, _>(
                             diff,
@@ -913,6 +932,22 @@ impl<T: Timestamp + Lattice + Codec64> RustType<ProtoRollup> for Rollup<T> {
                 .iter()
                 .map(|(id, schema)| (id.into_proto(), schema.into_proto()))
                 .collect(),
+            forks: self
+                .state
+                .state
+                .collections
+                .forks
+                .iter()
+                .map(|(id, seqno)| (id.into_proto(), seqno.into_proto()))
+                .collect(),
+            fork_held_parts: self
+                .state
+                .state
+                .collections
+                .fork_held_parts
+                .iter()
+                .map(|(key, part)| (key.into_proto(), part.into_proto()))
+                .collect(),
             trace: Some(self.state.state.collections.trace.into_proto()),
             diffs: self.diffs.as_ref().map(|x| x.into_proto()),
         }
@@ -943,6 +978,7 @@ impl<T: Timestamp + Lattice + Codec64> RustType<ProtoRollup> for Rollup<T> {
                 HollowRollup {
                     key: key.into_rust()?,
                     encoded_size_bytes: None,
+                    encryption_key_id: None,
                 },
             );
         }
@@ -962,6 +998,14 @@ impl<T: Timestamp + Lattice + Codec64> RustType<ProtoRollup> for Rollup<T> {
         for (id, x) in x.schemas {
             schemas.insert(id.into_rust()?, x.into_rust()?);
         }
+        let mut forks = BTreeMap::new();
+        for (id, seqno) in x.forks {
+            forks.insert(id.into_rust()?, seqno.into_rust()?);
+        }
+        let mut fork_held_parts = BTreeMap::new();
+        for (key, part) in x.fork_held_parts {
+            fork_held_parts.insert(key.into_rust()?, part.into_rust()?);
+        }
         let active_rollup = x
             .active_rollup
             .map(|rollup| rollup.into_rust())
@@ -976,6 +1020,8 @@ impl<T: Timestamp + Lattice + Codec64> RustType<ProtoRollup> for Rollup<T> {
             critical_readers,
             writers,
             schemas,
+            forks,
+            fork_held_parts,
             trace: x.trace.into_rust_if_some("trace")?,
         };
         let state = State {
@@ -1367,6 +1413,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoHollowBatch> for HollowBatch<T> {
                 format: None,
                 schema_id: None,
                 deprecated_schema_id: None,
+                encryption_key_id: None,
             }))
         }));
         // We discard default metadatas from the proto above; re-add them here.
@@ -1452,6 +1499,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoHollowBatchPart> for HollowRunRef<T>
             schema_id: None,
             structured_key_lower: self.structured_key_lower.into_proto(),
             deprecated_schema_id: None,
+            encryption_key_id: self.encryption_key_id.clone(),
         };
         part
     }
@@ -1469,6 +1517,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoHollowBatchPart> for HollowRunRef<T>
             max_part_bytes: run_proto.max_part_bytes.into_rust()?,
             key_lower: proto.key_lower.to_vec(),
             structured_key_lower: proto.structured_key_lower.into_rust()?,
+            encryption_key_id: proto.encryption_key_id,
             _phantom_data: Default::default(),
         })
     }
@@ -1488,6 +1537,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoHollowBatchPart> for BatchPart<T> {
                 format: x.format.map(|f| f.into_proto()),
                 schema_id: x.schema_id.into_proto(),
                 deprecated_schema_id: x.deprecated_schema_id.into_proto(),
+                encryption_key_id: x.encryption_key_id.clone(),
             },
             BatchPart::Inline {
                 updates,
@@ -1505,6 +1555,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoHollowBatchPart> for BatchPart<T> {
                 format: None,
                 schema_id: schema_id.into_proto(),
                 deprecated_schema_id: deprecated_schema_id.into_proto(),
+                encryption_key_id: None,
             },
         }
     }
@@ -1529,6 +1580,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoHollowBatchPart> for BatchPart<T> {
                     format: proto.format.map(|f| f.into_rust()).transpose()?,
                     schema_id,
                     deprecated_schema_id,
+                    encryption_key_id: proto.encryption_key_id,
                 }))
             }
             Some(proto_hollow_batch_part::Kind::Inline(x)) => {
@@ -1710,6 +1762,7 @@ impl RustType<ProtoHollowRollup> for HollowRollup {
         ProtoHollowRollup {
             key: self.key.into_proto(),
             encoded_size_bytes: self.encoded_size_bytes.into_proto(),
+            encryption_key_id: self.encryption_key_id.clone(),
         }
     }
 
@@ -1717,6 +1770,7 @@ impl RustType<ProtoHollowRollup> for HollowRollup {
         Ok(HollowRollup {
             key: proto.key.into_rust()?,
             encoded_size_bytes: proto.encoded_size_bytes.into_rust()?,
+            encryption_key_id: proto.encryption_key_id,
         })
     }
 }
@@ -1803,7 +1857,7 @@ mod tests {
 
     use crate::ShardId;
     use crate::internal::paths::PartialRollupKey;
-    use crate::internal::state::tests::any_state;
+    use crate::internal::state::tests::{any_run_part, any_state};
     use crate::internal::state::{BatchPart, HandleDebugState};
     use crate::internal::state_diff::StateDiff;
     use crate::tests::new_test_client_cache;
@@ -1896,6 +1950,7 @@ mod tests {
                 format: None,
                 schema_id: None,
                 deprecated_schema_id: None,
+                encryption_key_id: None,
             }))],
             4,
         );
@@ -1923,6 +1978,7 @@ mod tests {
                 format: None,
                 schema_id: None,
                 deprecated_schema_id: None,
+                encryption_key_id: None,
             })));
         assert_eq!(<HollowBatch<u64>>::from_proto(old).unwrap(), expected);
     }
@@ -1981,10 +2037,12 @@ mod tests {
         let r1 = HollowRollup {
             key: PartialRollupKey("foo".to_owned()),
             encoded_size_bytes: None,
+            encryption_key_id: None,
         };
         let r2 = HollowRollup {
             key: PartialRollupKey("bar".to_owned()),
             encoded_size_bytes: Some(2),
+            encryption_key_id: None,
         };
         let shard_id = ShardId::new();
         let mut state = TypedState::<(), (), u64, i64>::new(
@@ -2020,6 +2078,7 @@ mod tests {
         let r1_rollup = HollowRollup {
             key: PartialRollupKey("foo".to_owned()),
             encoded_size_bytes: None,
+            encryption_key_id: None,
         };
         let r1 = StateFieldDiff {
             key: SeqNo(1),
@@ -2028,6 +2087,7 @@ mod tests {
         let r2_rollup = HollowRollup {
             key: PartialRollupKey("bar".to_owned()),
             encoded_size_bytes: Some(2),
+            encryption_key_id: None,
         };
         let r2 = StateFieldDiff {
             key: SeqNo(2),
@@ -2036,6 +2096,7 @@ mod tests {
         let r3_rollup = HollowRollup {
             key: PartialRollupKey("baz".to_owned()),
             encoded_size_bytes: None,
+            encryption_key_id: None,
         };
         let r3 = StateFieldDiff {
             key: SeqNo(3),
@@ -2126,6 +2187,19 @@ mod tests {
         }
 
         proptest!(|(state in any_state::<u64>(0..3))| testcase(state));
+        proptest!(|(
+            mut state in any_state::<u64>(0..3),
+            forks in proptest::collection::btree_map(any::<ShardId>(), any::<SeqNo>(), 1..3),
+            fork_held_parts in proptest::collection::btree_map(
+                any::<PartialBatchKey>(),
+                any_run_part::<u64>(),
+                0..3,
+            ),
+        )| {
+            state.collections.forks = forks;
+            state.collections.fork_held_parts = fork_held_parts;
+            testcase(state)
+        });
     }
 
     #[mz_ore::test]
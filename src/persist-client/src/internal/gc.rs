@@ -39,8 +39,8 @@ use crate::ShardId;
 use crate::internal::machine::{Machine, retry_external};
 use crate::internal::maintenance::RoutineMaintenance;
 use crate::internal::metrics::{GcStepTimings, RetryMetrics};
-use crate::internal::paths::{BlobKey, PartialBlobKey, PartialRollupKey};
-use crate::internal::state::HollowBlobRef;
+use crate::internal::paths::{BlobKey, PartialBatchKey, PartialBlobKey, PartialRollupKey};
+use crate::internal::state::{HollowBlobRef, RunPart};
 use crate::internal::state_versions::{InspectDiff, StateVersionsIter};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -112,6 +112,15 @@ impl<K, V, T, D> Clone for GarbageCollector<K, V, T, D> {
 ///   leaked. We anyway always have the possibility of a write process being
 ///   killed between when it writes a blob and links it into state, so this is
 ///   fine; it'll be caught and fixed by the same mechanism.)
+/// - Forks (see [crate::PersistClient::fork_shard]) reference the blobs of the
+///   shard they were forked from. GC of a fork never deletes these foreign
+///   blobs. Instead, GC of the source scans the live versions of the state of
+///   each of its forks, and skips deleting the blobs a fork still references.
+///   It records them in the state of the source before truncating the diffs
+///   that deleted them, and deletes them in a later run once no fork
+///   references them anymore. A fork can only ever drop these references, so
+///   this happens once it has compacted them away or been finalized, at which
+///   point the fork is also released.
 impl<K, V, T, D> GarbageCollector<K, V, T, D>
 where
     K: Debug + Codec,
@@ -295,12 +304,17 @@ where
             states.len()
         );
 
+        let (fork_references, mut maintenance) =
+            Self::release_unreferenced_forks(machine, req.new_seqno_since).await;
+
         Self::incrementally_delete_and_truncate(
             &mut states,
             &gc_rollups,
+            &fork_references,
             machine,
             &mut report_step_timing,
             &mut gc_results,
+            &mut maintenance,
         )
         .await;
 
@@ -320,8 +334,9 @@ where
         // In short, while this step is not incremental, it does not need
         // to be for GC to efficiently resume. And in fact, making it
         // incremental could be quite expensive (e.g. more CaS operations).
-        let (removed_rollups, maintenance) =
+        let (removed_rollups, more_maintenance) =
             machine.remove_rollups(rollups_to_remove_from_state).await;
+        maintenance.merge(more_maintenance);
         report_step_timing(&machine.applier.metrics.gc.steps.remove_rollups_from_state);
         debug!("CaS removed rollups from state: {:?}", removed_rollups);
         gc_results.rollups_removed_from_state = removed_rollups;
@@ -386,29 +401,123 @@ where
         (maintenance, gc_results)
     }
 
+    /// Returns the keys of the blobs of this shard that the forks created
+    /// before `seqno` reference in any live version of their state.
+    ///
+    /// Forks that were finalized, or don't reference any blobs of this shard
+    /// because they compacted away all the data they were forked with, are
+    /// released: they can't start referencing them again. Likewise, the parts
+    /// that GC kept around for forks, but that none of them references
+    /// anymore, are deleted.
+    pub(crate) async fn release_unreferenced_forks(
+        machine: &Machine<K, V, T, D>,
+        seqno: SeqNo,
+    ) -> (BTreeSet<PartialBatchKey>, RoutineMaintenance) {
+        let shard_id = machine.shard_id();
+        let mut referenced = BTreeSet::new();
+        let mut maintenance = RoutineMaintenance::default();
+        for (fork, fork_seqno) in machine.applier.forks() {
+            if fork_seqno >= seqno {
+                continue;
+            }
+            let Some(states) = machine
+                .applier
+                .state_versions
+                .fetch_all_live_states::<T>(fork)
+                .await
+            else {
+                // The fork is still being created, in the meantime the blobs
+                // it references are held back by a leased reader.
+                continue;
+            };
+            let mut states = states
+                .check_ts_codec()
+                .expect("a fork has the ts codec of its source");
+            let mut fork_referenced = BTreeSet::new();
+            while let Some(_) = states.next(|diff| {
+                for blob in diff.referenced_blobs() {
+                    let HollowBlobRef::Batch(batch) = blob else {
+                        continue;
+                    };
+                    for key in batch.parts.iter().filter_map(|part| part.hollow_key()) {
+                        if key.owner() == Some(shard_id) {
+                            fork_referenced.insert(key.local());
+                        }
+                    }
+                }
+            }) {}
+            // Older versions of the state of a finalized fork can't be read
+            // anymore, whatever blobs they reference.
+            if fork_referenced.is_empty() || states.state().collections.is_tombstone() {
+                debug!("releasing fork {fork} of {shard_id}");
+                let (_, more_maintenance) = machine.release_fork(&fork).await;
+                maintenance.merge(more_maintenance);
+            } else {
+                referenced.append(&mut fork_referenced);
+            }
+        }
+
+        // Forks created at or after `seqno` can't reference any of the held
+        // parts: these were removed from the trace before the seqno_since of
+        // some earlier GC run, which is at most `seqno`.
+        let mut unreferenced = PartDeletes::default();
+        let mut unreferenced_keys = Vec::new();
+        for (key, part) in machine.applier.fork_held_parts() {
+            if !referenced.contains(&key) {
+                unreferenced.add(&part);
+                unreferenced_keys.push(key);
+            }
+        }
+        if !unreferenced_keys.is_empty() {
+            debug!(
+                "deleting {} parts of {shard_id} no longer referenced by forks",
+                unreferenced_keys.len()
+            );
+            // Delete the blobs before forgetting about them, so that they are
+            // not leaked if this is interrupted.
+            unreferenced
+                .delete(
+                    machine.applier.state_versions.blob.borrow(),
+                    shard_id,
+                    GC_BLOB_DELETE_CONCURRENCY_LIMIT.get(&machine.applier.cfg),
+                    &*machine.applier.metrics,
+                    &machine.applier.metrics.retries.external.batch_delete,
+                )
+                .instrument(debug_span!("batch::delete"))
+                .await;
+            maintenance.merge(machine.free_fork_parts(&unreferenced_keys).await);
+        }
+        (referenced, maintenance)
+    }
+
     /// Physically deletes all blobs from Blob and live diffs from Consensus that
     /// are safe to delete, given the `seqno_since`, ensuring that the earliest
     /// live diff in Consensus has a rollup of seqno `<= seqno_since`.
     ///
     /// Internally, performs deletions for each rollup encountered, ensuring that
     /// incremental progress is made even if the process is interrupted before
-    /// completing all gc work.
+    /// completing all gc work. Instead of deleting the parts in
+    /// `fork_references`, records them in state for a later run to delete.
     async fn incrementally_delete_and_truncate<F>(
         states: &mut StateVersionsIter<T>,
         gc_rollups: &GcRollups,
+        fork_references: &BTreeSet<PartialBatchKey>,
         machine: &Machine<K, V, T, D>,
         timer: &mut F,
         gc_results: &mut GcResults,
+        maintenance: &mut RoutineMaintenance,
     ) where
         F: FnMut(&Counter),
     {
         assert_eq!(states.state().shard_id, machine.shard_id());
         let shard_id = states.state().shard_id;
         let mut batch_parts_to_delete = PartDeletes::default();
+        let mut batch_parts_to_hold = Vec::new();
         let mut rollups_to_delete: BTreeSet<PartialRollupKey> = BTreeSet::new();
 
         for truncate_lt in gc_rollups.truncate_seqnos() {
             assert!(batch_parts_to_delete.is_empty());
+            assert!(batch_parts_to_hold.is_empty());
             assert!(rollups_to_delete.is_empty());
 
             // our state is already past the truncation point. there's no work to do --
@@ -428,9 +537,11 @@ where
             Self::find_removable_blobs(
                 states,
                 truncate_lt,
+                fork_references,
                 &machine.applier.metrics.gc.steps,
                 timer,
                 &mut batch_parts_to_delete,
+                &mut batch_parts_to_hold,
                 &mut rollups_to_delete,
             );
 
@@ -447,6 +558,20 @@ where
             // * `seqno_since` >= `truncate_lt`, therefore we must have enough live
             //   diffs to reach `truncate_lt`.
             assert_eq!(states.state().seqno, truncate_lt);
+
+            // Forks still reference some of the deleted parts. Record them in
+            // state before truncating the diffs that deleted them, which are
+            // otherwise the last reference to them.
+            if !batch_parts_to_hold.is_empty() {
+                debug!(
+                    "gc of {} keeping {} parts referenced by forks",
+                    shard_id,
+                    batch_parts_to_hold.len()
+                );
+                maintenance.merge(machine.hold_fork_parts(&batch_parts_to_hold).await);
+                batch_parts_to_hold.clear();
+            }
+
             // `truncate_lt` _is_ the seqno of a rollup, but let's very explicitly
             // assert that we're about to truncate everything less than a rollup
             // to maintain our invariant.
@@ -497,16 +622,19 @@ where
     }
 
     /// Iterates through `states`, accumulating all deleted blobs (both batch parts
-    /// and rollups) until reaching the seqno `truncate_lt`.
+    /// and rollups) until reaching the seqno `truncate_lt`. Deleted batch parts
+    /// in `fork_references` go to `batch_parts_to_hold` instead.
     ///
     /// * The initial seqno of `states` MUST be less than `truncate_lt`.
     /// * The seqno of `states` after this fn will be exactly `truncate_lt`.
     fn find_removable_blobs<F>(
         states: &mut StateVersionsIter<T>,
         truncate_lt: SeqNo,
+        fork_references: &BTreeSet<PartialBatchKey>,
         metrics: &GcStepTimings,
         timer: &mut F,
         batch_parts_to_delete: &mut PartDeletes<T>,
+        batch_parts_to_hold: &mut Vec<RunPart<T>>,
         rollups_to_delete: &mut BTreeSet<PartialRollupKey>,
     ) where
         F: FnMut(&Counter),
@@ -518,6 +646,13 @@ where
                 diff.blob_deletes().for_each(|blob| match blob {
                     HollowBlobRef::Batch(batch) => {
                         for part in &batch.parts {
+                            if part
+                                .hollow_key()
+                                .is_some_and(|key| fork_references.contains(key))
+                            {
+                                batch_parts_to_hold.push(part.clone());
+                                continue;
+                            }
                             // we use BTreeSets for fast lookups elsewhere, but we should never
                             // see repeat blob insertions within a single GC run, otherwise we
                             // have a logic error or our diffs are incorrect (!)
@@ -45,11 +45,11 @@ use crate::internal::compact::CompactReq;
 use crate::internal::gc::GarbageCollector;
 use crate::internal::maintenance::{RoutineMaintenance, WriterMaintenance};
 use crate::internal::metrics::{CmdMetrics, Metrics, MetricsRetryStream, RetryMetrics};
-use crate::internal::paths::PartialRollupKey;
+use crate::internal::paths::{PartialBatchKey, PartialRollupKey};
 use crate::internal::state::{
-    CompareAndAppendBreak, CriticalReaderState, HandleDebugState, HollowBatch, HollowRollup,
-    IdempotencyToken, LeasedReaderState, NoOpStateTransition, Since, SnapshotErr, StateCollections,
-    Upper,
+    CompareAndAppendBreak, CriticalReaderState, ForkState, HandleDebugState, HollowBatch,
+    HollowRollup, IdempotencyToken, LeasedReaderState, NoOpStateTransition, RunPart, Since,
+    SnapshotErr, StateCollections, Upper,
 };
 use crate::internal::state_versions::StateVersions;
 use crate::internal::trace::{ApplyMergeResult, FueledMergeRes};
@@ -725,6 +725,67 @@ where
         (seqno, maintenance)
     }
 
+    /// See [StateCollections::register_fork].
+    pub async fn register_fork(
+        &self,
+        reader_id: &LeasedReaderId,
+        target: &ShardId,
+        as_of: &Antichain<T>,
+        lease_duration: Duration,
+        heartbeat_timestamp_ms: u64,
+    ) -> (Result<ForkState<T>, InvalidUsage<T>>, RoutineMaintenance) {
+        let metrics = Arc::clone(&self.applier.metrics);
+        let source = self.shard_id();
+        let (_seqno, res, maintenance) = self
+            .apply_unbatched_idempotent_cmd(&metrics.cmds.register, |seqno, cfg, state| {
+                state.register_fork(
+                    &cfg.hostname,
+                    reader_id,
+                    &source,
+                    target,
+                    seqno,
+                    as_of,
+                    lease_duration,
+                    heartbeat_timestamp_ms,
+                )
+            })
+            .await;
+        (res, maintenance)
+    }
+
+    /// See [StateCollections::release_fork].
+    pub async fn release_fork(&self, target: &ShardId) -> (bool, RoutineMaintenance) {
+        let metrics = Arc::clone(&self.applier.metrics);
+        let (_seqno, existed, maintenance) = self
+            .apply_unbatched_idempotent_cmd(&metrics.cmds.expire_reader, |_, _, state| {
+                state.release_fork(target)
+            })
+            .await;
+        (existed, maintenance)
+    }
+
+    /// See [StateCollections::hold_fork_parts].
+    pub async fn hold_fork_parts(&self, parts: &[RunPart<T>]) -> RoutineMaintenance {
+        let metrics = Arc::clone(&self.applier.metrics);
+        let (_seqno, (), maintenance) = self
+            .apply_unbatched_idempotent_cmd(&metrics.cmds.remove_rollups, |_, _, state| {
+                state.hold_fork_parts(parts)
+            })
+            .await;
+        maintenance
+    }
+
+    /// See [StateCollections::free_fork_parts].
+    pub async fn free_fork_parts(&self, keys: &[PartialBatchKey]) -> RoutineMaintenance {
+        let metrics = Arc::clone(&self.applier.metrics);
+        let (_seqno, (), maintenance) = self
+            .apply_unbatched_idempotent_cmd(&metrics.cmds.remove_rollups, |_, _, state| {
+                state.free_fork_parts(keys)
+            })
+            .await;
+        maintenance
+    }
+
     #[allow(dead_code)] // TODO(bkirwi): remove this when since behaviour on expiry has settled
     pub async fn expire_critical_reader(
         &self,
@@ -829,6 +890,15 @@ where
 
         let mut maintenance = RoutineMaintenance::default();
 
+        // Forks that were finalized in the meantime don't need to hold up the
+        // finalization of this shard.
+        if !self.applier.forks().is_empty() || !self.applier.fork_held_parts().is_empty() {
+            let (_, more_maintenance) =
+                GarbageCollector::release_unreferenced_forks(self, self.seqno()).await;
+            maintenance.merge(more_maintenance);
+        }
+        self.applier.check_no_forks()?;
+
         loop {
             let (made_progress, more_maintenance) = self.tombstone_step().await?;
             maintenance.merge(more_maintenance);
@@ -2487,6 +2557,7 @@ pub mod datadriven {
 #[cfg(test)]
 pub mod tests {
     use std::sync::Arc;
+    use std::time::Duration;
 
     use mz_dyncfg::ConfigUpdates;
     use mz_ore::cast::CastFrom;
@@ -2495,12 +2566,14 @@ pub mod tests {
     use mz_persist::location::SeqNo;
     use timely::progress::Antichain;
 
-    use crate::ShardId;
     use crate::batch::BatchBuilderConfig;
     use crate::cache::StateCache;
+    use crate::cli::admin::dangerous_force_compaction_and_break_pushdown;
     use crate::internal::gc::{GarbageCollector, GcReq};
     use crate::internal::state::{HandleDebugState, ROLLUP_THRESHOLD};
-    use crate::tests::new_test_client;
+    use crate::tests::{all_ok, new_test_client};
+    use crate::write::WriteHandle;
+    use crate::{Diagnostics, PersistClient, ShardId};
 
     #[mz_persist_proc::test(tokio::test(flavor = "multi_thread"))]
     #[cfg_attr(miri, ignore)] // error: unsupported operation: integer-to-pointer casts and `ptr::from_exposed_addr` are not supported with `-Zmiri-strict-provenance`
@@ -2566,6 +2639,113 @@ pub mod tests {
         );
     }
 
+    // Forks referencing blobs of their source don't hold back truncation of
+    // the source's state: GC of the source keeps just those blobs around, and
+    // deletes them once the fork no longer references them.
+    #[mz_persist_proc::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: integer-to-pointer casts and `ptr::from_exposed_addr` are not supported with `-Zmiri-strict-provenance`
+    async fn gc_with_fork_references(dyncfgs: ConfigUpdates) {
+        async fn append(
+            client: &PersistClient,
+            write: &mut WriteHandle<String, (), u64, i64>,
+            idx: u64,
+        ) {
+            let mut batch = write
+                .expect_batch(&[((idx.to_string(), ()), idx, 1)], idx, idx + 1)
+                .await;
+            let cfg = BatchBuilderConfig::new(&client.cfg, write.shard_id());
+            batch
+                .flush_to_blob(
+                    &cfg,
+                    &client.metrics.user,
+                    &client.isolated_runtime,
+                    &write.write_schemas,
+                )
+                .await;
+            let (_, writer_maintenance) = write
+                .machine
+                .compare_and_append(
+                    &batch.into_hollow_batch(),
+                    &write.writer_id,
+                    &HandleDebugState::default(),
+                    (write.cfg.now)(),
+                )
+                .await
+                .unwrap();
+            writer_maintenance
+                .perform(&write.machine, &write.gc, write.compact.as_ref())
+                .await;
+        }
+
+        let client = new_test_client(&dyncfgs).await;
+        client.cfg.set_config(&ROLLUP_THRESHOLD, 5);
+        let (source, fork) = (ShardId::new(), ShardId::new());
+        let (mut write, mut read) = client.expect_open::<String, (), u64, i64>(source).await;
+        let data: Vec<_> = (0..10).map(|idx| ((idx.to_string(), ()), idx, 1)).collect();
+        for idx in 0..10 {
+            append(&client, &mut write, idx).await;
+        }
+        read.downgrade_since(&Antichain::from_elem(5)).await;
+        client
+            .fork_shard::<String, (), u64, i64>(
+                source,
+                fork,
+                Antichain::from_elem(5),
+                Diagnostics::for_tests(),
+            )
+            .await
+            .expect("invalid usage");
+        read.expire().await;
+
+        // Compact away the batches the fork references, and keep writing so
+        // that GC of the source runs past their deletion.
+        dangerous_force_compaction_and_break_pushdown(&write, || 1, || Duration::ZERO).await;
+        const NUM_BATCHES: u64 = 100;
+        for idx in 10..NUM_BATCHES {
+            append(&client, &mut write, idx).await;
+        }
+        let live_diffs = write
+            .machine
+            .applier
+            .state_versions
+            .fetch_all_live_diffs(&source)
+            .await;
+        let max_live_diffs = 2 * usize::cast_from(NUM_BATCHES.next_power_of_two().trailing_zeros());
+        assert!(
+            live_diffs.0.len() <= max_live_diffs,
+            "{} vs {}",
+            live_diffs.0.len(),
+            max_live_diffs
+        );
+        assert!(!write.machine.applier.fork_held_parts().is_empty());
+
+        let (mut fork_write, mut fork_read) =
+            client.expect_open::<String, (), u64, i64>(fork).await;
+        assert_eq!(
+            fork_read.expect_snapshot_and_fetch(5).await,
+            all_ok(&data[..6], 5)
+        );
+
+        // Once the fork is finalized, GC of the source deletes the blobs it
+        // kept around for it.
+        fork_read.downgrade_since(&Antichain::new()).await;
+        let empty: &[((String, ()), u64, i64)] = &[];
+        fork_write
+            .compare_and_append(empty, Antichain::from_elem(10), Antichain::new())
+            .await
+            .expect("usage should be valid")
+            .expect("upper should match");
+        client
+            .finalize_shard::<String, (), u64, i64>(fork, Diagnostics::for_tests())
+            .await
+            .expect("invalid usage");
+        for idx in NUM_BATCHES..NUM_BATCHES + 10 {
+            append(&client, &mut write, idx).await;
+        }
+        assert!(write.machine.applier.fork_held_parts().is_empty());
+        assert!(write.machine.applier.forks().is_empty());
+    }
+
     // A regression test for database-issues#4206, where a bug in gc led to an incremental
     // state invariant being violated which resulted in gc being permanently
     // wedged for the shard.
@@ -94,6 +94,8 @@ pub struct Metrics {
     pub consolidation: ConsolidationMetrics,
     /// Metrics for blob caching.
     pub blob_cache_mem: BlobMemCache,
+    /// Metrics for the on-disk blob cache.
+    pub blob_cache_disk: BlobDiskCache,
     /// Metrics for tokio tasks.
     pub tasks: TasksMetrics,
     /// Metrics for columnar data encoding and decoding.
@@ -166,6 +168,7 @@ impl Metrics {
             pushdown: PushdownMetrics::new(registry),
             consolidation: ConsolidationMetrics::new(registry),
             blob_cache_mem: BlobMemCache::new(registry),
+            blob_cache_disk: BlobDiskCache::new(registry),
             tasks: TasksMetrics::new(registry),
             columnar,
             schema: SchemaMetrics::new(registry),
@@ -2512,6 +2515,59 @@ impl BlobMemCache {
     }
 }
 
+#[derive(Debug)]
+pub struct BlobDiskCache {
+    pub(crate) size_blobs: UIntGauge,
+    pub(crate) size_bytes: UIntGauge,
+    pub(crate) hits_blobs: IntCounter,
+    pub(crate) hits_bytes: IntCounter,
+    pub(crate) misses_blobs: IntCounter,
+    pub(crate) evictions: IntCounter,
+    pub(crate) checksum_failures: IntCounter,
+}
+
+impl BlobDiskCache {
+    fn new(registry: &MetricsRegistry) -> Self {
+        BlobDiskCache {
+            size_blobs: registry.register(metric!(
+                name: "mz_persist_blob_cache_size_blobs",
+                help: "count of blobs in the cache",
+                const_labels: {"cache" => "disk"},
+            )),
+            size_bytes: registry.register(metric!(
+                name: "mz_persist_blob_cache_size_bytes",
+                help: "total size of blobs in the cache",
+                const_labels: {"cache" => "disk"},
+            )),
+            hits_blobs: registry.register(metric!(
+                name: "mz_persist_blob_cache_hits_blobs",
+                help: "count of blobs served via cache instead of s3",
+                const_labels: {"cache" => "disk"},
+            )),
+            hits_bytes: registry.register(metric!(
+                name: "mz_persist_blob_cache_hits_bytes",
+                help: "total size of blobs served via cache instead of s3",
+                const_labels: {"cache" => "disk"},
+            )),
+            misses_blobs: registry.register(metric!(
+                name: "mz_persist_blob_cache_misses_blobs",
+                help: "count of blob fetches not served via cache",
+                const_labels: {"cache" => "disk"},
+            )),
+            evictions: registry.register(metric!(
+                name: "mz_persist_blob_cache_evictions",
+                help: "count of capacity-based cache evictions",
+                const_labels: {"cache" => "disk"},
+            )),
+            checksum_failures: registry.register(metric!(
+                name: "mz_persist_blob_cache_checksum_failures",
+                help: "count of cache entries discarded because they failed checksum validation",
+                const_labels: {"cache" => "disk"},
+            )),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct SemaphoreMetrics {
     cfg: PersistConfig,
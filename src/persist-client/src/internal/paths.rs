@@ -116,6 +116,10 @@ impl Display for WriterKey {
 /// Used to reduce the bytes needed to refer to a blob key in memory and in
 /// persistent state, all access to blobs are always within the context of an
 /// individual shard.
+///
+/// The exception are forks (see [crate::PersistClient::fork_shard]), which
+/// refer to the blobs of the shard they were forked from. These keys are
+/// prefixed with the [ShardId] that owns the blob, see [Self::foreign].
 #[derive(Arbitrary, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct PartialBatchKey(pub(crate) String);
 
@@ -134,12 +138,51 @@ impl PartialBatchKey {
         PartialBatchKey(format!("{}/{}", version, part_id))
     }
 
+    /// Returns a key that refers to the blob of `key` in the shard `owner`
+    /// from the state of another shard.
+    pub fn foreign(owner: &ShardId, key: &PartialBatchKey) -> Self {
+        match key.owner() {
+            // A key that is already foreign keeps referring to its owner.
+            Some(_) => key.clone(),
+            None => PartialBatchKey(format!("{}/{}", owner, key)),
+        }
+    }
+
+    /// Returns the key of the blob within the shard that owns it, i.e. undoes
+    /// [Self::foreign].
+    pub fn local(&self) -> PartialBatchKey {
+        match self.owner() {
+            Some(_) => {
+                let (_owner, key) = self.0.split_once('/').expect("foreign key has an owner");
+                PartialBatchKey(key.to_owned())
+            }
+            None => self.clone(),
+        }
+    }
+
+    /// Returns the shard that owns the blob, if that's not the shard whose
+    /// state this key is part of.
+    pub fn owner(&self) -> Option<ShardId> {
+        if !self.0.starts_with('s') {
+            return None;
+        }
+        let (owner, _) = self.0.split_once('/')?;
+        ShardId::from_str(owner).ok()
+    }
+
     pub fn split(&self) -> Option<(WriterKey, PartId)> {
-        split_batch_key(&self.0).ok()
+        let key = match self.owner() {
+            Some(_) => self.0.split_once('/')?.1,
+            None => &self.0,
+        };
+        split_batch_key(key).ok()
     }
 
     pub fn complete(&self, shard_id: &ShardId) -> BlobKey {
-        BlobKey(format!("{}/{}", shard_id, self))
+        match self.owner() {
+            Some(_) => BlobKey(self.0.clone()),
+            None => BlobKey(format!("{}/{}", shard_id, self)),
+        }
     }
 }
 
@@ -230,7 +273,7 @@ pub enum PartialBlobKey {
 /// Use when directly interacting with a [mz_persist::location::Blob], otherwise
 /// use [PartialBatchKey] or [PartialRollupKey] to refer to a blob without
 /// needing to copy the [ShardId].
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct BlobKey(String);
 
 impl std::fmt::Display for BlobKey {
@@ -335,6 +378,31 @@ mod tests {
         );
     }
 
+    #[mz_ore::test]
+    fn foreign_blob_key_completion() {
+        let (owner, shard_id) = (ShardId::new(), ShardId::new());
+        let (writer_id, part_id) = (WriterId::new(), PartId::new());
+        let key = PartialBatchKey::new(&WriterKey::Id(writer_id.clone()), &part_id);
+        assert_eq!(key.owner(), None);
+
+        let foreign = PartialBatchKey::foreign(&owner, &key);
+        assert_eq!(foreign.owner(), Some(owner));
+        assert_eq!(foreign.complete(&shard_id), key.complete(&owner));
+        assert_eq!(foreign.split(), key.split());
+        assert_eq!(foreign.local(), key);
+        assert_eq!(key.local(), key);
+        assert_eq!(
+            BlobKey::parse_ids(&foreign.complete(&shard_id)),
+            Ok((
+                owner,
+                PartialBlobKey::Batch(WriterKey::Id(writer_id), part_id)
+            ))
+        );
+
+        // Forking a foreign key again keeps the original owner.
+        assert_eq!(PartialBatchKey::foreign(&shard_id, &foreign), foreign);
+    }
+
     #[mz_ore::test]
     fn blob_key_parse() -> Result<(), String> {
         let (shard_id, writer_id, part_id) = (ShardId::new(), WriterId::new(), PartId::new());
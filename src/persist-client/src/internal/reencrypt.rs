@@ -0,0 +1,174 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! See documentation on [[blobs_to_reencrypt]].
+
+use std::collections::BTreeSet;
+
+use mz_persist::location::Blob;
+use tracing::info;
+
+use crate::ShardId;
+use crate::internal::paths::BlobKey;
+use crate::internal::state::{BatchPart, HollowBlobRef, RunPart};
+use crate::internal::state_versions::StateVersions;
+use crate::metrics::Metrics;
+
+/// Returns the keys of the blobs referenced by any live state of the shard
+/// that, according to the key id recorded in state, are not encrypted with
+/// `active_key`.
+///
+/// This includes blobs that were written unencrypted or before we started
+/// recording key ids. Only runs of parts are fetched, to find the key ids of
+/// the parts in them; everything else is decided from state alone.
+///
+/// State keeps the key id that a blob was written with, so a blob that was
+/// re-encrypted in place is returned again until compaction replaces it.
+pub(crate) async fn blobs_to_reencrypt(
+    versions: &StateVersions,
+    blob: &dyn Blob,
+    shard_id: ShardId,
+    active_key: &str,
+    metrics: &Metrics,
+) -> BTreeSet<BlobKey> {
+    let mut to_reencrypt = BTreeSet::new();
+    let Some(states) = versions.fetch_all_live_states::<u64>(shard_id).await else {
+        info!("No state for shard {shard_id}.");
+        return to_reencrypt;
+    };
+    let mut states = states
+        .check_ts_codec()
+        .expect("ts should be a u64 in all prod shards");
+
+    let needs_reencrypt = |id: &Option<String>| id.as_deref() != Some(active_key);
+
+    // The same batch is usually referenced by many live states.
+    let mut batches = BTreeSet::new();
+    while let Some(_) = states.next(|diff| {
+        diff.referenced_blobs().for_each(|x| match x {
+            HollowBlobRef::Batch(batch) => {
+                batches.insert(batch.clone());
+            }
+            HollowBlobRef::Rollup(rollup) => {
+                if needs_reencrypt(&rollup.encryption_key_id) {
+                    to_reencrypt.insert(rollup.key.complete(&shard_id));
+                }
+            }
+        })
+    }) {}
+
+    let mut part_queue: Vec<RunPart<u64>> =
+        batches.into_iter().flat_map(|batch| batch.parts).collect();
+    let mut runs = BTreeSet::new();
+    while let Some(part) = part_queue.pop() {
+        match part {
+            RunPart::Single(BatchPart::Inline { .. }) => {}
+            RunPart::Single(BatchPart::Hollow(part)) => {
+                if needs_reencrypt(&part.encryption_key_id) {
+                    to_reencrypt.insert(part.key.complete(&shard_id));
+                }
+            }
+            RunPart::Many(run) => {
+                let key = run.key.complete(&shard_id);
+                if !runs.insert(key.clone()) {
+                    continue;
+                }
+                if needs_reencrypt(&run.encryption_key_id) {
+                    to_reencrypt.insert(key.clone());
+                }
+                match run.get(shard_id, blob, metrics).await {
+                    Some(run) => part_queue.extend(run.parts),
+                    // Only possible if GC deleted the run since we fetched
+                    // state, in which case there's nothing left to rewrite.
+                    None => info!("Run {key} no longer exists."),
+                }
+            }
+        }
+    }
+    to_reencrypt
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mz_dyncfg::ConfigUpdates;
+    use mz_persist::encrypt::{BLOB_ENCRYPTION_ACTIVE_KEY, BlobEncryptionKey, BlobEncryptionKeys};
+
+    use crate::PersistLocation;
+    use crate::batch::INLINE_WRITES_SINGLE_MAX_BYTES;
+    use crate::tests::new_test_client_cache;
+
+    use super::*;
+
+    #[mz_persist_proc::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    async fn blobs_to_reencrypt_follows_recorded_key_ids(dyncfgs: ConfigUpdates) {
+        let data = [
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+            (("2".to_owned(), "two".to_owned()), 2, 1),
+        ];
+
+        let mut cache = new_test_client_cache(&dyncfgs);
+        // Keep the set of referenced batches stable, and write them to blobs.
+        cache.cfg.compaction_enabled = false;
+        cache.cfg.set_config(&INLINE_WRITES_SINGLE_MAX_BYTES, 0);
+        let keys = [
+            BlobEncryptionKey::generate("k1").expect("valid key"),
+            BlobEncryptionKey::generate("k2").expect("valid key"),
+        ];
+        cache.cfg.blob_encryption = Some(BlobEncryptionKeys::from_keys(keys, "k1").unwrap());
+        let client = cache
+            .open(PersistLocation::new_in_mem())
+            .await
+            .expect("client construction failed");
+        let shard_id = ShardId::new();
+        let (mut write, _read) = client
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        write.expect_compare_and_append(&data[..1], 0, 2).await;
+
+        let versions = StateVersions::new(
+            client.cfg.clone(),
+            Arc::clone(&client.consensus),
+            Arc::clone(&client.blob),
+            Arc::clone(&client.metrics),
+        );
+        let to_reencrypt = |active_key: &'static str| {
+            let versions = &versions;
+            let client = &client;
+            async move {
+                blobs_to_reencrypt(
+                    versions,
+                    client.blob.as_ref(),
+                    shard_id,
+                    active_key,
+                    &client.metrics,
+                )
+                .await
+            }
+        };
+
+        // Everything so far was written with k1.
+        assert_eq!(to_reencrypt("k1").await, BTreeSet::new());
+        let written_with_k1 = to_reencrypt("k2").await;
+        assert!(!written_with_k1.is_empty());
+
+        // After a rotation, only blobs written before it need to be
+        // re-encrypted with k2. (GC may have deleted some of them since.)
+        client
+            .cfg
+            .set_config(&BLOB_ENCRYPTION_ACTIVE_KEY, "k2".to_owned());
+        write.expect_compare_and_append(&data[1..], 2, 3).await;
+        assert!(to_reencrypt("k2").await.is_subset(&written_with_k1));
+        let written_with_k2 = to_reencrypt("k1").await;
+        assert!(!written_with_k2.is_empty());
+        assert!(written_with_k2.is_disjoint(&written_with_k1));
+    }
+}
@@ -393,6 +393,11 @@ pub struct HollowRunRef<T> {
     /// The lower bound of the data in this part, ordered by the structured ordering.
     pub structured_key_lower: Option<LazyProto<ProtoArrayData>>,
 
+    /// The id of the blob encryption key that the run object was encrypted
+    /// with, or None if it was written unencrypted or before we started
+    /// recording this. The parts in the run record their own.
+    pub encryption_key_id: Option<String>,
+
     pub(crate) _phantom_data: PhantomData<T>,
 }
 impl<T: Eq> PartialOrd<Self> for HollowRunRef<T> {
@@ -415,11 +420,15 @@ impl<T> HollowRunRef<T> {
 
 impl<T: Timestamp + Codec64> HollowRunRef<T> {
     /// Stores the given runs and returns a [HollowRunRef] that points to them.
+    ///
+    /// `encryption_key_id` is the id of the key that `blob` encrypts the runs
+    /// with, if any.
     pub async fn set(
         shard_id: ShardId,
         blob: &dyn Blob,
         writer: &WriterKey,
         data: HollowRun<T>,
+        encryption_key_id: Option<String>,
         metrics: &Metrics,
     ) -> Self {
         let hollow_bytes = data.parts.iter().map(|p| p.hollow_bytes()).sum();
@@ -452,6 +461,7 @@ impl<T: Timestamp + Codec64> HollowRunRef<T> {
             max_part_bytes,
             key_lower,
             structured_key_lower,
+            encryption_key_id,
             _phantom_data: Default::default(),
         }
     }
@@ -472,9 +482,15 @@ impl<T: Timestamp + Codec64> HollowRunRef<T> {
         .await?;
         let proto_runs: ProtoHollowRun =
             prost::Message::decode(&mut bytes).expect("illegal state: invalid proto bytes");
-        let runs = proto_runs
+        let mut runs: HollowRun<T> = proto_runs
             .into_rust()
             .expect("illegal state: invalid encoded runs proto");
+        // The parts of a run live in the same shard as the run itself.
+        if let Some(owner) = self.key.owner() {
+            for part in runs.parts.iter_mut() {
+                part.make_foreign(&owner);
+            }
+        }
         Some(runs)
     }
 }
@@ -522,6 +538,15 @@ impl<T> RunPart<T> {
         }
     }
 
+    /// The key of the blob this part is stored in, if it's not inline.
+    pub fn hollow_key(&self) -> Option<&PartialBatchKey> {
+        match self {
+            Self::Single(BatchPart::Hollow(x)) => Some(&x.key),
+            Self::Single(BatchPart::Inline { .. }) => None,
+            Self::Many(r) => Some(&r.key),
+        }
+    }
+
     pub fn is_inline(&self) -> bool {
         match self {
             Self::Single(p) => p.is_inline(),
@@ -601,6 +626,25 @@ impl<T> RunPart<T> {
             Self::Many(_) => None,
         }
     }
+
+    /// Rewrites this part to refer to the blobs of the shard `owner`. See
+    /// [PartialBatchKey::foreign].
+    pub fn make_foreign(&mut self, owner: &ShardId) {
+        match self {
+            Self::Single(BatchPart::Hollow(p)) => p.key = PartialBatchKey::foreign(owner, &p.key),
+            Self::Single(BatchPart::Inline { .. }) => {}
+            Self::Many(r) => r.key = PartialBatchKey::foreign(owner, &r.key),
+        }
+    }
+
+    /// Whether this part refers to the blobs of another shard.
+    pub fn is_foreign(&self) -> bool {
+        match self {
+            Self::Single(BatchPart::Hollow(p)) => p.key.owner().is_some(),
+            Self::Single(BatchPart::Inline { .. }) => false,
+            Self::Many(r) => r.key.owner().is_some(),
+        }
+    }
 }
 
 /// A blob was missing!
@@ -751,6 +795,12 @@ pub struct HollowBatchPart<T> {
 
     /// ID of a schema that has since been deprecated and exists only to cleanly roundtrip.
     pub deprecated_schema_id: Option<SchemaId>,
+
+    /// The id of the blob encryption key that this part was encrypted with.
+    ///
+    /// This is `None` if the part was written unencrypted, or before we
+    /// started recording this.
+    pub encryption_key_id: Option<String>,
 }
 
 /// A [Batch] but with the updates themselves stored externally.
@@ -1083,6 +1133,7 @@ impl<T: Ord> Ord for HollowBatchPart<T> {
             format: self_format,
             schema_id: self_schema_id,
             deprecated_schema_id: self_deprecated_schema_id,
+            encryption_key_id: self_encryption_key_id,
         } = self;
         let HollowBatchPart {
             key: other_key,
@@ -1095,6 +1146,7 @@ impl<T: Ord> Ord for HollowBatchPart<T> {
             format: other_format,
             schema_id: other_schema_id,
             deprecated_schema_id: other_deprecated_schema_id,
+            encryption_key_id: other_encryption_key_id,
         } = other;
         (
             self_key,
@@ -1107,6 +1159,7 @@ impl<T: Ord> Ord for HollowBatchPart<T> {
             self_format,
             self_schema_id,
             self_deprecated_schema_id,
+            self_encryption_key_id,
         )
             .cmp(&(
                 other_key,
@@ -1119,6 +1172,7 @@ impl<T: Ord> Ord for HollowBatchPart<T> {
                 other_format,
                 other_schema_id,
                 other_deprecated_schema_id,
+                other_encryption_key_id,
             ))
     }
 }
@@ -1130,6 +1184,10 @@ pub struct HollowRollup {
     pub key: PartialRollupKey,
     /// The encoded size of this rollup, if known.
     pub encoded_size_bytes: Option<usize>,
+    /// The id of the blob encryption key that this rollup was encrypted with,
+    /// or None if it was written unencrypted or before we started recording
+    /// this.
+    pub encryption_key_id: Option<String>,
 }
 
 /// A pointer to a blob stored externally.
@@ -1181,6 +1239,17 @@ pub struct StateCollections<T> {
     pub(crate) critical_readers: BTreeMap<CriticalReaderId, CriticalReaderState<T>>,
     pub(crate) writers: BTreeMap<WriterId, WriterState<T>>,
     pub(crate) schemas: BTreeMap<SchemaId, EncodedSchemas>,
+    // The forks of this shard (see [crate::PersistClient::fork_shard]) and the
+    // seqno of this shard each of them was created at.
+    //
+    // - Invariant: GC doesn't delete a blob that a live version of the state
+    //   of a fork references.
+    pub(crate) forks: BTreeMap<ShardId, SeqNo>,
+    // The parts that were removed from `trace`, but that a fork still
+    // referenced when GC got to delete them, by key.
+    //
+    // - Invariant: GC deletes these once no fork references them anymore.
+    pub(crate) fork_held_parts: BTreeMap<PartialBatchKey, RunPart<T>>,
 
     // - Invariant: `trace.since == meet(all reader.since)`
     // - Invariant: `trace.since` doesn't regress across state versions.
@@ -1189,6 +1258,9 @@ pub struct StateCollections<T> {
     pub(crate) trace: Trace<T>,
 }
 
+/// The initial trace and schemas of a fork.
+pub type ForkState<T> = (Trace<T>, BTreeMap<SchemaId, EncodedSchemas>);
+
 /// A key and val [Codec::Schema] encoded via [Codec::encode_schema].
 ///
 /// This strategy of directly serializing the schema objects requires that
@@ -1881,6 +1953,138 @@ where
         Continue(existed)
     }
 
+    /// Registers `target` as a fork of this shard (see
+    /// [crate::PersistClient::fork_shard]), which GC then checks before
+    /// deleting any blobs.
+    ///
+    /// Until the state of the fork is written, it's the leased reader
+    /// `reader_id` at `seqno` that keeps the blobs it references around. The
+    /// caller is expected to expire it once the fork exists.
+    ///
+    /// Returns the initial trace of the fork, with its since advanced to
+    /// `as_of`, and the schemas registered for this shard.
+    pub fn register_fork(
+        &mut self,
+        hostname: &str,
+        reader_id: &LeasedReaderId,
+        source: &ShardId,
+        target: &ShardId,
+        seqno: SeqNo,
+        as_of: &Antichain<T>,
+        lease_duration: Duration,
+        heartbeat_timestamp_ms: u64,
+    ) -> ControlFlow<
+        NoOpStateTransition<Result<ForkState<T>, InvalidUsage<T>>>,
+        Result<ForkState<T>, InvalidUsage<T>>,
+    > {
+        let (since, upper) = (self.trace.since(), self.trace.upper());
+        if !PartialOrder::less_equal(since, as_of) || !PartialOrder::less_than(as_of, upper) {
+            return Break(NoOpStateTransition(Err(InvalidUsage::InvalidForkAsOf {
+                as_of: as_of.clone(),
+                since: since.clone(),
+                upper: upper.clone(),
+            })));
+        }
+        // A fork of a fork would reference the blobs of the original source,
+        // but only hold back the garbage collection of the fork.
+        if self
+            .trace
+            .batches()
+            .any(|b| b.parts.iter().any(|p| p.is_foreign()))
+        {
+            return Break(NoOpStateTransition(Err(InvalidUsage::ForkOfFork(*source))));
+        }
+        if self.forks.contains_key(target) {
+            return Break(NoOpStateTransition(Err(InvalidUsage::ForkTargetExists(
+                *target,
+            ))));
+        }
+
+        let reader_state = LeasedReaderState {
+            debug: HandleDebugState {
+                hostname: hostname.to_owned(),
+                purpose: format!("fork {target}"),
+            },
+            seqno,
+            // The fork holds back the blobs of this shard, but not its since.
+            since: Antichain::new(),
+            last_heartbeat_timestamp_ms: heartbeat_timestamp_ms,
+            lease_duration_ms: u64::try_from(lease_duration.as_millis())
+                .expect("lease duration as millis should fit within u64"),
+        };
+        self.leased_readers.insert(reader_id.clone(), reader_state);
+        self.forks.insert(*target, seqno);
+
+        let mut trace = self.trace.fork(source);
+        trace.downgrade_since(as_of);
+        Continue(Ok((trace, self.schemas.clone())))
+    }
+
+    /// The `since` that this shard would have if the given leased reader were
+    /// expired, or None if there are no other readers.
+    pub fn since_without_reader(&self, reader_id: &LeasedReaderId) -> Option<Antichain<T>> {
+        let mut sinces_iter = self
+            .leased_readers
+            .iter()
+            .filter(|(id, _)| *id != reader_id)
+            .map(|(_, x)| &x.since)
+            .chain(self.critical_readers.values().map(|x| &x.since));
+        let mut since = sinces_iter.next()?.clone();
+        while let Some(s) = sinces_iter.next() {
+            since.meet_assign(s);
+        }
+        Some(since)
+    }
+
+    /// Unregisters the fork `target` registered by [Self::register_fork], if
+    /// any, after which GC no longer checks it before deleting blobs.
+    pub fn release_fork(
+        &mut self,
+        target: &ShardId,
+    ) -> ControlFlow<NoOpStateTransition<bool>, bool> {
+        if self.forks.remove(target).is_none() {
+            return Break(NoOpStateTransition(false));
+        }
+        Continue(true)
+    }
+
+    /// Records parts that GC found to be deleted from `trace`, but that are
+    /// still referenced by a fork, so that GC can delete them later.
+    pub fn hold_fork_parts(
+        &mut self,
+        parts: &[RunPart<T>],
+    ) -> ControlFlow<NoOpStateTransition<()>, ()> {
+        let mut changed = false;
+        for part in parts {
+            if let Some(key) = part.hollow_key() {
+                changed |= self
+                    .fork_held_parts
+                    .insert(key.clone(), part.clone())
+                    .is_none();
+            }
+        }
+        if !changed {
+            return Break(NoOpStateTransition(()));
+        }
+        Continue(())
+    }
+
+    /// Forgets parts recorded by [Self::hold_fork_parts] after GC deleted
+    /// them.
+    pub fn free_fork_parts(
+        &mut self,
+        keys: &[PartialBatchKey],
+    ) -> ControlFlow<NoOpStateTransition<()>, ()> {
+        let held = self.fork_held_parts.len();
+        for key in keys {
+            self.fork_held_parts.remove(key);
+        }
+        if self.fork_held_parts.len() == held {
+            return Break(NoOpStateTransition(()));
+        }
+        Continue(())
+    }
+
     pub fn expire_critical_reader(
         &mut self,
         reader_id: &CriticalReaderId,
@@ -2001,6 +2205,8 @@ where
             && self.writers.is_empty()
             && self.leased_readers.is_empty()
             && self.critical_readers.is_empty()
+            && self.forks.is_empty()
+            && self.fork_held_parts.is_empty()
     }
 
     pub(crate) fn is_single_empty_batch(&self) -> bool {
@@ -2212,6 +2418,8 @@ where
                 critical_readers: BTreeMap::new(),
                 writers: BTreeMap::new(),
                 schemas: BTreeMap::new(),
+                forks: BTreeMap::new(),
+                fork_held_parts: BTreeMap::new(),
                 trace: Trace::default(),
             },
         };
@@ -2611,6 +2819,8 @@ impl<T: Serialize + Timestamp + Lattice> Serialize for State<T> {
                     critical_readers,
                     writers,
                     schemas,
+                    forks,
+                    fork_held_parts,
                     trace,
                 },
         } = self;
@@ -2628,6 +2838,8 @@ impl<T: Serialize + Timestamp + Lattice> Serialize for State<T> {
         let () = s.serialize_field("critical_readers", critical_readers)?;
         let () = s.serialize_field("writers", writers)?;
         let () = s.serialize_field("schemas", schemas)?;
+        let () = s.serialize_field("forks", forks)?;
+        let () = s.serialize_field("fork_held_parts", fork_held_parts)?;
         let () = s.serialize_field("since", &trace.since().elements())?;
         let () = s.serialize_field("upper", &trace.upper().elements())?;
         let trace = trace.flatten();
@@ -2774,6 +2986,7 @@ pub(crate) mod tests {
                 any::<Option<BatchColumnarFormat>>(),
                 any::<Option<SchemaId>>(),
                 any::<Option<SchemaId>>(),
+                any::<Option<String>>(),
             ),
             |(
                 key,
@@ -2785,6 +2998,7 @@ pub(crate) mod tests {
                 format,
                 schema_id,
                 deprecated_schema_id,
+                encryption_key_id,
             )| {
                 HollowBatchPart {
                     key,
@@ -2797,6 +3011,7 @@ pub(crate) mod tests {
                     format,
                     schema_id,
                     deprecated_schema_id,
+                    encryption_key_id,
                 }
             },
         )
@@ -2917,13 +3132,26 @@ pub(crate) mod tests {
             ),
             proptest::collection::btree_map(any::<WriterId>(), any_writer_state::<T>(), 0..3),
             proptest::collection::btree_map(any::<SchemaId>(), any_encoded_schemas(), 0..3),
+            // Forks are exercised separately, to keep the golden in
+            // `state_inspect_serde_json` stable.
+            Just(BTreeMap::new()),
+            Just(BTreeMap::new()),
             any_trace::<T>(num_trace_batches),
         );
 
         (part1, part2).prop_map(
             |(
                 (shard_id, seqno, walltime_ms, hostname, last_gc_req, rollups, active_rollup),
-                (active_gc, leased_readers, critical_readers, writers, schemas, trace),
+                (
+                    active_gc,
+                    leased_readers,
+                    critical_readers,
+                    writers,
+                    schemas,
+                    forks,
+                    fork_held_parts,
+                    trace,
+                ),
             )| State {
                 applier_version: semver::Version::new(1, 2, 3),
                 shard_id,
@@ -2939,6 +3167,8 @@ pub(crate) mod tests {
                     critical_readers,
                     writers,
                     schemas,
+                    forks,
+                    fork_held_parts,
                     trace,
                 },
             },
@@ -2970,6 +3200,7 @@ pub(crate) mod tests {
                         format: None,
                         schema_id: None,
                         deprecated_schema_id: None,
+                        encryption_key_id: None,
                     }))
                 })
                 .collect(),
@@ -3837,6 +4068,7 @@ pub(crate) mod tests {
         let rollup = HollowRollup {
             key: PartialRollupKey::new(rollup_seqno, &RollupId::new()),
             encoded_size_bytes: None,
+            encryption_key_id: None,
         };
 
         assert!(
@@ -3937,6 +4169,7 @@ pub(crate) mod tests {
         let rollup = HollowRollup {
             key: PartialRollupKey::new(rollup_seqno, &RollupId::new()),
             encoded_size_bytes: None,
+            encryption_key_id: None,
         };
         assert!(
             state
@@ -3987,6 +4220,7 @@ pub(crate) mod tests {
         let rollup = HollowRollup {
             key: PartialRollupKey::new(rollup_seqno, &RollupId::new()),
             encoded_size_bytes: None,
+            encryption_key_id: None,
         };
 
         assert!(
@@ -4063,6 +4297,7 @@ pub(crate) mod tests {
         let rollup = HollowRollup {
             key: PartialRollupKey::new(rollup_seqno, &RollupId::new()),
             encoded_size_bytes: None,
+            encryption_key_id: None,
         };
         assert!(
             state
@@ -26,11 +26,11 @@ use timely::progress::{Antichain, Timestamp};
 use tracing::debug;
 
 use crate::critical::CriticalReaderId;
-use crate::internal::paths::PartialRollupKey;
+use crate::internal::paths::{PartialBatchKey, PartialRollupKey};
 use crate::internal::state::{
     CriticalReaderState, EncodedSchemas, HollowBatch, HollowBlobRef, HollowRollup,
-    LeasedReaderState, ProtoStateField, ProtoStateFieldDiffType, ProtoStateFieldDiffs, State,
-    StateCollections, WriterState,
+    LeasedReaderState, ProtoStateField, ProtoStateFieldDiffType, ProtoStateFieldDiffs, RunPart,
+    State, StateCollections, WriterState,
 };
 use crate::internal::trace::{FueledMergeRes, SpineId, ThinMerge, ThinSpineBatch, Trace};
 use crate::read::LeasedReaderId;
@@ -84,6 +84,8 @@ pub struct StateDiff<T> {
     pub(crate) critical_readers: Vec<StateFieldDiff<CriticalReaderId, CriticalReaderState<T>>>,
     pub(crate) writers: Vec<StateFieldDiff<WriterId, WriterState<T>>>,
     pub(crate) schemas: Vec<StateFieldDiff<SchemaId, EncodedSchemas>>,
+    pub(crate) forks: Vec<StateFieldDiff<ShardId, SeqNo>>,
+    pub(crate) fork_held_parts: Vec<StateFieldDiff<PartialBatchKey, RunPart<T>>>,
     pub(crate) since: Vec<StateFieldDiff<(), Antichain<T>>>,
     pub(crate) legacy_batches: Vec<StateFieldDiff<HollowBatch<T>, ()>>,
     pub(crate) hollow_batches: Vec<StateFieldDiff<SpineId, Arc<HollowBatch<T>>>>,
@@ -114,6 +116,8 @@ impl<T: Timestamp + Codec64> StateDiff<T> {
             critical_readers: Vec::default(),
             writers: Vec::default(),
             schemas: Vec::default(),
+            forks: Vec::default(),
+            fork_held_parts: Vec::default(),
             since: Vec::default(),
             legacy_batches: Vec::default(),
             hollow_batches: Vec::default(),
@@ -160,6 +164,8 @@ impl<T: Timestamp + Lattice + Codec64> StateDiff<T> {
                     critical_readers: from_critical_readers,
                     writers: from_writers,
                     schemas: from_schemas,
+                    forks: from_forks,
+                    fork_held_parts: from_fork_held_parts,
                     trace: from_trace,
                 },
         } = from;
@@ -179,6 +185,8 @@ impl<T: Timestamp + Lattice + Codec64> StateDiff<T> {
                     critical_readers: to_critical_readers,
                     writers: to_writers,
                     schemas: to_schemas,
+                    forks: to_forks,
+                    fork_held_parts: to_fork_held_parts,
                     trace: to_trace,
                 },
         } = to;
@@ -217,6 +225,12 @@ impl<T: Timestamp + Lattice + Codec64> StateDiff<T> {
         );
         diff_field_sorted_iter(from_writers.iter(), to_writers, &mut diffs.writers);
         diff_field_sorted_iter(from_schemas.iter(), to_schemas, &mut diffs.schemas);
+        diff_field_sorted_iter(from_forks.iter(), to_forks, &mut diffs.forks);
+        diff_field_sorted_iter(
+            from_fork_held_parts.iter(),
+            to_fork_held_parts,
+            &mut diffs.fork_held_parts,
+        );
         diff_field_single(from_trace.since(), to_trace.since(), &mut diffs.since);
 
         let from_flat = from_trace.flatten();
@@ -409,6 +423,8 @@ impl<T: Timestamp + Lattice + Codec64> State<T> {
             critical_readers: diff_critical_readers,
             writers: diff_writers,
             schemas: diff_schemas,
+            forks: diff_forks,
+            fork_held_parts: diff_fork_held_parts,
             since: diff_since,
             legacy_batches: diff_legacy_batches,
             hollow_batches: diff_hollow_batches,
@@ -447,6 +463,8 @@ impl<T: Timestamp + Lattice + Codec64> State<T> {
             critical_readers,
             writers,
             schemas,
+            forks,
+            fork_held_parts,
             trace,
         } = &mut self.collections;
 
@@ -458,6 +476,8 @@ impl<T: Timestamp + Lattice + Codec64> State<T> {
         apply_diffs_map("critical_readers", diff_critical_readers, critical_readers)?;
         apply_diffs_map("writers", diff_writers, writers)?;
         apply_diffs_map("schemas", diff_schemas, schemas)?;
+        apply_diffs_map("forks", diff_forks, forks)?;
+        apply_diffs_map("fork_held_parts", diff_fork_held_parts, fork_held_parts)?;
 
         let structure_unchanged = diff_hollow_batches.is_empty()
             && diff_spine_batches.is_empty()
@@ -1384,6 +1404,7 @@ mod tests {
                 HollowRollup {
                     key: PartialRollupKey::new(seqno, &RollupId::new()),
                     encoded_size_bytes: None,
+                    encryption_key_id: None,
                 },
             );
             leader.collections.trace.roundtrip_structure = false;
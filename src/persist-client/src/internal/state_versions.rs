@@ -40,7 +40,8 @@ use crate::internal::paths::{BlobKey, PartialBlobKey, PartialRollupKey, RollupId
 #[cfg(debug_assertions)]
 use crate::internal::state::HollowBatch;
 use crate::internal::state::{
-    BatchPart, HollowBlobRef, HollowRollup, NoOpStateTransition, RunPart, State, TypedState,
+    BatchPart, ForkState, HollowBlobRef, HollowRollup, NoOpStateTransition, RunPart, State,
+    StateCollections, TypedState,
 };
 use crate::internal::state_diff::{StateDiff, StateFieldValDiff};
 use crate::{Metrics, PersistConfig, ShardId};
@@ -115,6 +116,7 @@ pub struct EncodedRollup {
     pub(crate) seqno: SeqNo,
     pub(crate) key: PartialRollupKey,
     pub(crate) _desc: Description<SeqNo>,
+    encryption_key_id: Option<String>,
     buf: Bytes,
 }
 
@@ -123,6 +125,7 @@ impl EncodedRollup {
         HollowRollup {
             key: self.key.clone(),
             encoded_size_bytes: Some(self.buf.len()),
+            encryption_key_id: self.encryption_key_id.clone(),
         }
     }
 }
@@ -166,7 +169,8 @@ impl StateVersions {
         }
 
         // Shard is not initialized, try initializing it.
-        let (initial_state, initial_diff) = self.write_initial_rollup(shard_metrics).await;
+        let (initial_state, initial_diff) =
+            self.write_initial_rollup(shard_metrics, &mut |_| {}).await;
         let (cas_res, _diff) =
             retry_external(&self.metrics.retries.external.maybe_init_cas, || async {
                 self.try_compare_and_set_current(
@@ -216,6 +220,62 @@ impl StateVersions {
         }
     }
 
+    /// Creates the state of a fork (see [crate::PersistClient::fork_shard])
+    /// with the given initial trace and schemas.
+    ///
+    /// Returns whether the fork was created, i.e. false if the shard was
+    /// already initialized.
+    pub async fn init_fork<K, V, T, D>(
+        &self,
+        shard_metrics: &ShardMetrics,
+        fork: ForkState<T>,
+    ) -> bool
+    where
+        K: Debug + Codec,
+        V: Debug + Codec,
+        T: Timestamp + Lattice + Codec64,
+        D: Semigroup + Codec64,
+    {
+        let shard_id = shard_metrics.shard_id;
+        let (trace, schemas) = fork;
+        let (initial_state, initial_diff) = self
+            .write_initial_rollup::<K, V, T, D>(shard_metrics, &mut |state| {
+                state.trace.clone_from(&trace);
+                state.schemas.clone_from(&schemas);
+            })
+            .await;
+        let (cas_res, _diff) =
+            retry_external(&self.metrics.retries.external.maybe_init_cas, || async {
+                self.try_compare_and_set_current(
+                    "init_fork",
+                    shard_metrics,
+                    None,
+                    &initial_state,
+                    &initial_diff,
+                )
+                .await
+                .map_err(|err| err.into())
+            })
+            .await;
+        match cas_res {
+            CaSResult::Committed => true,
+            CaSResult::ExpectationMismatch => {
+                // SUBTLE: As in `maybe_init_shard`, an Indeterminate error in
+                // the CaS above may have hidden that it actually went through.
+                let recent_live_diffs = self.fetch_recent_live_diffs::<T>(&shard_id).await;
+                let state = self
+                    .fetch_current_state::<T>(&shard_id, recent_live_diffs.0)
+                    .await;
+                let (_, rollup) = initial_state.latest_rollup();
+                if state.rollups().values().any(|x| x.key == rollup.key) {
+                    return true;
+                }
+                self.delete_rollup(&shard_id, &rollup.key).await;
+                false
+            }
+        }
+    }
+
     /// Updates the state of a shard to a new `current` iff `expected` matches
     /// `current`.
     ///
@@ -657,6 +717,7 @@ impl StateVersions {
     async fn write_initial_rollup<K, V, T, D>(
         &self,
         shard_metrics: &ShardMetrics,
+        init: &mut (dyn FnMut(&mut StateCollections<T>) + Send),
     ) -> (TypedState<K, V, T, D>, StateDiff<T>)
     where
         K: Debug + Codec,
@@ -671,22 +732,25 @@ impl StateVersions {
             (self.cfg.now)(),
         );
         let rollup_seqno = empty_state.seqno.next();
+        let encryption_key_id = self.cfg.blob_encryption_key_id().await;
         let rollup = HollowRollup {
             key: PartialRollupKey::new(rollup_seqno, &RollupId::new()),
             // Chicken-and-egg problem here. We don't know the size of the
             // rollup until we encode it, but it includes a reference back to
             // itself.
             encoded_size_bytes: None,
+            encryption_key_id: encryption_key_id.clone(),
         };
-        let (applied, initial_state) = match empty_state
-            .clone_apply(&self.cfg, &mut |_, _, state| {
+        let (applied, initial_state) =
+            match empty_state.clone_apply(&self.cfg, &mut |_, _, state| {
+                init(state);
                 state.add_rollup((rollup_seqno, &rollup))
             }) {
-            Continue(x) => x,
-            Break(NoOpStateTransition(_)) => {
-                panic!("initial state transition should not be a no-op")
-            }
-        };
+                Continue(x) => x,
+                Break(NoOpStateTransition(_)) => {
+                    panic!("initial state transition should not be a no-op")
+                }
+            };
         assert!(
             applied,
             "add_and_remove_rollups should apply to the empty state"
@@ -697,6 +761,7 @@ impl StateVersions {
             initial_state.clone_for_rollup(),
             vec![],
             rollup.key,
+            encryption_key_id,
         );
         let () = self.write_rollup_blob(&rollup).await;
         assert_eq!(initial_state.seqno, rollup.seqno);
@@ -771,7 +836,8 @@ impl StateVersions {
         assert_eq!(diffs.last().map(|x| x.seqno), Some(state.seqno));
 
         let key = PartialRollupKey::new(state.seqno, rollup_id);
-        let rollup = self.encode_rollup_blob(shard_metrics, state, diffs, key);
+        let encryption_key_id = self.cfg.blob_encryption_key_id().await;
+        let rollup = self.encode_rollup_blob(shard_metrics, state, diffs, key, encryption_key_id);
         let () = self.write_rollup_blob(&rollup).await;
 
         self.metrics.state.rollup_write_success.inc();
@@ -782,12 +848,15 @@ impl StateVersions {
     /// Encodes the given state and diffs as a rollup to be written to the specified key.
     ///
     /// The diffs must span the seqno range `(state.last_rollup().seqno, state.seqno]`.
+    /// `encryption_key_id` is the id of the key that the rollup will be
+    /// encrypted with, if any.
     pub fn encode_rollup_blob<K, V, T, D>(
         &self,
         shard_metrics: &ShardMetrics,
         state: TypedState<K, V, T, D>,
         diffs: Vec<VersionedData>,
         key: PartialRollupKey,
+        encryption_key_id: Option<String>,
     ) -> EncodedRollup
     where
         K: Debug + Codec,
@@ -818,6 +887,7 @@ impl StateVersions {
             key,
             buf,
             _desc: desc,
+            encryption_key_id,
         }
     }
 
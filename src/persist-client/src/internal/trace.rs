@@ -65,6 +65,7 @@ use timely::PartialOrder;
 use timely::progress::frontier::AntichainRef;
 use timely::progress::{Antichain, Timestamp};
 
+use crate::ShardId;
 use crate::internal::state::HollowBatch;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -266,6 +267,35 @@ impl<T: Timestamp + Lattice> Trace<T> {
             merges,
         }
     }
+
+    /// Returns a copy of this trace whose parts refer to the blobs of `owner`,
+    /// for use as the initial trace of a fork of that shard.
+    ///
+    /// Compactions claimed in this trace are not carried over, since they
+    /// belong to the writers of `owner`.
+    pub(crate) fn fork(&self, owner: &ShardId) -> Self {
+        let fork_batch = |batch: &Arc<HollowBatch<T>>| {
+            let mut batch = HollowBatch::clone(batch);
+            for part in batch.parts.iter_mut() {
+                part.make_foreign(owner);
+            }
+            Arc::new(batch)
+        };
+        let mut flat = self.flatten();
+        flat.legacy_batches = flat
+            .legacy_batches
+            .keys()
+            .map(|batch| (fork_batch(batch), ()))
+            .collect();
+        for batch in flat.hollow_batches.values_mut() {
+            *batch = fork_batch(batch);
+        }
+        for merge in flat.merges.values_mut() {
+            merge.active_compaction = None;
+        }
+        Self::unflatten(flat).expect("forking preserves the structure of the trace")
+    }
+
     pub(crate) fn unflatten(value: FlatTrace<T>) -> Result<Self, String> {
         let FlatTrace {
             since,
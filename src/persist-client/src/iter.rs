@@ -1184,6 +1184,7 @@ mod tests {
                             format: None,
                             schema_id: None,
                             deprecated_schema_id: None,
+                            encryption_key_id: None,
                         }))
                     })
                     .collect();
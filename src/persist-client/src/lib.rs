@@ -27,7 +27,7 @@ use mz_ore::{instrument, soft_assert_or_log};
 use mz_persist::location::{Blob, Consensus, ExternalError};
 use mz_persist_types::schema::SchemaId;
 use mz_persist_types::{Codec, Codec64, Opaque};
-use timely::progress::Timestamp;
+use timely::progress::{Antichain, Timestamp};
 
 use crate::async_runtime::IsolatedRuntime;
 use crate::cache::{PersistClientCache, StateCache};
@@ -60,6 +60,7 @@ pub mod cli {
 pub mod critical;
 pub mod error;
 pub mod fetch;
+pub mod iceberg;
 pub mod internals_bench;
 pub mod iter;
 pub mod metrics {
@@ -103,6 +104,7 @@ mod internal {
     pub mod merge;
     pub mod metrics;
     pub mod paths;
+    pub mod reencrypt;
     pub mod restore;
     pub mod service;
     pub mod state;
@@ -665,6 +667,135 @@ impl PersistClient {
         Ok(())
     }
 
+    /// Creates the shard `target` as a copy of the shard `source` at `as_of`,
+    /// without copying any of its data.
+    ///
+    /// The initial state of the fork references the blobs of `source`. The
+    /// fork can be read at `as_of` (but not before) and written to starting at
+    /// the upper of `source` at the time of the fork. Its garbage collection
+    /// never deletes blobs of `source`. Instead, `source` records the fork in
+    /// its state and its garbage collection skips deleting the blobs that the
+    /// fork still references, keeping them in its state until the fork no
+    /// longer does. Once the fork no longer references any, because it
+    /// compacted them away or was finalized, `source` releases the fork and
+    /// deletes these blobs. Until then, `source` cannot be finalized.
+    ///
+    /// Forks of forks are not supported: a fork can only be forked again once
+    /// it no longer references the blobs of its source.
+    ///
+    /// Creating SQL collections from forks is not yet supported, see
+    /// `doc/developer/design/20261019_clone_collections.md`.
+    #[instrument(level = "debug", fields(source = %source, target = %target))]
+    pub async fn fork_shard<K, V, T, D>(
+        &self,
+        source: ShardId,
+        target: ShardId,
+        as_of: Antichain<T>,
+        diagnostics: Diagnostics,
+    ) -> Result<(), InvalidUsage<T>>
+    where
+        K: Debug + Codec,
+        V: Debug + Codec,
+        T: Timestamp + Lattice + Codec64 + Sync,
+        D: Semigroup + Codec64 + Send + Sync,
+    {
+        let machine = self
+            .make_machine::<K, V, T, D>(source, diagnostics.clone())
+            .await?;
+        let gc = GarbageCollector::new(machine.clone(), Arc::clone(&self.isolated_runtime));
+
+        // Hold back the blobs of the forked version of `source` until the
+        // fork exists, after which its garbage collection checks the fork.
+        let reader_id = LeasedReaderId::new();
+        let (fork, maintenance) = machine
+            .register_fork(
+                &reader_id,
+                &target,
+                &as_of,
+                READER_LEASE_DURATION.get(&self.cfg),
+                (self.cfg.now)(),
+            )
+            .await;
+        maintenance.start_performing(&machine, &gc);
+        let fork = fork?;
+
+        // NB: Initializing the fork through a Machine would create an empty
+        // shard instead.
+        let shard_metrics = self.metrics.shards.shard(&target, &diagnostics.shard_name);
+        let created = self
+            .state_versions()
+            .init_fork::<K, V, T, D>(&shard_metrics, fork)
+            .await;
+        let (_, maintenance) = machine.expire_leased_reader(&reader_id).await;
+        maintenance.start_performing(&machine, &gc);
+        if !created {
+            let (_, maintenance) = machine.release_fork(&target).await;
+            maintenance.start_performing(&machine, &gc);
+            return Err(InvalidUsage::ForkTargetExists(target));
+        }
+        Ok(())
+    }
+
+    /// Unregisters the fork `target` from `source`. See [Self::fork_shard].
+    ///
+    /// Garbage collection of `source` does this on its own once the fork no
+    /// longer references any of its blobs, so this is only needed to clean up
+    /// after a fork that failed to be created, or to finalize `source` without
+    /// waiting for its garbage collection. It's only allowed once the fork no
+    /// longer references any blobs of `source`: either because it has been
+    /// finalized, or because it compacted away all the data it was forked
+    /// with. Readers of the fork that still hold back older versions of its
+    /// state must have been expired.
+    ///
+    /// Returns whether `target` was a fork of `source`.
+    #[instrument(level = "debug", fields(source = %source, target = %target))]
+    pub async fn release_fork<K, V, T, D>(
+        &self,
+        source: ShardId,
+        target: ShardId,
+        diagnostics: Diagnostics,
+    ) -> Result<bool, InvalidUsage<T>>
+    where
+        K: Debug + Codec,
+        V: Debug + Codec,
+        T: Timestamp + Lattice + Codec64 + Sync,
+        D: Semigroup + Codec64 + Send + Sync,
+    {
+        let live_diffs = self
+            .state_versions()
+            .fetch_recent_live_diffs::<T>(&target)
+            .await;
+        if !live_diffs.0.is_empty() {
+            let fork = self
+                .make_machine::<K, V, T, D>(target, diagnostics.clone())
+                .await?;
+            let in_use = !fork.is_finalized()
+                && fork
+                    .applier
+                    .all_batches()
+                    .iter()
+                    .any(|b| b.parts.iter().any(|p| p.is_foreign()));
+            if in_use {
+                return Err(InvalidUsage::ForkInUse(target));
+            }
+        }
+
+        let machine = self.make_machine::<K, V, T, D>(source, diagnostics).await?;
+        let gc = GarbageCollector::new(machine.clone(), Arc::clone(&self.isolated_runtime));
+        let (released, maintenance) = machine.release_fork(&target).await;
+        maintenance.start_performing(&machine, &gc);
+        Ok(released)
+    }
+
+    fn state_versions(&self) -> StateVersions {
+        StateVersions::new(
+            self.cfg.clone(),
+            Arc::clone(&self.consensus),
+            Arc::clone(&self.blob),
+            Arc::clone(&self.metrics),
+        )
+    }
+
     /// Returns the internal state of the shard for debugging and QA.
     ///
     /// We'll be thoughtful about making unnecessary changes, but the **output
@@ -674,12 +805,7 @@ impl PersistClient {
         &self,
         shard_id: &ShardId,
     ) -> Result<impl serde::Serialize, anyhow::Error> {
-        let state_versions = StateVersions::new(
-            self.cfg.clone(),
-            Arc::clone(&self.consensus),
-            Arc::clone(&self.blob),
-            Arc::clone(&self.metrics),
-        );
+        let state_versions = self.state_versions();
         // TODO: Don't fetch all live diffs. Feels like we should pull out a new
         // method in StateVersions for fetching the latest version of State of a
         // shard that might or might not exist.
@@ -749,7 +875,9 @@ mod tests {
     use timely::order::PartialOrder;
     use timely::progress::Antichain;
 
-    use crate::batch::BLOB_TARGET_SIZE;
+    use crate::batch::{
+        BLOB_TARGET_SIZE, INLINE_WRITES_SINGLE_MAX_BYTES, INLINE_WRITES_TOTAL_MAX_BYTES,
+    };
     use crate::cache::PersistClientCache;
     use crate::cfg::BATCH_BUILDER_MAX_OUTSTANDING_PARTS;
     use crate::error::{CodecConcreteType, CodecMismatch, UpperMismatch};
@@ -1962,6 +2090,135 @@ mod tests {
         assert!(is_finalized, "shard must still be finalized");
     }
 
+    #[mz_persist_proc::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn fork_shard(dyncfgs: ConfigUpdates) {
+        let data = [
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+            (("2".to_owned(), "two".to_owned()), 2, 1),
+            (("3".to_owned(), "three".to_owned()), 3, 1),
+        ];
+        let fork_data = [(("4".to_owned(), "four".to_owned()), 3, 1)];
+        let empty: &[((String, String), u64, i64)] = &[];
+
+        // Write the data to blob, so that the fork references it.
+        let cache = new_test_client_cache(&dyncfgs);
+        cache.cfg.set_config(&INLINE_WRITES_SINGLE_MAX_BYTES, 0);
+        cache.cfg.set_config(&INLINE_WRITES_TOTAL_MAX_BYTES, 0);
+        let client = cache
+            .open(PersistLocation::new_in_mem())
+            .await
+            .expect("client construction failed");
+
+        let (source, fork) = (ShardId::new(), ShardId::new());
+        let (mut write, mut read) = client.expect_open::<String, String, u64, i64>(source).await;
+        write
+            .expect_append(&data[..2], write.upper().clone(), vec![3])
+            .await;
+        read.downgrade_since(&Antichain::from_elem(1)).await;
+
+        // A fork can only be created at a time its source can be read at.
+        for as_of in [0, 3] {
+            let res = client
+                .fork_shard::<String, String, u64, i64>(
+                    source,
+                    fork,
+                    Antichain::from_elem(as_of),
+                    Diagnostics::for_tests(),
+                )
+                .await;
+            assert!(matches!(res, Err(InvalidUsage::InvalidForkAsOf { .. })));
+        }
+
+        client
+            .fork_shard::<String, String, u64, i64>(
+                source,
+                fork,
+                Antichain::from_elem(2),
+                Diagnostics::for_tests(),
+            )
+            .await
+            .expect("invalid usage");
+        let res = client
+            .fork_shard::<String, String, u64, i64>(
+                source,
+                fork,
+                Antichain::from_elem(2),
+                Diagnostics::for_tests(),
+            )
+            .await;
+        assert!(matches!(res, Err(InvalidUsage::ForkTargetExists(x)) if x == fork));
+        let res = client
+            .fork_shard::<String, String, u64, i64>(
+                fork,
+                ShardId::new(),
+                Antichain::from_elem(2),
+                Diagnostics::for_tests(),
+            )
+            .await;
+        assert!(matches!(res, Err(InvalidUsage::ForkOfFork(x)) if x == fork));
+
+        // The fork starts out with the data of its source, but the two evolve
+        // independently afterwards.
+        write
+            .expect_append(&data[2..], write.upper().clone(), vec![4])
+            .await;
+        let (mut fork_write, mut fork_read) =
+            client.expect_open::<String, String, u64, i64>(fork).await;
+        assert_eq!(fork_write.upper(), &Antichain::from_elem(3));
+        assert_eq!(fork_read.since(), &Antichain::from_elem(2));
+        assert_eq!(
+            fork_read.expect_snapshot_and_fetch(2).await,
+            all_ok(&data[..2], 2)
+        );
+        fork_write
+            .expect_append(&fork_data, fork_write.upper().clone(), vec![4])
+            .await;
+        assert_eq!(
+            fork_read.expect_snapshot_and_fetch(3).await,
+            all_ok(data[..2].iter().chain(&fork_data), 3)
+        );
+        assert_eq!(read.expect_snapshot_and_fetch(3).await, all_ok(&data, 3));
+
+        // The fork can only be released once it no longer references the
+        // blobs of its source.
+        let res = client
+            .release_fork::<String, String, u64, i64>(source, fork, Diagnostics::for_tests())
+            .await;
+        assert!(matches!(res, Err(InvalidUsage::ForkInUse(x)) if x == fork));
+        fork_read.downgrade_since(&Antichain::new()).await;
+        fork_write
+            .compare_and_append(empty, Antichain::from_elem(4), Antichain::new())
+            .await
+            .expect("usage should be valid")
+            .expect("upper should match");
+        client
+            .finalize_shard::<String, String, u64, i64>(fork, Diagnostics::for_tests())
+            .await
+            .expect("invalid usage");
+
+        // The data of the source is still around.
+        assert_eq!(read.expect_snapshot_and_fetch(3).await, all_ok(&data, 3));
+
+        // The source releases the finalized fork on its own, so it can be
+        // finalized without an explicit release.
+        read.downgrade_since(&Antichain::new()).await;
+        write
+            .compare_and_append(empty, Antichain::from_elem(4), Antichain::new())
+            .await
+            .expect("usage should be valid")
+            .expect("upper should match");
+        client
+            .finalize_shard::<String, String, u64, i64>(source, Diagnostics::for_tests())
+            .await
+            .expect("invalid usage");
+        let released = client
+            .release_fork::<String, String, u64, i64>(source, fork, Diagnostics::for_tests())
+            .await
+            .expect("invalid usage");
+        assert!(!released);
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(4096))]
 
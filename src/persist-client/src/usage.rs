@@ -465,6 +465,17 @@ impl StorageUsageClient {
                 }
             })
         }) {}
+        // GC keeps the parts that forks still reference in state, even after
+        // the diffs that referenced them are gone.
+        for part in states_iter.state().collections.fork_held_parts.values() {
+            if let Some(writer_id) = part.writer_key() {
+                let writer_referenced_batches_bytes =
+                    referenced_batches_bytes.entry(writer_id).or_default();
+                *writer_referenced_batches_bytes += u64::cast_from(part.hollow_bytes());
+            } else {
+                referenced_other_bytes += u64::cast_from(part.hollow_bytes());
+            }
+        }
 
         let mut current_state_batches_bytes = 0;
         let mut current_state_rollups_bytes = 0;
@@ -22,7 +22,7 @@ use mz_postgres_client::PostgresClientKnobs;
 use mz_postgres_client::metrics::PostgresClientMetrics;
 
 use crate::azure::{AzureBlob, AzureBlobConfig};
-use crate::file::{FileBlob, FileBlobConfig};
+use crate::file::{FileBlob, FileBlobConfig, FileConsensus, FileConsensusConfig};
 use crate::location::{Blob, Consensus, Determinate, ExternalError};
 use crate::mem::{MemBlob, MemBlobConfig, MemConsensus};
 use crate::metrics::S3BlobMetrics;
@@ -37,6 +37,7 @@ pub fn all_dyn_configs(configs: ConfigSet) -> ConfigSet {
         .add(&crate::s3::ENABLE_S3_LGALLOC_CC_SIZES)
         .add(&crate::s3::ENABLE_S3_LGALLOC_NONCC_SIZES)
         .add(&crate::postgres::USE_POSTGRES_TUNED_QUERIES)
+        .add(&crate::encrypt::BLOB_ENCRYPTION_ACTIVE_KEY)
 }
 
 /// Config for an implementation of [Blob].
@@ -214,6 +215,8 @@ impl BlobConfig {
 pub enum ConsensusConfig {
     /// Config for [PostgresConsensus].
     Postgres(PostgresConsensusConfig),
+    /// Config for [FileConsensus].
+    File(FileConsensusConfig),
     /// Config for [MemConsensus], only available in testing.
     Mem,
 }
@@ -225,6 +228,7 @@ impl ConsensusConfig {
             ConsensusConfig::Postgres(config) => {
                 Ok(Arc::new(PostgresConsensus::open(config).await?))
             }
+            ConsensusConfig::File(config) => Ok(Arc::new(FileConsensus::open(config).await?)),
             ConsensusConfig::Mem => Ok(Arc::new(MemConsensus::default())),
         }
     }
@@ -240,6 +244,7 @@ impl ConsensusConfig {
             "postgres" | "postgresql" => Ok(ConsensusConfig::Postgres(
                 PostgresConsensusConfig::new(url, knobs, metrics, dyncfg)?,
             )),
+            "file" => Ok(ConsensusConfig::File(FileConsensusConfig::from(url.path()))),
             "mem" => {
                 if !cfg!(debug_assertions) {
                     warn!("persist unexpectedly using in-mem consensus in a release binary");
@@ -0,0 +1,923 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Client-side envelope encryption of [Blob] contents.
+//!
+//! Every value written through an [EncryptedBlob] is encrypted with a fresh,
+//! random 256-bit data key using AES-256-GCM. The data key is itself encrypted
+//! ("wrapped") with a long-lived master key and stored alongside the
+//! ciphertext, together with the id of the master key that wrapped it. The
+//! blob key is bound into the data ciphertext as associated data, so an
+//! encrypted value can't be moved to a different key without detection.
+//!
+//! Rotating master keys is an online operation: new writes always use the
+//! active master key, while reads look up whichever master key is recorded in
+//! the envelope. To rotate, store the new key in the secrets store and set
+//! [BLOB_ENCRYPTION_ACTIVE_KEY] to its id. Every process loads the new key from
+//! its [BlobEncryptionKeySource] before its next write and encrypts with it
+//! from then on. Processes that read a blob wrapped by a key they don't know
+//! yet load that key on demand, so there is no need to restart anything.
+//! Retired keys must stay in the secrets store until every blob wrapped by
+//! them has been rewritten, which [EncryptedBlob::reencrypt] does for a single
+//! blob. Persist records the id of the key that each part and rollup was
+//! written with in shard state, which `persistcli admin reencrypt-blobs` uses
+//! to find the blobs that still need rewriting.
+//!
+//! By default, values that don't start with the envelope magic are returned
+//! unmodified. This allows enabling encryption on an existing deployment:
+//! blobs written before encryption was turned on remain readable and can be
+//! encrypted in the background with [EncryptedBlob::reencrypt]. Once that's
+//! done, [BlobEncryptionKeys::with_strict] turns unencrypted values into read
+//! errors, so that a value written to blob storage by anyone without the keys
+//! is never mistaken for a persist blob.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use anyhow::{Context, anyhow};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::FutureExt;
+use futures_util::future::BoxFuture;
+use mz_dyncfg::{Config, ConfigValHandle};
+use mz_ore::bytes::SegmentedBytes;
+use openssl::rand::rand_bytes;
+use openssl::symm::{Cipher, decrypt_aead, encrypt_aead};
+use tracing::{info, warn};
+
+use crate::location::{Blob, BlobMetadata, Determinate, ExternalError};
+
+/// The prefix of every value written by an [EncryptedBlob].
+const MAGIC: &[u8; 8] = b"MZPENC01";
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+/// The id of the master key to encrypt newly written blobs with.
+pub const BLOB_ENCRYPTION_ACTIVE_KEY: Config<&'static str> = Config::new(
+    "persist_blob_encryption_active_key",
+    "",
+    "The id of the secret holding the master key to encrypt newly written persist blobs with. \
+    Overrides --persist-blob-encryption-active-key once set. Empty leaves the active key unchanged.",
+);
+
+type ReadKeyFn = dyn Fn(String) -> BoxFuture<'static, Result<Vec<u8>, anyhow::Error>> + Send + Sync;
+
+/// Reads the key material of master keys by id, e.g., from a secrets store.
+#[derive(Clone)]
+pub struct BlobEncryptionKeySource {
+    read: Arc<ReadKeyFn>,
+}
+
+impl Debug for BlobEncryptionKeySource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlobEncryptionKeySource")
+            .finish_non_exhaustive()
+    }
+}
+
+impl BlobEncryptionKeySource {
+    /// Returns a source that reads the key material of a key with `read`.
+    pub fn new<F, Fut>(read: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, anyhow::Error>> + Send + 'static,
+    {
+        BlobEncryptionKeySource {
+            read: Arc::new(move |id| read(id).boxed()),
+        }
+    }
+
+    /// Reads the master key with the given id, which is parsed with
+    /// [BlobEncryptionKey::parse].
+    pub async fn read(&self, id: &str) -> Result<BlobEncryptionKey, anyhow::Error> {
+        let material = (self.read)(id.to_owned())
+            .await
+            .with_context(|| format!("reading blob encryption key {}", id))?;
+        BlobEncryptionKey::parse(id, &material)
+    }
+}
+
+/// A 256-bit master key, used to wrap the per-blob data keys.
+#[derive(Clone)]
+pub struct BlobEncryptionKey {
+    id: String,
+    key: [u8; KEY_LEN],
+}
+
+impl Debug for BlobEncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Intentionally omit the key material.
+        f.debug_struct("BlobEncryptionKey")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BlobEncryptionKey {
+    /// Returns a new master key with the given id and key material.
+    ///
+    /// The id is recorded in every blob wrapped by this key and must be
+    /// non-empty, unique, and never reused for different key material.
+    pub fn new(id: impl Into<String>, key: [u8; KEY_LEN]) -> Result<Self, anyhow::Error> {
+        let id = id.into();
+        if id.is_empty() || id.len() > usize::from(u8::MAX) {
+            return Err(anyhow!(
+                "blob encryption key id must be between 1 and 255 bytes: {:?}",
+                id
+            ));
+        }
+        Ok(BlobEncryptionKey { id, key })
+    }
+
+    /// Returns a new master key with random key material.
+    pub fn generate(id: impl Into<String>) -> Result<Self, anyhow::Error> {
+        let mut key = [0u8; KEY_LEN];
+        rand_bytes(&mut key)?;
+        Self::new(id, key)
+    }
+
+    /// Parses a master key from either 32 raw bytes or 64 hex characters
+    /// (surrounding whitespace is ignored for the latter).
+    pub fn parse(id: impl Into<String>, material: &[u8]) -> Result<Self, anyhow::Error> {
+        let id = id.into();
+        if let Ok(key) = <[u8; KEY_LEN]>::try_from(material) {
+            return Self::new(id, key);
+        }
+        let hex = std::str::from_utf8(material)
+            .ok()
+            .map(|x| x.trim())
+            .filter(|x| x.len() == 2 * KEY_LEN && x.is_ascii())
+            .ok_or_else(|| {
+                anyhow!(
+                    "blob encryption key {} must be {} raw bytes or {} hex characters",
+                    id,
+                    KEY_LEN,
+                    2 * KEY_LEN
+                )
+            })?;
+        let mut key = [0u8; KEY_LEN];
+        for (idx, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * idx..2 * idx + 2], 16)
+                .with_context(|| format!("invalid hex in blob encryption key {}", id))?;
+        }
+        Self::new(id, key)
+    }
+
+    /// The id of this key.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+#[derive(Debug)]
+struct KeyRing {
+    active: String,
+    keys: BTreeMap<String, BlobEncryptionKey>,
+}
+
+/// A shared, rotatable set of master keys.
+///
+/// New values are encrypted with the active key; values encrypted with any
+/// key in the ring can be decrypted. Clones share the same underlying ring, so
+/// a rotation is immediately visible to every [EncryptedBlob] using it.
+///
+/// With a [source](Self::with_source), keys that are not in the ring are
+/// loaded from it when needed, and with an [active key
+/// config](Self::with_active_key_config), the active key follows that config.
+#[derive(Debug, Clone)]
+pub struct BlobEncryptionKeys {
+    ring: Arc<RwLock<KeyRing>>,
+    strict: bool,
+    source: Option<BlobEncryptionKeySource>,
+    active_key_config: Option<ConfigValHandle<String>>,
+}
+
+impl BlobEncryptionKeys {
+    /// Returns a new key ring containing only `active`.
+    pub fn new(active: BlobEncryptionKey) -> Self {
+        let ring = KeyRing {
+            active: active.id.clone(),
+            keys: BTreeMap::from([(active.id.clone(), active)]),
+        };
+        BlobEncryptionKeys {
+            ring: Arc::new(RwLock::new(ring)),
+            strict: false,
+            source: None,
+            active_key_config: None,
+        }
+    }
+
+    /// Returns a new key ring containing `keys`, with `active` as the active
+    /// key.
+    pub fn from_keys(
+        keys: impl IntoIterator<Item = BlobEncryptionKey>,
+        active: &str,
+    ) -> Result<Self, anyhow::Error> {
+        let mut ring = BTreeMap::new();
+        for key in keys {
+            if ring.insert(key.id.clone(), key).is_some() {
+                return Err(anyhow!("duplicate blob encryption key id"));
+            }
+        }
+        if !ring.contains_key(active) {
+            return Err(anyhow!("active blob encryption key {} not found", active));
+        }
+        let ring = KeyRing {
+            active: active.to_owned(),
+            keys: ring,
+        };
+        Ok(BlobEncryptionKeys {
+            ring: Arc::new(RwLock::new(ring)),
+            strict: false,
+            source: None,
+            active_key_config: None,
+        })
+    }
+
+    /// Sets whether values without an encryption envelope are rejected on
+    /// read, instead of being returned unchanged.
+    ///
+    /// Only enable this once every existing blob has been encrypted.
+    /// [EncryptedBlob::reencrypt] accepts unencrypted values regardless.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets the source to load keys from that are not in the ring yet.
+    pub fn with_source(mut self, source: BlobEncryptionKeySource) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Sets the config that selects the active key, usually
+    /// [BLOB_ENCRYPTION_ACTIVE_KEY].
+    ///
+    /// Before each write, a key selected by the config that isn't active yet
+    /// is loaded from the [source](Self::with_source) and made active. An
+    /// empty config leaves the active key unchanged.
+    pub fn with_active_key_config(mut self, config: ConfigValHandle<String>) -> Self {
+        self.active_key_config = Some(config);
+        self
+    }
+
+    /// Loads the key with the given id from the source into the ring, unless
+    /// it is in the ring already.
+    pub async fn load(&self, id: &str) -> Result<(), anyhow::Error> {
+        if self.contains(id) {
+            return Ok(());
+        }
+        let source = self
+            .source
+            .as_ref()
+            .ok_or_else(|| anyhow!("unknown blob encryption key {}", id))?;
+        let key = source.read(id).await?;
+        self.add(key)?;
+        info!("loaded blob encryption key {}", id);
+        Ok(())
+    }
+
+    /// Makes the key selected by the active key config active, if it isn't
+    /// already.
+    ///
+    /// If the key can't be loaded, the previously active key stays active, so
+    /// that writes don't fail.
+    async fn refresh_active(&self) {
+        let Some(config) = &self.active_key_config else {
+            return;
+        };
+        let id = config.get();
+        if id.is_empty() || id == self.active_id() {
+            return;
+        }
+        match self.load(&id).await {
+            Ok(()) => {
+                self.ring.write().expect("lock poisoned").active = id.clone();
+                info!("rotated active blob encryption key to {}", id);
+            }
+            Err(err) => warn!(
+                "unable to rotate active blob encryption key to {}: {:#}",
+                id, err
+            ),
+        }
+    }
+
+    /// Loads the key that `value` is encrypted with, if it's encrypted with a
+    /// key that is not in the ring.
+    ///
+    /// Failures are ignored here and reported by the following decryption.
+    async fn load_for(&self, value: &[u8]) {
+        let Ok(Some(id)) = encryption_key_id(value) else {
+            return;
+        };
+        if let Err(err) = self.load(&id).await {
+            warn!("unable to load blob encryption key {}: {:#}", id, err);
+        }
+    }
+
+    /// Loads a key ring from a directory containing one master key per file.
+    ///
+    /// The file name is the key id and the contents are parsed with
+    /// [BlobEncryptionKey::parse]. This matches the layout of a mounted
+    /// Kubernetes secret volume as well as the local file secrets store.
+    /// Hidden files (e.g. the `..data` symlinks in Kubernetes volumes) are
+    /// skipped.
+    pub fn load_dir(dir: &Path, active: &str) -> Result<Self, anyhow::Error> {
+        let mut keys = Vec::new();
+        let entries = std::fs::read_dir(dir)
+            .with_context(|| format!("reading blob encryption keys from {}", dir.display()))?;
+        for entry in entries {
+            let entry = entry?;
+            let Some(id) = entry.file_name().to_str().map(|x| x.to_owned()) else {
+                continue;
+            };
+            if id.starts_with('.') || !entry.path().is_file() {
+                continue;
+            }
+            let material = std::fs::read(entry.path())?;
+            keys.push(BlobEncryptionKey::parse(id, &material)?);
+        }
+        Self::from_keys(keys, active).with_context(|| format!("loading {}", dir.display()))
+    }
+
+    /// Adds a key to the ring without making it active.
+    ///
+    /// Returns an error if a different key with the same id is already present.
+    pub fn add(&self, key: BlobEncryptionKey) -> Result<(), anyhow::Error> {
+        let mut ring = self.ring.write().expect("lock poisoned");
+        match ring.keys.get(&key.id) {
+            Some(existing) if existing.key != key.key => Err(anyhow!(
+                "blob encryption key {} already exists with different key material",
+                key.id
+            )),
+            Some(_) => Ok(()),
+            None => {
+                ring.keys.insert(key.id.clone(), key);
+                Ok(())
+            }
+        }
+    }
+
+    /// Adds `key` to the ring, if necessary, and makes it the active key.
+    ///
+    /// The previously active key is retained so that existing blobs remain
+    /// readable.
+    pub fn rotate(&self, key: BlobEncryptionKey) -> Result<(), anyhow::Error> {
+        let id = key.id.clone();
+        self.add(key)?;
+        self.ring.write().expect("lock poisoned").active = id;
+        Ok(())
+    }
+
+    /// Removes a retired key from the ring.
+    ///
+    /// Blobs still wrapped by this key will fail to decrypt afterward. The
+    /// active key can't be removed.
+    pub fn remove(&self, id: &str) -> Result<(), anyhow::Error> {
+        let mut ring = self.ring.write().expect("lock poisoned");
+        if ring.active == id {
+            return Err(anyhow!("cannot remove active blob encryption key {}", id));
+        }
+        ring.keys.remove(id);
+        Ok(())
+    }
+
+    /// The id of the active key.
+    pub fn active_id(&self) -> String {
+        self.ring.read().expect("lock poisoned").active.clone()
+    }
+
+    /// The id of the key that a value written now is encrypted with, after
+    /// following the active key config.
+    ///
+    /// Callers record this next to references to the blobs they write. A
+    /// rotation between this call and the write can leave the recorded id one
+    /// key behind, which only makes the blob look like it still needs to be
+    /// re-encrypted.
+    pub async fn refreshed_active_id(&self) -> String {
+        self.refresh_active().await;
+        self.active_id()
+    }
+
+    fn active(&self) -> BlobEncryptionKey {
+        let ring = self.ring.read().expect("lock poisoned");
+        ring.keys
+            .get(&ring.active)
+            .expect("active key is in ring")
+            .clone()
+    }
+
+    /// Whether the ring contains the key with the given id.
+    pub fn contains(&self, id: &str) -> bool {
+        self.ring
+            .read()
+            .expect("lock poisoned")
+            .keys
+            .contains_key(id)
+    }
+
+    fn get(&self, id: &str) -> Option<BlobEncryptionKey> {
+        self.ring
+            .read()
+            .expect("lock poisoned")
+            .keys
+            .get(id)
+            .cloned()
+    }
+
+    /// Encrypts `plaintext`, to be stored at `blob_key`, with the active key.
+    pub fn encrypt(&self, blob_key: &str, plaintext: &[u8]) -> Result<Vec<u8>, anyhow::Error> {
+        let master = self.active();
+        let cipher = Cipher::aes_256_gcm();
+
+        let mut data_key = [0u8; KEY_LEN];
+        rand_bytes(&mut data_key)?;
+        let mut key_nonce = [0u8; NONCE_LEN];
+        rand_bytes(&mut key_nonce)?;
+        let mut data_nonce = [0u8; NONCE_LEN];
+        rand_bytes(&mut data_nonce)?;
+
+        let mut key_tag = [0u8; TAG_LEN];
+        let wrapped_key = encrypt_aead(
+            cipher,
+            &master.key,
+            Some(&key_nonce),
+            master.id.as_bytes(),
+            &data_key,
+            &mut key_tag,
+        )?;
+        let mut data_tag = [0u8; TAG_LEN];
+        let ciphertext = encrypt_aead(
+            cipher,
+            &data_key,
+            Some(&data_nonce),
+            blob_key.as_bytes(),
+            plaintext,
+            &mut data_tag,
+        )?;
+
+        let id_len = u8::try_from(master.id.len()).expect("validated in constructor");
+        let mut buf = Vec::with_capacity(
+            MAGIC.len()
+                + 1
+                + master.id.len()
+                + 2 * (NONCE_LEN + TAG_LEN)
+                + KEY_LEN
+                + ciphertext.len(),
+        );
+        buf.extend_from_slice(MAGIC);
+        buf.push(id_len);
+        buf.extend_from_slice(master.id.as_bytes());
+        buf.extend_from_slice(&key_nonce);
+        buf.extend_from_slice(&wrapped_key);
+        buf.extend_from_slice(&key_tag);
+        buf.extend_from_slice(&data_nonce);
+        buf.extend_from_slice(&ciphertext);
+        buf.extend_from_slice(&data_tag);
+        Ok(buf)
+    }
+
+    /// Whether values without an encryption envelope are rejected on read.
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Decrypts a value read from `blob_key`.
+    ///
+    /// Values that were not written by [Self::encrypt] are returned unchanged,
+    /// unless this key ring is [strict](Self::with_strict).
+    pub fn decrypt(&self, blob_key: &str, value: Vec<u8>) -> Result<Vec<u8>, anyhow::Error> {
+        if self.strict && Envelope::parse(&value)?.is_none() {
+            return Err(anyhow!("blob {} is not encrypted", blob_key));
+        }
+        self.decrypt_or_passthrough(blob_key, value)
+    }
+
+    /// Like [Self::decrypt], but always returns unencrypted values unchanged.
+    fn decrypt_or_passthrough(
+        &self,
+        blob_key: &str,
+        value: Vec<u8>,
+    ) -> Result<Vec<u8>, anyhow::Error> {
+        let Some(envelope) = Envelope::parse(&value)? else {
+            return Ok(value);
+        };
+        let master = self.get(envelope.key_id).ok_or_else(|| {
+            anyhow!(
+                "blob {} is encrypted with unknown key {}",
+                blob_key,
+                envelope.key_id
+            )
+        })?;
+        let cipher = Cipher::aes_256_gcm();
+        let data_key = decrypt_aead(
+            cipher,
+            &master.key,
+            Some(envelope.key_nonce),
+            master.id.as_bytes(),
+            envelope.wrapped_key,
+            envelope.key_tag,
+        )
+        .with_context(|| format!("unwrapping data key of blob {}", blob_key))?;
+        let plaintext = decrypt_aead(
+            cipher,
+            &data_key,
+            Some(envelope.data_nonce),
+            blob_key.as_bytes(),
+            envelope.ciphertext,
+            envelope.data_tag,
+        )
+        .with_context(|| format!("decrypting blob {}", blob_key))?;
+        Ok(plaintext)
+    }
+}
+
+/// The parsed layout of an encrypted value:
+///
+/// ```text
+/// magic | key id len (u8) | key id | key nonce | wrapped data key | key tag
+///       | data nonce | ciphertext | data tag
+/// ```
+#[derive(Debug)]
+struct Envelope<'a> {
+    key_id: &'a str,
+    key_nonce: &'a [u8],
+    wrapped_key: &'a [u8],
+    key_tag: &'a [u8],
+    data_nonce: &'a [u8],
+    ciphertext: &'a [u8],
+    data_tag: &'a [u8],
+}
+
+impl<'a> Envelope<'a> {
+    /// Returns None if `value` is not an encrypted value.
+    fn parse(value: &'a [u8]) -> Result<Option<Self>, anyhow::Error> {
+        let Some(rest) = value.strip_prefix(MAGIC.as_slice()) else {
+            return Ok(None);
+        };
+        let truncated = || anyhow!("truncated encrypted blob");
+        let (id_len, rest) = rest.split_first().ok_or_else(truncated)?;
+        let id_len = usize::from(*id_len);
+        let header_len = id_len + NONCE_LEN + KEY_LEN + TAG_LEN + NONCE_LEN;
+        if rest.len() < header_len + TAG_LEN {
+            return Err(truncated());
+        }
+        let (key_id, rest) = rest.split_at(id_len);
+        let key_id = std::str::from_utf8(key_id).context("invalid encryption key id")?;
+        let (key_nonce, rest) = rest.split_at(NONCE_LEN);
+        let (wrapped_key, rest) = rest.split_at(KEY_LEN);
+        let (key_tag, rest) = rest.split_at(TAG_LEN);
+        let (data_nonce, rest) = rest.split_at(NONCE_LEN);
+        let (ciphertext, data_tag) = rest.split_at(rest.len() - TAG_LEN);
+        Ok(Some(Envelope {
+            key_id,
+            key_nonce,
+            wrapped_key,
+            key_tag,
+            data_nonce,
+            ciphertext,
+            data_tag,
+        }))
+    }
+}
+
+/// The length of the longest prefix of an encrypted value that
+/// [encryption_key_id] inspects: the magic, the key id length, and a key id of
+/// at most 255 bytes.
+pub const ENCRYPTION_KEY_ID_PREFIX_LEN: usize = MAGIC.len() + 1 + 255;
+
+/// Returns the id of the master key that `value` is encrypted with, or None if
+/// it is not encrypted.
+///
+/// Only the first [ENCRYPTION_KEY_ID_PREFIX_LEN] bytes of `value` are
+/// inspected, so it's sufficient to pass a prefix of the value.
+pub fn encryption_key_id(value: &[u8]) -> Result<Option<String>, anyhow::Error> {
+    let Some(rest) = value.strip_prefix(MAGIC.as_slice()) else {
+        return Ok(None);
+    };
+    let truncated = || anyhow!("truncated encrypted blob");
+    let (id_len, rest) = rest.split_first().ok_or_else(truncated)?;
+    let key_id = rest.get(..usize::from(*id_len)).ok_or_else(truncated)?;
+    let key_id = std::str::from_utf8(key_id).context("invalid encryption key id")?;
+    Ok(Some(key_id.to_owned()))
+}
+
+/// An implementation of [Blob] that transparently encrypts values written to,
+/// and decrypts values read from, a delegate [Blob].
+///
+/// The sizes reported by [Blob::list_keys_and_metadata] and [Blob::delete] are
+/// those of the encrypted values.
+#[derive(Debug)]
+pub struct EncryptedBlob {
+    blob: Arc<dyn Blob>,
+    keys: BlobEncryptionKeys,
+}
+
+impl EncryptedBlob {
+    /// Returns a new [EncryptedBlob] wrapping `blob`.
+    pub fn new(blob: Arc<dyn Blob>, keys: BlobEncryptionKeys) -> Self {
+        EncryptedBlob { blob, keys }
+    }
+
+    /// Rewrites the value at `key` so that it is encrypted with the active
+    /// master key.
+    ///
+    /// Returns whether the value was rewritten: values that are missing or
+    /// already encrypted with the active key are left alone. Unencrypted
+    /// values are rewritten even if the key ring is strict.
+    ///
+    /// Persist blobs are immutable once written, so this is safe to run
+    /// concurrently with normal operation.
+    pub async fn reencrypt(&self, key: &str) -> Result<bool, ExternalError> {
+        let Some(value) = self.blob.get(key).await? else {
+            return Ok(false);
+        };
+        let value = value.into_contiguous();
+        self.keys.refresh_active().await;
+        self.keys.load_for(&value).await;
+        let current = encryption_key_id(&value).map_err(determinate)?;
+        if current.as_deref() == Some(self.keys.active_id().as_str()) {
+            return Ok(false);
+        }
+        let plaintext = self
+            .keys
+            .decrypt_or_passthrough(key, value)
+            .map_err(determinate)?;
+        let ciphertext = self.keys.encrypt(key, &plaintext)?;
+        self.blob.set(key, Bytes::from(ciphertext)).await?;
+        Ok(true)
+    }
+}
+
+fn determinate(err: anyhow::Error) -> ExternalError {
+    ExternalError::Determinate(Determinate::new(err))
+}
+
+#[async_trait]
+impl Blob for EncryptedBlob {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let Some(value) = self.blob.get(key).await? else {
+            return Ok(None);
+        };
+        let value = value.into_contiguous();
+        self.keys.load_for(&value).await;
+        let plaintext = self.keys.decrypt(key, value).map_err(determinate)?;
+        Ok(Some(SegmentedBytes::from(plaintext)))
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        self.keys.refresh_active().await;
+        let ciphertext = self.keys.encrypt(key, &value)?;
+        self.blob.set(key, Bytes::from(ciphertext)).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        self.blob.delete(key).await
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        self.blob.restore(key).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_dyncfg::{ConfigSet, ConfigUpdates};
+    use mz_ore::cast::CastFrom;
+
+    use crate::file::{FileBlob, FileBlobConfig};
+    use crate::mem::{MemBlob, MemBlobConfig};
+
+    use super::*;
+
+    fn keys(id: &str) -> BlobEncryptionKeys {
+        BlobEncryptionKeys::new(BlobEncryptionKey::generate(id).expect("valid key"))
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    async fn encrypted_blob() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(crate::error::Error::from)?;
+        let file: Arc<dyn Blob> =
+            Arc::new(FileBlob::open(FileBlobConfig::from(temp_dir.path())).await?);
+        let mem: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+
+        for inner in [file, mem] {
+            let blob = EncryptedBlob::new(Arc::clone(&inner), keys("k0"));
+            let value = Bytes::from(vec![42u8; 1024]);
+
+            assert_eq!(blob.get("k").await?, None);
+            blob.set("k", value.clone()).await?;
+            assert_eq!(
+                blob.get("k").await?.map(|x| x.into_contiguous()),
+                Some(value.to_vec())
+            );
+            blob.set("empty", Bytes::new()).await?;
+            assert_eq!(
+                blob.get("empty").await?.map(|x| x.into_contiguous()),
+                Some(vec![])
+            );
+
+            // Listing and deletion report the size of the stored ciphertext.
+            let mut sizes = BTreeMap::new();
+            blob.list_keys_and_metadata("", &mut |x| {
+                sizes.insert(x.key.to_owned(), x.size_in_bytes);
+            })
+            .await?;
+            let stored = inner.get("k").await?.expect("present").len();
+            assert!(stored > value.len());
+            assert_eq!(sizes.get("k"), Some(&u64::cast_from(stored)));
+            assert_eq!(blob.delete("k").await?, Some(stored));
+            assert_eq!(blob.get("k").await?, None);
+        }
+
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    async fn encrypted_blob_envelope() -> Result<(), ExternalError> {
+        let inner: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let keys = keys("k0");
+        let blob = EncryptedBlob::new(Arc::clone(&inner), keys.clone());
+
+        // Values are encrypted at rest and bound to their key.
+        blob.set("a", Bytes::from_static(b"hello")).await?;
+        let raw = inner.get("a").await?.expect("present").into_contiguous();
+        assert!(raw.starts_with(MAGIC));
+        assert!(!raw.windows(5).any(|x| x == b"hello"));
+        assert_eq!(encryption_key_id(&raw).expect("valid"), Some("k0".into()));
+        inner.set("b", Bytes::from(raw)).await?;
+        assert!(blob.get("b").await.is_err());
+
+        // Unencrypted values pass through and can be encrypted in place.
+        inner.set("c", Bytes::from_static(b"plain")).await?;
+        assert_eq!(
+            blob.get("c").await?.map(|x| x.into_contiguous()),
+            Some(b"plain".to_vec())
+        );
+        assert!(blob.reencrypt("c").await?);
+        assert!(!blob.reencrypt("c").await?);
+        assert!(!blob.reencrypt("missing").await?);
+        let raw = inner.get("c").await?.expect("present").into_contiguous();
+        assert_eq!(encryption_key_id(&raw).expect("valid"), Some("k0".into()));
+
+        // Rotation: old values stay readable, new writes use the new key, and
+        // reencryption moves old values over.
+        keys.rotate(BlobEncryptionKey::generate("k1").expect("valid key"))
+            .expect("new key");
+        blob.set("d", Bytes::from_static(b"rotated")).await?;
+        let raw = inner.get("d").await?.expect("present").into_contiguous();
+        assert_eq!(encryption_key_id(&raw).expect("valid"), Some("k1".into()));
+        assert_eq!(
+            blob.get("a").await?.map(|x| x.into_contiguous()),
+            Some(b"hello".to_vec())
+        );
+        assert!(keys.remove("k1").is_err());
+        assert!(blob.reencrypt("a").await?);
+        keys.remove("k0").expect("retired key");
+        assert_eq!(
+            blob.get("a").await?.map(|x| x.into_contiguous()),
+            Some(b"hello".to_vec())
+        );
+
+        // A blob wrapped by a key that's no longer in the ring is an error.
+        let other = EncryptedBlob::new(Arc::clone(&inner), self::keys("k2"));
+        assert!(other.get("a").await.is_err());
+
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    async fn encrypted_blob_online_rotation() -> Result<(), ExternalError> {
+        let inner: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let secrets = Arc::new(std::sync::Mutex::new(BTreeMap::new()));
+        let source = {
+            let secrets = Arc::clone(&secrets);
+            BlobEncryptionKeySource::new(move |id| {
+                let material = secrets.lock().expect("lock poisoned").get(&id).cloned();
+                async move { material.ok_or_else(|| anyhow!("no secret {}", id)) }
+            })
+        };
+        secrets
+            .lock()
+            .expect("lock poisoned")
+            .insert("k0".to_owned(), vec![0u8; KEY_LEN]);
+        let key0 = BlobEncryptionKey::parse("k0", &[0u8; KEY_LEN]).expect("valid key");
+
+        // Two processes, each with their own copy of the configs and keys.
+        let process = || {
+            let configs = ConfigSet::default().add(&BLOB_ENCRYPTION_ACTIVE_KEY);
+            let keys = BlobEncryptionKeys::new(key0.clone())
+                .with_source(source.clone())
+                .with_active_key_config(BLOB_ENCRYPTION_ACTIVE_KEY.handle(&configs));
+            (configs, EncryptedBlob::new(Arc::clone(&inner), keys))
+        };
+        let (configs_a, blob_a) = process();
+        let (configs_b, blob_b) = process();
+        let set_active = |configs: &ConfigSet, id: &str| {
+            let mut updates = ConfigUpdates::default();
+            updates.add(&BLOB_ENCRYPTION_ACTIVE_KEY, id.to_owned());
+            updates.apply(configs);
+        };
+
+        // An active key that can't be loaded leaves the active key unchanged.
+        set_active(&configs_a, "k1");
+        blob_a.set("a", Bytes::from_static(b"hello")).await?;
+        let raw = inner.get("a").await?.expect("present").into_contiguous();
+        assert_eq!(encryption_key_id(&raw).expect("valid"), Some("k0".into()));
+
+        // Once the key exists, the next write rotates to it, and readers
+        // load it on demand.
+        secrets
+            .lock()
+            .expect("lock poisoned")
+            .insert("k1".to_owned(), vec![1u8; KEY_LEN]);
+        blob_a.set("b", Bytes::from_static(b"rotated")).await?;
+        let raw = inner.get("b").await?.expect("present").into_contiguous();
+        assert_eq!(encryption_key_id(&raw).expect("valid"), Some("k1".into()));
+        assert!(!blob_b.keys.contains("k1"));
+        assert_eq!(
+            blob_b.get("b").await?.map(|x| x.into_contiguous()),
+            Some(b"rotated".to_vec())
+        );
+        assert_eq!(blob_b.keys.active_id(), "k0");
+
+        // Reencryption moves old values over to the new active key.
+        set_active(&configs_b, "k1");
+        assert!(blob_b.reencrypt("a").await?);
+        let raw = inner.get("a").await?.expect("present").into_contiguous();
+        assert_eq!(encryption_key_id(&raw).expect("valid"), Some("k1".into()));
+
+        // Keys that are in neither the ring nor the source are an error.
+        inner.set("c", Bytes::from(raw)).await?;
+        let other = EncryptedBlob::new(Arc::clone(&inner), self::keys("k2"));
+        assert!(other.get("c").await.is_err());
+
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    async fn encrypted_blob_strict() -> Result<(), ExternalError> {
+        let inner: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let keys = keys("k0").with_strict(true);
+        let blob = EncryptedBlob::new(Arc::clone(&inner), keys);
+
+        blob.set("a", Bytes::from_static(b"hello")).await?;
+        assert_eq!(
+            blob.get("a").await?.map(|x| x.into_contiguous()),
+            Some(b"hello".to_vec())
+        );
+
+        // Unencrypted values are rejected, but can still be encrypted in
+        // place.
+        inner.set("b", Bytes::from_static(b"plain")).await?;
+        assert!(blob.get("b").await.is_err());
+        assert!(blob.reencrypt("b").await?);
+        assert_eq!(
+            blob.get("b").await?.map(|x| x.into_contiguous()),
+            Some(b"plain".to_vec())
+        );
+
+        Ok(())
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    fn blob_encryption_key_parse() {
+        let hex = "00112233445566778899aabbccddeeff00112233445566778899aabbccddeeff\n";
+        let key = BlobEncryptionKey::parse("k", hex.as_bytes()).expect("valid hex");
+        assert_eq!(key.key[1], 0x11);
+        assert!(BlobEncryptionKey::parse("k", &[7u8; 32]).is_ok());
+        assert!(BlobEncryptionKey::parse("k", b"too short").is_err());
+        assert!(BlobEncryptionKey::parse("", &[7u8; 32]).is_err());
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("k0"), [1u8; 32]).expect("write");
+        std::fs::write(dir.path().join("k1"), hex).expect("write");
+        std::fs::write(dir.path().join(".hidden"), b"junk").expect("write");
+        let keys = BlobEncryptionKeys::load_dir(dir.path(), "k1").expect("valid dir");
+        assert_eq!(keys.active_id(), "k1");
+        assert!(keys.get("k0").is_some());
+        assert!(BlobEncryptionKeys::load_dir(dir.path(), "k2").is_err());
+    }
+}
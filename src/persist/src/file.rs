@@ -7,7 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-//! File backed implementations for testing and benchmarking.
+//! File backed implementations for testing, benchmarking, and single-node
+//! deployments.
 
 use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
@@ -22,7 +23,10 @@ use tokio::fs::{self, File};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 use crate::error::Error;
-use crate::location::{Blob, BlobMetadata, Determinate, ExternalError};
+use crate::location::{
+    Blob, BlobMetadata, CaSResult, Consensus, Determinate, ExternalError, ResultStream, SeqNo,
+    VersionedData,
+};
 
 /// Configuration for opening a [FileBlob].
 #[derive(Debug, Clone)]
@@ -74,7 +78,7 @@ impl FileBlob {
     ///
     /// (And apologies to the callers who really did want to use U+2215 code points in their
     /// filenames.)
-    fn replace_forward_slashes(key: &str) -> String {
+    pub fn replace_forward_slashes(key: &str) -> String {
         key.replace('/', "∕")
     }
 
@@ -269,9 +273,327 @@ impl Blob for FileBlob {
     }
 }
 
+/// Configuration for opening a [FileConsensus].
+#[derive(Debug, Clone)]
+pub struct FileConsensusConfig {
+    base_dir: PathBuf,
+}
+
+impl<P: AsRef<Path>> From<P> for FileConsensusConfig {
+    fn from(base_dir: P) -> Self {
+        FileConsensusConfig {
+            base_dir: base_dir.as_ref().to_path_buf(),
+        }
+    }
+}
+
+/// Implementation of [Consensus] backed by files in a local directory.
+///
+/// This is intended for single-node deployments that don't want to depend on
+/// an external Postgres or CockroachDB. It may be shared by any number of
+/// processes on the same machine, but not across machines.
+///
+/// Each key is a directory, named by the hex encoding of the key, holding one
+/// file per version, named by its zero-padded sequence number. New versions are
+/// written to a temporary file, synced, and renamed into place, so a crash can
+/// never leave a partially written version behind (a temporary file left over
+/// from a crashed write is removed by the next truncate). Writes to a key (both
+/// compare_and_set and truncate) are serialized with an advisory lock on a
+/// `LOCK` file in the key's directory, which the OS releases if the holder
+/// crashes. Reads take no locks.
+#[derive(Debug)]
+pub struct FileConsensus {
+    base_dir: PathBuf,
+}
+
+const FILE_CONSENSUS_LOCK: &str = "LOCK";
+
+impl FileConsensus {
+    /// Opens the given location for non-exclusive read-write access.
+    pub async fn open(config: FileConsensusConfig) -> Result<Self, ExternalError> {
+        let FileConsensusConfig { base_dir } = config;
+        fs::create_dir_all(&base_dir).await.map_err(Error::from)?;
+        Ok(FileConsensus { base_dir })
+    }
+
+    fn key_dir(&self, key: &str) -> PathBuf {
+        let mut name = String::with_capacity(key.len() * 2);
+        for b in key.as_bytes() {
+            name.push_str(&format!("{:02x}", b));
+        }
+        self.base_dir.join(name)
+    }
+
+    fn decode_key(name: &str) -> Option<String> {
+        if name.len() % 2 != 0 || !name.is_ascii() {
+            return None;
+        }
+        let bytes = (0..name.len())
+            .step_by(2)
+            .map(|idx| u8::from_str_radix(&name[idx..idx + 2], 16).ok())
+            .collect::<Option<Vec<_>>>()?;
+        String::from_utf8(bytes).ok()
+    }
+
+    fn version_path(key_dir: &Path, seqno: SeqNo) -> PathBuf {
+        key_dir.join(format!("{:020}", seqno.0))
+    }
+
+    /// Returns the sequence numbers of all versions of the key, in ascending
+    /// order.
+    fn seqnos(key_dir: &Path) -> Result<Vec<SeqNo>, std::io::Error> {
+        let entries = match std::fs::read_dir(key_dir) {
+            Ok(x) => x,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err),
+        };
+        let mut seqnos = vec![];
+        for entry in entries {
+            let entry = entry?;
+            // Skip the lock file and in-progress writes.
+            if let Some(seqno) = entry.file_name().to_str().and_then(|x| x.parse().ok()) {
+                seqnos.push(SeqNo(seqno));
+            }
+        }
+        seqnos.sort();
+        Ok(seqnos)
+    }
+
+    /// Reads the given version, returning None if it was concurrently
+    /// truncated.
+    fn read_version(key_dir: &Path, seqno: SeqNo) -> Result<Option<VersionedData>, std::io::Error> {
+        match std::fs::read(Self::version_path(key_dir, seqno)) {
+            Ok(data) => Ok(Some(VersionedData {
+                seqno,
+                data: Bytes::from(data),
+            })),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn head_sync(key_dir: &Path) -> Result<Option<VersionedData>, std::io::Error> {
+        loop {
+            let Some(seqno) = Self::seqnos(key_dir)?.pop() else {
+                return Ok(None);
+            };
+            // The head is never truncated, but it may have been superseded (and
+            // the old head truncated) between listing and reading. In that
+            // case, try again.
+            if let Some(data) = Self::read_version(key_dir, seqno)? {
+                return Ok(Some(data));
+            }
+        }
+    }
+
+    fn scan_sync(
+        key_dir: &Path,
+        from: SeqNo,
+        limit: usize,
+    ) -> Result<Vec<VersionedData>, std::io::Error> {
+        'retry: loop {
+            let mut versions = vec![];
+            for seqno in Self::seqnos(key_dir)? {
+                if seqno < from {
+                    continue;
+                }
+                if versions.len() >= limit {
+                    break;
+                }
+                // A version that disappeared between listing and reading was
+                // truncated out from under us, which means every version before
+                // it is gone too. Start over so that we return a contiguous run
+                // of versions, as if we had listed after the truncation.
+                match Self::read_version(key_dir, seqno)? {
+                    Some(data) => versions.push(data),
+                    None => continue 'retry,
+                }
+            }
+            return Ok(versions);
+        }
+    }
+
+    fn compare_and_set_sync(
+        key_dir: &Path,
+        expected: Option<SeqNo>,
+        new: VersionedData,
+    ) -> Result<CaSResult, ExternalError> {
+        if !key_dir.exists() {
+            std::fs::create_dir_all(key_dir)?;
+            // fsync the parent so the new key directory (and so every version
+            // we write into it) survives a crash.
+            if let Some(parent) = key_dir.parent() {
+                std::fs::File::open(parent)?.sync_all()?;
+            }
+        }
+        let _lock = FileLock::lock(&key_dir.join(FILE_CONSENSUS_LOCK))?;
+
+        let current = Self::seqnos(key_dir)?.last().copied();
+        if current != expected {
+            return Ok(CaSResult::ExpectationMismatch);
+        }
+
+        let path = Self::version_path(key_dir, new.seqno);
+        let mut tmp_path = path.clone();
+        tmp_path.set_extension("tmp");
+        let mut file = std::fs::File::create(&tmp_path)?;
+        std::io::Write::write_all(&mut file, &new.data)?;
+        file.sync_all()?;
+        std::fs::rename(&tmp_path, &path)?;
+        // fsync the directory to make the rename durable.
+        std::fs::File::open(key_dir)?.sync_all()?;
+        Ok(CaSResult::Committed)
+    }
+
+    fn truncate_sync(key_dir: &Path, seqno: SeqNo) -> Result<usize, ExternalError> {
+        if !key_dir.exists() {
+            return Err(ExternalError::from(anyhow!(
+                "upper bound too high for truncate: {:?}",
+                seqno
+            )));
+        }
+        let _lock = FileLock::lock(&key_dir.join(FILE_CONSENSUS_LOCK))?;
+
+        let seqnos = Self::seqnos(key_dir)?;
+        if seqnos.last().map_or(true, |head| *head < seqno) {
+            return Err(ExternalError::from(anyhow!(
+                "upper bound too high for truncate: {:?}",
+                seqno
+            )));
+        }
+        let mut deleted = 0;
+        for old in seqnos.into_iter().take_while(|x| *x < seqno) {
+            std::fs::remove_file(Self::version_path(key_dir, old))?;
+            deleted += 1;
+        }
+        // Writers hold the lock for as long as their temporary file exists, so
+        // any we find now were left behind by a writer that crashed.
+        for entry in std::fs::read_dir(key_dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|x| x == "tmp") {
+                std::fs::remove_file(path)?;
+            }
+        }
+        std::fs::File::open(key_dir)?.sync_all()?;
+        Ok(deleted)
+    }
+}
+
+/// An exclusive advisory lock on a file, held until dropped.
+///
+/// The lock is released by the OS when the file is closed, including when the
+/// process crashes.
+#[derive(Debug)]
+struct FileLock(std::fs::File);
+
+impl FileLock {
+    fn lock(path: &Path) -> Result<Self, std::io::Error> {
+        use std::os::fd::AsRawFd;
+
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(path)?;
+        loop {
+            // SAFETY: The file descriptor is valid for the lifetime of `file`.
+            let ret = unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) };
+            if ret == 0 {
+                return Ok(FileLock(file));
+            }
+            let err = std::io::Error::last_os_error();
+            if err.kind() != ErrorKind::Interrupted {
+                return Err(err);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Consensus for FileConsensus {
+    fn list_keys(&self) -> ResultStream<String> {
+        let base_dir = self.base_dir.clone();
+        Box::pin(async_stream::try_stream! {
+            let mut entries = fs::read_dir(&base_dir).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                let name = entry.file_name();
+                if let Some(key) = name.to_str().and_then(FileConsensus::decode_key) {
+                    yield key;
+                }
+            }
+        })
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<VersionedData>, ExternalError> {
+        let key_dir = self.key_dir(key);
+        let head = mz_ore::task::spawn_blocking(
+            || "persist::file::head",
+            move || Self::head_sync(&key_dir),
+        )
+        .await??;
+        Ok(head)
+    }
+
+    async fn compare_and_set(
+        &self,
+        key: &str,
+        expected: Option<SeqNo>,
+        new: VersionedData,
+    ) -> Result<CaSResult, ExternalError> {
+        if let Some(expected) = expected {
+            if new.seqno <= expected {
+                return Err(ExternalError::from(anyhow!(
+                    "new seqno must be strictly greater than expected. Got new: {:?} expected: {:?}",
+                    new.seqno,
+                    expected
+                )));
+            }
+        }
+        if new.seqno.0 > i64::MAX.try_into().expect("i64::MAX known to fit in u64") {
+            return Err(ExternalError::from(anyhow!(
+                "sequence numbers must fit within [0, i64::MAX], received: {:?}",
+                new.seqno
+            )));
+        }
+
+        let key_dir = self.key_dir(key);
+        mz_ore::task::spawn_blocking(
+            || "persist::file::compare_and_set",
+            move || Self::compare_and_set_sync(&key_dir, expected, new),
+        )
+        .await?
+    }
+
+    async fn scan(
+        &self,
+        key: &str,
+        from: SeqNo,
+        limit: usize,
+    ) -> Result<Vec<VersionedData>, ExternalError> {
+        let key_dir = self.key_dir(key);
+        let versions = mz_ore::task::spawn_blocking(
+            || "persist::file::scan",
+            move || Self::scan_sync(&key_dir, from, limit),
+        )
+        .await??;
+        Ok(versions)
+    }
+
+    async fn truncate(&self, key: &str, seqno: SeqNo) -> Result<usize, ExternalError> {
+        let key_dir = self.key_dir(key);
+        mz_ore::task::spawn_blocking(
+            || "persist::file::truncate",
+            move || Self::truncate_sync(&key_dir, seqno),
+        )
+        .await?
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::location::tests::blob_impl_test;
+    use futures_util::TryStreamExt;
+
+    use crate::location::tests::{blob_impl_test, consensus_impl_test};
 
     use super::*;
 
@@ -296,4 +618,84 @@ mod tests {
 
         Ok(())
     }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `flock` on OS `linux`
+    async fn file_consensus() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let path = temp_dir.path().to_owned();
+        consensus_impl_test(|| FileConsensus::open(FileConsensusConfig::from(&path))).await?;
+
+        // Versions survive reopening, and the directory can be shared by
+        // multiple instances.
+        let c0 = FileConsensus::open(FileConsensusConfig::from(&path)).await?;
+        let c1 = FileConsensus::open(FileConsensusConfig::from(&path)).await?;
+        let v1 = VersionedData {
+            seqno: SeqNo(1),
+            data: Bytes::from("v1"),
+        };
+        assert_eq!(
+            c0.compare_and_set("k/0", None, v1.clone()).await?,
+            CaSResult::Committed
+        );
+        assert_eq!(
+            c1.compare_and_set("k/0", None, v1.clone()).await?,
+            CaSResult::ExpectationMismatch
+        );
+        drop(c0);
+        let c0 = FileConsensus::open(FileConsensusConfig::from(&path)).await?;
+        assert_eq!(c0.head("k/0").await?, Some(v1));
+        let keys: Vec<_> = c1.list_keys().try_collect().await?;
+        assert!(keys.contains(&"k/0".to_owned()));
+
+        // Temporary files left behind by crashed writers are cleaned up by
+        // truncate.
+        let key_dir = c0.key_dir("k/0");
+        let stray = FileConsensus::version_path(&key_dir, SeqNo(2)).with_extension("tmp");
+        std::fs::write(&stray, "v2").map_err(Error::from)?;
+        assert_eq!(c0.head("k/0").await?.map(|x| x.seqno), Some(SeqNo(1)));
+        assert_eq!(c0.truncate("k/0", SeqNo(1)).await?, 0);
+        assert!(!stray.exists());
+
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `flock` on OS `linux`
+    async fn file_consensus_scan_concurrent_truncate() -> Result<(), ExternalError> {
+        const KEY: &str = "k";
+        const VERSIONS: u64 = 200;
+
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let writer = FileConsensus::open(FileConsensusConfig::from(temp_dir.path())).await?;
+        let reader = FileConsensus::open(FileConsensusConfig::from(temp_dir.path())).await?;
+
+        let write = mz_ore::task::spawn(|| "writer", async move {
+            for seqno in 0..VERSIONS {
+                let expected = seqno.checked_sub(1).map(SeqNo);
+                let new = VersionedData {
+                    seqno: SeqNo(seqno),
+                    data: Bytes::from(seqno.to_string()),
+                };
+                let res = writer.compare_and_set(KEY, expected, new).await?;
+                assert_eq!(res, CaSResult::Committed);
+                writer.truncate(KEY, SeqNo(seqno)).await?;
+            }
+            Ok::<_, ExternalError>(())
+        });
+
+        while !write.is_finished() {
+            let versions = reader.scan(KEY, SeqNo::minimum(), usize::MAX).await?;
+            for (prev, next) in versions.iter().zip(versions.iter().skip(1)) {
+                assert_eq!(prev.seqno.next(), next.seqno);
+            }
+        }
+        write.await??;
+
+        let versions = reader.scan(KEY, SeqNo::minimum(), usize::MAX).await?;
+        let seqnos: Vec<_> = versions.into_iter().map(|x| x.seqno).collect();
+        assert_eq!(seqnos, vec![SeqNo(VERSIONS - 1)]);
+
+        Ok(())
+    }
 }
@@ -19,6 +19,7 @@
 
 pub mod azure;
 pub mod cfg;
+pub mod encrypt;
 pub mod error;
 pub mod file;
 pub mod generated;
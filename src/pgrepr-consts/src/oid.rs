@@ -72,6 +72,10 @@ pub const TYPE_TIMESTAMP_ARRAY_OID: u32 = 1115;
 pub const TYPE_TIMESTAMP_OID: u32 = 1114;
 pub const TYPE_TIMESTAMPTZ_ARRAY_OID: u32 = 1185;
 pub const TYPE_TIMESTAMPTZ_OID: u32 = 1184;
+pub const TYPE_TSQUERY_ARRAY_OID: u32 = 3645;
+pub const TYPE_TSQUERY_OID: u32 = 3615;
+pub const TYPE_TSVECTOR_ARRAY_OID: u32 = 3643;
+pub const TYPE_TSVECTOR_OID: u32 = 3614;
 pub const TYPE_UUID_ARRAY_OID: u32 = 2951;
 pub const TYPE_UUID_OID: u32 = 2950;
 pub const TYPE_VARCHAR_ARRAY_OID: u32 = 1015;
@@ -80,6 +84,7 @@ pub const TYPE_INT4RANGE_OID: u32 = 3904;
 pub const TYPE_INT4RANGE_ARRAY_OID: u32 = 3905;
 pub const TYPE_ANYRANGE_OID: u32 = 3831;
 pub const TYPE_ANYCOMPATIBLERANGE_OID: u32 = 5080;
+pub const TYPE_ANYENUM_OID: u32 = 3500;
 pub const TYPE_INT8RANGE_OID: u32 = 3926;
 pub const TYPE_INT8RANGE_ARRAY_OID: u32 = 3927;
 pub const TYPE_DATERANGE_OID: u32 = 3912;
@@ -777,3 +782,49 @@ pub const VIEW_MZ_WALLCLOCK_GLOBAL_LAG_OID: u32 = 17054;
 pub const SOURCE_MZ_WALLCLOCK_GLOBAL_LAG_HISTOGRAM_RAW_OID: u32 = 17055;
 pub const VIEW_MZ_WALLCLOCK_GLOBAL_LAG_HISTOGRAM_OID: u32 = 17056;
 pub const TABLE_MZ_SQL_SERVER_SOURCE_TABLES_OID: u32 = 17057;
+pub const TYPE_VECTOR_OID: u32 = 17058;
+pub const TYPE_VECTOR_ARRAY_OID: u32 = 17059;
+pub const FUNC_VECTOR_DIMS_OID: u32 = 17060;
+pub const FUNC_VECTOR_NORM_OID: u32 = 17061;
+pub const FUNC_L2_DISTANCE_OID: u32 = 17062;
+pub const FUNC_COSINE_DISTANCE_OID: u32 = 17063;
+pub const FUNC_INNER_PRODUCT_OID: u32 = 17064;
+pub const OP_L2_DISTANCE_VECTOR_OID: u32 = 17065;
+pub const OP_COSINE_DISTANCE_VECTOR_OID: u32 = 17066;
+pub const OP_NEGATIVE_INNER_PRODUCT_VECTOR_OID: u32 = 17067;
+pub const TYPE_GEOMETRY_OID: u32 = 17068;
+pub const TYPE_GEOMETRY_ARRAY_OID: u32 = 17069;
+pub const FUNC_ST_ASTEXT_OID: u32 = 17070;
+pub const FUNC_ST_ASEWKT_OID: u32 = 17071;
+pub const FUNC_ST_ASBINARY_OID: u32 = 17072;
+pub const FUNC_ST_GEOMFROMTEXT_OID: u32 = 17073;
+pub const FUNC_ST_GEOMFROMTEXT_SRID_OID: u32 = 17074;
+pub const FUNC_ST_GEOMFROMWKB_OID: u32 = 17075;
+pub const FUNC_ST_GEOMFROMWKB_SRID_OID: u32 = 17076;
+pub const FUNC_ST_MAKEPOINT_OID: u32 = 17077;
+pub const FUNC_ST_POINT_OID: u32 = 17078;
+pub const FUNC_ST_MAKEENVELOPE_OID: u32 = 17079;
+pub const FUNC_ST_MAKEENVELOPE_SRID_OID: u32 = 17080;
+pub const FUNC_ST_MAKELINE_OID: u32 = 17081;
+pub const FUNC_ST_MAKEPOLYGON_OID: u32 = 17082;
+pub const FUNC_ST_X_OID: u32 = 17083;
+pub const FUNC_ST_Y_OID: u32 = 17084;
+pub const FUNC_ST_SRID_OID: u32 = 17085;
+pub const FUNC_ST_SETSRID_OID: u32 = 17086;
+pub const FUNC_ST_GEOMETRYTYPE_OID: u32 = 17087;
+pub const FUNC_ST_CONTAINS_OID: u32 = 17088;
+pub const FUNC_ST_WITHIN_OID: u32 = 17089;
+pub const FUNC_ST_INTERSECTS_OID: u32 = 17090;
+pub const FUNC_ST_DISTANCE_OID: u32 = 17091;
+pub const FUNC_ST_DWITHIN_OID: u32 = 17092;
+pub const FUNC_ST_GEOHASH_OID: u32 = 17093;
+pub const FUNC_ST_GEOHASH_PRECISION_OID: u32 = 17094;
+pub const FUNC_ST_GEOHASH_CELLS_OID: u32 = 17095;
+pub const TABLE_MZ_INDEX_SUGGESTIONS_OID: u32 = 17096;
+pub const LOG_MZ_ARRANGEMENT_SPILLED_SIZE_RAW_OID: u32 = 17097;
+pub const TABLE_MZ_PUBLICATIONS_OID: u32 = 17098;
+pub const TABLE_MZ_PUBLICATION_TABLES_OID: u32 = 17099;
+pub const VIEW_MZ_SHOW_PUBLICATIONS_OID: u32 = 17100;
+pub const TABLE_MZ_NOTIFICATIONS_OID: u32 = 17101;
+pub const VIEW_MZ_SHOW_NOTIFICATIONS_OID: u32 = 17102;
+pub const TABLE_MZ_REPLICATION_SLOTS_OID: u32 = 17103;
@@ -22,6 +22,7 @@ use mz_repr::adt::timestamp::{
     InvalidTimestampPrecisionError, TimestampPrecision as AdtTimestampPrecision,
 };
 use mz_repr::adt::varchar::{InvalidVarCharMaxLengthError, VarCharMaxLength};
+use mz_repr::adt::vector::{InvalidVectorDimensionsError, VectorDimensions as AdtVectorDimensions};
 use mz_repr::namespaces::MZ_CATALOG_SCHEMA;
 
 use crate::oid;
@@ -163,6 +164,17 @@ pub enum Type {
     /// A list of privileges granted to a user that uses [`mz_repr::adt::system::Oid`]s for role
     /// references. This type is used primarily for compatibility with PostgreSQL.
     AclItem,
+    /// A full text search document.
+    TsVector,
+    /// A full text search query.
+    TsQuery,
+    /// A vector of single-precision floats.
+    Vector {
+        /// The number of dimensions of the vector.
+        dimensions: Option<VectorDimensions>,
+    },
+    /// A planar geometry.
+    Geometry,
 }
 
 /// An unpacked [`typmod`](Type::typmod) for a [`Type`].
@@ -226,6 +238,46 @@ impl fmt::Display for CharLength {
     }
 }
 
+/// The number of dimensions associated with [`Type::Vector`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct VectorDimensions(i32);
+
+impl VectorDimensions {
+    /// Consumes the newtype wrapper, returning the contents as an `i32`.
+    pub fn into_i32(self) -> i32 {
+        self.0
+    }
+}
+
+impl TypeConstraint for VectorDimensions {
+    fn from_typmod(typmod: i32) -> Result<Option<VectorDimensions>, String> {
+        // https://github.com/pgvector/pgvector/blob/v0.8.0/src/vector.c#L98-L111
+        if typmod >= 1 {
+            Ok(Some(VectorDimensions(typmod)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn into_typmod(&self) -> i32 {
+        self.0
+    }
+}
+
+impl From<AdtVectorDimensions> for VectorDimensions {
+    fn from(dimensions: AdtVectorDimensions) -> VectorDimensions {
+        // The `AdtVectorDimensions` newtype wrapper ensures that the inner
+        // `u32` is small enough to fit into an `i32`.
+        VectorDimensions(i32::try_from(dimensions.into_u32()).unwrap())
+    }
+}
+
+impl fmt::Display for VectorDimensions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({})", self.0)
+    }
+}
+
 /// Constraints associated with [`Type::Interval`]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct IntervalConstraints {
@@ -528,6 +580,46 @@ pub static MZ_ACL_ITEM_ARRAY: LazyLock<postgres_types::Type> = LazyLock::new(||
     )
 });
 
+/// An anonymous [`Type::Vector`], akin to [`postgres_types::Type::FLOAT4_ARRAY`].
+pub static VECTOR: LazyLock<postgres_types::Type> = LazyLock::new(|| {
+    postgres_types::Type::new(
+        "vector".to_owned(),
+        oid::TYPE_VECTOR_OID,
+        postgres_types::Kind::Pseudo,
+        MZ_CATALOG_SCHEMA.to_owned(),
+    )
+});
+
+/// An anonymous [`Type::Array`] of [`Type::Vector`].
+pub static VECTOR_ARRAY: LazyLock<postgres_types::Type> = LazyLock::new(|| {
+    postgres_types::Type::new(
+        "_vector".to_owned(),
+        oid::TYPE_VECTOR_ARRAY_OID,
+        postgres_types::Kind::Pseudo,
+        MZ_CATALOG_SCHEMA.to_owned(),
+    )
+});
+
+/// An anonymous [`Type::Geometry`], akin to [`postgres_types::Type::BYTEA`].
+pub static GEOMETRY: LazyLock<postgres_types::Type> = LazyLock::new(|| {
+    postgres_types::Type::new(
+        "geometry".to_owned(),
+        oid::TYPE_GEOMETRY_OID,
+        postgres_types::Kind::Pseudo,
+        MZ_CATALOG_SCHEMA.to_owned(),
+    )
+});
+
+/// An anonymous [`Type::Array`] of [`Type::Geometry`].
+pub static GEOMETRY_ARRAY: LazyLock<postgres_types::Type> = LazyLock::new(|| {
+    postgres_types::Type::new(
+        "_geometry".to_owned(),
+        oid::TYPE_GEOMETRY_ARRAY_OID,
+        postgres_types::Kind::Pseudo,
+        MZ_CATALOG_SCHEMA.to_owned(),
+    )
+});
+
 impl Type {
     /// Returns the type corresponding to the provided OID, if the OID is known.
     pub fn from_oid(oid: u32) -> Result<Type, TypeFromOidError> {
@@ -614,6 +706,10 @@ impl Type {
             postgres_types::Type::REGTYPE_ARRAY => Type::Array(Box::new(Type::RegType)),
             postgres_types::Type::INT2_VECTOR => Type::Int2Vector,
             postgres_types::Type::INT2_VECTOR_ARRAY => Type::Array(Box::new(Type::Int2Vector)),
+            postgres_types::Type::TS_VECTOR => Type::TsVector,
+            postgres_types::Type::TS_VECTOR_ARRAY => Type::Array(Box::new(Type::TsVector)),
+            postgres_types::Type::TSQUERY => Type::TsQuery,
+            postgres_types::Type::TSQUERY_ARRAY => Type::Array(Box::new(Type::TsQuery)),
             postgres_types::Type::INT4_RANGE => Type::Range {
                 element_type: Box::new(Type::Int4),
             },
@@ -680,6 +776,9 @@ impl Type {
                         *precision = TimestampPrecision::from_typmod(typmod)?
                     }
                     Type::VarChar { max_length } => *max_length = CharLength::from_typmod(typmod)?,
+                    Type::Vector { dimensions } => {
+                        *dimensions = VectorDimensions::from_typmod(typmod)?
+                    }
                     _ if typmod != -1 => return Err("type does not support type modifiers".into()),
                     _ => (),
                 }
@@ -746,6 +845,10 @@ impl Type {
                     _ => unreachable!(),
                 },
                 Type::MzAclItem => &MZ_ACL_ITEM_ARRAY,
+                Type::TsVector => &postgres_types::Type::TS_VECTOR_ARRAY,
+                Type::TsQuery => &postgres_types::Type::TSQUERY_ARRAY,
+                Type::Vector { .. } => &VECTOR_ARRAY,
+                Type::Geometry => &GEOMETRY_ARRAY,
             },
             Type::Bool => &postgres_types::Type::BOOL,
             Type::Bytea => &postgres_types::Type::BYTEA,
@@ -791,6 +894,10 @@ impl Type {
                 t => unreachable!("{t:?} is not a range element type"),
             },
             Type::MzAclItem => &MZ_ACL_ITEM,
+            Type::TsVector => &postgres_types::Type::TS_VECTOR,
+            Type::TsQuery => &postgres_types::Type::TSQUERY,
+            Type::Vector { .. } => &VECTOR,
+            Type::Geometry => &GEOMETRY,
         }
     }
 
@@ -846,6 +953,8 @@ impl Type {
                 oid::TYPE_UINT8_ARRAY_OID => "uint8[]",
                 oid::TYPE_MZ_TIMESTAMP_ARRAY_OID => "mz_timestamp[]",
                 oid::TYPE_MZ_ACL_ITEM_ARRAY_OID => "mz_aclitem[]",
+                oid::TYPE_VECTOR_ARRAY_OID => "vector[]",
+                oid::TYPE_GEOMETRY_ARRAY_OID => "geometry[]",
                 _ => other.name(),
             },
         }
@@ -885,6 +994,9 @@ impl Type {
             Type::TimestampTz {
                 precision: Some(precision),
             } => Some(precision),
+            Type::Vector {
+                dimensions: Some(dimensions),
+            } => Some(dimensions),
             Type::AclItem
             | Type::Array(_)
             | Type::Bool
@@ -922,7 +1034,11 @@ impl Type {
             | Type::MzTimestamp
             | Type::VarChar { max_length: None }
             | Type::Range { .. }
-            | Type::MzAclItem => None,
+            | Type::MzAclItem
+            | Type::TsVector
+            | Type::TsQuery
+            | Type::Vector { dimensions: None }
+            | Type::Geometry => None,
         }
     }
 
@@ -972,6 +1088,10 @@ impl Type {
             Type::Range { .. } => -1,
             Type::MzAclItem => MzAclItem::binary_size().try_into().expect("must fit"),
             Type::AclItem => AclItem::binary_size().try_into().expect("must fit"),
+            Type::TsVector => -1,
+            Type::TsQuery => -1,
+            Type::Vector { .. } => -1,
+            Type::Geometry => -1,
         }
     }
 
@@ -1104,6 +1224,17 @@ impl TryFrom<&Type> for ScalarType {
                 element_type: Box::new(TryFrom::try_from(&**element_type)?),
             }),
             Type::MzAclItem => Ok(ScalarType::MzAclItem),
+            Type::TsVector => Ok(ScalarType::TsVector),
+            Type::TsQuery => Ok(ScalarType::TsQuery),
+            Type::Vector { dimensions } => Ok(ScalarType::Vector {
+                dimensions: match dimensions {
+                    Some(dimensions) => Some(AdtVectorDimensions::try_from(i64::from(
+                        dimensions.into_i32(),
+                    ))?),
+                    None => None,
+                },
+            }),
+            Type::Geometry => Ok(ScalarType::Geometry),
         }
     }
 }
@@ -1165,6 +1296,9 @@ pub enum TypeConversionError {
     /// The source type contained an invalid precision for a
     /// [`ScalarType::Timestamp`] or [`ScalarType::TimestampTz`].
     InvalidTimestampPrecision(InvalidTimestampPrecisionError),
+    /// The source type contained invalid dimensions for a
+    /// [`ScalarType::Vector`].
+    InvalidVectorDimensions(InvalidVectorDimensionsError),
 }
 
 impl fmt::Display for TypeConversionError {
@@ -1176,6 +1310,7 @@ impl fmt::Display for TypeConversionError {
             TypeConversionError::InvalidCharLength(e) => e.fmt(f),
             TypeConversionError::InvalidVarCharMaxLength(e) => e.fmt(f),
             TypeConversionError::InvalidTimestampPrecision(e) => e.fmt(f),
+            TypeConversionError::InvalidVectorDimensions(e) => e.fmt(f),
         }
     }
 }
@@ -1206,6 +1341,12 @@ impl From<InvalidTimestampPrecisionError> for TypeConversionError {
     }
 }
 
+impl From<InvalidVectorDimensionsError> for TypeConversionError {
+    fn from(e: InvalidVectorDimensionsError) -> TypeConversionError {
+        TypeConversionError::InvalidVectorDimensions(e)
+    }
+}
+
 impl From<&ScalarType> for Type {
     fn from(typ: &ScalarType) -> Type {
         match typ {
@@ -1272,6 +1413,14 @@ impl From<&ScalarType> for Type {
                 element_type: Box::new(From::from(&**element_type)),
             },
             ScalarType::MzAclItem => Type::MzAclItem,
+            // Enum values are sent to clients as their labels.
+            ScalarType::Enum { .. } => Type::Text,
+            ScalarType::TsVector => Type::TsVector,
+            ScalarType::TsQuery => Type::TsQuery,
+            ScalarType::Vector { dimensions } => Type::Vector {
+                dimensions: (*dimensions).map(VectorDimensions::from),
+            },
+            ScalarType::Geometry => Type::Geometry,
         }
     }
 }
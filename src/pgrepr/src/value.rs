@@ -18,6 +18,7 @@ use mz_pgwire_common::Format;
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::char;
 use mz_repr::adt::date::Date;
+use mz_repr::adt::geometry::Geometry;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::mz_acl_item::{AclItem, MzAclItem};
 use mz_repr::adt::pg_legacy_name::NAME_MAX_BYTES;
@@ -116,6 +117,14 @@ pub enum Value {
     /// A list of privileges granted to a user that uses [`mz_repr::adt::system::Oid`]s for role
     /// references. This type is used primarily for compatibility with PostgreSQL.
     AclItem(AclItem),
+    /// A full text search document, in its canonical text form.
+    TsVector(String),
+    /// A full text search query, in its canonical text form.
+    TsQuery(String),
+    /// A vector of single-precision floats.
+    Vector(Vec<f32>),
+    /// A planar geometry, in its canonical EWKB form.
+    Geometry(Vec<u8>),
 }
 
 impl Value {
@@ -138,6 +147,9 @@ impl Value {
             (Datum::UInt32(oid), ScalarType::RegProc) => Some(Value::Oid(oid)),
             (Datum::UInt32(oid), ScalarType::RegType) => Some(Value::Oid(oid)),
             (Datum::UInt32(u), ScalarType::UInt32) => Some(Value::UInt4(UInt4(u))),
+            (Datum::UInt32(i), ScalarType::Enum { labels, .. }) => {
+                Some(Value::Text(strconv::format_enum(labels, i).into_owned()))
+            }
             (Datum::UInt64(u), ScalarType::UInt64) => Some(Value::UInt8(UInt8(u))),
             (Datum::Float32(f), ScalarType::Float32) => Some(Value::Float4(*f)),
             (Datum::Float64(f), ScalarType::Float64) => Some(Value::Float8(*f)),
@@ -153,8 +165,11 @@ impl Value {
             }
             (Datum::Interval(iv), ScalarType::Interval) => Some(Value::Interval(Interval(iv))),
             (Datum::Bytes(b), ScalarType::Bytes) => Some(Value::Bytea(b.to_vec())),
+            (Datum::Bytes(b), ScalarType::Geometry) => Some(Value::Geometry(b.to_vec())),
             (Datum::String(s), ScalarType::String) => Some(Value::Text(s.to_owned())),
             (Datum::String(s), ScalarType::VarChar { .. }) => Some(Value::VarChar(s.to_owned())),
+            (Datum::String(s), ScalarType::TsVector) => Some(Value::TsVector(s.to_owned())),
+            (Datum::String(s), ScalarType::TsQuery) => Some(Value::TsQuery(s.to_owned())),
             (Datum::String(s), ScalarType::Char { length }) => {
                 Some(Value::BpChar(char::format_str_pad(s, *length)))
             }
@@ -182,6 +197,14 @@ impl Value {
                     .collect();
                 Some(Value::Int2Vector { elements })
             }
+            (Datum::Array(array), ScalarType::Vector { .. }) => {
+                let elements = array
+                    .elements()
+                    .iter()
+                    .map(|elem| elem.unwrap_float32())
+                    .collect();
+                Some(Value::Vector(elements))
+            }
             (Datum::List(list), ScalarType::List { element_type, .. }) => {
                 let elements = list
                     .iter()
@@ -295,9 +318,11 @@ impl Value {
             Value::Timestamp(ts) => Datum::Timestamp(ts),
             Value::TimestampTz(ts) => Datum::TimestampTz(ts),
             Value::Interval(iv) => Datum::Interval(iv.0),
-            Value::Text(s) | Value::VarChar(s) | Value::Name(s) => {
-                Datum::String(buf.push_string(s))
-            }
+            Value::Text(s)
+            | Value::VarChar(s)
+            | Value::Name(s)
+            | Value::TsVector(s)
+            | Value::TsQuery(s) => Datum::String(buf.push_string(s)),
             Value::BpChar(s) => Datum::String(buf.push_string(s.trim_end().into())),
             Value::Uuid(u) => Datum::Uuid(u),
             Value::Numeric(n) => Datum::Numeric(n.0),
@@ -313,6 +338,18 @@ impl Value {
             }
             Value::MzAclItem(mz_acl_item) => Datum::MzAclItem(mz_acl_item),
             Value::AclItem(acl_item) => Datum::AclItem(acl_item),
+            Value::Vector(elements) => buf.make_datum(|packer| {
+                packer
+                    .try_push_array(
+                        &[ArrayDimension {
+                            lower_bound: 1,
+                            length: elements.len(),
+                        }],
+                        elements.into_iter().map(|f| Datum::Float32(f.into())),
+                    )
+                    .unwrap();
+            }),
+            Value::Geometry(b) => Datum::Bytes(buf.push_bytes(b)),
         }
     }
 
@@ -381,9 +418,12 @@ impl Value {
                 Some(elem) => Ok(elem.encode_text(buf.nonnull_buffer())),
             })
             .expect("provided closure never fails"),
-            Value::Text(s) | Value::VarChar(s) | Value::BpChar(s) | Value::Name(s) => {
-                strconv::format_string(buf, s)
-            }
+            Value::Text(s)
+            | Value::VarChar(s)
+            | Value::BpChar(s)
+            | Value::Name(s)
+            | Value::TsVector(s)
+            | Value::TsQuery(s) => strconv::format_string(buf, s),
             Value::Time(t) => strconv::format_time(buf, *t),
             Value::Timestamp(ts) => strconv::format_timestamp(buf, ts),
             Value::TimestampTz(ts) => strconv::format_timestamptz(buf, ts),
@@ -397,6 +437,8 @@ impl Value {
             .expect("provided closure never fails"),
             Value::MzAclItem(mz_acl_item) => strconv::format_mz_acl_item(buf, *mz_acl_item),
             Value::AclItem(acl_item) => strconv::format_acl_item(buf, *acl_item),
+            Value::Vector(elements) => strconv::format_vector(buf, elements.iter().copied()),
+            Value::Geometry(b) => strconv::format_geometry(buf, b),
         }
     }
 
@@ -537,6 +579,27 @@ impl Value {
                 Ok(postgres_types::IsNull::No)
             }
             Value::AclItem(_) => Err("aclitem has no binary encoding".into()),
+            Value::TsVector(_) => Err("binary encoding of tsvector is not implemented".into()),
+            Value::TsQuery(_) => Err("binary encoding of tsquery is not implemented".into()),
+            Value::Vector(elements) => {
+                // https://github.com/pgvector/pgvector/blob/v0.8.0/src/vector.c#L337-L353
+                let dim = i16::try_from(elements.len()).map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::Other,
+                        "vector dimensions do not fit into an i16",
+                    )
+                })?;
+                buf.put_i16(dim);
+                buf.put_i16(0);
+                for f in elements {
+                    buf.put_f32(*f);
+                }
+                Ok(postgres_types::IsNull::No)
+            }
+            Value::Geometry(b) => {
+                buf.extend_from_slice(b);
+                Ok(postgres_types::IsNull::No)
+            }
         }
         .expect("encode_binary should never trigger a to_sql failure");
         if let IsNull::Yes = is_null {
@@ -588,6 +651,11 @@ impl Value {
                 .iter()
                 .all(|(_, ty)| Self::can_encode_binary(&ty.scalar_type)),
             ScalarType::Range { element_type } => Self::can_encode_binary(element_type),
+            ScalarType::Enum { .. } => true,
+            ScalarType::TsVector => false, // "binary encoding of tsvector is not implemented"
+            ScalarType::TsQuery => false,  // "binary encoding of tsquery is not implemented"
+            ScalarType::Vector { .. } => true,
+            ScalarType::Geometry => true,
         }
     }
 
@@ -675,6 +743,10 @@ impl Value {
             })?),
             Type::MzAclItem => Value::MzAclItem(strconv::parse_mz_acl_item(s)?),
             Type::AclItem => Value::AclItem(strconv::parse_acl_item(s)?),
+            Type::TsVector => Value::TsVector(strconv::parse_tsvector(s)?.to_string()),
+            Type::TsQuery => Value::TsQuery(strconv::parse_tsquery(s)?.to_string()),
+            Type::Vector { .. } => Value::Vector(strconv::parse_vector(s)?),
+            Type::Geometry => Value::Geometry(strconv::parse_geometry(s)?.to_ewkb()),
         })
     }
 
@@ -789,6 +861,19 @@ impl Value {
             }
             Type::MzAclItem => packer.push(Datum::MzAclItem(strconv::parse_mz_acl_item(s)?)),
             Type::AclItem => packer.push(Datum::AclItem(strconv::parse_acl_item(s)?)),
+            Type::TsVector => packer.push(Datum::String(&strconv::parse_tsvector(s)?.to_string())),
+            Type::TsQuery => packer.push(Datum::String(&strconv::parse_tsquery(s)?.to_string())),
+            Type::Vector { .. } => {
+                let elements = strconv::parse_vector(s)?;
+                packer.try_push_array(
+                    &[ArrayDimension {
+                        lower_bound: 1,
+                        length: elements.len(),
+                    }],
+                    elements.into_iter().map(|f| Datum::Float32(f.into())),
+                )?
+            }
+            Type::Geometry => packer.push(Datum::Bytes(&strconv::parse_geometry(s)?.to_ewkb())),
         })
     }
 
@@ -859,6 +944,35 @@ impl Value {
                 Ok(Value::MzAclItem(mz_acl_item))
             }
             Type::AclItem => Err("aclitem has no binary encoding".into()),
+            Type::TsVector => Err("binary decoding of tsvector is not implemented".into()),
+            Type::TsQuery => Err("binary decoding of tsquery is not implemented".into()),
+            Type::Vector { .. } => {
+                // https://github.com/pgvector/pgvector/blob/v0.8.0/src/vector.c#L306-L335
+                let (header, data) = raw
+                    .split_first_chunk::<4>()
+                    .ok_or("invalid binary vector: missing header")?;
+                let dim = i16::from_be_bytes([header[0], header[1]]);
+                let unused = i16::from_be_bytes([header[2], header[3]]);
+                if unused != 0 {
+                    return Err("expected unused to be 0".into());
+                }
+                let dim = usize::try_from(dim).map_err(|_| "invalid binary vector dimensions")?;
+                if data.len() != dim * 4 {
+                    return Err("invalid binary vector: length does not match dimensions".into());
+                }
+                let elements: Vec<f32> = data
+                    .chunks_exact(4)
+                    .map(|c| f32::from_be_bytes([c[0], c[1], c[2], c[3]]))
+                    .collect();
+                if elements.is_empty() {
+                    return Err("vector must have at least 1 dimension".into());
+                }
+                if elements.iter().any(|f| !f.is_finite()) {
+                    return Err("vector elements must be finite".into());
+                }
+                Ok(Value::Vector(elements))
+            }
+            Type::Geometry => Ok(Value::Geometry(Geometry::from_ewkb(raw)?.to_ewkb())),
         }
     }
 }
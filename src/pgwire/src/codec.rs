@@ -254,8 +254,10 @@ impl Encoder<BackendMessage> for Codec {
             }
             BackendMessage::CopyInResponse { .. } => b'G',
             BackendMessage::CopyOutResponse { .. } => b'H',
+            BackendMessage::CopyBothResponse { .. } => b'W',
             BackendMessage::CopyData(_) => b'd',
             BackendMessage::CopyDone => b'c',
+            BackendMessage::NotificationResponse { .. } => b'A',
         };
         dst.put_u8(byte);
 
@@ -272,6 +274,10 @@ impl Encoder<BackendMessage> for Codec {
             | BackendMessage::CopyOutResponse {
                 overall_format,
                 column_formats,
+            }
+            | BackendMessage::CopyBothResponse {
+                overall_format,
+                column_formats,
             } => {
                 dst.put_format_i8(overall_format);
                 dst.put_length_i16(column_formats.len())?;
@@ -283,6 +289,15 @@ impl Encoder<BackendMessage> for Codec {
                 dst.put_slice(&data);
             }
             BackendMessage::CopyDone => (),
+            BackendMessage::NotificationResponse {
+                conn_id,
+                channel,
+                payload,
+            } => {
+                dst.put_u32(conn_id);
+                dst.put_string(&channel);
+                dst.put_string(&payload);
+            }
             BackendMessage::AuthenticationOk => {
                 dst.put_u32(0);
             }
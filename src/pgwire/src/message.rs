@@ -48,8 +48,17 @@ pub enum BackendMessage {
         overall_format: mz_pgwire_common::Format,
         column_formats: Vec<mz_pgwire_common::Format>,
     },
+    CopyBothResponse {
+        overall_format: mz_pgwire_common::Format,
+        column_formats: Vec<mz_pgwire_common::Format>,
+    },
     CopyData(Vec<u8>),
     CopyDone,
+    NotificationResponse {
+        conn_id: u32,
+        channel: String,
+        payload: String,
+    },
 }
 
 impl From<ErrorResponse> for BackendMessage {
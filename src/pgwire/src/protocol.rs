@@ -7,28 +7,29 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::convert::TryFrom;
 use std::future::Future;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use std::{iter, mem};
 
 use byteorder::{ByteOrder, NetworkEndian};
 use futures::future::{BoxFuture, FutureExt, pending};
 use itertools::izip;
-use mz_adapter::client::RecordFirstRowStream;
+use mz_adapter::client::{IdleEvent, RecordFirstRowStream, TimeoutType};
 use mz_adapter::session::{
     EndTransactionAction, InProgressRows, Portal, PortalState, SessionConfig, TransactionStatus,
 };
 use mz_adapter::statement_logging::{StatementEndedExecutionReason, StatementExecutionStrategy};
 use mz_adapter::{
     AdapterError, AdapterNotice, ExecuteContextExtra, ExecuteResponse, PeekResponseUnary,
-    RowsFuture, verify_datum_desc,
+    ReplicationSlotRequest, RowsFuture, verify_datum_desc,
 };
 use mz_auth::password::Password;
 use mz_frontegg_auth::Authenticator as FronteggAuthentication;
 use mz_ore::cast::CastFrom;
+use mz_ore::collections::CollectionExt;
 use mz_ore::netio::AsyncReady;
 use mz_ore::str::StrExt;
 use mz_ore::{assert_none, assert_ok, instrument};
@@ -38,12 +39,16 @@ use mz_pgwire_common::{
 };
 use mz_repr::user::InternalUserMetadata;
 use mz_repr::{
-    CatalogItemId, ColumnIndex, Datum, RelationDesc, RelationType, RowArena, RowIterator, RowRef,
-    ScalarType,
+    CatalogItemId, ColumnIndex, Datum, GlobalId, RelationDesc, RelationType,
+    RelationVersionSelector, RowArena, RowIterator, RowRef, ScalarType, Timestamp,
 };
 use mz_server_core::TlsMode;
 use mz_sql::ast::display::AstDisplay;
-use mz_sql::ast::{CopyDirection, CopyStatement, FetchDirection, Ident, Raw, Statement};
+use mz_sql::ast::{
+    CopyDirection, CopyStatement, FetchDirection, Ident, Raw, Statement, UnresolvedItemName,
+};
+use mz_sql::catalog::{CatalogItem, CatalogItemType, SessionCatalog};
+use mz_sql::names::PartialItemName;
 use mz_sql::parse::StatementParseResult;
 use mz_sql::plan::{CopyFormat, ExecuteTimeout, StatementDesc};
 use mz_sql::session::metadata::SessionMetadata;
@@ -60,6 +65,7 @@ use uuid::Uuid;
 
 use crate::codec::FramedConn;
 use crate::message::{self, BackendMessage};
+use crate::replication::{self, Lsn, ReplicationCommand, SubscribeRow, pgoutput};
 
 /// Reports whether the given stream begins with a pgwire handshake.
 ///
@@ -146,6 +152,7 @@ where
     }
 
     let user = params.remove("user").unwrap_or_else(String::new);
+    let replication = params.remove("replication");
 
     if internal {
         // The internal server can only be used to connect to the internal users.
@@ -274,6 +281,42 @@ where
     };
 
     let system_vars = adapter_client.get_system_vars().await;
+    // Of the replication modes PostgreSQL offers, we only support logical
+    // replication, which is requested with `replication=database`.
+    let replication = match replication.as_deref() {
+        None => false,
+        Some("database") if system_vars.enable_logical_replication() => true,
+        Some("database") => {
+            return conn
+                .send(ErrorResponse::fatal(
+                    SqlState::FEATURE_NOT_SUPPORTED,
+                    "logical replication is not supported",
+                ))
+                .await;
+        }
+        Some(value) => match value.to_lowercase().as_str() {
+            "false" | "off" | "no" | "0" => false,
+            "true" | "on" | "yes" | "1" => {
+                return conn
+                    .send(ErrorResponse::fatal(
+                        SqlState::FEATURE_NOT_SUPPORTED,
+                        "physical replication is not supported",
+                    ))
+                    .await;
+            }
+            _ => {
+                return conn
+                    .send(ErrorResponse::fatal(
+                        SqlState::INVALID_PARAMETER_VALUE,
+                        format!(
+                            "invalid value for parameter \"replication\": {}",
+                            value.quoted()
+                        ),
+                    ))
+                    .await;
+            }
+        },
+    };
     for (name, value) in params {
         let settings = match name.as_str() {
             "options" => match parse_options(&value) {
@@ -349,6 +392,8 @@ where
         conn,
         adapter_client,
         txn_needs_commit: false,
+        replication,
+        temporary_slots: BTreeSet::new(),
     };
 
     select! {
@@ -469,6 +514,12 @@ struct StateMachine<'a, A> {
     conn: &'a mut FramedConn<A>,
     adapter_client: mz_adapter::SessionClient,
     txn_needs_commit: bool,
+    /// Whether this is a logical replication connection, which accepts
+    /// replication commands in addition to SQL.
+    replication: bool,
+    /// The temporary replication slots created by this connection, which are
+    /// not recorded in the catalog.
+    temporary_slots: BTreeSet<String>,
 }
 
 enum SendRowsEndedReason {
@@ -485,6 +536,14 @@ enum SendRowsEndedReason {
 const ABORTED_TXN_MSG: &str =
     "current transaction is aborted, commands ignored until end of transaction block";
 
+/// How often an idle replication stream reports its position to the client.
+const REPLICATION_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How often a replication stream confirms the position that its client has
+/// flushed to the durable slot it streams from. Clients report their position
+/// far more often, and each confirmation is a catalog write.
+const REPLICATION_CONFIRM_INTERVAL: Duration = Duration::from_secs(10);
+
 impl<'a, A> StateMachine<'a, A>
 where
     A: AsyncRead + AsyncWrite + AsyncReady + Send + Sync + Unpin + 'a,
@@ -513,30 +572,29 @@ where
     #[instrument(level = "debug")]
     async fn advance_ready(&mut self) -> Result<State, io::Error> {
         // Handle timeouts first so we don't execute any statements when there's a pending timeout.
-        let message = select! {
-            biased;
-
-            // `recv_timeout()` is cancel-safe as per it's docs.
-            Some(timeout) = self.adapter_client.recv_timeout() => {
-                let err: AdapterError = timeout.into();
-                let conn_id = self.adapter_client.session().conn_id();
-                tracing::warn!("session timed out, conn_id {}", conn_id);
-
-                // Process the error, doing any state cleanup.
-                let error_response = err.into_response(Severity::Fatal);
-                let error_state = self.error(error_response).await;
-
-                // Terminate __after__ we do any cleanup.
-                self.adapter_client.terminate().await;
-
-                // We must wait for the client to send a request before we can send the error response.
-                // Due to the PG wire protocol, we can't send an ErrorResponse unless it is in response
-                // to a client message.
-                let _ = self.conn.recv().await?;
-                return error_state;
-            },
-            // `recv()` is cancel-safe as per it's docs.
-            message = self.conn.recv() => message?,
+        // Notifications for channels the session is listening on are delivered while we wait.
+        let message = loop {
+            select! {
+                biased;
+
+                // `recv_idle_event()` is cancel-safe as per it's docs.
+                event = self.adapter_client.recv_idle_event() => match event {
+                    IdleEvent::Timeout(timeout) => return self.handle_timeout(timeout).await,
+                    IdleEvent::Notification(notification) => {
+                        self.send(BackendMessage::NotificationResponse {
+                            // Notifications are raised by the system rather than by another
+                            // backend, so there is no meaningful process ID to report.
+                            conn_id: 0,
+                            channel: notification.channel,
+                            payload: notification.payload,
+                        })
+                        .await?;
+                        self.conn.flush().await?;
+                    }
+                },
+                // `recv()` is cancel-safe as per it's docs.
+                message = self.conn.recv() => break message?,
+            }
         };
 
         self.adapter_client
@@ -635,6 +693,25 @@ where
         Ok(next_state)
     }
 
+    async fn handle_timeout(&mut self, timeout: TimeoutType) -> Result<State, io::Error> {
+        let err: AdapterError = timeout.into();
+        let conn_id = self.adapter_client.session().conn_id();
+        tracing::warn!("session timed out, conn_id {}", conn_id);
+
+        // Process the error, doing any state cleanup.
+        let error_response = err.into_response(Severity::Fatal);
+        let error_state = self.error(error_response).await;
+
+        // Terminate __after__ we do any cleanup.
+        self.adapter_client.terminate().await;
+
+        // We must wait for the client to send a request before we can send the error response.
+        // Due to the PG wire protocol, we can't send an ErrorResponse unless it is in response
+        // to a client message.
+        let _ = self.conn.recv().await?;
+        error_state
+    }
+
     async fn advance_drain(&mut self) -> Result<State, io::Error> {
         let message = self.conn.recv().await?;
         if message.is_some() {
@@ -746,6 +823,12 @@ where
     // From https://www.postgresql.org/docs/current/protocol-flow.html
     #[instrument(level = "debug")]
     async fn query(&mut self, sql: String) -> Result<State, io::Error> {
+        if self.replication {
+            if let Some(command) = replication::parse_command(&sql) {
+                return self.replication_command(command).await;
+            }
+        }
+
         // Parse first before doing any transaction checking.
         let stmts = match self.parse_sql(&sql) {
             Ok(stmts) => stmts,
@@ -972,8 +1055,8 @@ where
             }
         };
 
-        // Binary encodings are disabled for list, map, and aclitem types, but this doesn't
-        // apply to COPY TO statements.
+        // Binary encodings are disabled for list, map, aclitem, and text search types, but this
+        // doesn't apply to COPY TO statements.
         if !stmt.stmt().map_or(false, |stmt| {
             matches!(
                 stmt,
@@ -1010,6 +1093,22 @@ where
                                 ))
                                 .await;
                         }
+                        (Format::Binary, mz_repr::ScalarType::TsVector) => {
+                            return self
+                                .error(ErrorResponse::error(
+                                    SqlState::PROTOCOL_VIOLATION,
+                                    "binary encoding of tsvector types is not implemented",
+                                ))
+                                .await;
+                        }
+                        (Format::Binary, mz_repr::ScalarType::TsQuery) => {
+                            return self
+                                .error(ErrorResponse::error(
+                                    SqlState::PROTOCOL_VIOLATION,
+                                    "binary encoding of tsquery types is not implemented",
+                                ))
+                                .await;
+                        }
                         _ => (),
                     }
                 }
@@ -1821,10 +1920,13 @@ where
             | ExecuteResponse::CreatedIndex { .. }
             | ExecuteResponse::CreatedIntrospectionSubscribe
             | ExecuteResponse::CreatedMaterializedView { .. }
+            | ExecuteResponse::CreatedFunction
+            | ExecuteResponse::CreatedNotification
             | ExecuteResponse::CreatedContinualTask { .. }
             | ExecuteResponse::CreatedRole
             | ExecuteResponse::CreatedSchema { .. }
             | ExecuteResponse::CreatedSecret { .. }
+            | ExecuteResponse::CreatedPublication { .. }
             | ExecuteResponse::CreatedSink { .. }
             | ExecuteResponse::CreatedSource { .. }
             | ExecuteResponse::CreatedTable { .. }
@@ -1843,12 +1945,14 @@ where
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(..)
             | ExecuteResponse::Copied(..)
+            | ExecuteResponse::Listening
             | ExecuteResponse::Prepare
             | ExecuteResponse::Raised
             | ExecuteResponse::ReassignOwned
             | ExecuteResponse::RevokedPrivilege
             | ExecuteResponse::RevokedRole
             | ExecuteResponse::StartedTransaction { .. }
+            | ExecuteResponse::Unlistening
             | ExecuteResponse::Updated(..)
             | ExecuteResponse::ValidatedConnection => {
                 command_complete!()
@@ -2317,6 +2421,616 @@ where
     }
 
     #[instrument(level = "debug")]
+    /// Handles a command that is only valid on a replication connection.
+    #[instrument(level = "debug")]
+    async fn replication_command(
+        &mut self,
+        command: Result<ReplicationCommand, ErrorResponse>,
+    ) -> Result<State, io::Error> {
+        if self.is_aborted_txn() {
+            self.aborted_txn_error().await?;
+            return self.ready().await;
+        }
+        let state = match command {
+            Err(err) => self.error(err).await?,
+            Ok(ReplicationCommand::IdentifySystem) => {
+                let system_id = self
+                    .adapter_client
+                    .catalog_snapshot()
+                    .await
+                    .config()
+                    .environment_id
+                    .to_string();
+                let xlogpos = self.current_lsn().to_string();
+                let database = self.adapter_client.session().vars().database().to_string();
+                self.send_replication_row(
+                    "IDENTIFY_SYSTEM",
+                    &[
+                        ("systemid", ScalarType::String, Some(system_id)),
+                        ("timeline", ScalarType::Int32, Some("1".into())),
+                        ("xlogpos", ScalarType::String, Some(xlogpos)),
+                        ("dbname", ScalarType::String, Some(database)),
+                    ],
+                )
+                .await?
+            }
+            Ok(ReplicationCommand::CreateReplicationSlot {
+                name,
+                plugin,
+                temporary,
+            }) => {
+                let exists = self.temporary_slots.contains(&name)
+                    || self
+                        .adapter_client
+                        .catalog_snapshot()
+                        .await
+                        .get_replication_slot(&name)
+                        .is_some();
+                let created = if plugin != replication::PGOUTPUT {
+                    let msg = format!("output plugin {} is not supported", plugin.quoted());
+                    Err(ErrorResponse::error(SqlState::FEATURE_NOT_SUPPORTED, msg))
+                } else if exists {
+                    let msg = format!("replication slot {} already exists", name.quoted());
+                    Err(ErrorResponse::error(SqlState::DUPLICATE_OBJECT, msg))
+                } else if temporary {
+                    self.temporary_slots.insert(name.clone());
+                    Ok(())
+                } else {
+                    self.adapter_client
+                        .replication_slot(ReplicationSlotRequest::Create {
+                            name: name.clone(),
+                            plugin: plugin.clone(),
+                        })
+                        .await
+                        .map(|_| ())
+                        .map_err(|e| e.into_response(Severity::Error))
+                };
+                if let Err(err) = created {
+                    self.error(err).await?
+                } else {
+                    let consistent_point = self.current_lsn().to_string();
+                    self.send_replication_row(
+                        "CREATE_REPLICATION_SLOT",
+                        &[
+                            ("slot_name", ScalarType::String, Some(name)),
+                            (
+                                "consistent_point",
+                                ScalarType::String,
+                                Some(consistent_point),
+                            ),
+                            ("snapshot_name", ScalarType::String, None),
+                            ("output_plugin", ScalarType::String, Some(plugin)),
+                        ],
+                    )
+                    .await?
+                }
+            }
+            Ok(ReplicationCommand::DropReplicationSlot { name }) => {
+                debug!("dropping replication slot {name}");
+                let dropped = if self.temporary_slots.remove(&name) {
+                    Ok(Timestamp::MIN)
+                } else {
+                    self.adapter_client
+                        .replication_slot(ReplicationSlotRequest::Drop { name })
+                        .await
+                };
+                match dropped {
+                    Ok(_) => {
+                        self.send(BackendMessage::CommandComplete {
+                            tag: "DROP_REPLICATION_SLOT".into(),
+                        })
+                        .await?;
+                        State::Ready
+                    }
+                    Err(e) => self.error(e.into_response(Severity::Error)).await?,
+                }
+            }
+            Ok(ReplicationCommand::StartReplication { slot, lsn, options }) => {
+                debug!("starting replication on slot {slot} at {lsn}");
+                self.ensure_transaction(1).await?;
+                let state = self.start_replication(slot.clone(), lsn, options).await?;
+                // Let other connections stream from the slot now that this
+                // connection no longer does.
+                if !self.temporary_slots.contains(&slot) {
+                    let request = ReplicationSlotRequest::Release { name: slot };
+                    if let Err(e) = self.adapter_client.replication_slot(request).await {
+                        warn!("failed to release replication slot: {e}");
+                    }
+                }
+                if self.adapter_client.session().transaction().is_implicit() {
+                    self.commit_transaction().await?;
+                }
+                state
+            }
+        };
+        match state {
+            State::Done => Ok(State::Done),
+            State::Ready | State::Drain => self.ready().await,
+        }
+    }
+
+    /// Sends a single row of text values in response to a replication
+    /// command.
+    async fn send_replication_row(
+        &mut self,
+        tag: &str,
+        columns: &[(&str, ScalarType, Option<String>)],
+    ) -> Result<State, io::Error> {
+        let desc = RelationDesc::builder()
+            .with_columns(
+                columns
+                    .iter()
+                    .map(|(name, typ, _)| (*name, typ.clone().nullable(true))),
+            )
+            .finish();
+        let formats = vec![Format::Text; columns.len()];
+        self.send(BackendMessage::RowDescription(
+            message::encode_row_description(&desc, &formats),
+        ))
+        .await?;
+        self.conn.set_encode_state(
+            columns
+                .iter()
+                .map(|(_, typ, _)| (mz_pgrepr::Type::from(typ), Format::Text))
+                .collect(),
+        );
+        let values = columns
+            .iter()
+            .map(|(_, typ, value)| {
+                value.as_ref().map(|value| match typ {
+                    ScalarType::Int32 => mz_pgrepr::Value::Int4(value.parse().expect("valid int4")),
+                    _ => mz_pgrepr::Value::Text(value.clone()),
+                })
+            })
+            .collect();
+        self.send(BackendMessage::DataRow(values)).await?;
+        self.send(BackendMessage::CommandComplete { tag: tag.into() })
+            .await?;
+        Ok(State::Ready)
+    }
+
+    /// Returns the LSN of the current time, which is the position that a
+    /// newly started replication stream would begin at.
+    fn current_lsn(&self) -> Lsn {
+        let now = self.adapter_client.inner().now().timestamp_millis();
+        Lsn(u64::try_from(now).unwrap_or(0))
+    }
+
+    /// Streams the changes to the relations in the publications named by the
+    /// `publication_names` option as `pgoutput` messages.
+    ///
+    /// Streaming starts after `lsn`, or after the position the slot's consumer
+    /// has confirmed, whichever is later.
+    #[instrument(level = "debug")]
+    async fn start_replication(
+        &mut self,
+        slot: String,
+        lsn: Lsn,
+        options: BTreeMap<String, String>,
+    ) -> Result<State, io::Error> {
+        let relations = match self.resolve_publications(&options).await {
+            Ok(relations) => relations,
+            Err(err) => return self.error(err).await,
+        };
+
+        // Temporary slots do not hold back compaction, so only durable slots
+        // need to learn about the published collections.
+        let durable_slot = (!self.temporary_slots.contains(&slot)).then_some(slot);
+        let lsn = match &durable_slot {
+            None => lsn,
+            Some(slot) => {
+                let request = ReplicationSlotRequest::Start {
+                    name: slot.clone(),
+                    lsn: Timestamp::from(lsn.0),
+                    collections: relations.iter().map(|(id, ..)| *id).collect(),
+                };
+                match self.adapter_client.replication_slot(request).await {
+                    Ok(start) => Lsn(start.into()),
+                    Err(e) => return self.error(e.into_response(Severity::Error)).await,
+                }
+            }
+        };
+
+        let (subscribed, relations): (Vec<_>, Vec<_>) = relations
+            .into_iter()
+            .map(|(_id, name, desc, relation)| ((name, desc), relation))
+            .unzip();
+        let sql = replication::subscribe_sql(&subscribed, lsn);
+        let stmt = match self.parse_sql(&sql) {
+            Ok(stmts) => stmts.into_element().ast,
+            Err(err) => return self.error(err).await,
+        };
+        const EMPTY_PORTAL: &str = "";
+        if let Err(e) = self
+            .adapter_client
+            .declare(EMPTY_PORTAL.to_string(), stmt, sql.clone())
+            .await
+        {
+            return self.error(e.into_response(Severity::Error)).await;
+        }
+        let result = match self
+            .adapter_client
+            .execute(EMPTY_PORTAL.to_string(), self.conn.wait_closed(), None)
+            .await
+        {
+            Ok((
+                ExecuteResponse::Subscribing {
+                    rx,
+                    ctx_extra,
+                    instance_id,
+                },
+                execute_started,
+            )) => {
+                let stream = RecordFirstRowStream::new(
+                    Box::new(UnboundedReceiverStream::new(rx)),
+                    execute_started,
+                    &self.adapter_client,
+                    Some(instance_id),
+                    None,
+                );
+                let (result, reason) = match self
+                    .stream_changes(stream, &relations, lsn, durable_slot.as_deref())
+                    .await
+                {
+                    // This is an error communicating with the connection.
+                    // We consider that to be a cancelation, rather than a query error.
+                    Err(e) => (Err(e), StatementEndedExecutionReason::Canceled),
+                    Ok((state, reason)) => (Ok(state), reason),
+                };
+                self.adapter_client.retire_execute(ctx_extra, reason);
+                result
+            }
+            Ok((response, _)) => {
+                unreachable!("SUBSCRIBE produced unexpected response: {response:?}")
+            }
+            Err(e) => self.error(e.into_response(Severity::Error)).await,
+        };
+        self.adapter_client.session().remove_portal(EMPTY_PORTAL);
+        result
+    }
+
+    /// Resolves the relations published by the publications named by the
+    /// `publication_names` option.
+    async fn resolve_publications(
+        &mut self,
+        options: &BTreeMap<String, String>,
+    ) -> Result<
+        Vec<(
+            GlobalId,
+            UnresolvedItemName,
+            RelationDesc,
+            pgoutput::Relation,
+        )>,
+        ErrorResponse,
+    > {
+        match options.get("proto_version").map(|v| v.as_str()) {
+            Some("1" | "2" | "3" | "4") => {}
+            Some(version) => {
+                let msg = format!("proto_version {} is not supported", version.quoted());
+                return Err(ErrorResponse::error(SqlState::FEATURE_NOT_SUPPORTED, msg));
+            }
+            None => {
+                return Err(ErrorResponse::error(
+                    SqlState::INVALID_PARAMETER_VALUE,
+                    "proto_version option missing",
+                ));
+            }
+        }
+        let Some(names) = options.get("publication_names") else {
+            return Err(ErrorResponse::error(
+                SqlState::INVALID_PARAMETER_VALUE,
+                "publication_names option missing",
+            ));
+        };
+        let names = replication::parse_identifier_list(names)?;
+
+        let catalog = self.adapter_client.catalog_snapshot().await;
+        let conn_catalog = catalog.for_session(self.adapter_client.session());
+        let mut ids = BTreeSet::new();
+        for name in names {
+            let publication = conn_catalog
+                .resolve_item(&PartialItemName {
+                    database: None,
+                    schema: None,
+                    item: name.clone(),
+                })
+                .ok()
+                .filter(|item| item.item_type() == CatalogItemType::Publication)
+                .ok_or_else(|| {
+                    ErrorResponse::error(
+                        SqlState::UNDEFINED_OBJECT,
+                        format!("publication {} does not exist", name.quoted()),
+                    )
+                })?;
+            ids.extend(publication.references().items().copied());
+        }
+
+        let mut relations = vec![];
+        for id in ids {
+            let item = conn_catalog.get_item(&id);
+            let full_name = conn_catalog.resolve_full_name(item.name());
+            let collection = item.at_version(RelationVersionSelector::Latest);
+            let desc = collection
+                .desc(&full_name)
+                .map_err(|e| ErrorResponse::error(SqlState::WRONG_OBJECT_TYPE, e.to_string()))?
+                .into_owned();
+            if i16::try_from(desc.arity()).is_err() {
+                let msg = format!(
+                    "relation {} has too many columns",
+                    full_name.to_string().quoted()
+                );
+                return Err(ErrorResponse::error(SqlState::TOO_MANY_COLUMNS, msg));
+            }
+            let relation = pgoutput::Relation {
+                oid: item.oid(),
+                namespace: full_name.schema.clone(),
+                name: full_name.item.clone(),
+                columns: desc
+                    .iter()
+                    .map(|(name, typ)| {
+                        let typ = mz_pgrepr::Type::from(&typ.scalar_type);
+                        pgoutput::Column {
+                            name: name.to_string(),
+                            type_oid: typ.oid(),
+                            type_mod: typ.typmod(),
+                        }
+                    })
+                    .collect(),
+            };
+            relations.push((
+                collection.global_id(),
+                mz_sql::normalize::unresolve(full_name),
+                desc,
+                relation,
+            ));
+        }
+        if relations.is_empty() {
+            return Err(ErrorResponse::error(
+                SqlState::INVALID_PARAMETER_VALUE,
+                "publications do not contain any relations",
+            ));
+        }
+        Ok(relations)
+    }
+
+    /// Sends the changes produced by the `SUBSCRIBE` behind a replication
+    /// stream to the client until either side ends the stream.
+    ///
+    /// Changes are buffered until the subscription reports that their
+    /// timestamp is complete, and then sent as one transaction per timestamp.
+    /// If streaming from the durable slot `slot`, the latest position the
+    /// client reports as flushed is confirmed to the slot every
+    /// [`REPLICATION_CONFIRM_INTERVAL`] and when the client ends the stream.
+    /// As in PostgreSQL, a stream that fails may leave the slot behind the
+    /// position its client flushed, and the client then receives those changes
+    /// again when it resumes.
+    async fn stream_changes(
+        &mut self,
+        mut stream: RecordFirstRowStream,
+        relations: &[pgoutput::Relation],
+        start: Lsn,
+        slot: Option<&str>,
+    ) -> Result<(State, StatementEndedExecutionReason), io::Error> {
+        self.send(BackendMessage::CopyBothResponse {
+            overall_format: Format::Binary,
+            column_formats: vec![],
+        })
+        .await?;
+        self.conn.flush().await?;
+
+        let mut pending: BTreeMap<u64, Vec<(usize, Vec<Option<String>>, i64)>> = BTreeMap::new();
+        let mut sent_relations = BTreeSet::new();
+        // All changes at or before this position have been sent.
+        let mut position = start;
+        // The position the client most recently reported as flushed.
+        let mut flushed = start;
+        // The position most recently confirmed to the slot.
+        let mut confirmed = start;
+        let mut xid: u32 = 0;
+        let mut result_size = 0;
+        let mut rows_returned = 0;
+        let mut keepalive = time::interval(REPLICATION_KEEPALIVE_INTERVAL);
+        let mut confirm = time::interval(REPLICATION_CONFIRM_INTERVAL);
+        loop {
+            select! {
+                message = self.conn.recv() => match message? {
+                    Some(FrontendMessage::CopyData(data)) => {
+                        match replication::decode_standby_status_update(&data) {
+                            // The client cannot have flushed changes that we
+                            // have not sent.
+                            Some(Ok(update)) => flushed = flushed.max(update.flush.min(position)),
+                            Some(Err(err)) => {
+                                return self
+                                    .error(err)
+                                    .await
+                                    .map(|state| (state, StatementEndedExecutionReason::Canceled));
+                            }
+                            None => {}
+                        }
+                    }
+                    Some(FrontendMessage::CopyDone) => break,
+                    Some(FrontendMessage::CopyFail(msg)) => {
+                        let msg = format!("replication stream failed: {msg}");
+                        return self
+                            .error(ErrorResponse::error(SqlState::QUERY_CANCELED, msg))
+                            .await
+                            .map(|state| (state, StatementEndedExecutionReason::Canceled));
+                    }
+                    Some(FrontendMessage::Terminate) | None => {
+                        return Ok((State::Done, StatementEndedExecutionReason::Canceled));
+                    }
+                    Some(message) => {
+                        let msg = format!("unexpected message type {} during replication", message.name());
+                        return self
+                            .error(ErrorResponse::fatal(SqlState::PROTOCOL_VIOLATION, msg))
+                            .await
+                            .map(|state| (state, StatementEndedExecutionReason::Canceled));
+                    }
+                },
+                batch = stream.recv() => match batch {
+                    // The subscription ends if one of its relations is dropped.
+                    None => break,
+                    Some(PeekResponseUnary::Error(text)) => {
+                        return self
+                            .error(ErrorResponse::error(SqlState::INTERNAL_ERROR, text.clone()))
+                            .await
+                            .map(|state| (state, StatementEndedExecutionReason::Errored { error: text }));
+                    }
+                    Some(PeekResponseUnary::Canceled) => {
+                        return self
+                            .error(ErrorResponse::error(
+                                SqlState::QUERY_CANCELED,
+                                "canceling statement due to user request",
+                            ))
+                            .await
+                            .map(|state| (state, StatementEndedExecutionReason::Canceled));
+                    }
+                    Some(PeekResponseUnary::Rows(mut rows)) => {
+                        while let Some(row) = rows.next() {
+                            result_size += row.byte_len();
+                            rows_returned += 1;
+                            match SubscribeRow::decode(row) {
+                                SubscribeRow::Change {
+                                    timestamp,
+                                    relation,
+                                    values,
+                                    diff,
+                                } => pending
+                                    .entry(timestamp)
+                                    .or_default()
+                                    .push((relation, values, diff)),
+                                SubscribeRow::Progress(upper) => {
+                                    let later = pending.split_off(&upper);
+                                    for (timestamp, changes) in mem::replace(&mut pending, later) {
+                                        xid = xid.wrapping_add(1);
+                                        self.send_replication_transaction(
+                                            Lsn(timestamp),
+                                            xid,
+                                            changes,
+                                            relations,
+                                            &mut sent_relations,
+                                        )
+                                        .await?;
+                                    }
+                                    position = position.max(Lsn(upper.saturating_sub(1)));
+                                }
+                            }
+                        }
+                    }
+                },
+                _ = keepalive.tick() => {
+                    let send_time = replication::pg_timestamp(self.current_lsn().0);
+                    self.send(BackendMessage::CopyData(replication::encode_keepalive(
+                        position, send_time,
+                    )))
+                    .await?;
+                }
+                _ = confirm.tick() => {
+                    let result = self
+                        .confirm_replication_position(slot, flushed, &mut confirmed)
+                        .await;
+                    if let Err(err) = result {
+                        return self
+                            .error(err)
+                            .await
+                            .map(|state| (state, StatementEndedExecutionReason::Canceled));
+                    }
+                }
+            }
+            self.conn.flush().await?;
+        }
+
+        let result = self
+            .confirm_replication_position(slot, flushed, &mut confirmed)
+            .await;
+        if let Err(err) = result {
+            return self
+                .error(err)
+                .await
+                .map(|state| (state, StatementEndedExecutionReason::Canceled));
+        }
+        self.send(BackendMessage::CopyDone).await?;
+        self.send(BackendMessage::CommandComplete {
+            tag: "START_REPLICATION".into(),
+        })
+        .await?;
+        Ok((
+            State::Ready,
+            StatementEndedExecutionReason::Success {
+                result_size: Some(u64::cast_from(result_size)),
+                rows_returned: Some(rows_returned),
+                execution_strategy: None,
+            },
+        ))
+    }
+
+    /// If streaming from the durable slot `slot`, confirms the position
+    /// `flush` that the client has reported as flushed to the slot, and
+    /// records it in `confirmed`.
+    async fn confirm_replication_position(
+        &mut self,
+        slot: Option<&str>,
+        flush: Lsn,
+        confirmed: &mut Lsn,
+    ) -> Result<(), ErrorResponse> {
+        let Some(slot) = slot else {
+            return Ok(());
+        };
+        if flush > *confirmed {
+            let request = ReplicationSlotRequest::Confirm {
+                name: slot.to_string(),
+                lsn: Timestamp::from(flush.0),
+            };
+            self.adapter_client
+                .replication_slot(request)
+                .await
+                .map_err(|e| e.into_response(Severity::Error))?;
+            *confirmed = flush;
+        }
+        Ok(())
+    }
+
+    /// Sends the changes at one timestamp as a transaction, preceded by the
+    /// description of any relation the client has not yet seen.
+    async fn send_replication_transaction(
+        &mut self,
+        lsn: Lsn,
+        xid: u32,
+        mut changes: Vec<(usize, Vec<Option<String>>, i64)>,
+        relations: &[pgoutput::Relation],
+        sent_relations: &mut BTreeSet<usize>,
+    ) -> Result<(), io::Error> {
+        // Deletes sort before inserts, so that an updated row is never
+        // present twice.
+        changes.sort_by_key(|(_, _, diff)| *diff > 0);
+        let commit_time = replication::pg_timestamp(lsn.0);
+        let send_time = replication::pg_timestamp(self.current_lsn().0);
+        let mut messages = vec![pgoutput::begin(lsn, commit_time, xid)];
+        for (index, values, diff) in changes {
+            let relation = &relations[index];
+            if sent_relations.insert(index) {
+                messages.push(pgoutput::relation(relation));
+            }
+            let values = values.iter().map(|v| v.as_deref()).collect::<Vec<_>>();
+            let message = if diff > 0 {
+                pgoutput::insert(relation.oid, &values)
+            } else {
+                pgoutput::delete(relation.oid, &values)
+            };
+            for _ in 0..diff.unsigned_abs() {
+                messages.push(message.clone());
+            }
+        }
+        messages.push(pgoutput::commit(lsn, lsn, commit_time));
+        for message in messages {
+            self.send(BackendMessage::CopyData(replication::encode_xlog_data(
+                lsn, lsn, send_time, &message,
+            )))
+            .await?;
+        }
+        Ok(())
+    }
+
     async fn send_pending_notices(&mut self) -> Result<(), io::Error> {
         let notices = self
             .adapter_client
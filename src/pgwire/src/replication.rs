@@ -0,0 +1,825 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Support for PostgreSQL's logical streaming replication protocol.
+//!
+//! Connections that pass `replication=database` in their startup message may
+//! issue the replication commands parsed by [`parse_command`] in addition to
+//! regular SQL. `START_REPLICATION` streams the changes to the relations in a
+//! publication as [`pgoutput`] messages, each wrapped in the `XLogData` frame
+//! of the streaming replication protocol.
+//!
+//! Materialize has no write-ahead log, so log sequence numbers are simply
+//! Materialize timestamps: every change that happens at timestamp `t` is
+//! reported in a transaction whose commit LSN is `t`. Replication slots are
+//! durable, and record the LSN up to which their consumer has confirmed
+//! changes in standby status updates. The coordinator holds back compaction
+//! of the published collections at that LSN, so `START_REPLICATION` can
+//! stream every change after it, even across restarts. Temporary slots only
+//! live as long as the connection that created them, and do not hold back
+//! compaction.
+//!
+//! # Useful references
+//!
+//!   * [Streaming replication protocol](https://www.postgresql.org/docs/current/protocol-replication.html)
+//!   * [Logical replication message formats](https://www.postgresql.org/docs/current/protocol-logicalrep-message-formats.html)
+//!
+//! [`pgoutput`]: https://www.postgresql.org/docs/current/protocol-logical-replication.html
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use bytes::{Buf, BufMut};
+use itertools::Itertools;
+use mz_pgwire_common::ErrorResponse;
+use mz_repr::{Datum, RelationDesc, RowRef, Timestamp};
+use mz_sql::ast::display::AstDisplay;
+use mz_sql::ast::{Ident, UnresolvedItemName};
+use postgres::error::SqlState;
+
+/// The name of the only output plugin we support.
+pub const PGOUTPUT: &str = "pgoutput";
+
+/// Milliseconds between the Unix epoch and the PostgreSQL epoch (2000-01-01).
+const PG_EPOCH_OFFSET_MILLIS: u64 = 946_684_800_000;
+
+/// A PostgreSQL log sequence number, which Materialize equates with a
+/// timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Lsn(pub u64);
+
+impl fmt::Display for Lsn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:X}/{:X}", self.0 >> 32, self.0 & 0xFFFF_FFFF)
+    }
+}
+
+impl FromStr for Lsn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Lsn, String> {
+        let err = || format!("invalid LSN: {s}");
+        let (hi, lo) = s.split_once('/').ok_or_else(err)?;
+        let hi = u32::from_str_radix(hi, 16).map_err(|_| err())?;
+        let lo = u32::from_str_radix(lo, 16).map_err(|_| err())?;
+        Ok(Lsn((u64::from(hi) << 32) | u64::from(lo)))
+    }
+}
+
+/// A command that is only valid on a replication connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplicationCommand {
+    /// `IDENTIFY_SYSTEM`
+    IdentifySystem,
+    /// `CREATE_REPLICATION_SLOT <name> [TEMPORARY] LOGICAL <plugin> ...`
+    CreateReplicationSlot {
+        name: String,
+        plugin: String,
+        temporary: bool,
+    },
+    /// `DROP_REPLICATION_SLOT <name> [WAIT]`
+    DropReplicationSlot { name: String },
+    /// `START_REPLICATION SLOT <name> LOGICAL <lsn> [(<option> [, ...])]`
+    StartReplication {
+        slot: String,
+        lsn: Lsn,
+        options: BTreeMap<String, String>,
+    },
+}
+
+/// Parses a replication command.
+///
+/// Returns `None` if `sql` does not start with a replication command, in
+/// which case it should be handled as a regular SQL query.
+pub fn parse_command(sql: &str) -> Option<Result<ReplicationCommand, ErrorResponse>> {
+    let keyword = sql
+        .trim_start()
+        .split(|c: char| c.is_whitespace() || c == ';' || c == '(')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_uppercase();
+    match keyword.as_str() {
+        "IDENTIFY_SYSTEM"
+        | "CREATE_REPLICATION_SLOT"
+        | "DROP_REPLICATION_SLOT"
+        | "START_REPLICATION" => {}
+        "ALTER_REPLICATION_SLOT"
+        | "READ_REPLICATION_SLOT"
+        | "TIMELINE_HISTORY"
+        | "BASE_BACKUP"
+        | "UPLOAD_MANIFEST" => {
+            return Some(Err(unsupported(format!("{keyword} is not supported"))));
+        }
+        _ => return None,
+    }
+    Some(tokenize(sql).and_then(|tokens| Parser { tokens, pos: 0 }.parse_command()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// An unquoted word, which may be a keyword, identifier, or LSN.
+    Word(String),
+    /// A double-quoted identifier.
+    Quoted(String),
+    /// A single-quoted string literal.
+    String(String),
+    LParen,
+    RParen,
+    Comma,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Word(word) => f.write_str(word),
+            Token::Quoted(ident) => write!(f, "\"{ident}\""),
+            Token::String(s) => write!(f, "'{s}'"),
+            Token::LParen => f.write_str("("),
+            Token::RParen => f.write_str(")"),
+            Token::Comma => f.write_str(","),
+        }
+    }
+}
+
+fn tokenize(sql: &str) -> Result<Vec<Token>, ErrorResponse> {
+    let mut tokens = vec![];
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            // A trailing semicolon is permitted, as in PostgreSQL.
+            ';' => {
+                if chars.any(|c| !c.is_whitespace()) {
+                    return Err(syntax_error("replication commands cannot be combined"));
+                }
+            }
+            '(' => tokens.push(Token::LParen),
+            ')' => tokens.push(Token::RParen),
+            ',' => tokens.push(Token::Comma),
+            '\'' | '"' => {
+                let mut s = String::new();
+                loop {
+                    match chars.next() {
+                        // A doubled quote is an escaped quote.
+                        Some(q) if q == c && chars.peek() == Some(&c) => {
+                            chars.next();
+                            s.push(c);
+                        }
+                        Some(q) if q == c => break,
+                        Some(q) => s.push(q),
+                        None => return Err(syntax_error("unterminated quoted string")),
+                    }
+                }
+                tokens.push(if c == '\'' {
+                    Token::String(s)
+                } else {
+                    Token::Quoted(s)
+                });
+            }
+            c => {
+                let mut s = String::from(c);
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || matches!(c, ';' | '(' | ')' | ',' | '\'' | '"') {
+                        break;
+                    }
+                    s.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Word(s));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn syntax_error(message: impl Into<String>) -> ErrorResponse {
+    ErrorResponse::error(SqlState::SYNTAX_ERROR, message)
+}
+
+fn unsupported(message: impl Into<String>) -> ErrorResponse {
+    ErrorResponse::error(SqlState::FEATURE_NOT_SUPPORTED, message)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn parse_command(mut self) -> Result<ReplicationCommand, ErrorResponse> {
+        let command = match self.next_word()?.to_ascii_uppercase().as_str() {
+            "IDENTIFY_SYSTEM" => ReplicationCommand::IdentifySystem,
+            "CREATE_REPLICATION_SLOT" => self.parse_create_replication_slot()?,
+            "DROP_REPLICATION_SLOT" => {
+                let name = self.parse_ident()?;
+                // Slots are dropped immediately, even if another connection is
+                // streaming from them, so there is nothing to wait for.
+                self.accept_keyword("WAIT");
+                ReplicationCommand::DropReplicationSlot { name }
+            }
+            "START_REPLICATION" => self.parse_start_replication()?,
+            _ => unreachable!("checked by parse_command"),
+        };
+        match self.tokens.get(self.pos) {
+            None => Ok(command),
+            Some(token) => Err(syntax_error(format!("syntax error at or near {token}"))),
+        }
+    }
+
+    fn parse_create_replication_slot(&mut self) -> Result<ReplicationCommand, ErrorResponse> {
+        let name = self.parse_ident()?;
+        let temporary = self.accept_keyword("TEMPORARY");
+        self.expect_logical()?;
+        let plugin = self.parse_ident()?;
+        // Snapshot options are accepted for compatibility but have no effect,
+        // as slots never export a snapshot.
+        if self.tokens.get(self.pos) == Some(&Token::LParen) {
+            self.parse_options()?;
+        } else {
+            while [
+                "EXPORT_SNAPSHOT",
+                "NOEXPORT_SNAPSHOT",
+                "USE_SNAPSHOT",
+                "TWO_PHASE",
+            ]
+            .iter()
+            .any(|kw| self.accept_keyword(kw))
+            {}
+        }
+        Ok(ReplicationCommand::CreateReplicationSlot {
+            name,
+            plugin,
+            temporary,
+        })
+    }
+
+    fn parse_start_replication(&mut self) -> Result<ReplicationCommand, ErrorResponse> {
+        if !self.accept_keyword("SLOT") {
+            return Err(unsupported("physical replication is not supported"));
+        }
+        let slot = self.parse_ident()?;
+        self.expect_logical()?;
+        let lsn = self.next_word()?.parse().map_err(syntax_error)?;
+        let options = if self.tokens.get(self.pos) == Some(&Token::LParen) {
+            self.parse_options()?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(ReplicationCommand::StartReplication { slot, lsn, options })
+    }
+
+    /// Parses a parenthesized, comma-separated list of options, each of which
+    /// is a name optionally followed by a value.
+    fn parse_options(&mut self) -> Result<BTreeMap<String, String>, ErrorResponse> {
+        self.expect(Token::LParen)?;
+        let mut options = BTreeMap::new();
+        loop {
+            let name = self.parse_ident()?;
+            let value = match self.tokens.get(self.pos) {
+                Some(Token::Word(s) | Token::Quoted(s) | Token::String(s)) => {
+                    let value = s.clone();
+                    self.pos += 1;
+                    value
+                }
+                _ => String::new(),
+            };
+            options.insert(name, value);
+            match self.next()? {
+                Token::Comma => continue,
+                Token::RParen => break,
+                token => return Err(syntax_error(format!("syntax error at or near {token}"))),
+            }
+        }
+        Ok(options)
+    }
+
+    fn expect_logical(&mut self) -> Result<(), ErrorResponse> {
+        match self.next_word()?.to_ascii_uppercase().as_str() {
+            "LOGICAL" => Ok(()),
+            "PHYSICAL" => Err(unsupported("physical replication is not supported")),
+            word => Err(syntax_error(format!("syntax error at or near {word}"))),
+        }
+    }
+
+    fn next(&mut self) -> Result<Token, ErrorResponse> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| syntax_error("unexpected end of replication command"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn next_word(&mut self) -> Result<String, ErrorResponse> {
+        match self.next()? {
+            Token::Word(word) => Ok(word),
+            token => Err(syntax_error(format!("syntax error at or near {token}"))),
+        }
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), ErrorResponse> {
+        match self.next()? {
+            token if token == expected => Ok(()),
+            token => Err(syntax_error(format!("syntax error at or near {token}"))),
+        }
+    }
+
+    fn accept_keyword(&mut self, keyword: &str) -> bool {
+        match self.tokens.get(self.pos) {
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Parses an identifier, folding it to lowercase unless it is quoted.
+    fn parse_ident(&mut self) -> Result<String, ErrorResponse> {
+        match self.next()? {
+            Token::Word(word) => Ok(word.to_lowercase()),
+            Token::Quoted(ident) => Ok(ident),
+            token => Err(syntax_error(format!("syntax error at or near {token}"))),
+        }
+    }
+}
+
+/// Parses a comma-separated list of identifiers, like the value of the
+/// `publication_names` option to `START_REPLICATION`.
+pub fn parse_identifier_list(s: &str) -> Result<Vec<String>, ErrorResponse> {
+    let mut parser = Parser {
+        tokens: tokenize(s)?,
+        pos: 0,
+    };
+    let mut idents = vec![parser.parse_ident()?];
+    while parser.pos < parser.tokens.len() {
+        parser.expect(Token::Comma)?;
+        idents.push(parser.parse_ident()?);
+    }
+    Ok(idents)
+}
+
+/// Builds the `SUBSCRIBE` statement that streams changes to `relations`
+/// after `lsn`, or their entire contents followed by all changes if `lsn` is
+/// zero.
+///
+/// Each row is projected to the index of its relation in `relations` and an
+/// array of its values in text format, so that relations of differing types
+/// can be combined in one subscription.
+pub fn subscribe_sql(relations: &[(UnresolvedItemName, RelationDesc)], lsn: Lsn) -> String {
+    let selects = relations
+        .iter()
+        .enumerate()
+        .map(|(i, (name, desc))| {
+            let values = if desc.arity() == 0 {
+                "'{}'::text[]".to_string()
+            } else {
+                let columns = desc
+                    .iter_names()
+                    .map(|column| {
+                        let column = Ident::new_unchecked(column.as_str());
+                        format!("{}::text", column.to_ast_string_stable())
+                    })
+                    .join(", ");
+                format!("ARRAY[{columns}]")
+            };
+            format!(
+                "SELECT {i}::int4, {values} FROM {}",
+                name.to_ast_string_stable()
+            )
+        })
+        .join(" UNION ALL ");
+    if lsn == Lsn(0) {
+        format!("SUBSCRIBE ({selects}) WITH (PROGRESS)")
+    } else {
+        format!(
+            "SUBSCRIBE ({selects}) WITH (PROGRESS, SNAPSHOT = false) AS OF {}",
+            lsn.0
+        )
+    }
+}
+
+/// A row produced by the statement built by [`subscribe_sql`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscribeRow {
+    /// All changes at timestamps before the given timestamp have been
+    /// produced.
+    Progress(u64),
+    /// A change to the relation at the given index.
+    Change {
+        timestamp: u64,
+        relation: usize,
+        values: Vec<Option<String>>,
+        diff: i64,
+    },
+}
+
+impl SubscribeRow {
+    pub fn decode(row: &RowRef) -> SubscribeRow {
+        let mut datums = row.iter();
+        let mut next = || datums.next().expect("SUBSCRIBE row has five columns");
+        let timestamp = Timestamp::try_from(next().unwrap_numeric().0)
+            .expect("mz_timestamp is a valid timestamp")
+            .into();
+        if next().unwrap_bool() {
+            return SubscribeRow::Progress(timestamp);
+        }
+        let diff = next().unwrap_int64();
+        let relation = usize::try_from(next().unwrap_int32()).expect("index is non-negative");
+        let values = next()
+            .unwrap_array()
+            .elements()
+            .iter()
+            .map(|datum| match datum {
+                Datum::Null => None,
+                datum => Some(datum.unwrap_str().to_string()),
+            })
+            .collect();
+        SubscribeRow::Change {
+            timestamp,
+            relation,
+            values,
+            diff,
+        }
+    }
+}
+
+/// Converts milliseconds since the Unix epoch to the microseconds since the
+/// PostgreSQL epoch used throughout the replication protocol.
+pub fn pg_timestamp(epoch_millis: u64) -> i64 {
+    let micros = epoch_millis
+        .saturating_sub(PG_EPOCH_OFFSET_MILLIS)
+        .saturating_mul(1000);
+    i64::try_from(micros).unwrap_or(i64::MAX)
+}
+
+/// Wraps a logical replication message in an `XLogData` frame.
+pub fn encode_xlog_data(wal_start: Lsn, wal_end: Lsn, send_time: i64, payload: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(25 + payload.len());
+    buf.put_u8(b'w');
+    buf.put_u64(wal_start.0);
+    buf.put_u64(wal_end.0);
+    buf.put_i64(send_time);
+    buf.put_slice(payload);
+    buf
+}
+
+/// The positions reported by a standby status update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StandbyStatusUpdate {
+    /// The position up to which the client has received changes.
+    pub write: Lsn,
+    /// The position up to which the client has durably stored changes.
+    pub flush: Lsn,
+    /// The position up to which the client has applied changes.
+    pub apply: Lsn,
+}
+
+/// Decodes a message sent by the client during replication.
+///
+/// Returns `None` for messages other than standby status updates, which
+/// carry no information that we use.
+pub fn decode_standby_status_update(
+    mut buf: &[u8],
+) -> Option<Result<StandbyStatusUpdate, ErrorResponse>> {
+    if buf.first() != Some(&b'r') {
+        return None;
+    }
+    buf.advance(1);
+    // Three positions, the client's clock, and whether a reply is requested.
+    if buf.len() != 8 + 8 + 8 + 8 + 1 {
+        return Some(Err(ErrorResponse::fatal(
+            SqlState::PROTOCOL_VIOLATION,
+            "invalid standby status update",
+        )));
+    }
+    Some(Ok(StandbyStatusUpdate {
+        write: Lsn(buf.get_u64()),
+        flush: Lsn(buf.get_u64()),
+        apply: Lsn(buf.get_u64()),
+    }))
+}
+
+/// Encodes a primary keepalive message.
+pub fn encode_keepalive(wal_end: Lsn, send_time: i64) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(18);
+    buf.put_u8(b'k');
+    buf.put_u64(wal_end.0);
+    buf.put_i64(send_time);
+    // We never need the client to reply immediately.
+    buf.put_u8(0);
+    buf
+}
+
+/// Encoders for version 1 of the `pgoutput` logical replication messages.
+pub mod pgoutput {
+    use bytes::BufMut;
+
+    use super::Lsn;
+
+    /// A column of a [`Relation`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Column {
+        pub name: String,
+        pub type_oid: u32,
+        pub type_mod: i32,
+    }
+
+    /// Describes a published relation to the consumer.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Relation {
+        pub oid: u32,
+        pub namespace: String,
+        pub name: String,
+        pub columns: Vec<Column>,
+    }
+
+    pub fn begin(final_lsn: Lsn, commit_time: i64, xid: u32) -> Vec<u8> {
+        let mut buf = vec![b'B'];
+        buf.put_u64(final_lsn.0);
+        buf.put_i64(commit_time);
+        buf.put_u32(xid);
+        buf
+    }
+
+    pub fn commit(commit_lsn: Lsn, end_lsn: Lsn, commit_time: i64) -> Vec<u8> {
+        let mut buf = vec![b'C'];
+        // Flags, which are currently unused.
+        buf.put_u8(0);
+        buf.put_u64(commit_lsn.0);
+        buf.put_u64(end_lsn.0);
+        buf.put_i64(commit_time);
+        buf
+    }
+
+    /// Encodes a relation message.
+    ///
+    /// Materialize relations have no primary keys, so the replica identity is
+    /// always `FULL` and no column is flagged as part of the key.
+    pub fn relation(relation: &Relation) -> Vec<u8> {
+        let mut buf = vec![b'R'];
+        buf.put_u32(relation.oid);
+        put_cstr(&mut buf, &relation.namespace);
+        put_cstr(&mut buf, &relation.name);
+        buf.put_u8(b'f');
+        buf.put_i16(
+            i16::try_from(relation.columns.len()).expect("column count validated by caller"),
+        );
+        for column in &relation.columns {
+            buf.put_u8(0);
+            put_cstr(&mut buf, &column.name);
+            buf.put_u32(column.type_oid);
+            buf.put_i32(column.type_mod);
+        }
+        buf
+    }
+
+    /// Encodes an insert of a tuple whose values are in text format.
+    pub fn insert(relation_oid: u32, values: &[Option<&str>]) -> Vec<u8> {
+        let mut buf = vec![b'I'];
+        buf.put_u32(relation_oid);
+        buf.put_u8(b'N');
+        put_tuple(&mut buf, values);
+        buf
+    }
+
+    /// Encodes a delete of a tuple whose values are in text format.
+    ///
+    /// As the replica identity is `FULL`, the entire old tuple is sent.
+    pub fn delete(relation_oid: u32, values: &[Option<&str>]) -> Vec<u8> {
+        let mut buf = vec![b'D'];
+        buf.put_u32(relation_oid);
+        buf.put_u8(b'O');
+        put_tuple(&mut buf, values);
+        buf
+    }
+
+    fn put_tuple(buf: &mut Vec<u8>, values: &[Option<&str>]) {
+        buf.put_i16(i16::try_from(values.len()).expect("column count validated by caller"));
+        for value in values {
+            match value {
+                None => buf.put_u8(b'n'),
+                Some(value) => {
+                    buf.put_u8(b't');
+                    buf.put_i32(i32::try_from(value.len()).expect("value smaller than 2GiB"));
+                    buf.put_slice(value.as_bytes());
+                }
+            }
+        }
+    }
+
+    fn put_cstr(buf: &mut Vec<u8>, s: &str) {
+        buf.put_slice(s.as_bytes());
+        buf.put_u8(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_ore::assert_none;
+    use mz_repr::ScalarType;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_lsn() {
+        for (s, lsn) in [("0/0", 0), ("0/16B3748", 0x16B3748), ("1/0", 1 << 32)] {
+            assert_eq!(s.parse::<Lsn>(), Ok(Lsn(lsn)));
+            assert_eq!(Lsn(lsn).to_string(), s);
+        }
+        assert_eq!("0/16b3748".parse::<Lsn>(), Ok(Lsn(0x16B3748)));
+        assert!("16B3748".parse::<Lsn>().is_err());
+        assert!("0/1/2".parse::<Lsn>().is_err());
+        assert!("100000000/0".parse::<Lsn>().is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_parse_command() {
+        let parse = |sql| {
+            parse_command(sql)
+                .expect("is a replication command")
+                .map_err(|e| e.message)
+        };
+
+        assert_none!(parse_command("SELECT 1"));
+        assert_none!(parse_command("show wal_level"));
+        assert_eq!(
+            parse("identify_system;"),
+            Ok(ReplicationCommand::IdentifySystem)
+        );
+        assert_eq!(
+            parse(
+                r#"CREATE_REPLICATION_SLOT "Slot" TEMPORARY LOGICAL pgoutput (SNAPSHOT 'nothing')"#
+            ),
+            Ok(ReplicationCommand::CreateReplicationSlot {
+                name: "Slot".into(),
+                plugin: "pgoutput".into(),
+                temporary: true,
+            })
+        );
+        assert_eq!(
+            parse("CREATE_REPLICATION_SLOT s LOGICAL pgoutput NOEXPORT_SNAPSHOT"),
+            Ok(ReplicationCommand::CreateReplicationSlot {
+                name: "s".into(),
+                plugin: "pgoutput".into(),
+                temporary: false,
+            })
+        );
+        assert_eq!(
+            parse("DROP_REPLICATION_SLOT S WAIT"),
+            Ok(ReplicationCommand::DropReplicationSlot { name: "s".into() })
+        );
+        assert_eq!(
+            parse(
+                r#"START_REPLICATION SLOT s LOGICAL 0/A ("proto_version" '1', publication_names '"p1",p2', binary)"#
+            ),
+            Ok(ReplicationCommand::StartReplication {
+                slot: "s".into(),
+                lsn: Lsn(10),
+                options: [
+                    ("binary", ""),
+                    ("proto_version", "1"),
+                    ("publication_names", "\"p1\",p2"),
+                ]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            })
+        );
+
+        for (sql, err) in [
+            ("BASE_BACKUP", "BASE_BACKUP is not supported"),
+            (
+                "CREATE_REPLICATION_SLOT s PHYSICAL",
+                "physical replication is not supported",
+            ),
+            (
+                "START_REPLICATION 0/0",
+                "physical replication is not supported",
+            ),
+            ("START_REPLICATION SLOT s LOGICAL 0", "invalid LSN: 0"),
+            (
+                "START_REPLICATION SLOT s LOGICAL 0/0 (proto_version '1'",
+                "unexpected end of replication command",
+            ),
+            ("DROP_REPLICATION_SLOT 'foo", "unterminated quoted string"),
+            (
+                "IDENTIFY_SYSTEM; SELECT 1",
+                "replication commands cannot be combined",
+            ),
+        ] {
+            assert_eq!(parse(sql), Err(err.to_string()), "sql: {sql}");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_parse_identifier_list() {
+        assert_eq!(
+            parse_identifier_list(r#"a, "B",c"#).map_err(|e| e.message),
+            Ok(vec!["a".into(), "B".into(), "c".into()])
+        );
+        assert!(parse_identifier_list("").is_err());
+        assert!(parse_identifier_list("a b").is_err());
+        assert!(parse_identifier_list("a,").is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_subscribe_sql() {
+        let relations = [
+            (
+                UnresolvedItemName::qualified(&[
+                    Ident::new_unchecked("materialize"),
+                    Ident::new_unchecked("public"),
+                    Ident::new_unchecked("T"),
+                ]),
+                RelationDesc::builder()
+                    .with_column("a", ScalarType::Int32.nullable(true))
+                    .with_column("b", ScalarType::String.nullable(false))
+                    .finish(),
+            ),
+            (
+                UnresolvedItemName::unqualified(Ident::new_unchecked("empty")),
+                RelationDesc::empty(),
+            ),
+        ];
+        assert_eq!(
+            subscribe_sql(&relations, Lsn(0)),
+            "SUBSCRIBE (SELECT 0::int4, ARRAY[\"a\"::text, \"b\"::text] \
+             FROM \"materialize\".\"public\".\"T\" \
+             UNION ALL SELECT 1::int4, '{}'::text[] FROM \"empty\") WITH (PROGRESS)"
+        );
+        assert_eq!(
+            subscribe_sql(&relations[1..], Lsn(42)),
+            "SUBSCRIBE (SELECT 0::int4, '{}'::text[] FROM \"empty\") \
+             WITH (PROGRESS, SNAPSHOT = false) AS OF 42"
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_decode_standby_status_update() {
+        let mut update = vec![b'r'];
+        for lsn in [3u64, 2, 1, 0] {
+            update.extend(lsn.to_be_bytes());
+        }
+        update.push(0);
+        assert_eq!(
+            decode_standby_status_update(&update).map(|r| r.map_err(|e| e.message)),
+            Some(Ok(StandbyStatusUpdate {
+                write: Lsn(3),
+                flush: Lsn(2),
+                apply: Lsn(1),
+            }))
+        );
+        assert_eq!(
+            decode_standby_status_update(&update[..10]).map(|r| r.map_err(|e| e.message)),
+            Some(Err("invalid standby status update".to_string()))
+        );
+        // Hot standby feedback only applies to physical replication.
+        assert_none!(decode_standby_status_update(b"h"));
+    }
+
+    #[mz_ore::test]
+    fn test_pg_timestamp() {
+        assert_eq!(pg_timestamp(0), 0);
+        assert_eq!(pg_timestamp(PG_EPOCH_OFFSET_MILLIS + 1), 1000);
+        assert_eq!(pg_timestamp(u64::MAX), i64::MAX);
+    }
+
+    #[mz_ore::test]
+    fn test_pgoutput() {
+        let relation = pgoutput::Relation {
+            oid: 20_000,
+            namespace: "public".into(),
+            name: "t".into(),
+            columns: vec![pgoutput::Column {
+                name: "a".into(),
+                type_oid: 23,
+                type_mod: -1,
+            }],
+        };
+        assert_eq!(
+            pgoutput::relation(&relation),
+            b"R\0\0\x4e\x20public\0t\0f\0\x01\0a\0\0\0\0\x17\xff\xff\xff\xff"
+        );
+        assert_eq!(
+            pgoutput::insert(20_000, &[Some("42"), None]),
+            b"I\0\0\x4e\x20N\0\x02t\0\0\0\x0242n"
+        );
+        assert_eq!(
+            pgoutput::delete(20_000, &[Some("")]),
+            b"D\0\0\x4e\x20O\0\x01t\0\0\0\0"
+        );
+        assert_eq!(
+            encode_xlog_data(Lsn(1), Lsn(2), 3, b"x"),
+            b"w\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\x03x"
+        );
+    }
+}
@@ -28,6 +28,7 @@ fn main() {
                 "repr/src/adt/regex.proto",
                 "repr/src/adt/timestamp.proto",
                 "repr/src/adt/varchar.proto",
+                "repr/src/adt/vector.proto",
                 "repr/src/antichain.proto",
                 "repr/src/catalog_item_id.proto",
                 "repr/src/global_id.proto",
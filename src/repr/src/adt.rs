@@ -21,6 +21,7 @@ pub mod array;
 pub mod char;
 pub mod date;
 pub mod datetime;
+pub mod geometry;
 pub mod interval;
 pub mod jsonb;
 pub mod mz_acl_item;
@@ -29,5 +30,7 @@ pub mod pg_legacy_name;
 pub mod range;
 pub mod regex;
 pub mod system;
+pub mod text_search;
 pub mod timestamp;
 pub mod varchar;
+pub mod vector;
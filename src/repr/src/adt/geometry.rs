@@ -0,0 +1,1634 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Planar geometries, e.g. for storing locations and geofences.
+//!
+//! The type, its text and binary formats, and the semantics of its functions
+//! follow the [PostGIS](https://postgis.net) `geometry` type, restricted to
+//! two dimensions. Geometries are stored in their canonical extended
+//! well-known binary (EWKB) form: little endian, with an SRID only if it is
+//! known.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use mz_ore::cast::CastLossy;
+
+/// The maximum precision of a geohash.
+pub const MAX_GEOHASH_PRECISION: usize = 20;
+
+/// The maximum number of cells [`Geometry::geohash_cells`] returns.
+pub const MAX_GEOHASH_CELLS: usize = 10_000;
+
+/// The maximum depth to which geometry collections can be nested.
+///
+/// Geometries are parsed, validated, and formatted recursively, so this bounds
+/// the stack those take.
+pub const MAX_COLLECTION_DEPTH: usize = 32;
+
+/// The number of levels of the grids that [`Geometry::grid_cover`] and
+/// [`Geometry::grid_anchor`] use, not counting the final level, whose single
+/// cell covers the whole plane.
+pub const GRID_LEVELS: i64 = 21;
+
+/// The cells at level `l` are squares with sides of length
+/// `2^(GRID_MIN_EXPONENT + GRID_LEVEL_STEP * l)`, i.e. from about a micrometer
+/// or a millionth of a degree up to about 10^12.
+const GRID_MIN_EXPONENT: i32 = -20;
+const GRID_LEVEL_STEP: i32 = 3;
+
+/// The maximum number of cells per axis that [`Geometry::grid_cover`] returns.
+const GRID_MAX_COVER_SPAN: i64 = 4;
+
+const GEOHASH_BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+const WKB_GEOMETRYCOLLECTION: u32 = 7;
+
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// A position in the plane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coord {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Coord {
+    fn distance(self, other: Coord) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+
+    fn midpoint(self, other: Coord) -> Coord {
+        Coord {
+            x: (self.x + other.x) / 2.0,
+            y: (self.y + other.y) / 2.0,
+        }
+    }
+}
+
+/// The shape of a [`Geometry`].
+///
+/// Line strings have at least two points, and the rings of polygons are
+/// closed and have at least four points. A line string, polygon, or
+/// collection without any points is empty.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    Point(Coord),
+    LineString(Vec<Coord>),
+    Polygon(Vec<Vec<Coord>>),
+    MultiPoint(Vec<Coord>),
+    MultiLineString(Vec<Vec<Coord>>),
+    MultiPolygon(Vec<Vec<Vec<Coord>>>),
+    GeometryCollection(Vec<Shape>),
+}
+
+impl Shape {
+    /// Returns the name of the shape's type, e.g. `Point`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Shape::Point(_) => "Point",
+            Shape::LineString(_) => "LineString",
+            Shape::Polygon(_) => "Polygon",
+            Shape::MultiPoint(_) => "MultiPoint",
+            Shape::MultiLineString(_) => "MultiLineString",
+            Shape::MultiPolygon(_) => "MultiPolygon",
+            Shape::GeometryCollection(_) => "GeometryCollection",
+        }
+    }
+
+    fn wkb_type(&self) -> u32 {
+        match self {
+            Shape::Point(_) => WKB_POINT,
+            Shape::LineString(_) => WKB_LINESTRING,
+            Shape::Polygon(_) => WKB_POLYGON,
+            Shape::MultiPoint(_) => WKB_MULTIPOINT,
+            Shape::MultiLineString(_) => WKB_MULTILINESTRING,
+            Shape::MultiPolygon(_) => WKB_MULTIPOLYGON,
+            Shape::GeometryCollection(_) => WKB_GEOMETRYCOLLECTION,
+        }
+    }
+
+    /// Reports whether the shape contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.coords().next().is_none()
+    }
+
+    /// Returns an iterator over all of the shape's points.
+    pub fn coords(&self) -> Box<dyn Iterator<Item = Coord> + '_> {
+        match self {
+            Shape::Point(c) => Box::new(std::iter::once(*c)),
+            Shape::LineString(cs) | Shape::MultiPoint(cs) => Box::new(cs.iter().copied()),
+            Shape::Polygon(rings) | Shape::MultiLineString(rings) => {
+                Box::new(rings.iter().flatten().copied())
+            }
+            Shape::MultiPolygon(polys) => Box::new(polys.iter().flatten().flatten().copied()),
+            Shape::GeometryCollection(shapes) => Box::new(shapes.iter().flat_map(|s| s.coords())),
+        }
+    }
+
+    /// Decomposes the shape into its non-empty points, line strings, and
+    /// polygons.
+    fn primitives(&self) -> Vec<Primitive<'_>> {
+        let mut out = vec![];
+        self.push_primitives(&mut out);
+        out
+    }
+
+    fn push_primitives<'a>(&'a self, out: &mut Vec<Primitive<'a>>) {
+        match self {
+            Shape::Point(c) => out.push(Primitive::Point(*c)),
+            Shape::MultiPoint(cs) => out.extend(cs.iter().map(|c| Primitive::Point(*c))),
+            Shape::LineString(cs) if !cs.is_empty() => out.push(Primitive::Line(cs)),
+            Shape::LineString(_) => {}
+            Shape::MultiLineString(lines) => out.extend(
+                lines
+                    .iter()
+                    .filter(|l| !l.is_empty())
+                    .map(|l| Primitive::Line(l)),
+            ),
+            Shape::Polygon(rings) if !rings.is_empty() => out.push(Primitive::Polygon(rings)),
+            Shape::Polygon(_) => {}
+            Shape::MultiPolygon(polys) => out.extend(
+                polys
+                    .iter()
+                    .filter(|p| !p.is_empty())
+                    .map(|p| Primitive::Polygon(p)),
+            ),
+            Shape::GeometryCollection(shapes) => {
+                for shape in shapes {
+                    shape.push_primitives(out);
+                }
+            }
+        }
+    }
+
+    /// Checks the invariants of the shape, which is nested in `depth`
+    /// collections.
+    fn validate(&self, depth: usize) -> Result<(), GeometryError> {
+        fn validate_coord(c: &Coord) -> Result<(), GeometryError> {
+            if c.x.is_finite() && c.y.is_finite() {
+                Ok(())
+            } else {
+                Err(GeometryError::new("coordinates must be finite"))
+            }
+        }
+        fn validate_line(cs: &[Coord]) -> Result<(), GeometryError> {
+            cs.iter().try_for_each(validate_coord)?;
+            if cs.len() == 1 {
+                return Err(GeometryError::new("geometry requires more points"));
+            }
+            Ok(())
+        }
+        fn validate_polygon(rings: &[Vec<Coord>]) -> Result<(), GeometryError> {
+            for ring in rings {
+                ring.iter().try_for_each(validate_coord)?;
+                if ring.len() < 4 {
+                    return Err(GeometryError::new("geometry requires more points"));
+                }
+                if ring.first() != ring.last() {
+                    return Err(GeometryError::new("geometry contains non-closed rings"));
+                }
+            }
+            Ok(())
+        }
+        match self {
+            Shape::Point(c) => validate_coord(c),
+            Shape::MultiPoint(cs) => cs.iter().try_for_each(validate_coord),
+            Shape::LineString(cs) => validate_line(cs),
+            Shape::MultiLineString(lines) => lines.iter().try_for_each(|l| validate_line(l)),
+            Shape::Polygon(rings) => validate_polygon(rings),
+            Shape::MultiPolygon(polys) => polys.iter().try_for_each(|p| validate_polygon(p)),
+            Shape::GeometryCollection(_) if depth >= MAX_COLLECTION_DEPTH => {
+                Err(collection_too_deep())
+            }
+            Shape::GeometryCollection(shapes) => {
+                shapes.iter().try_for_each(|s| s.validate(depth + 1))
+            }
+        }
+    }
+}
+
+/// A two-dimensional geometry with a spatial reference system identifier.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Geometry {
+    /// The spatial reference system identifier, or 0 if unknown.
+    pub srid: i32,
+    pub shape: Shape,
+}
+
+impl Geometry {
+    /// Constructs a geometry from `shape`, returning an error if the shape is
+    /// malformed.
+    pub fn new(srid: i32, shape: Shape) -> Result<Geometry, GeometryError> {
+        shape.validate(0)?;
+        Ok(Geometry { srid, shape })
+    }
+
+    /// Constructs a point with an unknown SRID.
+    pub fn point(x: f64, y: f64) -> Result<Geometry, GeometryError> {
+        Geometry::new(0, Shape::Point(Coord { x, y }))
+    }
+
+    /// Constructs the rectangular polygon spanning the given bounds.
+    pub fn envelope_polygon(
+        min_x: f64,
+        min_y: f64,
+        max_x: f64,
+        max_y: f64,
+        srid: i32,
+    ) -> Result<Geometry, GeometryError> {
+        let ring = vec![
+            Coord { x: min_x, y: min_y },
+            Coord { x: min_x, y: max_y },
+            Coord { x: max_x, y: max_y },
+            Coord { x: max_x, y: min_y },
+            Coord { x: min_x, y: min_y },
+        ];
+        Geometry::new(srid, Shape::Polygon(vec![ring]))
+    }
+
+    /// Constructs a line string from the points and line strings of `a`
+    /// followed by those of `b`.
+    pub fn make_line(a: &Geometry, b: &Geometry) -> Result<Geometry, GeometryError> {
+        check_same_srid(a, b)?;
+        let mut coords = vec![];
+        for g in [a, b] {
+            match &g.shape {
+                Shape::Point(_) | Shape::MultiPoint(_) | Shape::LineString(_) => {
+                    coords.extend(g.shape.coords())
+                }
+                _ => {
+                    return Err(GeometryError::new(
+                        "only point and line string geometries can be made into a line",
+                    ));
+                }
+            }
+        }
+        Geometry::new(a.srid, Shape::LineString(coords))
+    }
+
+    /// Constructs a polygon whose shell is the closed line string `shell`.
+    pub fn make_polygon(shell: &Geometry) -> Result<Geometry, GeometryError> {
+        let Shape::LineString(coords) = &shell.shape else {
+            return Err(GeometryError::new("shell is not a line string"));
+        };
+        if coords.len() < 4 {
+            return Err(GeometryError::new("shell must have at least 4 points"));
+        }
+        if coords.first() != coords.last() {
+            return Err(GeometryError::new("shell must be closed"));
+        }
+        Geometry::new(shell.srid, Shape::Polygon(vec![coords.clone()]))
+    }
+
+    /// Returns a copy of the geometry with the given SRID.
+    pub fn with_srid(&self, srid: i32) -> Geometry {
+        Geometry {
+            srid,
+            shape: self.shape.clone(),
+        }
+    }
+
+    /// Returns the smallest axis-aligned rectangle that contains the geometry,
+    /// or `None` if the geometry is empty.
+    pub fn envelope(&self) -> Option<Envelope> {
+        self.shape.coords().fold(None, |env, c| {
+            let env = env.unwrap_or(Envelope {
+                min_x: c.x,
+                min_y: c.y,
+                max_x: c.x,
+                max_y: c.y,
+            });
+            Some(Envelope {
+                min_x: env.min_x.min(c.x),
+                min_y: env.min_y.min(c.y),
+                max_x: env.max_x.max(c.x),
+                max_y: env.max_y.max(c.y),
+            })
+        })
+    }
+
+    /// Reports whether the geometries share any point.
+    pub fn intersects(&self, other: &Geometry) -> Result<bool, GeometryError> {
+        check_same_srid(self, other)?;
+        match (self.envelope(), other.envelope()) {
+            (Some(a), Some(b)) if a.intersects(&b) => {}
+            _ => return Ok(false),
+        }
+        let other = other.shape.primitives();
+        Ok(self
+            .shape
+            .primitives()
+            .iter()
+            .any(|a| other.iter().any(|b| a.intersects(b))))
+    }
+
+    /// Reports whether no point of `other` lies outside of the geometry and at
+    /// least one point of the interior of `other` lies in the interior of the
+    /// geometry.
+    ///
+    /// Each point, line string, and polygon of `other` must be covered by a
+    /// single component of the geometry; components that are only covered by
+    /// the union of several adjacent components are not detected.
+    pub fn contains(&self, other: &Geometry) -> Result<bool, GeometryError> {
+        check_same_srid(self, other)?;
+        match (self.envelope(), other.envelope()) {
+            (Some(a), Some(b)) if a.contains(&b) => {}
+            _ => return Ok(false),
+        }
+        let this = self.shape.primitives();
+        let other = other.shape.primitives();
+        let covered = other.iter().all(|b| this.iter().any(|a| a.covers(b)));
+        let interiors_intersect = other
+            .iter()
+            .any(|b| this.iter().any(|a| a.interiors_intersect(b)));
+        Ok(covered && interiors_intersect)
+    }
+
+    /// Returns the minimum Cartesian distance between the geometries, or
+    /// `None` if either is empty.
+    pub fn distance(&self, other: &Geometry) -> Result<Option<f64>, GeometryError> {
+        check_same_srid(self, other)?;
+        let this = self.shape.primitives();
+        let other = other.shape.primitives();
+        Ok(this
+            .iter()
+            .flat_map(|a| other.iter().map(move |b| a.distance(b)))
+            .reduce(f64::min))
+    }
+
+    /// Returns the geohash of the geometry, or `None` if it is empty.
+    ///
+    /// If `precision` is `None`, returns the longest geohash that contains the
+    /// geometry's envelope, up to [`MAX_GEOHASH_PRECISION`] characters.
+    /// Otherwise returns the geohash of the center of the envelope with the
+    /// given precision.
+    pub fn geohash(&self, precision: Option<usize>) -> Result<Option<String>, GeometryError> {
+        let Some(env) = self.envelope() else {
+            return Ok(None);
+        };
+        env.check_geographic()?;
+        let hash = match precision {
+            Some(precision) => geohash(env.center(), check_geohash_precision(precision)?),
+            None => {
+                let min = geohash(env.min(), MAX_GEOHASH_PRECISION);
+                let max = geohash(env.max(), MAX_GEOHASH_PRECISION);
+                min.chars()
+                    .zip(max.chars())
+                    .take_while(|(a, b)| a == b)
+                    .map(|(a, _)| a)
+                    .collect()
+            }
+        };
+        Ok(Some(hash))
+    }
+
+    /// Returns the geohashes with the given precision of all cells that
+    /// intersect the geometry's envelope.
+    ///
+    /// Joining the cells of a set of polygons against the geohashes of a set
+    /// of points, each with the same precision, yields a superset of the
+    /// point-in-polygon matches that can be maintained with equi-joins.
+    pub fn geohash_cells(&self, precision: usize) -> Result<Vec<String>, GeometryError> {
+        let precision = check_geohash_precision(precision)?;
+        let Some(env) = self.envelope() else {
+            return Ok(vec![]);
+        };
+        env.check_geographic()?;
+        let bits = 5 * precision;
+        let lon_bits = bits.div_ceil(2);
+        let lat_bits = bits / 2;
+        let cell = |v: f64, min: f64, span: f64, bits: usize| -> u64 {
+            let n = 1u64 << bits;
+            let i = u64::cast_lossy(((v - min) / span * f64::cast_lossy(n)).floor());
+            i.min(n - 1)
+        };
+        let (x0, x1) = (
+            cell(env.min_x, -180.0, 360.0, lon_bits),
+            cell(env.max_x, -180.0, 360.0, lon_bits),
+        );
+        let (y0, y1) = (
+            cell(env.min_y, -90.0, 180.0, lat_bits),
+            cell(env.max_y, -90.0, 180.0, lat_bits),
+        );
+        let count = (x1 - x0 + 1).saturating_mul(y1 - y0 + 1);
+        if count > u64::try_from(MAX_GEOHASH_CELLS).expect("fits") {
+            return Err(GeometryError::new(format!(
+                "geometry covers more than {MAX_GEOHASH_CELLS} geohash cells at precision {precision}"
+            )));
+        }
+        let mut cells = vec![];
+        for x in x0..=x1 {
+            for y in y0..=y1 {
+                cells.push(geohash_from_cell(x, y, precision));
+            }
+        }
+        cells.sort();
+        Ok(cells)
+    }
+
+    /// Returns the cells of the finest grid level at which at most
+    /// [`GRID_MAX_COVER_SPAN`] cells per axis cover the geometry's envelope.
+    ///
+    /// If the geometry contains another geometry, the other geometry's
+    /// envelope lies within this geometry's envelope, and exactly one of the
+    /// cells returned here is among the other geometry's
+    /// [`Geometry::grid_anchor`] cells. Joining the two on their cells
+    /// therefore produces every pair in which one contains the other exactly
+    /// once.
+    pub fn grid_cover(&self) -> Vec<GridCell> {
+        let Some(env) = self.envelope() else {
+            return vec![];
+        };
+        for level in 0..GRID_LEVELS {
+            let (x0, y0) = grid_position(env.min(), level);
+            let (x1, y1) = grid_position(env.max(), level);
+            if x1.saturating_sub(x0) < GRID_MAX_COVER_SPAN
+                && y1.saturating_sub(y0) < GRID_MAX_COVER_SPAN
+            {
+                let mut cells = vec![];
+                for x in x0..=x1 {
+                    for y in y0..=y1 {
+                        cells.push(GridCell { level, x, y });
+                    }
+                }
+                return cells;
+            }
+        }
+        vec![GridCell {
+            level: GRID_LEVELS,
+            x: 0,
+            y: 0,
+        }]
+    }
+
+    /// Returns the cell at every grid level, including the final one, that
+    /// contains the lower left corner of the geometry's envelope.
+    pub fn grid_anchor(&self) -> Vec<GridCell> {
+        let Some(env) = self.envelope() else {
+            return vec![];
+        };
+        let mut cells = (0..GRID_LEVELS)
+            .map(|level| {
+                let (x, y) = grid_position(env.min(), level);
+                GridCell { level, x, y }
+            })
+            .collect::<Vec<_>>();
+        cells.push(GridCell {
+            level: GRID_LEVELS,
+            x: 0,
+            y: 0,
+        });
+        cells
+    }
+
+    /// Parses a geometry from its extended well-known binary (EWKB)
+    /// representation, which also accepts plain well-known binary (WKB).
+    pub fn from_ewkb(bytes: &[u8]) -> Result<Geometry, GeometryError> {
+        let mut reader = WkbReader {
+            bytes,
+            pos: 0,
+            depth: 0,
+        };
+        let (srid, shape) = reader.read_geometry()?;
+        if reader.pos != bytes.len() {
+            return Err(GeometryError::new("unexpected trailing bytes in WKB"));
+        }
+        Geometry::new(srid.unwrap_or(0), shape)
+    }
+
+    /// Returns the canonical extended well-known binary (EWKB) representation
+    /// of the geometry.
+    pub fn to_ewkb(&self) -> Vec<u8> {
+        let mut buf = vec![];
+        let srid = (self.srid != 0).then_some(self.srid);
+        write_wkb(&mut buf, &self.shape, srid);
+        buf
+    }
+
+    /// Returns the well-known binary (WKB) representation of the geometry,
+    /// which omits the SRID.
+    pub fn to_wkb(&self) -> Vec<u8> {
+        let mut buf = vec![];
+        write_wkb(&mut buf, &self.shape, None);
+        buf
+    }
+
+    /// Returns the extended well-known text (EWKT) representation of the
+    /// geometry, which is its WKT prefixed by its SRID, if known.
+    pub fn to_ewkt(&self) -> String {
+        if self.srid == 0 {
+            self.to_string()
+        } else {
+            format!("SRID={};{}", self.srid, self)
+        }
+    }
+}
+
+/// Formats the geometry as well-known text (WKT).
+impl fmt::Display for Geometry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_wkt(f, &self.shape, true)
+    }
+}
+
+/// Parses a geometry from extended well-known text (EWKT), which also accepts
+/// plain well-known text (WKT).
+impl FromStr for Geometry {
+    type Err = GeometryError;
+
+    fn from_str(s: &str) -> Result<Geometry, GeometryError> {
+        let mut parser = WktParser {
+            s,
+            pos: 0,
+            depth: 0,
+        };
+        let srid = parser.parse_srid()?;
+        let shape = parser.parse_tagged()?;
+        parser.skip_whitespace();
+        if parser.pos != s.len() {
+            return Err(parser.error("unexpected trailing input"));
+        }
+        Geometry::new(srid, shape)
+    }
+}
+
+fn check_same_srid(a: &Geometry, b: &Geometry) -> Result<(), GeometryError> {
+    if a.srid != b.srid {
+        return Err(GeometryError::new(format!(
+            "operation on mixed SRID geometries ({}, {}) != ({}, {})",
+            a.shape.type_name(),
+            a.srid,
+            b.shape.type_name(),
+            b.srid
+        )));
+    }
+    Ok(())
+}
+
+fn collection_too_deep() -> GeometryError {
+    GeometryError::new(format!(
+        "geometry collections cannot be nested more than {MAX_COLLECTION_DEPTH} levels deep"
+    ))
+}
+
+/// A cell of the grids that [`Geometry::grid_cover`] and
+/// [`Geometry::grid_anchor`] use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GridCell {
+    pub level: i64,
+    pub x: i64,
+    pub y: i64,
+}
+
+/// Returns the indexes of the cell at `level` that contains `c`.
+///
+/// Indexes saturate rather than wrap, so that they are monotonic in the
+/// coordinates.
+fn grid_position(c: Coord, level: i64) -> (i64, i64) {
+    let level = i32::try_from(level).expect("grid level fits");
+    let size = 2f64.powi(GRID_MIN_EXPONENT + GRID_LEVEL_STEP * level);
+    (
+        i64::cast_lossy((c.x / size).floor()),
+        i64::cast_lossy((c.y / size).floor()),
+    )
+}
+
+fn check_geohash_precision(precision: usize) -> Result<usize, GeometryError> {
+    if precision == 0 || precision > MAX_GEOHASH_PRECISION {
+        return Err(GeometryError::new(format!(
+            "geohash precision must be between 1 and {MAX_GEOHASH_PRECISION}"
+        )));
+    }
+    Ok(precision)
+}
+
+/// An axis-aligned rectangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Envelope {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl Envelope {
+    fn intersects(&self, other: &Envelope) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+
+    fn contains(&self, other: &Envelope) -> bool {
+        self.min_x <= other.min_x
+            && other.max_x <= self.max_x
+            && self.min_y <= other.min_y
+            && other.max_y <= self.max_y
+    }
+
+    fn min(&self) -> Coord {
+        Coord {
+            x: self.min_x,
+            y: self.min_y,
+        }
+    }
+
+    fn max(&self) -> Coord {
+        Coord {
+            x: self.max_x,
+            y: self.max_y,
+        }
+    }
+
+    fn center(&self) -> Coord {
+        self.min().midpoint(self.max())
+    }
+
+    fn check_geographic(&self) -> Result<(), GeometryError> {
+        if self.min_x < -180.0 || self.max_x > 180.0 || self.min_y < -90.0 || self.max_y > 90.0 {
+            return Err(GeometryError::new(format!(
+                "geohash requires inputs in decimal degrees, got ({} {}, {} {})",
+                self.min_x, self.min_y, self.max_x, self.max_y
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the geohash of `c`, interpreted as a longitude and latitude, with
+/// `precision` characters.
+fn geohash(c: Coord, precision: usize) -> String {
+    let (mut lon, mut lat) = ((-180.0, 180.0), (-90.0, 90.0));
+    let mut hash = String::with_capacity(precision);
+    let mut even = true;
+    for _ in 0..precision {
+        let mut idx = 0;
+        for _ in 0..5 {
+            let (range, v) = if even {
+                (&mut lon, c.x)
+            } else {
+                (&mut lat, c.y)
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            idx <<= 1;
+            if v >= mid {
+                idx |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            even = !even;
+        }
+        hash.push(char::from(GEOHASH_BASE32[idx]));
+    }
+    hash
+}
+
+/// Returns the geohash of the cell with the given longitude and latitude
+/// indexes at `precision`.
+fn geohash_from_cell(x: u64, y: u64, precision: usize) -> String {
+    let bits = 5 * precision;
+    let (mut lon_bit, mut lat_bit) = (bits.div_ceil(2), bits / 2);
+    let mut hash = String::with_capacity(precision);
+    let mut even = true;
+    for _ in 0..precision {
+        let mut idx = 0;
+        for _ in 0..5 {
+            let bit = if even {
+                lon_bit -= 1;
+                (x >> lon_bit) & 1
+            } else {
+                lat_bit -= 1;
+                (y >> lat_bit) & 1
+            };
+            idx = (idx << 1) | usize::from(bit == 1);
+            even = !even;
+        }
+        hash.push(char::from(GEOHASH_BASE32[idx]));
+    }
+    hash
+}
+
+/// Where a point lies relative to a geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+    Interior,
+    Boundary,
+    Exterior,
+}
+
+/// A non-empty point, line string, or polygon.
+#[derive(Debug, Clone, Copy)]
+enum Primitive<'a> {
+    Point(Coord),
+    Line(&'a [Coord]),
+    Polygon(&'a [Vec<Coord>]),
+}
+
+impl<'a> Primitive<'a> {
+    fn locate(&self, p: Coord) -> Location {
+        match self {
+            Primitive::Point(c) if *c == p => Location::Interior,
+            Primitive::Point(_) => Location::Exterior,
+            Primitive::Line(cs) => locate_in_line(p, cs),
+            Primitive::Polygon(rings) => locate_in_polygon(p, rings),
+        }
+    }
+
+    fn segments(&self) -> Box<dyn Iterator<Item = (Coord, Coord)> + 'a> {
+        match self {
+            Primitive::Point(_) => Box::new(std::iter::empty()),
+            Primitive::Line(cs) => Box::new(segments(cs)),
+            Primitive::Polygon(rings) => Box::new(rings.iter().flat_map(|r| segments(r))),
+        }
+    }
+
+    fn intersects(&self, other: &Primitive) -> bool {
+        use Primitive::*;
+        match (self, other) {
+            (Point(p), b) => b.locate(*p) != Location::Exterior,
+            (a, Point(p)) => a.locate(*p) != Location::Exterior,
+            (Line(a), Line(b)) => segments(a)
+                .any(|(a1, a2)| segments(b).any(|(b1, b2)| segments_intersect(a1, a2, b1, b2))),
+            (Line(line), poly @ Polygon(_)) | (poly @ Polygon(_), Line(line)) => {
+                poly.locate(line[0]) != Location::Exterior || crosses_any(self, other)
+            }
+            (Polygon(a), Polygon(b)) => {
+                other.locate(a[0][0]) != Location::Exterior
+                    || self.locate(b[0][0]) != Location::Exterior
+                    || crosses_any(self, other)
+            }
+        }
+    }
+
+    fn distance(&self, other: &Primitive) -> f64 {
+        use Primitive::*;
+        if self.intersects(other) {
+            return 0.0;
+        }
+        match (self, other) {
+            (Point(p), Point(q)) => p.distance(*q),
+            (Point(p), b) | (b, Point(p)) => b
+                .segments()
+                .map(|(b1, b2)| point_segment_distance(*p, b1, b2))
+                .fold(f64::INFINITY, f64::min),
+            (a, b) => a
+                .segments()
+                .flat_map(|(a1, a2)| {
+                    b.segments()
+                        .map(move |(b1, b2)| segment_distance(a1, a2, b1, b2))
+                })
+                .fold(f64::INFINITY, f64::min),
+        }
+    }
+
+    /// Reports whether no point of `other` lies outside of `self`.
+    fn covers(&self, other: &Primitive) -> bool {
+        use Primitive::*;
+        match (self, other) {
+            (a, Point(p)) => a.locate(*p) != Location::Exterior,
+            (Point(_), _) | (Line(_), Polygon(_)) => false,
+            (Line(_), Line(b)) => {
+                b.iter().all(|c| self.locate(*c) != Location::Exterior)
+                    && segments(b)
+                        .all(|(b1, b2)| self.locate(b1.midpoint(b2)) != Location::Exterior)
+            }
+            (Polygon(_), Line(b)) => self.covers_path(b),
+            (Polygon(a), Polygon(b)) => {
+                // The shell of `other` must be covered, and none of the holes
+                // of `self` may lie within `other`.
+                self.covers_path(&b[0])
+                    && a[1..]
+                        .iter()
+                        .flatten()
+                        .all(|c| other.locate(*c) != Location::Interior)
+            }
+        }
+    }
+
+    /// Reports whether the path `b` lies within the polygon `self`.
+    fn covers_path(&self, b: &[Coord]) -> bool {
+        b.iter().all(|c| self.locate(*c) != Location::Exterior)
+            && segments(b).all(|(b1, b2)| {
+                self.locate(b1.midpoint(b2)) != Location::Exterior
+                    && !self
+                        .segments()
+                        .any(|(a1, a2)| segments_cross(a1, a2, b1, b2))
+            })
+    }
+
+    /// Reports whether the interiors of `self` and `other` share a point,
+    /// assuming that `self` covers `other`.
+    fn interiors_intersect(&self, other: &Primitive) -> bool {
+        use Primitive::*;
+        if !self.covers(other) {
+            return false;
+        }
+        match other {
+            Point(p) => self.locate(*p) == Location::Interior,
+            Line(b) => {
+                b.iter().any(|c| self.locate(*c) == Location::Interior)
+                    || segments(b)
+                        .any(|(b1, b2)| self.locate(b1.midpoint(b2)) == Location::Interior)
+            }
+            Polygon(_) => true,
+        }
+    }
+}
+
+fn segments(cs: &[Coord]) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+    cs.windows(2).map(|w| (w[0], w[1]))
+}
+
+/// Reports whether any segment of `a` intersects any segment of `b`.
+fn crosses_any(a: &Primitive, b: &Primitive) -> bool {
+    a.segments().any(|(a1, a2)| {
+        b.segments()
+            .any(|(b1, b2)| segments_intersect(a1, a2, b1, b2))
+    })
+}
+
+/// Returns twice the signed area of the triangle `abc`, which is positive if
+/// the points are in counterclockwise order.
+fn orientation(a: Coord, b: Coord, c: Coord) -> f64 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+/// Reports whether `p` lies on the segment `ab`.
+fn on_segment(p: Coord, a: Coord, b: Coord) -> bool {
+    orientation(a, b, p) == 0.0
+        && p.x >= a.x.min(b.x)
+        && p.x <= a.x.max(b.x)
+        && p.y >= a.y.min(b.y)
+        && p.y <= a.y.max(b.y)
+}
+
+/// Reports whether the segments `a1a2` and `b1b2` share any point.
+fn segments_intersect(a1: Coord, a2: Coord, b1: Coord, b2: Coord) -> bool {
+    segments_cross(a1, a2, b1, b2)
+        || on_segment(b1, a1, a2)
+        || on_segment(b2, a1, a2)
+        || on_segment(a1, b1, b2)
+        || on_segment(a2, b1, b2)
+}
+
+/// Reports whether the segments `a1a2` and `b1b2` cross at a single point
+/// that is interior to both.
+fn segments_cross(a1: Coord, a2: Coord, b1: Coord, b2: Coord) -> bool {
+    let d1 = orientation(a1, a2, b1);
+    let d2 = orientation(a1, a2, b2);
+    let d3 = orientation(b1, b2, a1);
+    let d4 = orientation(b1, b2, a2);
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+fn point_segment_distance(p: Coord, a: Coord, b: Coord) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len2 = dx * dx + dy * dy;
+    if len2 == 0.0 {
+        return p.distance(a);
+    }
+    let t = (((p.x - a.x) * dx + (p.y - a.y) * dy) / len2).clamp(0.0, 1.0);
+    p.distance(Coord {
+        x: a.x + t * dx,
+        y: a.y + t * dy,
+    })
+}
+
+fn segment_distance(a1: Coord, a2: Coord, b1: Coord, b2: Coord) -> f64 {
+    if segments_intersect(a1, a2, b1, b2) {
+        return 0.0;
+    }
+    point_segment_distance(a1, b1, b2)
+        .min(point_segment_distance(a2, b1, b2))
+        .min(point_segment_distance(b1, a1, a2))
+        .min(point_segment_distance(b2, a1, a2))
+}
+
+fn locate_in_line(p: Coord, cs: &[Coord]) -> Location {
+    if cs.len() == 1 {
+        return if cs[0] == p {
+            Location::Interior
+        } else {
+            Location::Exterior
+        };
+    }
+    if !segments(cs).any(|(a, b)| on_segment(p, a, b)) {
+        return Location::Exterior;
+    }
+    let (first, last) = (cs[0], cs[cs.len() - 1]);
+    if first != last && (p == first || p == last) {
+        Location::Boundary
+    } else {
+        Location::Interior
+    }
+}
+
+fn locate_in_ring(p: Coord, ring: &[Coord]) -> Location {
+    let mut inside = false;
+    for (a, b) in segments(ring) {
+        if on_segment(p, a, b) {
+            return Location::Boundary;
+        }
+        if (a.y > p.y) != (b.y > p.y) && p.x < (b.x - a.x) * (p.y - a.y) / (b.y - a.y) + a.x {
+            inside = !inside;
+        }
+    }
+    if inside {
+        Location::Interior
+    } else {
+        Location::Exterior
+    }
+}
+
+fn locate_in_polygon(p: Coord, rings: &[Vec<Coord>]) -> Location {
+    match locate_in_ring(p, &rings[0]) {
+        Location::Interior => {}
+        location => return location,
+    }
+    for hole in &rings[1..] {
+        match locate_in_ring(p, hole) {
+            Location::Interior => return Location::Exterior,
+            Location::Boundary => return Location::Boundary,
+            Location::Exterior => {}
+        }
+    }
+    Location::Interior
+}
+
+fn write_wkb(buf: &mut Vec<u8>, shape: &Shape, srid: Option<i32>) {
+    fn write_coords(buf: &mut Vec<u8>, cs: &[Coord]) {
+        write_len(buf, cs.len());
+        for c in cs {
+            write_coord(buf, c);
+        }
+    }
+    fn write_coord(buf: &mut Vec<u8>, c: &Coord) {
+        buf.extend(c.x.to_le_bytes());
+        buf.extend(c.y.to_le_bytes());
+    }
+    fn write_len(buf: &mut Vec<u8>, len: usize) {
+        let len = u32::try_from(len).expect("geometries have fewer than 2^32 parts");
+        buf.extend(len.to_le_bytes());
+    }
+
+    // Little endian.
+    buf.push(1);
+    let mut typ = shape.wkb_type();
+    if srid.is_some() {
+        typ |= EWKB_SRID_FLAG;
+    }
+    buf.extend(typ.to_le_bytes());
+    if let Some(srid) = srid {
+        buf.extend(srid.to_le_bytes());
+    }
+    match shape {
+        Shape::Point(c) => write_coord(buf, c),
+        Shape::LineString(cs) => write_coords(buf, cs),
+        Shape::Polygon(rings) => {
+            write_len(buf, rings.len());
+            for ring in rings {
+                write_coords(buf, ring);
+            }
+        }
+        Shape::MultiPoint(cs) => {
+            write_len(buf, cs.len());
+            for c in cs {
+                write_wkb(buf, &Shape::Point(*c), None);
+            }
+        }
+        Shape::MultiLineString(lines) => {
+            write_len(buf, lines.len());
+            for line in lines {
+                write_wkb(buf, &Shape::LineString(line.clone()), None);
+            }
+        }
+        Shape::MultiPolygon(polys) => {
+            write_len(buf, polys.len());
+            for poly in polys {
+                write_wkb(buf, &Shape::Polygon(poly.clone()), None);
+            }
+        }
+        Shape::GeometryCollection(shapes) => {
+            write_len(buf, shapes.len());
+            for shape in shapes {
+                write_wkb(buf, shape, None);
+            }
+        }
+    }
+}
+
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    /// The number of multi-geometries and collections being read.
+    depth: usize,
+}
+
+impl<'a> WkbReader<'a> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], GeometryError> {
+        let bytes = self
+            .bytes
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| GeometryError::new("unexpected end of WKB"))?;
+        self.pos += N;
+        Ok(bytes.try_into().expect("known length"))
+    }
+
+    fn read_u32(&mut self, little_endian: bool) -> Result<u32, GeometryError> {
+        let bytes = self.take()?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_len(&mut self, little_endian: bool) -> Result<usize, GeometryError> {
+        let len = usize::try_from(self.read_u32(little_endian)?).expect("u32 fits in usize");
+        // Every part takes at least one byte, which bounds the allocation.
+        if len > self.bytes.len() - self.pos {
+            return Err(GeometryError::new("unexpected end of WKB"));
+        }
+        Ok(len)
+    }
+
+    fn read_coord(&mut self, little_endian: bool) -> Result<Coord, GeometryError> {
+        let mut read_f64 = || -> Result<f64, GeometryError> {
+            let bytes = self.take()?;
+            Ok(if little_endian {
+                f64::from_le_bytes(bytes)
+            } else {
+                f64::from_be_bytes(bytes)
+            })
+        };
+        Ok(Coord {
+            x: read_f64()?,
+            y: read_f64()?,
+        })
+    }
+
+    fn read_coords(&mut self, little_endian: bool) -> Result<Vec<Coord>, GeometryError> {
+        let len = self.read_len(little_endian)?;
+        (0..len).map(|_| self.read_coord(little_endian)).collect()
+    }
+
+    fn read_geometry(&mut self) -> Result<(Option<i32>, Shape), GeometryError> {
+        let little_endian = match self.take::<1>()? {
+            [0] => false,
+            [1] => true,
+            _ => return Err(GeometryError::new("invalid byte order in WKB")),
+        };
+        let typ = self.read_u32(little_endian)?;
+        if typ & (EWKB_Z_FLAG | EWKB_M_FLAG) != 0 || (typ & !EWKB_SRID_FLAG) > 1000 {
+            return Err(GeometryError::new(
+                "only two-dimensional geometries are supported",
+            ));
+        }
+        let srid = if typ & EWKB_SRID_FLAG != 0 {
+            Some(i32::from_ne_bytes(
+                self.read_u32(little_endian)?.to_ne_bytes(),
+            ))
+        } else {
+            None
+        };
+        let shape = match typ & !EWKB_SRID_FLAG {
+            WKB_POINT => Shape::Point(self.read_coord(little_endian)?),
+            WKB_LINESTRING => Shape::LineString(self.read_coords(little_endian)?),
+            WKB_POLYGON => {
+                let len = self.read_len(little_endian)?;
+                Shape::Polygon(
+                    (0..len)
+                        .map(|_| self.read_coords(little_endian))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            typ @ (WKB_MULTIPOINT
+            | WKB_MULTILINESTRING
+            | WKB_MULTIPOLYGON
+            | WKB_GEOMETRYCOLLECTION) => {
+                if self.depth >= MAX_COLLECTION_DEPTH {
+                    return Err(collection_too_deep());
+                }
+                let len = self.read_len(little_endian)?;
+                let mut shapes = Vec::with_capacity(len);
+                self.depth += 1;
+                for _ in 0..len {
+                    shapes.push(self.read_geometry()?.1);
+                }
+                self.depth -= 1;
+                collect_multi(typ, shapes)?
+            }
+            typ => {
+                return Err(GeometryError::new(format!(
+                    "unknown WKB geometry type {typ}"
+                )));
+            }
+        };
+        Ok((srid, shape))
+    }
+}
+
+/// Assembles the members of a multi-geometry of the given WKB type.
+fn collect_multi(typ: u32, shapes: Vec<Shape>) -> Result<Shape, GeometryError> {
+    let mismatch = || GeometryError::new("invalid member of multi-geometry");
+    Ok(match typ {
+        WKB_MULTIPOINT => Shape::MultiPoint(
+            shapes
+                .into_iter()
+                .map(|s| match s {
+                    Shape::Point(c) => Ok(c),
+                    _ => Err(mismatch()),
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        WKB_MULTILINESTRING => Shape::MultiLineString(
+            shapes
+                .into_iter()
+                .map(|s| match s {
+                    Shape::LineString(cs) => Ok(cs),
+                    _ => Err(mismatch()),
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        WKB_MULTIPOLYGON => Shape::MultiPolygon(
+            shapes
+                .into_iter()
+                .map(|s| match s {
+                    Shape::Polygon(rings) => Ok(rings),
+                    _ => Err(mismatch()),
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        _ => Shape::GeometryCollection(shapes),
+    })
+}
+
+fn write_wkt(f: &mut fmt::Formatter, shape: &Shape, tagged: bool) -> fmt::Result {
+    fn write_num(f: &mut fmt::Formatter, n: f64) -> fmt::Result {
+        // Avoid printing negative zero.
+        if n == 0.0 {
+            f.write_str("0")
+        } else {
+            write!(f, "{n}")
+        }
+    }
+    fn write_coord(f: &mut fmt::Formatter, c: &Coord) -> fmt::Result {
+        write_num(f, c.x)?;
+        f.write_str(" ")?;
+        write_num(f, c.y)
+    }
+    fn write_list<T>(
+        f: &mut fmt::Formatter,
+        items: &[T],
+        mut write_item: impl FnMut(&mut fmt::Formatter, &T) -> fmt::Result,
+    ) -> fmt::Result {
+        f.write_str("(")?;
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write_item(f, item)?;
+        }
+        f.write_str(")")
+    }
+    fn write_coords(f: &mut fmt::Formatter, cs: &[Coord]) -> fmt::Result {
+        write_list(f, cs, write_coord)
+    }
+    fn write_rings(f: &mut fmt::Formatter, rings: &[Vec<Coord>]) -> fmt::Result {
+        write_list(f, rings, |f, ring| write_coords(f, ring))
+    }
+
+    if tagged {
+        f.write_str(&shape.type_name().to_uppercase())?;
+    }
+    if shape.is_empty() && !matches!(shape, Shape::Point(_)) {
+        return f.write_str(" EMPTY");
+    }
+    match shape {
+        Shape::Point(c) => write_list(f, &[*c], write_coord),
+        Shape::LineString(cs) => write_coords(f, cs),
+        Shape::Polygon(rings) => write_rings(f, rings),
+        Shape::MultiPoint(cs) => write_list(f, cs, |f, c| write_list(f, &[*c], write_coord)),
+        Shape::MultiLineString(lines) => write_rings(f, lines),
+        Shape::MultiPolygon(polys) => write_list(f, polys, |f, poly| write_rings(f, poly)),
+        Shape::GeometryCollection(shapes) => {
+            write_list(f, shapes, |f, shape| write_wkt(f, shape, true))
+        }
+    }
+}
+
+struct WktParser<'a> {
+    s: &'a str,
+    pos: usize,
+    /// The number of collections being parsed.
+    depth: usize,
+}
+
+impl<'a> WktParser<'a> {
+    fn error(&self, msg: &str) -> GeometryError {
+        GeometryError::new(format!("{msg} at position {}", self.pos))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.s[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.s[self.pos..].chars().next()
+    }
+
+    fn consume(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), GeometryError> {
+        if self.consume(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected \"{c}\"")))
+        }
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        self.skip_whitespace();
+        let rest = &self.s[self.pos..];
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    fn word(&mut self) -> String {
+        self.take_while(|c| c.is_ascii_alphabetic())
+            .to_ascii_uppercase()
+    }
+
+    fn parse_srid(&mut self) -> Result<i32, GeometryError> {
+        let start = self.pos;
+        if self.word() != "SRID" {
+            self.pos = start;
+            return Ok(0);
+        }
+        self.expect('=')?;
+        let srid = self
+            .take_while(|c| c.is_ascii_digit() || c == '-')
+            .parse()
+            .map_err(|_| self.error("invalid SRID"))?;
+        self.expect(';')?;
+        Ok(srid)
+    }
+
+    fn parse_number(&mut self) -> Result<f64, GeometryError> {
+        let s = self.take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'));
+        s.parse().map_err(|_| self.error("invalid number"))
+    }
+
+    fn parse_coord(&mut self) -> Result<Coord, GeometryError> {
+        let x = self.parse_number()?;
+        let y = self.parse_number()?;
+        if !matches!(self.peek(), Some(',' | ')')) {
+            return Err(self.error("only two-dimensional geometries are supported"));
+        }
+        Ok(Coord { x, y })
+    }
+
+    /// Parses a parenthesized, comma-separated list of items.
+    fn parse_list<T>(
+        &mut self,
+        mut parse_item: impl FnMut(&mut Self) -> Result<T, GeometryError>,
+    ) -> Result<Vec<T>, GeometryError> {
+        self.expect('(')?;
+        let mut items = vec![parse_item(self)?];
+        while self.consume(',') {
+            items.push(parse_item(self)?);
+        }
+        self.expect(')')?;
+        Ok(items)
+    }
+
+    fn parse_coords(&mut self) -> Result<Vec<Coord>, GeometryError> {
+        self.parse_list(Self::parse_coord)
+    }
+
+    fn parse_rings(&mut self) -> Result<Vec<Vec<Coord>>, GeometryError> {
+        self.parse_list(Self::parse_coords)
+    }
+
+    fn parse_tagged(&mut self) -> Result<Shape, GeometryError> {
+        let tag = self.word();
+        let start = self.pos;
+        match self.word().as_str() {
+            "" => {}
+            "EMPTY" => {
+                return match tag.as_str() {
+                    "POINT" => Err(self.error("empty points are not supported")),
+                    "LINESTRING" => Ok(Shape::LineString(vec![])),
+                    "POLYGON" => Ok(Shape::Polygon(vec![])),
+                    "MULTIPOINT" => Ok(Shape::MultiPoint(vec![])),
+                    "MULTILINESTRING" => Ok(Shape::MultiLineString(vec![])),
+                    "MULTIPOLYGON" => Ok(Shape::MultiPolygon(vec![])),
+                    "GEOMETRYCOLLECTION" => Ok(Shape::GeometryCollection(vec![])),
+                    _ => Err(self.error("unknown geometry type")),
+                };
+            }
+            "Z" | "M" | "ZM" => {
+                self.pos = start;
+                return Err(self.error("only two-dimensional geometries are supported"));
+            }
+            _ => {
+                self.pos = start;
+                return Err(self.error("expected \"(\" or EMPTY"));
+            }
+        }
+        match tag.as_str() {
+            "POINT" => {
+                self.expect('(')?;
+                let c = self.parse_coord()?;
+                self.expect(')')?;
+                Ok(Shape::Point(c))
+            }
+            "LINESTRING" => Ok(Shape::LineString(self.parse_coords()?)),
+            "POLYGON" => Ok(Shape::Polygon(self.parse_rings()?)),
+            "MULTIPOINT" => Ok(Shape::MultiPoint(self.parse_list(|p| {
+                // Both `MULTIPOINT((0 0),(1 1))` and `MULTIPOINT(0 0,1 1)` are
+                // accepted.
+                if p.consume('(') {
+                    let c = p.parse_coord()?;
+                    p.expect(')')?;
+                    Ok(c)
+                } else {
+                    p.parse_coord()
+                }
+            })?)),
+            "MULTILINESTRING" => Ok(Shape::MultiLineString(self.parse_rings()?)),
+            "MULTIPOLYGON" => Ok(Shape::MultiPolygon(self.parse_list(Self::parse_rings)?)),
+            "GEOMETRYCOLLECTION" => {
+                if self.depth >= MAX_COLLECTION_DEPTH {
+                    return Err(collection_too_deep());
+                }
+                self.depth += 1;
+                let shapes = self.parse_list(Self::parse_tagged)?;
+                self.depth -= 1;
+                Ok(Shape::GeometryCollection(shapes))
+            }
+            _ => Err(self.error("unknown geometry type")),
+        }
+    }
+}
+
+/// An error that occurs when parsing or operating on a geometry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeometryError(String);
+
+impl GeometryError {
+    fn new(msg: impl Into<String>) -> GeometryError {
+        GeometryError(msg.into())
+    }
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for GeometryError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn geom(s: &str) -> Geometry {
+        s.parse().unwrap()
+    }
+
+    #[mz_ore::test]
+    fn test_wkt_roundtrip() {
+        for s in [
+            "POINT(1 2)",
+            "POINT(-1.5 0.25)",
+            "LINESTRING(0 0,1 1,2 0)",
+            "LINESTRING EMPTY",
+            "POLYGON((0 0,0 10,10 10,10 0,0 0),(2 2,2 4,4 4,4 2,2 2))",
+            "MULTIPOINT((0 0),(1 1))",
+            "MULTILINESTRING((0 0,1 1),(2 2,3 3))",
+            "MULTIPOLYGON(((0 0,0 1,1 1,1 0,0 0)),((5 5,5 6,6 6,6 5,5 5)))",
+            "GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))",
+            "GEOMETRYCOLLECTION EMPTY",
+        ] {
+            let g = geom(s);
+            assert_eq!(g.to_string(), s);
+            assert_eq!(Geometry::from_ewkb(&g.to_ewkb()).unwrap(), g);
+        }
+
+        let g = geom(" srid=4326; point ( 1  2 ) ");
+        assert_eq!(g.srid, 4326);
+        assert_eq!(g.to_ewkt(), "SRID=4326;POINT(1 2)");
+        assert_eq!(Geometry::from_ewkb(&g.to_ewkb()).unwrap(), g);
+        assert_eq!(Geometry::from_ewkb(&g.to_wkb()).unwrap().srid, 0);
+        assert_eq!(geom("MULTIPOINT(0 0,1 1)"), geom("MULTIPOINT((0 0),(1 1))"));
+    }
+
+    #[mz_ore::test]
+    fn test_wkb() {
+        // POINT(1 2), big endian.
+        let wkb = hex::decode("00000000013FF00000000000004000000000000000").unwrap();
+        assert_eq!(Geometry::from_ewkb(&wkb).unwrap(), geom("POINT(1 2)"));
+        assert_eq!(
+            hex::encode_upper(geom("POINT(1 2)").to_ewkb()),
+            "0101000000000000000000F03F0000000000000040"
+        );
+        assert_eq!(
+            hex::encode_upper(geom("SRID=4326;POINT(1 2)").to_ewkb()),
+            "0101000020E6100000000000000000F03F0000000000000040"
+        );
+        assert!(Geometry::from_ewkb(&wkb[..10]).is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_invalid() {
+        for s in [
+            "POINT(1)",
+            "POINT(1 2 3)",
+            "POINT Z (1 2 3)",
+            "POINT EMPTY",
+            "LINESTRING(0 0)",
+            "POLYGON((0 0,1 1,1 0))",
+            "POLYGON((0 0,0 1,1 1,1 0,0 1))",
+            "CIRCLE(0 0)",
+            "POINT(1 2) x",
+        ] {
+            assert!(s.parse::<Geometry>().is_err(), "{s} should not parse");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_collection_depth() {
+        let nested = |depth: usize| {
+            let open = "GEOMETRYCOLLECTION(".repeat(depth);
+            let close = ")".repeat(depth);
+            format!("{open}POINT(0 0){close}")
+        };
+
+        let g = geom(&nested(MAX_COLLECTION_DEPTH));
+        assert_eq!(Geometry::from_ewkb(&g.to_ewkb()).unwrap(), g);
+
+        let err = nested(MAX_COLLECTION_DEPTH + 1)
+            .parse::<Geometry>()
+            .unwrap_err();
+        assert_eq!(err, collection_too_deep());
+        let err = nested(100_000).parse::<Geometry>().unwrap_err();
+        assert_eq!(err, collection_too_deep());
+
+        // A WKB collection that contains itself 100,000 times.
+        let mut wkb = vec![];
+        for _ in 0..100_000 {
+            wkb.extend([1, 7, 0, 0, 0, 1, 0, 0, 0]);
+        }
+        wkb.extend([1, 7, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            Geometry::from_ewkb(&wkb).unwrap_err(),
+            collection_too_deep()
+        );
+
+        let mut shape = Shape::Point(Coord { x: 0.0, y: 0.0 });
+        for _ in 0..=MAX_COLLECTION_DEPTH {
+            shape = Shape::GeometryCollection(vec![shape]);
+        }
+        assert_eq!(Geometry::new(0, shape).unwrap_err(), collection_too_deep());
+    }
+
+    #[mz_ore::test]
+    fn test_predicates() {
+        let square = geom("POLYGON((0 0,0 10,10 10,10 0,0 0),(4 4,4 6,6 6,6 4,4 4))");
+        let contains = |a: &Geometry, b: &str| a.contains(&geom(b)).unwrap();
+        let intersects = |a: &Geometry, b: &str| a.intersects(&geom(b)).unwrap();
+
+        assert!(contains(&square, "POINT(1 1)"));
+        assert!(!contains(&square, "POINT(0 5)"));
+        assert!(!contains(&square, "POINT(5 5)"));
+        assert!(!contains(&square, "POINT(11 5)"));
+        assert!(contains(&square, "LINESTRING(1 1,2 2,0 3)"));
+        assert!(!contains(&square, "LINESTRING(0 0,0 10)"));
+        assert!(!contains(&square, "LINESTRING(1 5,9 5)"));
+        assert!(contains(&square, "POLYGON((1 1,1 2,2 2,2 1,1 1))"));
+        assert!(!contains(&square, "POLYGON((3 3,3 7,7 7,7 3,3 3))"));
+        assert!(!contains(&square, "POLYGON((9 9,9 11,11 11,11 9,9 9))"));
+
+        assert!(intersects(&square, "POINT(0 5)"));
+        assert!(!intersects(&square, "POINT(5 5)"));
+        assert!(intersects(&square, "LINESTRING(-1 -1,1 1)"));
+        assert!(intersects(&square, "POLYGON((9 9,9 11,11 11,11 9,9 9))"));
+        assert!(!intersects(&square, "LINESTRING(11 0,11 10)"));
+        assert!(intersects(
+            &geom("LINESTRING(0 0,10 10)"),
+            "LINESTRING(0 10,10 0)"
+        ));
+
+        let origin = geom("POINT(0 0)");
+        let distance = |a: &Geometry, b: &str| a.distance(&geom(b)).unwrap().unwrap();
+        assert_eq!(distance(&origin, "POINT(3 4)"), 5.0);
+        assert_eq!(distance(&origin, "LINESTRING(-5 2,5 2)"), 2.0);
+        assert_eq!(distance(&square, "POINT(5 5)"), 1.0);
+        assert_eq!(distance(&square, "POINT(1 1)"), 0.0);
+        assert_eq!(origin.distance(&geom("LINESTRING EMPTY")).unwrap(), None);
+
+        assert!(origin.intersects(&geom("SRID=4326;POINT(0 0)")).is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_geohash() {
+        let p = geom("POINT(-126 48)");
+        assert_eq!(p.geohash(None).unwrap().unwrap(), "c0w3hf1s70w3hf1s70w3");
+        assert_eq!(p.geohash(Some(5)).unwrap().unwrap(), "c0w3h");
+        assert!(geom("POINT(200 0)").geohash(None).is_err());
+
+        // The cell of every point within a polygon's envelope is among the
+        // polygon's cells.
+        let fence = geom("POLYGON((-126 48,-126 48.1,-125.9 48.1,-125.9 48,-126 48))");
+        let cells = fence.geohash_cells(5).unwrap();
+        for point in [
+            "POINT(-126 48)",
+            "POINT(-125.95 48.05)",
+            "POINT(-125.9 48.1)",
+        ] {
+            let hash = geom(point).geohash(Some(5)).unwrap().unwrap();
+            assert!(cells.contains(&hash), "{hash} not in {cells:?}");
+        }
+        assert!(geom("POINT(0 0)").geohash_cells(0).is_err());
+        assert!(
+            geom("POLYGON((-180 -90,-180 90,180 90,180 -90,-180 -90))")
+                .geohash_cells(8)
+                .is_err()
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_grid() {
+        // Every geometry that a fence contains shares exactly one cell with
+        // it, regardless of the scale of the coordinates.
+        for (fence, contained) in [
+            (
+                "POLYGON((-126 48,-126 48.1,-125.9 48.1,-125.9 48,-126 48))",
+                vec![
+                    "POINT(-125.99 48.01)",
+                    "POINT(-125.95 48.05)",
+                    "LINESTRING(-125.99 48.01,-125.91 48.09)",
+                ],
+            ),
+            (
+                "POLYGON((0 0,0 1e9,1e9 1e9,1e9 0,0 0))",
+                vec!["POINT(1 1)", "LINESTRING(5e8 5e8,9e8 9e8)"],
+            ),
+            (
+                "POLYGON((-1e300 -1e300,-1e300 1e300,1e300 1e300,1e300 -1e300,-1e300 -1e300))",
+                vec!["POINT(0 0)", "POINT(-1e299 1e299)"],
+            ),
+        ] {
+            let fence = geom(fence);
+            let cover = fence.grid_cover();
+            assert!(!cover.is_empty());
+            assert!(cover.len() <= 16, "{cover:?}");
+            for other in contained {
+                let other = geom(other);
+                assert!(fence.contains(&other).unwrap());
+                let shared = other
+                    .grid_anchor()
+                    .into_iter()
+                    .filter(|cell| cover.contains(cell))
+                    .count();
+                assert_eq!(shared, 1, "{other}");
+            }
+        }
+
+        assert!(geom("LINESTRING EMPTY").grid_cover().is_empty());
+        assert!(geom("LINESTRING EMPTY").grid_anchor().is_empty());
+        assert_eq!(
+            geom("POINT(0 0)").grid_anchor().len(),
+            usize::try_from(GRID_LEVELS + 1).unwrap()
+        );
+    }
+}
@@ -41,6 +41,8 @@ const SELECT_CHAR: char = 'r';
 const UPDATE_CHAR: char = 'w';
 // Delete
 const DELETE_CHAR: char = 'd';
+// eXecute
+const EXECUTE_CHAR: char = 'X';
 // Usage
 const USAGE_CHAR: char = 'U';
 // Create
@@ -58,6 +60,7 @@ const INSERT_STR: &str = "INSERT";
 const SELECT_STR: &str = "SELECT";
 const UPDATE_STR: &str = "UPDATE";
 const DELETE_STR: &str = "DELETE";
+const EXECUTE_STR: &str = "EXECUTE";
 const USAGE_STR: &str = "USAGE";
 const CREATE_STR: &str = "CREATE";
 const CREATE_ROLE_STR: &str = "CREATEROLE";
@@ -90,6 +93,7 @@ bitflags! {
         const SELECT = 1 << 1;
         const UPDATE = 1 << 2;
         const DELETE = 1 << 3;
+        const EXECUTE = 1 << 7;
         const USAGE = 1 << 8;
         const CREATE = 1 << 9;
 
@@ -111,6 +115,7 @@ impl AclMode {
             SELECT_STR => Ok(AclMode::SELECT),
             UPDATE_STR => Ok(AclMode::UPDATE),
             DELETE_STR => Ok(AclMode::DELETE),
+            EXECUTE_STR => Ok(AclMode::EXECUTE),
             USAGE_STR => Ok(AclMode::USAGE),
             CREATE_STR => Ok(AclMode::CREATE),
             CREATE_ROLE_STR => Ok(AclMode::CREATE_ROLE),
@@ -148,6 +153,9 @@ impl AclMode {
         if self.contains(AclMode::DELETE) {
             privileges.push(DELETE_STR);
         }
+        if self.contains(AclMode::EXECUTE) {
+            privileges.push(EXECUTE_STR);
+        }
         if self.contains(AclMode::USAGE) {
             privileges.push(USAGE_STR);
         }
@@ -181,6 +189,7 @@ impl FromStr for AclMode {
                 SELECT_CHAR => acl_mode.bitor_assign(AclMode::SELECT),
                 UPDATE_CHAR => acl_mode.bitor_assign(AclMode::UPDATE),
                 DELETE_CHAR => acl_mode.bitor_assign(AclMode::DELETE),
+                EXECUTE_CHAR => acl_mode.bitor_assign(AclMode::EXECUTE),
                 USAGE_CHAR => acl_mode.bitor_assign(AclMode::USAGE),
                 CREATE_CHAR => acl_mode.bitor_assign(AclMode::CREATE),
                 CREATE_ROLE_CHAR => acl_mode.bitor_assign(AclMode::CREATE_ROLE),
@@ -210,6 +219,9 @@ impl fmt::Display for AclMode {
         if self.contains(AclMode::DELETE) {
             write!(f, "{DELETE_CHAR}")?;
         }
+        if self.contains(AclMode::EXECUTE) {
+            write!(f, "{EXECUTE_CHAR}")?;
+        }
         if self.contains(AclMode::USAGE) {
             write!(f, "{USAGE_CHAR}")?;
         }
@@ -0,0 +1,1033 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Full text search types.
+//!
+//! Values of [`ScalarType::TsVector`] and [`ScalarType::TsQuery`] are stored
+//! as strings in their canonical text form, and are parsed into the types in
+//! this module when they are operated on.
+//!
+//! See the [PostgreSQL documentation][pg] for the semantics of these types.
+//!
+//! [`ScalarType::TsVector`]: crate::ScalarType::TsVector
+//! [`ScalarType::TsQuery`]: crate::ScalarType::TsQuery
+//! [pg]: https://www.postgresql.org/docs/current/datatype-textsearch.html
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{Error, anyhow, bail};
+use mz_ore::stack::{CheckedRecursion, RecursionGuard};
+
+/// The largest position that can be recorded for a lexeme. Larger positions
+/// are clamped to this value.
+pub const MAX_POSITION: u16 = 16383;
+
+/// The maximum number of positions recorded for a single lexeme. Further
+/// positions are discarded.
+pub const MAX_POSITIONS_PER_LEXEME: usize = 256;
+
+/// The maximum length of a lexeme, in bytes.
+pub const MAX_LEXEME_LENGTH: usize = 2047;
+
+/// The maximum depth of a [`TsQuery`], i.e., the maximum number of nodes on a
+/// path from its root to an operand.
+///
+/// Queries are matched and formatted recursively, so this bounds the stack
+/// those take. Chains of operators like `a & b & c` count towards the depth,
+/// as well as groups and negations.
+pub const MAX_QUERY_DEPTH: usize = 1000;
+
+/// The weight of a lexeme position, used to rank matches.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Weight {
+    D,
+    C,
+    B,
+    A,
+}
+
+impl Weight {
+    fn from_char(c: char) -> Option<Weight> {
+        match c.to_ascii_uppercase() {
+            'A' => Some(Weight::A),
+            'B' => Some(Weight::B),
+            'C' => Some(Weight::C),
+            'D' => Some(Weight::D),
+            _ => None,
+        }
+    }
+
+    fn as_char(&self) -> char {
+        match self {
+            Weight::A => 'A',
+            Weight::B => 'B',
+            Weight::C => 'C',
+            Weight::D => 'D',
+        }
+    }
+
+    /// The default weight `ts_rank` assigns to positions of this weight.
+    pub fn rank_weight(&self) -> f32 {
+        match self {
+            Weight::D => 0.1,
+            Weight::C => 0.2,
+            Weight::B => 0.4,
+            Weight::A => 1.0,
+        }
+    }
+}
+
+/// A position at which a lexeme occurs in a document.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct LexemePosition {
+    pub position: u16,
+    pub weight: Weight,
+}
+
+/// A normalized word in a [`TsVector`], along with the positions at which it
+/// occurs.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Lexeme {
+    pub word: String,
+    /// The positions of the lexeme, sorted and without duplicates. Empty if the
+    /// vector does not record positions for the lexeme.
+    pub positions: Vec<LexemePosition>,
+}
+
+/// A sorted list of distinct lexemes, the result of normalizing a document.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TsVector {
+    lexemes: Vec<Lexeme>,
+}
+
+impl TsVector {
+    /// Constructs a vector from lexemes in any order, merging the positions of
+    /// duplicate lexemes.
+    pub fn new(mut lexemes: Vec<Lexeme>) -> TsVector {
+        lexemes.sort_by(|a, b| a.word.cmp(&b.word));
+        let mut out: Vec<Lexeme> = Vec::with_capacity(lexemes.len());
+        for lexeme in lexemes {
+            match out.last_mut() {
+                Some(last) if last.word == lexeme.word => last.positions.extend(lexeme.positions),
+                _ => out.push(lexeme),
+            }
+        }
+        for lexeme in &mut out {
+            normalize_positions(&mut lexeme.positions);
+        }
+        TsVector { lexemes: out }
+    }
+
+    /// The lexemes of the vector, sorted by word.
+    pub fn lexemes(&self) -> &[Lexeme] {
+        &self.lexemes
+    }
+
+    /// Returns the lexemes that match the query operand `word`.
+    fn matching(&self, word: &str, prefix: bool) -> &[Lexeme] {
+        let start = self.lexemes.partition_point(|l| l.word.as_str() < word);
+        let end = if prefix {
+            start
+                + self.lexemes[start..]
+                    .iter()
+                    .take_while(|l| l.word.starts_with(word))
+                    .count()
+        } else {
+            start
+                + self.lexemes[start..]
+                    .first()
+                    .map_or(0, |l| usize::from(l.word == word))
+        };
+        &self.lexemes[start..end]
+    }
+
+    /// All positions of all lexemes in the vector.
+    fn all_positions(&self) -> BTreeSet<u16> {
+        self.lexemes
+            .iter()
+            .flat_map(|l| l.positions.iter().map(|p| p.position))
+            .collect()
+    }
+}
+
+/// Sorts `positions` and removes duplicates, keeping the highest weight of any
+/// duplicate position.
+fn normalize_positions(positions: &mut Vec<LexemePosition>) {
+    positions.sort_by(|a, b| a.position.cmp(&b.position).then(b.weight.cmp(&a.weight)));
+    positions.dedup_by_key(|p| p.position);
+    positions.truncate(MAX_POSITIONS_PER_LEXEME);
+}
+
+/// Writes `word` as a single-quoted string, doubling quotes and backslashes.
+fn write_quoted(f: &mut fmt::Formatter, word: &str) -> fmt::Result {
+    f.write_str("'")?;
+    for c in word.chars() {
+        match c {
+            '\'' => f.write_str("''")?,
+            '\\' => f.write_str("\\\\")?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    f.write_str("'")
+}
+
+impl fmt::Display for TsVector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, lexeme) in self.lexemes.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write_quoted(f, &lexeme.word)?;
+            for (j, p) in lexeme.positions.iter().enumerate() {
+                f.write_str(if j == 0 { ":" } else { "," })?;
+                write!(f, "{}", p.position)?;
+                if p.weight != Weight::D {
+                    write!(f, "{}", p.weight.as_char())?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A cursor over the input of a text search value.
+struct Lexer<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(input: &'a str) -> Lexer<'a> {
+        Lexer { input, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn consume(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.pos == self.input.len()
+    }
+
+    /// Parses a word, which is either single-quoted or runs until whitespace
+    /// or a character for which `is_special` returns true.
+    fn parse_word(&mut self, is_special: impl Fn(char) -> bool) -> Result<String, Error> {
+        let mut word = String::new();
+        if self.consume('\'') {
+            loop {
+                match self.next() {
+                    None => bail!("unterminated quoted string"),
+                    Some('\'') if self.consume('\'') => word.push('\''),
+                    Some('\'') => break,
+                    Some('\\') => match self.next() {
+                        Some(c) => word.push(c),
+                        None => bail!("there is no escaped character"),
+                    },
+                    Some(c) => word.push(c),
+                }
+            }
+        } else {
+            while let Some(c) = self.peek() {
+                if c.is_whitespace() || is_special(c) {
+                    break;
+                }
+                self.pos += c.len_utf8();
+                match c {
+                    '\\' => match self.next() {
+                        Some(c) => word.push(c),
+                        None => bail!("there is no escaped character"),
+                    },
+                    c => word.push(c),
+                }
+            }
+        }
+        if word.is_empty() {
+            bail!("syntax error");
+        }
+        if word.len() > MAX_LEXEME_LENGTH {
+            bail!(
+                "word is too long ({} bytes, max {} bytes)",
+                word.len(),
+                MAX_LEXEME_LENGTH
+            );
+        }
+        Ok(word)
+    }
+
+    fn parse_number(&mut self) -> Option<u64> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.input[start..self.pos].parse().ok()
+    }
+}
+
+impl FromStr for TsVector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lexer = Lexer::new(s);
+        let mut lexemes = vec![];
+        loop {
+            lexer.skip_whitespace();
+            if lexer.is_done() {
+                break;
+            }
+            let word = lexer.parse_word(|c| c == ':')?;
+            let mut positions = vec![];
+            if lexer.consume(':') {
+                loop {
+                    let position = match lexer.parse_number() {
+                        Some(0) | None => bail!("wrong position info"),
+                        Some(p) => u16::try_from(p).unwrap_or(MAX_POSITION).min(MAX_POSITION),
+                    };
+                    let weight = match lexer.peek().and_then(Weight::from_char) {
+                        Some(weight) => {
+                            lexer.next();
+                            weight
+                        }
+                        None => Weight::D,
+                    };
+                    positions.push(LexemePosition { position, weight });
+                    if !lexer.consume(',') {
+                        break;
+                    }
+                }
+                if !lexer.is_done() && !lexer.peek().is_some_and(|c| c.is_whitespace()) {
+                    bail!("wrong position info");
+                }
+            }
+            lexemes.push(Lexeme { word, positions });
+        }
+        Ok(TsVector::new(lexemes))
+    }
+}
+
+/// A node of a [`TsQuery`].
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum TsQueryNode {
+    /// Matches lexemes equal to `word` or, if `prefix` is set, starting with
+    /// `word`. If `weights` is non-empty, only positions with one of the
+    /// listed weights match.
+    Lexeme {
+        word: String,
+        prefix: bool,
+        weights: Vec<Weight>,
+    },
+    Not(Box<TsQueryNode>),
+    And(Box<TsQueryNode>, Box<TsQueryNode>),
+    Or(Box<TsQueryNode>, Box<TsQueryNode>),
+    /// Matches when `right` occurs exactly `distance` positions after `left`.
+    Phrase {
+        left: Box<TsQueryNode>,
+        right: Box<TsQueryNode>,
+        distance: u16,
+    },
+}
+
+/// The positions at which a node matches within a phrase.
+enum PhraseMatch {
+    NoMatch,
+    /// The node matches, but the vector does not record positions for (some
+    /// of) the matched lexemes.
+    Unpositioned,
+    Positions(BTreeSet<u16>),
+}
+
+impl TsQueryNode {
+    /// The binding strength of the node's operator; higher binds tighter.
+    fn precedence(&self) -> u8 {
+        match self {
+            TsQueryNode::Or(..) => 1,
+            TsQueryNode::And(..) => 2,
+            TsQueryNode::Phrase { .. } => 3,
+            TsQueryNode::Not(_) => 4,
+            TsQueryNode::Lexeme { .. } => 5,
+        }
+    }
+
+    /// Returns the number of nodes on the longest path from this node to an
+    /// operand.
+    pub fn depth(&self) -> usize {
+        let mut max = 0;
+        let mut stack = vec![(self, 1)];
+        while let Some((node, depth)) = stack.pop() {
+            max = max.max(depth);
+            match node {
+                TsQueryNode::Lexeme { .. } => {}
+                TsQueryNode::Not(child) => stack.push((child, depth + 1)),
+                TsQueryNode::And(left, right)
+                | TsQueryNode::Or(left, right)
+                | TsQueryNode::Phrase { left, right, .. } => {
+                    stack.push((left, depth + 1));
+                    stack.push((right, depth + 1));
+                }
+            }
+        }
+        max
+    }
+
+    /// Returns the lexemes that every vector that the node matches contains,
+    /// also within phrases.
+    fn required_lexemes(&self) -> BTreeSet<&str> {
+        match self {
+            TsQueryNode::Lexeme {
+                word,
+                prefix: false,
+                ..
+            } => BTreeSet::from([word.as_str()]),
+            TsQueryNode::Lexeme { prefix: true, .. } | TsQueryNode::Not(_) => BTreeSet::new(),
+            TsQueryNode::And(left, right) | TsQueryNode::Phrase { left, right, .. } => {
+                let mut lexemes = left.required_lexemes();
+                lexemes.extend(right.required_lexemes());
+                lexemes
+            }
+            TsQueryNode::Or(left, right) => {
+                let right = right.required_lexemes();
+                left.required_lexemes()
+                    .into_iter()
+                    .filter(|lexeme| right.contains(lexeme))
+                    .collect()
+            }
+        }
+    }
+
+    fn matches(&self, vector: &TsVector) -> bool {
+        match self {
+            TsQueryNode::Lexeme {
+                word,
+                prefix,
+                weights,
+            } => vector
+                .matching(word, *prefix)
+                .iter()
+                .any(|l| weights.is_empty() || !matching_positions(l, weights).is_empty()),
+            TsQueryNode::Not(node) => !node.matches(vector),
+            TsQueryNode::And(left, right) => left.matches(vector) && right.matches(vector),
+            TsQueryNode::Or(left, right) => left.matches(vector) || right.matches(vector),
+            TsQueryNode::Phrase { .. } => {
+                !matches!(self.phrase_match(vector), PhraseMatch::NoMatch)
+            }
+        }
+    }
+
+    fn phrase_match(&self, vector: &TsVector) -> PhraseMatch {
+        match self {
+            TsQueryNode::Lexeme {
+                word,
+                prefix,
+                weights,
+            } => {
+                let mut out = BTreeSet::new();
+                let mut matched = false;
+                for lexeme in vector.matching(word, *prefix) {
+                    if lexeme.positions.is_empty() {
+                        if weights.is_empty() {
+                            return PhraseMatch::Unpositioned;
+                        }
+                        continue;
+                    }
+                    let positions = matching_positions(lexeme, weights);
+                    matched |= !positions.is_empty();
+                    out.extend(positions);
+                }
+                if matched {
+                    PhraseMatch::Positions(out)
+                } else {
+                    PhraseMatch::NoMatch
+                }
+            }
+            // Negations within phrases match the positions of every lexeme
+            // that the negated operand does not match.
+            TsQueryNode::Not(node) => match node.phrase_match(vector) {
+                PhraseMatch::NoMatch => PhraseMatch::Unpositioned,
+                PhraseMatch::Unpositioned => PhraseMatch::NoMatch,
+                PhraseMatch::Positions(positions) => {
+                    let rest: BTreeSet<_> = vector
+                        .all_positions()
+                        .difference(&positions)
+                        .copied()
+                        .collect();
+                    if rest.is_empty() {
+                        PhraseMatch::NoMatch
+                    } else {
+                        PhraseMatch::Positions(rest)
+                    }
+                }
+            },
+            TsQueryNode::And(left, right) => {
+                match (left.phrase_match(vector), right.phrase_match(vector)) {
+                    (PhraseMatch::NoMatch, _) | (_, PhraseMatch::NoMatch) => PhraseMatch::NoMatch,
+                    (PhraseMatch::Unpositioned, other) | (other, PhraseMatch::Unpositioned) => {
+                        other
+                    }
+                    (PhraseMatch::Positions(l), PhraseMatch::Positions(r)) => {
+                        let both: BTreeSet<_> = l.intersection(&r).copied().collect();
+                        if both.is_empty() {
+                            PhraseMatch::NoMatch
+                        } else {
+                            PhraseMatch::Positions(both)
+                        }
+                    }
+                }
+            }
+            TsQueryNode::Or(left, right) => {
+                match (left.phrase_match(vector), right.phrase_match(vector)) {
+                    (PhraseMatch::NoMatch, other) | (other, PhraseMatch::NoMatch) => other,
+                    (PhraseMatch::Unpositioned, _) | (_, PhraseMatch::Unpositioned) => {
+                        PhraseMatch::Unpositioned
+                    }
+                    (PhraseMatch::Positions(mut l), PhraseMatch::Positions(r)) => {
+                        l.extend(r);
+                        PhraseMatch::Positions(l)
+                    }
+                }
+            }
+            TsQueryNode::Phrase {
+                left,
+                right,
+                distance,
+            } => match (left.phrase_match(vector), right.phrase_match(vector)) {
+                (PhraseMatch::NoMatch, _) | (_, PhraseMatch::NoMatch) => PhraseMatch::NoMatch,
+                (PhraseMatch::Unpositioned, other) | (other, PhraseMatch::Unpositioned) => {
+                    match other {
+                        PhraseMatch::Positions(_) | PhraseMatch::Unpositioned => {
+                            PhraseMatch::Unpositioned
+                        }
+                        PhraseMatch::NoMatch => PhraseMatch::NoMatch,
+                    }
+                }
+                (PhraseMatch::Positions(l), PhraseMatch::Positions(r)) => {
+                    let out: BTreeSet<_> = r
+                        .into_iter()
+                        .filter(|p| p.checked_sub(*distance).is_some_and(|p| l.contains(&p)))
+                        .collect();
+                    if out.is_empty() {
+                        PhraseMatch::NoMatch
+                    } else {
+                        PhraseMatch::Positions(out)
+                    }
+                }
+            },
+        }
+    }
+
+    /// Collects the operands of the query, in order of appearance.
+    fn operands<'a>(&'a self, out: &mut Vec<(&'a str, bool, &'a [Weight])>) {
+        match self {
+            TsQueryNode::Lexeme {
+                word,
+                prefix,
+                weights,
+            } => out.push((word, *prefix, weights)),
+            TsQueryNode::Not(node) => node.operands(out),
+            TsQueryNode::And(left, right)
+            | TsQueryNode::Or(left, right)
+            | TsQueryNode::Phrase { left, right, .. } => {
+                left.operands(out);
+                right.operands(out);
+            }
+        }
+    }
+
+    fn fmt_child(&self, f: &mut fmt::Formatter, child: &TsQueryNode) -> fmt::Result {
+        if child.precedence() < self.precedence()
+            || (matches!(self, TsQueryNode::Phrase { .. }) && child.precedence() == 3)
+        {
+            write!(f, "( {child} )")
+        } else {
+            write!(f, "{child}")
+        }
+    }
+}
+
+/// Returns the positions of `lexeme` with one of the given weights, or all of
+/// its positions if `weights` is empty.
+fn matching_positions(lexeme: &Lexeme, weights: &[Weight]) -> Vec<u16> {
+    lexeme
+        .positions
+        .iter()
+        .filter(|p| weights.is_empty() || weights.contains(&p.weight))
+        .map(|p| p.position)
+        .collect()
+}
+
+impl fmt::Display for TsQueryNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TsQueryNode::Lexeme {
+                word,
+                prefix,
+                weights,
+            } => {
+                write_quoted(f, word)?;
+                if *prefix || !weights.is_empty() {
+                    f.write_str(":")?;
+                }
+                if *prefix {
+                    f.write_str("*")?;
+                }
+                for w in [Weight::A, Weight::B, Weight::C, Weight::D] {
+                    if weights.contains(&w) {
+                        write!(f, "{}", w.as_char())?;
+                    }
+                }
+                Ok(())
+            }
+            TsQueryNode::Not(node) => {
+                f.write_str("!")?;
+                self.fmt_child(f, node)
+            }
+            TsQueryNode::And(left, right) => {
+                self.fmt_child(f, left)?;
+                f.write_str(" & ")?;
+                self.fmt_child(f, right)
+            }
+            TsQueryNode::Or(left, right) => {
+                self.fmt_child(f, left)?;
+                f.write_str(" | ")?;
+                self.fmt_child(f, right)
+            }
+            TsQueryNode::Phrase {
+                left,
+                right,
+                distance,
+            } => {
+                self.fmt_child(f, left)?;
+                if *distance == 1 {
+                    f.write_str(" <-> ")?;
+                } else {
+                    write!(f, " <{distance}> ")?;
+                }
+                self.fmt_child(f, right)
+            }
+        }
+    }
+}
+
+/// A text search query, which is matched against a [`TsVector`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TsQuery {
+    /// The root of the query, or `None` if the query is empty. Empty queries
+    /// match nothing.
+    pub root: Option<TsQueryNode>,
+}
+
+impl TsQuery {
+    /// Reports whether `vector` matches the query, i.e. the result of the
+    /// `@@` operator.
+    pub fn matches(&self, vector: &TsVector) -> bool {
+        match &self.root {
+            Some(root) => root.matches(vector),
+            None => false,
+        }
+    }
+
+    /// Returns lexemes that every vector that matches the query contains.
+    ///
+    /// Vectors that contain all of them need not match the query. The result
+    /// is empty if the query has no such lexemes, e.g. for `a | b`, `!a` or
+    /// `a:*`.
+    pub fn required_lexemes(&self) -> BTreeSet<&str> {
+        match &self.root {
+            Some(root) => root.required_lexemes(),
+            None => BTreeSet::new(),
+        }
+    }
+
+    /// Ranks how well `vector` matches the query, using the algorithm and
+    /// default weights of PostgreSQL's `ts_rank`.
+    pub fn rank(&self, vector: &TsVector) -> f32 {
+        let Some(root) = &self.root else {
+            return 0.0;
+        };
+        let mut operands = vec![];
+        root.operands(&mut operands);
+        operands.sort();
+        operands.dedup();
+        let rank = match root {
+            TsQueryNode::And(..) | TsQueryNode::Phrase { .. } => rank_and(vector, &operands),
+            _ => rank_or(vector, &operands),
+        };
+        if rank < 0.0 { 1e-20 } else { rank }
+    }
+}
+
+/// The positions of `lexeme`, or a single position of weight `D` if the vector
+/// does not record positions for the lexeme.
+fn rank_positions(lexeme: &Lexeme) -> &[LexemePosition] {
+    const UNPOSITIONED: &[LexemePosition] = &[LexemePosition {
+        position: 0,
+        weight: Weight::D,
+    }];
+    if lexeme.positions.is_empty() {
+        UNPOSITIONED
+    } else {
+        &lexeme.positions
+    }
+}
+
+fn rank_or(vector: &TsVector, operands: &[(&str, bool, &[Weight])]) -> f32 {
+    // The sum of 1/n^2 for all n, which normalizes the rank of a lexeme that
+    // occurs arbitrarily often.
+    const ZETA_2: f32 = 1.644_934;
+    let mut rank = 0.0;
+    for (word, prefix, _) in operands {
+        for lexeme in vector.matching(word, *prefix) {
+            let mut sum = 0.0;
+            let mut max_weight = -1.0;
+            let mut max_index = 0;
+            for (i, p) in rank_positions(lexeme).iter().enumerate() {
+                let w = p.weight.rank_weight();
+                let n = (i + 1) as f32;
+                sum += w / (n * n);
+                if w > max_weight {
+                    max_weight = w;
+                    max_index = i;
+                }
+            }
+            let n = (max_index + 1) as f32;
+            rank += (max_weight + sum - max_weight / (n * n)) / ZETA_2;
+        }
+    }
+    if !operands.is_empty() {
+        rank /= operands.len() as f32;
+    }
+    rank
+}
+
+fn rank_and(vector: &TsVector, operands: &[(&str, bool, &[Weight])]) -> f32 {
+    if operands.len() < 2 {
+        return rank_or(vector, operands);
+    }
+    let word_distance = |distance: u16| -> f32 {
+        if distance > 100 {
+            1e-30
+        } else {
+            (1.0 / (1.005 + 0.05 * (f64::from(distance) / 1.5 - 2.0).exp())) as f32
+        }
+    };
+    let positions: Vec<Vec<&[LexemePosition]>> = operands
+        .iter()
+        .map(|(word, prefix, _)| {
+            vector
+                .matching(word, *prefix)
+                .iter()
+                .map(rank_positions)
+                .collect()
+        })
+        .collect();
+    let mut rank: f32 = -1.0;
+    for i in 0..positions.len() {
+        for k in 0..i {
+            for a in positions[i].iter().flat_map(|p| p.iter()) {
+                for b in positions[k].iter().flat_map(|p| p.iter()) {
+                    let distance = a.position.abs_diff(b.position);
+                    if distance == 0 && a.position != 0 && b.position != 0 {
+                        continue;
+                    }
+                    let w =
+                        (a.weight.rank_weight() * b.weight.rank_weight() * word_distance(distance))
+                            .sqrt();
+                    rank = if rank < 0.0 {
+                        w
+                    } else {
+                        1.0 - (1.0 - rank) * (1.0 - w)
+                    };
+                }
+            }
+        }
+    }
+    rank
+}
+
+impl fmt::Display for TsQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.root {
+            Some(root) => write!(f, "{root}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A recursive descent parser for the text form of a [`TsQuery`].
+///
+/// Every parse method returns the parsed node along with its depth, and
+/// rejects nodes deeper than [`MAX_QUERY_DEPTH`].
+struct QueryParser<'a> {
+    lexer: Lexer<'a>,
+    recursion_guard: RecursionGuard,
+}
+
+impl<'a> CheckedRecursion for QueryParser<'a> {
+    fn recursion_guard(&self) -> &RecursionGuard {
+        &self.recursion_guard
+    }
+}
+
+impl<'a> QueryParser<'a> {
+    fn is_special(c: char) -> bool {
+        matches!(c, '!' | '&' | '|' | '(' | ')' | '<' | ':')
+    }
+
+    fn consume(&mut self, c: char) -> bool {
+        self.lexer.skip_whitespace();
+        self.lexer.consume(c)
+    }
+
+    /// Returns the depth of a node whose deepest child has depth `child`.
+    fn parent_depth(child: usize) -> Result<usize, Error> {
+        if child >= MAX_QUERY_DEPTH {
+            bail!("tsquery cannot be nested more than {MAX_QUERY_DEPTH} levels deep");
+        }
+        Ok(child + 1)
+    }
+
+    fn parse_or(&mut self) -> Result<(TsQueryNode, usize), Error> {
+        let (mut node, mut depth) = self.parse_and()?;
+        while self.consume('|') {
+            let (right, right_depth) = self.parse_and()?;
+            depth = Self::parent_depth(depth.max(right_depth))?;
+            node = TsQueryNode::Or(Box::new(node), Box::new(right));
+        }
+        Ok((node, depth))
+    }
+
+    fn parse_and(&mut self) -> Result<(TsQueryNode, usize), Error> {
+        let (mut node, mut depth) = self.parse_phrase()?;
+        while self.consume('&') {
+            let (right, right_depth) = self.parse_phrase()?;
+            depth = Self::parent_depth(depth.max(right_depth))?;
+            node = TsQueryNode::And(Box::new(node), Box::new(right));
+        }
+        Ok((node, depth))
+    }
+
+    fn parse_phrase(&mut self) -> Result<(TsQueryNode, usize), Error> {
+        let (mut node, mut depth) = self.parse_not()?;
+        while self.consume('<') {
+            let distance = if self.lexer.consume('-') {
+                1
+            } else {
+                match self.lexer.parse_number() {
+                    Some(d) if d <= u64::from(MAX_POSITION) => {
+                        u16::try_from(d).expect("known to fit")
+                    }
+                    Some(_) => bail!(
+                        "distance in phrase operator must be an integer value between zero and {MAX_POSITION} inclusive"
+                    ),
+                    None => bail!("syntax error"),
+                }
+            };
+            if !self.lexer.consume('>') {
+                bail!("syntax error");
+            }
+            let (right, right_depth) = self.parse_not()?;
+            depth = Self::parent_depth(depth.max(right_depth))?;
+            node = TsQueryNode::Phrase {
+                left: Box::new(node),
+                right: Box::new(right),
+                distance,
+            };
+        }
+        Ok((node, depth))
+    }
+
+    fn parse_not(&mut self) -> Result<(TsQueryNode, usize), Error> {
+        if self.consume('!') {
+            let (node, depth) = self.checked_recur_mut(|p| p.parse_not())?;
+            Ok((TsQueryNode::Not(Box::new(node)), Self::parent_depth(depth)?))
+        } else {
+            self.parse_primary()
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<(TsQueryNode, usize), Error> {
+        if self.consume('(') {
+            let node = self.checked_recur_mut(|p| p.parse_or())?;
+            if !self.consume(')') {
+                bail!("syntax error");
+            }
+            return Ok(node);
+        }
+        self.lexer.skip_whitespace();
+        let word = self.lexer.parse_word(Self::is_special)?;
+        let mut prefix = false;
+        let mut weights = vec![];
+        if self.lexer.consume(':') {
+            while let Some(c) = self.lexer.peek() {
+                if c == '*' {
+                    prefix = true;
+                } else if let Some(w) = Weight::from_char(c) {
+                    if !weights.contains(&w) {
+                        weights.push(w);
+                    }
+                } else {
+                    break;
+                }
+                self.lexer.next();
+            }
+        }
+        weights.sort();
+        let node = TsQueryNode::Lexeme {
+            word,
+            prefix,
+            weights,
+        };
+        Ok((node, 1))
+    }
+}
+
+impl FromStr for TsQuery {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = QueryParser {
+            lexer: Lexer::new(s),
+            // Groups do not add nodes, so their nesting is limited separately.
+            recursion_guard: RecursionGuard::with_limit(MAX_QUERY_DEPTH),
+        };
+        parser.lexer.skip_whitespace();
+        if parser.lexer.is_done() {
+            return Ok(TsQuery { root: None });
+        }
+        let (root, _) = parser.parse_or()?;
+        parser.lexer.skip_whitespace();
+        if !parser.lexer.is_done() {
+            return Err(anyhow!("syntax error"));
+        }
+        Ok(TsQuery { root: Some(root) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_tsvector_roundtrip() {
+        for (input, expected) in [
+            ("a fat cat", "'a' 'cat' 'fat'"),
+            ("fat:2,1 cat:3A fat:4B", "'cat':3A 'fat':1,2,4B"),
+            ("'it''s' 'a\\\\b'", "'a\\\\b' 'it''s'"),
+            ("x:1A,1", "'x':1A"),
+            ("x:20000", "'x':16383"),
+            ("", ""),
+        ] {
+            let vector: TsVector = input.parse().unwrap();
+            assert_eq!(vector.to_string(), expected, "input: {input}");
+        }
+        for input in ["x:0", "x:", "'x", "x:1Q"] {
+            assert!(input.parse::<TsVector>().is_err(), "input: {input}");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_tsquery_roundtrip() {
+        for (input, expected) in [
+            ("fat & rat", "'fat' & 'rat'"),
+            ("fat & (rat | cat)", "'fat' & ( 'rat' | 'cat' )"),
+            ("!fat <-> rat", "!'fat' <-> 'rat'"),
+            ("a <2> b", "'a' <2> 'b'"),
+            ("super:*AB", "'super':*AB"),
+            ("", ""),
+        ] {
+            let query: TsQuery = input.parse().unwrap();
+            assert_eq!(query.to_string(), expected, "input: {input}");
+        }
+        for input in ["a &", "(a", "a b", "a <x> b"] {
+            assert!(input.parse::<TsQuery>().is_err(), "input: {input}");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_tsquery_depth() {
+        let chain = |n: usize| vec!["a"; n].join(" & ");
+        let query: TsQuery = chain(MAX_QUERY_DEPTH).parse().unwrap();
+        assert_eq!(query.root.unwrap().depth(), MAX_QUERY_DEPTH);
+        for input in [
+            chain(MAX_QUERY_DEPTH + 1),
+            chain(100_000),
+            format!("{}a", "!".repeat(100_000)),
+            format!("{}a{}", "(".repeat(100_000), ")".repeat(100_000)),
+        ] {
+            assert!(input.parse::<TsQuery>().is_err());
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_tsquery_matches() {
+        let vector: TsVector = "'fat':2 'cat':3 'rat':5A".parse().unwrap();
+        for (query, expected) in [
+            ("cat", true),
+            ("dog", false),
+            ("cat & rat", true),
+            ("cat & !rat", false),
+            ("dog | rat", true),
+            ("fat <-> cat", true),
+            ("cat <-> fat", false),
+            ("cat <2> rat", true),
+            ("ca:*", true),
+            ("rat:A", true),
+            ("cat:AB", false),
+        ] {
+            let q: TsQuery = query.parse().unwrap();
+            assert_eq!(q.matches(&vector), expected, "query: {query}");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_tsquery_required_lexemes() {
+        for (query, expected) in [
+            ("cat", vec!["cat"]),
+            ("cat & !rat", vec!["cat"]),
+            ("fat <-> (cat | rat)", vec!["fat"]),
+            ("(cat & fat) | (cat & rat)", vec!["cat"]),
+            ("cat | rat", vec![]),
+            ("!cat", vec![]),
+            ("ca:* & rat:A", vec!["rat"]),
+            ("", vec![]),
+        ] {
+            let q: TsQuery = query.parse().unwrap();
+            let lexemes: Vec<_> = q.required_lexemes().into_iter().collect();
+            assert_eq!(lexemes, expected, "query: {query}");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_tsquery_rank() {
+        let vector: TsVector = "'a':1 'cat':3 'fat':2".parse().unwrap();
+        let query: TsQuery = "cat".parse().unwrap();
+        assert!((query.rank(&vector) - 0.060_792_7).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,300 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Fixed-dimension vectors of single-precision floats, e.g. for storing
+//! embeddings.
+//!
+//! The type, its text format, and its distance functions follow the
+//! [pgvector](https://github.com/pgvector/pgvector) PostgreSQL extension.
+//! Instead of pgvector's HNSW and IVFFlat indexes, approximate
+//! nearest-neighbor indexes hash each vector into one of
+//! 2^[`ANN_HYPERPLANES`] buckets by the side of a fixed set of hyperplanes
+//! through the origin that it falls on (see [`ann_bucket`]). The buckets
+//! only depend on the direction of a vector, so they approximate the cosine
+//! distance, but neither the Euclidean distance nor the inner product. If a
+//! session opts into approximate results, a peek for the nearest rows by
+//! cosine distance only computes the distance to the rows in the buckets that
+//! [`ann_probes`] returns for the query vector.
+
+use std::error::Error;
+use std::fmt;
+
+use mz_lowertest::MzReflect;
+use mz_ore::cast::{CastFrom, CastLossy};
+use mz_proto::{RustType, TryFromProtoError};
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::{BoxedStrategy, Strategy};
+use serde::{Deserialize, Serialize};
+
+include!(concat!(env!("OUT_DIR"), "/mz_repr.adt.vector.rs"));
+
+/// The maximum number of dimensions of a vector.
+// https://github.com/pgvector/pgvector/blob/v0.8.0/src/vector.h#L4
+pub const MAX_DIMENSIONS: u32 = 16_000;
+
+/// The `dimensions` of a [`ScalarType::Vector`].
+///
+/// This newtype wrapper ensures that the number of dimensions is within the
+/// valid range.
+///
+/// [`ScalarType::Vector`]: crate::ScalarType::Vector
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, MzReflect,
+)]
+pub struct VectorDimensions(pub(crate) u32);
+
+impl VectorDimensions {
+    /// Consumes the newtype wrapper, returning the inner `u32`.
+    pub fn into_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Returns an error if a vector with `len` elements does not have these
+    /// dimensions.
+    pub fn check(self, len: usize) -> Result<(), VectorDimensionsMismatchError> {
+        if usize::try_from(self.0) == Ok(len) {
+            Ok(())
+        } else {
+            Err(VectorDimensionsMismatchError {
+                expected: self.0,
+                actual: len,
+            })
+        }
+    }
+}
+
+impl TryFrom<i64> for VectorDimensions {
+    type Error = InvalidVectorDimensionsError;
+
+    fn try_from(dimensions: i64) -> Result<Self, Self::Error> {
+        match u32::try_from(dimensions) {
+            Ok(dimensions) if dimensions > 0 && dimensions <= MAX_DIMENSIONS => {
+                Ok(VectorDimensions(dimensions))
+            }
+            _ => Err(InvalidVectorDimensionsError),
+        }
+    }
+}
+
+impl RustType<ProtoVectorDimensions> for VectorDimensions {
+    fn into_proto(&self) -> ProtoVectorDimensions {
+        ProtoVectorDimensions { value: self.0 }
+    }
+
+    fn from_proto(proto: ProtoVectorDimensions) -> Result<Self, TryFromProtoError> {
+        Ok(VectorDimensions(proto.value))
+    }
+}
+
+impl Arbitrary for VectorDimensions {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<VectorDimensions>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        // Large vectors are uninteresting and slow down tests.
+        (1..=16u32).prop_map(VectorDimensions).boxed()
+    }
+}
+
+/// The error returned when constructing a [`VectorDimensions`] from an invalid
+/// value.
+#[derive(Debug, Clone)]
+pub struct InvalidVectorDimensionsError;
+
+impl fmt::Display for InvalidVectorDimensionsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "dimensions for type vector must be between 1 and {}",
+            MAX_DIMENSIONS
+        )
+    }
+}
+
+impl Error for InvalidVectorDimensionsError {}
+
+/// The error returned when a vector does not have the expected dimensions.
+#[derive(Debug, Clone)]
+pub struct VectorDimensionsMismatchError {
+    pub expected: u32,
+    pub actual: usize,
+}
+
+impl fmt::Display for VectorDimensionsMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "expected {} dimensions, not {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl Error for VectorDimensionsMismatchError {}
+
+/// Returns the Euclidean distance between `a` and `b`.
+///
+/// The vectors must have the same number of elements.
+pub fn l2_distance(a: &[f32], b: &[f32]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| {
+            let d = f64::from(*a) - f64::from(*b);
+            d * d
+        })
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Returns the inner product of `a` and `b`.
+///
+/// The vectors must have the same number of elements.
+pub fn inner_product(a: &[f32], b: &[f32]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| f64::from(*a) * f64::from(*b))
+        .sum()
+}
+
+/// Returns the cosine distance between `a` and `b`, i.e. one minus their
+/// cosine similarity.
+///
+/// The distance is `NaN` if either vector has no magnitude. The vectors must
+/// have the same number of elements.
+pub fn cosine_distance(a: &[f32], b: &[f32]) -> f64 {
+    let similarity = inner_product(a, b) / (norm(a) * norm(b));
+    if similarity.is_nan() {
+        return f64::NAN;
+    }
+    // Guard against rounding errors pushing the similarity out of range.
+    1.0 - similarity.clamp(-1.0, 1.0)
+}
+
+/// Returns the Euclidean norm of `a`.
+pub fn norm(a: &[f32]) -> f64 {
+    a.iter()
+        .map(|a| f64::from(*a) * f64::from(*a))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// The number of hyperplanes that partition the vectors of an approximate
+/// nearest-neighbor index into buckets.
+pub const ANN_HYPERPLANES: u32 = 8;
+
+/// Returns the approximate nearest-neighbor bucket of `a`.
+///
+/// Bit `i` of the bucket is set if `a` lies on the positive side of
+/// hyperplane `i`. Vectors with a small angle between them are likely to
+/// fall on the same side of most hyperplanes, and so to share a bucket or
+/// land in buckets that differ in few bits.
+pub fn ann_bucket(a: &[f32]) -> i32 {
+    (0..ANN_HYPERPLANES).fold(0, |bucket, plane| {
+        let side = a
+            .iter()
+            .enumerate()
+            .map(|(i, a)| f64::from(*a) * hyperplane_component(plane, i))
+            .sum::<f64>();
+        if side > 0.0 {
+            bucket | (1 << plane)
+        } else {
+            bucket
+        }
+    })
+}
+
+/// Returns the approximate nearest-neighbor buckets to search for the
+/// neighbors of `a`: its own bucket, followed by the buckets that differ from
+/// it in one hyperplane.
+///
+/// The buckets are distinct.
+pub fn ann_probes(a: &[f32]) -> Vec<i32> {
+    let bucket = ann_bucket(a);
+    std::iter::once(bucket)
+        .chain((0..ANN_HYPERPLANES).map(|plane| bucket ^ (1 << plane)))
+        .collect()
+}
+
+/// Returns component `i` of the normal of hyperplane `plane`, a pseudo-random
+/// number in `[-1, 1)` that only depends on `plane` and `i`.
+///
+/// The normals must never change, as the buckets of existing index entries
+/// would otherwise no longer match the buckets of the query vectors.
+fn hyperplane_component(plane: u32, i: usize) -> f64 {
+    // SplitMix64, seeded by the plane and the dimension.
+    let mut z = ((u64::from(plane) << 32) | u64::cast_from(i)).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    // Use the top 53 bits, which `f64` represents exactly.
+    f64::cast_lossy(z >> 11) / f64::cast_lossy(1u64 << 52) - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_ore::assert_ok;
+    use mz_proto::protobuf_roundtrip;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[mz_ore::test]
+        fn vector_dimensions_protobuf_roundtrip(expect in any::<VectorDimensions>()) {
+            let actual = protobuf_roundtrip::<_, ProtoVectorDimensions>(&expect);
+            assert_ok!(actual);
+            assert_eq!(actual.unwrap(), expect);
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_distances() {
+        let a = [1.0, 2.0, 3.0];
+        let b = [4.0, 6.0, 3.0];
+        assert_eq!(l2_distance(&a, &b), 5.0);
+        assert_eq!(inner_product(&a, &b), 25.0);
+        assert_eq!(norm(&[3.0, 4.0]), 5.0);
+        assert_eq!(cosine_distance(&a, &a), 0.0);
+        assert_eq!(cosine_distance(&[1.0, 0.0], &[0.0, 1.0]), 1.0);
+        assert_eq!(cosine_distance(&[1.0, 0.0], &[-1.0, 0.0]), 2.0);
+        assert!(cosine_distance(&[0.0, 0.0], &[1.0, 1.0]).is_nan());
+    }
+
+    #[mz_ore::test]
+    fn test_ann_buckets() {
+        let a = [1.0, 2.0, 3.0];
+        let bucket = ann_bucket(&a);
+        assert!((0..1 << ANN_HYPERPLANES).contains(&bucket));
+        // The bucket only depends on the direction of the vector.
+        assert_eq!(ann_bucket(&[2.0, 4.0, 6.0]), bucket);
+        assert_eq!(ann_bucket(&[-1.0, -2.0, -3.0]), !bucket & 0xff);
+
+        let probes = ann_probes(&a);
+        assert_eq!(probes[0], bucket);
+        assert_eq!(probes.len(), 1 + usize::cast_from(ANN_HYPERPLANES));
+        let mut distinct = probes.clone();
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(distinct.len(), probes.len());
+        assert!(probes[1..].iter().all(|p| (p ^ bucket).count_ones() == 1));
+    }
+
+    #[mz_ore::test]
+    fn test_dimensions() {
+        assert!(VectorDimensions::try_from(0).is_err());
+        assert!(VectorDimensions::try_from(-1).is_err());
+        assert!(VectorDimensions::try_from(i64::from(MAX_DIMENSIONS) + 1).is_err());
+        let dims = VectorDimensions::try_from(3).unwrap();
+        assert!(dims.check(3).is_ok());
+        assert_eq!(
+            dims.check(2).unwrap_err().to_string(),
+            "expected 3 dimensions, not 2"
+        );
+    }
+}
@@ -785,6 +785,48 @@ impl UsedIndexes {
     }
 }
 
+/// Statistics about the contents of a collection, as seen by the optimizer.
+#[derive(
+    Clone, Debug, Default, Arbitrary, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd, Hash,
+)]
+pub struct CollectionStatistics {
+    /// The estimated number of rows in the collection.
+    pub rows: Option<usize>,
+    /// The estimated number of distinct values in some of the columns of the collection.
+    pub distinct: BTreeMap<usize, usize>,
+}
+
+impl CollectionStatistics {
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_none() && self.distinct.is_empty()
+    }
+}
+
+/// The collection statistics that informed the cost estimates of the explained plan.
+///
+/// Using a `BTreeMap` here ensures a deterministic iteration order, which in turn ensures that
+/// the corresponding EXPLAIN output is deterministic as well.
+#[derive(Clone, Debug, Default)]
+pub struct UsedStatistics(BTreeMap<GlobalId, CollectionStatistics>);
+
+impl UsedStatistics {
+    pub fn new(values: BTreeMap<GlobalId, CollectionStatistics>) -> UsedStatistics {
+        UsedStatistics(values)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the numbers of distinct values of all columns with known statistics.
+    pub fn distinct_map(&self) -> BTreeMap<(GlobalId, usize), usize> {
+        self.0
+            .iter()
+            .flat_map(|(id, stats)| stats.distinct.iter().map(|(col, n)| ((*id, *col), *n)))
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, Arbitrary, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum IndexUsageType {
     /// Read the entire index.
@@ -815,6 +857,9 @@ pub enum IndexUsageType {
     /// Note: When a fast path peek does a lookup and also has a limit, the usage type will be
     /// `Lookup`. However, the smart limiting logic will still apply.
     FastPathLimit,
+    /// A fast path peek that orders by the distance to a literal vector reads only the buckets of
+    /// an ANN index that are near that vector.
+    NearestNeighbors,
     /// We saw a dangling `ArrangeBy`, i.e., where we have no idea what the arrangement will be used
     /// for. This is an internal error. Can be a bug either in `CollectIndexRequests`, or some
     /// other transform that messed up the plan. It's also possible that somebody is trying to add
@@ -860,6 +905,7 @@ impl std::fmt::Display for IndexUsageType {
                 IndexUsageType::SinkExport => "sink export",
                 IndexUsageType::IndexExport => "index export",
                 IndexUsageType::FastPathLimit => "fast path limit",
+                IndexUsageType::NearestNeighbors => "nearest neighbor lookup",
                 IndexUsageType::DanglingArrangeBy => "*** INTERNAL ERROR (dangling ArrangeBy) ***",
                 IndexUsageType::Unknown => "*** INTERNAL ERROR (unknown usage) ***",
             }
@@ -11,11 +11,11 @@
 
 use std::fmt;
 
-use mz_ore::str::Indent;
+use mz_ore::str::{Indent, separated};
 
 use crate::explain::{
     CompactScalarSeq, CompactScalars, ExprHumanizer, IndexUsageType, Indices, ScalarOps,
-    UnsupportedFormat, UsedIndexes,
+    UnsupportedFormat, UsedIndexes, UsedStatistics,
 };
 
 /// A trait implemented by explanation types that can be rendered as
@@ -84,6 +84,45 @@ where
     }
 }
 
+impl<'a, C> DisplayText<C> for UsedStatistics
+where
+    C: AsMut<Indent> + AsRef<&'a dyn ExprHumanizer>,
+{
+    fn fmt_text(&self, f: &mut fmt::Formatter<'_>, ctx: &mut C) -> fmt::Result {
+        writeln!(f, "{}Statistics:", ctx.as_mut())?;
+        *ctx.as_mut() += 1;
+        for (id, stats) in &self.0 {
+            let name = ctx
+                .as_ref()
+                .humanize_id(*id)
+                .unwrap_or_else(|| "[DELETED]".to_string());
+            let rows = match stats.rows {
+                Some(rows) => format!("{rows} rows"),
+                None => "unknown rows".to_string(),
+            };
+            if stats.distinct.is_empty() {
+                writeln!(f, "{}- {} ({})", ctx.as_mut(), name, rows)?;
+            } else {
+                let distinct = stats
+                    .distinct
+                    .iter()
+                    .map(|(col, count)| format!("#{col}={count}"));
+                let distinct = separated(", ", distinct);
+                writeln!(
+                    f,
+                    "{}- {} ({}; distinct values: {})",
+                    ctx.as_mut(),
+                    name,
+                    rows,
+                    distinct
+                )?;
+            }
+        }
+        *ctx.as_mut() -= 1;
+        Ok(())
+    }
+}
+
 impl<'a> fmt::Display for Indices<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut is_first = true;
@@ -126,6 +126,14 @@ optimizer_feature_flags!({
     enable_less_reduce_in_eqprop: bool,
     // See the feature flag of the same name.
     enable_dequadratic_eqprop_map: bool,
+    // See the feature flag of the same name.
+    enable_ordered_top_k: bool,
+    // See the feature flag of the same name.
+    enable_join_elimination: bool,
+    // See the feature flag of the same name.
+    enable_join_size_estimates: bool,
+    // See the feature flag of the same name.
+    enable_spatial_join: bool,
 });
 
 /// A trait used to implement layered config construction.
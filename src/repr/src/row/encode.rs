@@ -101,11 +101,16 @@ pub fn preserves_order(scalar_type: &ScalarType) -> bool {
         | ScalarType::TimestampTz { .. }
         | ScalarType::Interval
         | ScalarType::Bytes
+        | ScalarType::Geometry
         | ScalarType::String
         | ScalarType::Uuid
         | ScalarType::MzTimestamp
         | ScalarType::MzAclItem
-        | ScalarType::AclItem => true,
+        | ScalarType::AclItem
+        | ScalarType::TsVector
+        | ScalarType::TsQuery
+        // Enums are encoded as their position in the declared label order.
+        | ScalarType::Enum { .. } => true,
         // We sort records lexicographically; a record has a meaningful sort if all its fields do.
         ScalarType::Record { fields, .. } => fields
             .iter()
@@ -131,6 +136,7 @@ pub fn preserves_order(scalar_type: &ScalarType) -> bool {
         | ScalarType::RegType
         | ScalarType::RegClass
         | ScalarType::Int2Vector
+        | ScalarType::Vector { .. }
         | ScalarType::Range { .. } => false,
     }
 }
@@ -1559,7 +1565,8 @@ fn array_to_decoder(
             | ScalarType::Oid
             | ScalarType::RegClass
             | ScalarType::RegProc
-            | ScalarType::RegType,
+            | ScalarType::RegType
+            | ScalarType::Enum { .. },
         ) => {
             let array = downcast_array::<UInt32Array>(array)?;
             DatumColumnDecoder::U32(array.clone())
@@ -1604,12 +1611,14 @@ fn array_to_decoder(
             ScalarType::String
             | ScalarType::PgLegacyName
             | ScalarType::Char { .. }
-            | ScalarType::VarChar { .. },
+            | ScalarType::VarChar { .. }
+            | ScalarType::TsVector
+            | ScalarType::TsQuery,
         ) => {
             let array = downcast_array::<StringArray>(array)?;
             DatumColumnDecoder::String(array.clone())
         }
-        (DataType::Binary, ScalarType::Bytes) => {
+        (DataType::Binary, ScalarType::Bytes | ScalarType::Geometry) => {
             let array = downcast_array::<BinaryArray>(array)?;
             DatumColumnDecoder::Bytes(array.clone())
         }
@@ -1657,10 +1666,14 @@ fn array_to_decoder(
             let array = downcast_array::<StringArray>(array)?;
             DatumColumnDecoder::Json(array.clone())
         }
-        (DataType::Struct(_), s @ ScalarType::Array(_) | s @ ScalarType::Int2Vector) => {
+        (
+            DataType::Struct(_),
+            s @ ScalarType::Array(_) | s @ ScalarType::Int2Vector | s @ ScalarType::Vector { .. },
+        ) => {
             let element_type = match s {
                 ScalarType::Array(inner) => inner,
                 ScalarType::Int2Vector => &ScalarType::Int16,
+                ScalarType::Vector { .. } => &ScalarType::Float32,
                 _ => unreachable!("checked above"),
             };
 
@@ -1763,7 +1776,8 @@ fn scalar_type_to_encoder(col_ty: &ScalarType) -> Result<DatumColumnEncoder, any
         | ScalarType::Oid
         | ScalarType::RegClass
         | ScalarType::RegProc
-        | ScalarType::RegType => DatumColumnEncoder::U32(UInt32Builder::new()),
+        | ScalarType::RegType
+        | ScalarType::Enum { .. } => DatumColumnEncoder::U32(UInt32Builder::new()),
         ScalarType::UInt64 => DatumColumnEncoder::U64(UInt64Builder::new()),
         ScalarType::Int16 => DatumColumnEncoder::I16(Int16Builder::new()),
         ScalarType::Int32 => DatumColumnEncoder::I32(Int32Builder::new()),
@@ -1778,8 +1792,10 @@ fn scalar_type_to_encoder(col_ty: &ScalarType) -> Result<DatumColumnEncoder, any
         ScalarType::String
         | ScalarType::PgLegacyName
         | ScalarType::Char { .. }
-        | ScalarType::VarChar { .. } => DatumColumnEncoder::String(StringBuilder::new()),
-        ScalarType::Bytes => DatumColumnEncoder::Bytes(BinaryBuilder::new()),
+        | ScalarType::VarChar { .. }
+        | ScalarType::TsVector
+        | ScalarType::TsQuery => DatumColumnEncoder::String(StringBuilder::new()),
+        ScalarType::Bytes | ScalarType::Geometry => DatumColumnEncoder::Bytes(BinaryBuilder::new()),
         ScalarType::Date => DatumColumnEncoder::Date(Int32Builder::new()),
         ScalarType::Time => DatumColumnEncoder::Time(FixedSizeBinaryBuilder::new(TIME_FIXED_BYTES)),
         ScalarType::Timestamp { .. } => {
@@ -1803,10 +1819,11 @@ fn scalar_type_to_encoder(col_ty: &ScalarType) -> Result<DatumColumnEncoder, any
             buf: Vec::new(),
             nulls: None,
         },
-        s @ ScalarType::Array(_) | s @ ScalarType::Int2Vector => {
+        s @ ScalarType::Array(_) | s @ ScalarType::Int2Vector | s @ ScalarType::Vector { .. } => {
             let element_type = match s {
                 ScalarType::Array(inner) => inner,
                 ScalarType::Int2Vector => &ScalarType::Int16,
+                ScalarType::Vector { .. } => &ScalarType::Float32,
                 _ => unreachable!("checked above"),
             };
             let inner = scalar_type_to_encoder(element_type)?;
@@ -2323,7 +2340,8 @@ mod tests {
                         | ScalarType::Map { .. }
                         | ScalarType::List { .. }
                         | ScalarType::Record { .. }
-                        | ScalarType::Int2Vector => (),
+                        | ScalarType::Int2Vector
+                        | ScalarType::Vector { .. } => (),
                         other => panic!("should have collected stats for {other:?}"),
                     }
                 }
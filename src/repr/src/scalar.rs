@@ -11,7 +11,7 @@ use std::collections::BTreeMap;
 use std::fmt::{self, Debug};
 use std::hash::Hash;
 use std::iter;
-use std::ops::Add;
+use std::ops::{Add, RangeInclusive};
 use std::sync::LazyLock;
 
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
@@ -32,6 +32,7 @@ use uuid::Uuid;
 use crate::adt::array::{Array, ArrayDimension};
 use crate::adt::char::{Char, CharLength};
 use crate::adt::date::Date;
+use crate::adt::geometry::{Coord, Geometry, Shape};
 use crate::adt::interval::Interval;
 use crate::adt::jsonb::{Jsonb, JsonbRef};
 use crate::adt::mz_acl_item::{AclItem, AclMode, MzAclItem};
@@ -39,10 +40,12 @@ use crate::adt::numeric::{Numeric, NumericMaxScale};
 use crate::adt::pg_legacy_name::PgLegacyName;
 use crate::adt::range::{Range, RangeLowerBound, RangeUpperBound};
 use crate::adt::system::{Oid, PgLegacyChar, RegClass, RegProc, RegType};
+use crate::adt::text_search::{Lexeme, TsVector};
 use crate::adt::timestamp::{
     CheckedTimestamp, HIGH_DATE, LOW_DATE, TimestampError, TimestampPrecision,
 };
 use crate::adt::varchar::{VarChar, VarCharMaxLength};
+use crate::adt::vector::VectorDimensions;
 pub use crate::relation_and_scalar::ProtoScalarType;
 pub use crate::relation_and_scalar::proto_scalar_type::ProtoRecordField;
 use crate::role_id::RoleId;
@@ -990,6 +993,9 @@ impl<'a> Datum<'a> {
                     (Datum::UInt32(_), ScalarType::RegProc) => true,
                     (Datum::UInt32(_), ScalarType::RegType) => true,
                     (Datum::UInt32(_), ScalarType::UInt32) => true,
+                    (Datum::UInt32(i), ScalarType::Enum { labels, .. }) => {
+                        usize::cast_from(*i) < labels.len()
+                    }
                     (Datum::UInt32(_), _) => false,
                     (Datum::UInt64(_), ScalarType::UInt64) => true,
                     (Datum::UInt64(_), _) => false,
@@ -1008,11 +1014,14 @@ impl<'a> Datum<'a> {
                     (Datum::Interval(_), ScalarType::Interval) => true,
                     (Datum::Interval(_), _) => false,
                     (Datum::Bytes(_), ScalarType::Bytes) => true,
+                    (Datum::Bytes(_), ScalarType::Geometry) => true,
                     (Datum::Bytes(_), _) => false,
                     (Datum::String(_), ScalarType::String)
                     | (Datum::String(_), ScalarType::VarChar { .. })
                     | (Datum::String(_), ScalarType::Char { .. })
-                    | (Datum::String(_), ScalarType::PgLegacyName) => true,
+                    | (Datum::String(_), ScalarType::PgLegacyName)
+                    | (Datum::String(_), ScalarType::TsVector)
+                    | (Datum::String(_), ScalarType::TsQuery) => true,
                     (Datum::String(_), _) => false,
                     (Datum::Uuid(_), ScalarType::Uuid) => true,
                     (Datum::Uuid(_), _) => false,
@@ -1029,6 +1038,15 @@ impl<'a> Datum<'a> {
                                 .iter()
                                 .all(|e| is_instance_of_scalar(e, &ScalarType::Int16))
                     }
+                    (Datum::Array(array), ScalarType::Vector { dimensions }) => {
+                        array.dims().len() == 1
+                            && dimensions
+                                .is_none_or(|d| d.check(array.elements.iter().count()).is_ok())
+                            && array
+                                .elements
+                                .iter()
+                                .all(|e| is_instance_of_scalar(e, &ScalarType::Float32))
+                    }
                     (Datum::Array(_), _) => false,
                     (Datum::List(list), ScalarType::List { element_type, .. }) => list
                         .iter()
@@ -1543,6 +1561,33 @@ pub enum ScalarType {
     MzAclItem,
     /// The type of [`Datum::AclItem`]
     AclItem,
+    /// A user-defined enumerated type, backed by a [`Datum::UInt32`].
+    ///
+    /// The datum holds the position of the value's label in `labels`, so
+    /// values of the type sort in their declared order.
+    Enum {
+        /// The labels of the type, in their declared sort order.
+        ///
+        /// Boxed slice to reduce the size of the enum variant.
+        labels: Box<[String]>,
+        custom_id: CatalogItemId,
+    },
+    /// A full text search document, backed by a [`Datum::String`] holding the
+    /// canonical text form of a [`TsVector`](crate::adt::text_search::TsVector).
+    TsVector,
+    /// A full text search query, backed by a [`Datum::String`] holding the
+    /// canonical text form of a [`TsQuery`](crate::adt::text_search::TsQuery).
+    TsQuery,
+    /// A vector of single-precision floats, backed by a one-dimensional
+    /// [`Datum::Array`] of non-null [`Datum::Float32`]s.
+    ///
+    /// Can optionally constrain the number of elements in the vector.
+    Vector {
+        dimensions: Option<VectorDimensions>,
+    },
+    /// A planar geometry, backed by a [`Datum::Bytes`] holding the canonical
+    /// EWKB form of a [`Geometry`](crate::adt::geometry::Geometry).
+    Geometry,
 }
 
 impl RustType<ProtoRecordField> for (ColumnName, ColumnType) {
@@ -1635,6 +1680,16 @@ impl RustType<ProtoScalarType> for ScalarType {
                 })),
                 ScalarType::MzAclItem => MzAclItem(()),
                 ScalarType::AclItem => AclItem(()),
+                ScalarType::Enum { labels, custom_id } => Enum(ProtoEnum {
+                    labels: labels.to_vec(),
+                    custom_id: Some(custom_id.into_proto()),
+                }),
+                ScalarType::TsVector => TsVector(()),
+                ScalarType::TsQuery => TsQuery(()),
+                ScalarType::Vector { dimensions } => Vector(ProtoVector {
+                    dimensions: dimensions.into_proto(),
+                }),
+                ScalarType::Geometry => Geometry(()),
             }),
         }
     }
@@ -1721,6 +1776,16 @@ impl RustType<ProtoScalarType> for ScalarType {
             }),
             MzAclItem(()) => Ok(ScalarType::MzAclItem),
             AclItem(()) => Ok(ScalarType::AclItem),
+            Enum(x) => Ok(ScalarType::Enum {
+                labels: x.labels.into(),
+                custom_id: x.custom_id.into_rust_if_some("ProtoEnum::custom_id")?,
+            }),
+            TsVector(()) => Ok(ScalarType::TsVector),
+            TsQuery(()) => Ok(ScalarType::TsQuery),
+            Vector(x) => Ok(ScalarType::Vector {
+                dimensions: x.dimensions.into_rust()?,
+            }),
+            Geometry(()) => Ok(ScalarType::Geometry),
         }
     }
 }
@@ -2757,6 +2822,7 @@ impl ScalarType {
             // to support Char values of different lengths in e.g. lists.
             Char { .. } => Char { length: None },
             VarChar { .. } => VarChar { max_length: None },
+            Vector { .. } => Vector { dimensions: None },
             Range { element_type } => Range {
                 element_type: Box::new(element_type.without_modifiers()),
             },
@@ -2770,27 +2836,31 @@ impl ScalarType {
     ///
     /// # Panics
     ///
-    /// Panics if called on anything other than a [`ScalarType::Array`] or
-    /// [`ScalarType::Int2Vector`].
+    /// Panics if called on anything other than a [`ScalarType::Array`],
+    /// [`ScalarType::Int2Vector`], or [`ScalarType::Vector`].
     pub fn unwrap_array_element_type(&self) -> &ScalarType {
         match self {
             ScalarType::Array(s) => &**s,
             ScalarType::Int2Vector => &ScalarType::Int16,
+            ScalarType::Vector { .. } => &ScalarType::Float32,
             _ => panic!("ScalarType::unwrap_array_element_type called on {:?}", self),
         }
     }
 
     /// Returns the [`ScalarType`] of elements in a [`ScalarType::Array`],
-    /// [`ScalarType::Int2Vector`], or [`ScalarType::List`].
+    /// [`ScalarType::Int2Vector`], [`ScalarType::Vector`], or
+    /// [`ScalarType::List`].
     ///
     /// # Panics
     ///
     /// Panics if called on anything other than a [`ScalarType::Array`],
-    /// [`ScalarType::Int2Vector`], or [`ScalarType::List`].
+    /// [`ScalarType::Int2Vector`], [`ScalarType::Vector`], or
+    /// [`ScalarType::List`].
     pub fn unwrap_collection_element_type(&self) -> &ScalarType {
         match self {
             ScalarType::Array(element_type) => element_type,
             ScalarType::Int2Vector => &ScalarType::Int16,
+            ScalarType::Vector { .. } => &ScalarType::Float32,
             ScalarType::List { element_type, .. } => element_type,
             _ => panic!(
                 "ScalarType::unwrap_collection_element_type called on {:?}",
@@ -2835,6 +2905,18 @@ impl ScalarType {
         }
     }
 
+    /// Returns the dimensions of a [`ScalarType::Vector`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called on anything other than a [`ScalarType::Vector`].
+    pub fn unwrap_vector_dimensions(&self) -> Option<VectorDimensions> {
+        match self {
+            ScalarType::Vector { dimensions } => *dimensions,
+            _ => panic!("ScalarType::unwrap_vector_dimensions called on {:?}", self),
+        }
+    }
+
     /// Returns the [`ScalarType`] of elements in a [`ScalarType::Range`].
     ///
     /// # Panics
@@ -2918,6 +3000,7 @@ impl ScalarType {
                         .map(|(_, t)| t)
                         .any(|t| t.scalar_type.is_custom_type())
             }
+            Enum { .. } => true,
             _ => false,
         }
     }
@@ -2991,6 +3074,14 @@ impl ScalarType {
                                 && a.1.scalar_type.eq_inner(&b.1.scalar_type, structure_only)
                         })
             }
+            (
+                Enum {
+                    custom_id: oid_a, ..
+                },
+                Enum {
+                    custom_id: oid_b, ..
+                },
+            ) => oid_a == oid_b || structure_only,
             (s, o) => ScalarBaseType::from(s) == ScalarBaseType::from(o),
         }
     }
@@ -3417,6 +3508,39 @@ impl ScalarType {
         });
         // aclitem has no binary encoding so we can't test it here.
         static ACLITEM: LazyLock<Row> = LazyLock::new(|| Row::pack_slice(&[]));
+        // tsvector and tsquery have no binary encoding so we can't test them here.
+        static TSVECTOR: LazyLock<Row> = LazyLock::new(|| Row::pack_slice(&[]));
+        static TSQUERY: LazyLock<Row> = LazyLock::new(|| Row::pack_slice(&[]));
+        static VECTOR: LazyLock<Row> = LazyLock::new(|| {
+            let vectors: &[&[f32]] = &[&[0.0], &[1.0, -1.0], &[f32::MIN, f32::MAX, 0.5]];
+            let mut row = Row::default();
+            let mut packer = row.packer();
+            for vector in vectors {
+                packer
+                    .try_push_array(
+                        &[ArrayDimension {
+                            lower_bound: 1,
+                            length: vector.len(),
+                        }],
+                        vector.iter().map(|f| Datum::Float32((*f).into())),
+                    )
+                    .expect("failed to push vector");
+            }
+            row
+        });
+        static GEOMETRY: LazyLock<Row> = LazyLock::new(|| {
+            let geometries: Vec<_> = [
+                "POINT(0 0)",
+                "SRID=4326;POINT(-73.98 40.75)",
+                "LINESTRING(0 0,1 1)",
+                "POLYGON((0 0,0 1,1 1,1 0,0 0))",
+                "GEOMETRYCOLLECTION EMPTY",
+            ]
+            .into_iter()
+            .map(|s| s.parse::<Geometry>().expect("valid geometry").to_ewkb())
+            .collect();
+            Row::pack(geometries.iter().map(|g| Datum::Bytes(g)))
+        });
 
         let iter: Box<dyn Iterator<Item = Datum<'static>>> = match self {
             ScalarType::Bool => Box::new((*BOOL).iter()),
@@ -3466,6 +3590,17 @@ impl ScalarType {
             ScalarType::Range { .. } => Box::new((*RANGE).iter()),
             ScalarType::MzAclItem { .. } => Box::new((*MZACLITEM).iter()),
             ScalarType::AclItem { .. } => Box::new((*ACLITEM).iter()),
+            // The valid datums depend on the type's labels, which are not
+            // known statically.
+            ScalarType::Enum { .. } => Box::new(std::iter::empty()),
+            ScalarType::TsVector => Box::new((*TSVECTOR).iter()),
+            ScalarType::TsQuery => Box::new((*TSQUERY).iter()),
+            ScalarType::Vector { dimensions: None } => Box::new((*VECTOR).iter()),
+            // The valid datums depend on the type's dimensions.
+            ScalarType::Vector {
+                dimensions: Some(_),
+            } => Box::new(std::iter::empty()),
+            ScalarType::Geometry => Box::new((*GEOMETRY).iter()),
         };
 
         iter
@@ -3524,6 +3659,10 @@ impl ScalarType {
             ScalarType::Int2Vector,
             ScalarType::MzTimestamp,
             ScalarType::MzAclItem,
+            ScalarType::TsVector,
+            ScalarType::TsQuery,
+            ScalarType::Vector { dimensions: None },
+            ScalarType::Geometry,
             // TODO: Fill in some variants of these.
             /*
             ScalarType::AclItem,
@@ -3584,7 +3723,12 @@ impl ScalarType {
             | ScalarType::Int2Vector
             | ScalarType::MzTimestamp
             | ScalarType::Range { .. }
-            | ScalarType::MzAclItem { .. }) => Ok(t),
+            | ScalarType::MzAclItem { .. }
+            | ScalarType::Enum { .. }
+            | ScalarType::TsVector
+            | ScalarType::TsQuery
+            | ScalarType::Vector { .. }
+            | ScalarType::Geometry) => Ok(t),
 
             ScalarType::Array(elem) => Ok(elem.array_of_self_elem_type()?),
 
@@ -3647,6 +3791,12 @@ impl Arbitrary for ScalarType {
             Just(ScalarType::RegType).boxed(),
             Just(ScalarType::RegClass).boxed(),
             Just(ScalarType::Int2Vector).boxed(),
+            Just(ScalarType::TsVector).boxed(),
+            Just(ScalarType::TsQuery).boxed(),
+            any::<Option<VectorDimensions>>()
+                .prop_map(|dimensions| ScalarType::Vector { dimensions })
+                .boxed(),
+            Just(ScalarType::Geometry).boxed(),
         ])
         // None of the leaf ScalarTypes types are really "simpler" than others
         // so don't waste time trying to shrink.
@@ -3889,6 +4039,10 @@ pub fn arb_datum_for_scalar(scalar_type: ScalarType) -> impl Strategy<Value = Pr
         | ScalarType::RegClass
         | ScalarType::RegProc
         | ScalarType::RegType => any::<u32>().prop_map(PropDatum::UInt32).boxed(),
+        ScalarType::Enum { labels, .. } => {
+            let len = u32::try_from(labels.len()).expect("enum labels fit in a u32");
+            (0..len.max(1)).prop_map(PropDatum::UInt32).boxed()
+        }
         ScalarType::UInt64 => any::<u64>().prop_map(PropDatum::UInt64).boxed(),
         ScalarType::Float32 => any::<f32>().prop_map(PropDatum::Float32).boxed(),
         ScalarType::Float64 => any::<f64>().prop_map(PropDatum::Float64).boxed(),
@@ -3897,6 +4051,21 @@ pub fn arb_datum_for_scalar(scalar_type: ScalarType) -> impl Strategy<Value = Pr
         | ScalarType::PgLegacyName
         | ScalarType::Char { length: None }
         | ScalarType::VarChar { max_length: None } => ".*".prop_map(PropDatum::String).boxed(),
+        ScalarType::TsVector => prop::collection::vec("[a-z]{1,8}", 0..8)
+            .prop_map(|words| {
+                let lexemes = words
+                    .into_iter()
+                    .map(|word| Lexeme {
+                        word,
+                        positions: vec![],
+                    })
+                    .collect();
+                PropDatum::String(TsVector::new(lexemes).to_string())
+            })
+            .boxed(),
+        ScalarType::TsQuery => prop::collection::vec("[a-z]{1,8}", 0..4)
+            .prop_map(|words| PropDatum::String(words.join(" & ")))
+            .boxed(),
         ScalarType::Char {
             length: Some(length),
         } => {
@@ -3957,6 +4126,33 @@ pub fn arb_datum_for_scalar(scalar_type: ScalarType) -> impl Strategy<Value = Pr
         ScalarType::Int2Vector => arb_array(any::<i16>().prop_map(PropDatum::Int16).boxed())
             .prop_map(PropDatum::Array)
             .boxed(),
+        ScalarType::Vector { dimensions } => {
+            let len = match dimensions {
+                Some(dimensions) => {
+                    let dimensions = usize::cast_from(dimensions.into_u32());
+                    dimensions..=dimensions
+                }
+                None => 1..=16,
+            };
+            arb_vector(len).prop_map(PropDatum::Array).boxed()
+        }
+        ScalarType::Geometry => prop::collection::vec((-180i16..=180, -90i16..=90), 1..8)
+            .prop_map(|coords| {
+                let coords: Vec<_> = coords
+                    .into_iter()
+                    .map(|(x, y)| Coord {
+                        x: f64::from(x),
+                        y: f64::from(y),
+                    })
+                    .collect();
+                let shape = match coords.as_slice() {
+                    [c] => Shape::Point(*c),
+                    _ => Shape::LineString(coords),
+                };
+                let geometry = Geometry::new(0, shape).expect("valid geometry");
+                PropDatum::Bytes(geometry.to_ewkb())
+            })
+            .boxed(),
         ScalarType::Map { value_type, .. } => arb_dict(arb_datum_for_scalar(*value_type))
             .prop_map(PropDatum::Map)
             .boxed(),
@@ -4039,6 +4235,27 @@ fn arb_array_dimension() -> BoxedStrategy<ArrayDimension> {
 #[derive(Debug, PartialEq, Clone)]
 pub struct PropArray(Row, Vec<PropDatum>);
 
+fn arb_vector(len: RangeInclusive<usize>) -> BoxedStrategy<PropArray> {
+    // Vectors are one-dimensional, and their elements are finite and never
+    // null.
+    prop::collection::vec((-1e6f32..1e6).prop_map(PropDatum::Float32), len)
+        .prop_map(|elements| {
+            let element_datums: Vec<Datum<'_>> = elements.iter().map(|pd| pd.into()).collect();
+            let mut row = Row::default();
+            row.packer()
+                .try_push_array(
+                    &[ArrayDimension {
+                        lower_bound: 1,
+                        length: element_datums.len(),
+                    }],
+                    element_datums,
+                )
+                .unwrap();
+            PropArray(row, elements)
+        })
+        .boxed()
+}
+
 fn arb_array(element_strategy: BoxedStrategy<PropDatum>) -> BoxedStrategy<PropArray> {
     // Elements in Arrays can always be Null.
     let element_strategy = Union::new_weighted(vec![
@@ -129,7 +129,8 @@ pub fn col_values<'a>(
             | ScalarType::Oid
             | ScalarType::RegClass
             | ScalarType::RegProc
-            | ScalarType::RegType,
+            | ScalarType::RegType
+            | ScalarType::Enum { .. },
             ColumnStatKinds::Primitive(U32(stats)),
         ) => map_stats(stats, Datum::UInt32),
         (ScalarType::UInt64, ColumnStatKinds::Primitive(U64(stats))) => {
@@ -169,12 +170,15 @@ pub fn col_values<'a>(
             ScalarType::String
             | ScalarType::PgLegacyName
             | ScalarType::Char { .. }
-            | ScalarType::VarChar { .. },
+            | ScalarType::VarChar { .. }
+            | ScalarType::TsVector
+            | ScalarType::TsQuery,
             ColumnStatKinds::Primitive(String(stats)),
         ) => map_stats(stats, Datum::String),
-        (ScalarType::Bytes, ColumnStatKinds::Bytes(BytesStats::Primitive(stats))) => {
-            Some((Datum::Bytes(&stats.lower), Datum::Bytes(&stats.upper)))
-        }
+        (
+            ScalarType::Bytes | ScalarType::Geometry,
+            ColumnStatKinds::Bytes(BytesStats::Primitive(stats)),
+        ) => Some((Datum::Bytes(&stats.lower), Datum::Bytes(&stats.upper))),
         (ScalarType::Date, ColumnStatKinds::Primitive(I32(stats))) => {
             let lower = soft_expect_or_log(Date::from_pg_epoch(stats.lower))?;
             let upper = soft_expect_or_log(Date::from_pg_epoch(stats.upper))?;
@@ -233,7 +237,8 @@ pub fn col_values<'a>(
             | ScalarType::Map { .. }
             | ScalarType::List { .. }
             | ScalarType::Record { .. }
-            | ScalarType::Int2Vector,
+            | ScalarType::Int2Vector
+            | ScalarType::Vector { .. },
             ColumnStatKinds::None,
         ) => None,
         // V0 Columnar Stat Types that differ from the above.
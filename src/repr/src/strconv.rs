@@ -35,7 +35,7 @@ use chrono::offset::{Offset, TimeZone};
 use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
 use dec::OrderedDecimal;
 use mz_lowertest::MzReflect;
-use mz_ore::cast::ReinterpretCast;
+use mz_ore::cast::{CastFrom, ReinterpretCast};
 use mz_ore::error::ErrorExt;
 use mz_ore::fmt::FormatBuffer;
 use mz_ore::lex::LexBuf;
@@ -52,13 +52,16 @@ use uuid::Uuid;
 use crate::adt::array::ArrayDimension;
 use crate::adt::date::Date;
 use crate::adt::datetime::{self, DateTimeField, ParsedDateTime};
+use crate::adt::geometry::{Geometry, GeometryError};
 use crate::adt::interval::Interval;
 use crate::adt::jsonb::{Jsonb, JsonbRef};
 use crate::adt::mz_acl_item::{AclItem, MzAclItem};
 use crate::adt::numeric::{self, NUMERIC_DATUM_MAX_PRECISION, Numeric};
 use crate::adt::pg_legacy_name::NAME_MAX_BYTES;
 use crate::adt::range::{Range, RangeBound, RangeInner};
+use crate::adt::text_search::{TsQuery, TsVector};
 use crate::adt::timestamp::CheckedTimestamp;
+use crate::adt::vector;
 
 include!(concat!(env!("OUT_DIR"), "/mz_repr.strconv.rs"));
 
@@ -743,6 +746,35 @@ where
     Nestable::Yes
 }
 
+/// Parses the label of an enum value, returning the position of the label in
+/// `labels`.
+///
+/// Unlike PostgreSQL, which only reports the offending input, the error
+/// details list the labels that are valid for the type.
+pub fn parse_enum(labels: &[String], s: &str) -> Result<u32, ParseError> {
+    match labels.iter().position(|label| label == s) {
+        Some(i) => Ok(u32::try_from(i).expect("enum labels fit in a u32")),
+        None => Err(
+            ParseError::invalid_input_syntax("enum", s).with_details(format!(
+                "valid labels are: {}",
+                mz_ore::str::separated(", ", labels.iter().map(|l| l.quoted()))
+            )),
+        ),
+    }
+}
+
+/// Returns the label of the enum value at `position` in `labels`.
+///
+/// Labels can be appended to an enum type while relations planned with the
+/// type's previous labels are still running, e.g. sinks. Such a relation
+/// formats values whose labels it does not know as their position.
+pub fn format_enum(labels: &[String], position: u32) -> Cow<'_, str> {
+    match labels.get(usize::cast_from(position)) {
+        Some(label) => Cow::Borrowed(label),
+        None => Cow::Owned(position.to_string()),
+    }
+}
+
 fn format_nanos_to_micros<F>(buf: &mut F, nanos: u32)
 where
     F: FormatBuffer,
@@ -1731,6 +1763,105 @@ pub fn parse_acl_item(s: &str) -> Result<AclItem, ParseError> {
         .map_err(|e| ParseError::invalid_input_syntax("aclitem", s).with_details(e))
 }
 
+/// Writes a `tsvector` to `buf`.
+pub fn format_tsvector<F>(buf: &mut F, tsvector: &TsVector) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{tsvector}");
+    Nestable::MayNeedEscaping
+}
+
+/// Parses a `tsvector` from `s`.
+pub fn parse_tsvector(s: &str) -> Result<TsVector, ParseError> {
+    s.parse()
+        .map_err(|e| ParseError::invalid_input_syntax("tsvector", s).with_details(e))
+}
+
+/// Writes a `tsquery` to `buf`.
+pub fn format_tsquery<F>(buf: &mut F, tsquery: &TsQuery) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{tsquery}");
+    Nestable::MayNeedEscaping
+}
+
+/// Parses a `tsquery` from `s`.
+pub fn parse_tsquery(s: &str) -> Result<TsQuery, ParseError> {
+    s.parse()
+        .map_err(|e| ParseError::invalid_input_syntax("tsquery", s).with_details(e))
+}
+
+/// Parses a `vector` from `s`, e.g. `[1,2,3]`.
+pub fn parse_vector(s: &str) -> Result<Vec<f32>, ParseError> {
+    let err = || ParseError::invalid_input_syntax("vector", s);
+    let inner = s
+        .trim()
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .ok_or_else(|| err().with_details("vector must start with \"[\" and end with \"]\""))?;
+    if inner.trim().is_empty() {
+        return Err(err().with_details("vector must have at least 1 dimension"));
+    }
+    let mut elems = vec![];
+    for elem in inner.split(',') {
+        let elem: f32 = elem.trim().parse().map_err(|_| err())?;
+        if !elem.is_finite() {
+            return Err(err().with_details("vector elements must be finite"));
+        }
+        elems.push(elem);
+    }
+    if elems.len() > usize::cast_from(vector::MAX_DIMENSIONS) {
+        return Err(err().with_details(format!(
+            "vector cannot have more than {} dimensions",
+            vector::MAX_DIMENSIONS
+        )));
+    }
+    Ok(elems)
+}
+
+/// Writes a `vector` to `buf`.
+pub fn format_vector<F, I>(buf: &mut F, elems: I) -> Nestable
+where
+    F: FormatBuffer,
+    I: IntoIterator<Item = f32>,
+{
+    buf.write_char('[');
+    for (i, elem) in elems.into_iter().enumerate() {
+        if i > 0 {
+            buf.write_char(',');
+        }
+        format_float32(buf, elem);
+    }
+    buf.write_char(']');
+    Nestable::MayNeedEscaping
+}
+
+/// Parses a `geometry` from `s`, which may hold either extended well-known
+/// text or hex-encoded extended well-known binary.
+pub fn parse_geometry(s: &str) -> Result<Geometry, ParseError> {
+    let err = |e: String| ParseError::invalid_input_syntax("geometry", s).with_details(e);
+    let trimmed = s.trim();
+    if !trimmed.is_empty() && trimmed.bytes().all(|b| b.is_ascii_hexdigit()) {
+        let bytes = hex::decode(trimmed).map_err(|e| err(e.to_string()))?;
+        Geometry::from_ewkb(&bytes).map_err(|e| err(e.to_string()))
+    } else {
+        trimmed
+            .parse()
+            .map_err(|e: GeometryError| err(e.to_string()))
+    }
+}
+
+/// Writes a `geometry`, given in its EWKB form, to `buf` as hex-encoded EWKB.
+pub fn format_geometry<F>(buf: &mut F, ewkb: &[u8]) -> Nestable
+where
+    F: FormatBuffer,
+{
+    buf.write_str(&hex::encode_upper(ewkb));
+    Nestable::Yes
+}
+
 pub trait ElementEscaper {
     fn needs_escaping(elem: &[u8]) -> bool;
     fn escape_char(c: u8) -> u8;
@@ -44,10 +44,14 @@ fn main() -> Result<()> {
 
         let mut buf = CodegenBuf::new();
 
+        // Keywords that contain underscores, like `Match_Condition`, drop the
+        // underscores from their enum variant name.
+        let variant = |kw: &str| kw.replace('_', "");
+
         buf.writeln("#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]");
         buf.write_block("pub enum Keyword", |buf| {
             for kw in &keywords {
-                buf.writeln(format!("{},", kw));
+                buf.writeln(format!("{},", variant(kw)));
             }
         });
 
@@ -55,7 +59,11 @@ fn main() -> Result<()> {
             buf.write_block("pub fn as_str(&self) -> &'static str", |buf| {
                 buf.write_block("match self", |buf| {
                     for kw in &keywords {
-                        buf.writeln(format!("Keyword::{} => {:?},", kw, kw.to_uppercase()));
+                        buf.writeln(format!(
+                            "Keyword::{} => {:?},",
+                            variant(kw),
+                            kw.to_uppercase()
+                        ));
                     }
                 });
             });
@@ -65,13 +73,13 @@ fn main() -> Result<()> {
             buf.writeln(format!(
                 "pub const {}: Keyword = Keyword::{};",
                 kw.to_uppercase(),
-                kw
+                variant(kw)
             ));
         }
 
         let mut phf = phf_codegen::Map::new();
         for kw in &keywords {
-            phf.entry(UncasedStr::new(kw), &format!("Keyword::{}", kw));
+            phf.entry(UncasedStr::new(kw), &format!("Keyword::{}", variant(kw)));
         }
         buf.writeln(format!(
             "pub static KEYWORDS: phf::Map<&'static UncasedStr, Keyword> = {};",
@@ -582,6 +582,18 @@ pub enum TableFactor<T: AstInfo> {
         join: Box<TableWithJoins<T>>,
         alias: Option<TableAlias>,
     },
+    /// Represents a windowing table function, such as
+    /// `TUMBLE(t, ts, INTERVAL '1 minute')` or
+    /// `HOP(t, ts, INTERVAL '1 minute', INTERVAL '10 seconds')`.
+    TimeWindow {
+        kind: TimeWindowKind,
+        relation: T::ItemName,
+        time_column: Ident,
+        size: Expr<T>,
+        /// The slide of the window. Only present for `HOP`.
+        slide: Option<Expr<T>>,
+        alias: Option<TableAlias>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for TableFactor<T> {
@@ -649,11 +661,55 @@ impl<T: AstInfo> AstDisplay for TableFactor<T> {
                     f.write_node(alias);
                 }
             }
+            TableFactor::TimeWindow {
+                kind,
+                relation,
+                time_column,
+                size,
+                slide,
+                alias,
+            } => {
+                f.write_node(kind);
+                f.write_str("(");
+                f.write_node(relation);
+                f.write_str(", ");
+                f.write_node(time_column);
+                f.write_str(", ");
+                f.write_node(size);
+                if let Some(slide) = slide {
+                    f.write_str(", ");
+                    f.write_node(slide);
+                }
+                f.write_str(")");
+                if let Some(alias) = alias {
+                    f.write_str(" AS ");
+                    f.write_node(alias);
+                }
+            }
         }
     }
 }
 impl_display_t!(TableFactor);
 
+/// The kind of a [`TableFactor::TimeWindow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TimeWindowKind {
+    /// Fixed-size, non-overlapping windows.
+    Tumble,
+    /// Fixed-size windows that advance by a slide, and may overlap.
+    Hop,
+}
+
+impl AstDisplay for TimeWindowKind {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            TimeWindowKind::Tumble => "TUMBLE",
+            TimeWindowKind::Hop => "HOP",
+        })
+    }
+}
+impl_display!(TimeWindowKind);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct TableAlias {
     pub name: Ident,
@@ -753,6 +809,19 @@ impl<T: AstInfo> AstDisplay for Join<T> {
                 f.write_str(" CROSS JOIN ");
                 f.write_node(&self.relation);
             }
+            JoinOperator::AsOf {
+                match_condition,
+                constraint,
+            } => {
+                f.write_str(" ASOF JOIN ");
+                f.write_node(&self.relation);
+                f.write_str(" MATCH_CONDITION (");
+                f.write_node(match_condition);
+                f.write_str(")");
+                if let Some(constraint) = constraint {
+                    f.write_node(&suffix(constraint));
+                }
+            }
         }
     }
 }
@@ -765,6 +834,13 @@ pub enum JoinOperator<T: AstInfo> {
     RightOuter(JoinConstraint<T>),
     FullOuter(JoinConstraint<T>),
     CrossJoin,
+    /// For each row of the left input, joins the row of the right input whose
+    /// time is closest to the left row's time per the `match_condition`, among
+    /// the rows that satisfy the optional `constraint`.
+    AsOf {
+        match_condition: Expr<T>,
+        constraint: Option<JoinConstraint<T>>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
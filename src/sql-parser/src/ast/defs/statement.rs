@@ -64,6 +64,9 @@ pub enum Statement<T: AstInfo> {
     CreateCluster(CreateClusterStatement<T>),
     CreateClusterReplica(CreateClusterReplicaStatement<T>),
     CreateSecret(CreateSecretStatement<T>),
+    CreatePublication(CreatePublicationStatement<T>),
+    CreateNotification(CreateNotificationStatement<T>),
+    CreateFunction(CreateFunctionStatement<T>),
     CreateNetworkPolicy(CreateNetworkPolicyStatement<T>),
     AlterCluster(AlterClusterStatement<T>),
     AlterOwner(AlterOwnerStatement<T>),
@@ -82,7 +85,10 @@ pub enum Statement<T: AstInfo> {
     AlterNetworkPolicy(AlterNetworkPolicyStatement<T>),
     AlterRole(AlterRoleStatement<T>),
     AlterTableAddColumn(AlterTableAddColumnStatement<T>),
+    AlterTypeAddValue(AlterTypeAddValueStatement),
     Discard(DiscardStatement),
+    Listen(ListenStatement),
+    Unlisten(UnlistenStatement),
     DropObjects(DropObjectsStatement),
     DropOwned(DropOwnedStatement<T>),
     SetVariable(SetVariableStatement),
@@ -95,6 +101,7 @@ pub enum Statement<T: AstInfo> {
     Subscribe(SubscribeStatement<T>),
     ExplainPlan(ExplainPlanStatement<T>),
     ExplainPushdown(ExplainPushdownStatement<T>),
+    ExplainAnalyze(ExplainAnalyzeStatement<T>),
     ExplainTimestamp(ExplainTimestampStatement<T>),
     ExplainSinkSchema(ExplainSinkSchemaStatement<T>),
     Declare(DeclareStatement<T>),
@@ -137,6 +144,9 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateIndex(stmt) => f.write_node(stmt),
             Statement::CreateRole(stmt) => f.write_node(stmt),
             Statement::CreateSecret(stmt) => f.write_node(stmt),
+            Statement::CreatePublication(stmt) => f.write_node(stmt),
+            Statement::CreateNotification(stmt) => f.write_node(stmt),
+            Statement::CreateFunction(stmt) => f.write_node(stmt),
             Statement::CreateType(stmt) => f.write_node(stmt),
             Statement::CreateCluster(stmt) => f.write_node(stmt),
             Statement::CreateClusterReplica(stmt) => f.write_node(stmt),
@@ -158,7 +168,10 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::AlterConnection(stmt) => f.write_node(stmt),
             Statement::AlterRole(stmt) => f.write_node(stmt),
             Statement::AlterTableAddColumn(stmt) => f.write_node(stmt),
+            Statement::AlterTypeAddValue(stmt) => f.write_node(stmt),
             Statement::Discard(stmt) => f.write_node(stmt),
+            Statement::Listen(stmt) => f.write_node(stmt),
+            Statement::Unlisten(stmt) => f.write_node(stmt),
             Statement::DropObjects(stmt) => f.write_node(stmt),
             Statement::DropOwned(stmt) => f.write_node(stmt),
             Statement::SetVariable(stmt) => f.write_node(stmt),
@@ -171,6 +184,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Subscribe(stmt) => f.write_node(stmt),
             Statement::ExplainPlan(stmt) => f.write_node(stmt),
             Statement::ExplainPushdown(stmt) => f.write_node(stmt),
+            Statement::ExplainAnalyze(stmt) => f.write_node(stmt),
             Statement::ExplainTimestamp(stmt) => f.write_node(stmt),
             Statement::ExplainSinkSchema(stmt) => f.write_node(stmt),
             Statement::Declare(stmt) => f.write_node(stmt),
@@ -219,6 +233,9 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::CreateCluster => "create_cluster",
         StatementKind::CreateClusterReplica => "create_cluster_replica",
         StatementKind::CreateSecret => "create_secret",
+        StatementKind::CreatePublication => "create_publication",
+        StatementKind::CreateNotification => "create_notification",
+        StatementKind::CreateFunction => "create_function",
         StatementKind::CreateNetworkPolicy => "create_network_policy",
         StatementKind::AlterCluster => "alter_cluster",
         StatementKind::AlterObjectRename => "alter_object_rename",
@@ -237,7 +254,10 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::AlterOwner => "alter_owner",
         StatementKind::AlterConnection => "alter_connection",
         StatementKind::AlterTableAddColumn => "alter_table",
+        StatementKind::AlterTypeAddValue => "alter_type",
         StatementKind::Discard => "discard",
+        StatementKind::Listen => "listen",
+        StatementKind::Unlisten => "unlisten",
         StatementKind::DropObjects => "drop_objects",
         StatementKind::DropOwned => "drop_owned",
         StatementKind::SetVariable => "set_variable",
@@ -250,6 +270,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Subscribe => "subscribe",
         StatementKind::ExplainPlan => "explain_plan",
         StatementKind::ExplainPushdown => "explain_pushdown",
+        StatementKind::ExplainAnalyze => "explain_analyze",
         StatementKind::ExplainTimestamp => "explain_timestamp",
         StatementKind::ExplainSinkSchema => "explain_sink_schema",
         StatementKind::Declare => "declare",
@@ -1796,6 +1817,8 @@ pub struct CreateIndexStatement<T: AstInfo> {
     pub in_cluster: Option<T::ClusterName>,
     /// `ON` table or view name
     pub on_name: T::ItemName,
+    /// The method named in `USING`.
+    pub method: IndexMethod,
     /// Expressions that form part of the index key. If not included, the
     /// key_parts will be inferred from the named object.
     pub key_parts: Option<Vec<Expr<T>>>,
@@ -1824,6 +1847,10 @@ impl<T: AstInfo> AstDisplay for CreateIndexStatement<T> {
         }
         f.write_str("ON ");
         f.write_node(&self.on_name);
+        if self.method != IndexMethod::Arrangement {
+            f.write_str(" USING ");
+            f.write_node(&self.method);
+        }
         if let Some(key_parts) = &self.key_parts {
             f.write_str(" (");
             f.write_node(&display::comma_separated(key_parts));
@@ -1838,6 +1865,30 @@ impl<T: AstInfo> AstDisplay for CreateIndexStatement<T> {
 }
 impl_display_t!(CreateIndexStatement);
 
+/// The method of an index, as named in `CREATE INDEX ... USING`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IndexMethod {
+    /// Arranges rows by the key expressions. This is the default.
+    Arrangement,
+    /// Arranges rows under each lexeme of their single `tsvector` key
+    /// expression.
+    Lexeme,
+    /// Arranges rows under a bucket of their single vector key expression, so
+    /// that nearby vectors tend to share a bucket.
+    Ann,
+}
+
+impl AstDisplay for IndexMethod {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            IndexMethod::Arrangement => f.write_str("ARRANGEMENT"),
+            IndexMethod::Lexeme => f.write_str("LEXEME"),
+            IndexMethod::Ann => f.write_str("ANN"),
+        }
+    }
+}
+impl_display!(IndexMethod);
+
 /// An option in a `CREATE CLUSTER` statement.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum IndexOptionName {
@@ -1906,6 +1957,10 @@ pub enum RoleAttribute {
     NoInherit,
     /// The `PASSWORD` option.
     Password(Option<String>),
+    /// The `REPLICATION` option.
+    Replication,
+    /// The `NOREPLICATION` option.
+    NoReplication,
     // The following are not supported, but included to give helpful error messages.
     Login,
     NoLogin,
@@ -1935,6 +1990,8 @@ impl AstDisplay for RoleAttribute {
             RoleAttribute::CreateRole => f.write_str("CREATEROLE"),
             RoleAttribute::NoCreateRole => f.write_str("NOCREATEROLE"),
             RoleAttribute::Password(_) => f.write_str("PASSWORD"),
+            RoleAttribute::Replication => f.write_str("REPLICATION"),
+            RoleAttribute::NoReplication => f.write_str("NOREPLICATION"),
         }
     }
 }
@@ -2123,6 +2180,195 @@ impl<T: AstInfo> AstDisplay for CreateSecretStatement<T> {
 }
 impl_display_t!(CreateSecretStatement);
 
+/// A `CREATE PUBLICATION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreatePublicationStatement<T: AstInfo> {
+    pub name: UnresolvedItemName,
+    pub if_not_exists: bool,
+    /// The relations whose changes are published.
+    pub tables: Vec<T::ItemName>,
+}
+
+impl<T: AstInfo> AstDisplay for CreatePublicationStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE PUBLICATION ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" FOR TABLE ");
+        f.write_node(&display::comma_separated(&self.tables));
+    }
+}
+impl_display_t!(CreatePublicationStatement);
+
+/// A `CREATE NOTIFICATION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateNotificationStatement<T: AstInfo> {
+    pub name: UnresolvedItemName,
+    pub if_not_exists: bool,
+    /// The materialized view whose changes trigger the notification.
+    pub on: T::ItemName,
+    /// The channel that listeners subscribe to with `LISTEN`.
+    pub channel: Ident,
+    /// An optional filter; only changed rows that satisfy it trigger the
+    /// notification.
+    pub predicate: Option<Expr<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateNotificationStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE NOTIFICATION ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" ON MATERIALIZED VIEW ");
+        f.write_node(&self.on);
+        f.write_str(" CHANNEL ");
+        f.write_node(&self.channel);
+        if let Some(predicate) = &self.predicate {
+            f.write_str(" WHEN ");
+            f.write_node(predicate);
+        }
+    }
+}
+impl_display_t!(CreateNotificationStatement);
+
+/// A `CREATE FUNCTION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateFunctionStatement<T: AstInfo> {
+    pub name: UnresolvedItemName,
+    pub if_not_exists: bool,
+    pub parameters: Vec<FunctionParameter<T>>,
+    pub returns: FunctionReturns<T>,
+    pub body: FunctionBody<T>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateFunctionStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE FUNCTION ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str("(");
+        f.write_node(&display::comma_separated(&self.parameters));
+        f.write_str(") RETURNS ");
+        f.write_node(&self.returns);
+        f.write_str(" ");
+        f.write_node(&self.body);
+    }
+}
+impl_display_t!(CreateFunctionStatement);
+
+/// A parameter in a `CREATE FUNCTION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionParameter<T: AstInfo> {
+    pub name: Option<Ident>,
+    pub data_type: T::DataType,
+}
+
+impl<T: AstInfo> AstDisplay for FunctionParameter<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        if let Some(name) = &self.name {
+            f.write_node(name);
+            f.write_str(" ");
+        }
+        f.write_node(&self.data_type);
+    }
+}
+impl_display_t!(FunctionParameter);
+
+/// The `RETURNS` clause of a `CREATE FUNCTION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FunctionReturns<T: AstInfo> {
+    /// `RETURNS <type>`
+    Scalar(T::DataType),
+    /// `RETURNS SETOF <type>`
+    SetOf(T::DataType),
+    /// `RETURNS TABLE (<name> <type>, ...)`
+    Table(Vec<FunctionTableColumn<T>>),
+}
+
+impl<T: AstInfo> AstDisplay for FunctionReturns<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            FunctionReturns::Scalar(data_type) => f.write_node(data_type),
+            FunctionReturns::SetOf(data_type) => {
+                f.write_str("SETOF ");
+                f.write_node(data_type);
+            }
+            FunctionReturns::Table(columns) => {
+                f.write_str("TABLE (");
+                f.write_node(&display::comma_separated(columns));
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display_t!(FunctionReturns);
+
+/// A column in the `RETURNS TABLE` clause of a `CREATE FUNCTION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionTableColumn<T: AstInfo> {
+    pub name: Ident,
+    pub data_type: T::DataType,
+}
+
+impl<T: AstInfo> AstDisplay for FunctionTableColumn<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        f.write_str(" ");
+        f.write_node(&self.data_type);
+    }
+}
+impl_display_t!(FunctionTableColumn);
+
+/// The implementation of a function in a `CREATE FUNCTION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FunctionBody<T: AstInfo> {
+    /// `LANGUAGE SQL AS '<query>'`
+    Sql(Box<Query<T>>),
+    /// `LANGUAGE WASM AS '<module>'`, where the module is a WebAssembly binary
+    /// in `bytea` text format.
+    Wasm(String),
+}
+
+impl<T: AstInfo> AstDisplay for FunctionBody<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            FunctionBody::Sql(query) => {
+                f.write_str("LANGUAGE SQL AS ");
+                // The body is printed as a dollar-quoted string, so that it
+                // does not need escaping. Choose a tag that does not appear in
+                // the body itself.
+                let body = query.to_ast_string(f.mode());
+                let mut tag = "$function$".to_string();
+                let mut i = 0;
+                while body.contains(&tag) {
+                    i += 1;
+                    tag = format!("$function{i}$");
+                }
+                f.write_str(&tag);
+                f.write_str(body);
+                f.write_str(&tag);
+            }
+            FunctionBody::Wasm(module) => {
+                f.write_str("LANGUAGE WASM AS ");
+                if f.redacted() {
+                    f.write_str("'<REDACTED>'");
+                } else {
+                    f.write_str("'");
+                    f.write_node(&display::escape_single_quote_string(module));
+                    f.write_str("'");
+                }
+            }
+        }
+    }
+}
+impl_display_t!(FunctionBody);
+
 /// `CREATE TYPE ..`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateTypeStatement<T: AstInfo> {
@@ -2134,6 +2380,27 @@ pub struct CreateTypeStatement<T: AstInfo> {
 
 impl<T: AstInfo> AstDisplay for CreateTypeStatement<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        if let CreateTypeAs::Domain {
+            data_type,
+            not_null,
+            check,
+        } = &self.as_type
+        {
+            f.write_str("CREATE DOMAIN ");
+            f.write_node(&self.name);
+            f.write_str(" AS ");
+            f.write_node(data_type);
+            if *not_null {
+                f.write_str(" NOT NULL");
+            }
+            if let Some(check) = check {
+                f.write_str(" CHECK (");
+                f.write_node(check);
+                f.write_str(")");
+            }
+            return;
+        }
+
         f.write_str("CREATE TYPE ");
         f.write_node(&self.name);
         f.write_str(" AS ");
@@ -2161,6 +2428,20 @@ impl<T: AstInfo> AstDisplay for CreateTypeStatement<T> {
                 }
                 f.write_str(")");
             }
+            CreateTypeAs::Enum { labels } => {
+                f.write_str(&self.as_type);
+                f.write_str("(");
+                for (i, label) in labels.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ");
+                    }
+                    f.write_str("'");
+                    f.write_node(&display::escape_single_quote_string(label));
+                    f.write_str("'");
+                }
+                f.write_str(")");
+            }
+            CreateTypeAs::Domain { .. } => unreachable!("handled above"),
         };
     }
 }
@@ -2168,6 +2449,10 @@ impl_display_t!(CreateTypeStatement);
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ClusterOptionName {
+    /// The `ARRANGEMENT SPILL [[=] <enabled>]` option.
+    ArrangementSpill,
+    /// The `AUTO INDEX [[=] <enabled>]` option.
+    AutoIndex,
     /// The `AVAILABILITY ZONES [[=] '[' <values> ']' ]` option.
     AvailabilityZones,
     /// The `DISK` option.
@@ -2193,6 +2478,8 @@ pub enum ClusterOptionName {
 impl AstDisplay for ClusterOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
+            ClusterOptionName::ArrangementSpill => f.write_str("ARRANGEMENT SPILL"),
+            ClusterOptionName::AutoIndex => f.write_str("AUTO INDEX"),
             ClusterOptionName::AvailabilityZones => f.write_str("AVAILABILITY ZONES"),
             ClusterOptionName::Disk => f.write_str("DISK"),
             ClusterOptionName::IntrospectionDebugging => f.write_str("INTROSPECTION DEBUGGING"),
@@ -2215,7 +2502,9 @@ impl WithOptionName for ClusterOptionName {
     /// on the conservative side and return `true`.
     fn redact_value(&self) -> bool {
         match self {
-            ClusterOptionName::AvailabilityZones
+            ClusterOptionName::ArrangementSpill
+            | ClusterOptionName::AutoIndex
+            | ClusterOptionName::AvailabilityZones
             | ClusterOptionName::Disk
             | ClusterOptionName::IntrospectionDebugging
             | ClusterOptionName::IntrospectionInterval
@@ -2583,6 +2872,15 @@ pub enum CreateTypeAs<T: AstInfo> {
     Record {
         column_defs: Vec<ColumnDef<T>>,
     },
+    Enum {
+        labels: Vec<String>,
+    },
+    /// `CREATE DOMAIN .. AS <data type> [NOT NULL] [CHECK (<expr>)]`
+    Domain {
+        data_type: T::DataType,
+        not_null: bool,
+        check: Option<Expr<T>>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for CreateTypeAs<T> {
@@ -2591,6 +2889,8 @@ impl<T: AstInfo> AstDisplay for CreateTypeAs<T> {
             CreateTypeAs::List { .. } => f.write_str("LIST "),
             CreateTypeAs::Map { .. } => f.write_str("MAP "),
             CreateTypeAs::Record { .. } => f.write_str("RECORD "),
+            CreateTypeAs::Enum { .. } => f.write_str("ENUM "),
+            CreateTypeAs::Domain { .. } => f.write_str("DOMAIN "),
         }
     }
 }
@@ -2741,6 +3041,56 @@ impl<T: AstInfo> AstDisplay for AlterRetainHistoryStatement<T> {
 }
 impl_display_t!(AlterRetainHistoryStatement);
 
+/// `ALTER TYPE ... ADD VALUE [IF NOT EXISTS] ... [{BEFORE | AFTER} ...]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTypeAddValueStatement {
+    pub name: UnresolvedItemName,
+    pub if_not_exists: bool,
+    /// The label to add to the enum.
+    pub value: String,
+    /// Where to place the new label. If `None`, the label is appended.
+    pub position: Option<AlterTypeAddValuePosition>,
+}
+
+impl AstDisplay for AlterTypeAddValueStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER TYPE ");
+        f.write_node(&self.name);
+        f.write_str(" ADD VALUE ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_str("'");
+        f.write_node(&display::escape_single_quote_string(&self.value));
+        f.write_str("'");
+        if let Some(position) = &self.position {
+            f.write_str(" ");
+            f.write_node(position);
+        }
+    }
+}
+impl_display!(AlterTypeAddValueStatement);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterTypeAddValuePosition {
+    Before(String),
+    After(String),
+}
+
+impl AstDisplay for AlterTypeAddValuePosition {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        let (keyword, label) = match self {
+            AlterTypeAddValuePosition::Before(label) => ("BEFORE", label),
+            AlterTypeAddValuePosition::After(label) => ("AFTER", label),
+        };
+        f.write_str(keyword);
+        f.write_str(" '");
+        f.write_node(&display::escape_single_quote_string(label));
+        f.write_str("'");
+    }
+}
+impl_display!(AlterTypeAddValuePosition);
+
 /// `ALTER <OBJECT> SWAP ...`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AlterObjectSwapStatement {
@@ -3213,6 +3563,38 @@ impl AstDisplay for DiscardTarget {
 }
 impl_display!(DiscardTarget);
 
+/// `LISTEN <channel>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ListenStatement {
+    pub channel: Ident,
+}
+
+impl AstDisplay for ListenStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("LISTEN ");
+        f.write_node(&self.channel);
+    }
+}
+impl_display!(ListenStatement);
+
+/// `UNLISTEN { <channel> | * }`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnlistenStatement {
+    /// The channel to stop listening on, or `None` for all channels.
+    pub channel: Option<Ident>,
+}
+
+impl AstDisplay for UnlistenStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("UNLISTEN ");
+        match &self.channel {
+            Some(channel) => f.write_node(channel),
+            None => f.write_str("*"),
+        }
+    }
+}
+impl_display!(UnlistenStatement);
+
 /// `DROP`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DropObjectsStatement {
@@ -3409,6 +3791,8 @@ pub enum ShowObjectType<T: AstInfo> {
         in_cluster: Option<T::ClusterName>,
     },
     NetworkPolicy,
+    Publication,
+    Notification,
 }
 /// `SHOW <object>S`
 ///
@@ -3452,6 +3836,8 @@ impl<T: AstInfo> AstDisplay for ShowObjectsStatement<T> {
             ShowObjectType::RoleMembership { .. } => "ROLE MEMBERSHIP",
             ShowObjectType::ContinualTask { .. } => "CONTINUAL TASKS",
             ShowObjectType::NetworkPolicy => "NETWORK POLICIES",
+            ShowObjectType::Publication => "PUBLICATIONS",
+            ShowObjectType::Notification => "NOTIFICATIONS",
         });
 
         if let ShowObjectType::Index { on_object, .. } = &self.object_type {
@@ -3696,6 +4082,24 @@ impl<T: AstInfo> AstDisplay for ShowCreateConnectionStatement<T> {
     }
 }
 
+/// `SHOW [REDACTED] CREATE FUNCTION <function>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ShowCreateFunctionStatement<T: AstInfo> {
+    pub function_name: T::ItemName,
+    pub redacted: bool,
+}
+
+impl<T: AstInfo> AstDisplay for ShowCreateFunctionStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("SHOW ");
+        if self.redacted {
+            f.write_str("REDACTED ");
+        }
+        f.write_str("CREATE FUNCTION ");
+        f.write_node(&self.function_name);
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ShowCreateClusterStatement<T: AstInfo> {
     pub cluster_name: T::ClusterName,
@@ -4033,6 +4437,69 @@ impl<T: AstInfo> AstDisplay for ExplainPushdownStatement<T> {
 }
 impl_display_t!(ExplainPushdownStatement);
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExplainAnalyzeStatement<T: AstInfo> {
+    pub properties: ExplainAnalyzeProperty,
+    pub explainee: Explainee<T>,
+}
+
+impl<T: AstInfo> AstDisplay for ExplainAnalyzeStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("EXPLAIN ANALYZE");
+        f.write_node(&self.properties);
+        f.write_str(" FOR ");
+        f.write_node(&self.explainee);
+    }
+}
+impl_display_t!(ExplainAnalyzeStatement);
+
+/// The runtime properties reported by an `EXPLAIN ANALYZE` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplainAnalyzeProperty {
+    /// Resource usage of each LIR node, optionally with its skew across
+    /// workers. An empty list of properties requests all of them.
+    Computation {
+        properties: Vec<ExplainAnalyzeComputationProperty>,
+        skew: bool,
+    },
+    /// Tuning hints for the LIR nodes that accept them.
+    Hints,
+}
+
+impl AstDisplay for ExplainAnalyzeProperty {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            ExplainAnalyzeProperty::Computation { properties, skew } => {
+                if !properties.is_empty() {
+                    f.write_str(" ");
+                    f.write_node(&display::comma_separated(properties));
+                }
+                if *skew {
+                    f.write_str(" WITH SKEW");
+                }
+            }
+            ExplainAnalyzeProperty::Hints => f.write_str(" HINTS"),
+        }
+    }
+}
+impl_display!(ExplainAnalyzeProperty);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ExplainAnalyzeComputationProperty {
+    Cpu,
+    Memory,
+}
+
+impl AstDisplay for ExplainAnalyzeComputationProperty {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            ExplainAnalyzeComputationProperty::Cpu => f.write_str("CPU"),
+            ExplainAnalyzeComputationProperty::Memory => f.write_str("MEMORY"),
+        }
+    }
+}
+impl_display!(ExplainAnalyzeComputationProperty);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExplainTimestampStatement<T: AstInfo> {
     pub format: Option<ExplainFormat>,
@@ -4094,6 +4561,8 @@ pub enum ObjectType {
     Subsource,
     ContinualTask,
     NetworkPolicy,
+    Publication,
+    Notification,
 }
 
 impl ObjectType {
@@ -4110,7 +4579,9 @@ impl ObjectType {
             | ObjectType::Connection
             | ObjectType::Func
             | ObjectType::Subsource
-            | ObjectType::ContinualTask => true,
+            | ObjectType::ContinualTask
+            | ObjectType::Publication
+            | ObjectType::Notification => true,
             ObjectType::Database
             | ObjectType::Schema
             | ObjectType::Cluster
@@ -4142,6 +4613,8 @@ impl AstDisplay for ObjectType {
             ObjectType::Subsource => "SUBSOURCE",
             ObjectType::ContinualTask => "CONTINUAL TASK",
             ObjectType::NetworkPolicy => "NETWORK POLICY",
+            ObjectType::Publication => "PUBLICATION",
+            ObjectType::Notification => "NOTIFICATION",
         })
     }
 }
@@ -4525,6 +4998,8 @@ pub enum ExplainStage {
     Trace,
     /// Insights about the plan
     PlanInsights,
+    /// The difference between the stored and a freshly optimized plan
+    PlanDiff,
 }
 
 impl ExplainStage {
@@ -4539,6 +5014,7 @@ impl ExplainStage {
             Self::PhysicalPlan => Some(smallvec![Physical]),
             Self::Trace => None,
             Self::PlanInsights => Some(smallvec![Raw, Global, FastPath]),
+            Self::PlanDiff => Some(smallvec![Physical]),
         }
     }
 
@@ -4553,6 +5029,7 @@ impl ExplainStage {
             Self::PhysicalPlan => true,
             Self::Trace => false,
             Self::PlanInsights => false,
+            Self::PlanDiff => false,
         }
     }
 }
@@ -4567,6 +5044,7 @@ impl AstDisplay for ExplainStage {
             Self::PhysicalPlan => f.write_str("PHYSICAL PLAN"),
             Self::Trace => f.write_str("OPTIMIZER TRACE"),
             Self::PlanInsights => f.write_str("PLAN INSIGHTS"),
+            Self::PlanDiff => f.write_str("PLAN DIFF"),
         }
     }
 }
@@ -4991,6 +5469,7 @@ pub enum ShowStatement<T: AstInfo> {
     ShowCreateSink(ShowCreateSinkStatement<T>),
     ShowCreateIndex(ShowCreateIndexStatement<T>),
     ShowCreateConnection(ShowCreateConnectionStatement<T>),
+    ShowCreateFunction(ShowCreateFunctionStatement<T>),
     ShowCreateCluster(ShowCreateClusterStatement<T>),
     ShowVariable(ShowVariableStatement),
     InspectShard(InspectShardStatement),
@@ -5008,6 +5487,7 @@ impl<T: AstInfo> AstDisplay for ShowStatement<T> {
             ShowStatement::ShowCreateSink(stmt) => f.write_node(stmt),
             ShowStatement::ShowCreateIndex(stmt) => f.write_node(stmt),
             ShowStatement::ShowCreateConnection(stmt) => f.write_node(stmt),
+            ShowStatement::ShowCreateFunction(stmt) => f.write_node(stmt),
             ShowStatement::ShowCreateCluster(stmt) => f.write_node(stmt),
             ShowStatement::ShowVariable(stmt) => f.write_node(stmt),
             ShowStatement::InspectShard(stmt) => f.write_node(stmt),
@@ -5062,6 +5542,7 @@ pub enum Privilege {
     DELETE,
     USAGE,
     CREATE,
+    EXECUTE,
     CREATEROLE,
     CREATEDB,
     CREATECLUSTER,
@@ -5077,6 +5558,7 @@ impl AstDisplay for Privilege {
             Privilege::DELETE => "DELETE",
             Privilege::CREATE => "CREATE",
             Privilege::USAGE => "USAGE",
+            Privilege::EXECUTE => "EXECUTE",
             Privilege::CREATEROLE => "CREATEROLE",
             Privilege::CREATEDB => "CREATEDB",
             Privilege::CREATECLUSTER => "CREATECLUSTER",
@@ -5449,6 +5931,8 @@ pub enum CommentObjectType<T: AstInfo> {
     ClusterReplica { name: QualifiedReplica },
     ContinualTask { name: T::ItemName },
     NetworkPolicy { name: T::NetworkPolicyName },
+    Publication { name: T::ItemName },
+    Notification { name: T::ItemName },
 }
 
 impl<T: AstInfo> AstDisplay for CommentObjectType<T> {
@@ -5528,6 +6012,14 @@ impl<T: AstInfo> AstDisplay for CommentObjectType<T> {
                 f.write_str("NETWORK POLICY ");
                 f.write_node(name);
             }
+            Publication { name } => {
+                f.write_str("PUBLICATION ");
+                f.write_node(name);
+            }
+            Notification { name } => {
+                f.write_str("NOTIFICATION ");
+                f.write_node(name);
+            }
         }
     }
 }
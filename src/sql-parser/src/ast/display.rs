@@ -120,6 +120,11 @@ where
         self.mode == FormatMode::SimpleRedacted
     }
 
+    /// Returns the current mode.
+    pub fn mode(&self) -> FormatMode {
+        self.mode
+    }
+
     /// Sets the current mode to a compatible version that does not redact
     /// values; returns the current mode, which should be reset when the
     /// unredacted printing is complete using [`Self::set_mode`].
@@ -163,6 +163,24 @@ pub fn parse_expr(sql: &str) -> Result<Expr<Raw>, ParserError> {
     }
 }
 
+/// Parses the body of a `LANGUAGE SQL` function, which must be a single query
+/// optionally followed by a semicolon.
+pub fn parse_function_body(sql: &str) -> Result<Query<Raw>, ParserError> {
+    let tokens = lexer::lex(sql)?;
+    let mut parser = Parser::new(sql, tokens);
+    let query = parser.parse_query()?;
+    parser.consume_token(&Token::Semicolon);
+    if parser.next_token().is_some() {
+        parser_err!(
+            parser,
+            parser.peek_prev_pos(),
+            "extra token after function body"
+        )
+    } else {
+        Ok(query)
+    }
+}
+
 /// Parses a SQL string containing a single data type.
 pub fn parse_data_type(sql: &str) -> Result<RawDataType, ParserError> {
     let tokens = lexer::lex(sql)?;
@@ -388,6 +406,12 @@ impl<'a> Parser<'a> {
                     .parse_discard()
                     .map_parser_err(StatementKind::Discard)?),
                 Token::Keyword(DROP) => Ok(self.parse_drop()?),
+                Token::Keyword(LISTEN) => {
+                    Ok(self.parse_listen().map_parser_err(StatementKind::Listen)?)
+                }
+                Token::Keyword(UNLISTEN) => Ok(self
+                    .parse_unlisten()
+                    .map_parser_err(StatementKind::Unlisten)?),
                 Token::Keyword(DELETE) => {
                     Ok(self.parse_delete().map_parser_err(StatementKind::Delete)?)
                 }
@@ -1887,6 +1911,9 @@ impl<'a> Parser<'a> {
         } else if self.peek_keyword(TYPE) {
             self.parse_create_type()
                 .map_parser_err(StatementKind::CreateType)
+        } else if self.peek_keyword(DOMAIN) {
+            self.parse_create_domain()
+                .map_parser_err(StatementKind::CreateType)
         } else if self.peek_keyword(ROLE) {
             self.parse_create_role()
                 .map_parser_err(StatementKind::CreateRole)
@@ -1924,6 +1951,15 @@ impl<'a> Parser<'a> {
         } else if self.peek_keyword(SECRET) {
             self.parse_create_secret()
                 .map_parser_err(StatementKind::CreateSecret)
+        } else if self.peek_keyword(PUBLICATION) {
+            self.parse_create_publication()
+                .map_parser_err(StatementKind::CreatePublication)
+        } else if self.peek_keyword(NOTIFICATION) {
+            self.parse_create_notification()
+                .map_parser_err(StatementKind::CreateNotification)
+        } else if self.peek_keyword(FUNCTION) {
+            self.parse_create_function()
+                .map_parser_err(StatementKind::CreateFunction)
         } else if self.peek_keyword(CONNECTION) {
             self.parse_create_connection()
                 .map_parser_err(StatementKind::CreateConnection)
@@ -4053,10 +4089,16 @@ impl<'a> Parser<'a> {
         self.expect_keyword(ON)?;
         let on_name = self.parse_raw_name()?;
 
-        // Arrangements are the only index type we support, so we can just ignore this
-        if self.parse_keyword(USING) {
-            self.expect_keyword(ARRANGEMENT)?;
-        }
+        let method = if self.parse_keyword(USING) {
+            match self.expect_one_of_keywords(&[ARRANGEMENT, LEXEME, ANN])? {
+                ARRANGEMENT => IndexMethod::Arrangement,
+                LEXEME => IndexMethod::Lexeme,
+                ANN => IndexMethod::Ann,
+                _ => unreachable!(),
+            }
+        } else {
+            IndexMethod::Arrangement
+        };
 
         let key_parts = if default_index {
             None
@@ -4092,6 +4134,7 @@ impl<'a> Parser<'a> {
             name,
             in_cluster,
             on_name,
+            method,
             key_parts,
             with_options,
             if_not_exists,
@@ -4202,6 +4245,8 @@ impl<'a> Parser<'a> {
                 CREATEROLE,
                 NOCREATEROLE,
                 PASSWORD,
+                REPLICATION,
+                NOREPLICATION,
             ]) {
                 None => break,
                 Some(SUPERUSER) => options.push(RoleAttribute::SuperUser),
@@ -4216,6 +4261,8 @@ impl<'a> Parser<'a> {
                 Some(NOCREATEDB) => options.push(RoleAttribute::NoCreateDB),
                 Some(CREATEROLE) => options.push(RoleAttribute::CreateRole),
                 Some(NOCREATEROLE) => options.push(RoleAttribute::NoCreateRole),
+                Some(REPLICATION) => options.push(RoleAttribute::Replication),
+                Some(NOREPLICATION) => options.push(RoleAttribute::NoReplication),
                 Some(PASSWORD) => {
                     if self.parse_keyword(NULL) {
                         options.push(RoleAttribute::Password(None));
@@ -4243,12 +4290,166 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_create_publication(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(PUBLICATION)?;
+        let if_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_item_name()?;
+        self.expect_keywords(&[FOR, TABLE])?;
+        let tables = self.parse_comma_separated(Parser::parse_raw_name)?;
+        Ok(Statement::CreatePublication(CreatePublicationStatement {
+            name,
+            if_not_exists,
+            tables,
+        }))
+    }
+
+    fn parse_create_notification(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(NOTIFICATION)?;
+        let if_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_item_name()?;
+        self.expect_keywords(&[ON, MATERIALIZED, VIEW])?;
+        let on = self.parse_raw_name()?;
+        self.expect_keyword(CHANNEL)?;
+        let channel = self.parse_identifier()?;
+        let predicate = if self.parse_keyword(WHEN) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        Ok(Statement::CreateNotification(CreateNotificationStatement {
+            name,
+            if_not_exists,
+            on,
+            channel,
+            predicate,
+        }))
+    }
+
+    fn parse_create_function(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(FUNCTION)?;
+        let if_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_item_name()?;
+        self.expect_token(&Token::LParen)?;
+        let parameters = if self.consume_token(&Token::RParen) {
+            vec![]
+        } else {
+            let parameters = self.parse_comma_separated(Parser::parse_function_parameter)?;
+            self.expect_token(&Token::RParen)?;
+            parameters
+        };
+        self.expect_keyword(RETURNS)?;
+        let returns = if self.parse_keyword(SETOF) {
+            FunctionReturns::SetOf(self.parse_data_type()?)
+        } else if self.parse_keyword(TABLE) {
+            self.expect_token(&Token::LParen)?;
+            let columns = self.parse_comma_separated(|parser| {
+                Ok(FunctionTableColumn {
+                    name: parser.parse_identifier()?,
+                    data_type: parser.parse_data_type()?,
+                })
+            })?;
+            self.expect_token(&Token::RParen)?;
+            FunctionReturns::Table(columns)
+        } else {
+            FunctionReturns::Scalar(self.parse_data_type()?)
+        };
+
+        // `LANGUAGE` and `AS` may appear in either order.
+        let mut language = None;
+        let mut definition = None;
+        while let Some(keyword) = self.parse_one_of_keywords(&[LANGUAGE, AS]) {
+            match keyword {
+                LANGUAGE if language.is_none() => {
+                    language = Some((self.peek_pos(), self.parse_identifier()?));
+                }
+                AS if definition.is_none() => {
+                    definition = Some((self.peek_pos(), self.parse_literal_string()?));
+                }
+                _ => {
+                    return parser_err!(
+                        self,
+                        self.peek_prev_pos(),
+                        "{} specified more than once",
+                        keyword
+                    );
+                }
+            }
+        }
+        let Some((language_pos, language)) = language else {
+            return self.expected(self.peek_pos(), "LANGUAGE", self.peek_token());
+        };
+        let Some((definition_pos, definition)) = definition else {
+            return self.expected(self.peek_pos(), "AS", self.peek_token());
+        };
+        let body = match language.as_str() {
+            "sql" => {
+                let query = parse_function_body(&definition).map_err(|e| {
+                    ParserError::new(
+                        definition_pos,
+                        format!("invalid function body: {}", e.message),
+                    )
+                })?;
+                FunctionBody::Sql(Box::new(query))
+            }
+            "wasm" => FunctionBody::Wasm(definition),
+            _ => {
+                return parser_err!(
+                    self,
+                    language_pos,
+                    "unsupported function language: {}",
+                    language
+                );
+            }
+        };
+
+        Ok(Statement::CreateFunction(CreateFunctionStatement {
+            name,
+            if_not_exists,
+            parameters,
+            returns,
+            body,
+        }))
+    }
+
+    fn parse_function_parameter(&mut self) -> Result<FunctionParameter<Raw>, ParserError> {
+        // Parameters are either `<type>` or `<name> <type>`. Try the former
+        // first, and fall back to the latter if the type is not immediately
+        // followed by the end of the parameter.
+        let index = self.index;
+        if let Ok(data_type) = self.parse_data_type() {
+            if matches!(self.peek_token(), Some(Token::Comma | Token::RParen)) {
+                return Ok(FunctionParameter {
+                    name: None,
+                    data_type,
+                });
+            }
+        }
+        self.index = index;
+        Ok(FunctionParameter {
+            name: Some(self.parse_identifier()?),
+            data_type: self.parse_data_type()?,
+        })
+    }
+
     fn parse_create_type(&mut self) -> Result<Statement<Raw>, ParserError> {
         self.expect_keyword(TYPE)?;
         let name = self.parse_item_name()?;
         self.expect_keyword(AS)?;
 
-        match self.parse_one_of_keywords(&[LIST, MAP]) {
+        match self.parse_one_of_keywords(&[ENUM, LIST, MAP]) {
+            Some(ENUM) => {
+                self.expect_token(&Token::LParen)?;
+                let labels = if self.peek_token() == Some(Token::RParen) {
+                    vec![]
+                } else {
+                    self.parse_comma_separated(Parser::parse_literal_string)?
+                };
+                self.expect_token(&Token::RParen)?;
+                Ok(Statement::CreateType(CreateTypeStatement {
+                    name,
+                    as_type: CreateTypeAs::Enum { labels },
+                }))
+            }
             Some(LIST) => {
                 self.expect_token(&Token::LParen)?;
                 let options = self.parse_comma_separated(Parser::parse_create_type_list_option)?;
@@ -4279,6 +4480,44 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn parse_create_domain(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(DOMAIN)?;
+        let name = self.parse_item_name()?;
+        // Postgres makes `AS` optional.
+        let _ = self.parse_keyword(AS);
+        let data_type = self.parse_data_type()?;
+
+        let mut not_null = false;
+        let mut check = None;
+        loop {
+            if self.parse_keywords(&[NOT, NULL]) {
+                not_null = true;
+            } else if self.parse_keyword(CHECK) {
+                if check.is_some() {
+                    return parser_err!(
+                        self,
+                        self.peek_prev_pos(),
+                        "multiple CHECK constraints are not supported"
+                    );
+                }
+                self.expect_token(&Token::LParen)?;
+                check = Some(self.parse_expr()?);
+                self.expect_token(&Token::RParen)?;
+            } else {
+                break;
+            }
+        }
+
+        Ok(Statement::CreateType(CreateTypeStatement {
+            name,
+            as_type: CreateTypeAs::Domain {
+                data_type,
+                not_null,
+                check,
+            },
+        }))
+    }
+
     fn parse_create_type_list_option(&mut self) -> Result<CreateTypeListOption<Raw>, ParserError> {
         self.expect_keywords(&[ELEMENT, TYPE])?;
         let name = CreateTypeListOptionName::ElementType;
@@ -4334,6 +4573,8 @@ impl<'a> Parser<'a> {
 
     fn parse_cluster_option_name(&mut self) -> Result<ClusterOptionName, ParserError> {
         let option = self.expect_one_of_keywords(&[
+            ARRANGEMENT,
+            AUTO,
             AVAILABILITY,
             DISK,
             INTROSPECTION,
@@ -4345,6 +4586,14 @@ impl<'a> Parser<'a> {
             WORKLOAD,
         ])?;
         let name = match option {
+            ARRANGEMENT => {
+                self.expect_keyword(SPILL)?;
+                ClusterOptionName::ArrangementSpill
+            }
+            AUTO => {
+                self.expect_keyword(INDEX)?;
+                ClusterOptionName::AutoIndex
+            }
             AVAILABILITY => {
                 self.expect_keyword(ZONES)?;
                 ClusterOptionName::AvailabilityZones
@@ -4636,6 +4885,20 @@ impl<'a> Parser<'a> {
         Ok(Statement::Discard(DiscardStatement { target }))
     }
 
+    fn parse_listen(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let channel = self.parse_identifier()?;
+        Ok(Statement::Listen(ListenStatement { channel }))
+    }
+
+    fn parse_unlisten(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let channel = if self.consume_token(&Token::Star) {
+            None
+        } else {
+            Some(self.parse_identifier()?)
+        };
+        Ok(Statement::Unlisten(UnlistenStatement { channel }))
+    }
+
     fn parse_drop(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
         if self.parse_keyword(OWNED) {
             self.parse_drop_owned()
@@ -4714,7 +4977,10 @@ impl<'a> Parser<'a> {
             | ObjectType::Type
             | ObjectType::Secret
             | ObjectType::Connection
-            | ObjectType::ContinualTask => {
+            | ObjectType::ContinualTask
+            | ObjectType::Publication
+            | ObjectType::Notification
+            | ObjectType::Func => {
                 let names = self.parse_comma_separated(|parser| {
                     Ok(UnresolvedObjectName::Item(parser.parse_item_name()?))
                 })?;
@@ -4729,7 +4995,7 @@ impl<'a> Parser<'a> {
                     cascade,
                 }))
             }
-            ObjectType::Func | ObjectType::Subsource => parser_err!(
+            ObjectType::Subsource => parser_err!(
                 self,
                 self.peek_prev_pos(),
                 format!("Unsupported DROP on {object_type}")
@@ -5419,6 +5685,9 @@ impl<'a> Parser<'a> {
             ObjectType::Index => self.parse_alter_index(),
             ObjectType::Secret => self.parse_alter_secret(),
             ObjectType::Connection => self.parse_alter_connection(),
+            ObjectType::Publication | ObjectType::Notification => {
+                self.parse_alter_rename_or_owner(object_type)
+            }
             ObjectType::View
             | ObjectType::MaterializedView
             | ObjectType::Table
@@ -5427,10 +5696,14 @@ impl<'a> Parser<'a> {
                 let if_exists = self
                     .parse_if_exists()
                     .map_parser_err(StatementKind::AlterOwner)?;
-                let name = UnresolvedObjectName::Item(
-                    self.parse_item_name()
-                        .map_parser_err(StatementKind::AlterOwner)?,
-                );
+                let name = self
+                    .parse_item_name()
+                    .map_parser_err(StatementKind::AlterOwner)?;
+                if !if_exists && self.parse_keyword(ADD) {
+                    return self
+                        .parse_alter_type_add_value(name)
+                        .map_parser_err(StatementKind::AlterTypeAddValue);
+                }
                 self.expect_keywords(&[OWNER, TO])
                     .map_parser_err(StatementKind::AlterOwner)?;
                 let new_owner = self
@@ -5439,7 +5712,7 @@ impl<'a> Parser<'a> {
                 Ok(Statement::AlterOwner(AlterOwnerStatement {
                     object_type,
                     if_exists,
-                    name,
+                    name: UnresolvedObjectName::Item(name),
                     new_owner,
                 }))
             }
@@ -5914,6 +6187,53 @@ impl<'a> Parser<'a> {
         )
     }
 
+    /// Parse an ALTER statement for an item type that only supports renaming
+    /// and changing owners, like publications and notifications.
+    fn parse_alter_rename_or_owner(
+        &mut self,
+        object_type: ObjectType,
+    ) -> Result<Statement<Raw>, ParserStatementError> {
+        let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
+        let name = self.parse_item_name().map_no_statement_parser_err()?;
+
+        Ok(
+            match self
+                .expect_one_of_keywords(&[RENAME, OWNER])
+                .map_no_statement_parser_err()?
+            {
+                RENAME => {
+                    self.expect_keyword(TO)
+                        .map_parser_err(StatementKind::AlterObjectRename)?;
+                    let to_item_name = self
+                        .parse_identifier()
+                        .map_parser_err(StatementKind::AlterObjectRename)?;
+
+                    Statement::AlterObjectRename(AlterObjectRenameStatement {
+                        object_type,
+                        if_exists,
+                        name: UnresolvedObjectName::Item(name),
+                        to_item_name,
+                    })
+                }
+                OWNER => {
+                    self.expect_keyword(TO)
+                        .map_parser_err(StatementKind::AlterOwner)?;
+                    let new_owner = self
+                        .parse_identifier()
+                        .map_parser_err(StatementKind::AlterOwner)?;
+
+                    Statement::AlterOwner(AlterOwnerStatement {
+                        object_type,
+                        if_exists,
+                        name: UnresolvedObjectName::Item(name),
+                        new_owner,
+                    })
+                }
+                _ => unreachable!(),
+            },
+        )
+    }
+
     /// Parse an ALTER SINK statement.
     fn parse_alter_sink(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
         let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
@@ -6344,6 +6664,31 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn parse_alter_type_add_value(
+        &mut self,
+        name: UnresolvedItemName,
+    ) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(VALUE)?;
+        let if_not_exists = self.parse_if_not_exists()?;
+        let value = self.parse_literal_string()?;
+        let position = match self.parse_one_of_keywords(&[BEFORE, AFTER]) {
+            Some(BEFORE) => Some(AlterTypeAddValuePosition::Before(
+                self.parse_literal_string()?,
+            )),
+            Some(AFTER) => Some(AlterTypeAddValuePosition::After(
+                self.parse_literal_string()?,
+            )),
+            None => None,
+            _ => unreachable!(),
+        };
+        Ok(Statement::AlterTypeAddValue(AlterTypeAddValueStatement {
+            name,
+            if_not_exists,
+            value,
+            position,
+        }))
+    }
+
     fn parse_alter_schema(
         &mut self,
         object_type: ObjectType,
@@ -7054,7 +7399,9 @@ impl<'a> Parser<'a> {
             | ObjectType::Secret
             | ObjectType::Connection
             | ObjectType::Func
-            | ObjectType::ContinualTask => UnresolvedObjectName::Item(self.parse_item_name()?),
+            | ObjectType::ContinualTask
+            | ObjectType::Publication
+            | ObjectType::Notification => UnresolvedObjectName::Item(self.parse_item_name()?),
             ObjectType::Role => UnresolvedObjectName::Role(self.parse_identifier()?),
             ObjectType::Cluster => UnresolvedObjectName::Cluster(self.parse_identifier()?),
             ObjectType::ClusterReplica => {
@@ -7849,6 +8196,8 @@ impl<'a> Parser<'a> {
                 ObjectType::Connection => ShowObjectType::Connection,
                 ObjectType::Cluster => ShowObjectType::Cluster,
                 ObjectType::NetworkPolicy => ShowObjectType::NetworkPolicy,
+                ObjectType::Publication => ShowObjectType::Publication,
+                ObjectType::Notification => ShowObjectType::Notification,
                 ObjectType::MaterializedView => {
                     let in_cluster = self.parse_optional_in_cluster()?;
                     ShowObjectType::MaterializedView { in_cluster }
@@ -7950,6 +8299,13 @@ impl<'a> Parser<'a> {
                     redacted,
                 },
             ))
+        } else if self.parse_keywords(&[CREATE, FUNCTION]) {
+            Ok(ShowStatement::ShowCreateFunction(
+                ShowCreateFunctionStatement {
+                    function_name: self.parse_raw_name()?,
+                    redacted,
+                },
+            ))
         } else if self.parse_keywords(&[CREATE, CLUSTER]) {
             if redacted {
                 return parser_err!(
@@ -8060,6 +8416,25 @@ impl<'a> Parser<'a> {
                     relation: self.parse_table_factor()?,
                     join_operator: JoinOperator::CrossJoin,
                 }
+            } else if self.parse_keyword(ASOF) {
+                self.expect_keyword(JOIN)?;
+                let relation = self.parse_table_factor()?;
+                self.expect_keyword(MATCH_CONDITION)?;
+                self.expect_token(&Token::LParen)?;
+                let match_condition = self.parse_expr()?;
+                self.expect_token(&Token::RParen)?;
+                let constraint = if self.peek_one_of_keywords(&[ON, USING]) {
+                    Some(self.parse_join_constraint(false)?)
+                } else {
+                    None
+                };
+                Join {
+                    relation,
+                    join_operator: JoinOperator::AsOf {
+                        match_condition,
+                        constraint,
+                    },
+                }
             } else {
                 let natural = self.parse_keyword(NATURAL);
                 let peek_keyword = if let Some(Token::Keyword(kw)) = self.peek_token() {
@@ -8092,6 +8467,13 @@ impl<'a> Parser<'a> {
                             self.peek_token(),
                         );
                     }
+                    Some(ASOF) if natural => {
+                        return parser_err!(
+                            self,
+                            self.peek_pos(),
+                            "NATURAL ASOF JOIN is not supported"
+                        );
+                    }
                     None if natural => {
                         return self.expected(
                             self.peek_pos(),
@@ -8193,6 +8575,10 @@ impl<'a> Parser<'a> {
             })
         } else if self.parse_keywords(&[ROWS, FROM]) {
             Ok(self.parse_rows_from()?)
+        } else if self.peek_one_of_keywords(&[TUMBLE, HOP])
+            && self.peek_nth_token(1) == Some(Token::LParen)
+        {
+            self.parse_time_window()
         } else {
             let name = self.parse_raw_name()?;
             if self.consume_token(&Token::LParen) {
@@ -8232,6 +8618,39 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses a `TUMBLE` or `HOP` windowing table function, assuming the next
+    /// token is one of those keywords.
+    fn parse_time_window(&mut self) -> Result<TableFactor<Raw>, ParserError> {
+        let kind = match self.expect_one_of_keywords(&[TUMBLE, HOP])? {
+            TUMBLE => TimeWindowKind::Tumble,
+            HOP => TimeWindowKind::Hop,
+            _ => unreachable!(),
+        };
+        self.expect_token(&Token::LParen)?;
+        let relation = self.parse_raw_name()?;
+        self.expect_token(&Token::Comma)?;
+        let time_column = self.parse_identifier()?;
+        self.expect_token(&Token::Comma)?;
+        let size = self.parse_expr()?;
+        let slide = match kind {
+            TimeWindowKind::Tumble => None,
+            TimeWindowKind::Hop => {
+                self.expect_token(&Token::Comma)?;
+                Some(self.parse_expr()?)
+            }
+        };
+        self.expect_token(&Token::RParen)?;
+        let alias = self.parse_optional_table_alias()?;
+        Ok(TableFactor::TimeWindow {
+            kind,
+            relation,
+            time_column,
+            size,
+            slide,
+            alias,
+        })
+    }
+
     fn parse_named_function(&mut self) -> Result<Function<Raw>, ParserError> {
         let name = self.parse_raw_name()?;
         self.parse_function(name)
@@ -8635,6 +9054,9 @@ impl<'a> Parser<'a> {
         } else if self.parse_keywords(&[FILTER, PUSHDOWN]) {
             self.parse_explain_pushdown()
                 .map_parser_err(StatementKind::ExplainPushdown)
+        } else if self.parse_keyword(ANALYZE) {
+            self.parse_explain_analyze()
+                .map_parser_err(StatementKind::ExplainAnalyze)
         } else if self.peek_keyword(KEY) || self.peek_keyword(VALUE) {
             self.parse_explain_schema()
                 .map_parser_err(StatementKind::ExplainSinkSchema)
@@ -8748,6 +9170,8 @@ impl<'a> Parser<'a> {
             Some(PLAN) => {
                 if self.parse_keyword(INSIGHTS) {
                     (true, Some(ExplainStage::PlanInsights))
+                } else if self.parse_keyword(DIFF) {
+                    (true, Some(ExplainStage::PlanDiff))
                 } else {
                     // Use the default plan for the explainee.
                     (true, None)
@@ -8829,6 +9253,48 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Parse an `EXPLAIN ANALYZE` statement, assuming that the `EXPLAIN
+    /// ANALYZE` tokens have already been consumed.
+    fn parse_explain_analyze(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let properties = if self.parse_keyword(HINTS) {
+            ExplainAnalyzeProperty::Hints
+        } else {
+            let properties = if self.peek_one_of_keywords(&[CPU, MEMORY]) {
+                self.parse_comma_separated(|parser| {
+                    match parser.expect_one_of_keywords(&[CPU, MEMORY])? {
+                        CPU => Ok(ExplainAnalyzeComputationProperty::Cpu),
+                        MEMORY => Ok(ExplainAnalyzeComputationProperty::Memory),
+                        _ => unreachable!(),
+                    }
+                })?
+            } else {
+                vec![]
+            };
+            let skew = self.parse_keywords(&[WITH, SKEW]);
+            ExplainAnalyzeProperty::Computation { properties, skew }
+        };
+
+        self.expect_keyword(FOR)?;
+
+        // Only dataflows that are running can be analyzed.
+        let explainee = if self.parse_keyword(INDEX) {
+            Explainee::Index(self.parse_raw_name()?)
+        } else if self.parse_keywords(&[MATERIALIZED, VIEW]) {
+            Explainee::MaterializedView(self.parse_raw_name()?)
+        } else {
+            return self.expected(
+                self.peek_pos(),
+                "INDEX or MATERIALIZED VIEW",
+                self.peek_token(),
+            );
+        };
+
+        Ok(Statement::ExplainAnalyze(ExplainAnalyzeStatement {
+            properties,
+            explainee,
+        }))
+    }
+
     /// Parse an `EXPLAIN TIMESTAMP` statement, assuming that the `EXPLAIN
     /// TIMESTAMP` tokens have already been consumed.
     fn parse_explain_timestamp(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -9219,8 +9685,9 @@ impl<'a> Parser<'a> {
             | ObjectType::Index
             | ObjectType::ClusterReplica
             | ObjectType::Role
-            | ObjectType::Func
-            | ObjectType::Subsource => {
+            | ObjectType::Subsource
+            | ObjectType::Publication
+            | ObjectType::Notification => {
                 parser_err!(
                     self,
                     self.peek_prev_pos(),
@@ -9229,6 +9696,7 @@ impl<'a> Parser<'a> {
             }
             ObjectType::Table
             | ObjectType::Type
+            | ObjectType::Func
             | ObjectType::Cluster
             | ObjectType::Secret
             | ObjectType::Connection
@@ -9259,6 +9727,8 @@ impl<'a> Parser<'a> {
                 FUNCTION,
                 CONTINUAL,
                 NETWORK,
+                PUBLICATION,
+                NOTIFICATION,
             ])? {
                 TABLE => ObjectType::Table,
                 VIEW => ObjectType::View,
@@ -9300,6 +9770,8 @@ impl<'a> Parser<'a> {
                     }
                     ObjectType::NetworkPolicy
                 }
+                PUBLICATION => ObjectType::Publication,
+                NOTIFICATION => ObjectType::Notification,
                 _ => unreachable!(),
             },
         )
@@ -9432,6 +9904,8 @@ impl<'a> Parser<'a> {
                 SUBSOURCES,
                 CONTINUAL,
                 NETWORK,
+                PUBLICATIONS,
+                NOTIFICATIONS,
             ])? {
                 TABLES => ObjectType::Table,
                 VIEWS => ObjectType::View,
@@ -9478,6 +9952,8 @@ impl<'a> Parser<'a> {
                         return None;
                     }
                 }
+                PUBLICATIONS => ObjectType::Publication,
+                NOTIFICATIONS => ObjectType::Notification,
                 _ => unreachable!(),
             },
         )
@@ -9579,6 +10055,7 @@ impl<'a> Parser<'a> {
                 DELETE,
                 USAGE,
                 CREATE,
+                EXECUTE,
                 CREATEROLE,
                 CREATEDB,
                 CREATECLUSTER,
@@ -9590,6 +10067,7 @@ impl<'a> Parser<'a> {
                 DELETE => Privilege::DELETE,
                 USAGE => Privilege::USAGE,
                 CREATE => Privilege::CREATE,
+                EXECUTE => Privilege::EXECUTE,
                 CREATEROLE => Privilege::CREATEROLE,
                 CREATEDB => Privilege::CREATEDB,
                 CREATECLUSTER => Privilege::CREATECLUSTER,
@@ -9661,6 +10139,8 @@ impl<'a> Parser<'a> {
             CLUSTER,
             CONTINUAL,
             NETWORK,
+            PUBLICATION,
+            NOTIFICATION,
         ])? {
             TABLE => {
                 let name = self.parse_raw_name()?;
@@ -9738,6 +10218,14 @@ impl<'a> Parser<'a> {
                 let name = self.parse_raw_network_policy_name()?;
                 CommentObjectType::NetworkPolicy { name }
             }
+            PUBLICATION => {
+                let name = self.parse_raw_name()?;
+                CommentObjectType::Publication { name }
+            }
+            NOTIFICATION => {
+                let name = self.parse_raw_name()?;
+                CommentObjectType::Notification { name }
+            }
             _ => unreachable!(),
         };
 
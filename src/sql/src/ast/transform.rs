@@ -18,10 +18,11 @@ use mz_sql_parser::ast::CreateTableFromSourceStatement;
 use crate::ast::visit::{self, Visit};
 use crate::ast::visit_mut::{self, VisitMut};
 use crate::ast::{
-    AstInfo, CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
+    AstInfo, CreateConnectionStatement, CreateFunctionStatement, CreateIndexStatement,
+    CreateMaterializedViewStatement, CreateNotificationStatement, CreatePublicationStatement,
     CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
-    CreateTableStatement, CreateViewStatement, CreateWebhookSourceStatement, Expr, Ident, Query,
-    Raw, RawItemName, Statement, UnresolvedItemName, ViewDefinition,
+    CreateTableStatement, CreateViewStatement, CreateWebhookSourceStatement, Expr, FunctionBody,
+    Ident, Query, Raw, RawItemName, Statement, UnresolvedItemName, ViewDefinition,
 };
 use crate::names::FullItemName;
 
@@ -47,7 +48,10 @@ pub fn create_stmt_rename_schema_refs(
         | stmt @ Statement::CreateTableFromSource(_)
         | stmt @ Statement::CreateIndex(_)
         | stmt @ Statement::CreateType(_)
-        | stmt @ Statement::CreateSecret(_) => {
+        | stmt @ Statement::CreateSecret(_)
+        | stmt @ Statement::CreatePublication(_)
+        | stmt @ Statement::CreateNotification(_)
+        | stmt @ Statement::CreateFunction(_) => {
             let mut visitor = CreateSqlRewriteSchema {
                 database,
                 cur_schema,
@@ -151,6 +155,9 @@ pub fn create_stmt_rename(create_stmt: &mut Statement<Raw>, to_item_name: String
         | Statement::CreateTable(CreateTableStatement { name, .. })
         | Statement::CreateTableFromSource(CreateTableFromSourceStatement { name, .. })
         | Statement::CreateSecret(CreateSecretStatement { name, .. })
+        | Statement::CreatePublication(CreatePublicationStatement { name, .. })
+        | Statement::CreateNotification(CreateNotificationStatement { name, .. })
+        | Statement::CreateFunction(CreateFunctionStatement { name, .. })
         | Statement::CreateConnection(CreateConnectionStatement { name, .. })
         | Statement::CreateWebhookSource(CreateWebhookSourceStatement { name, .. }) => {
             // The last name in an ItemName is the item name. The item name
@@ -199,11 +206,23 @@ pub fn create_stmt_rename_refs(
         Statement::CreateSink(CreateSinkStatement { from, .. }) => {
             maybe_update_item_name(from.name_mut());
         }
+        Statement::CreatePublication(CreatePublicationStatement { tables, .. }) => {
+            for table in tables {
+                maybe_update_item_name(table.name_mut());
+            }
+        }
+        Statement::CreateNotification(CreateNotificationStatement { on, .. }) => {
+            maybe_update_item_name(on.name_mut());
+        }
         Statement::CreateView(CreateViewStatement {
             definition: ViewDefinition { query, .. },
             ..
         })
-        | Statement::CreateMaterializedView(CreateMaterializedViewStatement { query, .. }) => {
+        | Statement::CreateMaterializedView(CreateMaterializedViewStatement { query, .. })
+        | Statement::CreateFunction(CreateFunctionStatement {
+            body: FunctionBody::Sql(query),
+            ..
+        }) => {
             rewrite_query(from_name, to_item_name, query)?;
         }
         Statement::CreateSource(_)
@@ -212,7 +231,11 @@ pub fn create_stmt_rename_refs(
         | Statement::CreateTableFromSource(_)
         | Statement::CreateSecret(_)
         | Statement::CreateConnection(_)
-        | Statement::CreateWebhookSource(_) => {}
+        | Statement::CreateWebhookSource(_)
+        | Statement::CreateFunction(CreateFunctionStatement {
+            body: FunctionBody::Wasm(_),
+            ..
+        }) => {}
         item => {
             unreachable!("Internal error: only catalog items need to update item refs {item:?}")
         }
@@ -34,6 +34,7 @@ use mz_repr::network_policy_id::NetworkPolicyId;
 use mz_repr::role_id::RoleId;
 use mz_repr::{
     CatalogItemId, ColumnName, GlobalId, RelationDesc, RelationVersion, RelationVersionSelector,
+    ScalarType,
 };
 use mz_sql_parser::ast::{Expr, QualifiedReplica, UnresolvedItemName};
 use mz_storage_types::connections::inline::{ConnectionResolver, ReferencedConnection};
@@ -492,6 +493,8 @@ pub struct RoleAttributes {
     pub superuser: Option<bool>,
     /// Whether this role is login
     pub login: Option<bool>,
+    /// Whether this role can stream changes over logical replication.
+    pub replication: Option<bool>,
     // Force use of constructor.
     _private: (),
 }
@@ -504,6 +507,7 @@ impl RoleAttributes {
             password: None,
             superuser: None,
             login: None,
+            replication: None,
             _private: (),
         }
     }
@@ -531,6 +535,7 @@ impl RoleAttributes {
             password: None,
             superuser: self.superuser,
             login: self.login,
+            replication: self.replication,
             _private: (),
         }
     }
@@ -543,6 +548,7 @@ impl From<PlannedRoleAttributes> for RoleAttributes {
             password,
             superuser,
             login,
+            replication,
             ..
         }: PlannedRoleAttributes,
     ) -> RoleAttributes {
@@ -552,6 +558,7 @@ impl From<PlannedRoleAttributes> for RoleAttributes {
             password,
             superuser,
             login,
+            replication,
             _private: (),
         }
     }
@@ -749,6 +756,10 @@ pub trait CatalogItem {
     /// catalog item is a type.
     fn type_details(&self) -> Option<&CatalogTypeDetails<IdReference>>;
 
+    /// Returns the signature of the catalog item, if the catalog item is a
+    /// user-defined function.
+    fn func_details(&self) -> Option<&CatalogFuncDetails>;
+
     /// Returns the ID of the owning role.
     fn owner_id(&self) -> RoleId;
 
@@ -804,6 +815,10 @@ pub enum CatalogItemType {
     Connection,
     /// A continual task.
     ContinualTask,
+    /// A publication.
+    Publication,
+    /// A notification.
+    Notification,
 }
 
 impl CatalogItemType {
@@ -838,6 +853,8 @@ impl CatalogItemType {
             CatalogItemType::Secret => false,
             CatalogItemType::Connection => false,
             CatalogItemType::ContinualTask => true,
+            CatalogItemType::Publication => false,
+            CatalogItemType::Notification => false,
         }
     }
 }
@@ -856,6 +873,8 @@ impl fmt::Display for CatalogItemType {
             CatalogItemType::Secret => f.write_str("secret"),
             CatalogItemType::Connection => f.write_str("connection"),
             CatalogItemType::ContinualTask => f.write_str("continual task"),
+            CatalogItemType::Publication => f.write_str("publication"),
+            CatalogItemType::Notification => f.write_str("notification"),
         }
     }
 }
@@ -874,6 +893,8 @@ impl From<CatalogItemType> for ObjectType {
             CatalogItemType::Secret => ObjectType::Secret,
             CatalogItemType::Connection => ObjectType::Connection,
             CatalogItemType::ContinualTask => ObjectType::ContinualTask,
+            CatalogItemType::Publication => ObjectType::Publication,
+            CatalogItemType::Notification => ObjectType::Notification,
         }
     }
 }
@@ -892,6 +913,8 @@ impl From<CatalogItemType> for mz_audit_log::ObjectType {
             CatalogItemType::Secret => mz_audit_log::ObjectType::Secret,
             CatalogItemType::Connection => mz_audit_log::ObjectType::Connection,
             CatalogItemType::ContinualTask => mz_audit_log::ObjectType::ContinualTask,
+            CatalogItemType::Publication => mz_audit_log::ObjectType::Publication,
+            CatalogItemType::Notification => mz_audit_log::ObjectType::Notification,
         }
     }
 }
@@ -907,6 +930,33 @@ pub struct CatalogTypeDetails<T: TypeReference> {
     pub pg_metadata: Option<CatalogTypePgMetadata>,
 }
 
+/// Details about a user-defined function in the catalog.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CatalogFuncDetails {
+    /// The parameters of the function, in order.
+    pub params: Vec<CatalogFuncParam>,
+    /// What the function returns.
+    pub returns: CatalogFuncReturns,
+}
+
+/// A parameter of a user-defined function.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CatalogFuncParam {
+    /// The name of the parameter, if it has one.
+    pub name: Option<ColumnName>,
+    /// The type of the parameter.
+    pub typ: ScalarType,
+}
+
+/// What a user-defined function returns.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CatalogFuncReturns {
+    /// A single value of the specified type.
+    Scalar(ScalarType),
+    /// A set of rows with the specified columns.
+    Table(Vec<(ColumnName, ScalarType)>),
+}
+
 /// Additional PostgreSQL metadata about a type.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CatalogTypePgMetadata {
@@ -942,7 +992,8 @@ impl TypeReference for IdReference {
 ///
 /// The variants correspond one-to-one with [`mz_repr::ScalarType`], but with type
 /// modifiers removed and with embedded types replaced with references to other
-/// types in the catalog.
+/// types in the catalog. The exception is [`CatalogType::Domain`], which
+/// corresponds to the `ScalarType` of its base type.
 #[allow(missing_docs)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CatalogType<T: TypeReference> {
@@ -954,8 +1005,23 @@ pub enum CatalogType<T: TypeReference> {
     Bytes,
     Char,
     Date,
+    /// A type that constrains the values of its base type.
+    Domain {
+        base_reference: T::Reference,
+        base_modifiers: Vec<i64>,
+        /// Whether the domain rejects `NULL`s.
+        not_null: bool,
+        /// A boolean expression over the keyword `VALUE` that every non-`NULL`
+        /// value of the domain must satisfy.
+        check: Option<Expr<Aug>>,
+    },
+    Enum {
+        /// The labels of the enum, in their declared sort order.
+        labels: Vec<String>,
+    },
     Float32,
     Float64,
+    Geometry,
     Int16,
     Int32,
     Int64,
@@ -993,8 +1059,11 @@ pub enum CatalogType<T: TypeReference> {
     Time,
     Timestamp,
     TimestampTz,
+    TsQuery,
+    TsVector,
     Uuid,
     VarChar,
+    Vector,
     Int2Vector,
     MzAclItem,
 }
@@ -1283,6 +1352,10 @@ pub enum CatalogError {
     UnknownConnection(String),
     /// Unknown network policy.
     UnknownNetworkPolicy(String),
+    /// Replication slot already exists.
+    ReplicationSlotAlreadyExists(String),
+    /// Unknown replication slot.
+    UnknownReplicationSlot(String),
     /// Expected the catalog item to have the given type, but it did not.
     UnexpectedType {
         /// The item's name.
@@ -1332,6 +1405,12 @@ impl fmt::Display for CatalogError {
             }
             Self::UnknownCluster(name) => write!(f, "unknown cluster '{}'", name),
             Self::UnknownNetworkPolicy(name) => write!(f, "unknown network policy '{}'", name),
+            Self::ReplicationSlotAlreadyExists(name) => {
+                write!(f, "replication slot \"{name}\" already exists")
+            }
+            Self::UnknownReplicationSlot(name) => {
+                write!(f, "replication slot \"{name}\" does not exist")
+            }
             Self::UnexpectedBuiltinCluster(name) => {
                 write!(f, "Unexpected builtin cluster '{}'", name)
             }
@@ -1427,6 +1506,8 @@ pub enum ObjectType {
     Func,
     ContinualTask,
     NetworkPolicy,
+    Publication,
+    Notification,
 }
 
 impl ObjectType {
@@ -1449,7 +1530,9 @@ impl ObjectType {
             | ObjectType::Cluster
             | ObjectType::ClusterReplica
             | ObjectType::Role
-            | ObjectType::NetworkPolicy => false,
+            | ObjectType::NetworkPolicy
+            | ObjectType::Publication
+            | ObjectType::Notification => false,
         }
     }
 }
@@ -1475,6 +1558,8 @@ impl From<mz_sql_parser::ast::ObjectType> for ObjectType {
             mz_sql_parser::ast::ObjectType::Func => ObjectType::Func,
             mz_sql_parser::ast::ObjectType::ContinualTask => ObjectType::ContinualTask,
             mz_sql_parser::ast::ObjectType::NetworkPolicy => ObjectType::NetworkPolicy,
+            mz_sql_parser::ast::ObjectType::Publication => ObjectType::Publication,
+            mz_sql_parser::ast::ObjectType::Notification => ObjectType::Notification,
         }
     }
 }
@@ -1499,6 +1584,8 @@ impl From<CommentObjectId> for ObjectType {
             CommentObjectId::ClusterReplica(_) => ObjectType::ClusterReplica,
             CommentObjectId::ContinualTask(_) => ObjectType::ContinualTask,
             CommentObjectId::NetworkPolicy(_) => ObjectType::NetworkPolicy,
+            CommentObjectId::Publication(_) => ObjectType::Publication,
+            CommentObjectId::Notification(_) => ObjectType::Notification,
         }
     }
 }
@@ -1523,6 +1610,8 @@ impl Display for ObjectType {
             ObjectType::Func => "FUNCTION",
             ObjectType::ContinualTask => "CONTINUAL TASK",
             ObjectType::NetworkPolicy => "NETWORK POLICY",
+            ObjectType::Publication => "PUBLICATION",
+            ObjectType::Notification => "NOTIFICATION",
         })
     }
 }
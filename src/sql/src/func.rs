@@ -77,7 +77,11 @@ impl TypeCategory {
             ScalarType::AclItem
             | ScalarType::Bytes
             | ScalarType::Jsonb
+            | ScalarType::TsQuery
+            | ScalarType::TsVector
             | ScalarType::Uuid
+            | ScalarType::Vector { .. }
+            | ScalarType::Geometry
             | ScalarType::MzAclItem => Self::UserDefined,
             ScalarType::Date
             | ScalarType::Time
@@ -113,6 +117,7 @@ impl TypeCategory {
             ScalarType::Map { .. } => Self::Pseudo,
             ScalarType::MzTimestamp => Self::Numeric,
             ScalarType::Range { .. } => Self::Range,
+            ScalarType::Enum { .. } => Self::Enum,
         }
     }
 
@@ -123,6 +128,7 @@ impl TypeCategory {
             | ParamType::ArrayAny
             | ParamType::ArrayAnyCompatible
             | ParamType::AnyCompatible
+            | ParamType::EnumAny
             | ParamType::ListAny
             | ParamType::ListAnyCompatible
             | ParamType::ListElementAnyCompatible
@@ -151,7 +157,11 @@ impl TypeCategory {
             CatalogType::AclItem
             | CatalogType::Bytes
             | CatalogType::Jsonb
+            | CatalogType::TsQuery
+            | CatalogType::TsVector
             | CatalogType::Uuid
+            | CatalogType::Vector
+            | CatalogType::Geometry
             | CatalogType::MzAclItem => Self::UserDefined,
             CatalogType::Date
             | CatalogType::Time
@@ -181,6 +191,10 @@ impl TypeCategory {
             CatalogType::Map { .. } | CatalogType::Pseudo => Self::Pseudo,
             CatalogType::MzTimestamp => Self::String,
             CatalogType::Range { .. } => Self::Range,
+            CatalogType::Enum { .. } => Self::Enum,
+            // Postgres reports a domain's category as that of its base type,
+            // which we cannot look up here.
+            CatalogType::Domain { .. } => Self::UserDefined,
         }
     }
 
@@ -681,6 +695,9 @@ pub enum ParamType {
     /// A pseudotype permitting any array type, permitting other "Compatibility"-type
     /// parameters to find the best common type.
     ArrayAnyCompatible,
+    /// A pseudotype permitting any enum type, requiring other "Any"-type
+    /// parameters to be of the same type.
+    EnumAny,
     /// An pseudotype permitting any list type, requiring other "Any"-type
     /// parameters to be of the same type.
     ListAny,
@@ -736,6 +753,7 @@ impl ParamType {
         match self {
             Any | AnyElement | AnyCompatible | ListElementAnyCompatible => true,
             ArrayAny | ArrayAnyCompatible => matches!(t, Array(..) | Int2Vector),
+            EnumAny => matches!(t, Enum { .. }),
             ListAny | ListAnyCompatible => matches!(t, List { .. }),
             MapAny | MapAnyCompatible => matches!(t, Map { .. }),
             RangeAny | RangeAnyCompatible => matches!(t, Range { .. }),
@@ -782,6 +800,7 @@ impl ParamType {
             | ArrayAny
             | ArrayAnyCompatible
             | AnyCompatible
+            | EnumAny
             | ListAny
             | ListAnyCompatible
             | ListElementAnyCompatible
@@ -814,6 +833,7 @@ impl ParamType {
             ParamType::AnyElement => "anyelement",
             ParamType::ArrayAny => "anyarray",
             ParamType::ArrayAnyCompatible => "anycompatiblearray",
+            ParamType::EnumAny => "anyenum",
             ParamType::Internal => "internal",
             ParamType::ListAny => "list",
             ParamType::ListAnyCompatible => "anycompatiblelist",
@@ -856,7 +876,7 @@ impl From<ScalarBaseType> for ParamType {
     fn from(s: ScalarBaseType) -> ParamType {
         use ScalarBaseType::*;
         let s = match s {
-            Array | List | Map | Record | Range => {
+            Array | Enum | List | Map | Record | Range => {
                 panic!("use polymorphic parameters rather than {:?}", s);
             }
             AclItem => ScalarType::AclItem,
@@ -879,10 +899,14 @@ impl From<ScalarBaseType> for ParamType {
             String => ScalarType::String,
             Char => ScalarType::Char { length: None },
             VarChar => ScalarType::VarChar { max_length: None },
+            Vector => ScalarType::Vector { dimensions: None },
+            Geometry => ScalarType::Geometry,
             PgLegacyChar => ScalarType::PgLegacyChar,
             PgLegacyName => ScalarType::PgLegacyName,
             Jsonb => ScalarType::Jsonb,
             Uuid => ScalarType::Uuid,
+            TsVector => ScalarType::TsVector,
+            TsQuery => ScalarType::TsQuery,
             Oid => ScalarType::Oid,
             RegClass => ScalarType::RegClass,
             RegProc => ScalarType::RegProc,
@@ -1321,7 +1345,7 @@ impl TryFrom<&ParamType> for PolymorphicCompatClass {
         use ParamType::*;
 
         Ok(match param {
-            AnyElement | ArrayAny | ListAny | MapAny | NonVecAny | RangeAny => {
+            AnyElement | ArrayAny | EnumAny | ListAny | MapAny | NonVecAny | RangeAny => {
                 PolymorphicCompatClass::Any
             }
             ArrayAnyCompatible | AnyCompatible | RangeAnyCompatible | NonVecAnyCompatible => {
@@ -1390,7 +1414,7 @@ impl PolymorphicSolution {
 
         self.seen.push(match param {
             // These represent the keys of their respective compatibility classes.
-            AnyElement | AnyCompatible | ListAnyCompatible |  MapAnyCompatible | NonVecAny | RecordAny => seen,
+            AnyElement | AnyCompatible | EnumAny | ListAnyCompatible |  MapAnyCompatible | NonVecAny | RecordAny => seen,
             MapAny => seen.map_coerced(|array| array.unwrap_map_value_type().clone()),
             ListAny => seen.map_coerced(|array| array.unwrap_list_element_type().clone()),
             ArrayAny | ArrayAnyCompatible => seen.map_coerced(|array| array.unwrap_array_element_type().clone()),
@@ -1510,9 +1534,8 @@ impl PolymorphicSolution {
         );
 
         match param {
-            AnyElement | AnyCompatible | ListAnyCompatible | MapAnyCompatible | NonVecAny => {
-                self.key.clone()
-            }
+            AnyElement | AnyCompatible | EnumAny | ListAnyCompatible | MapAnyCompatible
+            | NonVecAny => self.key.clone(),
             ArrayAny | ArrayAnyCompatible => self
                 .key
                 .as_ref()
@@ -2694,6 +2717,20 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "to_timestamp" => Scalar {
             params!(Float64) => UnaryFunc::ToTimestamp(func::ToTimestamp) => TimestampTz, 1158;
         },
+        "to_tsquery" => Scalar {
+            params!(String) => Operation::unary(move |_ecx, input| {
+                let config = HirScalarExpr::literal(Datum::String("english"), ScalarType::String);
+                Ok(config.call_binary(input, BinaryFunc::ToTsQuery))
+            }) => TsQuery, 3750;
+            params!(String, String) => BinaryFunc::ToTsQuery => TsQuery, 3746;
+        },
+        "to_tsvector" => Scalar {
+            params!(String) => Operation::unary(move |_ecx, input| {
+                let config = HirScalarExpr::literal(Datum::String("english"), ScalarType::String);
+                Ok(config.call_binary(input, BinaryFunc::ToTsVector))
+            }) => TsVector, 3749;
+            params!(String, String) => BinaryFunc::ToTsVector => TsVector, 3745;
+        },
         "translate" => Scalar {
             params!(String, String, String) => VariadicFunc::Translate => String, 878;
         },
@@ -2702,6 +2739,12 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(Float64) => UnaryFunc::TruncFloat64(func::TruncFloat64) => Float64, 1343;
             params!(Numeric) => UnaryFunc::TruncNumeric(func::TruncNumeric) => Numeric, 1710;
         },
+        "ts_rank" => Scalar {
+            params!(TsVector, TsQuery) => BinaryFunc::TsRank => Float32, 3704;
+        },
+        "tsvector_to_array" => Scalar {
+            params!(TsVector) => UnaryFunc::TsVectorToArray(func::TsVectorToArray) => ScalarType::Array(Box::new(ScalarType::String)), 3326;
+        },
         "tsrange" => Scalar {
             params!(Timestamp, Timestamp) => Operation::variadic(|_ecx, mut exprs| {
                 exprs.push(HirScalarExpr::literal(Datum::String("[)"), ScalarType::String));
@@ -2796,6 +2839,9 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "anyelement_in" => Scalar {
             params!(String) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("anyelement_in")) => AnyElement, 2312;
         },
+        "anyenum_in" => Scalar {
+            params!(String) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("anyenum_in")) => EnumAny, 3504;
+        },
         "anynonarray_in" => Scalar {
             params!(String) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("anynonarray_in")) => NonVecAny, 2777;
         },
@@ -2890,6 +2936,12 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "uuid_in" => Scalar {
             params!(String) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("uuid_in")) => Uuid, 2952;
         },
+        "tsvectorin" => Scalar {
+            params!(String) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("tsvectorin")) => TsVector, 3610;
+        },
+        "tsqueryin" => Scalar {
+            params!(String) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("tsqueryin")) => TsQuery, 3612;
+        },
         "boolrecv" => Scalar {
             params!(Internal) => Operation::nullary(|_ecx| catalog_name_only!("boolrecv")) => Bool, 2436;
         },
@@ -2965,6 +3017,12 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "uuid_recv" => Scalar {
             params!(Internal) => Operation::nullary(|_ecx| catalog_name_only!("uuid_recv")) => Uuid, 2961;
         },
+        "tsvectorrecv" => Scalar {
+            params!(Internal) => Operation::nullary(|_ecx| catalog_name_only!("tsvectorrecv")) => TsVector, 3639;
+        },
+        "tsqueryrecv" => Scalar {
+            params!(Internal) => Operation::nullary(|_ecx| catalog_name_only!("tsqueryrecv")) => TsQuery, 3641;
+        },
         "varcharrecv" => Scalar {
             params!(Internal) => Operation::nullary(|_ecx| catalog_name_only!("varcharrecv")) => VarChar, 2432;
         },
@@ -3544,6 +3602,9 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "concat_agg" => Aggregate {
             params!(Any) => Operation::unary(|_ecx, _e| bail_unsupported!("concat_agg")) => String, oid::FUNC_CONCAT_AGG_OID;
         },
+        "cosine_distance" => Scalar {
+            params!(Vector, Vector) => BinaryFunc::VectorCosineDistance => Float64, oid::FUNC_COSINE_DISTANCE_OID;
+        },
         "crc32" => Scalar {
             params!(String) => UnaryFunc::Crc32String(func::Crc32String) => UInt32, oid::FUNC_CRC32_STRING_OID;
             params!(Bytes) => UnaryFunc::Crc32Bytes(func::Crc32Bytes) => UInt32, oid::FUNC_CRC32_BYTES_OID;
@@ -3662,10 +3723,16 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(String, String) => sql_impl_func("has_type_privilege(current_user, $1, $2)") => Bool, 3142;
             params!(Oid, String) => sql_impl_func("has_type_privilege(current_user, $1, $2)") => Bool, 3143;
         },
+        "inner_product" => Scalar {
+            params!(Vector, Vector) => BinaryFunc::VectorInnerProduct => Float64, oid::FUNC_INNER_PRODUCT_OID;
+        },
         "kafka_murmur2" => Scalar {
             params!(String) => UnaryFunc::KafkaMurmur2String(func::KafkaMurmur2String) => Int32, oid::FUNC_KAFKA_MURMUR2_STRING_OID;
             params!(Bytes) => UnaryFunc::KafkaMurmur2Bytes(func::KafkaMurmur2Bytes) => Int32, oid::FUNC_KAFKA_MURMUR2_BYTES_OID;
         },
+        "l2_distance" => Scalar {
+            params!(Vector, Vector) => BinaryFunc::VectorL2Distance => Float64, oid::FUNC_L2_DISTANCE_OID;
+        },
         "list_agg" => Aggregate {
             params!(Any) => Operation::unary_ordered(|ecx, e, order_by| {
                 if let ScalarType::Char {.. }  = ecx.scalar_type(&e) {
@@ -3852,6 +3919,91 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "starts_with" => Scalar {
             params!(String, String) => BinaryFunc::StartsWith => Bool, 3696;
         },
+        "st_asbinary" => Scalar {
+            params!(Geometry) => UnaryFunc::StAsBinary(func::StAsBinary) => Bytes, oid::FUNC_ST_ASBINARY_OID;
+        },
+        "st_asewkt" => Scalar {
+            params!(Geometry) => UnaryFunc::StAsEwkt(func::StAsEwkt) => String, oid::FUNC_ST_ASEWKT_OID;
+        },
+        "st_astext" => Scalar {
+            params!(Geometry) => UnaryFunc::StAsText(func::StAsText) => String, oid::FUNC_ST_ASTEXT_OID;
+        },
+        "st_contains" => Scalar {
+            params!(Geometry, Geometry) => BinaryFunc::StContains => Bool, oid::FUNC_ST_CONTAINS_OID;
+        },
+        "st_distance" => Scalar {
+            params!(Geometry, Geometry) => BinaryFunc::StDistance => Float64, oid::FUNC_ST_DISTANCE_OID;
+        },
+        "st_dwithin" => Scalar {
+            params!(Geometry, Geometry, Float64) => Operation::variadic(|_ecx, exprs| {
+                let [a, b, distance] = <[_; 3]>::try_from(exprs).expect("st_dwithin takes three arguments");
+                Ok(a.call_binary(b, BinaryFunc::StDistance).call_binary(distance, BinaryFunc::Lte))
+            }) => Bool, oid::FUNC_ST_DWITHIN_OID;
+        },
+        "st_geohash" => Scalar {
+            params!(Geometry) => Operation::unary(|_ecx, e| {
+                // A precision of zero chooses the precision from the geometry's extent.
+                let precision = HirScalarExpr::literal(Datum::Int32(0), ScalarType::Int32);
+                Ok(e.call_binary(precision, BinaryFunc::StGeoHash))
+            }) => String, oid::FUNC_ST_GEOHASH_OID;
+            params!(Geometry, Int32) => BinaryFunc::StGeoHash => String, oid::FUNC_ST_GEOHASH_PRECISION_OID;
+        },
+        "st_geohash_cells" => Scalar {
+            params!(Geometry, Int32) => BinaryFunc::StGeoHashCells => ScalarType::Array(Box::new(ScalarType::String)), oid::FUNC_ST_GEOHASH_CELLS_OID;
+        },
+        "st_geometrytype" => Scalar {
+            params!(Geometry) => UnaryFunc::StGeometryType(func::StGeometryType) => String, oid::FUNC_ST_GEOMETRYTYPE_OID;
+        },
+        "st_geomfromtext" => Scalar {
+            params!(String) => UnaryFunc::CastStringToGeometry(func::CastStringToGeometry) => Geometry, oid::FUNC_ST_GEOMFROMTEXT_OID;
+            params!(String, Int32) => Operation::binary(|_ecx, text, srid| {
+                let geometry = text.call_unary(UnaryFunc::CastStringToGeometry(func::CastStringToGeometry));
+                Ok(geometry.call_binary(srid, BinaryFunc::StSetSrid))
+            }) => Geometry, oid::FUNC_ST_GEOMFROMTEXT_SRID_OID;
+        },
+        "st_geomfromwkb" => Scalar {
+            params!(Bytes) => UnaryFunc::CastBytesToGeometry(func::CastBytesToGeometry) => Geometry, oid::FUNC_ST_GEOMFROMWKB_OID;
+            params!(Bytes, Int32) => Operation::binary(|_ecx, wkb, srid| {
+                let geometry = wkb.call_unary(UnaryFunc::CastBytesToGeometry(func::CastBytesToGeometry));
+                Ok(geometry.call_binary(srid, BinaryFunc::StSetSrid))
+            }) => Geometry, oid::FUNC_ST_GEOMFROMWKB_SRID_OID;
+        },
+        "st_intersects" => Scalar {
+            params!(Geometry, Geometry) => BinaryFunc::StIntersects => Bool, oid::FUNC_ST_INTERSECTS_OID;
+        },
+        "st_makeenvelope" => Scalar {
+            params!(Float64, Float64, Float64, Float64) => VariadicFunc::StMakeEnvelope => Geometry, oid::FUNC_ST_MAKEENVELOPE_OID;
+            params!(Float64, Float64, Float64, Float64, Int32) => VariadicFunc::StMakeEnvelope => Geometry, oid::FUNC_ST_MAKEENVELOPE_SRID_OID;
+        },
+        "st_makeline" => Scalar {
+            params!(Geometry, Geometry) => BinaryFunc::StMakeLine => Geometry, oid::FUNC_ST_MAKELINE_OID;
+        },
+        "st_makepoint" => Scalar {
+            params!(Float64, Float64) => BinaryFunc::StMakePoint => Geometry, oid::FUNC_ST_MAKEPOINT_OID;
+        },
+        "st_makepolygon" => Scalar {
+            params!(Geometry) => UnaryFunc::StMakePolygon(func::StMakePolygon) => Geometry, oid::FUNC_ST_MAKEPOLYGON_OID;
+        },
+        "st_point" => Scalar {
+            params!(Float64, Float64) => BinaryFunc::StMakePoint => Geometry, oid::FUNC_ST_POINT_OID;
+        },
+        "st_setsrid" => Scalar {
+            params!(Geometry, Int32) => BinaryFunc::StSetSrid => Geometry, oid::FUNC_ST_SETSRID_OID;
+        },
+        "st_srid" => Scalar {
+            params!(Geometry) => UnaryFunc::StSrid(func::StSrid) => Int32, oid::FUNC_ST_SRID_OID;
+        },
+        "st_within" => Scalar {
+            params!(Geometry, Geometry) => Operation::binary(|_ecx, a, b| {
+                Ok(b.call_binary(a, BinaryFunc::StContains))
+            }) => Bool, oid::FUNC_ST_WITHIN_OID;
+        },
+        "st_x" => Scalar {
+            params!(Geometry) => UnaryFunc::StX(func::StX) => Float64, oid::FUNC_ST_X_OID;
+        },
+        "st_y" => Scalar {
+            params!(Geometry) => UnaryFunc::StY(func::StY) => Float64, oid::FUNC_ST_Y_OID;
+        },
         "timezone_offset" => Scalar {
             params!(String, TimestampTz) => BinaryFunc::TimezoneOffset => RecordAny, oid::FUNC_TIMEZONE_OFFSET;
         },
@@ -3897,6 +4049,12 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             }) =>
                 // This return type should be equivalent to "ListElementAny", but this would be its sole use.
                 ReturnType::set_of(Any), oid::FUNC_UNNEST_MAP_OID;
+        },
+        "vector_dims" => Scalar {
+            params!(Vector) => UnaryFunc::VectorDims(func::VectorDims) => Int32, oid::FUNC_VECTOR_DIMS_OID;
+        },
+        "vector_norm" => Scalar {
+            params!(Vector) => UnaryFunc::VectorNorm(func::VectorNorm) => Float64, oid::FUNC_VECTOR_NORM_OID;
         }
     }
 });
@@ -4773,6 +4931,21 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
                 Ok(lhs.call_binary(rhs, BinaryFunc::ListContainsList { rev: false }))
             }) => Bool, oid::OP_CONTAINS_LIST_LIST_OID;
         },
+        "<->" => Scalar {
+            params!(Vector, Vector) => VectorL2Distance => Float64, oid::OP_L2_DISTANCE_VECTOR_OID;
+        },
+        "<=>" => Scalar {
+            params!(Vector, Vector) => VectorCosineDistance => Float64, oid::OP_COSINE_DISTANCE_VECTOR_OID;
+        },
+        "<#>" => Scalar {
+            params!(Vector, Vector) => VectorNegativeInnerProduct => Float64, oid::OP_NEGATIVE_INNER_PRODUCT_VECTOR_OID;
+        },
+        "@@" => Scalar {
+            params!(TsVector, TsQuery) => TsMatch => Bool, 3636;
+            params!(TsQuery, TsVector) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, TsMatch))
+            }) => Bool, 3637;
+        },
         "<@" => Scalar {
             params!(Jsonb, Jsonb) => Operation::binary(|_ecx, lhs, rhs| {
                 Ok(rhs.call_binary(
@@ -4859,6 +5032,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Lt => Bool, 2990;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Lt =>Bool, oid::FUNC_MZ_TIMESTAMP_LT_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Lt => Bool, 3884;
+            params!(EnumAny, EnumAny) => BinaryFunc::Lt => Bool, 3518;
         },
         "<=" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Lte => Bool, 1755;
@@ -4888,6 +5062,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Lte => Bool, 2992;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Lte =>Bool, oid::FUNC_MZ_TIMESTAMP_LTE_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Lte => Bool, 3885;
+            params!(EnumAny, EnumAny) => BinaryFunc::Lte => Bool, 3520;
         },
         ">" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Gt => Bool, 1756;
@@ -4917,6 +5092,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Gt => Bool, 2991;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Gt =>Bool, oid::FUNC_MZ_TIMESTAMP_GT_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Gt => Bool, 3887;
+            params!(EnumAny, EnumAny) => BinaryFunc::Gt => Bool, 3519;
         },
         ">=" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Gte => Bool, 1757;
@@ -4946,6 +5122,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Gte => Bool, 2993;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Gte =>Bool, oid::FUNC_MZ_TIMESTAMP_GTE_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Gte => Bool, 3886;
+            params!(EnumAny, EnumAny) => BinaryFunc::Gte => Bool, 3521;
         },
         // Warning!
         // - If you are writing functions here that do not simply use
@@ -4985,6 +5162,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Eq => Bool, 2988;
             params!(MzTimestamp, MzTimestamp) => BinaryFunc::Eq => Bool, oid::FUNC_MZ_TIMESTAMP_EQ_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Eq => Bool, 3882;
+            params!(EnumAny, EnumAny) => BinaryFunc::Eq => Bool, 3516;
             params!(MzAclItem, MzAclItem) => BinaryFunc::Eq => Bool, oid::FUNC_MZ_ACL_ITEM_EQ_MZ_ACL_ITEM_OID;
             params!(AclItem, AclItem) => BinaryFunc::Eq => Bool, 974;
         },
@@ -5016,6 +5194,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::NotEq => Bool, 2989;
             params!(MzTimestamp, MzTimestamp) => BinaryFunc::NotEq => Bool, oid::FUNC_MZ_TIMESTAMP_NOT_EQ_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::NotEq => Bool, 3883;
+            params!(EnumAny, EnumAny) => BinaryFunc::NotEq => Bool, 3517;
             params!(MzAclItem, MzAclItem) => BinaryFunc::NotEq => Bool, oid::FUNC_MZ_ACL_ITEM_NOT_EQ_MZ_ACL_ITEM_OID;
         }
     }
@@ -38,7 +38,8 @@ use crate::ast::visit_mut::VisitMut;
 use crate::ast::{
     self, AstInfo, Cte, CteBlock, CteMutRec, DocOnIdentifier, GrantTargetSpecification,
     GrantTargetSpecificationInner, Ident, MutRecBlock, ObjectType, Query, Raw, RawClusterName,
-    RawDataType, RawItemName, Statement, UnresolvedItemName, UnresolvedObjectName,
+    RawDataType, RawItemName, ShowCreateFunctionStatement, Statement, UnresolvedItemName,
+    UnresolvedObjectName,
 };
 use crate::catalog::{
     CatalogError, CatalogItem, CatalogItemType, CatalogType, CatalogTypeDetails, SessionCatalog,
@@ -1188,7 +1189,9 @@ impl From<CommentObjectId> for ObjectId {
             | CommentObjectId::Connection(item_id)
             | CommentObjectId::Type(item_id)
             | CommentObjectId::Secret(item_id)
-            | CommentObjectId::ContinualTask(item_id) => ObjectId::Item(item_id),
+            | CommentObjectId::ContinualTask(item_id)
+            | CommentObjectId::Publication(item_id)
+            | CommentObjectId::Notification(item_id) => ObjectId::Item(item_id),
             CommentObjectId::Role(id) => ObjectId::Role(id),
             CommentObjectId::Database(id) => ObjectId::Database(id),
             CommentObjectId::Schema(id) => ObjectId::Schema(id),
@@ -1244,6 +1247,8 @@ pub enum CommentObjectId {
     Type(CatalogItemId),
     Secret(CatalogItemId),
     ContinualTask(CatalogItemId),
+    Publication(CatalogItemId),
+    Notification(CatalogItemId),
     Role(RoleId),
     Database(DatabaseId),
     Schema((ResolvedDatabaseSpecifier, SchemaSpecifier)),
@@ -2167,6 +2172,21 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                 join: Box::new(self.fold_table_with_joins(*join)),
                 alias: alias.map(|alias| self.fold_table_alias(alias)),
             },
+            TimeWindow {
+                kind,
+                relation,
+                time_column,
+                size,
+                slide,
+                alias,
+            } => TimeWindow {
+                kind,
+                relation: self.fold_item_name(relation),
+                time_column: self.fold_ident(time_column),
+                size: self.fold_expr(size),
+                slide: slide.map(|slide| self.fold_expr(slide)),
+                alias: alias.map(|alias| self.fold_table_alias(alias)),
+            },
         }
     }
 
@@ -2176,35 +2196,54 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
     ) -> GrantTargetSpecification<Aug> {
         match node {
             GrantTargetSpecification::Object {
-                object_type: ObjectType::Type,
+                object_type: object_type @ (ObjectType::Type | ObjectType::Func),
                 object_spec_inner: GrantTargetSpecificationInner::Objects { names },
-            } => GrantTargetSpecification::Object {
-                object_type: ObjectType::Type,
-                object_spec_inner: GrantTargetSpecificationInner::Objects {
-                    names: names
-                        .into_iter()
-                        .map(|name| match name {
-                            UnresolvedObjectName::Item(name) => {
-                                ResolvedObjectName::Item(self.resolve_item_name_name(
-                                    name,
-                                    // `{GRANT|REVOKE} ... ON TYPE ...` can only
-                                    // refer to type names.
-                                    ItemResolutionConfig {
-                                        functions: false,
-                                        types: true,
-                                        relations: false,
-                                    },
-                                ))
-                            }
-                            _ => self.fold_object_name(name),
-                        })
-                        .collect(),
-                },
-            },
+            } => {
+                // `{GRANT|REVOKE} ... ON TYPE ...` can only refer to type
+                // names, and `{GRANT|REVOKE} ... ON FUNCTION ...` can only
+                // refer to function names.
+                let config = ItemResolutionConfig {
+                    functions: object_type == ObjectType::Func,
+                    types: object_type == ObjectType::Type,
+                    relations: false,
+                };
+                GrantTargetSpecification::Object {
+                    object_type,
+                    object_spec_inner: GrantTargetSpecificationInner::Objects {
+                        names: names
+                            .into_iter()
+                            .map(|name| match name {
+                                UnresolvedObjectName::Item(name) => ResolvedObjectName::Item(
+                                    self.resolve_item_name_name(name, config),
+                                ),
+                                _ => self.fold_object_name(name),
+                            })
+                            .collect(),
+                    },
+                }
+            }
             _ => mz_sql_parser::ast::fold::fold_grant_target_specification(self, node),
         }
     }
 
+    fn fold_show_create_function_statement(
+        &mut self,
+        node: ShowCreateFunctionStatement<Raw>,
+    ) -> ShowCreateFunctionStatement<Aug> {
+        ShowCreateFunctionStatement {
+            function_name: self.resolve_item_name(
+                node.function_name,
+                // `SHOW CREATE FUNCTION ...` can only refer to function names.
+                ItemResolutionConfig {
+                    functions: true,
+                    types: false,
+                    relations: false,
+                },
+            ),
+            redacted: node.redacted,
+        }
+    }
+
     fn fold_doc_on_identifier(&mut self, node: DocOnIdentifier<Raw>) -> DocOnIdentifier<Aug> {
         match node {
             DocOnIdentifier::Column(name) => DocOnIdentifier::Column(self.fold_column_name(name)),
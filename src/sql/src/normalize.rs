@@ -22,12 +22,13 @@ use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     ContinualTaskStmt, CreateConnectionStatement, CreateContinualTaskStatement,
-    CreateContinualTaskSugar, CreateIndexStatement, CreateMaterializedViewStatement,
+    CreateContinualTaskSugar, CreateFunctionStatement, CreateIndexStatement,
+    CreateMaterializedViewStatement, CreateNotificationStatement, CreatePublicationStatement,
     CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
     CreateTableFromSourceStatement, CreateTableStatement, CreateTypeStatement, CreateViewStatement,
-    CreateWebhookSourceStatement, CteBlock, Function, FunctionArgs, Ident, IfExistsBehavior,
-    MutRecBlock, Op, Query, Statement, TableFactor, TableFromSourceColumns, UnresolvedItemName,
-    UnresolvedSchemaName, Value, ViewDefinition,
+    CreateWebhookSourceStatement, CteBlock, Function, FunctionArgs, FunctionBody, Ident,
+    IfExistsBehavior, MutRecBlock, Op, Query, Statement, TableFactor, TableFromSourceColumns,
+    UnresolvedItemName, UnresolvedSchemaName, Value, ViewDefinition,
 };
 
 use crate::names::{Aug, FullItemName, PartialItemName, PartialSchemaName, RawDatabaseSpecifier};
@@ -488,6 +489,49 @@ pub fn create_statement(
             *name = allocate_name(name)?;
             *if_not_exists = false;
         }
+        Statement::CreatePublication(CreatePublicationStatement {
+            name,
+            if_not_exists,
+            tables: _,
+        }) => {
+            *name = allocate_name(name)?;
+            *if_not_exists = false;
+        }
+        Statement::CreateNotification(CreateNotificationStatement {
+            name,
+            if_not_exists,
+            on: _,
+            channel: _,
+            predicate,
+        }) => {
+            *name = allocate_name(name)?;
+            let mut normalizer = QueryNormalizer::new();
+            if let Some(predicate) = predicate {
+                normalizer.visit_expr_mut(predicate);
+            }
+            if let Some(err) = normalizer.err {
+                return Err(err);
+            }
+            *if_not_exists = false;
+        }
+        Statement::CreateFunction(CreateFunctionStatement {
+            name,
+            if_not_exists,
+            parameters: _,
+            returns: _,
+            body,
+        }) => {
+            *name = allocate_name(name)?;
+            let mut normalizer = QueryNormalizer::new();
+            match body {
+                FunctionBody::Sql(query) => normalizer.visit_query_mut(query),
+                FunctionBody::Wasm(_) => {}
+            }
+            if let Some(err) = normalizer.err {
+                return Err(err);
+            }
+            *if_not_exists = false;
+        }
         Statement::CreateConnection(CreateConnectionStatement {
             name,
             connection_type: _,
@@ -77,8 +77,8 @@ use crate::ast::{
     TransactionAccessMode,
 };
 use crate::catalog::{
-    CatalogType, DefaultPrivilegeAclItem, DefaultPrivilegeObject, IdReference, ObjectType,
-    RoleAttributes,
+    CatalogFuncDetails, CatalogType, DefaultPrivilegeAclItem, DefaultPrivilegeObject, IdReference,
+    ObjectType, RoleAttributes,
 };
 use crate::names::{
     Aug, CommentObjectId, DependencyIds, FullItemName, ObjectId, QualifiedItemName,
@@ -140,6 +140,9 @@ pub enum Plan {
     CreateSource(CreateSourcePlan),
     CreateSources(Vec<CreateSourcePlanBundle>),
     CreateSecret(CreateSecretPlan),
+    CreatePublication(CreatePublicationPlan),
+    CreateNotification(CreateNotificationPlan),
+    CreateFunction(CreateFunctionPlan),
     CreateSink(CreateSinkPlan),
     CreateTable(CreateTablePlan),
     CreateView(CreateViewPlan),
@@ -151,6 +154,8 @@ pub enum Plan {
     Comment(CommentPlan),
     DiscardTemp,
     DiscardAll,
+    Listen(ListenPlan),
+    Unlisten(UnlistenPlan),
     DropObjects(DropObjectsPlan),
     DropOwned(DropOwnedPlan),
     EmptyQuery,
@@ -193,6 +198,7 @@ pub enum Plan {
     AlterRole(AlterRolePlan),
     AlterOwner(AlterOwnerPlan),
     AlterTableAddColumn(AlterTablePlan),
+    AlterTypeAddValue(AlterTypeAddValuePlan),
     AlterNetworkPolicy(AlterNetworkPolicyPlan),
     Declare(DeclarePlan),
     Fetch(FetchPlan),
@@ -253,6 +259,7 @@ impl Plan {
             StatementKind::AlterTableAddColumn => {
                 &[PlanKind::AlterNoop, PlanKind::AlterTableAddColumn]
             }
+            StatementKind::AlterTypeAddValue => &[PlanKind::AlterNoop, PlanKind::AlterTypeAddValue],
             StatementKind::Close => &[PlanKind::Close],
             StatementKind::Comment => &[PlanKind::Comment],
             StatementKind::Commit => &[PlanKind::CommitTransaction],
@@ -273,6 +280,9 @@ impl Plan {
             StatementKind::CreateRole => &[PlanKind::CreateRole],
             StatementKind::CreateSchema => &[PlanKind::CreateSchema],
             StatementKind::CreateSecret => &[PlanKind::CreateSecret],
+            StatementKind::CreatePublication => &[PlanKind::CreatePublication],
+            StatementKind::CreateNotification => &[PlanKind::CreateNotification],
+            StatementKind::CreateFunction => &[PlanKind::CreateFunction],
             StatementKind::CreateSink => &[PlanKind::CreateSink],
             StatementKind::CreateSource | StatementKind::CreateSubsource => {
                 &[PlanKind::CreateSource]
@@ -286,11 +296,14 @@ impl Plan {
             StatementKind::Declare => &[PlanKind::Declare],
             StatementKind::Delete => &[PlanKind::ReadThenWrite],
             StatementKind::Discard => &[PlanKind::DiscardAll, PlanKind::DiscardTemp],
+            StatementKind::Listen => &[PlanKind::Listen],
+            StatementKind::Unlisten => &[PlanKind::Unlisten],
             StatementKind::DropObjects => &[PlanKind::DropObjects],
             StatementKind::DropOwned => &[PlanKind::DropOwned],
             StatementKind::Execute => &[PlanKind::Execute],
             StatementKind::ExplainPlan => &[PlanKind::ExplainPlan],
             StatementKind::ExplainPushdown => &[PlanKind::ExplainPushdown],
+            StatementKind::ExplainAnalyze => &[PlanKind::Select],
             StatementKind::ExplainTimestamp => &[PlanKind::ExplainTimestamp],
             StatementKind::ExplainSinkSchema => &[PlanKind::ExplainSinkSchema],
             StatementKind::Fetch => &[PlanKind::Fetch],
@@ -335,6 +348,9 @@ impl Plan {
             Plan::CreateSource(_) => "create source",
             Plan::CreateSources(_) => "create source",
             Plan::CreateSecret(_) => "create secret",
+            Plan::CreatePublication(_) => "create publication",
+            Plan::CreateNotification(_) => "create notification",
+            Plan::CreateFunction(_) => "create function",
             Plan::CreateSink(_) => "create sink",
             Plan::CreateTable(_) => "create table",
             Plan::CreateView(_) => "create view",
@@ -346,6 +362,8 @@ impl Plan {
             Plan::Comment(_) => "comment",
             Plan::DiscardTemp => "discard temp",
             Plan::DiscardAll => "discard all",
+            Plan::Listen(_) => "listen",
+            Plan::Unlisten(_) => "unlisten",
             Plan::DropObjects(plan) => match plan.object_type {
                 ObjectType::Table => "drop table",
                 ObjectType::View => "drop view",
@@ -364,6 +382,8 @@ impl Plan {
                 ObjectType::Func => "drop function",
                 ObjectType::ContinualTask => "drop continual task",
                 ObjectType::NetworkPolicy => "drop network policy",
+                ObjectType::Publication => "drop publication",
+                ObjectType::Notification => "drop notification",
             },
             Plan::DropOwned(_) => "drop owned",
             Plan::EmptyQuery => "do nothing",
@@ -405,6 +425,8 @@ impl Plan {
                 ObjectType::Func => "alter function",
                 ObjectType::ContinualTask => "alter continual task",
                 ObjectType::NetworkPolicy => "alter network policy",
+                ObjectType::Publication => "alter publication",
+                ObjectType::Notification => "alter notification",
             },
             Plan::AlterCluster(_) => "alter cluster",
             Plan::AlterClusterRename(_) => "alter cluster rename",
@@ -441,8 +463,11 @@ impl Plan {
                 ObjectType::Func => "alter function owner",
                 ObjectType::ContinualTask => "alter continual task owner",
                 ObjectType::NetworkPolicy => "alter network policy owner",
+                ObjectType::Publication => "alter publication owner",
+                ObjectType::Notification => "alter notification owner",
             },
             Plan::AlterTableAddColumn(_) => "alter table add column",
+            Plan::AlterTypeAddValue(_) => "alter type add value",
             Plan::Declare(_) => "declare",
             Plan::Fetch(_) => "fetch",
             Plan::Close(_) => "close",
@@ -557,6 +582,8 @@ pub struct CreateClusterPlan {
     pub name: String,
     pub variant: CreateClusterVariant,
     pub workload_class: Option<String>,
+    pub arrangement_spill: bool,
+    pub auto_index: bool,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -704,6 +731,27 @@ pub struct CreateSecretPlan {
     pub if_not_exists: bool,
 }
 
+#[derive(Debug)]
+pub struct CreatePublicationPlan {
+    pub name: QualifiedItemName,
+    pub publication: Publication,
+    pub if_not_exists: bool,
+}
+
+#[derive(Debug)]
+pub struct CreateNotificationPlan {
+    pub name: QualifiedItemName,
+    pub notification: Notification,
+    pub if_not_exists: bool,
+}
+
+#[derive(Debug)]
+pub struct CreateFunctionPlan {
+    pub name: QualifiedItemName,
+    pub function: Function,
+    pub if_not_exists: bool,
+}
+
 #[derive(Debug)]
 pub struct CreateSinkPlan {
     pub name: QualifiedItemName,
@@ -840,6 +888,17 @@ pub struct ResetVariablePlan {
     pub name: String,
 }
 
+#[derive(Debug)]
+pub struct ListenPlan {
+    pub channel: String,
+}
+
+#[derive(Debug)]
+pub struct UnlistenPlan {
+    /// The channel to stop listening on, or `None` for all channels.
+    pub channel: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct SetTransactionPlan {
     pub local: bool,
@@ -1312,6 +1371,14 @@ pub struct AlterTablePlan {
     pub raw_sql_type: RawDataType,
 }
 
+#[derive(Debug)]
+pub struct AlterTypeAddValuePlan {
+    pub id: CatalogItemId,
+    /// The labels of the enum after adding the new value, in their declared
+    /// sort order.
+    pub labels: Vec<String>,
+}
+
 #[derive(Debug)]
 pub struct DeclarePlan {
     pub name: String,
@@ -1453,6 +1520,21 @@ pub struct Table {
     pub temporary: bool,
     pub compaction_window: Option<CompactionWindow>,
     pub data_source: TableDataSource,
+    pub foreign_keys: Vec<ForeignKey>,
+}
+
+/// A `FOREIGN KEY` constraint of a table.
+///
+/// Foreign keys are not enforced, but the optimizer relies on them to remove
+/// joins if `enable_join_elimination` is set.
+#[derive(Clone, Debug, Serialize)]
+pub struct ForeignKey {
+    /// The referencing columns.
+    pub columns: Vec<usize>,
+    /// The referenced table.
+    pub referenced_id: CatalogItemId,
+    /// The referenced columns, in the order of `columns`.
+    pub referenced_columns: Vec<usize>,
 }
 
 #[derive(Clone, Debug)]
@@ -1756,6 +1838,35 @@ pub struct Secret {
     pub secret_as: MirScalarExpr,
 }
 
+#[derive(Clone, Debug)]
+pub struct Publication {
+    /// Parse-able SQL that is stored durably and defines this publication.
+    pub create_sql: String,
+    /// The relations whose changes are published, in the order they were
+    /// named.
+    pub relations: Vec<CatalogItemId>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Notification {
+    /// Parse-able SQL that is stored durably and defines this notification.
+    pub create_sql: String,
+    /// The materialized view whose changes trigger the notification.
+    pub on: CatalogItemId,
+    /// The channel on which notifications are delivered.
+    pub channel: String,
+    /// The SQL text of the filter applied to changed rows, if any.
+    pub predicate: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Function {
+    /// Parse-able SQL that is stored durably and defines this function.
+    pub create_sql: String,
+    /// The signature of the function.
+    pub details: CatalogFuncDetails,
+}
+
 #[derive(Clone, Debug)]
 pub struct Sink {
     /// Parse-able SQL that is stored durably and defines this sink.
@@ -1808,10 +1919,26 @@ pub struct Index {
     /// Collection this index is on top of.
     pub on: GlobalId,
     pub keys: Vec<mz_expr::MirScalarExpr>,
+    pub kind: IndexKind,
     pub compaction_window: Option<CompactionWindow>,
     pub cluster_id: ClusterId,
 }
 
+/// How an index arranges the collection it is on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum IndexKind {
+    /// Arranges the rows of the collection by the index keys.
+    Arrangement,
+    /// Arranges each row of the collection under each lexeme of the `tsvector`
+    /// that the single index key evaluates to, so that `@@` predicates on that
+    /// key can look up the rows that contain a lexeme of the query.
+    Lexeme,
+    /// Arranges each row of the collection under the bucket of the vector that
+    /// the single index key evaluates to, so that nearest-neighbor peeks on
+    /// that key can look up the rows in the buckets near the query vector.
+    Ann,
+}
+
 #[derive(Clone, Debug)]
 pub struct Type {
     pub create_sql: String,
@@ -1946,6 +2073,8 @@ pub struct PlanClusterOption {
     pub disk: AlterOptionParameter<bool>,
     pub schedule: AlterOptionParameter<ClusterSchedule>,
     pub workload_class: AlterOptionParameter<Option<String>>,
+    pub arrangement_spill: AlterOptionParameter<bool>,
+    pub auto_index: AlterOptionParameter<bool>,
 }
 
 impl Default for PlanClusterOption {
@@ -1961,6 +2090,8 @@ impl Default for PlanClusterOption {
             disk: AlterOptionParameter::Unchanged,
             schedule: AlterOptionParameter::Unchanged,
             workload_class: AlterOptionParameter::Unchanged,
+            arrangement_spill: AlterOptionParameter::Unchanged,
+            auto_index: AlterOptionParameter::Unchanged,
         }
     }
 }
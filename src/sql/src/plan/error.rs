@@ -26,6 +26,7 @@ use mz_repr::adt::mz_acl_item::AclMode;
 use mz_repr::adt::numeric::InvalidNumericMaxScaleError;
 use mz_repr::adt::timestamp::InvalidTimestampPrecisionError;
 use mz_repr::adt::varchar::InvalidVarCharMaxLengthError;
+use mz_repr::adt::vector::InvalidVectorDimensionsError;
 use mz_repr::{CatalogItemId, ColumnName, strconv};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{IdentError, UnresolvedItemName};
@@ -128,6 +129,7 @@ pub enum PlanError {
     },
     InvalidVarCharMaxLength(InvalidVarCharMaxLengthError),
     InvalidTimestampPrecision(InvalidTimestampPrecisionError),
+    InvalidVectorDimensions(InvalidVectorDimensionsError),
     InvalidSecret(Box<ResolvedItemName>),
     InvalidTemporarySchema,
     InvalidCast {
@@ -217,6 +219,9 @@ pub enum PlanError {
         duplicate_azs: BTreeSet<String>,
     },
     InvalidSchemaName,
+    InvalidEnumLabel {
+        label: String,
+    },
     ItemAlreadyExists {
         name: String,
         item_type: CatalogItemType,
@@ -309,6 +314,7 @@ impl PlanError {
             Self::PostgresConnectionErr { cause } => Some(cause.to_string_with_causes()),
             Self::InvalidProtobufSchema { cause } => Some(cause.to_string_with_causes()),
             Self::InvalidOptionValue { err, .. } => err.detail(),
+            Self::InvalidEnumLabel { .. } => Some("Labels must be non-empty and at most 63 bytes long.".into()),
             Self::UpsertSinkWithInvalidKey {
                 name,
                 desired_key,
@@ -594,6 +600,7 @@ impl fmt::Display for PlanError {
             Self::InvalidCharLength(e) => e.fmt(f),
             Self::InvalidVarCharMaxLength(e) => e.fmt(f),
             Self::InvalidTimestampPrecision(e) => e.fmt(f),
+            Self::InvalidVectorDimensions(e) => e.fmt(f),
             Self::Parser(e) => e.fmt(f),
             Self::ParserStatement(e) => e.fmt(f),
             Self::Unstructured(e) => write!(f, "{}", e),
@@ -706,6 +713,7 @@ impl fmt::Display for PlanError {
             Self::InvalidPrivatelinkAvailabilityZone { name, ..} => write!(f, "invalid AWS PrivateLink availability zone {}", name.quoted()),
             Self::DuplicatePrivatelinkAvailabilityZone {..} =>   write!(f, "connection cannot contain duplicate availability zones"),
             Self::InvalidSchemaName => write!(f, "no schema has been selected to create in"),
+            Self::InvalidEnumLabel { label } => write!(f, "invalid enum label {}", label.quoted()),
             Self::ItemAlreadyExists { name, item_type } => write!(f, "{item_type} {} already exists", name.quoted()),
             Self::ManagedCluster {cluster_name} => write!(f, "cannot modify managed cluster {cluster_name}"),
             Self::InvalidKeysInSubscribeEnvelopeUpsert => {
@@ -861,6 +869,12 @@ impl From<InvalidTimestampPrecisionError> for PlanError {
     }
 }
 
+impl From<InvalidVectorDimensionsError> for PlanError {
+    fn from(e: InvalidVectorDimensionsError) -> PlanError {
+        PlanError::InvalidVectorDimensions(e)
+    }
+}
+
 impl From<anyhow::Error> for PlanError {
     fn from(e: anyhow::Error) -> PlanError {
         // WIP: Do we maybe want to keep the alternate selector for these?
@@ -1073,6 +1073,9 @@ pub enum JoinKind {
     LeftOuter,
     RightOuter,
     FullOuter,
+    /// An inner join that matches each row of the left input with at most one row of the right
+    /// input, the one closest in time according to the join's match condition.
+    AsOf,
 }
 
 impl fmt::Display for JoinKind {
@@ -1085,6 +1088,7 @@ impl fmt::Display for JoinKind {
                 JoinKind::LeftOuter => "LeftOuter",
                 JoinKind::RightOuter => "RightOuter",
                 JoinKind::FullOuter => "FullOuter",
+                JoinKind::AsOf => "AsOf",
             }
         )
     }
@@ -1094,7 +1098,7 @@ impl JoinKind {
     pub fn can_be_correlated(&self) -> bool {
         match self {
             JoinKind::Inner | JoinKind::LeftOuter => true,
-            JoinKind::RightOuter | JoinKind::FullOuter => false,
+            JoinKind::RightOuter | JoinKind::FullOuter | JoinKind::AsOf => false,
         }
     }
 }
@@ -3592,6 +3596,37 @@ impl HirScalarExpr {
             })
     }
 
+    /// Attempts to simplify this expression of [`ScalarType::Interval`] to a literal Interval
+    /// and returns it.
+    ///
+    /// Returns `PlanError::ConstantExpressionSimplificationFailed` under the same conditions as
+    /// [`HirScalarExpr::try_into_literal_int64`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if this expression does not have type [`ScalarType::Interval`].
+    pub fn try_into_literal_interval(self) -> Result<Interval, PlanError> {
+        if !self.is_constant() {
+            return Err(PlanError::ConstantExpressionSimplificationFailed(format!(
+                "Expected a constant expression, got {}",
+                self
+            )));
+        }
+        self.clone()
+            .simplify_to_literal_with_result()
+            .and_then(|row| {
+                let datum = row.unpack_first();
+                if datum.is_null() {
+                    Err(PlanError::ConstantExpressionSimplificationFailed(format!(
+                        "Expected an expression that evaluates to a non-null value, got {}",
+                        self
+                    )))
+                } else {
+                    Ok(datum.unwrap_interval())
+                }
+            })
+    }
+
     pub fn contains_parameters(&self) -> bool {
         let mut contains_parameters = false;
         #[allow(deprecated)]
@@ -41,7 +41,10 @@ use std::iter::repeat;
 
 use itertools::Itertools;
 use mz_expr::visit::Visit;
-use mz_expr::{AccessStrategy, AggregateFunc, MirRelationExpr, MirScalarExpr};
+use mz_expr::{
+    AccessStrategy, AggregateFunc, AsOfComparison, AsOfMatch, JoinImplementation, MirRelationExpr,
+    MirScalarExpr,
+};
 use mz_ore::collections::CollectionExt;
 use mz_ore::stack::maybe_grow;
 use mz_repr::*;
@@ -138,6 +141,9 @@ pub struct Config {
     pub enable_new_outer_join_lowering: bool,
     /// Enable outer join lowering implemented in database-issues#7561.
     pub enable_variadic_left_join_lowering: bool,
+    /// Enable the simpler outer join lowering for inputs that are unique on
+    /// the join key, which lets `JoinElimination` remove such joins.
+    pub enable_join_elimination: bool,
 }
 
 impl From<&SystemVars> for Config {
@@ -145,6 +151,7 @@ impl From<&SystemVars> for Config {
         Self {
             enable_new_outer_join_lowering: vars.enable_new_outer_join_lowering(),
             enable_variadic_left_join_lowering: vars.enable_variadic_left_join_lowering(),
+            enable_join_elimination: vars.enable_join_elimination(),
         }
     }
 }
@@ -706,6 +713,20 @@ impl HirRelationExpr {
                             // Remember if `on` had any subqueries.
                             let on_has_subqueries = !on_subquery_types.is_empty();
 
+                            // ASOF joins are lowered to a dedicated join implementation, which
+                            // requires `on` to be free of subqueries.
+                            if kind == JoinKind::AsOf {
+                                if on_has_subqueries {
+                                    bail_unsupported!("subqueries in ASOF JOIN conditions");
+                                }
+                                return lower_asof_join(
+                                    get_left.clone(),
+                                    get_right.clone(),
+                                    on,
+                                    oa,
+                                );
+                            }
+
                             // Attempt an efficient equijoin implementation, in which outer joins are
                             // more efficiently rendered than in general. This can return `None` if
                             // such a plan is not possible, for example if `on` does not describe an
@@ -2031,6 +2052,109 @@ impl AggregateExpr {
     }
 }
 
+/// Lowers an ASOF join between the decorrelated inputs `left` and `right`.
+///
+/// The first `oa` columns of both inputs correspond to an outer context, which `on` references
+/// followed by the remaining columns of `left` and `right`. The conjuncts of `on` must be
+/// equalities between the two inputs, plus exactly one inequality between them that becomes the
+/// match condition of the join.
+fn lower_asof_join(
+    left: MirRelationExpr,
+    right: MirRelationExpr,
+    on: MirScalarExpr,
+    oa: usize,
+) -> Result<MirRelationExpr, PlanError> {
+    let l_type = left.typ();
+    let r_type = right.typ();
+    let la = l_type.column_types.len() - oa;
+    let ra = r_type.column_types.len() - oa;
+
+    let mut on_type = l_type.column_types.clone();
+    on_type.extend(r_type.column_types.iter().skip(oa).cloned());
+    let mut on = vec![on];
+    mz_expr::canonicalize::canonicalize_predicates(&mut on, &on_type);
+
+    // The join's output repeats the outer columns after the columns of `left`.
+    let permutation = (0..(oa + la))
+        .chain((oa + la + oa)..(oa + la + oa + ra))
+        .collect_vec();
+    let mut output_type = l_type.column_types;
+    output_type.extend(r_type.column_types);
+    let is_left = |expr: &MirScalarExpr| expr.support().iter().all(|c| *c < oa + la);
+    let is_right = |expr: &MirScalarExpr| {
+        let support = expr.support();
+        !support.is_empty() && support.iter().all(|c| *c >= oa + la + oa)
+    };
+
+    let mut equivalences = (0..oa)
+        .map(|i| vec![MirScalarExpr::column(i), MirScalarExpr::column(oa + la + i)])
+        .collect_vec();
+    let mut asof_match = None;
+    for mut predicate in on {
+        predicate.permute(&permutation);
+        let (func, expr1, expr2) = match predicate {
+            MirScalarExpr::CallBinary { func, expr1, expr2 } => (func, *expr1, *expr2),
+            _ => sql_bail!("ASOF JOIN conditions must compare the two inputs of the join"),
+        };
+        let (left_expr, right_expr, flipped) = if is_left(&expr1) && is_right(&expr2) {
+            (expr1, expr2, false)
+        } else if is_right(&expr1) && is_left(&expr2) {
+            (expr2, expr1, true)
+        } else {
+            sql_bail!("ASOF JOIN conditions must compare the two inputs of the join")
+        };
+        let comparison = match func {
+            mz_expr::BinaryFunc::Eq => {
+                equivalences.push(vec![left_expr, right_expr]);
+                continue;
+            }
+            mz_expr::BinaryFunc::Lt => AsOfComparison::Lt,
+            mz_expr::BinaryFunc::Lte => AsOfComparison::Lte,
+            mz_expr::BinaryFunc::Gt => AsOfComparison::Gt,
+            mz_expr::BinaryFunc::Gte => AsOfComparison::Gte,
+            _ => sql_bail!("ASOF JOIN conditions must be equalities or inequalities"),
+        };
+        if asof_match.is_some() {
+            sql_bail!("ASOF JOIN conditions must contain exactly one inequality");
+        }
+        asof_match = Some(AsOfMatch {
+            left_time: left_expr,
+            right_time: right_expr,
+            comparison: if flipped {
+                comparison.flip()
+            } else {
+                comparison
+            },
+        });
+    }
+    let asof_match = match asof_match {
+        Some(asof_match) => asof_match,
+        None => sql_bail!("ASOF JOIN conditions must contain exactly one inequality"),
+    };
+
+    // Join equivalences consider nulls equal, unlike SQL equality, so rows with null keys must
+    // be removed from the inputs. This cannot change the closest match of any other row.
+    let mut left_filters = Vec::new();
+    let mut right_filters = Vec::new();
+    for class in &equivalences[oa..] {
+        if class[0].typ(&output_type).nullable {
+            left_filters.push(class[0].clone().call_is_null().not());
+        }
+        if class[1].typ(&output_type).nullable {
+            let mut expr = class[1].clone();
+            expr.visit_columns(|c| *c -= oa + la);
+            right_filters.push(expr.call_is_null().not());
+        }
+    }
+
+    Ok(MirRelationExpr::Join {
+        inputs: vec![left.filter(left_filters), right.filter(right_filters)],
+        equivalences,
+        implementation: JoinImplementation::AsOf(asof_match),
+    }
+    .project(permutation))
+}
+
 /// Attempts an efficient outer join, if `on` has equijoin structure.
 ///
 /// Both `left` and `right` are decorrelated inputs.
@@ -2080,6 +2204,7 @@ fn attempt_outer_equijoin(
     output_type.extend(l_type.column_types);
     output_type.extend(r_type.column_types.into_iter().skip(oa));
     output_type.extend(on_subquery_types);
+    let (l_keys, r_keys) = (l_type.keys, r_type.keys);
 
     // Generally healthy to do, but specifically `USING` conditions sometimes
     // put an `AND true` at the end of the `ON` condition.
@@ -2101,6 +2226,21 @@ fn attempt_outer_equijoin(
         return Ok(None);
     }
 
+    // Whether each row of one side matches at most one row of the other side,
+    // because the other side is unique on the columns it is equated on.
+    let unique_on = |keys: &[Vec<usize>], eq: Vec<MirScalarExpr>| {
+        let columns = eq
+            .iter()
+            .filter_map(|e| e.as_column())
+            .collect::<BTreeSet<_>>();
+        context.config.enable_join_elimination
+            && keys
+                .iter()
+                .any(|key| key.iter().all(|c| columns.contains(c)))
+    };
+    let right_unique = unique_on(&r_keys, on_predicates.eq_rhs().collect());
+    let left_unique = unique_on(&l_keys, on_predicates.eq_lhs().collect());
+
     // If we've gotten this far, we can do the clever thing.
     // We'll want to use left and right multiple times
     let result = left.let_in(id_gen, |id_gen, get_left| {
@@ -2139,24 +2279,33 @@ fn attempt_outer_equijoin(
 
                 both_keys.let_in(id_gen, |_id_gen, get_both| {
                     if let JoinKind::LeftOuter { .. } | JoinKind::FullOuter = kind {
-                        // Rows in `left` matched in the inner equijoin. This is
-                        // a semi-join between `left` and `both_keys`.
-                        let left_present = MirRelationExpr::join_scalars(
-                            vec![
-                                get_left
-                                    .clone()
-                                    // Push local predicates.
-                                    .filter(on_predicates.lhs()),
-                                get_both.clone(),
-                            ],
-                            itertools::zip_eq(
-                                on_predicates.eq_lhs(),
-                                (0..both_keys_arity).map(|k| MirScalarExpr::column(oa + la + k)),
+                        // Rows in `left` matched in the inner equijoin. If each
+                        // `left` row matches at most one `right` row, these are
+                        // just the `left` columns of the join, which lets
+                        // `JoinElimination` cancel the join if the `right`
+                        // columns are not used. Otherwise, this is a semi-join
+                        // between `left` and `both_keys`.
+                        let left_present = if right_unique {
+                            get_join.clone().project((0..(oa + la)).collect())
+                        } else {
+                            MirRelationExpr::join_scalars(
+                                vec![
+                                    get_left
+                                        .clone()
+                                        // Push local predicates.
+                                        .filter(on_predicates.lhs()),
+                                    get_both.clone(),
+                                ],
+                                itertools::zip_eq(
+                                    on_predicates.eq_lhs(),
+                                    (0..both_keys_arity)
+                                        .map(|k| MirScalarExpr::column(oa + la + k)),
+                                )
+                                .map(|(l_key, b_key)| [l_key, b_key].to_vec())
+                                .collect(),
                             )
-                            .map(|(l_key, b_key)| [l_key, b_key].to_vec())
-                            .collect(),
-                        )
-                        .project((0..(oa + la)).collect());
+                            .project((0..(oa + la)).collect())
+                        };
 
                         // Determine the types of nulls to use as filler.
                         let right_fill = rt
@@ -2173,24 +2322,33 @@ fn attempt_outer_equijoin(
                     }
 
                     if let JoinKind::RightOuter | JoinKind::FullOuter = kind {
-                        // Rows in `right` matched in the inner equijoin. This
-                        // is a semi-join between `right` and `both_keys`.
-                        let right_present = MirRelationExpr::join_scalars(
-                            vec![
-                                get_right
-                                    .clone()
-                                    // Push local predicates.
-                                    .filter(on_predicates.rhs()),
-                                get_both,
-                            ],
-                            itertools::zip_eq(
-                                on_predicates.eq_rhs(),
-                                (0..both_keys_arity).map(|k| MirScalarExpr::column(oa + ra + k)),
+                        // Rows in `right` matched in the inner equijoin. As
+                        // above, these are the `right` columns of the join if
+                        // each `right` row matches at most one `left` row, and
+                        // a semi-join between `right` and `both_keys` otherwise.
+                        let right_present = if left_unique {
+                            get_join
+                                .clone()
+                                .project((0..oa).chain((oa + la)..(oa + la + ra)).collect())
+                        } else {
+                            MirRelationExpr::join_scalars(
+                                vec![
+                                    get_right
+                                        .clone()
+                                        // Push local predicates.
+                                        .filter(on_predicates.rhs()),
+                                    get_both,
+                                ],
+                                itertools::zip_eq(
+                                    on_predicates.eq_rhs(),
+                                    (0..both_keys_arity)
+                                        .map(|k| MirScalarExpr::column(oa + ra + k)),
+                                )
+                                .map(|(r_key, b_key)| [r_key, b_key].to_vec())
+                                .collect(),
                             )
-                            .map(|(r_key, b_key)| [r_key, b_key].to_vec())
-                            .collect(),
-                        )
-                        .project((0..(oa + ra)).collect());
+                            .project((0..(oa + ra)).collect())
+                        };
 
                         // Determine the types of nulls to use as filler.
                         let left_fill = lt
@@ -58,9 +58,11 @@ use mz_ore::option::FallibleMapExt;
 use mz_ore::stack::{CheckedRecursion, RecursionGuard};
 use mz_ore::str::StrExt;
 use mz_repr::adt::char::CharLength;
+use mz_repr::adt::interval::Interval;
 use mz_repr::adt::numeric::{NUMERIC_DATUM_MAX_PRECISION, NumericMaxScale};
 use mz_repr::adt::timestamp::TimestampPrecision;
 use mz_repr::adt::varchar::VarCharMaxLength;
+use mz_repr::adt::vector::VectorDimensions;
 use mz_repr::{
     CatalogItemId, ColumnIndex, ColumnName, ColumnType, Datum, RelationDesc, RelationType,
     RelationVersionSelector, Row, RowArena, ScalarType, strconv,
@@ -69,21 +71,25 @@ use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::visit::Visit;
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
-    AsOf, Assignment, AstInfo, CreateWebhookSourceBody, CreateWebhookSourceCheck,
-    CreateWebhookSourceHeader, CreateWebhookSourceSecret, CteBlock, DeleteStatement, Distinct,
-    Expr, Function, FunctionArgs, HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join,
-    JoinConstraint, JoinOperator, Limit, MapEntry, MutRecBlock, MutRecBlockOption,
-    MutRecBlockOptionName, OrderByExpr, Query, Select, SelectItem, SelectOption, SelectOptionName,
-    SetExpr, SetOperator, ShowStatement, SubscriptPosition, TableAlias, TableFactor,
-    TableWithJoins, UnresolvedItemName, UpdateStatement, Value, Values, WindowFrame,
-    WindowFrameBound, WindowFrameUnits, WindowSpec, visit,
+    AsOf, Assignment, AstInfo, CreateFunctionStatement, CreateWebhookSourceBody,
+    CreateWebhookSourceCheck, CreateWebhookSourceHeader, CreateWebhookSourceSecret, CteBlock,
+    DeleteStatement, Distinct, Expr, Function, FunctionArgs, FunctionBody, HomogenizingFunction,
+    Ident, InsertSource, IsExprConstruct, Join, JoinConstraint, JoinOperator, Limit, MapEntry,
+    MutRecBlock, MutRecBlockOption, MutRecBlockOptionName, OrderByExpr, Query, Select, SelectItem,
+    SelectOption, SelectOptionName, SetExpr, SetOperator, ShowStatement, Statement,
+    SubscriptPosition, TableAlias, TableFactor, TableWithJoins, TimeWindowKind, UnresolvedItemName,
+    UpdateStatement, Value, Values, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
+    visit,
 };
 use mz_sql_parser::ident;
 
-use crate::catalog::{CatalogItemType, CatalogType, SessionCatalog};
+use crate::catalog::{
+    CatalogFuncDetails, CatalogFuncReturns, CatalogItem, CatalogItemType, CatalogType,
+    CatalogTypeDetails, SessionCatalog,
+};
 use crate::func::{self, Func, FuncSpec};
 use crate::names::{
-    Aug, FullItemName, PartialItemName, ResolvedDataType, ResolvedItemName, SchemaSpecifier,
+    self, Aug, FullItemName, PartialItemName, ResolvedDataType, ResolvedItemName, SchemaSpecifier,
 };
 use crate::normalize;
 use crate::plan::PlanError::InvalidWmrRecursionLimit;
@@ -92,7 +98,8 @@ use crate::plan::hir::{
     AbstractColumnType, AbstractExpr, AggregateExpr, AggregateFunc, AggregateWindowExpr,
     BinaryFunc, CoercibleScalarExpr, CoercibleScalarType, ColumnOrder, ColumnRef, Hir,
     HirRelationExpr, HirScalarExpr, JoinKind, ScalarWindowExpr, ScalarWindowFunc, UnaryFunc,
-    ValueWindowExpr, ValueWindowFunc, VariadicFunc, WindowExpr, WindowExprType,
+    UnmaterializableFunc, ValueWindowExpr, ValueWindowFunc, VariadicFunc, WindowExpr,
+    WindowExprType,
 };
 use crate::plan::plan_utils::{self, GroupSizeHints, JoinSide};
 use crate::plan::scope::{Scope, ScopeItem, ScopeUngroupedColumn};
@@ -1298,6 +1305,136 @@ pub fn plan_default_expr(
     Ok(hir)
 }
 
+/// Plans the `CHECK` constraint of a domain whose base type is `base_type`.
+///
+/// The constraint refers to the value being checked via the keyword `VALUE`,
+/// which is planned as a reference to column 0 of the current scope.
+pub fn plan_domain_check(
+    scx: &StatementContext,
+    check: &Expr<Aug>,
+    base_type: &ScalarType,
+) -> Result<HirScalarExpr, PlanError> {
+    let mut check = check.clone();
+    transform_ast::transform(scx, &mut check)?;
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "CHECK constraint",
+        scope: &Scope::from_source(None, [ColumnName::from("value")]),
+        relation_type: &RelationType::new(vec![base_type.clone().nullable(true)]),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    let hir = plan_expr(ecx, &check)?.type_as(ecx, &ScalarType::Bool)?;
+    Ok(hir)
+}
+
+/// Plans the `WHEN` predicate of a notification on a relation described by
+/// `desc`.
+///
+/// The predicate may refer to the relation's columns, but not to other
+/// relations.
+pub fn plan_notification_predicate(
+    scx: &StatementContext,
+    predicate: &Expr<Aug>,
+    desc: &RelationDesc,
+) -> Result<HirScalarExpr, PlanError> {
+    let mut predicate = predicate.clone();
+    transform_ast::transform(scx, &mut predicate)?;
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "WHEN clause",
+        scope: &Scope::from_source(None, desc.iter_names()),
+        relation_type: desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    let hir = plan_expr(ecx, &predicate)?.type_as(ecx, &ScalarType::Bool)?;
+    Ok(hir)
+}
+
+/// Wraps `expr`, which has already been cast to the base type of the domain
+/// `id`, in checks that enforce the domain's constraints. Returns `expr`
+/// unchanged if `id` does not refer to a domain.
+fn plan_domain_constraints(
+    ecx: &ExprContext,
+    id: CatalogItemId,
+    mut expr: HirScalarExpr,
+) -> Result<HirScalarExpr, PlanError> {
+    let scx = ecx.qcx.scx;
+    let item = scx.catalog.get_item(&id);
+    let Some(CatalogTypeDetails {
+        typ:
+            CatalogType::Domain {
+                base_reference,
+                base_modifiers,
+                not_null,
+                check,
+            },
+        ..
+    }) = item.type_details()
+    else {
+        return Ok(expr);
+    };
+    let name = scx.catalog.resolve_full_name(item.name()).to_string();
+    let base_type = scalar_type_from_catalog(scx.catalog, *base_reference, base_modifiers)?;
+
+    if let Some(check) = check {
+        let mut check = plan_domain_check(scx, check, &base_type)?;
+        // Substitute the value being cast for `VALUE`. The constraint cannot
+        // contain subqueries, so `VALUE` is the only column it references.
+        #[allow(deprecated)]
+        let _ = check.visit_recursively_mut(0, &mut |_depth, e: &mut HirScalarExpr| {
+            if let HirScalarExpr::Column(
+                ColumnRef {
+                    level: 0,
+                    column: 0,
+                },
+                _name,
+            ) = e
+            {
+                *e = expr.clone();
+            }
+            Ok::<_, ()>(())
+        });
+        // As in PostgreSQL, a constraint that evaluates to `NULL` is satisfied.
+        let satisfied = HirScalarExpr::call_variadic(
+            VariadicFunc::Coalesce,
+            vec![check, HirScalarExpr::literal_true()],
+        );
+        let msg = format!(
+            "value for domain {} violates check constraint",
+            name.quoted()
+        );
+        let violation = HirScalarExpr::call_variadic(
+            VariadicFunc::ErrorIfNull,
+            vec![
+                HirScalarExpr::literal_null(base_type.clone()),
+                HirScalarExpr::literal(Datum::String(&msg), ScalarType::String),
+            ],
+        );
+        expr = HirScalarExpr::if_then_else(satisfied, expr, violation);
+    }
+
+    if *not_null {
+        let msg = format!("domain {} does not allow null values", name.quoted());
+        expr = HirScalarExpr::call_variadic(
+            VariadicFunc::ErrorIfNull,
+            vec![
+                expr,
+                HirScalarExpr::literal(Datum::String(&msg), ScalarType::String),
+            ],
+        );
+    }
+
+    Ok(expr)
+}
+
 pub fn plan_params<'a>(
     scx: &'a StatementContext,
     params: Vec<Expr<Aug>>,
@@ -2032,6 +2169,10 @@ fn plan_set_expr(
                     show::plan_show_create_connection(qcx.scx, stmt.clone())?,
                     show::describe_show_create_connection(qcx.scx, stmt)?,
                 ),
+                ShowStatement::ShowCreateFunction(stmt) => to_hirscope(
+                    show::plan_show_create_function(qcx.scx, stmt.clone())?,
+                    show::describe_show_create_function(qcx.scx, stmt)?,
+                ),
                 ShowStatement::ShowCreateCluster(stmt) => to_hirscope(
                     show::plan_show_create_cluster(qcx.scx, stmt.clone())?,
                     show::describe_show_create_cluster(qcx.scx, stmt)?,
@@ -3012,9 +3153,147 @@ fn plan_table_factor(
             let scope = plan_table_alias(scope, alias.as_ref())?;
             Ok((expr, scope))
         }
+
+        TableFactor::TimeWindow {
+            kind,
+            relation,
+            time_column,
+            size,
+            slide,
+            alias,
+        } => {
+            let (expr, scope) =
+                plan_time_window(qcx, *kind, relation, time_column, size, slide.as_ref())?;
+            let scope = plan_table_alias(scope, alias.as_ref())?;
+            Ok((expr, scope))
+        }
     }
 }
 
+/// Plans a `TUMBLE` or `HOP` windowing table function.
+///
+/// The result contains the columns of `relation` followed by a `window_start`
+/// and a `window_end` column, with one row for each window that contains the
+/// row's timestamp. Windows are assigned by a `FlatMap` over a dedicated table
+/// function, and each row is retracted once its window closes through a
+/// temporal filter, so that aggregations over the windows maintain state only
+/// for open windows.
+///
+/// There is no dedicated relation operator for windowing: the filter fuses into
+/// the `FlatMap`'s MFP, which renders as a single operator that emits each row
+/// into its windows and schedules the retractions through the temporal MFP
+/// machinery of `MfpPlan`.
+fn plan_time_window(
+    qcx: &QueryContext,
+    kind: TimeWindowKind,
+    relation: &ResolvedItemName,
+    time_column: &Ident,
+    size: &Expr<Aug>,
+    slide: Option<&Expr<Aug>>,
+) -> Result<(HirRelationExpr, Scope), PlanError> {
+    qcx.scx
+        .require_feature_flag(&vars::ENABLE_TIME_WINDOW_FUNCTIONS)?;
+
+    let (expr, mut scope) = qcx.resolve_table_name(relation.clone())?;
+    let (time_column, _) = scope.resolve_column(
+        &[],
+        &normalize::column_name(time_column.clone()),
+        &mut qcx.name_manager.borrow_mut(),
+    )?;
+    let typ = expr.typ(&qcx.outer_relation_types, &qcx.scx.param_types.borrow());
+    let tz = match &typ.column_types[time_column.column].scalar_type {
+        ScalarType::Timestamp { .. } => false,
+        ScalarType::TimestampTz { .. } => true,
+        other => sql_bail!(
+            "{} time column must have type timestamp or timestamp with time zone, not type {}",
+            kind,
+            qcx.humanize_scalar_type(other, false),
+        ),
+    };
+
+    let plan_interval = |name: &str, expr: &Expr<Aug>| -> Result<Interval, PlanError> {
+        let ecx = &ExprContext {
+            qcx,
+            name: &format!("{} {}", kind, name),
+            scope: &Scope::empty(),
+            relation_type: &RelationType::empty(),
+            allow_aggregates: false,
+            allow_subqueries: false,
+            allow_parameters: false,
+            allow_windows: false,
+        };
+        let interval = plan_expr(ecx, expr)?
+            .type_as(ecx, &ScalarType::Interval)?
+            .try_into_literal_interval()?;
+        // Months have no fixed length, so windows could not be aligned to the
+        // epoch.
+        if interval.months != 0 {
+            sql_bail!("{} {} must not contain months or years", kind, name);
+        }
+        if interval.as_microseconds() <= 0 {
+            sql_bail!("{} {} must be positive", kind, name);
+        }
+        // Evaluation computes window bounds in microseconds since the epoch,
+        // so reject intervals that would fail every row there.
+        if i64::try_from(interval.as_microseconds()).is_err() {
+            sql_bail!("{} {} is out of range", kind, name);
+        }
+        Ok(interval)
+    };
+    let size = plan_interval("window size", size)?;
+    let func = match (kind, slide) {
+        (TimeWindowKind::Tumble, _) => mz_expr::TableFunc::TumbleWindow { size, tz },
+        (TimeWindowKind::Hop, Some(slide)) => {
+            let slide = plan_interval("window slide", slide)?;
+            let windows = mz_expr::TableFunc::hop_windows_per_row(size, slide);
+            let max_windows = mz_expr::TableFunc::MAX_HOP_WINDOWS_PER_ROW;
+            if windows > max_windows {
+                sql_bail!(
+                    "HOP would assign each row to {} windows, which exceeds the limit of {}; \
+                     use a larger slide or a smaller window size",
+                    windows,
+                    max_windows,
+                );
+            }
+            mz_expr::TableFunc::HopWindow { size, slide, tz }
+        }
+        (TimeWindowKind::Hop, None) => sql_bail!("HOP requires a slide"),
+    };
+
+    // The table function is correlated with the relation, which is one level
+    // out from the right-hand side of the join.
+    let windows = HirRelationExpr::CallTable {
+        func,
+        exprs: vec![HirScalarExpr::unnamed_column(ColumnRef {
+            level: 1,
+            column: time_column.column,
+        })],
+    };
+    let arity = scope.len();
+    let to_mz_timestamp = if tz {
+        UnaryFunc::CastTimestampTzToMzTimestamp(expr_func::CastTimestampTzToMzTimestamp)
+    } else {
+        UnaryFunc::CastTimestampToMzTimestamp(expr_func::CastTimestampToMzTimestamp)
+    };
+    let open = HirScalarExpr::call_unmaterializable(UnmaterializableFunc::MzNow).call_binary(
+        HirScalarExpr::column(arity + 1).call_unary(to_mz_timestamp),
+        BinaryFunc::Lt,
+    );
+    let expr = expr
+        .join(windows, HirScalarExpr::literal_true(), JoinKind::Inner)
+        .filter(vec![open]);
+
+    // Qualify the window columns with the name of the relation, so that
+    // `t.window_start` resolves like any other column of `t`.
+    let table_name = scope.items[time_column.column].table_name.clone();
+    for column_name in ["window_start", "window_end"] {
+        scope
+            .items
+            .push(ScopeItem::from_name(table_name.clone(), column_name));
+    }
+    Ok((expr, scope))
+}
+
 /// Plans a `ROWS FROM` expression.
 ///
 /// `ROWS FROM` concatenates table functions into a single table, filling in
@@ -3293,40 +3572,35 @@ fn plan_table_function_internal(
         item: table_name,
     });
 
-    let (mut expr, mut scope) = match resolve_func(ecx, name, args)? {
-        Func::Table(impls) => {
-            let tf = func::select_impl(ecx, FuncSpec::Func(name), impls, scalar_args, vec![])?;
-            let scope = Scope::from_source(scope_name.clone(), tf.column_names);
-            (tf.expr, scope)
+    let (mut expr, mut scope) = if let Some((details, create_sql)) =
+        resolve_user_func(qcx.scx, name)
+    {
+        match plan_user_func_call(ecx, name, &details, &create_sql, scalar_args)? {
+            UserFuncBody::Table { expr, column_names } => {
+                let scope = Scope::from_source(scope_name.clone(), column_names);
+                (expr, scope)
+            }
+            UserFuncBody::Scalar(expr) => {
+                plan_tabletized_scalar(qcx, name, scope_name.clone(), expr)?
+            }
         }
-        Func::Scalar(impls) => {
-            let expr = func::select_impl(ecx, FuncSpec::Func(name), impls, scalar_args, vec![])?;
-            let output = expr.typ(
-                &qcx.outer_relation_types,
-                &RelationType::new(vec![]),
-                &qcx.scx.param_types.borrow(),
-            );
-
-            let relation = RelationType::new(vec![output]);
-
-            let function_ident = Ident::new(name.full_item_name().item.clone())?;
-            let column_name = normalize::column_name(function_ident);
-            let name = column_name.to_string();
-
-            let scope = Scope::from_source(scope_name.clone(), vec![column_name]);
-
-            (
-                HirRelationExpr::CallTable {
-                    func: mz_expr::TableFunc::TabletizedScalar { relation, name },
-                    exprs: vec![expr],
-                },
-                scope,
-            )
+    } else {
+        match resolve_func(ecx, name, args)? {
+            Func::Table(impls) => {
+                let tf = func::select_impl(ecx, FuncSpec::Func(name), impls, scalar_args, vec![])?;
+                let scope = Scope::from_source(scope_name.clone(), tf.column_names);
+                (tf.expr, scope)
+            }
+            Func::Scalar(impls) => {
+                let expr =
+                    func::select_impl(ecx, FuncSpec::Func(name), impls, scalar_args, vec![])?;
+                plan_tabletized_scalar(qcx, name, scope_name.clone(), expr)?
+            }
+            o => sql_bail!(
+                "{} functions are not supported in functions in FROM",
+                o.class()
+            ),
         }
-        o => sql_bail!(
-            "{} functions are not supported in functions in FROM",
-            o.class()
-        ),
     };
 
     if with_ordinality {
@@ -3346,6 +3620,37 @@ fn plan_table_function_internal(
     Ok((expr, scope))
 }
 
+/// Wraps a call to a scalar function in `FROM` into a table function that
+/// returns a single row with the result of the call.
+fn plan_tabletized_scalar(
+    qcx: &QueryContext,
+    name: &ResolvedItemName,
+    scope_name: Option<PartialItemName>,
+    expr: HirScalarExpr,
+) -> Result<(HirRelationExpr, Scope), PlanError> {
+    let output = expr.typ(
+        &qcx.outer_relation_types,
+        &RelationType::new(vec![]),
+        &qcx.scx.param_types.borrow(),
+    );
+
+    let relation = RelationType::new(vec![output]);
+
+    let function_ident = Ident::new(name.full_item_name().item.clone())?;
+    let column_name = normalize::column_name(function_ident);
+    let name = column_name.to_string();
+
+    let scope = Scope::from_source(scope_name, vec![column_name]);
+
+    Ok((
+        HirRelationExpr::CallTable {
+            func: mz_expr::TableFunc::TabletizedScalar { relation, name },
+            exprs: vec![expr],
+        },
+        scope,
+    ))
+}
+
 fn plan_table_alias(mut scope: Scope, alias: Option<&TableAlias>) -> Result<Scope, PlanError> {
     if let Some(TableAlias {
         name,
@@ -3664,6 +3969,9 @@ fn plan_join(
         JoinOperator::LeftOuter(constraint) => (JoinKind::LeftOuter, constraint),
         JoinOperator::RightOuter(constraint) => (JoinKind::RightOuter, constraint),
         JoinOperator::FullOuter(constraint) => (JoinKind::FullOuter, constraint),
+        JoinOperator::AsOf { constraint, .. } => {
+            (JoinKind::AsOf, constraint.as_ref().unwrap_or(&ON_TRUE))
+        }
     };
 
     let mut right_qcx = left_qcx.derived_context(left_scope.clone(), left_qcx.relation_type(&left));
@@ -3682,6 +3990,35 @@ fn plan_join(
     }
     let (right, right_scope) = plan_table_factor(&right_qcx, &join.relation)?;
 
+    // The match condition of an ASOF join is planned against both inputs, and conjoined with the
+    // join constraint. Lowering later separates it back out from the equalities.
+    let match_condition = match &join.join_operator {
+        JoinOperator::AsOf {
+            match_condition, ..
+        } => {
+            let product_scope = left_scope.clone().product(right_scope.clone())?;
+            let ecx = &ExprContext {
+                qcx: left_qcx,
+                name: "MATCH_CONDITION clause",
+                scope: &product_scope,
+                relation_type: &RelationType::new(
+                    left_qcx
+                        .relation_type(&left)
+                        .column_types
+                        .into_iter()
+                        .chain(right_qcx.relation_type(&right).column_types)
+                        .collect(),
+                ),
+                allow_aggregates: false,
+                allow_subqueries: false,
+                allow_parameters: true,
+                allow_windows: false,
+            };
+            Some(plan_expr(ecx, match_condition)?.type_as(ecx, &ScalarType::Bool)?)
+        }
+        _ => None,
+    };
+
     let (expr, scope) = match constraint {
         JoinConstraint::On(expr) => {
             let product_scope = left_scope.product(right_scope)?;
@@ -3703,6 +4040,10 @@ fn plan_join(
                 allow_windows: false,
             };
             let on = plan_expr(ecx, expr)?.type_as(ecx, &ScalarType::Bool)?;
+            let on = match match_condition {
+                Some(match_condition) => match_condition.and(on),
+                None => on,
+            };
             let joined = left.join(right, on, kind);
             (joined, product_scope)
         }
@@ -3722,9 +4063,13 @@ fn plan_join(
                 right_scope,
                 kind,
                 alias.as_ref(),
+                match_condition,
             )?
         }
         JoinConstraint::Natural => {
+            if kind == JoinKind::AsOf {
+                bail_unsupported!("NATURAL ASOF JOIN");
+            }
             // We shouldn't need to set ambiguous_columns on both the right and left qcx since they
             // have the same scx. However, it doesn't hurt to be safe.
             *left_qcx.scx.ambiguous_columns.borrow_mut() = true;
@@ -3745,6 +4090,7 @@ fn plan_join(
                 right_scope,
                 kind,
                 None,
+                None,
             )?
         }
     };
@@ -3763,6 +4109,7 @@ fn plan_using_constraint(
     right_scope: Scope,
     kind: JoinKind,
     alias: Option<&Ident>,
+    match_condition: Option<HirScalarExpr>,
 ) -> Result<(HirRelationExpr, Scope), PlanError> {
     let mut both_scope = left_scope.clone().product(right_scope.clone())?;
 
@@ -3859,7 +4206,7 @@ fn plan_using_constraint(
         let (expr1, expr2) = (exprs.remove(0), exprs.remove(0));
 
         match kind {
-            JoinKind::LeftOuter { .. } | JoinKind::Inner { .. } => {
+            JoinKind::LeftOuter { .. } | JoinKind::Inner { .. } | JoinKind::AsOf => {
                 join_cols.push(lhs.column);
                 hidden_cols.push(rhs.column);
             }
@@ -3921,7 +4268,7 @@ fn plan_using_constraint(
 
     both_scope = both_scope.project(&project_key);
 
-    let on = HirScalarExpr::variadic_and(join_exprs);
+    let on = HirScalarExpr::variadic_and(match_condition.into_iter().chain(join_exprs).collect());
 
     let both = left
         .join(right, on, kind)
@@ -4086,7 +4433,10 @@ fn plan_cast(
     };
     let ecx = &ecx.with_name("CAST");
     let expr = typeconv::plan_coerce(ecx, expr, &to_scalar_type)?;
-    let expr = typeconv::plan_cast(ecx, CastContext::Explicit, expr, &to_scalar_type)?;
+    let mut expr = typeconv::plan_cast(ecx, CastContext::Explicit, expr, &to_scalar_type)?;
+    if let ResolvedDataType::Named { id, .. } = data_type {
+        expr = plan_domain_constraints(ecx, *id, expr)?;
+    }
     Ok(expr.into())
 }
 
@@ -5186,6 +5536,13 @@ fn plan_identifier(ecx: &ExprContext, names: &[Ident]) -> Result<HirScalarExpr,
         Err(e) => return Err(e),
     };
 
+    // If we are planning the body of a user-defined function, check if the
+    // name refers to a named parameter. As in PostgreSQL, column names take
+    // precedence over parameter names.
+    if let Some(n) = ecx.qcx.scx.param_names.get(&col_name) {
+        return Ok(HirScalarExpr::parameter(*n));
+    }
+
     // The name doesn't refer to a column. Check if it is a whole-row reference
     // to a table.
     let items = ecx.scope.items_from_table(
@@ -5270,11 +5627,28 @@ fn plan_function<'a>(
     f @ Function {
         name,
         args,
-        filter,
+        filter: _,
         over,
-        distinct,
+        distinct: _,
     }: &'a Function<Aug>,
 ) -> Result<HirScalarExpr, PlanError> {
+    if let Some((details, create_sql)) = resolve_user_func(ecx.qcx.scx, name) {
+        if over.is_some() {
+            sql_bail!(
+                "OVER clause not allowed on {name}. The OVER clause can only be used with window functions (including aggregations)."
+            );
+        }
+        let scalar_args = plan_non_aggregate_args(ecx, f)?;
+        return match plan_user_func_call(ecx, name, &details, &create_sql, scalar_args)? {
+            UserFuncBody::Scalar(expr) => Ok(expr),
+            UserFuncBody::Table { .. } => sql_bail!(
+                "table functions are not allowed in {} (function {})",
+                ecx.name,
+                name
+            ),
+        };
+    }
+
     let impls = match resolve_func(ecx, name, args)? {
         Func::Table(_) => {
             sql_bail!(
@@ -5440,6 +5814,22 @@ fn plan_function<'a>(
         unreachable!("If there is an OVER clause, we should have returned already above.");
     }
 
+    let scalar_args = plan_non_aggregate_args(ecx, f)?;
+    func::select_impl(ecx, FuncSpec::Func(name), impls, scalar_args, vec![])
+}
+
+/// Plans the arguments to a call to a function that is not an aggregate
+/// function, rejecting any aggregate-only syntax.
+fn plan_non_aggregate_args(
+    ecx: &ExprContext,
+    Function {
+        name,
+        args,
+        filter,
+        over: _,
+        distinct,
+    }: &Function<Aug>,
+) -> Result<Vec<CoercibleScalarExpr>, PlanError> {
     if *distinct {
         sql_bail!(
             "DISTINCT specified, but {} is not an aggregate function",
@@ -5459,7 +5849,7 @@ fn plan_function<'a>(
         );
     }
 
-    let scalar_args = match &args {
+    match args {
         FunctionArgs::Star => {
             sql_bail!(
                 "* argument is invalid with non-aggregate function {}",
@@ -5479,11 +5869,9 @@ fn plan_function<'a>(
                         .expect("already resolved")
                 );
             }
-            plan_exprs(ecx, args)?
+            plan_exprs(ecx, args)
         }
-    };
-
-    func::select_impl(ecx, FuncSpec::Func(name), impls, scalar_args, vec![])
+    }
 }
 
 pub const IGNORE_NULLS_ERROR_MSG: &str =
@@ -5518,18 +5906,352 @@ pub fn resolve_func(
         }
     };
 
+    Err(unknown_function(ecx, name, &cexprs))
+}
+
+/// Constructs the error for a call to `name` with `cexprs` that does not match
+/// any known function.
+fn unknown_function(
+    ecx: &ExprContext,
+    name: &ResolvedItemName,
+    cexprs: &[CoercibleScalarExpr],
+) -> PlanError {
     let arg_types: Vec<_> = cexprs
-        .into_iter()
-        .map(|ty| match ecx.scalar_type(&ty) {
+        .iter()
+        .map(|ty| match ecx.scalar_type(ty) {
             CoercibleScalarType::Coerced(ty) => ecx.humanize_scalar_type(&ty, false),
             CoercibleScalarType::Record(_) => "record".to_string(),
             CoercibleScalarType::Uncoerced => "unknown".to_string(),
         })
         .collect();
 
-    Err(PlanError::UnknownFunction {
+    PlanError::UnknownFunction {
         name: name.to_string(),
         arg_types,
+    }
+}
+
+/// The planned body of a user-defined SQL function.
+///
+/// The body refers to the function's arguments via parameters (`$1`, `$2`,
+/// ...), which callers are expected to splice away.
+#[derive(Debug)]
+pub enum UserFuncBody {
+    /// The body of a function that returns a single value.
+    Scalar(HirScalarExpr),
+    /// The body of a set-returning function.
+    Table {
+        expr: HirRelationExpr,
+        column_names: Vec<ColumnName>,
+    },
+}
+
+/// Looks up the definition of the user-defined SQL function named by `name`.
+///
+/// Returns `None` if `name` does not refer to a user-defined function.
+fn resolve_user_func(
+    scx: &StatementContext,
+    name: &ResolvedItemName,
+) -> Option<(CatalogFuncDetails, String)> {
+    let item = scx.get_item_by_resolved_name(name).ok()?;
+    let details = item.func_details()?.clone();
+    Some((details, item.create_sql().to_string()))
+}
+
+/// Reports whether `item` is a table function, either built-in or
+/// user-defined.
+fn is_table_func<I: CatalogItem + ?Sized>(item: &I) -> bool {
+    matches!(item.func(), Ok(Func::Table { .. }))
+        || matches!(
+            item.func_details(),
+            Some(CatalogFuncDetails {
+                returns: CatalogFuncReturns::Table(_),
+                ..
+            })
+        )
+}
+
+/// Plans the body of a user-defined WebAssembly function, whose module is
+/// given in `bytea` text format.
+///
+/// The module is decoded but not validated; see [`expr_func::WasmUdf::validate`].
+pub fn plan_wasm_udf(
+    scx: &StatementContext,
+    name: String,
+    details: &CatalogFuncDetails,
+    module: &str,
+) -> Result<expr_func::WasmUdf, PlanError> {
+    let CatalogFuncReturns::Scalar(return_type) = &details.returns else {
+        bail_unsupported!("set-returning WebAssembly functions");
+    };
+    for typ in details.params.iter().map(|p| &p.typ).chain([return_type]) {
+        if !expr_func::WasmUdf::supports_type(typ) {
+            bail_unsupported!(format!(
+                "type {} in WebAssembly functions",
+                scx.humanize_scalar_type(typ, false)
+            ));
+        }
+    }
+    let module =
+        strconv::parse_bytes(module).map_err(|e| sql_err!("invalid WebAssembly module: {}", e))?;
+    Ok(expr_func::WasmUdf::new(
+        name,
+        module.into(),
+        return_type.clone(),
+    ))
+}
+
+/// Plans the body of a user-defined SQL function, with the function's
+/// parameters bound to their declared types.
+///
+/// The result is cast to the declared return type of the function.
+pub fn plan_user_func_body(
+    qcx: &QueryContext,
+    details: &CatalogFuncDetails,
+    body: &Query<Aug>,
+) -> Result<UserFuncBody, PlanError> {
+    // Reconstruct a statement context where the parameter types and names are
+    // bound to those of the function. The body cannot refer to anything in
+    // the calling query.
+    let mut scx = qcx.scx.clone();
+    scx.param_types = RefCell::new(
+        details
+            .params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| (i + 1, param.typ.clone()))
+            .collect(),
+    );
+    scx.param_names = details
+        .params
+        .iter()
+        .enumerate()
+        .filter_map(|(i, param)| param.name.clone().map(|name| (name, i + 1)))
+        .collect();
+    let mut qcx = QueryContext::root(&scx, qcx.lifetime);
+
+    let mut body = body.clone();
+    transform_ast::transform(&scx, &mut body)?;
+
+    let out = match (&details.returns, bare_select_expr(&scx, &body)) {
+        // Plan the common case of a function whose body is a single
+        // expression without a wrapping subquery, so that the function
+        // expands to exactly that expression.
+        (CatalogFuncReturns::Scalar(typ), Some(expr)) => {
+            let ecx = ExprContext {
+                qcx: &qcx,
+                name: "function body",
+                scope: &Scope::empty(),
+                relation_type: &RelationType::empty(),
+                allow_aggregates: false,
+                allow_subqueries: true,
+                allow_parameters: true,
+                allow_windows: false,
+            };
+            let expr = plan_expr(&ecx, expr)?.type_as_any(&ecx)?;
+            let got = ecx.column_type(&expr);
+            let expr = typeconv::plan_cast(&ecx, CastContext::Assignment, expr, typ)
+                .map_err(|_| return_type_mismatch(&ecx, &details.returns, &[got]))?;
+            UserFuncBody::Scalar(expr)
+        }
+        (returns, _) => {
+            let (expr, scope) = plan_nested_query(&mut qcx, &body)?;
+            let relation_type = qcx.relation_type(&expr);
+            let ecx = ExprContext {
+                qcx: &qcx,
+                name: "function body",
+                scope: &scope,
+                relation_type: &relation_type,
+                allow_aggregates: false,
+                allow_subqueries: true,
+                allow_parameters: true,
+                allow_windows: false,
+            };
+            let expected: Vec<_> = match returns {
+                CatalogFuncReturns::Scalar(typ) => vec![typ.clone()],
+                CatalogFuncReturns::Table(columns) => {
+                    columns.iter().map(|(_name, typ)| typ.clone()).collect()
+                }
+            };
+            if expected.len() != relation_type.arity() {
+                return Err(return_type_mismatch(
+                    &ecx,
+                    &details.returns,
+                    &relation_type.column_types,
+                ));
+            }
+            let casts = expected
+                .iter()
+                .enumerate()
+                .map(|(i, typ)| {
+                    typeconv::plan_cast(
+                        &ecx,
+                        CastContext::Assignment,
+                        HirScalarExpr::column(i),
+                        typ,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| {
+                    return_type_mismatch(&ecx, &details.returns, &relation_type.column_types)
+                })?;
+            let arity = relation_type.arity();
+            let expr = expr.map(casts).project((arity..arity * 2).collect());
+            match returns {
+                CatalogFuncReturns::Scalar(_) => UserFuncBody::Scalar(expr.select()),
+                CatalogFuncReturns::Table(columns) => UserFuncBody::Table {
+                    expr,
+                    column_names: columns.iter().map(|(name, _typ)| name.clone()).collect(),
+                },
+            }
+        }
+    };
+
+    // Parameters that are not declared by the function are not bound to a
+    // type, so planning will have inferred one for them.
+    if let Some(n) = scx
+        .param_types
+        .borrow()
+        .keys()
+        .find(|n| **n > details.params.len())
+    {
+        sql_bail!(
+            "function body refers to parameter ${n}, but the function has {} parameters",
+            details.params.len()
+        );
+    }
+
+    Ok(out)
+}
+
+/// Constructs the error for a function body whose output does not match the
+/// declared return type of the function.
+fn return_type_mismatch(
+    ecx: &ExprContext,
+    returns: &CatalogFuncReturns,
+    got: &[ColumnType],
+) -> PlanError {
+    let expected = match returns {
+        CatalogFuncReturns::Scalar(typ) => ecx.humanize_scalar_type(typ, false),
+        CatalogFuncReturns::Table(columns) => format!(
+            "TABLE ({})",
+            columns
+                .iter()
+                .map(|(_name, typ)| ecx.humanize_scalar_type(typ, false))
+                .join(", ")
+        ),
+    };
+    let got = got
+        .iter()
+        .map(|typ| ecx.humanize_scalar_type(&typ.scalar_type, false))
+        .join(", ");
+    sql_err!("return type mismatch in function declared to return {expected}: body returns ({got})")
+}
+
+/// If `query` is a bare `SELECT <expr>` with no other clauses and no aggregate
+/// or table functions, returns `expr`.
+fn bare_select_expr<'a>(scx: &StatementContext, query: &'a Query<Aug>) -> Option<&'a Expr<Aug>> {
+    let Query {
+        ctes,
+        body: SetExpr::Select(select),
+        order_by,
+        limit: None,
+        offset: None,
+    } = query
+    else {
+        return None;
+    };
+    let Select {
+        distinct: None,
+        projection,
+        from,
+        selection: None,
+        group_by,
+        having: None,
+        qualify: None,
+        options,
+    } = &**select
+    else {
+        return None;
+    };
+    if !ctes.is_empty() || !order_by.is_empty() || !from.is_empty() || !group_by.is_empty() {
+        return None;
+    }
+    if !options.is_empty() {
+        return None;
+    }
+    let mut visitor = AggregateTableFuncVisitor::new(scx);
+    visitor.visit_select_mut(&mut (**select).clone());
+    match visitor.into_result() {
+        Ok((aggs, tables)) if aggs.is_empty() && tables.is_empty() => (),
+        _ => return None,
+    }
+    match projection.as_slice() {
+        [SelectItem::Expr { expr, alias: _ }] => Some(expr),
+        _ => None,
+    }
+}
+
+/// Plans a call to a user-defined SQL function by expanding its body with the
+/// arguments substituted for its parameters.
+fn plan_user_func_call(
+    ecx: &ExprContext,
+    name: &ResolvedItemName,
+    details: &CatalogFuncDetails,
+    create_sql: &str,
+    cexprs: Vec<CoercibleScalarExpr>,
+) -> Result<UserFuncBody, PlanError> {
+    if cexprs.len() != details.params.len() {
+        return Err(unknown_function(ecx, name, &cexprs));
+    }
+    let mut args = Vec::with_capacity(cexprs.len());
+    for (cexpr, param) in cexprs.iter().zip_eq(&details.params) {
+        let arg = typeconv::plan_coerce(ecx, cexpr.clone(), &param.typ)?;
+        let arg = typeconv::plan_cast(ecx, CastContext::Implicit, arg, &param.typ)
+            .map_err(|_| unknown_function(ecx, name, &cexprs))?;
+        args.push(arg);
+    }
+
+    let body = match mz_sql_parser::parser::parse_statements(create_sql)?
+        .into_element()
+        .ast
+    {
+        Statement::CreateFunction(CreateFunctionStatement {
+            body: FunctionBody::Sql(body),
+            ..
+        }) => *body,
+        Statement::CreateFunction(CreateFunctionStatement {
+            body: FunctionBody::Wasm(module),
+            ..
+        }) => {
+            let udf = plan_wasm_udf(ecx.qcx.scx, name.full_name_str(), details, &module)?;
+            return Ok(UserFuncBody::Scalar(HirScalarExpr::call_variadic(
+                VariadicFunc::WasmUdf(udf),
+                args,
+            )));
+        }
+        stmt => sql_bail!(
+            "internal error: {} is not a SQL function: {}",
+            name,
+            stmt.to_ast_string_redacted()
+        ),
+    };
+    // The body's dependencies don't need to be tracked here: they are recorded on the function's
+    // catalog item, and RBAC checks them against the caller through `references()`.
+    let (body, _) = names::resolve(ecx.qcx.scx.catalog, body)?;
+
+    Ok(match plan_user_func_body(ecx.qcx, details, &body)? {
+        UserFuncBody::Scalar(mut expr) => {
+            expr.splice_parameters(&args, 0);
+            UserFuncBody::Scalar(expr)
+        }
+        UserFuncBody::Table {
+            mut expr,
+            column_names,
+        } => {
+            expr.splice_parameters(&args, 0);
+            UserFuncBody::Table { expr, column_names }
+        }
     })
 }
 
@@ -5983,6 +6705,17 @@ pub fn scalar_type_from_catalog(
             }
             Ok(ScalarType::VarChar { max_length: length })
         }
+        CatalogType::Vector => {
+            let mut modifiers = modifiers.iter().fuse();
+            let dimensions = match modifiers.next() {
+                Some(d) => Some(VectorDimensions::try_from(*d)?),
+                None => None,
+            };
+            if modifiers.next().is_some() {
+                sql_bail!("type vector supports at most one type modifier");
+            }
+            Ok(ScalarType::Vector { dimensions })
+        }
         CatalogType::Timestamp => {
             let mut modifiers = modifiers.iter().fuse();
             let precision = match modifiers.next() {
@@ -6072,12 +6805,25 @@ pub fn scalar_type_from_catalog(
                         custom_id: Some(id),
                     })
                 }
+                // Domains are transparent: values of a domain are values of
+                // its base type. The domain's constraints are enforced when
+                // casting to the domain; see `plan_cast`.
+                CatalogType::Domain {
+                    base_reference,
+                    base_modifiers,
+                    ..
+                } => scalar_type_from_catalog(catalog, *base_reference, base_modifiers),
+                CatalogType::Enum { labels } => Ok(ScalarType::Enum {
+                    labels: labels.clone().into_boxed_slice(),
+                    custom_id: id,
+                }),
                 CatalogType::AclItem => Ok(ScalarType::AclItem),
                 CatalogType::Bool => Ok(ScalarType::Bool),
                 CatalogType::Bytes => Ok(ScalarType::Bytes),
                 CatalogType::Date => Ok(ScalarType::Date),
                 CatalogType::Float32 => Ok(ScalarType::Float32),
                 CatalogType::Float64 => Ok(ScalarType::Float64),
+                CatalogType::Geometry => Ok(ScalarType::Geometry),
                 CatalogType::Int16 => Ok(ScalarType::Int16),
                 CatalogType::Int32 => Ok(ScalarType::Int32),
                 CatalogType::Int64 => Ok(ScalarType::Int64),
@@ -6101,12 +6847,15 @@ pub fn scalar_type_from_catalog(
                 CatalogType::RegType => Ok(ScalarType::RegType),
                 CatalogType::String => Ok(ScalarType::String),
                 CatalogType::Time => Ok(ScalarType::Time),
+                CatalogType::TsQuery => Ok(ScalarType::TsQuery),
+                CatalogType::TsVector => Ok(ScalarType::TsVector),
                 CatalogType::Uuid => Ok(ScalarType::Uuid),
                 CatalogType::Int2Vector => Ok(ScalarType::Int2Vector),
                 CatalogType::MzAclItem => Ok(ScalarType::MzAclItem),
                 CatalogType::Numeric => unreachable!("handled above"),
                 CatalogType::Char => unreachable!("handled above"),
                 CatalogType::VarChar => unreachable!("handled above"),
+                CatalogType::Vector => unreachable!("handled above"),
                 CatalogType::Timestamp => unreachable!("handled above"),
                 CatalogType::TimestampTz => unreachable!("handled above"),
             }
@@ -6198,7 +6947,7 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
                 self.within_aggregate = old_within_aggregate;
                 self.table_disallowed_context.pop();
             }
-            Ok(Func::Table { .. }) => {
+            _ if is_table_func(&*item) => {
                 self.table_disallowed_context.push("other table functions");
                 visit_mut::visit_function_mut(self, func);
                 self.table_disallowed_context.pop();
@@ -6223,7 +6972,7 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
                 // and save the table func so it can be planned elsewhere.
                 let mut table_func = None;
                 if let Ok(item) = self.scx.get_item_by_resolved_name(&func.name) {
-                    if let Ok(Func::Table { .. }) = item.func() {
+                    if is_table_func(&*item) {
                         if let Some(context) = self.table_disallowed_context.last() {
                             self.err = Some(sql_err!(
                                 "table functions are not allowed in {} (function {})",
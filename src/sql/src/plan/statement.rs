@@ -119,6 +119,7 @@ pub fn describe(
         catalog,
         param_types: RefCell::new(param_types),
         ambiguous_columns: RefCell::new(false),
+        param_names: BTreeMap::new(),
     };
 
     let desc = match stmt {
@@ -138,6 +139,7 @@ pub fn describe(
         Statement::AlterSystemReset(stmt) => ddl::describe_alter_system_reset(&scx, stmt)?,
         Statement::AlterSystemResetAll(stmt) => ddl::describe_alter_system_reset_all(&scx, stmt)?,
         Statement::AlterTableAddColumn(stmt) => ddl::describe_alter_table_add_column(&scx, stmt)?,
+        Statement::AlterTypeAddValue(stmt) => ddl::describe_alter_type_add_value(&scx, stmt)?,
         Statement::AlterNetworkPolicy(stmt) => ddl::describe_alter_network_policy(&scx, stmt)?,
         Statement::Comment(stmt) => ddl::describe_comment(&scx, stmt)?,
         Statement::CreateCluster(stmt) => ddl::describe_create_cluster(&scx, stmt)?,
@@ -147,6 +149,9 @@ pub fn describe(
         Statement::CreateIndex(stmt) => ddl::describe_create_index(&scx, stmt)?,
         Statement::CreateRole(stmt) => ddl::describe_create_role(&scx, stmt)?,
         Statement::CreateSchema(stmt) => ddl::describe_create_schema(&scx, stmt)?,
+        Statement::CreatePublication(stmt) => ddl::describe_create_publication(&scx, stmt)?,
+        Statement::CreateNotification(stmt) => ddl::describe_create_notification(&scx, stmt)?,
+        Statement::CreateFunction(stmt) => ddl::describe_create_function(&scx, stmt)?,
         Statement::CreateSecret(stmt) => ddl::describe_create_secret(&scx, stmt)?,
         Statement::CreateSink(stmt) => ddl::describe_create_sink(&scx, stmt)?,
         Statement::CreateWebhookSource(stmt) => ddl::describe_create_webhook_source(&scx, stmt)?,
@@ -184,6 +189,9 @@ pub fn describe(
         Statement::Show(ShowStatement::ShowCreateConnection(stmt)) => {
             show::describe_show_create_connection(&scx, stmt)?
         }
+        Statement::Show(ShowStatement::ShowCreateFunction(stmt)) => {
+            show::describe_show_create_function(&scx, stmt)?
+        }
         Statement::Show(ShowStatement::ShowCreateCluster(stmt)) => {
             show::describe_show_create_cluster(&scx, stmt)?
         }
@@ -214,6 +222,8 @@ pub fn describe(
         Statement::Deallocate(stmt) => scl::describe_deallocate(&scx, stmt)?,
         Statement::Declare(stmt) => scl::describe_declare(&scx, stmt, param_types_in)?,
         Statement::Discard(stmt) => scl::describe_discard(&scx, stmt)?,
+        Statement::Listen(stmt) => scl::describe_listen(&scx, stmt)?,
+        Statement::Unlisten(stmt) => scl::describe_unlisten(&scx, stmt)?,
         Statement::Execute(stmt) => scl::describe_execute(&scx, stmt)?,
         Statement::Fetch(stmt) => scl::describe_fetch(&scx, stmt)?,
         Statement::Prepare(stmt) => scl::describe_prepare(&scx, stmt)?,
@@ -228,6 +238,7 @@ pub fn describe(
         Statement::Delete(stmt) => dml::describe_delete(&scx, stmt)?,
         Statement::ExplainPlan(stmt) => dml::describe_explain_plan(&scx, stmt)?,
         Statement::ExplainPushdown(stmt) => dml::describe_explain_pushdown(&scx, stmt)?,
+        Statement::ExplainAnalyze(stmt) => dml::describe_explain_analyze(&scx, stmt)?,
         Statement::ExplainTimestamp(stmt) => dml::describe_explain_timestamp(&scx, stmt)?,
         Statement::ExplainSinkSchema(stmt) => dml::describe_explain_schema(&scx, stmt)?,
         Statement::Insert(stmt) => dml::describe_insert(&scx, stmt)?,
@@ -295,6 +306,7 @@ pub fn plan(
         catalog,
         param_types: RefCell::new(param_types),
         ambiguous_columns: RefCell::new(false),
+        param_names: BTreeMap::new(),
     };
 
     if resolved_ids
@@ -327,6 +339,7 @@ pub fn plan(
         Statement::AlterSystemReset(stmt) => ddl::plan_alter_system_reset(scx, stmt),
         Statement::AlterSystemResetAll(stmt) => ddl::plan_alter_system_reset_all(scx, stmt),
         Statement::AlterTableAddColumn(stmt) => ddl::plan_alter_table_add_column(scx, stmt),
+        Statement::AlterTypeAddValue(stmt) => ddl::plan_alter_type_add_value(scx, stmt),
         Statement::AlterNetworkPolicy(stmt) => ddl::plan_alter_network_policy(scx, stmt),
         Statement::Comment(stmt) => ddl::plan_comment(scx, stmt),
         Statement::CreateCluster(stmt) => ddl::plan_create_cluster(scx, stmt),
@@ -336,6 +349,9 @@ pub fn plan(
         Statement::CreateIndex(stmt) => ddl::plan_create_index(scx, stmt),
         Statement::CreateRole(stmt) => ddl::plan_create_role(scx, stmt),
         Statement::CreateSchema(stmt) => ddl::plan_create_schema(scx, stmt),
+        Statement::CreatePublication(stmt) => ddl::plan_create_publication(scx, stmt),
+        Statement::CreateNotification(stmt) => ddl::plan_create_notification(scx, stmt),
+        Statement::CreateFunction(stmt) => ddl::plan_create_function(scx, stmt),
         Statement::CreateSecret(stmt) => ddl::plan_create_secret(scx, stmt),
         Statement::CreateSink(stmt) => ddl::plan_create_sink(scx, stmt),
         Statement::CreateWebhookSource(stmt) => ddl::plan_create_webhook_source(scx, stmt),
@@ -365,6 +381,7 @@ pub fn plan(
         Statement::Delete(stmt) => dml::plan_delete(scx, stmt, params),
         Statement::ExplainPlan(stmt) => dml::plan_explain_plan(scx, stmt, params),
         Statement::ExplainPushdown(stmt) => dml::plan_explain_pushdown(scx, stmt, params),
+        Statement::ExplainAnalyze(stmt) => dml::plan_explain_analyze(scx, stmt, params),
         Statement::ExplainTimestamp(stmt) => dml::plan_explain_timestamp(scx, stmt),
         Statement::ExplainSinkSchema(stmt) => dml::plan_explain_schema(scx, stmt),
         Statement::Insert(stmt) => dml::plan_insert(scx, stmt, params),
@@ -377,6 +394,9 @@ pub fn plan(
         Statement::Show(ShowStatement::ShowCreateConnection(stmt)) => {
             show::plan_show_create_connection(scx, stmt).map(Plan::ShowCreate)
         }
+        Statement::Show(ShowStatement::ShowCreateFunction(stmt)) => {
+            show::plan_show_create_function(scx, stmt).map(Plan::ShowCreate)
+        }
         Statement::Show(ShowStatement::ShowCreateCluster(stmt)) => {
             show::plan_show_create_cluster(scx, stmt).map(Plan::ShowCreate)
         }
@@ -405,6 +425,8 @@ pub fn plan(
         Statement::Deallocate(stmt) => scl::plan_deallocate(scx, stmt),
         Statement::Declare(stmt) => scl::plan_declare(scx, stmt, params),
         Statement::Discard(stmt) => scl::plan_discard(scx, stmt),
+        Statement::Listen(stmt) => scl::plan_listen(scx, stmt),
+        Statement::Unlisten(stmt) => scl::plan_unlisten(scx, stmt),
         Statement::Execute(stmt) => scl::plan_execute(scx, stmt),
         Statement::Fetch(stmt) => scl::plan_fetch(scx, stmt),
         Statement::Prepare(stmt) => scl::plan_prepare(scx, stmt),
@@ -463,7 +485,9 @@ impl PartialEq<ObjectType> for CatalogItemType {
             | (CatalogItemType::Index, ObjectType::Index)
             | (CatalogItemType::Type, ObjectType::Type)
             | (CatalogItemType::Secret, ObjectType::Secret)
-            | (CatalogItemType::Connection, ObjectType::Connection) => true,
+            | (CatalogItemType::Connection, ObjectType::Connection)
+            | (CatalogItemType::Publication, ObjectType::Publication)
+            | (CatalogItemType::Notification, ObjectType::Notification) => true,
             (_, _) => false,
         }
     }
@@ -490,6 +514,10 @@ pub struct StatementContext<'a> {
     /// Whether the statement contains an expression that can make the exact column list
     /// ambiguous. For example `NATURAL JOIN` or `SELECT *`. This is filled in as planning occurs.
     pub ambiguous_columns: RefCell<bool>,
+    /// The names of the parameters in scope, mapped to their positions. This
+    /// is only populated while planning the body of a user-defined function
+    /// whose parameters are named.
+    pub param_names: BTreeMap<mz_repr::ColumnName, usize>,
 }
 
 impl<'a> StatementContext<'a> {
@@ -502,6 +530,7 @@ impl<'a> StatementContext<'a> {
             catalog,
             param_types: Default::default(),
             ambiguous_columns: RefCell::new(false),
+            param_names: BTreeMap::new(),
         }
     }
 
@@ -1042,6 +1071,7 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::AlterSystemReset(_) => DDL,
             Statement::AlterSystemResetAll(_) => DDL,
             Statement::AlterTableAddColumn(_) => DDL,
+            Statement::AlterTypeAddValue(_) => DDL,
             Statement::Comment(_) => DDL,
             Statement::CreateCluster(_) => DDL,
             Statement::CreateClusterReplica(_) => DDL,
@@ -1051,6 +1081,9 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::CreateIndex(_) => DDL,
             Statement::CreateRole(_) => DDL,
             Statement::CreateSchema(_) => DDL,
+            Statement::CreatePublication(_) => DDL,
+            Statement::CreateNotification(_) => DDL,
+            Statement::CreateFunction(_) => DDL,
             Statement::CreateSecret(_) => DDL,
             Statement::CreateSink(_) => DDL,
             Statement::CreateWebhookSource(_) => DDL,
@@ -1079,6 +1112,7 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::Delete(_) => DML,
             Statement::ExplainPlan(_) => DML,
             Statement::ExplainPushdown(_) => DML,
+            Statement::ExplainAnalyze(_) => DML,
             Statement::ExplainTimestamp(_) => DML,
             Statement::ExplainSinkSchema(_) => DML,
             Statement::Insert(_) => DML,
@@ -1089,6 +1123,7 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             // `SHOW` statements.
             Statement::Show(ShowStatement::ShowColumns(_)) => Show,
             Statement::Show(ShowStatement::ShowCreateConnection(_)) => Show,
+            Statement::Show(ShowStatement::ShowCreateFunction(_)) => Show,
             Statement::Show(ShowStatement::ShowCreateCluster(_)) => Show,
             Statement::Show(ShowStatement::ShowCreateIndex(_)) => Show,
             Statement::Show(ShowStatement::ShowCreateSink(_)) => Show,
@@ -1103,6 +1138,8 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::Deallocate(_) => SCL,
             Statement::Declare(_) => SCL,
             Statement::Discard(_) => SCL,
+            Statement::Listen(_) => SCL,
+            Statement::Unlisten(_) => SCL,
             Statement::Execute(_) => SCL,
             Statement::Fetch(_) => SCL,
             Statement::Prepare(_) => SCL,
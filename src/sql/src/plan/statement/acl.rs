@@ -605,6 +605,7 @@ fn privilege_to_acl_mode(privilege: Privilege) -> AclMode {
         Privilege::INSERT => AclMode::INSERT,
         Privilege::UPDATE => AclMode::UPDATE,
         Privilege::DELETE => AclMode::DELETE,
+        Privilege::EXECUTE => AclMode::EXECUTE,
         Privilege::USAGE => AclMode::USAGE,
         Privilege::CREATE => AclMode::CREATE,
         Privilege::CREATEROLE => AclMode::CREATE_ROLE,
@@ -637,9 +638,14 @@ pub fn plan_alter_default_privileges(
         | ObjectType::ContinualTask => sql_bail!(
             "{object_type}S is not valid for ALTER DEFAULT PRIVILEGES, use TABLES instead"
         ),
-        ObjectType::Sink | ObjectType::ClusterReplica | ObjectType::Role | ObjectType::Func => {
+        ObjectType::Sink
+        | ObjectType::ClusterReplica
+        | ObjectType::Role
+        | ObjectType::Publication
+        | ObjectType::Notification => {
             sql_bail!("{object_type}S do not have privileges")
         }
+        ObjectType::Func => bail_unsupported!("ALTER DEFAULT PRIVILEGES ON FUNCTIONS"),
         ObjectType::Cluster | ObjectType::Database
             if matches!(
                 target_objects,
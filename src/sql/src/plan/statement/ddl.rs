@@ -51,36 +51,39 @@ use mz_sql_parser::ast::{
     AlterSetClusterStatement, AlterSinkAction, AlterSinkStatement, AlterSourceAction,
     AlterSourceAddSubsourceOption, AlterSourceAddSubsourceOptionName, AlterSourceStatement,
     AlterSystemResetAllStatement, AlterSystemResetStatement, AlterSystemSetStatement,
-    AlterTableAddColumnStatement, AvroSchema, AvroSchemaOption, AvroSchemaOptionName,
-    ClusterAlterOption, ClusterAlterOptionName, ClusterAlterOptionValue,
-    ClusterAlterUntilReadyOption, ClusterAlterUntilReadyOptionName, ClusterFeature,
-    ClusterFeatureName, ClusterOption, ClusterOptionName, ClusterScheduleOptionValue, ColumnDef,
-    ColumnOption, CommentObjectType, CommentStatement, ConnectionOption, ConnectionOptionName,
-    ContinualTaskOption, ContinualTaskOptionName, CreateClusterReplicaStatement,
-    CreateClusterStatement, CreateConnectionOption, CreateConnectionOptionName,
-    CreateConnectionStatement, CreateConnectionType, CreateContinualTaskStatement,
-    CreateDatabaseStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateNetworkPolicyStatement, CreateRoleStatement, CreateSchemaStatement,
-    CreateSecretStatement, CreateSinkConnection, CreateSinkOption, CreateSinkOptionName,
-    CreateSinkStatement, CreateSourceConnection, CreateSourceOption, CreateSourceOptionName,
-    CreateSourceStatement, CreateSubsourceOption, CreateSubsourceOptionName,
-    CreateSubsourceStatement, CreateTableFromSourceStatement, CreateTableStatement, CreateTypeAs,
-    CreateTypeListOption, CreateTypeListOptionName, CreateTypeMapOption, CreateTypeMapOptionName,
-    CreateTypeStatement, CreateViewStatement, CreateWebhookSourceStatement, CsrConfigOption,
-    CsrConfigOptionName, CsrConnection, CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf,
-    CsvColumns, DeferredItemName, DocOnIdentifier, DocOnSchema, DropObjectsStatement,
-    DropOwnedStatement, Expr, Format, FormatSpecifier, Ident, IfExistsBehavior, IndexOption,
-    IndexOptionName, KafkaSinkConfigOption, KeyConstraint, LoadGeneratorOption,
-    LoadGeneratorOptionName, MaterializedViewOption, MaterializedViewOptionName, MySqlConfigOption,
-    MySqlConfigOptionName, NetworkPolicyOption, NetworkPolicyOptionName,
-    NetworkPolicyRuleDefinition, NetworkPolicyRuleOption, NetworkPolicyRuleOptionName,
-    PgConfigOption, PgConfigOptionName, ProtobufSchema, QualifiedReplica, RefreshAtOptionValue,
-    RefreshEveryOptionValue, RefreshOptionValue, ReplicaDefinition, ReplicaOption,
-    ReplicaOptionName, RoleAttribute, SetRoleVar, SourceErrorPolicy, SourceIncludeMetadata,
-    SqlServerConfigOption, SqlServerConfigOptionName, Statement, TableConstraint,
-    TableFromSourceColumns, TableFromSourceOption, TableFromSourceOptionName, TableOption,
-    TableOptionName, UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName,
-    UnresolvedSchemaName, Value, ViewDefinition, WithOptionValue,
+    AlterTableAddColumnStatement, AlterTypeAddValuePosition, AlterTypeAddValueStatement,
+    AvroSchema, AvroSchemaOption, AvroSchemaOptionName, ClusterAlterOption, ClusterAlterOptionName,
+    ClusterAlterOptionValue, ClusterAlterUntilReadyOption, ClusterAlterUntilReadyOptionName,
+    ClusterFeature, ClusterFeatureName, ClusterOption, ClusterOptionName,
+    ClusterScheduleOptionValue, ColumnDef, ColumnOption, CommentObjectType, CommentStatement,
+    ConnectionOption, ConnectionOptionName, ContinualTaskOption, ContinualTaskOptionName,
+    CreateClusterReplicaStatement, CreateClusterStatement, CreateConnectionOption,
+    CreateConnectionOptionName, CreateConnectionStatement, CreateConnectionType,
+    CreateContinualTaskStatement, CreateDatabaseStatement, CreateFunctionStatement,
+    CreateIndexStatement, CreateMaterializedViewStatement, CreateNetworkPolicyStatement,
+    CreateNotificationStatement, CreatePublicationStatement, CreateRoleStatement,
+    CreateSchemaStatement, CreateSecretStatement, CreateSinkConnection, CreateSinkOption,
+    CreateSinkOptionName, CreateSinkStatement, CreateSourceConnection, CreateSourceOption,
+    CreateSourceOptionName, CreateSourceStatement, CreateSubsourceOption,
+    CreateSubsourceOptionName, CreateSubsourceStatement, CreateTableFromSourceStatement,
+    CreateTableStatement, CreateTypeAs, CreateTypeListOption, CreateTypeListOptionName,
+    CreateTypeMapOption, CreateTypeMapOptionName, CreateTypeStatement, CreateViewStatement,
+    CreateWebhookSourceStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
+    CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DeferredItemName,
+    DocOnIdentifier, DocOnSchema, DropObjectsStatement, DropOwnedStatement, Expr, Format,
+    FormatSpecifier, FunctionBody, FunctionParameter, FunctionReturns, FunctionTableColumn, Ident,
+    IfExistsBehavior, IndexMethod, IndexOption, IndexOptionName, KafkaSinkConfigOption,
+    KeyConstraint, LoadGeneratorOption, LoadGeneratorOptionName, MaterializedViewOption,
+    MaterializedViewOptionName, MySqlConfigOption, MySqlConfigOptionName, NetworkPolicyOption,
+    NetworkPolicyOptionName, NetworkPolicyRuleDefinition, NetworkPolicyRuleOption,
+    NetworkPolicyRuleOptionName, PgConfigOption, PgConfigOptionName, ProtobufSchema,
+    QualifiedReplica, RefreshAtOptionValue, RefreshEveryOptionValue, RefreshOptionValue,
+    ReplicaDefinition, ReplicaOption, ReplicaOptionName, RoleAttribute, SetRoleVar,
+    SourceErrorPolicy, SourceIncludeMetadata, SqlServerConfigOption, SqlServerConfigOptionName,
+    Statement, TableConstraint, TableFromSourceColumns, TableFromSourceOption,
+    TableFromSourceOptionName, TableOption, TableOptionName, UnresolvedDatabaseName,
+    UnresolvedItemName, UnresolvedObjectName, UnresolvedSchemaName, Value, ViewDefinition,
+    WithOptionValue,
 };
 use mz_sql_parser::ident;
 use mz_sql_parser::parser::StatementParseResult;
@@ -122,8 +125,9 @@ use prost::Message;
 
 use crate::ast::display::AstDisplay;
 use crate::catalog::{
-    CatalogCluster, CatalogDatabase, CatalogError, CatalogItem, CatalogItemType,
-    CatalogRecordField, CatalogType, CatalogTypeDetails, ObjectType, SystemObjectType,
+    CatalogCluster, CatalogDatabase, CatalogError, CatalogFuncDetails, CatalogFuncParam,
+    CatalogFuncReturns, CatalogItem, CatalogItemType, CatalogRecordField, CatalogType,
+    CatalogTypeDetails, ObjectType, SystemObjectType,
 };
 use crate::kafka_util::{KafkaSinkConfigOptionExtracted, KafkaSourceConfigOptionExtracted};
 use crate::names::{
@@ -134,8 +138,8 @@ use crate::names::{
 use crate::normalize::{self, ident};
 use crate::plan::error::PlanError;
 use crate::plan::query::{
-    CteDesc, ExprContext, QueryLifetime, cast_relation, plan_expr, scalar_type_from_catalog,
-    scalar_type_from_sql,
+    CteDesc, ExprContext, QueryLifetime, cast_relation, plan_domain_check, plan_expr,
+    plan_notification_predicate, scalar_type_from_catalog, scalar_type_from_sql,
 };
 use crate::plan::scope::Scope;
 use crate::plan::statement::ddl::connection::{INALTERABLE_OPTIONS, MUTUALLY_EXCLUSIVE_SETS};
@@ -148,18 +152,20 @@ use crate::plan::{
     AlterNetworkPolicyPlan, AlterNoopPlan, AlterOptionParameter, AlterRetainHistoryPlan,
     AlterRolePlan, AlterSchemaRenamePlan, AlterSchemaSwapPlan, AlterSecretPlan,
     AlterSetClusterPlan, AlterSinkPlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
-    AlterSystemSetPlan, AlterTablePlan, ClusterSchedule, CommentPlan, ComputeReplicaConfig,
-    ComputeReplicaIntrospectionConfig, ConnectionDetails, CreateClusterManagedPlan,
-    CreateClusterPlan, CreateClusterReplicaPlan, CreateClusterUnmanagedPlan, CreateClusterVariant,
-    CreateConnectionPlan, CreateContinualTaskPlan, CreateDatabasePlan, CreateIndexPlan,
-    CreateMaterializedViewPlan, CreateNetworkPolicyPlan, CreateRolePlan, CreateSchemaPlan,
-    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
-    CreateViewPlan, DataSourceDesc, DropObjectsPlan, DropOwnedPlan, HirRelationExpr, Index,
+    AlterSystemSetPlan, AlterTablePlan, AlterTypeAddValuePlan, ClusterSchedule, CommentPlan,
+    ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, ConnectionDetails,
+    CreateClusterManagedPlan, CreateClusterPlan, CreateClusterReplicaPlan,
+    CreateClusterUnmanagedPlan, CreateClusterVariant, CreateConnectionPlan,
+    CreateContinualTaskPlan, CreateDatabasePlan, CreateFunctionPlan, CreateIndexPlan,
+    CreateMaterializedViewPlan, CreateNetworkPolicyPlan, CreateNotificationPlan,
+    CreatePublicationPlan, CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan,
+    CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, DataSourceDesc,
+    DropObjectsPlan, DropOwnedPlan, ForeignKey, Function, HirRelationExpr, Index, IndexKind,
     Ingestion, MaterializedView, NetworkPolicyRule, NetworkPolicyRuleAction,
-    NetworkPolicyRuleDirection, Plan, PlanClusterOption, PlanNotice, PolicyAddress, QueryContext,
-    ReplicaConfig, Secret, Sink, Source, Table, TableDataSource, Type, VariableValue, View,
-    WebhookBodyFormat, WebhookHeaderFilters, WebhookHeaders, WebhookValidation, literal,
-    plan_utils, query, transform_ast,
+    NetworkPolicyRuleDirection, Notification, Plan, PlanClusterOption, PlanNotice, PolicyAddress,
+    Publication, QueryContext, ReplicaConfig, Secret, Sink, Source, Table, TableDataSource, Type,
+    VariableValue, View, WebhookBodyFormat, WebhookHeaderFilters, WebhookHeaders,
+    WebhookValidation, literal, plan_utils, query, transform_ast,
 };
 use crate::session::vars::{
     self, ENABLE_CLUSTER_SCHEDULE_REFRESH, ENABLE_COLLECTION_PARTITION_BY,
@@ -302,11 +308,31 @@ pub fn plan_create_table(
     let mut defaults = Vec::with_capacity(columns.len());
     let mut changes = BTreeMap::new();
     let mut keys = Vec::new();
+    let mut foreign_keys = Vec::new();
 
     for (i, c) in columns.into_iter().enumerate() {
         let aug_data_type = &c.data_type;
         let ty = query::scalar_type_from_sql(scx, aug_data_type)?;
         let mut nullable = true;
+        // The constraints of a domain are only enforced when casting to the
+        // domain, so columns can only use domains whose constraints can be
+        // expressed as column constraints.
+        if let ResolvedDataType::Named { id, .. } = aug_data_type {
+            if let Some(CatalogTypeDetails {
+                typ: CatalogType::Domain {
+                    not_null, check, ..
+                },
+                ..
+            }) = scx.catalog.get_item(id).type_details()
+            {
+                if check.is_some() {
+                    bail_unsupported!(
+                        "CREATE TABLE with column of domain type with CHECK constraint"
+                    );
+                }
+                nullable = !*not_null;
+            }
+        }
         let mut default = Expr::null();
         let mut versioned = false;
         for option in &c.options {
@@ -397,10 +423,22 @@ pub fn plan_create_table(
                     keys.push(key);
                 }
             }
-            TableConstraint::ForeignKey { .. } => {
+            TableConstraint::ForeignKey {
+                name: _,
+                columns,
+                foreign_table,
+                referred_columns,
+            } => {
                 // Foreign key constraints are not presently enforced. We allow
                 // them with feature flags for sqllogictest's sake.
-                scx.require_feature_flag(&vars::UNSAFE_ENABLE_TABLE_FOREIGN_KEY)?
+                scx.require_feature_flag(&vars::UNSAFE_ENABLE_TABLE_FOREIGN_KEY)?;
+                foreign_keys.extend(plan_foreign_key(
+                    scx,
+                    &names,
+                    columns,
+                    foreign_table,
+                    referred_columns,
+                ));
             }
             TableConstraint::Check { .. } => {
                 // Check constraints are not presently enforced. We allow them
@@ -476,6 +514,7 @@ pub fn plan_create_table(
         temporary,
         compaction_window,
         data_source: TableDataSource::TableWrites { defaults },
+        foreign_keys,
     };
     Ok(Plan::CreateTable(CreateTablePlan {
         name,
@@ -484,6 +523,50 @@ pub fn plan_create_table(
     }))
 }
 
+/// Plans a `FOREIGN KEY` constraint of a table with the columns `names`.
+///
+/// Returns `None` if the constraint does not name existing columns of both the
+/// table and a referenced table. Foreign keys were accepted without checking
+/// them before the optimizer relied on them, so we ignore such constraints
+/// rather than fail to plan existing tables.
+fn plan_foreign_key(
+    scx: &StatementContext,
+    names: &[ColumnName],
+    columns: &[Ident],
+    foreign_table: &ResolvedItemName,
+    referred_columns: &[Ident],
+) -> Option<ForeignKey> {
+    if columns.is_empty() || columns.len() != referred_columns.len() {
+        return None;
+    }
+    let item = scx.get_item_by_resolved_name(foreign_table).ok()?;
+    if item.item_type() != CatalogItemType::Table {
+        return None;
+    }
+    let foreign_desc = item
+        .desc(&scx.catalog.resolve_full_name(item.name()))
+        .ok()?;
+    let columns = columns
+        .iter()
+        .map(|column| {
+            let column = normalize::column_name(column.clone());
+            names.iter().position(|name| *name == column)
+        })
+        .collect::<Option<_>>()?;
+    let referenced_columns = referred_columns
+        .iter()
+        .map(|column| {
+            let (i, _) = foreign_desc.get_by_name(&normalize::column_name(column.clone()))?;
+            Some(i)
+        })
+        .collect::<Option<_>>()?;
+    Some(ForeignKey {
+        columns,
+        referenced_id: item.id(),
+        referenced_columns,
+    })
+}
+
 pub fn describe_create_table_from_source(
     _: &StatementContext,
     _: CreateTableFromSourceStatement<Aug>,
@@ -706,6 +789,7 @@ pub fn plan_create_webhook_source(
                 temporary: false,
                 compaction_window: None,
                 data_source,
+                foreign_keys: Vec::new(),
             },
         })
     } else {
@@ -1606,6 +1690,7 @@ pub fn plan_create_subsource(
                         scx,
                         &table,
                         &text_columns,
+                        columns,
                     )?,
                     table,
                 })
@@ -1753,11 +1838,16 @@ pub fn plan_create_table_from_source(
 
     let details = match details {
         SourceExportStatementDetails::Postgres { table } => {
+            let columns = match columns {
+                TableFromSourceColumns::Defined(columns) => columns.as_slice(),
+                _ => &[],
+            };
             SourceExportDetails::Postgres(PostgresSourceExportDetails {
                 column_casts: crate::pure::postgres::generate_column_casts(
                     scx,
                     &table,
                     &text_columns,
+                    columns,
                 )?,
                 table,
             })
@@ -1940,6 +2030,7 @@ pub fn plan_create_table_from_source(
             desc: data_source,
             timeline,
         },
+        foreign_keys: Vec::new(),
     };
 
     Ok(Plan::CreateTable(CreateTablePlan {
@@ -3020,7 +3111,9 @@ pub fn plan_create_continual_task(
         | CatalogItemType::Type
         | CatalogItemType::Func
         | CatalogItemType::Secret
-        | CatalogItemType::Connection => {
+        | CatalogItemType::Connection
+        | CatalogItemType::Publication
+        | CatalogItemType::Notification => {
             sql_bail!(
                 "CONTINUAL TASK cannot use {} as an input",
                 input.item_type()
@@ -3927,6 +4020,7 @@ pub fn plan_create_index(
         name,
         on_name,
         in_cluster,
+        method,
         key_parts,
         with_options,
         if_not_exists,
@@ -3967,6 +4061,33 @@ pub fn plan_create_index(
     };
     let keys = query::plan_index_exprs(scx, &on_desc, filled_key_parts.clone())?;
 
+    let kind = match method {
+        IndexMethod::Arrangement => IndexKind::Arrangement,
+        IndexMethod::Lexeme => {
+            // A lexeme index arranges rows by the lexemes of one document.
+            let is_document = |key: &mz_expr::MirScalarExpr| {
+                key.typ(&on_desc.typ().column_types).scalar_type == ScalarType::TsVector
+            };
+            match keys.as_slice() {
+                [key] if key_parts.is_some() && is_document(key) => IndexKind::Lexeme,
+                _ => sql_bail!("lexeme indexes must have exactly one key of type tsvector"),
+            }
+        }
+        IndexMethod::Ann => {
+            // An ANN index buckets rows by one vector.
+            let is_vector = |key: &mz_expr::MirScalarExpr| {
+                matches!(
+                    key.typ(&on_desc.typ().column_types).scalar_type,
+                    ScalarType::Vector { .. }
+                )
+            };
+            match keys.as_slice() {
+                [key] if key_parts.is_some() && is_vector(key) => IndexKind::Ann,
+                _ => sql_bail!("ANN indexes must have exactly one key of type vector"),
+            }
+        }
+    };
+
     let index_name = if let Some(name) = name {
         QualifiedItemName {
             qualifiers: on.name().qualifiers.clone(),
@@ -4061,6 +4182,7 @@ pub fn plan_create_index(
             create_sql,
             on: on.global_id(),
             keys,
+            kind,
             cluster_id,
             compaction_window,
         },
@@ -4168,6 +4290,42 @@ pub fn plan_create_type(
             }
             CatalogType::Record { fields }
         }
+        CreateTypeAs::Enum { labels } => {
+            scx.require_feature_flag(&vars::ENABLE_ENUM_AND_DOMAIN_TYPES)?;
+            for label in &labels {
+                validate_enum_label(label)?;
+            }
+            if let Some(label) = labels.iter().duplicates().next() {
+                sql_bail!("enum label {} specified more than once", label.quoted());
+            }
+            CatalogType::Enum { labels }
+        }
+        CreateTypeAs::Domain {
+            data_type,
+            not_null,
+            check,
+        } => {
+            scx.require_feature_flag(&vars::ENABLE_ENUM_AND_DOMAIN_TYPES)?;
+            let (id, modifiers) = match data_type {
+                ResolvedDataType::Named { id, modifiers, .. } => (id, modifiers),
+                _ => sql_bail!(
+                    "CREATE DOMAIN can only use named data types, but found unnamed data type {}. \
+                    Use CREATE TYPE to create a named type first",
+                    data_type.human_readable_name(),
+                ),
+            };
+            // Validate that the modifiers are actually valid.
+            let base_type = scalar_type_from_catalog(scx.catalog, id, &modifiers)?;
+            if let Some(check) = &check {
+                plan_domain_check(scx, check, &base_type)?;
+            }
+            CatalogType::Domain {
+                base_reference: id,
+                base_modifiers: modifiers,
+                not_null,
+                check,
+            }
+        }
     };
 
     let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
@@ -4192,6 +4350,18 @@ pub fn plan_create_type(
     }))
 }
 
+/// The maximum length of an enum label, in bytes, matching PostgreSQL.
+const MAX_ENUM_LABEL_LENGTH: usize = 63;
+
+fn validate_enum_label(label: &str) -> Result<(), PlanError> {
+    if label.is_empty() || label.len() > MAX_ENUM_LABEL_LENGTH {
+        return Err(PlanError::InvalidEnumLabel {
+            label: label.to_string(),
+        });
+    }
+    Ok(())
+}
+
 generate_extracted_config!(CreateTypeListOption, (ElementType, ResolvedDataType));
 
 generate_extracted_config!(
@@ -4216,6 +4386,7 @@ pub struct PlannedRoleAttributes {
     pub nopassword: Option<bool>,
     pub superuser: Option<bool>,
     pub login: Option<bool>,
+    pub replication: Option<bool>,
 }
 
 fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttributes, PlanError> {
@@ -4224,6 +4395,7 @@ fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttrib
         password: None,
         superuser: None,
         login: None,
+        replication: None,
         nopassword: None,
     };
 
@@ -4292,6 +4464,18 @@ fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttrib
                 }
                 planned_attributes.login = Some(false);
             }
+            RoleAttribute::Replication => {
+                if planned_attributes.replication == Some(false) {
+                    sql_bail!("conflicting or redundant options");
+                }
+                planned_attributes.replication = Some(true);
+            }
+            RoleAttribute::NoReplication => {
+                if planned_attributes.replication == Some(true) {
+                    sql_bail!("conflicting or redundant options");
+                }
+                planned_attributes.replication = Some(false);
+            }
         }
     }
     if planned_attributes.inherit == Some(false) {
@@ -4470,6 +4654,8 @@ pub fn describe_create_cluster(
 // to ALTER CLUSTER would always reset the value of that option to the default.
 generate_extracted_config!(
     ClusterOption,
+    (ArrangementSpill, bool),
+    (AutoIndex, bool),
     (AvailabilityZones, Vec<String>),
     (Disk, bool),
     (IntrospectionDebugging, bool),
@@ -4566,6 +4752,8 @@ pub fn plan_create_cluster_inner(
     }: CreateClusterStatement<Aug>,
 ) -> Result<CreateClusterPlan, PlanError> {
     let ClusterOptionExtracted {
+        arrangement_spill,
+        auto_index,
         availability_zones,
         introspection_debugging,
         introspection_interval,
@@ -4590,8 +4778,17 @@ pub fn plan_create_cluster_inner(
         }
     }
 
+    if arrangement_spill.is_some() {
+        scx.require_feature_flag(&vars::ENABLE_ARRANGEMENT_SPILL)?;
+    }
+    if auto_index.is_some() {
+        scx.require_feature_flag(&vars::ENABLE_AUTO_INDEX)?;
+    }
+
     let schedule = schedule.unwrap_or(ClusterScheduleOptionValue::Manual);
     let workload_class = workload_class.and_then(|v| v.0);
+    let arrangement_spill = arrangement_spill.unwrap_or(false);
+    let auto_index = auto_index.unwrap_or(false);
 
     if managed {
         if replicas.is_some() {
@@ -4688,6 +4885,8 @@ pub fn plan_create_cluster_inner(
                 schedule,
             }),
             workload_class,
+            arrangement_spill,
+            auto_index,
         })
     } else {
         let Some(replica_defs) = replicas else {
@@ -4729,6 +4928,8 @@ pub fn plan_create_cluster_inner(
             name: normalize::ident(name),
             variant: CreateClusterVariant::Unmanaged(CreateClusterUnmanagedPlan { replicas }),
             workload_class,
+            arrangement_spill,
+            auto_index,
         })
     }
 }
@@ -4742,6 +4943,8 @@ pub fn unplan_create_cluster(
         name,
         variant,
         workload_class,
+        arrangement_spill,
+        auto_index,
     }: CreateClusterPlan,
 ) -> Result<CreateClusterStatement<Aug>, PlanError> {
     match variant {
@@ -4770,6 +4973,10 @@ pub fn unplan_create_cluster(
                 enable_projection_pushdown_after_relation_cse,
                 enable_less_reduce_in_eqprop: _,
                 enable_dequadratic_eqprop_map: _,
+                enable_ordered_top_k: _,
+                enable_join_elimination: _,
+                enable_join_size_estimates: _,
+                enable_spatial_join: _,
             } = optimizer_feature_overrides;
             // The ones from above that don't occur below are not wired up to cluster features.
             let features_extracted = ClusterFeatureExtracted {
@@ -4807,6 +5014,8 @@ pub fn unplan_create_cluster(
             let options_extracted = ClusterOptionExtracted {
                 // Seen is ignored when unplanning.
                 seen: Default::default(),
+                arrangement_spill: arrangement_spill.then_some(true),
+                auto_index: auto_index.then_some(true),
                 availability_zones,
                 disk: Some(disk),
                 introspection_debugging: Some(introspection_debugging),
@@ -5168,6 +5377,203 @@ pub fn plan_create_secret(
     }))
 }
 
+pub fn describe_create_publication(
+    _: &StatementContext,
+    _: CreatePublicationStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_publication(
+    scx: &StatementContext,
+    stmt: CreatePublicationStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let CreatePublicationStatement {
+        name,
+        if_not_exists,
+        tables,
+    } = &stmt;
+    scx.require_feature_flag(&vars::ENABLE_LOGICAL_REPLICATION)?;
+
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name.to_owned())?)?;
+    let mut relations = Vec::with_capacity(tables.len());
+    for table in tables {
+        let item = scx.get_item_by_resolved_name(table)?;
+        match item.item_type() {
+            CatalogItemType::Table | CatalogItemType::MaterializedView => {}
+            _ => sql_bail!(
+                "{} is a {}, but only tables and materialized views can be published",
+                scx.catalog.resolve_full_name(item.name()),
+                item.item_type()
+            ),
+        }
+        if item.id().is_system() {
+            sql_bail!(
+                "cannot publish system relation {}",
+                scx.catalog.resolve_full_name(item.name())
+            );
+        }
+        if relations.contains(&item.id()) {
+            sql_bail!(
+                "relation {} is already member of publication {}",
+                scx.catalog
+                    .resolve_full_name(item.name())
+                    .to_string()
+                    .quoted(),
+                name.item.quoted()
+            );
+        }
+        relations.push(item.id());
+    }
+    let create_sql = normalize::create_statement(scx, Statement::CreatePublication(stmt.clone()))?;
+
+    Ok(Plan::CreatePublication(CreatePublicationPlan {
+        name,
+        publication: Publication {
+            create_sql,
+            relations,
+        },
+        if_not_exists: *if_not_exists,
+    }))
+}
+
+pub fn describe_create_notification(
+    _: &StatementContext,
+    _: CreateNotificationStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_notification(
+    scx: &StatementContext,
+    stmt: CreateNotificationStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let CreateNotificationStatement {
+        name,
+        if_not_exists,
+        on,
+        channel,
+        predicate,
+    } = &stmt;
+    scx.require_feature_flag(&vars::ENABLE_NOTIFICATIONS)?;
+
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name.to_owned())?)?;
+    let item = scx.get_item_by_resolved_name(on)?;
+    let on_name = scx.catalog.resolve_full_name(item.name());
+    if item.item_type() != CatalogItemType::MaterializedView {
+        sql_bail!(
+            "{on_name} is a {}, but notifications can only be created on materialized views",
+            item.item_type()
+        );
+    }
+    if let Some(predicate) = predicate {
+        let desc = item.desc(&on_name)?;
+        plan_notification_predicate(scx, predicate, &desc)?;
+    }
+    let create_sql = normalize::create_statement(scx, Statement::CreateNotification(stmt.clone()))?;
+
+    Ok(Plan::CreateNotification(CreateNotificationPlan {
+        name,
+        notification: Notification {
+            create_sql,
+            on: item.id(),
+            channel: channel.clone().into_string(),
+            predicate: predicate
+                .as_ref()
+                .map(|predicate| predicate.to_ast_string_stable()),
+        },
+        if_not_exists: *if_not_exists,
+    }))
+}
+
+pub fn describe_create_function(
+    _: &StatementContext,
+    _: CreateFunctionStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_function(
+    scx: &StatementContext,
+    stmt: CreateFunctionStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let CreateFunctionStatement {
+        name,
+        if_not_exists,
+        parameters,
+        returns,
+        body,
+    } = &stmt;
+    scx.require_feature_flag(&vars::ENABLE_CREATE_FUNCTION)?;
+
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name.to_owned())?)?;
+
+    let mut params = Vec::with_capacity(parameters.len());
+    let mut seen_names = BTreeSet::new();
+    for FunctionParameter { name, data_type } in parameters {
+        let name = name.clone().map(normalize::column_name);
+        if let Some(name) = &name {
+            if !seen_names.insert(name.clone()) {
+                sql_bail!("parameter name {} used more than once", name.quoted());
+            }
+        }
+        params.push(CatalogFuncParam {
+            name,
+            typ: scalar_type_from_sql(scx, data_type)?,
+        });
+    }
+
+    let returns = match returns {
+        FunctionReturns::Scalar(data_type) => {
+            CatalogFuncReturns::Scalar(scalar_type_from_sql(scx, data_type)?)
+        }
+        FunctionReturns::SetOf(data_type) => CatalogFuncReturns::Table(vec![(
+            ColumnName::from(name.item.as_str()),
+            scalar_type_from_sql(scx, data_type)?,
+        )]),
+        FunctionReturns::Table(columns) => {
+            let mut seen_names = BTreeSet::new();
+            let mut out = Vec::with_capacity(columns.len());
+            for FunctionTableColumn { name, data_type } in columns {
+                let name = normalize::column_name(name.clone());
+                if !seen_names.insert(name.clone()) {
+                    sql_bail!("column name {} specified more than once", name.quoted());
+                }
+                out.push((name, scalar_type_from_sql(scx, data_type)?));
+            }
+            CatalogFuncReturns::Table(out)
+        }
+    };
+    let details = CatalogFuncDetails { params, returns };
+
+    // Plan the body now to surface any errors at creation time, rather than
+    // when the function is first called.
+    match body {
+        FunctionBody::Sql(query) => {
+            let qcx = QueryContext::root(scx, QueryLifetime::View);
+            query::plan_user_func_body(&qcx, &details, query)?;
+        }
+        FunctionBody::Wasm(module) => {
+            scx.require_feature_flag(&vars::ENABLE_WASM_FUNCTIONS)?;
+            let full_name = scx.catalog.resolve_full_name(&name).to_string();
+            query::plan_wasm_udf(scx, full_name, &details, module)?
+                .validate()
+                .map_err(|e| sql_err!("invalid WebAssembly module: {}", e))?;
+        }
+    }
+
+    let create_sql = normalize::create_statement(scx, Statement::CreateFunction(stmt.clone()))?;
+
+    Ok(Plan::CreateFunction(CreateFunctionPlan {
+        name,
+        function: Function {
+            create_sql,
+            details,
+        },
+        if_not_exists: *if_not_exists,
+    }))
+}
+
 pub fn describe_create_connection(
     _: &StatementContext,
     _: CreateConnectionStatement<Aug>,
@@ -5281,11 +5687,6 @@ pub fn plan_drop_objects(
         cascade,
     }: DropObjectsStatement,
 ) -> Result<Plan, PlanError> {
-    assert_ne!(
-        object_type,
-        mz_sql_parser::ast::ObjectType::Func,
-        "rejected in parser"
-    );
     let object_type = object_type.into();
 
     let mut referenced_ids = Vec::new();
@@ -5531,7 +5932,9 @@ fn dependency_prevents_drop(object_type: ObjectType, dep: &dyn CatalogItem) -> b
             | CatalogItemType::Type
             | CatalogItemType::Secret
             | CatalogItemType::Connection
-            | CatalogItemType::ContinualTask => true,
+            | CatalogItemType::ContinualTask
+            | CatalogItemType::Publication
+            | CatalogItemType::Notification => true,
             CatalogItemType::Index => false,
         },
     }
@@ -5985,6 +6388,8 @@ pub fn plan_alter_cluster(
             with_options,
         } => {
             let ClusterOptionExtracted {
+                arrangement_spill,
+                auto_index,
                 availability_zones,
                 introspection_debugging,
                 introspection_interval,
@@ -6192,6 +6597,14 @@ pub fn plan_alter_cluster(
             if let Some(workload_class) = workload_class {
                 options.workload_class = AlterOptionParameter::Set(workload_class.0);
             }
+            if let Some(arrangement_spill) = arrangement_spill {
+                scx.require_feature_flag(&vars::ENABLE_ARRANGEMENT_SPILL)?;
+                options.arrangement_spill = AlterOptionParameter::Set(arrangement_spill);
+            }
+            if let Some(auto_index) = auto_index {
+                scx.require_feature_flag(&vars::ENABLE_AUTO_INDEX)?;
+                options.auto_index = AlterOptionParameter::Set(auto_index);
+            }
         }
         AlterClusterAction::ResetOptions(reset_options) => {
             use AlterOptionParameter::Reset;
@@ -6205,6 +6618,8 @@ pub fn plan_alter_cluster(
 
             for option in reset_options {
                 match option {
+                    ArrangementSpill => options.arrangement_spill = Reset,
+                    AutoIndex => options.auto_index = Reset,
                     AvailabilityZones => options.availability_zones = Reset,
                     Disk => options.disk = Reset,
                     IntrospectionInterval => options.introspection_interval = Reset,
@@ -6317,7 +6732,9 @@ pub fn plan_alter_object_rename(
             | ObjectType::Index
             | ObjectType::Sink
             | ObjectType::Secret
-            | ObjectType::Connection,
+            | ObjectType::Connection
+            | ObjectType::Publication
+            | ObjectType::Notification,
             UnresolvedObjectName::Item(name),
         ) => plan_alter_item_rename(scx, object_type, name, to_item_name, if_exists),
         (ObjectType::Cluster, UnresolvedObjectName::Cluster(name)) => {
@@ -7261,6 +7678,130 @@ pub fn plan_alter_table_add_column(
     }))
 }
 
+pub fn describe_alter_type_add_value(
+    _: &StatementContext,
+    _: AlterTypeAddValueStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_type_add_value(
+    scx: &StatementContext,
+    stmt: AlterTypeAddValueStatement,
+) -> Result<Plan, PlanError> {
+    let AlterTypeAddValueStatement {
+        name,
+        if_not_exists,
+        value,
+        position,
+    } = stmt;
+
+    scx.require_feature_flag(&vars::ENABLE_ENUM_AND_DOMAIN_TYPES)?;
+
+    let item =
+        resolve_item_or_type(scx, ObjectType::Type, name, false)?.expect("if_exists is false");
+    let type_name = scx.catalog.resolve_full_name(item.name());
+    let mut labels = match item.type_details() {
+        Some(CatalogTypeDetails {
+            typ: CatalogType::Enum { labels },
+            ..
+        }) => labels.clone(),
+        _ => sql_bail!("{} is not an enum", type_name.to_string().quoted()),
+    };
+
+    validate_enum_label(&value)?;
+    if labels.contains(&value) {
+        if if_not_exists {
+            scx.catalog
+                .add_notice(PlanNotice::EnumLabelAlreadyExists { label: value });
+            return Ok(Plan::AlterNoop(AlterNoopPlan {
+                object_type: ObjectType::Type,
+            }));
+        } else {
+            sql_bail!("enum label {} already exists", value.quoted());
+        }
+    }
+
+    let index = match &position {
+        None => labels.len(),
+        Some(
+            AlterTypeAddValuePosition::Before(neighbor)
+            | AlterTypeAddValuePosition::After(neighbor),
+        ) => {
+            let Some(index) = labels.iter().position(|l| l == neighbor) else {
+                sql_bail!("{} is not an existing enum label", neighbor.quoted());
+            };
+            if matches!(position, Some(AlterTypeAddValuePosition::After(_))) {
+                index + 1
+            } else {
+                index
+            }
+        }
+    };
+
+    // Enum values are stored as the position of their label, so inserting a
+    // label before an existing one changes the meaning of stored values.
+    // Appending a label leaves existing values intact, so it is allowed even
+    // when other objects depend on the type.
+    if index < labels.len() {
+        if let Some(dependent) = item.used_by().first() {
+            let dependent = scx.catalog.get_item(dependent);
+            sql_bail!(
+                "cannot add value to enum {} before an existing label because {} {} depends on it",
+                type_name.to_string().quoted(),
+                dependent.item_type(),
+                scx.catalog
+                    .resolve_full_name(dependent.name())
+                    .to_string()
+                    .quoted(),
+            );
+        }
+    }
+
+    // Dependents are replanned with the new labels, but running dataflows and
+    // sinks keep the labels they were rendered with, and temporary items
+    // cannot be replanned outside of their session. Neither would be able to
+    // format the new label, so only allow appending while none of them depends
+    // on the type, directly or through views.
+    let mut todo = item.used_by().to_vec();
+    let mut seen = BTreeSet::new();
+    while let Some(id) = todo.pop() {
+        if !seen.insert(id) {
+            continue;
+        }
+        let dependent = scx.catalog.get_item(&id);
+        let is_dataflow = matches!(
+            dependent.item_type(),
+            CatalogItemType::MaterializedView
+                | CatalogItemType::Index
+                | CatalogItemType::Sink
+                | CatalogItemType::ContinualTask
+        );
+        let is_temporary = matches!(
+            dependent.name().qualifiers.schema_spec,
+            SchemaSpecifier::Temporary
+        );
+        if is_dataflow || is_temporary {
+            sql_bail!(
+                "cannot add value to enum {} because {} {} depends on it",
+                type_name.to_string().quoted(),
+                dependent.item_type(),
+                scx.catalog
+                    .resolve_full_name(dependent.name())
+                    .to_string()
+                    .quoted(),
+            );
+        }
+        todo.extend(dependent.used_by());
+    }
+    labels.insert(index, value);
+
+    Ok(Plan::AlterTypeAddValue(AlterTypeAddValuePlan {
+        id: item.id(),
+        labels,
+    }))
+}
+
 pub fn describe_comment(
     _: &StatementContext,
     _: CommentStatement<Aug>,
@@ -7296,7 +7837,9 @@ pub fn plan_comment(
         | com_ty @ CommentObjectType::Source { name }
         | com_ty @ CommentObjectType::Sink { name }
         | com_ty @ CommentObjectType::Secret { name }
-        | com_ty @ CommentObjectType::ContinualTask { name } => {
+        | com_ty @ CommentObjectType::ContinualTask { name }
+        | com_ty @ CommentObjectType::Publication { name }
+        | com_ty @ CommentObjectType::Notification { name } => {
             let item = scx.get_item_by_resolved_name(name)?;
             match (com_ty, item.item_type()) {
                 (CommentObjectType::Table { .. }, CatalogItemType::Table) => {
@@ -7329,6 +7872,12 @@ pub fn plan_comment(
                 (CommentObjectType::ContinualTask { .. }, CatalogItemType::ContinualTask) => {
                     (CommentObjectId::ContinualTask(item.id()), None)
                 }
+                (CommentObjectType::Publication { .. }, CatalogItemType::Publication) => {
+                    (CommentObjectId::Publication(item.id()), None)
+                }
+                (CommentObjectType::Notification { .. }, CatalogItemType::Notification) => {
+                    (CommentObjectId::Notification(item.id()), None)
+                }
                 (com_ty, cat_ty) => {
                     let expected_type = match com_ty {
                         CommentObjectType::Table { .. } => ObjectType::Table,
@@ -7340,6 +7889,9 @@ pub fn plan_comment(
                         CommentObjectType::Source { .. } => ObjectType::Source,
                         CommentObjectType::Sink { .. } => ObjectType::Sink,
                         CommentObjectType::Secret { .. } => ObjectType::Secret,
+                        CommentObjectType::ContinualTask { .. } => ObjectType::ContinualTask,
+                        CommentObjectType::Publication { .. } => ObjectType::Publication,
+                        CommentObjectType::Notification { .. } => ObjectType::Notification,
                         _ => unreachable!("these are the only types we match on"),
                     };
 
@@ -7501,6 +8053,7 @@ pub(crate) fn resolve_item_or_type<'a>(
     let name = normalize::unresolved_item_name(name)?;
     let catalog_item = match object_type {
         ObjectType::Type => scx.catalog.resolve_type(&name),
+        ObjectType::Func => scx.catalog.resolve_function(&name),
         _ => scx.catalog.resolve_item(&name),
     };
 
@@ -20,6 +20,7 @@ use itertools::Itertools;
 use mz_arrow_util::builder::ArrowBuilder;
 use mz_expr::visit::Visit;
 use mz_expr::{MirRelationExpr, RowSetFinishing};
+use mz_ore::collections::CollectionExt;
 use mz_ore::num::NonNeg;
 use mz_ore::soft_panic_or_log;
 use mz_pgcopy::{CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams};
@@ -27,11 +28,12 @@ use mz_repr::adt::numeric::NumericMaxScale;
 use mz_repr::bytes::ByteSize;
 use mz_repr::explain::{ExplainConfig, ExplainFormat};
 use mz_repr::optimize::OptimizerFeatureOverrides;
-use mz_repr::{CatalogItemId, Datum, RelationDesc, ScalarType};
+use mz_repr::{CatalogItemId, Datum, GlobalId, RelationDesc, ScalarType};
 use mz_sql_parser::ast::{
-    CteBlock, ExplainPlanOption, ExplainPlanOptionName, ExplainPushdownStatement,
-    ExplainSinkSchemaFor, ExplainSinkSchemaStatement, ExplainTimestampStatement, Expr,
-    IfExistsBehavior, OrderByExpr, SetExpr, SubscribeOutput, UnresolvedItemName,
+    CteBlock, ExplainAnalyzeComputationProperty, ExplainAnalyzeProperty, ExplainAnalyzeStatement,
+    ExplainPlanOption, ExplainPlanOptionName, ExplainPushdownStatement, ExplainSinkSchemaFor,
+    ExplainSinkSchemaStatement, ExplainTimestampStatement, Expr, IfExistsBehavior, OrderByExpr,
+    SetExpr, Statement, SubscribeOutput, UnresolvedItemName, Value,
 };
 use mz_sql_parser::ident;
 use mz_storage_types::sinks::{
@@ -46,9 +48,10 @@ use crate::ast::{
     SelectStatement, SubscribeOption, SubscribeOptionName, SubscribeRelation, SubscribeStatement,
     UpdateStatement,
 };
-use crate::catalog::CatalogItemType;
-use crate::names::{Aug, ResolvedItemName};
+use crate::catalog::{CatalogCollectionItem, CatalogItemType};
+use crate::names::{self, Aug, ResolvedItemName};
 use crate::normalize;
+use crate::parse;
 use crate::plan::query::{ExprContext, QueryLifetime, offset_into_value, plan_expr, plan_up_to};
 use crate::plan::scope::Scope;
 use crate::plan::statement::{StatementContext, StatementDesc, ddl};
@@ -323,6 +326,10 @@ pub fn describe_explain_plan(
             let name = "Plan Insights";
             relation_desc = relation_desc.with_column(name, ScalarType::String.nullable(false));
         }
+        ExplainStage::PlanDiff => {
+            let name = "Plan Diff";
+            relation_desc = relation_desc.with_column(name, ScalarType::String.nullable(false));
+        }
     };
     let relation_desc = relation_desc.finish();
 
@@ -354,6 +361,15 @@ pub fn describe_explain_pushdown(
     )
 }
 
+pub fn describe_explain_analyze(
+    scx: &StatementContext,
+    statement: ExplainAnalyzeStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    let item = explain_analyze_item(scx, &statement.explainee)?;
+    let select = explain_analyze_select(scx, item.global_id(), &statement.properties)?;
+    describe_select(scx, select)
+}
+
 pub fn describe_explain_timestamp(
     scx: &StatementContext,
     ExplainTimestampStatement { select, .. }: ExplainTimestampStatement<Aug>,
@@ -471,6 +487,10 @@ impl TryFrom<ExplainPlanOptionExtracted> for ExplainConfig {
                     .enable_projection_pushdown_after_relation_cse,
                 enable_less_reduce_in_eqprop: Default::default(),
                 enable_dequadratic_eqprop_map: Default::default(),
+                enable_ordered_top_k: Default::default(),
+                enable_join_elimination: Default::default(),
+                enable_join_size_estimates: Default::default(),
+                enable_spatial_join: Default::default(),
             },
         })
     }
@@ -619,7 +639,19 @@ pub fn plan_explain_plan(
         ExplainConfig::try_from(with_options)?
     };
 
-    let explainee = plan_explainee(scx, explain.explainee, params)?;
+    // `EXPLAIN PLAN DIFF` compares the stored plan of a materialized view
+    // against a fresh re-optimization, so we plan it as a `REPLAN` explainee.
+    let explainee = if stage == ExplainStage::PlanDiff {
+        let Explainee::MaterializedView(name) = explain.explainee else {
+            sql_bail!("EXPLAIN PLAN DIFF is only supported for materialized views");
+        };
+        if format != ExplainFormat::Text {
+            sql_bail!("EXPLAIN PLAN DIFF only supports TEXT format");
+        }
+        plan_explainee(scx, Explainee::ReplanMaterializedView(name), params)?
+    } else {
+        plan_explainee(scx, explain.explainee, params)?
+    };
 
     Ok(Plan::ExplainPlan(ExplainPlanPlan {
         stage,
@@ -702,6 +734,207 @@ pub fn plan_explain_pushdown(
     Ok(Plan::ExplainPushdown(ExplainPushdownPlan { explainee }))
 }
 
+/// Plans an `EXPLAIN ANALYZE` statement as a `SELECT` over the introspection
+/// relations of the explainee's dataflow.
+///
+/// Every LIR node of the dataflow is recorded in `mz_lir_mapping` with the
+/// range of dataflow operators that implement it, and these ranges are joined
+/// with the per-operator arrangement sizes, scheduling times, and hints.
+pub fn plan_explain_analyze(
+    scx: &StatementContext,
+    statement: ExplainAnalyzeStatement<Aug>,
+    params: &Params,
+) -> Result<Plan, PlanError> {
+    let item = explain_analyze_item(scx, &statement.explainee)?;
+
+    // Introspection relations only report on the dataflows of the active
+    // cluster.
+    let cluster_id = item
+        .cluster_id()
+        .expect("indexes and materialized views have a cluster");
+    let active_cluster = scx.resolve_cluster(None)?;
+    if cluster_id != active_cluster.id() {
+        let cluster = scx.catalog.get_cluster(cluster_id);
+        sql_bail!(
+            "cannot EXPLAIN ANALYZE {} because it runs on cluster {}, \
+             but the active cluster is {}",
+            statement.explainee,
+            cluster.name(),
+            active_cluster.name(),
+        );
+    }
+
+    let select = explain_analyze_select(scx, item.global_id(), &statement.properties)?;
+    plan_select(scx, select, params, None)
+}
+
+/// Resolves the index or materialized view named by an `EXPLAIN ANALYZE`
+/// statement.
+fn explain_analyze_item<'a>(
+    scx: &'a StatementContext,
+    explainee: &Explainee<Aug>,
+) -> Result<Box<dyn CatalogCollectionItem + 'a>, PlanError> {
+    let (name, expected_type, expected) = match explainee {
+        Explainee::Index(name) => (name, CatalogItemType::Index, "an index"),
+        Explainee::MaterializedView(name) => (
+            name,
+            CatalogItemType::MaterializedView,
+            "a materialized view",
+        ),
+        _ => sql_bail!("EXPLAIN ANALYZE is only supported for indexes and materialized views"),
+    };
+    let item = scx.get_item_by_resolved_name(name)?;
+    let item_type = item.item_type();
+    if item_type != expected_type {
+        sql_bail!("Expected {name} to be {expected}, not a {item_type}");
+    }
+    Ok(item)
+}
+
+/// Builds the `SELECT` statement that reports `properties` for the LIR nodes of
+/// the dataflow that exports `global_id`.
+///
+/// A dataflow can contain the plans of several objects, like the view an index
+/// is built on, so its LIR nodes are found through all global IDs of the
+/// dataflow rather than through `global_id` alone. Names are not used, as
+/// dataflow names do not quote the names of the objects they are built for.
+/// Nodes are listed from the root of the plan down, indented by their nesting
+/// level. Skew is the ratio between the largest and the average value across
+/// the workers that report one.
+fn explain_analyze_select(
+    scx: &StatementContext,
+    global_id: GlobalId,
+    properties: &ExplainAnalyzeProperty,
+) -> Result<SelectStatement<Aug>, PlanError> {
+    let mut ctes = vec![format!(
+        "lir AS (
+    SELECT lir_id, operator, nesting, operator_id_start, operator_id_end
+    FROM mz_introspection.mz_dataflow_global_ids
+    JOIN mz_introspection.mz_lir_mapping USING (global_id)
+    WHERE id IN (
+        SELECT id FROM mz_introspection.mz_dataflow_global_ids WHERE global_id = {}
+    )
+)",
+        Value::String(global_id.to_string())
+    )];
+    let mut columns =
+        vec!["pg_catalog.repeat(' ', 2 * nesting::int4) || operator AS operator".to_string()];
+    let mut joins = vec![];
+
+    match properties {
+        ExplainAnalyzeProperty::Computation { properties, skew } => {
+            let properties = if properties.is_empty() {
+                &[
+                    ExplainAnalyzeComputationProperty::Memory,
+                    ExplainAnalyzeComputationProperty::Cpu,
+                ][..]
+            } else {
+                &properties[..]
+            };
+            for property in properties.iter().unique() {
+                match property {
+                    ExplainAnalyzeComputationProperty::Memory => {
+                        ctes.push(
+                            "memory_per_worker AS (
+    SELECT lir_id, worker_id, pg_catalog.sum(size) AS size, pg_catalog.sum(records) AS records
+    FROM lir JOIN mz_introspection.mz_arrangement_sizes_per_worker mas
+    ON lir.operator_id_start <= mas.operator_id AND mas.operator_id < lir.operator_id_end
+    GROUP BY lir_id, worker_id
+)"
+                            .into(),
+                        );
+                        ctes.push(
+                            "memory AS (
+    SELECT
+        lir_id,
+        pg_catalog.sum(size) AS total_size,
+        pg_catalog.sum(records) AS total_records,
+        pg_catalog.max(size) / NULLIF(pg_catalog.avg(size), 0) AS size_skew,
+        pg_catalog.max(records) / NULLIF(pg_catalog.avg(records), 0) AS records_skew
+    FROM memory_per_worker
+    GROUP BY lir_id
+)"
+                            .into(),
+                        );
+                        columns.push(
+                            "pg_catalog.pg_size_pretty(memory.total_size) AS total_memory".into(),
+                        );
+                        columns.push("memory.total_records AS total_records".into());
+                        if *skew {
+                            columns.push(
+                                "pg_catalog.round(memory.size_skew, 2) AS memory_skew".into(),
+                            );
+                            columns.push(
+                                "pg_catalog.round(memory.records_skew, 2) AS records_skew".into(),
+                            );
+                        }
+                        joins.push("LEFT JOIN memory USING (lir_id)");
+                    }
+                    ExplainAnalyzeComputationProperty::Cpu => {
+                        ctes.push(
+                            "cpu_per_worker AS (
+    SELECT lir_id, worker_id, pg_catalog.sum(elapsed_ns) AS elapsed_ns
+    FROM lir JOIN mz_introspection.mz_scheduling_elapsed_per_worker mse
+    ON lir.operator_id_start <= mse.id AND mse.id < lir.operator_id_end
+    GROUP BY lir_id, worker_id
+)"
+                            .into(),
+                        );
+                        ctes.push(
+                            "cpu AS (
+    SELECT
+        lir_id,
+        pg_catalog.sum(elapsed_ns) AS total_elapsed_ns,
+        pg_catalog.max(elapsed_ns) / NULLIF(pg_catalog.avg(elapsed_ns), 0) AS elapsed_skew
+    FROM cpu_per_worker
+    GROUP BY lir_id
+)"
+                            .into(),
+                        );
+                        columns.push(
+                            "(cpu.total_elapsed_ns / 1000)::float8 * INTERVAL '1 microsecond' \
+                             AS total_elapsed"
+                                .into(),
+                        );
+                        if *skew {
+                            columns
+                                .push("pg_catalog.round(cpu.elapsed_skew, 2) AS cpu_skew".into());
+                        }
+                        joins.push("LEFT JOIN cpu USING (lir_id)");
+                    }
+                }
+            }
+        }
+        ExplainAnalyzeProperty::Hints => {
+            columns.extend([
+                "advice.levels AS levels".to_string(),
+                "advice.to_cut AS to_cut".to_string(),
+                "advice.hint AS hint".to_string(),
+                "pg_catalog.pg_size_pretty(advice.savings) AS savings".to_string(),
+            ]);
+            joins.push(
+                "LEFT JOIN mz_introspection.mz_expected_group_size_advice advice
+ON lir.operator_id_start <= advice.region_id AND advice.region_id < lir.operator_id_end",
+            );
+        }
+    }
+
+    let query = format!(
+        "WITH {}\nSELECT {}\nFROM lir {}\nORDER BY lir_id DESC",
+        ctes.join(",\n"),
+        columns.join(", "),
+        joins.join(" "),
+    );
+    let stmts = parse::parse(&query).expect("EXPLAIN ANALYZE generated invalid SQL");
+    let stmt = match stmts.into_element().ast {
+        Statement::Select(select) => select,
+        _ => panic!("EXPLAIN ANALYZE generated a non-SELECT statement"),
+    };
+    let (mut stmt, _) = names::resolve(scx.catalog, stmt)?;
+    transform_ast::transform(scx, &mut stmt)?;
+    Ok(stmt)
+}
+
 pub fn plan_explain_timestamp(
     scx: &StatementContext,
     explain: ExplainTimestampStatement<Aug>,
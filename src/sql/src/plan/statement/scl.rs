@@ -20,15 +20,16 @@ use uncased::UncasedStr;
 use crate::ast::display::AstDisplay;
 use crate::ast::{
     CloseStatement, DeallocateStatement, DeclareStatement, DiscardStatement, DiscardTarget,
-    ExecuteStatement, FetchOption, FetchOptionName, FetchStatement, PrepareStatement,
-    ResetVariableStatement, SetVariableStatement, SetVariableTo, ShowVariableStatement,
+    ExecuteStatement, FetchOption, FetchOptionName, FetchStatement, ListenStatement,
+    PrepareStatement, ResetVariableStatement, SetVariableStatement, SetVariableTo,
+    ShowVariableStatement, UnlistenStatement,
 };
 use crate::names::{self, Aug};
 use crate::plan::statement::{StatementContext, StatementDesc};
 use crate::plan::{
     ClosePlan, DeallocatePlan, DeclarePlan, ExecutePlan, ExecuteTimeout, FetchPlan,
-    InspectShardPlan, Params, Plan, PlanError, PreparePlan, ResetVariablePlan, SetVariablePlan,
-    ShowVariablePlan, VariableValue, describe, query,
+    InspectShardPlan, ListenPlan, Params, Plan, PlanError, PreparePlan, ResetVariablePlan,
+    SetVariablePlan, ShowVariablePlan, UnlistenPlan, VariableValue, describe, query,
 };
 use crate::session::vars;
 use crate::session::vars::{IsolationLevel, SCHEMA_ALIAS, TRANSACTION_ISOLATION_VAR_NAME};
@@ -177,6 +178,38 @@ pub fn plan_discard(
     }
 }
 
+pub fn describe_listen(
+    _: &StatementContext,
+    _: ListenStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_listen(
+    _: &StatementContext,
+    ListenStatement { channel }: ListenStatement,
+) -> Result<Plan, PlanError> {
+    Ok(Plan::Listen(ListenPlan {
+        channel: channel.into_string(),
+    }))
+}
+
+pub fn describe_unlisten(
+    _: &StatementContext,
+    _: UnlistenStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_unlisten(
+    _: &StatementContext,
+    UnlistenStatement { channel }: UnlistenStatement,
+) -> Result<Plan, PlanError> {
+    Ok(Plan::Unlisten(UnlistenPlan {
+        channel: channel.map(|channel| channel.into_string()),
+    }))
+}
+
 pub fn describe_declare(
     scx: &StatementContext,
     DeclareStatement { stmt, .. }: DeclareStatement<Aug>,
@@ -22,9 +22,9 @@ use mz_repr::{CatalogItemId, Datum, RelationDesc, Row, ScalarType};
 use mz_sql_parser::ast::display::{AstDisplay, FormatMode};
 use mz_sql_parser::ast::{
     CreateSubsourceOptionName, ExternalReferenceExport, ExternalReferences, ObjectType,
-    ShowCreateClusterStatement, ShowCreateConnectionStatement, ShowCreateMaterializedViewStatement,
-    ShowObjectType, SqlServerConfigOptionName, SystemObjectType, UnresolvedItemName,
-    WithOptionValue,
+    ShowCreateClusterStatement, ShowCreateConnectionStatement, ShowCreateFunctionStatement,
+    ShowCreateMaterializedViewStatement, ShowObjectType, SqlServerConfigOptionName,
+    SystemObjectType, UnresolvedItemName, WithOptionValue,
 };
 use mz_sql_pretty::PrettyConfig;
 use query::QueryContext;
@@ -120,7 +120,7 @@ fn plan_show_create_item(
     if item.id().is_system()
         && matches!(
             expect_type,
-            CatalogItemType::Table | CatalogItemType::Source
+            CatalogItemType::Table | CatalogItemType::Source | CatalogItemType::Func
         )
     {
         sql_bail!("cannot show create for system object {name}");
@@ -265,6 +265,28 @@ pub fn plan_show_create_connection(
     plan_show_create_item(scx, &connection_name, CatalogItemType::Connection, redacted)
 }
 
+pub fn describe_show_create_function(
+    _: &StatementContext,
+    _: ShowCreateFunctionStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(Some(
+        RelationDesc::builder()
+            .with_column("name", ScalarType::String.nullable(false))
+            .with_column("create_sql", ScalarType::String.nullable(false))
+            .finish(),
+    )))
+}
+
+pub fn plan_show_create_function(
+    scx: &StatementContext,
+    ShowCreateFunctionStatement {
+        function_name,
+        redacted,
+    }: ShowCreateFunctionStatement<Aug>,
+) -> Result<ShowCreatePlan, PlanError> {
+    plan_show_create_item(scx, &function_name, CatalogItemType::Func, redacted)
+}
+
 pub fn show_databases<'a>(
     scx: &'a StatementContext<'a>,
     filter: Option<ShowStatementFilter<Aug>>,
@@ -382,6 +404,8 @@ pub fn show_objects<'a>(
             assert_none!(from, "parser should reject from");
             show_network_policies(scx, filter)
         }
+        ShowObjectType::Publication => show_publications(scx, from, filter),
+        ShowObjectType::Notification => show_notifications(scx, from, filter),
     }
 }
 
@@ -678,7 +702,9 @@ pub fn show_columns<'a>(
         | ty @ CatalogItemType::Func
         | ty @ CatalogItemType::Secret
         | ty @ CatalogItemType::Type
-        | ty @ CatalogItemType::Sink => {
+        | ty @ CatalogItemType::Sink
+        | ty @ CatalogItemType::Publication
+        | ty @ CatalogItemType::Notification => {
             sql_bail!("{full_name} is a {ty} and so does not have columns");
         }
     }
@@ -755,6 +781,44 @@ pub fn show_secrets<'a>(
     ShowSelect::new(scx, query, filter, None, Some(&["name", "comment"]))
 }
 
+pub fn show_publications<'a>(
+    scx: &'a StatementContext<'a>,
+    from: Option<ResolvedSchemaName>,
+    filter: Option<ShowStatementFilter<Aug>>,
+) -> Result<ShowSelect<'a>, PlanError> {
+    let schema_spec = scx.resolve_optional_schema(&from)?;
+
+    let query = format!(
+        "SELECT name, comment
+        FROM mz_internal.mz_show_publications
+        WHERE schema_id = '{schema_spec}'",
+    );
+
+    ShowSelect::new(scx, query, filter, None, Some(&["name", "comment"]))
+}
+
+pub fn show_notifications<'a>(
+    scx: &'a StatementContext<'a>,
+    from: Option<ResolvedSchemaName>,
+    filter: Option<ShowStatementFilter<Aug>>,
+) -> Result<ShowSelect<'a>, PlanError> {
+    let schema_spec = scx.resolve_optional_schema(&from)?;
+
+    let query = format!(
+        "SELECT name, \"on\", channel, comment
+        FROM mz_internal.mz_show_notifications
+        WHERE schema_id = '{schema_spec}'",
+    );
+
+    ShowSelect::new(
+        scx,
+        query,
+        filter,
+        None,
+        Some(&["name", "on", "channel", "comment"]),
+    )
+}
+
 pub fn show_privileges<'a>(
     scx: &'a StatementContext<'a>,
     object_type: Option<SystemObjectType>,
@@ -546,6 +546,7 @@ static VALID_CASTS: LazyLock<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl
 
             // BYTES
             (Bytes, String) => Assignment: CastBytesToString(func::CastBytesToString),
+            (Bytes, Geometry) => Explicit: CastBytesToGeometry(func::CastBytesToGeometry),
 
             // STRING
             (String, Bool) => Explicit: CastStringToBool(func::CastStringToBool),
@@ -594,6 +595,9 @@ static VALID_CASTS: LazyLock<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl
             (String, Bytes) => Explicit: CastStringToBytes(func::CastStringToBytes),
             (String, Jsonb) => Explicit: CastStringToJsonb(func::CastStringToJsonb),
             (String, Uuid) => Explicit: CastStringToUuid(func::CastStringToUuid),
+            (String, TsVector) => Explicit: CastStringToTsVector(func::CastStringToTsVector),
+            (String, TsQuery) => Explicit: CastStringToTsQuery(func::CastStringToTsQuery),
+            (String, Geometry) => Explicit: CastStringToGeometry(func::CastStringToGeometry),
             (String, Array) => Explicit: CastTemplate::new(|ecx, ccx, from_type, to_type| {
                 let return_ty = to_type.clone();
                 let to_el_type = to_type.unwrap_array_element_type();
@@ -621,6 +625,10 @@ static VALID_CASTS: LazyLock<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl
                     cast_expr: Box::new(cast_expr),
                 })))
             }),
+            (String, Enum) => Explicit: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
+                let return_ty = to_type.clone();
+                Some(|e: HirScalarExpr| e.call_unary(CastStringToEnum(func::CastStringToEnum { return_ty })))
+            }),
             (String, Range) => Explicit: CastTemplate::new(|ecx, ccx, from_type, to_type| {
                 let return_ty = to_type.clone();
                 let to_el_type = to_type.unwrap_range_element_type();
@@ -631,6 +639,10 @@ static VALID_CASTS: LazyLock<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl
                 })))
             }),
             (String, Int2Vector) => Explicit: CastStringToInt2Vector(func::CastStringToInt2Vector),
+            (String, Vector) => Explicit: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
+                let dimensions = to_type.unwrap_vector_dimensions();
+                Some(move |e: HirScalarExpr| e.call_unary(CastStringToVector(func::CastStringToVector { dimensions })))
+            }),
             (String, Char) => Implicit: CastTemplate::new(|_ecx, ccx, _from_type, to_type| {
                 let length = to_type.unwrap_char_length();
                 Some(move |e: HirScalarExpr| e.call_unary(CastStringToChar(func::CastStringToChar {length, fail_on_len: ccx != CastContext::Explicit})))
@@ -727,6 +739,30 @@ static VALID_CASTS: LazyLock<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl
                 Some(move |e: HirScalarExpr| e.call_unary(CastArrayToArray(func::CastArrayToArray { return_ty, cast_expr: Box::new(cast_expr) })))
             }),
 
+            // Like pgvector, arrays of integers and floating-point numbers
+            // convert to vectors.
+            (Array, Vector) => Assignment: CastTemplate::new(|ecx, ccx, from_type, to_type| {
+                let dimensions = to_type.unwrap_vector_dimensions();
+                let from_el_type = from_type.unwrap_array_element_type();
+                let cast_expr = match from_el_type {
+                    ScalarType::Float32 => None,
+                    ScalarType::Int32 | ScalarType::Float64 | ScalarType::Numeric { .. } => {
+                        Some(plan_hypothetical_cast(ecx, ccx, from_el_type, &ScalarType::Float32)?)
+                    }
+                    _ => return None,
+                };
+                Some(move |e: HirScalarExpr| {
+                    let e = match cast_expr {
+                        Some(cast_expr) => e.call_unary(CastArrayToArray(func::CastArrayToArray {
+                            return_ty: ScalarType::Array(Box::new(ScalarType::Float32)),
+                            cast_expr: Box::new(cast_expr),
+                        })),
+                        None => e,
+                    };
+                    e.call_unary(CastArrayToVector(func::CastArrayToVector { dimensions }))
+                })
+            }),
+
             // INT2VECTOR
             (Int2Vector, Array) => Implicit: CastTemplate::new(|_ecx, _ccx, _from_type, _to_type| {
                 Some(|e: HirScalarExpr| e.call_unary(UnaryFunc::CastInt2VectorToArray(func::CastInt2VectorToArray)))
@@ -763,6 +799,12 @@ static VALID_CASTS: LazyLock<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl
                 Some(|e: HirScalarExpr| e.call_unary(CastMapToString(func::CastMapToString { ty })))
             }),
 
+            // ENUM
+            (Enum, String) => Assignment: CastTemplate::new(|_ecx, _ccx, from_type, _to_type| {
+                let ty = from_type.clone();
+                Some(|e: HirScalarExpr| e.call_unary(CastEnumToString(func::CastEnumToString { ty })))
+            }),
+
             // JSONB
             (Jsonb, Bool) => Explicit: CastJsonbToBool(func::CastJsonbToBool),
             (Jsonb, Int16) => Explicit: CastJsonbToInt16(func::CastJsonbToInt16),
@@ -779,6 +821,29 @@ static VALID_CASTS: LazyLock<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl
             // UUID
             (Uuid, String) => Assignment: CastUuidToString(func::CastUuidToString),
 
+            // TSVECTOR
+            (TsVector, String) => Assignment: CastTsVectorToString(func::CastTsVectorToString),
+
+            // VECTOR
+            (Vector, String) => Assignment: CastVectorToString(func::CastVectorToString),
+            (Vector, Vector) => Implicit: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
+                let dimensions = to_type.unwrap_vector_dimensions();
+                Some(move |e: HirScalarExpr| e.call_unary(CastVectorToVector(func::CastVectorToVector { dimensions })))
+            }),
+            (Vector, Array) => Implicit: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
+                if to_type.unwrap_array_element_type() != &ScalarType::Float32 {
+                    return None;
+                }
+                Some(|e: HirScalarExpr| e.call_unary(CastVectorToArray(func::CastVectorToArray)))
+            }),
+
+            // TSQUERY
+            (TsQuery, String) => Assignment: CastTsQueryToString(func::CastTsQueryToString),
+
+            // GEOMETRY
+            (Geometry, String) => Assignment: CastGeometryToString(func::CastGeometryToString),
+            (Geometry, Bytes) => Explicit: CastGeometryToBytes(func::CastGeometryToBytes),
+
             // Numeric
             (Numeric, Numeric) => Assignment: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
                 let scale = to_type.unwrap_numeric_max_scale();
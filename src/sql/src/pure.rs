@@ -255,6 +255,11 @@ pub enum PurifiedExportDetails {
     Postgres {
         table: PostgresTableDesc,
         text_columns: Option<Vec<Ident>>,
+        /// The upstream enum types used by the table's columns, keyed by OID.
+        enum_types: BTreeMap<u32, postgres::PostgresEnumType>,
+        /// The OIDs of the upstream PostGIS `geometry` types used by the
+        /// table's columns.
+        geometry_types: BTreeSet<u32>,
     },
     SqlServer {
         table: SqlServerTableDesc,
@@ -900,6 +905,7 @@ async fn purify_create_source(
                 text_columns,
                 source_name,
                 &reference_policy,
+                scx.catalog.system_vars().enable_pg_source_enum_types(),
             )
             .await?;
 
@@ -1400,6 +1406,8 @@ async fn purify_alter_source(
                 ))?;
             }
 
+            let map_enum_types = scx.catalog.system_vars().enable_pg_source_enum_types();
+
             purify_alter_source_add_subsources(
                 external_references,
                 options,
@@ -1408,6 +1416,7 @@ async fn purify_alter_source(
                 unresolved_source_name,
                 resolved_source_name,
                 storage_configuration,
+                map_enum_types,
             )
             .await
         }
@@ -1439,6 +1448,7 @@ async fn purify_alter_source_add_subsources(
     unresolved_source_name: UnresolvedItemName,
     resolved_source_name: ResolvedItemName,
     storage_configuration: &StorageConfiguration,
+    map_enum_types: bool,
 ) -> Result<PurifiedStatement, PlanError> {
     // Validate this is a source that can have subsources added.
     match desc.connection {
@@ -1524,6 +1534,7 @@ async fn purify_alter_source_add_subsources(
                 text_columns,
                 &unresolved_source_name,
                 &SourceReferencePolicy::Required,
+                map_enum_types,
             )
             .await?;
 
@@ -1911,6 +1922,7 @@ async fn purify_create_table_from_source(
                 qualified_text_columns,
                 &unresolved_source_name,
                 &SourceReferencePolicy::Required,
+                scx.catalog.system_vars().enable_pg_source_enum_types(),
             )
             .await?;
             // There should be exactly one source_export returned for this statement
@@ -28,9 +28,11 @@ use prost::Message;
 use tokio_postgres::Client;
 use tokio_postgres::types::Oid;
 
-use crate::names::{Aug, ResolvedItemName};
+use crate::catalog::{CatalogType, CatalogTypeDetails};
+use crate::names::{Aug, ResolvedDataType, ResolvedItemName};
 use crate::normalize;
 use crate::plan::hir::ColumnRef;
+use crate::plan::query::scalar_type_from_sql;
 use crate::plan::typeconv::{CastContext, plan_cast};
 use crate::plan::{
     ExprContext, HirScalarExpr, PlanError, QueryContext, QueryLifetime, Scope, StatementContext,
@@ -220,11 +222,13 @@ pub(super) fn generate_source_export_statement_values(
     purified_export: PurifiedSourceExport,
     unsupported_cols: &mut Vec<(String, mz_repr::adt::system::Oid)>,
 ) -> Result<PostgresExportStatementValues, PlanError> {
-    let (text_columns, table) = match purified_export.details {
+    let (text_columns, table, enum_types, geometry_types) = match purified_export.details {
         PurifiedExportDetails::Postgres {
             text_columns,
             table,
-        } => (text_columns, table),
+            enum_types,
+            geometry_types,
+        } => (text_columns, table, enum_types, geometry_types),
         _ => unreachable!("purified export details must be postgres"),
     };
 
@@ -237,23 +241,35 @@ pub(super) fn generate_source_export_statement_values(
     for c in table.columns.iter() {
         let name = Ident::new(c.name.clone())?;
 
-        let ty = match text_column_set {
-            Some(ref names) if names.contains(c.name.as_str()) => mz_pgrepr::Type::Text,
+        let data_type = match text_column_set {
+            Some(ref names) if names.contains(c.name.as_str()) => {
+                scx.resolve_type(mz_pgrepr::Type::Text)?
+            }
             _ => match mz_pgrepr::Type::from_oid_and_typmod(c.type_oid, c.type_mod) {
-                Ok(t) => t,
-                Err(_) => {
-                    let mut full_name = purified_export.external_reference.0.clone();
-                    full_name.push(name);
-                    unsupported_cols.push((
-                        UnresolvedItemName(full_name).to_ast_string_simple(),
-                        mz_repr::adt::system::Oid(c.type_oid),
-                    ));
-                    continue;
+                Ok(t) => scx.resolve_type(t)?,
+                // PostGIS geometry columns arrive as hex-encoded EWKB, which
+                // is also the text format of our geometry type.
+                Err(_) if geometry_types.contains(&c.type_oid) => {
+                    scx.resolve_type(mz_pgrepr::Type::Geometry)?
                 }
+                Err(_) => match enum_types
+                    .get(&c.type_oid)
+                    .and_then(|enum_type| resolve_enum_type(scx, enum_type))
+                {
+                    Some(data_type) => data_type,
+                    None => {
+                        let mut full_name = purified_export.external_reference.0.clone();
+                        full_name.push(name);
+                        unsupported_cols.push((
+                            UnresolvedItemName(full_name).to_ast_string_simple(),
+                            mz_repr::adt::system::Oid(c.type_oid),
+                        ));
+                        continue;
+                    }
+                },
             },
         };
 
-        let data_type = scx.resolve_type(ty)?;
         let mut options = vec![];
 
         if !c.nullable {
@@ -321,6 +337,98 @@ pub(super) fn generate_source_export_statement_values(
     })
 }
 
+/// An enum type in the upstream PostgreSQL database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostgresEnumType {
+    /// The unqualified name of the type.
+    pub name: String,
+    /// The labels of the type, in their declared sort order.
+    pub labels: Vec<String>,
+}
+
+/// Resolves the Materialize enum type that stands in for the upstream enum
+/// type `enum_type`, if any.
+///
+/// The Materialize type must be resolvable by the upstream type's name and
+/// must declare exactly the same labels in the same order, as values are
+/// compared and sorted by their position in the label list.
+fn resolve_enum_type(
+    scx: &StatementContext,
+    enum_type: &PostgresEnumType,
+) -> Option<ResolvedDataType> {
+    let item = scx
+        .catalog
+        .resolve_type(&PartialItemName {
+            database: None,
+            schema: None,
+            item: enum_type.name.clone(),
+        })
+        .ok()?;
+    match item.type_details() {
+        Some(CatalogTypeDetails {
+            typ: CatalogType::Enum { labels },
+            ..
+        }) if *labels == enum_type.labels => Some(ResolvedDataType::Named {
+            id: item.id(),
+            qualifiers: item.name().qualifiers.clone(),
+            full_name: scx.catalog.resolve_full_name(item.name()),
+            modifiers: vec![],
+            print_id: true,
+        }),
+        _ => None,
+    }
+}
+
+/// Fetches the name and labels of each enum type in `type_oids`. OIDs that do
+/// not refer to enum types are ignored.
+async fn fetch_enum_types(
+    client: &Client,
+    type_oids: &[Oid],
+) -> Result<BTreeMap<Oid, PostgresEnumType>, PlanError> {
+    let rows = client
+        .query(
+            "
+            SELECT
+                t.oid,
+                t.typname::text AS name,
+                array_agg(e.enumlabel::text ORDER BY e.enumsortorder) AS labels
+            FROM pg_type AS t
+            JOIN pg_enum AS e ON e.enumtypid = t.oid
+            WHERE t.oid = ANY($1::oid[])
+            GROUP BY t.oid, t.typname",
+            &[&type_oids],
+        )
+        .await
+        .map_err(mz_postgres_util::PostgresError::from)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let enum_type = PostgresEnumType {
+                name: row.get("name"),
+                labels: row.get("labels"),
+            };
+            (row.get("oid"), enum_type)
+        })
+        .collect())
+}
+
+/// Returns the OIDs in `type_oids` that refer to the PostGIS `geometry` type.
+async fn fetch_geometry_type_oids(
+    client: &Client,
+    type_oids: &[Oid],
+) -> Result<BTreeSet<Oid>, PlanError> {
+    let rows = client
+        .query(
+            "SELECT oid FROM pg_type WHERE oid = ANY($1::oid[]) AND typname = 'geometry'",
+            &[&type_oids],
+        )
+        .await
+        .map_err(mz_postgres_util::PostgresError::from)?;
+
+    Ok(rows.into_iter().map(|row| row.get("oid")).collect())
+}
+
 pub(super) struct PurifiedSourceExports {
     pub(super) source_exports: BTreeMap<UnresolvedItemName, PurifiedSourceExport>,
     // NOTE(roshan): The text columns are already part of their
@@ -343,6 +451,7 @@ pub(super) async fn purify_source_exports(
     mut text_columns: Vec<UnresolvedItemName>,
     unresolved_source_name: &UnresolvedItemName,
     reference_policy: &SourceReferencePolicy,
+    map_enum_types: bool,
 ) -> Result<PurifiedSourceExports, PlanError> {
     let requested_exports = match requested_references.as_ref() {
         Some(requested) if matches!(reference_policy, SourceReferencePolicy::NotAllowed) => {
@@ -401,10 +510,48 @@ pub(super) async fn purify_source_exports(
         .map(WithOptionValue::UnresolvedItemName)
         .collect();
 
+    let unknown_type_oids: Vec<_> = requested_exports
+        .iter()
+        .flat_map(|r| &r.meta.postgres_desc().expect("is postgres").columns)
+        .filter(|c| mz_pgrepr::Type::from_oid_and_typmod(c.type_oid, c.type_mod).is_err())
+        .map(|c| c.type_oid)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    // Look up any upstream enum types, which can be ingested as Materialize
+    // enum types of the same name.
+    let enum_types = if map_enum_types && !unknown_type_oids.is_empty() {
+        fetch_enum_types(client, &unknown_type_oids).await?
+    } else {
+        BTreeMap::new()
+    };
+
+    // PostGIS is an extension, so its geometry type has no fixed OID.
+    let geometry_type_oids = if unknown_type_oids.is_empty() {
+        BTreeSet::new()
+    } else {
+        fetch_geometry_type_oids(client, &unknown_type_oids).await?
+    };
+
     let source_exports = requested_exports
         .into_iter()
         .map(|r| {
             let desc = r.meta.postgres_desc().expect("known postgres");
+            let enum_types = desc
+                .columns
+                .iter()
+                .filter_map(|c| {
+                    let enum_type = enum_types.get(&c.type_oid)?;
+                    Some((c.type_oid, enum_type.clone()))
+                })
+                .collect();
+            let geometry_types = desc
+                .columns
+                .iter()
+                .map(|c| c.type_oid)
+                .filter(|oid| geometry_type_oids.contains(oid))
+                .collect();
             (
                 r.name,
                 PurifiedSourceExport {
@@ -416,6 +563,8 @@ pub(super) async fn purify_source_exports(
                                 .collect()
                         }),
                         table: desc.clone(),
+                        enum_types,
+                        geometry_types,
                     },
                 },
             )
@@ -460,6 +609,7 @@ pub(crate) fn generate_column_casts(
     scx: &StatementContext,
     table: &PostgresTableDesc,
     text_columns: &Vec<Ident>,
+    columns: &[ColumnDef<Aug>],
 ) -> Result<Vec<(CastType, MirScalarExpr)>, PlanError> {
     // Generate the cast expressions required to convert the text encoded columns into
     // the appropriate target types, creating a Vec<MirScalarExpr>
@@ -498,47 +648,56 @@ pub(crate) fn generate_column_casts(
     // column and casts it to the appropriate target type
     let mut table_cast = vec![];
     for (i, column) in table.columns.iter().enumerate() {
-        let (cast_type, ty) = if text_columns.contains(column.name.as_str()) {
+        let (cast_type, scalar_type) = if text_columns.contains(column.name.as_str()) {
             // Treat the column as text if it was referenced in
             // `TEXT COLUMNS`. This is the only place we need to
             // perform this logic; even if the type is unsupported,
             // we'll be able to ingest its values as text in
             // storage.
-            (CastType::Text, mz_pgrepr::Type::Text)
+            (CastType::Text, ScalarType::String)
         } else {
             match mz_pgrepr::Type::from_oid_and_typmod(column.type_oid, column.type_mod) {
-                Ok(t) => (CastType::Natural, t),
-                // If this reference survived purification, we
-                // do not expect it to be from a table that the
-                // user will consume., i.e. expect this table to
-                // be filtered out of table casts.
-                Err(_) => {
-                    table_cast.push((
-                        CastType::Natural,
-                        HirScalarExpr::call_variadic(
-                            mz_expr::VariadicFunc::ErrorIfNull,
-                            vec![
-                                HirScalarExpr::literal_null(ScalarType::String),
-                                HirScalarExpr::literal(
-                                    mz_repr::Datum::from(
-                                        format!("Unsupported type with OID {}", column.type_oid)
+                Ok(t) => {
+                    let data_type = scx.resolve_type(t)?;
+                    (CastType::Natural, scalar_type_from_sql(scx, &data_type)?)
+                }
+                Err(_) => match mapped_column_type(scx, columns, &column.name)? {
+                    // Upstream enum and geometry types that purification
+                    // mapped to a Materialize type are declared as such on
+                    // the export.
+                    Some(scalar_type) => (CastType::Natural, scalar_type),
+                    // If this reference survived purification, we
+                    // do not expect it to be from a table that the
+                    // user will consume., i.e. expect this table to
+                    // be filtered out of table casts.
+                    None => {
+                        table_cast.push((
+                            CastType::Natural,
+                            HirScalarExpr::call_variadic(
+                                mz_expr::VariadicFunc::ErrorIfNull,
+                                vec![
+                                    HirScalarExpr::literal_null(ScalarType::String),
+                                    HirScalarExpr::literal(
+                                        mz_repr::Datum::from(
+                                            format!(
+                                                "Unsupported type with OID {}",
+                                                column.type_oid
+                                            )
                                             .as_str(),
+                                        ),
+                                        ScalarType::String,
                                     ),
-                                    ScalarType::String,
-                                ),
-                            ],
-                        )
-                        .lower_uncorrelated()
-                        .expect("no correlation"),
-                    ));
-                    continue;
-                }
+                                ],
+                            )
+                            .lower_uncorrelated()
+                            .expect("no correlation"),
+                        ));
+                        continue;
+                    }
+                },
             }
         };
 
-        let data_type = scx.resolve_type(ty)?;
-        let scalar_type = crate::plan::query::scalar_type_from_sql(scx, &data_type)?;
-
         let col_expr = HirScalarExpr::named_column(
             ColumnRef {
                 level: 0,
@@ -597,6 +756,23 @@ pub(crate) fn generate_column_casts(
     Ok(table_cast)
 }
 
+/// Returns the type of the export's column named `name` if it is an enum or
+/// geometry type, which purification maps from upstream types without fixed
+/// OIDs.
+fn mapped_column_type(
+    scx: &StatementContext,
+    columns: &[ColumnDef<Aug>],
+    name: &str,
+) -> Result<Option<ScalarType>, PlanError> {
+    let Some(column) = columns.iter().find(|c| c.name.as_str() == name) else {
+        return Ok(None);
+    };
+    match scalar_type_from_sql(scx, &column.data_type)? {
+        ty @ (ScalarType::Enum { .. } | ScalarType::Geometry) => Ok(Some(ty)),
+        _ => Ok(None),
+    }
+}
+
 mod privileges {
     use mz_postgres_util::{Config, PostgresError};
 
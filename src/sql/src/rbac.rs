@@ -94,9 +94,10 @@ fn filter_requirements(
     rbac_requirements
 }
 
-// The default item types that most statements require USAGE privileges for.
+// The default item types that most statements require USAGE privileges for. Functions require
+// EXECUTE privileges instead.
 static DEFAULT_ITEM_USAGE: LazyLock<BTreeSet<CatalogItemType>> = LazyLock::new(|| {
-    btreeset! {CatalogItemType::Secret, CatalogItemType::Connection}
+    btreeset! {CatalogItemType::Secret, CatalogItemType::Connection, CatalogItemType::Func}
 });
 // CREATE statements require USAGE privileges on the default item types and USAGE privileges on
 // Types.
@@ -113,6 +114,10 @@ pub enum UnauthorizedError {
     /// The action can only be performed by a superuser.
     #[error("permission denied to {action}")]
     Superuser { action: String },
+    /// The action can only be performed by a superuser or a role with the
+    /// `REPLICATION` attribute.
+    #[error("permission denied to {action}")]
+    Replication { action: String },
     /// The action requires ownership of an object.
     #[error("must be owner of {}", objects.iter().map(|(object_type, object_name)| format!("{object_type} {object_name}")).join(", "))]
     Ownership { objects: Vec<(ObjectType, String)> },
@@ -145,6 +150,10 @@ impl UnauthorizedError {
             UnauthorizedError::Superuser { action } => {
                 Some(format!("You must be a superuser to {}", action))
             }
+            UnauthorizedError::Replication { action } => Some(format!(
+                "You must be a superuser or have the REPLICATION attribute to {}",
+                action
+            )),
             UnauthorizedError::Privilege {
                 object_description,
                 role_name,
@@ -309,14 +318,26 @@ pub fn check_usage(
     let existing_resolved_ids =
         resolved_ids.retain_items(|item_id| catalog.try_get_item(item_id).is_some());
 
-    let required_privileges = generate_usage_privileges(
+    let mut required_privileges = generate_usage_privileges(
         catalog,
         &existing_resolved_ids,
         session.role_metadata().current_role,
         item_types,
-    )
-    .into_iter()
-    .collect();
+    );
+    // SQL functions run with the privileges of their caller, so the caller must also be able to
+    // read everything that the function bodies read.
+    if item_types.contains(&CatalogItemType::Func) {
+        let user_funcs = existing_resolved_ids
+            .items()
+            .copied()
+            .filter(|id| catalog.get_item(id).func_details().is_some());
+        required_privileges.extend(generate_read_privileges(
+            catalog,
+            user_funcs,
+            session.role_metadata().current_role,
+        ));
+    }
+    let required_privileges = required_privileges.into_iter().collect();
 
     let mut rbac_requirements = RbacRequirements::empty();
     rbac_requirements.privileges = required_privileges;
@@ -434,6 +455,11 @@ fn generate_rbac_requirements(
                     superuser_action: Some("create superuser role".to_string()),
                     ..Default::default()
                 }
+            } else if attributes.replication.unwrap_or(false) {
+                RbacRequirements {
+                    superuser_action: Some("create replication role".to_string()),
+                    ..Default::default()
+                }
             } else {
                 RbacRequirements {
                     privileges: vec![(SystemObjectId::System, AclMode::CREATE_ROLE, role_id)],
@@ -455,6 +481,8 @@ fn generate_rbac_requirements(
             name: _,
             variant: _,
             workload_class: _,
+            arrangement_spill: _,
+            auto_index: _,
         }) => RbacRequirements {
             privileges: vec![(SystemObjectId::System, AclMode::CREATE_CLUSTER, role_id)],
             item_usage: &CREATE_ITEM_USAGE,
@@ -554,6 +582,61 @@ fn generate_rbac_requirements(
                 ..Default::default()
             }
         }
+        Plan::CreatePublication(plan::CreatePublicationPlan {
+            name,
+            publication,
+            if_not_exists: _,
+        }) => {
+            let mut privileges = vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )];
+            privileges.extend_from_slice(&generate_read_privileges(
+                catalog,
+                publication.relations.iter().copied(),
+                role_id,
+            ));
+            RbacRequirements {
+                privileges,
+                item_usage: &CREATE_ITEM_USAGE,
+                ..Default::default()
+            }
+        }
+        Plan::CreateFunction(plan::CreateFunctionPlan {
+            name,
+            function: _,
+            if_not_exists: _,
+        }) => RbacRequirements {
+            privileges: vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
+        Plan::CreateNotification(plan::CreateNotificationPlan {
+            name,
+            notification,
+            if_not_exists: _,
+        }) => {
+            let mut privileges = vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )];
+            privileges.extend_from_slice(&generate_read_privileges(
+                catalog,
+                iter::once(notification.on),
+                role_id,
+            ));
+            RbacRequirements {
+                privileges,
+                item_usage: &CREATE_ITEM_USAGE,
+                ..Default::default()
+            }
+        }
         Plan::CreateTable(plan::CreateTablePlan {
             name,
             table: _,
@@ -1183,6 +1266,15 @@ fn generate_rbac_requirements(
                     ..Default::default()
                 }
             }
+            // Only superusers can change the replication attribute of a role.
+            plan::PlannedAlterRoleOption::Attributes(attributes)
+                if attributes.replication.is_some() =>
+            {
+                RbacRequirements {
+                    superuser_action: Some("change replication attribute of role".to_string()),
+                    ..Default::default()
+                }
+            }
             // Roles are allowed to change their own password.
             plan::PlannedAlterRoleOption::Attributes(attributes)
                 if attributes.password.is_some() && role_id == *id =>
@@ -1257,6 +1349,11 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::AlterTypeAddValue(plan::AlterTypeAddValuePlan { id, .. }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*id)],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::AlterNetworkPolicy(plan::AlterNetworkPolicyPlan { id, .. }) => RbacRequirements {
             ownership: vec![ObjectId::NetworkPolicy(*id)],
             item_usage: &CREATE_ITEM_USAGE,
@@ -1480,6 +1577,8 @@ fn generate_rbac_requirements(
         }
         Plan::DiscardTemp
         | Plan::DiscardAll
+        | Plan::Listen(plan::ListenPlan { channel: _ })
+        | Plan::Unlisten(plan::UnlistenPlan { channel: _ })
         | Plan::EmptyQuery
         | Plan::ShowAllVariables
         | Plan::ShowVariable(plan::ShowVariablePlan { name: _ })
@@ -1642,6 +1741,7 @@ fn generate_read_privileges_inner(
 ) -> Vec<(SystemObjectId, AclMode, RoleId)> {
     let mut privileges = Vec::new();
     let mut views = Vec::new();
+    let mut functions = Vec::new();
 
     for id in ids {
         if seen.insert((id.into(), role_id)) {
@@ -1663,7 +1763,17 @@ fn generate_read_privileges_inner(
                 CatalogItemType::Type | CatalogItemType::Secret | CatalogItemType::Connection => {
                     privileges.push((SystemObjectId::Object(id.into()), AclMode::USAGE, role_id));
                 }
-                CatalogItemType::Sink | CatalogItemType::Index | CatalogItemType::Func => {}
+                // User-defined functions execute with the privileges of the caller, so `role_id`
+                // must be able to read everything the function body reads.
+                CatalogItemType::Func if item.func_details().is_some() => {
+                    privileges.push((SystemObjectId::Object(id.into()), AclMode::EXECUTE, role_id));
+                    functions.push(item.references().items().copied());
+                }
+                CatalogItemType::Sink
+                | CatalogItemType::Index
+                | CatalogItemType::Func
+                | CatalogItemType::Publication
+                | CatalogItemType::Notification => {}
             }
         }
     }
@@ -1674,6 +1784,15 @@ fn generate_read_privileges_inner(
         ));
     }
 
+    for function_ids in functions {
+        privileges.extend_from_slice(&generate_read_privileges_inner(
+            catalog,
+            function_ids,
+            role_id,
+            seen,
+        ));
+    }
+
     privileges
 }
 
@@ -1687,15 +1806,21 @@ fn generate_usage_privileges(
     ids.items()
         .filter_map(move |id| {
             let item = catalog.get_item(id);
-            if item_types.contains(&item.item_type()) {
-                let schema_id = item.name().qualifiers.clone().into();
-                Some([
-                    (SystemObjectId::Object(schema_id), AclMode::USAGE, role_id),
-                    (SystemObjectId::Object(id.into()), AclMode::USAGE, role_id),
-                ])
-            } else {
-                None
+            if !item_types.contains(&item.item_type()) {
+                return None;
             }
+            let acl_mode = match item.item_type() {
+                // Only user-defined functions have privileges. Built-in functions can be called
+                // by anyone.
+                CatalogItemType::Func if item.func_details().is_some() => AclMode::EXECUTE,
+                CatalogItemType::Func => return None,
+                _ => AclMode::USAGE,
+            };
+            let schema_id = item.name().qualifiers.clone().into();
+            Some([
+                (SystemObjectId::Object(schema_id), AclMode::USAGE, role_id),
+                (SystemObjectId::Object(id.into()), acl_mode, role_id),
+            ])
         })
         .flatten()
         .collect()
@@ -1783,8 +1908,10 @@ pub const fn all_object_privileges(object_type: SystemObjectType) -> AclMode {
         SystemObjectType::Object(ObjectType::Connection) => AclMode::USAGE,
         SystemObjectType::Object(ObjectType::Database) => USAGE_CREATE_ACL_MODE,
         SystemObjectType::Object(ObjectType::Schema) => USAGE_CREATE_ACL_MODE,
-        SystemObjectType::Object(ObjectType::Func) => EMPTY_ACL_MODE,
+        SystemObjectType::Object(ObjectType::Func) => AclMode::EXECUTE,
         SystemObjectType::Object(ObjectType::ContinualTask) => AclMode::SELECT,
+        SystemObjectType::Object(ObjectType::Publication) => EMPTY_ACL_MODE,
+        SystemObjectType::Object(ObjectType::Notification) => EMPTY_ACL_MODE,
         SystemObjectType::System => ALL_SYSTEM_PRIVILEGES,
     }
 }
@@ -1814,7 +1941,9 @@ const fn default_builtin_object_acl_mode(object_type: ObjectType) -> AclMode {
         | ObjectType::Connection
         | ObjectType::Database
         | ObjectType::Func
-        | ObjectType::NetworkPolicy => AclMode::empty(),
+        | ObjectType::NetworkPolicy
+        | ObjectType::Publication
+        | ObjectType::Notification => AclMode::empty(),
     }
 }
 
@@ -412,6 +412,7 @@ impl SessionVars {
             &AUTO_ROUTE_CATALOG_QUERIES,
             &ENABLE_SESSION_RBAC_CHECKS,
             &ENABLE_SESSION_CARDINALITY_ESTIMATES,
+            &ENABLE_APPROXIMATE_NEAREST_NEIGHBORS,
             &MAX_IDENTIFIER_LENGTH,
             &STATEMENT_LOGGING_SAMPLE_RATE,
             &EMIT_INTROSPECTION_QUERY_NOTICE,
@@ -826,6 +827,11 @@ impl SessionVars {
         *self.expect_value(&ENABLE_SESSION_CARDINALITY_ESTIMATES)
     }
 
+    /// Returns the value of `enable_approximate_nearest_neighbors` configuration parameter.
+    pub fn enable_approximate_nearest_neighbors(&self) -> bool {
+        *self.expect_value(&ENABLE_APPROXIMATE_NEAREST_NEIGHBORS)
+    }
+
     /// Returns the value of `is_superuser` configuration parameter.
     pub fn is_superuser(&self) -> bool {
         self.user.is_superuser()
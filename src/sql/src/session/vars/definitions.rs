@@ -1329,6 +1329,14 @@ pub static ENABLE_SESSION_CARDINALITY_ESTIMATES: VarDefinition = VarDefinition::
 )
 .with_feature_flag(&ENABLE_CARDINALITY_ESTIMATES);
 
+pub static ENABLE_APPROXIMATE_NEAREST_NEIGHBORS: VarDefinition = VarDefinition::new(
+    "enable_approximate_nearest_neighbors",
+    value!(bool; false),
+    "Whether to answer `ORDER BY <vector> <=> <literal> LIMIT <n>` queries approximately from \
+        ANN indexes, which may miss some of the nearest rows (Materialize).",
+    true,
+);
+
 pub static OPTIMIZER_STATS_TIMEOUT: VarDefinition = VarDefinition::new(
     "optimizer_stats_timeout",
     value!(Duration; Duration::from_millis(250)),
@@ -2000,6 +2008,12 @@ feature_flags!(
         default: true,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_index_advisor,
+        desc: "suggesting indexes by replaying the statement log (populates `mz_internal.mz_index_suggestions`)",
+        default: false,
+        enable_for_item_parsing: false,
+    },
     {
         name: enable_alter_swap,
         desc: "the ALTER SWAP feature for objects",
@@ -2030,6 +2044,12 @@ feature_flags!(
         default: true,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_time_window_functions,
+        desc: "the TUMBLE and HOP windowing table functions",
+        default: false,
+        enable_for_item_parsing: true,
+    },
     {
         name: enable_mz_notices,
         desc: "Populate the contents of `mz_internal.mz_notices`",
@@ -2127,6 +2147,18 @@ feature_flags!(
         default: false,
         enable_for_item_parsing: false,
     },
+    {
+        name: enable_auto_index,
+        desc: "AUTO INDEX cluster option",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_arrangement_spill,
+        desc: "ARRANGEMENT SPILL cluster option",
+        default: false,
+        enable_for_item_parsing: false,
+    },
     {
         name: enable_zero_downtime_cluster_reconfiguration,
         desc: "Enable zero-downtime reconfiguration for alter cluster",
@@ -2181,6 +2213,18 @@ feature_flags!(
         default: false,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_enum_and_domain_types,
+        desc: "CREATE TYPE .. AS ENUM and CREATE DOMAIN",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_pg_source_enum_types,
+        desc: "Whether PostgreSQL sources ingest enum columns as the Materialize enum type of the same name.",
+        default: false,
+        enable_for_item_parsing: false,
+    },
     {
         name: enable_copy_from_remote,
         desc: "Whether to allow COPY FROM <url>.",
@@ -2217,6 +2261,54 @@ feature_flags!(
         default: true,
         enable_for_item_parsing: false,
     },
+    {
+        name: enable_ordered_top_k,
+        desc: "Render TopK operators over non-monotonic inputs with literal limits using an order-statistic tree per group.",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_join_elimination,
+        desc: "Eliminate outer joins against inputs that are unique on the join key and inner joins against inputs that a foreign key references, if their columns are not used, and convert outer joins to inner joins under null-rejecting predicates.",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_join_size_estimates,
+        desc: "When eager delta joins are enabled, choose between delta and differential joins by the estimated sizes of the arrangements they create rather than by their number.",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_spatial_join,
+        desc: "Plan joins on st_contains and st_within between two inputs as equi-joins over grid cells.",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_logical_replication,
+        desc: "CREATE PUBLICATION and pgoutput logical replication connections",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_notifications,
+        desc: "CREATE NOTIFICATION",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_create_function,
+        desc: "CREATE FUNCTION",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_wasm_functions,
+        desc: "CREATE FUNCTION ... LANGUAGE WASM",
+        default: false,
+        enable_for_item_parsing: true,
+    },
 );
 
 impl From<&super::SystemVars> for OptimizerFeatures {
@@ -2237,6 +2329,10 @@ impl From<&super::SystemVars> for OptimizerFeatures {
                 .enable_projection_pushdown_after_relation_cse(),
             enable_less_reduce_in_eqprop: vars.enable_less_reduce_in_eqprop(),
             enable_dequadratic_eqprop_map: vars.enable_dequadratic_eqprop_map(),
+            enable_ordered_top_k: vars.enable_ordered_top_k(),
+            enable_join_elimination: vars.enable_join_elimination(),
+            enable_join_size_estimates: vars.enable_join_size_estimates(),
+            enable_spatial_join: vars.enable_spatial_join(),
         }
     }
 }
@@ -81,7 +81,7 @@ use mz_sql::catalog::EnvironmentId;
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
     CreateIndexStatement, CreateViewStatement, CteBlock, Distinct, DropObjectsStatement, Ident,
-    IfExistsBehavior, ObjectType, OrderByExpr, Query, RawItemName, Select, SelectItem,
+    IfExistsBehavior, IndexMethod, ObjectType, OrderByExpr, Query, RawItemName, Select, SelectItem,
     SelectStatement, SetExpr, Statement as AstStatement, TableFactor, TableWithJoins,
     UnresolvedItemName, UnresolvedObjectName, ViewDefinition,
 };
@@ -554,6 +554,13 @@ impl<'a> FromSql<'a> for Slt {
                     oid::TYPE_MZ_ACL_ITEM_OID => Self(Value::MzAclItem(MzAclItem::decode_binary(
                         types::bytea_from_sql(raw),
                     )?)),
+                    oid::TYPE_VECTOR_OID => Self(Value::decode_binary(
+                        &mz_pgrepr::Type::Vector { dimensions: None },
+                        raw,
+                    )?),
+                    oid::TYPE_GEOMETRY_OID => {
+                        Self(Value::decode_binary(&mz_pgrepr::Type::Geometry, raw)?)
+                    }
                     _ => unreachable!(),
                 },
             },
@@ -569,7 +576,9 @@ impl<'a> FromSql<'a> for Slt {
             | oid::TYPE_UINT4_OID
             | oid::TYPE_UINT8_OID
             | oid::TYPE_MZ_TIMESTAMP_OID
-            | oid::TYPE_MZ_ACL_ITEM_OID => return true,
+            | oid::TYPE_MZ_ACL_ITEM_OID
+            | oid::TYPE_VECTOR_OID
+            | oid::TYPE_GEOMETRY_OID => return true,
             _ => {}
         }
         matches!(
@@ -1034,6 +1043,7 @@ impl<'a> RunnerInner<'a> {
                 now: SYSTEM_TIME.clone(),
                 metrics_registry: metrics_registry.clone(),
                 persist_pubsub_url: format!("http://localhost:{}", persist_pubsub_server_port),
+                persist_blob_encryption_args: Default::default(),
                 secrets_args: mz_service::secrets::SecretsReaderCliArgs {
                     secrets_reader: mz_service::secrets::SecretsControllerKind::LocalFile,
                     secrets_reader_local_file_dir: Some(secrets_dir),
@@ -2401,6 +2411,7 @@ fn generate_view_sql(
         name: None,
         in_cluster: None,
         on_name: RawItemName::Name(name.clone()),
+        method: IndexMethod::Arrangement,
         key_parts: if columns.len() == 0 {
             None
         } else {
@@ -2614,6 +2625,7 @@ fn mutate(sql: &str) -> Vec<String> {
                     name: None,
                     in_cluster: None,
                     on_name: RawItemName::Name(stmt.name.clone()),
+                    method: IndexMethod::Arrangement,
                     key_parts: Some(
                         stmt.columns
                             .iter()
@@ -497,6 +497,13 @@ pub trait StorageController: Debug {
         source_exports: BTreeMap<GlobalId, SourceExportDataConfig>,
     ) -> Result<(), StorageError<Self::Timestamp>>;
 
+    /// Alters the details for the specified source exports of the specified
+    /// ingestions, e.g. because the types of their columns changed.
+    async fn alter_ingestion_export_details(
+        &mut self,
+        source_exports: BTreeMap<GlobalId, SourceExportDetails>,
+    ) -> Result<(), StorageError<Self::Timestamp>>;
+
     async fn alter_table_desc(
         &mut self,
         existing_collection: GlobalId,
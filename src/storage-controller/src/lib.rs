@@ -80,7 +80,7 @@ use mz_storage_types::read_policy::ReadPolicy;
 use mz_storage_types::sinks::{StorageSinkConnection, StorageSinkDesc};
 use mz_storage_types::sources::{
     GenericSourceConnection, IngestionDescription, SourceConnection, SourceData, SourceDesc,
-    SourceExport, SourceExportDataConfig,
+    SourceExport, SourceExportDataConfig, SourceExportDetails,
 };
 use mz_storage_types::{AlterCompatible, StorageDiff, dyncfgs};
 use mz_txn_wal::metrics::Metrics as TxnMetrics;
@@ -1412,6 +1412,69 @@ where
         Ok(())
     }
 
+    async fn alter_ingestion_export_details(
+        &mut self,
+        source_exports: BTreeMap<GlobalId, SourceExportDetails>,
+    ) -> Result<(), StorageError<Self::Timestamp>> {
+        let mut ingestions_to_run = BTreeSet::new();
+
+        for (source_export_id, new_details) in source_exports {
+            // We need to adjust the details on the CollectionState for the
+            // source export collection directly
+            let source_export_collection = self
+                .collections
+                .get_mut(&source_export_id)
+                .ok_or_else(|| StorageError::IdentifierMissing(source_export_id))?;
+            let ingestion_id = match &mut source_export_collection.data_source {
+                DataSource::IngestionExport {
+                    ingestion_id,
+                    details,
+                    data_config: _,
+                } => {
+                    *details = new_details.clone();
+                    *ingestion_id
+                }
+                o => {
+                    tracing::warn!("alter_ingestion_export_details called on {:?}", o);
+                    Err(StorageError::IdentifierInvalid(source_export_id))?
+                }
+            };
+            // We also need to adjust the details on the CollectionState of the
+            // Ingestion that the export is associated with.
+            let ingestion_collection = self
+                .collections
+                .get_mut(&ingestion_id)
+                .ok_or_else(|| StorageError::IdentifierMissing(ingestion_id))?;
+
+            match &mut ingestion_collection.data_source {
+                DataSource::Ingestion(ingestion_desc) => {
+                    let source_export = ingestion_desc
+                        .source_exports
+                        .get_mut(&source_export_id)
+                        .ok_or_else(|| StorageError::IdentifierMissing(source_export_id))?;
+
+                    // If the details haven't changed, there's no sense in
+                    // re-rendering the dataflow.
+                    if source_export.details != new_details {
+                        tracing::info!(?source_export_id, from = ?source_export.details, to = ?new_details, "alter_ingestion_export_details, updating");
+                        source_export.details = new_details;
+
+                        ingestions_to_run.insert(ingestion_id);
+                    }
+                }
+                o => {
+                    tracing::warn!("alter_ingestion_export_details called on {:?}", o);
+                    Err(StorageError::IdentifierInvalid(ingestion_id))?
+                }
+            }
+        }
+
+        for id in ingestions_to_run {
+            self.run_ingestion(id)?;
+        }
+        Ok(())
+    }
+
     async fn alter_table_desc(
         &mut self,
         existing_collection: GlobalId,
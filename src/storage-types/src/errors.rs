@@ -768,6 +768,20 @@ mod columnation {
                         EvalError::PrettyError(x) => {
                             EvalError::PrettyError(self.string_region.copy(x))
                         }
+                        e @ EvalError::VectorDimensionsMismatch { expected, actual } => {
+                            assert_copy(expected);
+                            assert_copy(actual);
+                            e.clone()
+                        }
+                        e @ EvalError::DifferentVectorDimensions(a, b) => {
+                            assert_copy(a);
+                            assert_copy(b);
+                            e.clone()
+                        }
+                        EvalError::WasmUdfError { function, message } => EvalError::WasmUdfError {
+                            function: self.string_region.copy(function),
+                            message: self.string_region.copy(message),
+                        },
                     };
                     let reference = self.eval_error_region.copy_iter(once(err));
                     let boxed = unsafe { Box::from_raw(reference.as_mut_ptr()) };
@@ -217,10 +217,110 @@ impl RelationPartStats<'_> {
 
         Some(values.union(nulls))
     }
+
+    /// Returns a bound on the number of distinct values of column `idx` in this part, or `None`
+    /// if there are no stats for the column.
+    pub fn col_distinct(&self, idx: &ColumnIndex) -> Option<DistinctBound> {
+        let name = self.desc.get_name_idx(idx);
+        let typ = self.desc.get_type(idx);
+
+        let ok_stats = self.stats.key.cols.get("ok")?;
+        let ColumnStatKinds::Struct(ok_stats) = &ok_stats.values else {
+            panic!("'ok' column stats should be a struct")
+        };
+        let col_stats = ok_stats.cols.get(name.as_str())?;
+
+        let arena = RowArena::new();
+        let min_max = mz_repr::stats::col_values(&typ.scalar_type, &col_stats.values, &arena);
+        let null_count = col_stats.nulls.as_ref().map_or(0, |nulls| nulls.count);
+        let non_null_count = self.len()?.saturating_sub(null_count);
+
+        // Only the bounds of integral columns are exact: the bounds of other types (like
+        // strings) can be truncated, so they say nothing about the number of values.
+        let range = match min_max {
+            _ if non_null_count == 0 => None,
+            Some((min, max)) => DistinctBound::integer(min).zip(DistinctBound::integer(max)),
+            None => None,
+        };
+        let values = match range {
+            Some((min, max)) => DistinctBound::range_len(min, max, non_null_count),
+            None => non_null_count,
+        };
+
+        Some(DistinctBound {
+            values,
+            range,
+            nulls: null_count > 0,
+        })
+    }
+}
+
+/// A bound on the number of distinct values of a column, derived from the stats of one or more
+/// parts of a shard.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DistinctBound {
+    /// An upper bound on the number of distinct non-null values.
+    values: usize,
+    /// The smallest and largest non-null value, if the column is integral and has any.
+    range: Option<(i128, i128)>,
+    /// Whether the column contains nulls.
+    nulls: bool,
+}
+
+impl DistinctBound {
+    /// Combines the bounds of two disjoint sets of parts.
+    ///
+    /// The values of both sets may overlap, so their counts are added. If both sets have integral
+    /// values, the combined count is also bounded by the size of their combined range.
+    pub fn merge(self, other: DistinctBound) -> DistinctBound {
+        let range = match (self.range, other.range) {
+            (Some((min1, max1)), Some((min2, max2))) => Some((min1.min(min2), max1.max(max2))),
+            // A set without non-null values doesn't widen the range.
+            (range, None) if other.values == 0 => range,
+            (None, range) if self.values == 0 => range,
+            _ => None,
+        };
+        let mut values = self.values.saturating_add(other.values);
+        if let Some((min, max)) = range {
+            values = Self::range_len(min, max, values);
+        }
+        DistinctBound {
+            values,
+            range,
+            nulls: self.nulls || other.nulls,
+        }
+    }
+
+    /// The bound on the number of distinct values, counting `NULL` as a value.
+    pub fn count(&self) -> usize {
+        self.values + usize::from(self.nulls)
+    }
+
+    /// Returns the value of an integral datum.
+    fn integer(datum: Datum) -> Option<i128> {
+        match datum {
+            Datum::Int16(v) => Some(v.into()),
+            Datum::Int32(v) => Some(v.into()),
+            Datum::Int64(v) => Some(v.into()),
+            Datum::UInt8(v) => Some(v.into()),
+            Datum::UInt16(v) => Some(v.into()),
+            Datum::UInt32(v) => Some(v.into()),
+            Datum::UInt64(v) => Some(v.into()),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of integers in `[min, max]`, capped at `cap`.
+    fn range_len(min: i128, max: i128, cap: usize) -> usize {
+        let len = max.saturating_sub(min).saturating_add(1);
+        usize::try_from(len).map_or(cap, |len| len.min(cap))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
+
     use arrow::array::AsArray;
     use mz_ore::metrics::MetricsRegistry;
     use mz_persist_types::codec_impls::UnitSchema;
@@ -268,6 +368,12 @@ mod tests {
             assert!(spec.may_contain(*datum));
         }
 
+        // Validate that the distinct bound covers all of the provided datums.
+        let distinct: BTreeSet<_> = datums.iter().collect();
+        if let Some(bound) = stats.col_distinct(&ColumnIndex::from_raw(0)) {
+            assert!(bound.count() >= distinct.len());
+        }
+
         Ok(())
     }
 
@@ -0,0 +1,406 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A timestamp oracle backed by files in a local directory, for single-node
+//! deployments that don't have a Postgres/CRDB to put the oracle in.
+//!
+//! The state of each timeline is stored as a versioned value in a persist
+//! [`FileConsensus`], so every oracle operation that changes the state is a
+//! durable, linearizable compare-and-set against the latest version. This
+//! makes the oracle safe to share between processes on the same machine.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::TryStreamExt;
+use mz_ore::instrument;
+use mz_ore::metrics::MetricsRegistry;
+use mz_persist::file::{FileConsensus, FileConsensusConfig};
+use mz_persist::location::{CaSResult, Consensus, SeqNo, VersionedData};
+use mz_repr::Timestamp;
+use tracing::{debug, info, warn};
+
+use crate::WriteTimestamp;
+use crate::metrics::Metrics;
+use crate::postgres_oracle::retry_fallible;
+use crate::{GenericNowFn, TimestampOracle};
+
+/// The prefix of the consensus keys that hold the state of a timeline.
+const TIMELINE_KEY_PREFIX: &str = "timeline/";
+
+/// A [`TimestampOracle`] backed by local files.
+#[derive(Debug)]
+pub struct FileTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp>,
+{
+    key: String,
+    next: N,
+    consensus: Arc<dyn Consensus>,
+    metrics: Arc<Metrics>,
+    /// A read-only timestamp oracle is NOT allowed to do operations that change
+    /// the backing state.
+    read_only: bool,
+}
+
+/// Configuration for a file-backed implementation of [`TimestampOracle`].
+#[derive(Clone, Debug)]
+pub struct FileTimestampOracleConfig {
+    dir: PathBuf,
+    pub metrics: Arc<Metrics>,
+}
+
+impl FileTimestampOracleConfig {
+    /// Returns a new instance of [`FileTimestampOracleConfig`] that stores
+    /// oracle state in `dir`.
+    pub fn new(dir: &Path, metrics_registry: &MetricsRegistry) -> Self {
+        FileTimestampOracleConfig {
+            dir: dir.to_owned(),
+            metrics: Arc::new(Metrics::new(metrics_registry)),
+        }
+    }
+
+    async fn open_consensus(&self) -> Result<Arc<dyn Consensus>, anyhow::Error> {
+        let consensus = FileConsensus::open(FileConsensusConfig::from(&self.dir)).await?;
+        Ok(Arc::new(consensus))
+    }
+}
+
+/// The durable state of a timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TimelineState {
+    read_ts: Timestamp,
+    write_ts: Timestamp,
+}
+
+impl TimelineState {
+    fn encode(&self) -> Bytes {
+        let mut buf = Vec::with_capacity(16);
+        buf.extend_from_slice(&u64::from(self.read_ts).to_le_bytes());
+        buf.extend_from_slice(&u64::from(self.write_ts).to_le_bytes());
+        Bytes::from(buf)
+    }
+
+    fn decode(buf: &[u8]) -> Result<Self, anyhow::Error> {
+        let (read_ts, write_ts) = buf
+            .split_first_chunk::<8>()
+            .and_then(|(read_ts, rest)| Some((read_ts, <&[u8; 8]>::try_from(rest).ok()?)))
+            .ok_or_else(|| anyhow::anyhow!("invalid timestamp oracle state: {:?}", buf))?;
+        Ok(TimelineState {
+            read_ts: u64::from_le_bytes(*read_ts).into(),
+            write_ts: u64::from_le_bytes(*write_ts).into(),
+        })
+    }
+}
+
+impl<N> FileTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp> + std::fmt::Debug + 'static,
+{
+    /// Open a file-backed [`TimestampOracle`] instance with `config`, for the
+    /// timeline named `timeline`. `next` generates new timestamps when invoked.
+    /// Timestamps that are returned are made durable and will never retract.
+    pub async fn open(
+        config: FileTimestampOracleConfig,
+        timeline: String,
+        initially: Timestamp,
+        next: N,
+        read_only: bool,
+    ) -> Self {
+        info!(config = ?config, "opening FileTimestampOracle");
+
+        let fallible = || async {
+            let oracle = FileTimestampOracle {
+                key: format!("{}{}", TIMELINE_KEY_PREFIX, timeline),
+                next: next.clone(),
+                consensus: config.open_consensus().await?,
+                metrics: Arc::clone(&config.metrics),
+                read_only,
+            };
+
+            // Create the state for our timeline, if it doesn't exist. Losing
+            // this race to another process is fine: either way the timeline
+            // exists afterward.
+            if oracle.consensus.head(&oracle.key).await?.is_none() {
+                let initial = TimelineState {
+                    read_ts: initially,
+                    write_ts: initially,
+                };
+                let new = VersionedData {
+                    seqno: SeqNo::minimum(),
+                    data: initial.encode(),
+                };
+                let _ = oracle
+                    .consensus
+                    .compare_and_set(&oracle.key, None, new)
+                    .await?;
+            }
+
+            // Forward timestamps to what we're given from outside.
+            if !read_only {
+                TimestampOracle::apply_write(&oracle, initially).await;
+            }
+
+            Result::<_, anyhow::Error>::Ok(oracle)
+        };
+
+        let metrics = &config.metrics.retries.open;
+
+        retry_fallible(metrics, fallible).await
+    }
+
+    /// Returns a `Vec` of all known timelines along with their current greatest
+    /// timestamp (max of read_ts and write_ts).
+    ///
+    /// For use when initializing another [`TimestampOracle`] implementation
+    /// from another oracle's state.
+    pub async fn get_all_timelines(
+        config: FileTimestampOracleConfig,
+    ) -> Result<Vec<(String, Timestamp)>, anyhow::Error> {
+        let fallible = || async {
+            let consensus = config.open_consensus().await?;
+            let keys: Vec<String> = consensus.list_keys().try_collect().await?;
+            let mut result = Vec::new();
+            for key in keys {
+                let Some(timeline) = key.strip_prefix(TIMELINE_KEY_PREFIX) else {
+                    continue;
+                };
+                if let Some(head) = consensus.head(&key).await? {
+                    let state = TimelineState::decode(&head.data)?;
+                    result.push((
+                        timeline.to_owned(),
+                        std::cmp::max(state.read_ts, state.write_ts),
+                    ));
+                }
+            }
+            Result::<_, anyhow::Error>::Ok(result)
+        };
+
+        let metrics = &config.metrics.retries.get_all_timelines;
+
+        Ok(retry_fallible(metrics, fallible).await)
+    }
+
+    async fn state(&self) -> Result<(SeqNo, TimelineState), anyhow::Error> {
+        let head = self
+            .consensus
+            .head(&self.key)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("timeline {} does not exist", self.key))?;
+        Ok((head.seqno, TimelineState::decode(&head.data)?))
+    }
+
+    /// Applies `f` to the current state of the timeline, retrying on
+    /// concurrent modification, and returns the resulting state.
+    async fn update(
+        &self,
+        f: impl Fn(TimelineState) -> TimelineState,
+    ) -> Result<TimelineState, anyhow::Error> {
+        loop {
+            let (seqno, current) = self.state().await?;
+            let new = f(current);
+            if new == current {
+                return Ok(current);
+            }
+            let data = VersionedData {
+                seqno: seqno.next(),
+                data: new.encode(),
+            };
+            match self
+                .consensus
+                .compare_and_set(&self.key, Some(seqno), data)
+                .await?
+            {
+                CaSResult::Committed => {
+                    // Only the latest version is ever read, so there's no
+                    // reason to keep the old ones around.
+                    if let Err(err) = self.consensus.truncate(&self.key, seqno.next()).await {
+                        warn!("failed to truncate timestamp oracle state: {}", err);
+                    }
+                    return Ok(new);
+                }
+                CaSResult::ExpectationMismatch => continue,
+            }
+        }
+    }
+
+    #[mz_ore::instrument(name = "oracle::write_ts")]
+    async fn fallible_write_ts(&self) -> Result<WriteTimestamp<Timestamp>, anyhow::Error> {
+        if self.read_only {
+            panic!("attempting write_ts in read-only mode");
+        }
+
+        let proposed_next_ts = self.next.now();
+        let state = self
+            .update(|state| TimelineState {
+                read_ts: state.read_ts,
+                write_ts: std::cmp::max(state.write_ts.step_forward(), proposed_next_ts),
+            })
+            .await?;
+        let write_ts = state.write_ts;
+
+        debug!(
+            timeline = ?self.key,
+            write_ts = ?write_ts,
+            proposed_next_ts = ?proposed_next_ts,
+            "returning from write_ts()");
+
+        Ok(WriteTimestamp {
+            timestamp: write_ts,
+            advance_to: write_ts.step_forward(),
+        })
+    }
+
+    #[mz_ore::instrument(name = "oracle::peek_write_ts")]
+    async fn fallible_peek_write_ts(&self) -> Result<Timestamp, anyhow::Error> {
+        let (_, state) = self.state().await?;
+        Ok(state.write_ts)
+    }
+
+    #[mz_ore::instrument(name = "oracle::read_ts")]
+    async fn fallible_read_ts(&self) -> Result<Timestamp, anyhow::Error> {
+        let (_, state) = self.state().await?;
+        Ok(state.read_ts)
+    }
+
+    #[mz_ore::instrument(name = "oracle::apply_write")]
+    async fn fallible_apply_write(&self, write_ts: Timestamp) -> Result<(), anyhow::Error> {
+        if self.read_only {
+            panic!("attempting apply_write in read-only mode");
+        }
+
+        let _ = self
+            .update(|state| TimelineState {
+                read_ts: std::cmp::max(state.read_ts, write_ts),
+                write_ts: std::cmp::max(state.write_ts, write_ts),
+            })
+            .await?;
+
+        debug!(
+            timeline = ?self.key,
+            write_ts = ?write_ts,
+            "returning from apply_write()");
+
+        Ok(())
+    }
+}
+
+// A wrapper around the `fallible_` methods that adds operation metrics and
+// retries.
+#[async_trait]
+impl<N> TimestampOracle<Timestamp> for FileTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp> + std::fmt::Debug + 'static,
+{
+    #[instrument]
+    async fn write_ts(&self) -> WriteTimestamp<Timestamp> {
+        let metrics = &self.metrics.retries.write_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .write_ts
+                .run_op(|| self.fallible_write_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn peek_write_ts(&self) -> Timestamp {
+        let metrics = &self.metrics.retries.peek_write_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .peek_write_ts
+                .run_op(|| self.fallible_peek_write_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn read_ts(&self) -> Timestamp {
+        let metrics = &self.metrics.retries.read_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .read_ts
+                .run_op(|| self.fallible_read_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn apply_write(&self, write_ts: Timestamp) {
+        let metrics = &self.metrics.retries.apply_write;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .apply_write
+                .run_op(|| self.fallible_apply_write(write_ts))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `flock` on OS `linux`
+    async fn test_file_timestamp_oracle() -> Result<(), anyhow::Error> {
+        let temp_dir = tempfile::tempdir()?;
+        let config = FileTimestampOracleConfig::new(temp_dir.path(), &MetricsRegistry::new());
+
+        crate::tests::timestamp_oracle_impl_test(|timeline, now_fn, initial_ts| {
+            let oracle = FileTimestampOracle::open(
+                config.clone(),
+                timeline,
+                initial_ts,
+                now_fn,
+                false, /* read-only */
+            );
+
+            async {
+                let arced_oracle: Arc<dyn TimestampOracle<Timestamp> + Send + Sync> =
+                    Arc::new(oracle.await);
+
+                arced_oracle
+            }
+        })
+        .await?;
+
+        // State survives reopening, and is visible to get_all_timelines.
+        let now_fn = mz_ore::now::NowFn::from(|| 0u64);
+        let oracle = FileTimestampOracle::open(
+            config.clone(),
+            "t".into(),
+            5u64.into(),
+            now_fn.clone(),
+            false,
+        )
+        .await;
+        assert_eq!(oracle.write_ts().await.timestamp, Timestamp::from(6u64));
+        drop(oracle);
+        let oracle =
+            FileTimestampOracle::open(config.clone(), "t".into(), 0u64.into(), now_fn, true).await;
+        assert_eq!(oracle.peek_write_ts().await, Timestamp::from(6u64));
+        assert_eq!(oracle.read_ts().await, Timestamp::from(5u64));
+        let timelines =
+            FileTimestampOracle::<mz_ore::now::NowFn>::get_all_timelines(config).await?;
+        assert!(timelines.contains(&("t".to_owned(), Timestamp::from(6u64))));
+
+        Ok(())
+    }
+}
@@ -14,10 +14,19 @@
 //! reported completed write timestamps, and strictly less than all subsequently
 //! emitted write timestamps.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::{EpochMillis, NowFn};
+use mz_ore::url::SensitiveUrl;
+
+use crate::file_oracle::{FileTimestampOracle, FileTimestampOracleConfig};
+use crate::metrics::Metrics;
+use crate::postgres_oracle::{PostgresTimestampOracle, PostgresTimestampOracleConfig};
 
 pub mod batching_oracle;
+pub mod file_oracle;
 pub mod metrics;
 pub mod postgres_oracle;
 pub mod retry;
@@ -62,6 +71,82 @@ pub trait TimestampOracle<T>: std::fmt::Debug {
     async fn apply_write(&self, lower_bound: T);
 }
 
+/// Configuration for the durable backend of a [`TimestampOracle`], selected by
+/// the scheme of the timestamp oracle URL.
+#[derive(Clone, Debug)]
+pub enum TimestampOracleConfig {
+    /// A Postgres/CRDB-backed oracle, for `postgres://` and `postgresql://`
+    /// URLs.
+    Postgres(PostgresTimestampOracleConfig),
+    /// An oracle backed by a local directory, for `file://` URLs.
+    File(FileTimestampOracleConfig),
+}
+
+impl TimestampOracleConfig {
+    /// Parses a [`TimestampOracleConfig`] from a URL.
+    pub fn from_url(
+        url: &SensitiveUrl,
+        metrics_registry: &MetricsRegistry,
+    ) -> Result<Self, anyhow::Error> {
+        match url.scheme() {
+            "postgres" | "postgresql" => Ok(TimestampOracleConfig::Postgres(
+                PostgresTimestampOracleConfig::new(url, metrics_registry),
+            )),
+            "file" => Ok(TimestampOracleConfig::File(FileTimestampOracleConfig::new(
+                std::path::Path::new(url.path()),
+                metrics_registry,
+            ))),
+            p => Err(anyhow::anyhow!(
+                "unknown timestamp oracle scheme {}: {}",
+                p,
+                url.as_str()
+            )),
+        }
+    }
+
+    /// Returns the metrics of the oracle.
+    pub fn metrics(&self) -> &Arc<Metrics> {
+        match self {
+            TimestampOracleConfig::Postgres(config) => &config.metrics,
+            TimestampOracleConfig::File(config) => &config.metrics,
+        }
+    }
+
+    /// Opens a [`TimestampOracle`] for the timeline named `timeline`. See
+    /// [`PostgresTimestampOracle::open`].
+    pub async fn open(
+        self,
+        timeline: String,
+        initially: mz_repr::Timestamp,
+        next: NowFn,
+        read_only: bool,
+    ) -> Arc<dyn TimestampOracle<mz_repr::Timestamp> + Send + Sync> {
+        match self {
+            TimestampOracleConfig::Postgres(config) => Arc::new(
+                PostgresTimestampOracle::open(config, timeline, initially, next, read_only).await,
+            ),
+            TimestampOracleConfig::File(config) => Arc::new(
+                FileTimestampOracle::open(config, timeline, initially, next, read_only).await,
+            ),
+        }
+    }
+
+    /// Returns all known timelines along with their current greatest
+    /// timestamp. See [`PostgresTimestampOracle::get_all_timelines`].
+    pub async fn get_all_timelines(
+        self,
+    ) -> Result<Vec<(String, mz_repr::Timestamp)>, anyhow::Error> {
+        match self {
+            TimestampOracleConfig::Postgres(config) => {
+                PostgresTimestampOracle::<NowFn>::get_all_timelines(config).await
+            }
+            TimestampOracleConfig::File(config) => {
+                FileTimestampOracle::<NowFn>::get_all_timelines(config).await
+            }
+        }
+    }
+}
+
 /// A [`NowFn`] that is generic over the timestamp.
 ///
 /// The oracle operations work in terms of [`mz_repr::Timestamp`] and we could
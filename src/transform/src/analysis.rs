@@ -1252,9 +1252,10 @@ mod explain {
                 builder.require(super::UniqueKeys);
             }
             if context.config.cardinality {
-                builder.require(super::Cardinality::with_stats(
-                    context.cardinality_stats.clone(),
-                ));
+                builder.require(
+                    super::Cardinality::with_stats(context.cardinality_stats.clone())
+                        .with_distinct_stats(context.used_statistics.distinct_map()),
+                );
             }
             if context.config.column_names || context.config.humanized_exprs {
                 builder.require(super::ColumnNames);
@@ -1392,6 +1393,7 @@ mod cardinality {
     };
     use mz_ore::cast::{CastFrom, CastLossy, TryCastFrom};
     use mz_repr::GlobalId;
+    use mz_repr::adt::geometry::GRID_LEVELS;
 
     use ordered_float::OrderedFloat;
 
@@ -1402,19 +1404,88 @@ mod cardinality {
     pub struct Cardinality {
         /// Cardinalities for globally named entities
         pub stats: BTreeMap<GlobalId, usize>,
+        /// Numbers of distinct values for the columns of globally named entities
+        pub distinct: BTreeMap<(GlobalId, usize), usize>,
     }
 
     impl Cardinality {
         /// A cardinality estimator with provided statistics for the given global identifiers
         pub fn with_stats(stats: BTreeMap<GlobalId, usize>) -> Self {
-            Cardinality { stats }
+            Cardinality {
+                stats,
+                distinct: BTreeMap::new(),
+            }
+        }
+
+        /// Adds numbers of distinct values for columns of the given global identifiers
+        pub fn with_distinct_stats(mut self, distinct: BTreeMap<(GlobalId, usize), usize>) -> Self {
+            self.distinct = distinct;
+            self
+        }
+
+        /// Looks up the number of distinct values of column `col` of `expr`, if `expr` passes
+        /// that column through unchanged from a globally named entity with known statistics.
+        fn distinct_values(&self, expr: &MirRelationExpr, col: usize) -> Option<usize> {
+            if self.distinct.is_empty() {
+                return None;
+            }
+            base_column(expr, col).and_then(|key| self.distinct.get(&key).copied())
         }
     }
 
     impl Default for Cardinality {
         fn default() -> Self {
-            Cardinality {
-                stats: BTreeMap::new(),
+            Cardinality::with_stats(BTreeMap::new())
+        }
+    }
+
+    /// Traces column `col` of `expr` back to the column of a globally named entity it is
+    /// taken from, if any.
+    ///
+    /// Only operators that pass column values through unchanged are considered, so the number
+    /// of distinct values of the entity's column bounds the number of distinct values of `col`.
+    fn base_column(mut expr: &MirRelationExpr, mut col: usize) -> Option<(GlobalId, usize)> {
+        use MirRelationExpr::*;
+
+        loop {
+            match expr {
+                Get {
+                    id: Id::Global(id), ..
+                } => return Some((*id, col)),
+                ArrangeBy { input, .. }
+                | Filter { input, .. }
+                | Negate { input }
+                | Threshold { input }
+                | TopK { input, .. } => expr = input,
+                Map { input, .. } if col < input.arity() => expr = input,
+                Project { input, outputs } => {
+                    col = *outputs.get(col)?;
+                    expr = input;
+                }
+                Reduce {
+                    input, group_key, ..
+                } => match group_key.get(col) {
+                    Some(MirScalarExpr::Column(c, _)) => {
+                        col = *c;
+                        expr = input;
+                    }
+                    _ => return None,
+                },
+                Join { inputs, .. } => {
+                    let mut offset = 0;
+                    let mut found = None;
+                    for input in inputs {
+                        let arity = input.arity();
+                        if col < offset + arity {
+                            found = Some(input);
+                            break;
+                        }
+                        offset += arity;
+                    }
+                    expr = found?;
+                    col -= offset;
+                }
+                _ => return None,
             }
         }
     }
@@ -1575,6 +1646,17 @@ mod cardinality {
                 TableFunc::Wrap { types, width } => {
                     input * (f64::cast_lossy(types.len()) / f64::cast_lossy(*width))
                 }
+                // Each row belongs to exactly one tumbling window.
+                TableFunc::TumbleWindow { .. } => input,
+                // Each row belongs to `size / slide` hopping windows, rounded up.
+                TableFunc::HopWindow { size, slide, .. } => {
+                    let windows = TableFunc::hop_windows_per_row(*size, *slide);
+                    input * CardinalityEstimate::from(f64::cast_lossy(windows).max(1.0))
+                }
+                // Each geometry is anchored in one cell per grid level.
+                TableFunc::GeometryGridAnchor => {
+                    input * CardinalityEstimate::from(f64::cast_lossy(GRID_LEVELS + 1))
+                }
                 _ => {
                     // TODO(mgree) what explosion factor should we make up?
                     input * CardinalityEstimate::from(4.0)
@@ -1586,6 +1668,7 @@ mod cardinality {
             &self,
             predicate_expr: &MirScalarExpr,
             unique_columns: &BTreeSet<usize>,
+            distinct_columns: &BTreeMap<usize, usize>,
         ) -> OrderedFloat<f64> {
             let index_selectivity = |expr: &MirScalarExpr| -> Option<OrderedFloat<f64>> {
                 match expr {
@@ -1600,13 +1683,25 @@ mod cardinality {
                     _ => None,
                 }
             };
+            // Assuming values are uniformly distributed, an equality on a column with `n`
+            // distinct values selects one `n`th of the rows.
+            let eq_selectivity = |expr: &MirScalarExpr| -> Option<OrderedFloat<f64>> {
+                index_selectivity(expr).or_else(|| match expr {
+                    MirScalarExpr::Column(col, _) => distinct_columns
+                        .get(col)
+                        .map(|n| OrderedFloat(1.0 / f64::cast_lossy(std::cmp::max(*n, 1)))),
+                    _ => None,
+                })
+            };
 
             match predicate_expr {
                 MirScalarExpr::Column(_, _)
                 | MirScalarExpr::Literal(_, _)
                 | MirScalarExpr::CallUnmaterializable(_) => OrderedFloat(1.0),
                 MirScalarExpr::CallUnary { func, expr } => match func {
-                    UnaryFunc::Not(_) => OrderedFloat(1.0) - self.predicate(expr, unique_columns),
+                    UnaryFunc::Not(_) => {
+                        OrderedFloat(1.0) - self.predicate(expr, unique_columns, distinct_columns)
+                    }
                     UnaryFunc::IsTrue(_) | UnaryFunc::IsFalse(_) => OrderedFloat(0.5),
                     UnaryFunc::IsNull(_) => {
                         if let Some(icard) = index_selectivity(expr) {
@@ -1619,23 +1714,19 @@ mod cardinality {
                 },
                 MirScalarExpr::CallBinary { func, expr1, expr2 } => {
                     match func {
-                        BinaryFunc::Eq => {
-                            match (index_selectivity(expr1), index_selectivity(expr2)) {
-                                (Some(isel1), Some(isel2)) => std::cmp::max(isel1, isel2),
-                                (Some(isel), None) | (None, Some(isel)) => isel,
-                                (None, None) => WORST_CASE_SELECTIVITY,
-                            }
-                        }
+                        BinaryFunc::Eq => match (eq_selectivity(expr1), eq_selectivity(expr2)) {
+                            (Some(isel1), Some(isel2)) => std::cmp::max(isel1, isel2),
+                            (Some(isel), None) | (None, Some(isel)) => isel,
+                            (None, None) => WORST_CASE_SELECTIVITY,
+                        },
                         // 1.0 - the Eq case
-                        BinaryFunc::NotEq => {
-                            match (index_selectivity(expr1), index_selectivity(expr2)) {
-                                (Some(isel1), Some(isel2)) => {
-                                    OrderedFloat(1.0) - std::cmp::max(isel1, isel2)
-                                }
-                                (Some(isel), None) | (None, Some(isel)) => OrderedFloat(1.0) - isel,
-                                (None, None) => OrderedFloat(1.0) - WORST_CASE_SELECTIVITY,
+                        BinaryFunc::NotEq => match (eq_selectivity(expr1), eq_selectivity(expr2)) {
+                            (Some(isel1), Some(isel2)) => {
+                                OrderedFloat(1.0) - std::cmp::max(isel1, isel2)
                             }
-                        }
+                            (Some(isel), None) | (None, Some(isel)) => OrderedFloat(1.0) - isel,
+                            (None, None) => OrderedFloat(1.0) - WORST_CASE_SELECTIVITY,
+                        },
                         BinaryFunc::Lt | BinaryFunc::Lte | BinaryFunc::Gt | BinaryFunc::Gte => {
                             // TODO(mgree) if we have high/low key values and one of the columns is an index, we can do better
                             OrderedFloat(0.33)
@@ -1646,7 +1737,7 @@ mod cardinality {
                 MirScalarExpr::CallVariadic { func, exprs } => match func {
                     VariadicFunc::And => exprs
                         .iter()
-                        .map(|expr| self.predicate(expr, unique_columns))
+                        .map(|expr| self.predicate(expr, unique_columns, distinct_columns))
                         .product(),
                     VariadicFunc::Or => {
                         // TODO(mgree): BETWEEN will get compiled down to an AND of appropriate bounds---we could try to detect it and be clever
@@ -1657,13 +1748,13 @@ mod cardinality {
                         let mut expr1;
 
                         if let Some(first) = exprs.next() {
-                            expr1 = self.predicate(first, unique_columns);
+                            expr1 = self.predicate(first, unique_columns, distinct_columns);
                         } else {
                             return OrderedFloat(1.0);
                         }
 
                         for expr2 in exprs {
-                            let expr2 = self.predicate(expr2, unique_columns);
+                            let expr2 = self.predicate(expr2, unique_columns, distinct_columns);
                             expr1 = expr1 + expr2 - expr1 * expr2;
                         }
                         expr1
@@ -1671,8 +1762,8 @@ mod cardinality {
                     _ => OrderedFloat(1.0),
                 },
                 MirScalarExpr::If { cond: _, then, els } => std::cmp::max(
-                    self.predicate(then, unique_columns),
-                    self.predicate(els, unique_columns),
+                    self.predicate(then, unique_columns, distinct_columns),
+                    self.predicate(els, unique_columns, distinct_columns),
                 ),
             }
         }
@@ -1681,6 +1772,7 @@ mod cardinality {
             &self,
             predicates: &Vec<MirScalarExpr>,
             keys: &Vec<Vec<usize>>,
+            distinct_columns: &BTreeMap<usize, usize>,
             input: CardinalityEstimate,
         ) -> CardinalityEstimate {
             // TODO(mgree): should we try to do something for indices built on multiple columns?
@@ -1693,7 +1785,7 @@ mod cardinality {
 
             let mut estimate = input;
             for expr in predicates {
-                let selectivity = self.predicate(expr, &unique_columns);
+                let selectivity = self.predicate(expr, &unique_columns, distinct_columns);
                 debug_assert!(
                     OrderedFloat(0.0) <= selectivity && selectivity <= OrderedFloat(1.0),
                     "predicate selectivity {selectivity} should be in the range [0,1]"
@@ -1707,14 +1799,21 @@ mod cardinality {
         fn join(
             &self,
             equivalences: &Vec<Vec<MirScalarExpr>>,
-            _implementation: &JoinImplementation,
+            implementation: &JoinImplementation,
             unique_columns: BTreeMap<usize, usize>,
+            distinct_columns: BTreeMap<usize, usize>,
             mut inputs: Vec<CardinalityEstimate>,
         ) -> CardinalityEstimate {
             if inputs.is_empty() {
                 return CardinalityEstimate::from(0.0);
             }
 
+            // An ASOF join produces at most one row for each row of its first input.
+            if implementation.is_asof() {
+                return inputs.swap_remove(0);
+            }
+
+            let mut selectivity = CardinalityEstimate::from(1.0);
             for equiv in equivalences {
                 // those sources which have a unique key
                 let mut unique_sources = BTreeSet::new();
@@ -1734,6 +1833,20 @@ mod cardinality {
 
                 // no unique columns in this equivalence
                 if unique_sources.is_empty() {
+                    // when joining R and S on R.x = S.x, assuming the values of the column with
+                    // fewer distinct values all occur in the other, each row of the product matches
+                    // with a probability of one over the larger number of distinct values
+                    let max_distinct = equiv
+                        .iter()
+                        .filter_map(|expr| match expr {
+                            MirScalarExpr::Column(col, _) => distinct_columns.get(col),
+                            _ => None,
+                        })
+                        .max();
+                    if let Some(max_distinct) = max_distinct {
+                        selectivity =
+                            selectivity / f64::cast_lossy(std::cmp::max(*max_distinct, 1));
+                    }
                     continue;
                 }
 
@@ -1765,7 +1878,7 @@ mod cardinality {
                 }
             }
 
-            let mut product = CardinalityEstimate::from(1.0);
+            let mut product = selectivity;
             for input in inputs {
                 product = product * input;
             }
@@ -1874,11 +1987,19 @@ mod cardinality {
                     let input = results[index - 1];
                     self.flat_map(func, input)
                 }
-                Filter { predicates, .. } => {
+                Filter {
+                    predicates,
+                    input: filter_input,
+                } => {
                     let input = results[index - 1];
                     let keys = depends.results::<UniqueKeys>();
                     let keys = &keys[index - 1];
-                    self.filter(predicates, keys, input)
+                    let distinct_columns = predicates
+                        .iter()
+                        .flat_map(|p| p.support())
+                        .filter_map(|col| self.distinct_values(filter_input, col).map(|n| (col, n)))
+                        .collect();
+                    self.filter(predicates, keys, &distinct_columns, input)
                 }
                 Join {
                     equivalences,
@@ -1886,6 +2007,7 @@ mod cardinality {
                     inputs,
                     ..
                 } => {
+                    let join = expr;
                     let mut input_results = Vec::with_capacity(inputs.len());
 
                     // maps a column to the index in `inputs` that it belongs to
@@ -1909,7 +2031,25 @@ mod cardinality {
                         offset += &sizes[index - offset];
                     }
 
-                    self.join(equivalences, implementation, unique_columns, input_results)
+                    // maps a column referenced by the equivalences to its number of distinct values
+                    let distinct_columns = equivalences
+                        .iter()
+                        .flatten()
+                        .filter_map(|expr| match expr {
+                            MirScalarExpr::Column(col, _) => {
+                                self.distinct_values(join, *col).map(|n| (*col, n))
+                            }
+                            _ => None,
+                        })
+                        .collect();
+
+                    self.join(
+                        equivalences,
+                        implementation,
+                        unique_columns,
+                        distinct_columns,
+                        input_results,
+                    )
                 }
                 Reduce {
                     group_key,
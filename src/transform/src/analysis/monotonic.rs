@@ -74,6 +74,9 @@ impl Analysis for Monotonic {
             | MirRelationExpr::Threshold { .. }
             | MirRelationExpr::Let { .. }
             | MirRelationExpr::LetRec { .. } => results[index - 1],
+            // A new row in the second input of an ASOF join can replace the closest match of
+            // existing rows, which retracts their previous output.
+            MirRelationExpr::Join { implementation, .. } if implementation.is_asof() => false,
             // Monotonic iff all inputs are.
             MirRelationExpr::Union { .. } | MirRelationExpr::Join { .. } => {
                 Self::has_monotonic_children(expr, index, results, depends)
@@ -25,7 +25,9 @@ use mz_expr::{
 use mz_ore::stack::{CheckedRecursion, RecursionGuard, RecursionLimitError};
 use mz_ore::{assert_none, soft_assert_eq_or_log, soft_assert_or_log, soft_panic_or_log};
 use mz_repr::GlobalId;
-use mz_repr::explain::{DeltaJoinIndexUsageType, IndexUsageType, UsedIndexes};
+use mz_repr::explain::{
+    CollectionStatistics, DeltaJoinIndexUsageType, IndexUsageType, UsedIndexes, UsedStatistics,
+};
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
@@ -99,11 +101,38 @@ pub fn optimize_dataflow(
         transform_ctx.df_meta,
     )?;
 
+    record_dataflow_statistics(dataflow, transform_ctx);
+
     mz_repr::explain::trace_plan(dataflow);
 
     Ok(())
 }
 
+/// Records the statistics that were available for the collections imported by the dataflow.
+///
+/// These are the statistics that informed the cost estimates of the optimizer, and are reported
+/// in `EXPLAIN` output.
+fn record_dataflow_statistics(dataflow: &DataflowDesc, transform_ctx: &mut TransformCtx) {
+    let mut distinct = BTreeMap::<GlobalId, BTreeMap<usize, usize>>::new();
+    for ((id, column), count) in transform_ctx.stats.distinct_map() {
+        distinct.entry(id).or_default().insert(column, count);
+    }
+
+    let imported_ids = dataflow
+        .source_imports
+        .keys()
+        .chain(dataflow.index_imports.values().map(|i| &i.desc.on_id));
+    for id in imported_ids {
+        let stats = CollectionStatistics {
+            rows: transform_ctx.stats.cardinality_estimate(*id),
+            distinct: distinct.get(id).cloned().unwrap_or_default(),
+        };
+        if !stats.is_empty() {
+            transform_ctx.df_meta.statistics.insert(*id, stats);
+        }
+    }
+}
+
 /// Inline views used in one other view, and in no exported objects.
 #[mz_ore::instrument(
     target = "optimizer",
@@ -924,6 +953,14 @@ impl<'a> CollectIndexRequests<'a> {
                                 )?;
                             }
                         }
+                        JoinImplementation::AsOf(..) => {
+                            // ASOF joins arrange their inputs themselves, so there is nothing
+                            // interesting here.
+                            let empty_context = Vec::new();
+                            for input in inputs {
+                                this.collect_index_reqs_inner(input, &empty_context)?;
+                            }
+                        }
                         JoinImplementation::Unimplemented => {
                             soft_panic_or_log!(
                                 "CollectIndexRequests encountered an Unimplemented join"
@@ -969,7 +1006,8 @@ impl<'a> CollectIndexRequests<'a> {
                                         // be saved as `FullScan` (NOT as `PlanRootNoArrangement`),
                                         // because we are going into the `try_full_scan` if.
                                     },
-                                    IndexUsageType::FastPathLimit => {
+                                    IndexUsageType::FastPathLimit
+                                    | IndexUsageType::NearestNeighbors => {
                                         // These are created much later, not even inside
                                         // `prune_and_annotate_dataflow_index_imports`.
                                         unreachable!()
@@ -1237,6 +1275,9 @@ pub struct DataflowMetainfo<Notice = RawOptimizerNotice> {
     /// What kind of operation (full scan, lookup, ...) will access each index. Computed by
     /// `prune_and_annotate_dataflow_index_imports`.
     pub index_usage_types: BTreeMap<GlobalId, Vec<IndexUsageType>>,
+    /// The statistics that were available for the collections read by the dataflow. Computed by
+    /// `record_dataflow_statistics`.
+    pub statistics: BTreeMap<GlobalId, CollectionStatistics>,
 }
 
 impl Default for DataflowMetainfo {
@@ -1244,6 +1285,7 @@ impl Default for DataflowMetainfo {
         DataflowMetainfo {
             optimizer_notices: Vec::new(),
             index_usage_types: BTreeMap::new(),
+            statistics: BTreeMap::new(),
         }
     }
 }
@@ -1275,6 +1317,21 @@ impl<Notice> DataflowMetainfo<Notice> {
     }
 }
 
+impl<Notice> DataflowMetainfo<Notice> {
+    /// Create a [`UsedStatistics`] instance from the recorded [`DataflowMetainfo::statistics`].
+    pub fn used_statistics(&self) -> UsedStatistics {
+        UsedStatistics::new(self.statistics.clone())
+    }
+
+    /// Returns the recorded row count estimates, as expected by the `Cardinality` analysis.
+    pub fn cardinality_stats(&self) -> BTreeMap<GlobalId, usize> {
+        self.statistics
+            .iter()
+            .filter_map(|(id, stats)| stats.rows.map(|rows| (*id, rows)))
+            .collect()
+    }
+}
+
 impl DataflowMetainfo<RawOptimizerNotice> {
     /// Pushes a [`RawOptimizerNotice`] into [`Self::optimizer_notices`], but
     /// only if the exact same notice is not already present.
@@ -14,8 +14,8 @@ use mz_ore::assert_none;
 use std::collections::{BTreeMap, BTreeSet};
 
 use mz_expr::{
-    AggregateExpr, AggregateFunc, Id, JoinInputMapper, MirRelationExpr, MirScalarExpr,
-    RECURSION_LIMIT,
+    AggregateExpr, AggregateFunc, Id, JoinImplementation, JoinInputMapper, MirRelationExpr,
+    MirScalarExpr, RECURSION_LIMIT,
 };
 use mz_ore::stack::{CheckedRecursion, RecursionGuard};
 use mz_repr::{Datum, Row};
@@ -232,7 +232,7 @@ impl Demand {
                 MirRelationExpr::Join {
                     inputs,
                     equivalences,
-                    implementation: _,
+                    implementation,
                 } => {
                     let input_mapper = JoinInputMapper::new(inputs);
 
@@ -263,6 +263,11 @@ impl Demand {
                             expr.support_into(&mut columns);
                         }
                     }
+                    // As does the match condition of an ASOF join.
+                    if let JoinImplementation::AsOf(asof) = implementation {
+                        asof.left_time.support_into(&mut columns);
+                        asof.right_time.support_into(&mut columns);
+                    }
 
                     // Populate child demands from external and internal demands.
                     let new_columns = input_mapper.split_column_set_by_input(columns.iter());
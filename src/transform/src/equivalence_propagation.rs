@@ -389,6 +389,26 @@ impl EquivalencePropagation {
                 }
             }
 
+            MirRelationExpr::Join {
+                inputs,
+                implementation,
+                ..
+            } if implementation.is_asof() => {
+                // An ASOF join is not an inner join: the equivalences that hold for its output
+                // need not hold for the rows of an input, which may still be the closest match
+                // for some other row. We present each input with no equivalences.
+                let mut children: Vec<_> = derived.children_rev().collect::<Vec<_>>();
+                children.reverse();
+                for (child, expr) in children.into_iter().zip(inputs.iter_mut()) {
+                    self.apply(
+                        expr,
+                        child,
+                        EquivalenceClasses::default(),
+                        get_equivalences,
+                        ctx,
+                    );
+                }
+            }
             MirRelationExpr::Join {
                 inputs,
                 equivalences,
@@ -16,7 +16,8 @@ use std::iter;
 
 use mz_expr::visit::Visit;
 use mz_expr::{
-    AggregateExpr, ColumnOrder, EvalError, MirRelationExpr, MirScalarExpr, TableFunc, UnaryFunc,
+    AggregateExpr, AsOfMatch, ColumnOrder, EvalError, JoinImplementation, MirRelationExpr,
+    MirScalarExpr, TableFunc, UnaryFunc,
 };
 use mz_repr::{Datum, Diff, RelationType, Row, RowArena};
 
@@ -290,7 +291,7 @@ impl FoldConstants {
             MirRelationExpr::Join {
                 inputs,
                 equivalences,
-                ..
+                implementation,
             } => {
                 if inputs.iter().any(|e| e.is_empty()) {
                     relation.take_safely(Some(relation_type.clone()));
@@ -299,6 +300,43 @@ impl FoldConstants {
                         rows: Err(e.clone()),
                         typ: relation_type.clone(),
                     };
+                } else if let JoinImplementation::AsOf(asof) = implementation {
+                    if let [left, right] = &inputs[..] {
+                        if let (Some((Ok(left), ..)), Some((Ok(right), ..))) =
+                            (left.as_const(), right.as_const())
+                        {
+                            if equivalences
+                                .iter()
+                                .any(|equiv| equiv.iter().any(|e| e.contains_unmaterializable()))
+                                || asof.left_time.contains_unmaterializable()
+                                || asof.right_time.contains_unmaterializable()
+                            {
+                                return Ok(());
+                            }
+                            let new_rows = Self::fold_asof_join_constant(
+                                left,
+                                right,
+                                equivalences,
+                                asof,
+                                self.limit,
+                            );
+                            match new_rows {
+                                Ok(None) => {}
+                                Ok(Some(rows)) => {
+                                    *relation = MirRelationExpr::Constant {
+                                        rows: Ok(rows),
+                                        typ: relation_type.clone(),
+                                    };
+                                }
+                                Err(err) => {
+                                    *relation = MirRelationExpr::Constant {
+                                        rows: Err(err),
+                                        typ: relation_type.clone(),
+                                    };
+                                }
+                            }
+                        }
+                    }
                 } else if inputs
                     .iter()
                     .all(|i| matches!(i.as_const(), Some((Ok(_), ..))))
@@ -631,6 +669,91 @@ impl FoldConstants {
         Ok(Some(new_rows))
     }
 
+    /// Folds an ASOF join of two constant inputs.
+    ///
+    /// This must agree with the rendering of ASOF joins: each row of `left` is matched with the
+    /// closest row of `right` that satisfies the `equivalences`, where ties in time are broken by
+    /// the rest of the row, and rows with a null time never match.
+    fn fold_asof_join_constant(
+        left: &[(Row, Diff)],
+        right: &[(Row, Diff)],
+        equivalences: &[Vec<MirScalarExpr>],
+        asof: &AsOfMatch,
+        limit: Option<usize>,
+    ) -> Result<Option<Vec<(Row, Diff)>>, EvalError> {
+        if let Some(limit) = limit {
+            if left.len() * right.len() > limit {
+                return Ok(None);
+            }
+        }
+
+        // Only rows of `right` with a positive accumulated count can be matched.
+        let mut counts = BTreeMap::new();
+        for (row, diff) in right {
+            *counts.entry(row).or_insert(Diff::ZERO) += *diff;
+        }
+        let right = counts
+            .into_iter()
+            .filter(|(_row, diff)| diff.is_positive())
+            .map(|(row, _diff)| row)
+            .collect::<Vec<_>>();
+
+        let mut new_rows = Vec::new();
+        let mut row_buf = Row::default();
+        let mut datum_vec = mz_repr::DatumVec::new();
+        for (left_row, diff) in left {
+            // The rows of `right` with equal keys, along with their times.
+            let mut candidates = Vec::new();
+            'candidates: for right_row in right.iter() {
+                let mut packer = row_buf.packer();
+                packer.extend_by_row(left_row);
+                packer.extend_by_row(right_row);
+                let datums = datum_vec.borrow_with(&row_buf);
+                let temp_storage = RowArena::new();
+                for equivalence in equivalences {
+                    let mut values = equivalence.iter().map(|e| e.eval(&datums, &temp_storage));
+                    if let Some(value) = values.next() {
+                        let value = value?;
+                        for other in values {
+                            if other? != value {
+                                continue 'candidates;
+                            }
+                        }
+                    }
+                }
+                let time = asof.right_time.eval(&datums, &temp_storage)?;
+                if !time.is_null() {
+                    candidates.push((Row::pack_slice(&[time]), *right_row));
+                }
+            }
+            candidates.sort_by(|(t1, r1), (t2, r2)| {
+                t1.unpack_first()
+                    .cmp(&t2.unpack_first())
+                    .then_with(|| r1.iter().cmp(r2.iter()))
+            });
+            let times = candidates
+                .iter()
+                .map(|(time, _row)| time.unpack_first())
+                .collect::<Vec<_>>();
+
+            // The left time only references columns of `left`, which come first.
+            let datums = datum_vec.borrow_with(left_row);
+            let temp_storage = RowArena::new();
+            let left_time = asof.left_time.eval(&datums, &temp_storage)?;
+            if left_time.is_null() {
+                continue;
+            }
+            if let Some(pos) = asof.comparison.closest_match(left_time, &times) {
+                let (_time, right_row) = &candidates[pos];
+                let mut packer = row_buf.packer();
+                packer.extend_by_row(left_row);
+                packer.extend_by_row(right_row);
+                new_rows.push((row_buf.clone(), *diff));
+            }
+        }
+        Ok(Some(new_rows))
+    }
+
     fn fold_filter_constant(
         predicates: &[MirScalarExpr],
         rows: &[(Row, Diff)],
@@ -89,9 +89,14 @@ impl Join {
         if let MirRelationExpr::Join {
             inputs,
             equivalences,
-            ..
+            implementation,
         } = relation
         {
+            // ASOF joins are not inner joins, and must keep their two inputs.
+            if implementation.is_asof() {
+                return Ok(false);
+            }
+
             // Local non-fusion tidying.
             inputs.retain(|e| !e.is_constant_singleton());
             if inputs.len() == 0 {
@@ -117,8 +122,8 @@ impl Join {
                         | MirRelationExpr::Project { input, .. } => {
                             expr = &**input;
                         }
-                        MirRelationExpr::Join { .. } => {
-                            result = Some(true);
+                        MirRelationExpr::Join { implementation, .. } => {
+                            result = Some(!implementation.is_asof());
                         }
                         _ => {
                             result = Some(false);
@@ -132,15 +137,13 @@ impl Join {
                     .iter()
                     .map(|expr| {
                         let (mfp, inner) = MapFilterProject::extract_from_expression(expr);
-                        if let MirRelationExpr::Join {
-                            inputs,
-                            equivalences,
-                            ..
-                        } = inner
-                        {
-                            Ok((mfp, (inputs, equivalences)))
-                        } else {
-                            Err((mfp.projection.len(), expr))
+                        match inner {
+                            MirRelationExpr::Join {
+                                inputs,
+                                equivalences,
+                                implementation,
+                            } if !implementation.is_asof() => Ok((mfp, (inputs, equivalences))),
+                            _ => Err((mfp.projection.len(), expr)),
                         }
                     })
                     .collect::<Vec<_>>();
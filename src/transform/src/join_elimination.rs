@@ -0,0 +1,420 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Removes outer joins whose null-padded columns are never used, and inner
+//! joins against inputs that a foreign key references.
+//!
+//! An outer join is lowered to the inner join, unioned with the rows of the
+//! preserved side that have no match, padded with nulls. For a left join whose
+//! right side is unique on the join key, the lowering forms the latter from
+//! the columns of the join itself (see `attempt_outer_equijoin`):
+//!
+//! ```text
+//! Union
+//!   Map (null, ..)
+//!     Union
+//!       Negate
+//!         Project (#0..#n)
+//!           Get join
+//!       Get left
+//!   Get join
+//! ```
+//!
+//! If none of the padded columns are used above the union, we can project them
+//! away in each branch. The matched rows then appear once negated and once
+//! as-is, and cancel out, leaving just `Get left`. In particular, the join is
+//! no longer rendered.
+//!
+//! An inner join can only be removed if every row of the other side has exactly
+//! one match. A foreign key promises this for the rows whose foreign key columns
+//! are not null, provided the join equates them with the referenced columns, and
+//! the referenced input is all of a collection that is unique on them. If the
+//! columns of the referenced input are otherwise unused, we replace the join by
+//! the referencing rows with non-null foreign keys:
+//!
+//! ```text
+//! Project (#0, #1)                      Project (#0, #1)
+//!   Join on=(#0 = #2)           =>        Filter (#0) IS NOT NULL
+//!     Get fact                              Get fact
+//!     Project (#0)
+//!       Get dim
+//! ```
+//!
+//! Foreign keys are not enforced, so this trusts that the user declared them
+//! correctly. `ASSERT NOT NULL` only rules out null join keys, not keys without
+//! a match, and so does not allow removing inner joins by itself.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use itertools::Itertools;
+use mz_expr::visit::Visit;
+use mz_expr::{Id, JoinImplementation, JoinInputMapper, MirRelationExpr, MirScalarExpr};
+use mz_repr::{GlobalId, RelationType};
+
+use crate::union_cancel::UnionBranchCancellation;
+use crate::{ForeignKeyOracle, TransformCtx, TransformError};
+
+/// Removes outer joins whose null-padded columns are never used, and inner
+/// joins against inputs that a foreign key references.
+#[derive(Debug)]
+pub struct JoinElimination;
+
+impl crate::Transform for JoinElimination {
+    fn name(&self) -> &'static str {
+        "JoinElimination"
+    }
+
+    #[mz_ore::instrument(
+        target = "optimizer",
+        level = "debug",
+        fields(path.segment = "join_elimination")
+    )]
+    fn actually_perform_transform(
+        &self,
+        relation: &mut MirRelationExpr,
+        ctx: &mut TransformCtx,
+    ) -> Result<(), TransformError> {
+        let result = relation.try_visit_mut_post(&mut |e| {
+            self.action(e)?;
+            self.eliminate_referenced(e, ctx.foreign_keys);
+            Ok(())
+        });
+        mz_repr::explain::trace_plan(&*relation);
+        result
+    }
+}
+
+impl JoinElimination {
+    /// Pushes a projection that ignores null-padded columns into a union, if
+    /// that lets branches of the union cancel out.
+    ///
+    /// Looks for `Project (Map? (Union ..))`, where the optional `Map` stems
+    /// from the `SELECT` list.
+    pub fn action(&self, relation: &mut MirRelationExpr) -> Result<(), TransformError> {
+        let MirRelationExpr::Project { input, outputs } = relation else {
+            return Ok(());
+        };
+        let (union, scalars) = match &**input {
+            MirRelationExpr::Map { input, scalars } => (&**input, scalars.clone()),
+            input => (input, vec![]),
+        };
+        let MirRelationExpr::Union { base, inputs } = union else {
+            return Ok(());
+        };
+
+        // The columns of the union that are used above it.
+        let arity = union.arity();
+        let mut demand = outputs
+            .iter()
+            .copied()
+            .filter(|c| *c < arity)
+            .collect::<BTreeSet<_>>();
+        for scalar in scalars.iter() {
+            demand.extend(scalar.support().into_iter().filter(|c| *c < arity));
+        }
+        let demand = demand.into_iter().collect_vec();
+
+        let branches = std::iter::once(&**base).chain(inputs.iter());
+        if !branches.clone().any(|b| padded_input(b, &demand).is_some()) {
+            return Ok(());
+        }
+
+        // Project each branch to the demanded columns, replacing padded
+        // branches by their input and flattening unions that this exposes.
+        let mut new_branches = Vec::new();
+        for branch in branches {
+            match padded_input(branch, &demand) {
+                Some(MirRelationExpr::Union { base, inputs }) => {
+                    for branch in std::iter::once(&**base).chain(inputs.iter()) {
+                        new_branches.push(project_branch(branch.clone(), &demand));
+                    }
+                }
+                Some(input) => new_branches.push(project_branch(input.clone(), &demand)),
+                None => new_branches.push(project_branch(branch.clone(), &demand)),
+            }
+        }
+        let empty_before = new_branches.iter().filter(|b| b.is_empty()).count();
+        let mut new_branches = new_branches.into_iter();
+        let mut new_union = MirRelationExpr::Union {
+            base: Box::new(new_branches.next().expect("at least one branch")),
+            inputs: new_branches.collect(),
+        };
+        UnionBranchCancellation.action(&mut new_union)?;
+
+        // Only rewrite the plan if branches cancelled out. Otherwise we would
+        // just push down a projection that `ProjectionLifting` lifts again.
+        let MirRelationExpr::Union { base, inputs } = &new_union else {
+            unreachable!("`UnionBranchCancellation` preserves unions")
+        };
+        let empty_after = std::iter::once(&**base)
+            .chain(inputs.iter())
+            .filter(|b| b.is_empty())
+            .count();
+        if empty_after == empty_before {
+            return Ok(());
+        }
+
+        // Renumber the columns used above the union.
+        let remap = demand
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (*c, i))
+            .chain((arity..arity + scalars.len()).map(|c| (c, c - arity + demand.len())))
+            .collect::<BTreeMap<_, _>>();
+        let scalars = scalars
+            .into_iter()
+            .map(|mut scalar| {
+                scalar.permute_map(&remap);
+                scalar
+            })
+            .collect();
+        let outputs = outputs.iter().map(|c| remap[c]).collect();
+        *relation = new_union.map(scalars).project(outputs);
+
+        Ok(())
+    }
+
+    /// Removes an input of an inner join that a foreign key of another input
+    /// references, if none of its columns are used other than the ones equated
+    /// with the foreign key.
+    ///
+    /// Looks for `Project (Join ..)`, where the referenced input reads a
+    /// collection through projections only, and the referencing input reads a
+    /// collection through projections and filters.
+    pub fn eliminate_referenced(
+        &self,
+        relation: &mut MirRelationExpr,
+        foreign_keys: &dyn ForeignKeyOracle,
+    ) {
+        if let Some(new_relation) = without_referenced_input(relation, foreign_keys) {
+            *relation = new_relation;
+        }
+    }
+}
+
+/// Returns `relation` without an input of its inner join that a foreign key of
+/// another input references, if that is possible (see
+/// [`JoinElimination::eliminate_referenced`]).
+fn without_referenced_input(
+    relation: &MirRelationExpr,
+    foreign_keys: &dyn ForeignKeyOracle,
+) -> Option<MirRelationExpr> {
+    let MirRelationExpr::Project { input, outputs } = relation else {
+        return None;
+    };
+    let MirRelationExpr::Join {
+        inputs,
+        equivalences,
+        implementation,
+    } = &**input
+    else {
+        return None;
+    };
+    // ASOF joins are not inner joins.
+    if implementation.is_asof() {
+        return None;
+    }
+
+    let referenced = inputs
+        .iter()
+        .map(|input| source_columns(input, false))
+        .collect_vec();
+    if referenced.iter().all(|source| source.is_none()) {
+        return None;
+    }
+    let mapper = JoinInputMapper::new(inputs);
+
+    for (i, input) in inputs.iter().enumerate() {
+        let Some((id, _, columns)) = source_columns(input, true) else {
+            continue;
+        };
+        for fk in foreign_keys.foreign_keys_on(id) {
+            for (j, source) in referenced.iter().enumerate() {
+                let Some((referenced_id, typ, referenced_columns)) = source else {
+                    continue;
+                };
+                if i == j || *referenced_id != fk.referenced_id {
+                    continue;
+                }
+                // Each foreign key matches at most one referenced row.
+                if !typ
+                    .keys
+                    .iter()
+                    .any(|key| key.iter().all(|c| fk.referenced_columns.contains(c)))
+                {
+                    continue;
+                }
+
+                // The columns of `j` that the join equates with the
+                // foreign key columns of `i`, mapped to the latter.
+                let mut pairs = BTreeMap::new();
+                for (c, rc) in fk.columns.iter().zip_eq(fk.referenced_columns.iter()) {
+                    let (Some(li), Some(lj)) = (
+                        columns.iter().position(|x| x == c),
+                        referenced_columns.iter().position(|x| x == rc),
+                    ) else {
+                        break;
+                    };
+                    let gi = mapper.map_column_to_global(li, i);
+                    let gj = mapper.map_column_to_global(lj, j);
+                    if equivalences.iter().any(|class| {
+                        class.iter().any(|e| e.as_column() == Some(gi))
+                            && class.iter().any(|e| e.as_column() == Some(gj))
+                    }) {
+                        pairs.insert(gj, gi);
+                    }
+                }
+                if pairs.len() != fk.columns.len() {
+                    continue;
+                }
+
+                // The other columns of `j` must not be used.
+                let j_columns = mapper.global_columns(j);
+                let unused = |c: &usize| !j_columns.contains(c) || pairs.contains_key(c);
+                if !outputs.iter().all(unused)
+                    || !equivalences.iter().flatten().all(|e| match e.as_column() {
+                        Some(c) => unused(&c),
+                        None => e.support().iter().all(|c| !j_columns.contains(c)),
+                    })
+                {
+                    continue;
+                }
+
+                // A row of `i` whose foreign key columns are not all
+                // non-null is not constrained by the foreign key. Such rows
+                // have no match if the referenced columns are non-null, and
+                // we filter them out. Otherwise, we cannot tell.
+                let input_type = input.typ();
+                let nullable = pairs
+                    .values()
+                    .filter(|gi| {
+                        input_type.column_types[mapper.map_column_to_local(**gi).0].nullable
+                    })
+                    .copied()
+                    .collect_vec();
+                if !nullable.is_empty()
+                    && fk
+                        .referenced_columns
+                        .iter()
+                        .any(|c| typ.column_types[*c].nullable)
+                {
+                    continue;
+                }
+
+                // Remove `j`, map its columns to their partners in `i`,
+                // and renumber the columns after it.
+                let remap = (0..mapper.total_columns())
+                    .filter_map(|c| {
+                        let new = pairs.get(&c).copied().unwrap_or(c);
+                        if j_columns.contains(&new) {
+                            None
+                        } else if new >= j_columns.end {
+                            Some((c, new - j_columns.len()))
+                        } else {
+                            Some((c, new))
+                        }
+                    })
+                    .collect::<BTreeMap<_, _>>();
+                let equivalences = equivalences
+                    .iter()
+                    .map(|class| {
+                        class
+                            .iter()
+                            .filter(|e| !e.as_column().is_some_and(|c| pairs.contains_key(&c)))
+                            .map(|e| {
+                                let mut e = e.clone();
+                                e.permute_map(&remap);
+                                e
+                            })
+                            .collect_vec()
+                    })
+                    .filter(|class| class.len() > 1)
+                    .collect_vec();
+                let predicates = nullable
+                    .iter()
+                    .map(|gi| MirScalarExpr::column(remap[gi]).call_is_null().not());
+                let outputs = outputs.iter().map(|c| remap[c]).collect();
+                let mut inputs = inputs.clone();
+                inputs.remove(j);
+                let join = MirRelationExpr::Join {
+                    inputs,
+                    equivalences,
+                    implementation: JoinImplementation::Unimplemented,
+                };
+                return Some(join.filter(predicates).project(outputs));
+            }
+        }
+    }
+    None
+}
+
+/// If `input` reads a global collection through projections, and also through
+/// filters if `filters` is set, returns the id and type of the collection, and
+/// the column of the collection that each column of `input` holds.
+fn source_columns(
+    mut input: &MirRelationExpr,
+    filters: bool,
+) -> Option<(GlobalId, &RelationType, Vec<usize>)> {
+    let mut projections = Vec::new();
+    loop {
+        match input {
+            MirRelationExpr::Project {
+                input: inner,
+                outputs,
+            } => {
+                projections.push(outputs);
+                input = inner;
+            }
+            MirRelationExpr::Filter { input: inner, .. } if filters => input = inner,
+            MirRelationExpr::Get {
+                id: Id::Global(id),
+                typ,
+                ..
+            } => {
+                let mut columns = (0..typ.arity()).collect_vec();
+                for outputs in projections.iter().rev() {
+                    columns = outputs.iter().map(|c| columns[*c]).collect();
+                }
+                return Some((*id, typ, columns));
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// If `branch` pads its input with nulls in columns that are not in `demand`,
+/// returns that input.
+fn padded_input<'a>(branch: &'a MirRelationExpr, demand: &[usize]) -> Option<&'a MirRelationExpr> {
+    match branch {
+        MirRelationExpr::Map { input, scalars }
+            if scalars.iter().all(|s| s.is_literal_null())
+                && demand.iter().all(|c| *c < input.arity()) =>
+        {
+            Some(&**input)
+        }
+        _ => None,
+    }
+}
+
+/// Projects `branch` to `outputs`, pushing the projection through `Negate`s
+/// and fusing it with other projections, so that `UnionBranchCancellation`
+/// recognizes equal branches.
+fn project_branch(branch: MirRelationExpr, outputs: &[usize]) -> MirRelationExpr {
+    match branch {
+        MirRelationExpr::Negate { input } => project_branch(*input, outputs).negate(),
+        MirRelationExpr::Project {
+            input,
+            outputs: inner,
+        } => {
+            let outputs = outputs.iter().map(|c| inner[*c]).collect_vec();
+            project_branch(*input, &outputs)
+        }
+        branch => branch.project(outputs.to_vec()),
+    }
+}
@@ -146,6 +146,8 @@ impl JoinImplementation {
             //  - Delta, then we are good already.
             //  - IndexedFilter, then we just leave that alone, because those are out of scope
             //    for JoinImplementation (they are created by `LiteralConstraints`).
+            //  - AsOf, then we also leave that alone, because ASOF joins are planned during
+            //    lowering and rendered by their own operator.
             // We don't want to change from a Differential plan to an other Differential plan, or
             // from a Delta plan to an other Delta plan, because the second run cannot distinguish
             // between an ArrangeBy that marks an already existing arrangement and an ArrangeBy
@@ -281,7 +283,10 @@ impl JoinImplementation {
                 if features.enable_cardinality_estimates {
                     let mut builder = DerivedBuilder::new(features);
                     // TODO(mgree): it would be good to not have to copy the statistics here
-                    builder.require(Cardinality::with_stats(stats.as_map()));
+                    builder.require(
+                        Cardinality::with_stats(stats.as_map())
+                            .with_distinct_stats(stats.distinct_map()),
+                    );
                     let derived = builder.visit(input);
 
                     let estimate = *derived.as_view().value::<Cardinality>().unwrap();
@@ -481,16 +486,33 @@ impl JoinImplementation {
                 }
                 // If the delta plan needs new arrangements, compare with the differential plan.
                 Ok((delta_query_plan, delta_new_arrangements)) => {
+                    // With cardinality estimates, we can compare the sizes of the new arrangements
+                    // of both plans, including the intermediate arrangements of the differential plan.
+                    let new_records = if features.enable_join_size_estimates {
+                        new_arrangement_records(&delta_query_plan)
+                            .zip(new_arrangement_records(&differential_query_plan))
+                    } else {
+                        None
+                    };
                     tracing::debug!(
                         delta_new_arrangements = delta_new_arrangements,
                         differential_new_arrangements = differential_new_arrangements,
+                        ?new_records,
                         "comparing delta and differential joins",
                     );
 
-                    if features.enable_eager_delta_joins
-                        && delta_new_arrangements <= differential_new_arrangements
-                    {
-                        // If we're eagerly planning delta joins, pick the delta plan if it's more economical.
+                    // Only eager delta joins may create new arrangements for a delta plan. The size
+                    // estimates, where enabled and available, refine what "more economical" means.
+                    let prefer_delta = features.enable_eager_delta_joins
+                        && match new_records {
+                            Some((delta_records, differential_records)) => {
+                                delta_records <= differential_records
+                            }
+                            None => delta_new_arrangements <= differential_new_arrangements,
+                        };
+                    if prefer_delta {
+                        // If we're eagerly planning delta joins, pick the delta plan if it's more
+                        // economical.
                         tracing::debug!(
                             plan = ?delta_query_plan,
                             "picking delta query plan");
@@ -827,6 +849,58 @@ mod differential {
     }
 }
 
+/// Estimates the number of records held by the arrangements that the implementation of `join`
+/// newly creates, based on the cardinality estimates in its [`JoinInputCharacteristics`].
+///
+/// Intermediate results of a differential join are assumed to be about as large as the largest
+/// input joined so far, except that joining an input on a unique key does not grow them.
+///
+/// Returns `None` if the implementation is not a delta or differential join, or if any of its
+/// inputs lacks a cardinality estimate.
+fn new_arrangement_records(join: &MirRelationExpr) -> Option<usize> {
+    let MirRelationExpr::Join { implementation, .. } = join else {
+        return None;
+    };
+
+    let mut new_arrangements = BTreeMap::new();
+    let mut intermediate_records = 0;
+    match implementation {
+        mz_expr::JoinImplementation::DeltaQuery(orders) => {
+            for (input, key, characteristics) in orders.iter().flatten() {
+                let characteristics = characteristics.as_ref()?;
+                let cardinality = characteristics.cardinality()?;
+                if !characteristics.arranged() {
+                    new_arrangements.insert((*input, key), cardinality);
+                }
+            }
+        }
+        Differential((start, start_key, start_characteristics), order) => {
+            let start_characteristics = start_characteristics.as_ref()?;
+            let mut intermediate = start_characteristics.cardinality()?;
+            if !start_characteristics.arranged() {
+                new_arrangements.insert((*start, start_key.as_ref()?), intermediate);
+            }
+            for (position, (input, key, characteristics)) in order.iter().enumerate() {
+                let characteristics = characteristics.as_ref()?;
+                let cardinality = characteristics.cardinality()?;
+                if !characteristics.arranged() {
+                    new_arrangements.insert((*input, key), cardinality);
+                }
+                if !characteristics.unique_key() {
+                    intermediate = std::cmp::max(intermediate, cardinality);
+                }
+                // Every intermediate result but the final one is arranged for the next join.
+                if position + 1 < order.len() {
+                    intermediate_records += intermediate;
+                }
+            }
+        }
+        _ => return None,
+    }
+
+    Some(intermediate_records + new_arrangements.values().sum::<usize>())
+}
+
 /// Modify `inputs` to ensure specified arrangements are available.
 ///
 /// Lift filter predicates when all needed arrangements are otherwise available.
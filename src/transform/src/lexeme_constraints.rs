@@ -0,0 +1,219 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! See if there are predicates of the form `<document> @@ literal` that can be sped up using a
+//! lexeme index.
+//!
+//! A lexeme index on `<document>` arranges the rows of a collection under each lexeme of their
+//! `tsvector`, i.e., it is an index with key `#0` on the collection that
+//! [`lexeme_collection`] describes. If an MFP on top of a Get has such a predicate in its filter,
+//! and the query has a lexeme that every matching document must contain, we look up that lexeme
+//! in the index with an `IndexedFilter` join, the same way [`LiteralConstraints`] looks up
+//! literals in a regular index:
+//!
+//! ```text
+//! Filter (#1 @@ "fat & rat")          Project (#1..=#2)
+//!   Get docs                     =>     Filter (#2 @@ "fat & rat")
+//!                                         Join on=(#0 = #3) type=indexed_filter
+//!                                           ArrangeBy keys=[[#0]]
+//!                                             Get lexeme_idx
+//!                                           ArrangeBy keys=[[#0]]
+//!                                             Constant
+//!                                               - ("fat")
+//! ```
+//!
+//! The lexemes of a `tsvector` are distinct, so the lookup produces each row that contains the
+//! lexeme exactly once. The predicate stays in place to remove the rows that contain the lexeme
+//! without matching the query.
+//!
+//! Queries that a document can match without containing any particular lexeme, like `fat | rat`,
+//! `!fat` or `fat:*`, are not rewritten.
+//!
+//! [`LiteralConstraints`]: crate::literal_constraints::LiteralConstraints
+
+use std::sync::LazyLock;
+
+use mz_expr::JoinImplementation::IndexedFilter;
+use mz_expr::visit::VisitChildren;
+use mz_expr::{
+    BinaryFunc, Id, MapFilterProject, MirRelationExpr, MirScalarExpr, TableFunc, UnaryFunc,
+    VariadicFunc, func,
+};
+use mz_repr::{Datum, Diff, GlobalId, RelationType, Row, ScalarType};
+
+use crate::TransformCtx;
+use crate::canonicalize_mfp::CanonicalizeMfp;
+
+/// The key of the arrangement that a lexeme index maintains: the lexeme column of
+/// [`lexeme_collection`].
+pub static LEXEME_INDEX_KEY: LazyLock<Vec<MirScalarExpr>> =
+    LazyLock::new(|| vec![MirScalarExpr::column(0)]);
+
+/// Returns the collection that a lexeme index on `document` arranges: one row per lexeme of
+/// `document` in each row of `input`, with the lexeme as the first column, followed by the
+/// columns of the row.
+pub fn lexeme_collection(input: MirRelationExpr, document: MirScalarExpr) -> MirRelationExpr {
+    let arity = input.arity();
+    input
+        .flat_map(
+            TableFunc::UnnestArray {
+                el_typ: ScalarType::String,
+            },
+            vec![document.call_unary(UnaryFunc::TsVectorToArray(func::TsVectorToArray))],
+        )
+        .project(std::iter::once(arity).chain(0..arity).collect())
+}
+
+/// Convert `@@` predicates into `IndexedFilter` joins on lexeme indexes.
+#[derive(Debug)]
+pub struct LexemeConstraints;
+
+impl crate::Transform for LexemeConstraints {
+    fn name(&self) -> &'static str {
+        "LexemeConstraints"
+    }
+
+    #[mz_ore::instrument(
+        target = "optimizer",
+        level = "debug",
+        fields(path.segment = "lexeme_constraints")
+    )]
+    fn actually_perform_transform(
+        &self,
+        relation: &mut MirRelationExpr,
+        ctx: &mut TransformCtx,
+    ) -> Result<(), crate::TransformError> {
+        let result = self.action(relation, ctx);
+        mz_repr::explain::trace_plan(&*relation);
+        result
+    }
+}
+
+impl LexemeConstraints {
+    fn action(
+        &self,
+        relation: &mut MirRelationExpr,
+        transform_ctx: &mut TransformCtx,
+    ) -> Result<(), crate::TransformError> {
+        let mut mfp = MapFilterProject::extract_non_errors_from_expr_mut(relation);
+        relation.try_visit_mut_children(|e| self.action(e, transform_ctx))?;
+
+        if let MirRelationExpr::Get {
+            id: Id::Global(id),
+            ref typ,
+            ..
+        } = *relation
+        {
+            if let Some((idx_id, document, lexeme)) =
+                Self::detect_lexeme_constraint(&mfp, id, transform_ctx)
+            {
+                let arity = typ.arity();
+                let idx_typ = lexeme_collection(relation.clone(), document).typ();
+
+                let filter_list = MirRelationExpr::Constant {
+                    rows: Ok(vec![(lexeme.clone(), Diff::ONE)]),
+                    typ: RelationType::new(vec![ScalarType::String.nullable(false)])
+                        .with_key(vec![0]),
+                }
+                .arrange_by(&[vec![MirScalarExpr::column(0)]]);
+
+                *relation = MirRelationExpr::Join {
+                    // It's important to keep the `filter_list` in the second position.
+                    // Both the lowering and EXPLAIN depends on this.
+                    inputs: vec![
+                        MirRelationExpr::global_get(idx_id, idx_typ)
+                            .arrange_by(&[LEXEME_INDEX_KEY.clone()]),
+                        filter_list,
+                    ],
+                    equivalences: vec![vec![
+                        MirScalarExpr::column(0),
+                        MirScalarExpr::column(arity + 1),
+                    ]],
+                    implementation: IndexedFilter(
+                        idx_id,
+                        idx_id,
+                        LEXEME_INDEX_KEY.clone(),
+                        vec![lexeme],
+                    ),
+                };
+
+                // Rebuild the MFP to remove the lexeme columns on both sides of the join. The
+                // predicate stays, because containing the lexeme doesn't mean matching the query.
+                let (map, filter, project) = mfp.as_map_filter_project();
+                mfp = MapFilterProject::new(arity + 2)
+                    .project(1..=arity)
+                    .map(map)
+                    .filter(filter)
+                    .project(project);
+                mfp.optimize();
+            }
+        }
+
+        CanonicalizeMfp::rebuild_mfp(mfp, relation);
+
+        Ok(())
+    }
+
+    /// Detects a predicate `<document> @@ literal` in an MFP on top of a Get of `id`, where
+    /// `<document>` is the key of a lexeme index on `id`.
+    ///
+    /// Returns the id and the key of the index, and a lexeme that every row that satisfies the predicate
+    /// contains. If there are several, we pick the longest one, as longer lexemes tend to be
+    /// rarer.
+    fn detect_lexeme_constraint(
+        mfp: &MapFilterProject,
+        get_id: GlobalId,
+        transform_ctx: &TransformCtx,
+    ) -> Option<(GlobalId, MirScalarExpr, Row)> {
+        let documents: Vec<_> = transform_ctx.indexes.lexeme_indexes_on(get_id).collect();
+        if documents.is_empty() {
+            return None;
+        }
+
+        // Look through the map expressions, so that we find the document even if it was factored
+        // out of the predicate.
+        let mut inlined = mfp.clone();
+        let should_inline = (0..inlined.input_arity + inlined.expressions.len())
+            .map(|c| c >= inlined.input_arity)
+            .collect();
+        inlined.perform_inlining(should_inline);
+
+        let mut best: Option<(GlobalId, &MirScalarExpr, String)> = None;
+        for (_, predicate) in inlined.predicates.iter() {
+            for conjunct in predicate.and_or_args(VariadicFunc::And) {
+                let MirScalarExpr::CallBinary {
+                    func: BinaryFunc::TsMatch,
+                    expr1,
+                    expr2,
+                } = &conjunct
+                else {
+                    continue;
+                };
+                let Some((idx_id, document)) = documents.iter().find(|(_, doc)| **doc == **expr1)
+                else {
+                    continue;
+                };
+                let Some(Ok(query)) = expr2.as_literal_str().map(mz_repr::strconv::parse_tsquery)
+                else {
+                    continue;
+                };
+                let lexeme = query.required_lexemes().into_iter().max_by_key(|l| l.len());
+                if let Some(lexeme) = lexeme {
+                    if best.as_ref().is_none_or(|(_, _, b)| b.len() < lexeme.len()) {
+                        best = Some((*idx_id, *document, lexeme.to_string()));
+                    }
+                }
+            }
+        }
+        best.map(|(idx_id, document, lexeme)| {
+            let lexeme = Row::pack_slice(&[Datum::String(&lexeme)]);
+            (idx_id, document.clone(), lexeme)
+        })
+    }
+}
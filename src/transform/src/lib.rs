@@ -42,7 +42,9 @@ use crate::dataflow::DataflowMetainfo;
 use crate::demand::Demand;
 use crate::equivalence_propagation::EquivalencePropagation;
 use crate::fold_constants::FoldConstants;
+use crate::join_elimination::JoinElimination;
 use crate::join_implementation::JoinImplementation;
+use crate::lexeme_constraints::LexemeConstraints;
 use crate::literal_constraints::LiteralConstraints;
 use crate::literal_lifting::LiteralLifting;
 use crate::movement::ProjectionPushdown;
@@ -55,6 +57,7 @@ use crate::reduce_reduction::ReduceReduction;
 use crate::reduction_pushdown::ReductionPushdown;
 use crate::redundant_join::RedundantJoin;
 use crate::semijoin_idempotence::SemijoinIdempotence;
+use crate::spatial_join::SpatialJoin;
 use crate::threshold_elision::ThresholdElision;
 use crate::typecheck::{SharedContext, Typecheck};
 use crate::union_cancel::UnionBranchCancellation;
@@ -73,7 +76,9 @@ pub mod demand;
 pub mod equivalence_propagation;
 pub mod fold_constants;
 pub mod fusion;
+pub mod join_elimination;
 pub mod join_implementation;
+pub mod lexeme_constraints;
 pub mod literal_constraints;
 pub mod literal_lifting;
 pub mod monotonic;
@@ -89,6 +94,7 @@ pub mod reduce_reduction;
 pub mod reduction_pushdown;
 pub mod redundant_join;
 pub mod semijoin_idempotence;
+pub mod spatial_join;
 pub mod threshold_elision;
 pub mod typecheck;
 pub mod union_cancel;
@@ -118,6 +124,8 @@ pub struct TransformCtx<'a> {
     pub indexes: &'a dyn IndexOracle,
     /// Statistical estimates.
     pub stats: &'a dyn StatisticsOracle,
+    /// The declared foreign keys.
+    pub foreign_keys: &'a dyn ForeignKeyOracle,
     /// Features passed to the enclosing `Optimizer`.
     pub features: &'a OptimizerFeatures,
     /// Typechecking context.
@@ -149,6 +157,7 @@ impl<'a> TransformCtx<'a> {
         Self {
             indexes: &EmptyIndexOracle,
             stats: &EmptyStatisticsOracle,
+            foreign_keys: &EmptyForeignKeyOracle,
             global_id,
             features,
             typecheck_ctx,
@@ -165,6 +174,7 @@ impl<'a> TransformCtx<'a> {
     pub fn global(
         indexes: &'a dyn IndexOracle,
         stats: &'a dyn StatisticsOracle,
+        foreign_keys: &'a dyn ForeignKeyOracle,
         features: &'a OptimizerFeatures,
         typecheck_ctx: &'a SharedContext,
         df_meta: &'a mut DataflowMetainfo,
@@ -173,6 +183,7 @@ impl<'a> TransformCtx<'a> {
         Self {
             indexes,
             stats,
+            foreign_keys,
             global_id: None,
             features,
             df_meta,
@@ -358,6 +369,21 @@ pub trait IndexOracle: fmt::Debug {
         &self,
         id: GlobalId,
     ) -> Box<dyn Iterator<Item = (GlobalId, &[MirScalarExpr])> + '_>;
+
+    /// Returns an iterator over the lexeme indexes that exist on the identified
+    /// collection.
+    ///
+    /// Each index is described by its `tsvector` key expression. A lexeme index
+    /// does not arrange the identified collection itself, but the collection
+    /// described by [`lexeme_constraints::lexeme_collection`]. It is returned
+    /// by [`IndexOracle::indexes_on`] for its own id, with the key
+    /// [`lexeme_constraints::LEXEME_INDEX_KEY`].
+    fn lexeme_indexes_on(
+        &self,
+        _id: GlobalId,
+    ) -> Box<dyn Iterator<Item = (GlobalId, &MirScalarExpr)> + '_> {
+        Box::new(iter::empty())
+    }
 }
 
 /// An [`IndexOracle`] that knows about no indexes.
@@ -382,6 +408,18 @@ pub trait StatisticsOracle: fmt::Debug + Send {
 
     /// Returns a map from identifiers to sizes
     fn as_map(&self) -> BTreeMap<GlobalId, usize>;
+
+    /// Returns an estimate of the number of distinct values in column `column` of the given identifier
+    ///
+    /// Returning `None` means "no estimate". Unless overridden, no estimates are given.
+    fn distinct_estimate(&self, _id: GlobalId, _column: usize) -> Option<usize> {
+        None
+    }
+
+    /// Returns a map from identifiers and columns to numbers of distinct values
+    fn distinct_map(&self) -> BTreeMap<(GlobalId, usize), usize> {
+        BTreeMap::new()
+    }
 }
 
 /// A [`StatisticsOracle`] that knows nothing and can give no estimates.
@@ -398,6 +436,39 @@ impl StatisticsOracle for EmptyStatisticsOracle {
     }
 }
 
+/// A foreign key from some columns of a collection to a unique key of another
+/// collection.
+#[derive(Clone, Debug)]
+pub struct ForeignKey {
+    /// The referencing columns.
+    pub columns: Vec<usize>,
+    /// The referenced collection.
+    pub referenced_id: GlobalId,
+    /// The referenced columns, in the order of `columns`.
+    pub referenced_columns: Vec<usize>,
+}
+
+/// A trait for a type that can answer questions about what foreign keys are
+/// declared.
+pub trait ForeignKeyOracle: fmt::Debug {
+    /// Returns the foreign keys declared on the identified collection.
+    ///
+    /// Foreign keys are not enforced, so transforms that rely on them trust
+    /// the user's declaration. If the identified collection is unknown, the
+    /// returned list is empty.
+    fn foreign_keys_on(&self, id: GlobalId) -> Vec<ForeignKey>;
+}
+
+/// A [`ForeignKeyOracle`] that knows about no foreign keys.
+#[derive(Debug)]
+pub struct EmptyForeignKeyOracle;
+
+impl ForeignKeyOracle for EmptyForeignKeyOracle {
+    fn foreign_keys_on(&self, _: GlobalId) -> Vec<ForeignKey> {
+        Vec::new()
+    }
+}
+
 /// A sequence of transformations iterated some number of times.
 #[derive(Debug)]
 pub struct Fixpoint {
@@ -757,8 +828,14 @@ impl Optimizer {
             Box::new(Fixpoint {
                 name: "fixpoint_logical_02",
                 limit: 100,
-                transforms: vec![
+                transforms: transforms![
                     Box::new(SemijoinIdempotence::default()),
+                    // Converts outer joins to inner joins if predicates that
+                    // only surfaced after lowering reject the null-padded rows.
+                    Box::new(NonNullRequirements::default()); if ctx.features.enable_join_elimination,
+                    // Removes outer joins whose null-padded columns are unused,
+                    // and inner joins against inputs that a foreign key references.
+                    Box::new(JoinElimination); if ctx.features.enable_join_elimination,
                     // Pushes aggregations down
                     Box::new(ReductionPushdown),
                     // Replaces reduces with maps when the group keys are
@@ -801,11 +878,15 @@ impl Optimizer {
                     .disallow_new_globals()
                     .strict_join_equivalences(),
             ),
+            // Turns joins on spatial containment into equi-joins. This must
+            // run only once, and before join planning.
+            Box::new(SpatialJoin); if ctx.features.enable_spatial_join,
             // Considerations for the relationship between JoinImplementation and other transforms:
             // - there should be a run of LiteralConstraints before JoinImplementation lifts away
             //   the Filters from the Gets;
             // - there should be no RelationCSE between this LiteralConstraints and
             //   JoinImplementation, because that could move an IndexedFilter behind a Get.
+            //   The same holds for LexemeConstraints, which runs right after it.
             // - The last RelationCSE before JoinImplementation should be with inline_mfp = true.
             // - Currently, JoinImplementation can't be before LiteralLifting because the latter
             //   sometimes creates `Unimplemented` joins (despite LiteralLifting already having been
@@ -841,6 +922,7 @@ impl Optimizer {
                 ],
             }),
             Box::new(LiteralConstraints),
+            Box::new(LexemeConstraints),
             Box::new(Fixpoint {
                 name: "fixpoint_join_impl",
                 limit: 100,
@@ -933,6 +1015,7 @@ impl Optimizer {
         let transforms: Vec<Box<dyn Transform>> = vec![
             Box::new(canonicalization::ReduceScalars),
             Box::new(LiteralConstraints),
+            Box::new(LexemeConstraints),
             Box::new(CanonicalizeMfp),
             // We might have arrived at a constant, e.g., due to contradicting literal constraints.
             Box::new(Fixpoint {
@@ -463,7 +463,16 @@ impl LiteralLifting {
                     equivalences,
                     implementation,
                 } => {
-                    if !matches!(implementation, IndexedFilter(..)) {
+                    if implementation.is_asof() {
+                        // Literals can't be lifted through an ASOF join without rewriting its
+                        // match condition, so we put them back on top of each input.
+                        for input in inputs.iter_mut() {
+                            let literals = self.action(input, gets)?;
+                            if !literals.is_empty() {
+                                *input = input.take_dangerous().map(literals);
+                            }
+                        }
+                    } else if !matches!(implementation, IndexedFilter(..)) {
                         // before lifting, save the original shape of the inputs
                         let old_input_mapper = JoinInputMapper::new(inputs);
 
@@ -219,6 +219,12 @@ impl ProjectionLifting {
                         self.action(input, gets)?;
                     }
 
+                    // ASOF joins keep their input projections in place, as lifting them would
+                    // require rewriting the match condition.
+                    if implementation.is_asof() {
+                        return Ok(());
+                    }
+
                     // Track the location of the projected columns in the un-projected join.
                     let mut projection = Vec::new();
                     let mut temp_arity = 0;
@@ -223,7 +223,7 @@ impl ProjectionPushdown {
                     inputs,
                     equivalences,
                     implementation,
-                } if self.include_joins => {
+                } if self.include_joins && !implementation.is_asof() => {
                     assert!(
                         matches!(implementation, JoinImplementation::Unimplemented),
                         "ProjectionPushdown can't deal with filled in join implementations. Turn off `include_joins` if you'd like to run it after `JoinImplementation`."
@@ -257,7 +257,8 @@ impl ProjectionPushdown {
 
                     columns_to_pushdown.into_iter().collect()
                 }
-                // Skip joins if `self.include_joins` is turned off.
+                // Skip joins if `self.include_joins` is turned off, and always skip ASOF joins,
+                // whose match condition refers to the unprojected input columns.
                 MirRelationExpr::Join { inputs, equivalences: _, implementation: _ } => {
                     let input_mapper = JoinInputMapper::new(inputs);
 
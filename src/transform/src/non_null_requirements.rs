@@ -221,7 +221,7 @@ impl NonNullRequirements {
                 MirRelationExpr::Join {
                     inputs,
                     equivalences,
-                    ..
+                    implementation,
                 } => {
                     let input_types = inputs.iter().map(|i| i.typ()).collect::<Vec<_>>();
 
@@ -252,6 +252,12 @@ impl NonNullRequirements {
                         }
                     }
 
+                    // Discarding rows of the right input of an ASOF join could change which row
+                    // is the closest match, so only the left input receives requirements.
+                    if implementation.is_asof() {
+                        new_columns[1].clear();
+                    }
+
                     for (input, columns) in inputs.iter_mut().zip(new_columns) {
                         self.action(input, columns, gets)?;
                     }
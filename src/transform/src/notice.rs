@@ -31,10 +31,12 @@
 mod index_already_exists;
 mod index_key_empty;
 mod index_too_wide_for_literal_constraints;
+mod index_would_enable_fast_path;
 
 pub use index_already_exists::IndexAlreadyExists;
 pub use index_key_empty::IndexKeyEmpty;
 pub use index_too_wide_for_literal_constraints::IndexTooWideForLiteralConstraints;
+pub use index_would_enable_fast_path::IndexWouldEnableFastPath;
 
 use std::collections::BTreeSet;
 use std::fmt::{self, Error, Formatter, Write};
@@ -359,6 +361,7 @@ raw_optimizer_notices![
     IndexAlreadyExists => "An identical index already exists",
     IndexTooWideForLiteralConstraints => "Index too wide for literal constraints",
     IndexKeyEmpty => "Empty index key",
+    IndexWouldEnableFastPath => "Index would enable fast-path peeks",
 ];
 
 impl RawOptimizerNotice {
@@ -0,0 +1,106 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Hosts [`IndexWouldEnableFastPath`].
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use mz_expr::MirScalarExpr;
+use mz_expr::explain::{HumanizedNotice, HumanizerMode};
+use mz_ore::str::separated;
+use mz_repr::GlobalId;
+use mz_repr::explain::ExprHumanizer;
+
+use crate::notice::{ActionKind, OptimizerNoticeApi};
+
+/// An index that does not exist yet would have turned logged `SELECT`s that
+/// ran as slow-path peeks into fast-path peeks.
+///
+/// Emitted by the index advisor, which replays statements from the statement
+/// log against hypothetical indexes.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct IndexWouldEnableFastPath {
+    /// The id of the object that the index should be created on.
+    pub index_on_id: GlobalId,
+    /// The key of the suggested index.
+    pub recommended_key: Vec<MirScalarExpr>,
+    /// The (estimated) number of logged executions that would have been
+    /// served from the suggested index.
+    pub executions: u64,
+}
+
+impl OptimizerNoticeApi for IndexWouldEnableFastPath {
+    fn dependencies(&self) -> BTreeSet<GlobalId> {
+        BTreeSet::from([self.index_on_id])
+    }
+
+    fn fmt_message(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        humanizer: &dyn ExprHumanizer,
+        redacted: bool,
+    ) -> fmt::Result {
+        let index_on_id_name = humanizer
+            .humanize_id_unqualified(self.index_on_id)
+            .unwrap_or_else(|| self.index_on_id.to_string());
+
+        let mode = HumanizedNotice::new(redacted);
+        let col_names = humanizer.column_names_for_id(self.index_on_id);
+        let col_names = col_names.as_ref();
+        let recommended_key = separated(", ", mode.seq(&self.recommended_key, col_names));
+
+        let executions = self.executions;
+        write!(
+            f,
+            "An index on {index_on_id_name}({recommended_key}) would have served \
+             {executions} recently logged queries as fast-path peeks."
+        )
+    }
+
+    fn fmt_hint(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        _humanizer: &dyn ExprHumanizer,
+        _redacted: bool,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "Create the index in the cluster that runs these queries if the \
+             queries matter more than the memory needed to maintain the index. \
+             See mz_internal.mz_index_suggestions for cost estimates."
+        )
+    }
+
+    fn fmt_action(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        humanizer: &dyn ExprHumanizer,
+        redacted: bool,
+    ) -> fmt::Result {
+        let Some(index_on_id_name) = humanizer.humanize_id_unqualified(self.index_on_id) else {
+            return Ok(());
+        };
+
+        let mode = HumanizedNotice::new(redacted);
+        let col_names = humanizer.column_names_for_id(self.index_on_id);
+
+        let recommended_key = mode.seq(&self.recommended_key, col_names.as_ref());
+        let recommended_key = separated(", ", recommended_key);
+
+        write!(f, "CREATE INDEX ON {index_on_id_name}({recommended_key});")
+    }
+
+    fn action_kind(&self, humanizer: &dyn ExprHumanizer) -> ActionKind {
+        match humanizer.humanize_id_unqualified(self.index_on_id) {
+            Some(_) => ActionKind::SqlStatements,
+            None => ActionKind::None,
+        }
+    }
+}
@@ -190,6 +190,11 @@ impl PredicatePushdown {
                                 .or_insert_with(|| predicates.iter().cloned().collect())
                                 .retain(|p| predicates.contains(p));
                         }
+                        x @ MirRelationExpr::Join { .. } if x.is_asof_join() => {
+                            // Predicates can't move into the inputs of an ASOF join, as they
+                            // could change which row of the second input is the closest match.
+                            x.try_visit_mut_children(|e| self.action(e, get_predicates))?;
+                        }
                         MirRelationExpr::Join {
                             inputs,
                             equivalences,
@@ -566,8 +571,8 @@ impl PredicatePushdown {
                 MirRelationExpr::Join {
                     inputs,
                     equivalences,
-                    ..
-                } => {
+                    implementation,
+                } if !implementation.is_asof() => {
                     // The goal is to push
                     //   1) equivalences of the form `expr = <runtime constant>`, where `expr`
                     //      comes from a single input.
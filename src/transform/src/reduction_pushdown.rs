@@ -143,9 +143,13 @@ impl ReductionPushdown {
             if let MirRelationExpr::Join {
                 inputs,
                 equivalences,
-                implementation: _,
+                implementation,
             } = &mut **input
             {
+                // Reductions cannot be split across the inputs of an ASOF join.
+                if implementation.is_asof() {
+                    return Ok(());
+                }
                 if let Some(new_relation_expr) = try_push_reduce_through_join(
                     inputs,
                     equivalences,
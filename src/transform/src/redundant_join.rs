@@ -193,10 +193,12 @@ impl RedundantJoin {
                     // If we find an input that can be removed, we should do so!
                     // We only do this once per invocation to keep our sanity, but we could
                     // rewrite it to iterate. We can avoid looking for any relation that
-                    // does not have keys, as it cannot be redundant in that case.
+                    // does not have keys, as it cannot be redundant in that case. The inputs of
+                    // ASOF joins are never redundant, as the match condition filters them.
+                    let is_asof = implementation.is_asof();
                     if let Some((remove_input_idx, mut bindings)) = (0..input_types.len())
                         .rev()
-                        .filter(|i| !input_types[*i].keys.is_empty())
+                        .filter(|i| !is_asof && !input_types[*i].keys.is_empty())
                         .flat_map(|i| {
                             find_redundancy(
                                 i,
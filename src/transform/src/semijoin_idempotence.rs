@@ -165,13 +165,16 @@ impl SemijoinIdempotence {
                     implementation,
                     ..
                 } => {
-                    attempt_join_simplification(
-                        inputs,
-                        equivalences,
-                        implementation,
-                        let_replacements,
-                        gets_behind_gets,
-                    );
+                    // ASOF joins do not behave like semijoins, and are left as they are.
+                    if !implementation.is_asof() {
+                        attempt_join_simplification(
+                            inputs,
+                            equivalences,
+                            implementation,
+                            let_replacements,
+                            gets_behind_gets,
+                        );
+                    }
                     for input in inputs {
                         self.action(input, let_replacements, gets_behind_gets)?;
                     }
@@ -351,8 +354,8 @@ fn list_replacements(
         MirRelationExpr::Join {
             inputs,
             equivalences,
-            ..
-        } => {
+            implementation,
+        } if !implementation.is_asof() => {
             results.extend(list_replacements_join(
                 inputs,
                 equivalences,
@@ -0,0 +1,202 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Plans joins on spatial containment as equi-joins over grid cells.
+//!
+//! A join between two inputs whose only condition is `st_contains(a, b)` (or
+//! `st_within(b, a)`, which is planned as the former) has no equality between
+//! its inputs, so it would be rendered as a cross join followed by a filter.
+//! Each new point would be compared against every polygon, and vice versa.
+//!
+//! This transform flat maps the input of `a` with the grid cells that cover
+//! the envelope of `a`, and the input of `b` with the grid cells that contain
+//! the lower left corner of the envelope of `b`, one per grid level, and
+//! equates the cells of the two inputs:
+//!
+//! ```text
+//! Filter st_contains(#0, #1)          Project (#0, #4)
+//!   CrossJoin                    =>     Filter st_contains(#0, #4)
+//!     Get fences                          Join on=(#1 = #5 AND #2 = #6 AND #3 = #7)
+//!     Get pings                             FlatMap geometry_grid_cover(#0)
+//!                                             Get fences
+//!                                           FlatMap geometry_grid_anchor(#0)
+//!                                             Get pings
+//! ```
+//!
+//! If `a` contains `b`, exactly one of the cells of `a` is among the cells of
+//! `b` (see [`Geometry::grid_cover`]), so the rewritten join produces each pair
+//! of rows that satisfies the predicate exactly once. The predicate stays in
+//! place to remove the pairs that share a cell without satisfying it.
+//!
+//! Predicates that can be true for pairs that do not share a cell, like
+//! `st_intersects` or `st_dwithin`, are not rewritten. Neither are joins that
+//! already have an implementation: an ASOF join keeps filters above it, and
+//! planning it as an inner join would change its results.
+//!
+//! The transform runs once, before join planning. Running it again would add
+//! a second grid join, which is correct but redundant.
+//!
+//! [`Geometry::grid_cover`]: mz_repr::adt::geometry::Geometry::grid_cover
+
+use std::collections::BTreeSet;
+
+use mz_expr::visit::Visit;
+use mz_expr::{
+    BinaryFunc, JoinImplementation, JoinInputMapper, MirRelationExpr, MirScalarExpr, TableFunc,
+};
+
+use crate::{TransformCtx, TransformError};
+
+/// The number of columns that identify a grid cell: its level and indexes.
+const GRID_CELL_COLUMNS: usize = 3;
+
+/// Plans joins on spatial containment as equi-joins over grid cells.
+#[derive(Debug)]
+pub struct SpatialJoin;
+
+impl crate::Transform for SpatialJoin {
+    fn name(&self) -> &'static str {
+        "SpatialJoin"
+    }
+
+    #[mz_ore::instrument(
+        target = "optimizer",
+        level = "debug",
+        fields(path.segment = "spatial_join")
+    )]
+    fn actually_perform_transform(
+        &self,
+        relation: &mut MirRelationExpr,
+        _: &mut TransformCtx,
+    ) -> Result<(), TransformError> {
+        relation.visit_mut_post(&mut Self::action)?;
+        mz_repr::explain::trace_plan(&*relation);
+        Ok(())
+    }
+}
+
+impl SpatialJoin {
+    /// Rewrites `Filter (Join ..)` if one of the filter's predicates is a
+    /// containment between two inputs of the join that are not already
+    /// equi-joined.
+    pub fn action(relation: &mut MirRelationExpr) {
+        let MirRelationExpr::Filter { input, predicates } = relation else {
+            return;
+        };
+        let MirRelationExpr::Join {
+            inputs,
+            equivalences,
+            implementation: JoinImplementation::Unimplemented,
+        } = &**input
+        else {
+            return;
+        };
+
+        let mapper = JoinInputMapper::new(inputs);
+        let Some(containment) = predicates.iter().find_map(|p| {
+            let containment = Containment::new(p, &mapper)?;
+            // An existing equality between the two inputs is likely more
+            // selective than the grid, and we do not want to add a second
+            // join condition to it.
+            let joined = equivalences.iter().any(|class| {
+                let inputs = class
+                    .iter()
+                    .flat_map(|e| mapper.lookup_inputs(e))
+                    .collect::<BTreeSet<_>>();
+                inputs.contains(&containment.container.0)
+                    && inputs.contains(&containment.contained.0)
+            });
+            (!joined).then_some(containment)
+        }) else {
+            return;
+        };
+
+        let MirRelationExpr::Join {
+            mut inputs,
+            mut equivalences,
+            ..
+        } = input.take_dangerous()
+        else {
+            unreachable!("matched above")
+        };
+        let mut predicates = std::mem::take(predicates);
+
+        // Append the cell columns to the two inputs.
+        let (container, cover) = containment.container;
+        let (contained, anchor) = containment.contained;
+        inputs[container] = inputs[container]
+            .take_dangerous()
+            .flat_map(TableFunc::GeometryGridCover, vec![cover]);
+        inputs[contained] = inputs[contained]
+            .take_dangerous()
+            .flat_map(TableFunc::GeometryGridAnchor, vec![anchor]);
+        let new_mapper = JoinInputMapper::new(&inputs);
+
+        // The new positions of the join's existing columns.
+        let permutation = (0..inputs.len())
+            .flat_map(|i| {
+                let start = new_mapper.global_columns(i).start;
+                (0..mapper.input_arity(i)).map(move |c| start + c)
+            })
+            .collect::<Vec<_>>();
+        for expr in equivalences.iter_mut().flatten() {
+            expr.permute(&permutation);
+        }
+        for predicate in predicates.iter_mut() {
+            predicate.permute(&permutation);
+        }
+
+        let cells = |i: usize| new_mapper.global_columns(i).end - GRID_CELL_COLUMNS;
+        for c in 0..GRID_CELL_COLUMNS {
+            equivalences.push(vec![
+                MirScalarExpr::column(cells(container) + c),
+                MirScalarExpr::column(cells(contained) + c),
+            ]);
+        }
+
+        *relation = MirRelationExpr::Join {
+            inputs,
+            equivalences,
+            implementation: JoinImplementation::Unimplemented,
+        }
+        .filter(predicates)
+        .project(permutation);
+    }
+}
+
+/// A predicate `st_contains(a, b)`, with `a` and `b` expressions over different
+/// inputs of a join.
+struct Containment {
+    /// The input of `a`, and `a` in terms of that input's columns.
+    container: (usize, MirScalarExpr),
+    /// The input of `b`, and `b` in terms of that input's columns.
+    contained: (usize, MirScalarExpr),
+}
+
+impl Containment {
+    fn new(predicate: &MirScalarExpr, mapper: &JoinInputMapper) -> Option<Containment> {
+        let MirScalarExpr::CallBinary {
+            func: BinaryFunc::StContains,
+            expr1,
+            expr2,
+        } = predicate
+        else {
+            return None;
+        };
+        let container = mapper.single_input(expr1)?;
+        let contained = mapper.single_input(expr2)?;
+        if container == contained {
+            return None;
+        }
+        Some(Containment {
+            container: (container, mapper.map_expr_to_local((**expr1).clone())),
+            contained: (contained, mapper.map_expr_to_local((**expr2).clone())),
+        })
+    }
+}
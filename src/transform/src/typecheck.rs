@@ -16,8 +16,8 @@ use std::sync::{Arc, Mutex};
 use itertools::Itertools;
 use mz_expr::explain::{HumanizedExplain, HumanizerMode};
 use mz_expr::{
-    AggregateExpr, ColumnOrder, Id, JoinImplementation, LocalId, MirRelationExpr, MirScalarExpr,
-    RECURSION_LIMIT, non_nullable_columns,
+    AggregateExpr, AsOfMatch, ColumnOrder, Id, JoinImplementation, LocalId, MirRelationExpr,
+    MirScalarExpr, RECURSION_LIMIT, non_nullable_columns,
 };
 use mz_ore::soft_panic_or_log;
 use mz_ore::stack::{CheckedRecursion, RecursionGuard, RecursionLimitError};
@@ -793,6 +793,27 @@ impl Typecheck {
                             }
                         }
                     }
+                    JoinImplementation::AsOf(AsOfMatch {
+                        left_time,
+                        right_time,
+                        comparison: _,
+                    }) => {
+                        // The times have global column references, like the equivalences.
+                        let t_left = tc.typecheck_scalar(left_time, expr, &t_in_global)?;
+                        let t_right = tc.typecheck_scalar(right_time, expr, &t_in_global)?;
+
+                        let diffs =
+                            scalar_subtype_difference(&t_right.scalar_type, &t_left.scalar_type);
+                        if !diffs.is_empty() {
+                            return Err(TypeError::MismatchColumn {
+                                source: expr,
+                                got: t_right,
+                                expected: t_left,
+                                diffs,
+                                message: "ASOF join times have different scalar types".into(),
+                            });
+                        }
+                    }
                     JoinImplementation::Unimplemented => (),
                 }
 
@@ -162,6 +162,7 @@ mod tests {
                     humanizer: cat,
                     cardinality_stats: Default::default(), // empty stats
                     used_indexes: Default::default(),
+                    used_statistics: Default::default(),
                     finishing: Default::default(),
                     duration: Default::default(),
                     target_cluster: Default::default(),
@@ -66,6 +66,7 @@ fn handle_explain(
         humanizer: catalog,
         cardinality_stats: Default::default(), // empty stats
         used_indexes: Default::default(),
+        used_statistics: Default::default(),
         finishing: Default::default(),
         duration: Default::default(),
         target_cluster: Default::default(),
@@ -3566,7 +3566,11 @@ def workflow_statement_logging(c: Composition, parser: WorkflowArgumentParser) -
             user="mz_system",
         )
 
-        c.run_testdrive_files("statement-logging/statement-logging.td")
+        c.run_testdrive_files(
+            "statement-logging/statement-logging.td",
+            "statement-logging/peek-dataflow-cache.td",
+            "statement-logging/auto-index.td",
+        )
 
 
 def workflow_blue_green_deployment(